@@ -12,15 +12,15 @@ use crate::models::util::format_float;
 use crate::models::{
     ColorPickerId, ColorQuadInput, PdfFitModeOption, PdfLabelContentModeOption,
     PdfLabelPositionOption, PdfOrientationOption, PdfPageSizeOption,
-    PdfTransparentBackgroundOption, TabId, TextField, ToggleField,
+    PdfTransparentBackgroundOption, PngCompressionOption, TabId, TextField, ToggleField,
 };
 
-use wayscriber::config::validate_ocr_languages;
+use wayscriber::config::{validate_capture_format, validate_ocr_languages};
 
 use super::super::search::SearchArea;
 use super::super::state::ConfiguratorApp;
 use super::color_rows::{ResolvedColor, color_row};
-use super::{BuiltPage, PageBuilder};
+use super::{BuiltPage, PageBuilder, validate_u32_range};
 
 pub(super) fn build(sender: &ComponentSender<ConfiguratorApp>) -> BuiltPage {
     let mut page = PageBuilder::new(sender, TabId::Capture);
@@ -42,10 +42,43 @@ pub(super) fn build(sender: &ComponentSender<ConfiguratorApp>) -> BuiltPage {
             |app| app.draft.capture_filename_template.clone(),
             |value| Message::TextChanged(TextField::CaptureFilename, value),
         )
-        .entry_row(
-            "Format (png, jpg, ...)",
+        .entry_row_validated(
+            "Format (png, jpg, webp)",
             |app| app.draft.capture_format.clone(),
             |value| Message::TextChanged(TextField::CaptureFormat, value),
+            |app| format_error(&app.draft.capture_format),
+        )
+        .entry_row_validated(
+            "Canvas export format (png, jpg, webp)",
+            |app| app.draft.capture_canvas_export_format.clone(),
+            |value| Message::TextChanged(TextField::CaptureCanvasExportFormat, value),
+            |app| format_error(&app.draft.capture_canvas_export_format),
+        )
+        .combo_row(
+            "PNG compression",
+            "",
+            PngCompressionOption::list(),
+            labels(PngCompressionOption::list(), PngCompressionOption::label),
+            |app| app.draft.capture_png_compression,
+            Message::CapturePngCompressionChanged,
+        )
+        .entry_row_validated(
+            "JPEG quality",
+            |app| app.draft.capture_jpeg_quality.clone(),
+            |value| Message::TextChanged(TextField::CaptureJpegQuality, value),
+            |app| validate_u32_range(&app.draft.capture_jpeg_quality, 1, 100),
+        )
+        .switch_row(
+            "Near-lossless WebP",
+            "Saves smaller files with reduced color precision. Off is fully lossless.",
+            |app| app.draft.capture_webp_near_lossless,
+            |value| Message::ToggleChanged(ToggleField::CaptureWebpNearLossless, value),
+        )
+        .entry_row_validated(
+            "WebP near-lossless level (100 drops nothing)",
+            |app| app.draft.capture_webp_near_lossless_level.clone(),
+            |value| Message::TextChanged(TextField::CaptureWebpNearLosslessLevel, value),
+            |app| validate_u32_range(&app.draft.capture_webp_near_lossless_level, 1, 100),
         )
         .switch_row(
             "Copy to clipboard",
@@ -53,6 +86,12 @@ pub(super) fn build(sender: &ComponentSender<ConfiguratorApp>) -> BuiltPage {
             |app| app.draft.capture_copy_to_clipboard,
             |value| Message::ToggleChanged(ToggleField::CaptureCopyToClipboard, value),
         )
+        .switch_row(
+            "Backdrop behind copied selections",
+            "PNG/SVG copies for other apps get the board background (white on the overlay).",
+            |app| app.draft.capture_copy_selection_backdrop,
+            |value| Message::ToggleChanged(ToggleField::CaptureCopySelectionBackdrop, value),
+        )
         .switch_row(
             "Always exit overlay after capture",
             "",
@@ -239,6 +278,14 @@ pub(super) fn validate_f64_range(value: &str, min: f64, max: f64) -> Option<Stri
     }
 }
 
+/// Error text for a capture or export image format, `None` while the input
+/// names a supported one.
+fn format_error(value: &str) -> Option<String> {
+    validate_capture_format(value)
+        .err()
+        .map(|reason| format!("Format {reason}."))
+}
+
 /// The dialog/swatch seed for a `0.0..=1.0` RGBA quad.
 fn quad_color(input: &ColorQuadInput) -> ResolvedColor {
     let [red, green, blue, alpha] = parse_quad_values(&input.components);
@@ -375,6 +375,15 @@ pub(super) const CAPTURE_FILE_TERMS: &[&str] = &[
     "copy to clipboard",
     "always exit overlay after capture",
     "format",
+    "png",
+    "jpeg",
+    "webp",
+    "quality",
+    "compression",
+    "lossless",
+    "near-lossless",
+    "backdrop",
+    "copy selection",
 ];
 pub(super) const CAPTURE_PDF_TERMS: &[&str] = &[
     "pdf",
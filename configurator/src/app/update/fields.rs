@@ -8,8 +8,9 @@ use wayscriber::config::PerformanceFieldId;
 use crate::models::{
     FontStyleOption, FontWeightOption, KeybindingField, PdfFitModeOption,
     PdfLabelContentModeOption, PdfLabelPositionOption, PdfOrientationOption, PdfPageSizeOption,
-    PdfTransparentBackgroundOption, PresenterToolBehaviorOption, PresenterToolbarModeOption,
-    SessionCompressionOption, SessionStorageModeOption, TextField, ToggleField,
+    PdfTransparentBackgroundOption, PngCompressionOption, PresenterToolBehaviorOption,
+    PresenterToolbarModeOption, SessionCompressionOption, SessionStorageModeOption, TextField,
+    ToggleField,
 };
 #[cfg(feature = "tablet-input")]
 use crate::models::{PressureThicknessEditModeOption, PressureThicknessEntryModeOption};
@@ -82,6 +83,16 @@ impl ConfiguratorApp {
         Vec::new()
     }
 
+    pub(super) fn handle_capture_png_compression_changed(
+        &mut self,
+        option: PngCompressionOption,
+    ) -> Vec<Effect> {
+        self.status = StatusMessage::idle();
+        self.draft.capture_png_compression = option;
+        self.refresh_dirty_flag();
+        Vec::new()
+    }
+
     pub(super) fn handle_export_pdf_orientation_changed(
         &mut self,
         option: PdfOrientationOption,
@@ -248,6 +248,9 @@ impl ConfiguratorApp {
             Message::PresenterToolbarModeChanged(option) => {
                 self.handle_presenter_toolbar_mode_changed(option)
             }
+            Message::CapturePngCompressionChanged(option) => {
+                self.handle_capture_png_compression_changed(option)
+            }
             Message::ExportPdfPageSizeChanged(option) => {
                 self.handle_export_pdf_page_size_changed(option)
             }
@@ -9,14 +9,14 @@ use crate::models::{
     InputHudModeOption, InputHudPositionOption, KeybindingField, KeybindingsTabId,
     KeyboardModifiers, NamedColorOption, OverrideOption, PdfFitModeOption,
     PdfLabelContentModeOption, PdfLabelPositionOption, PdfOrientationOption, PdfPageSizeOption,
-    PdfTransparentBackgroundOption, PresenterToolBehaviorOption, PresenterToolbarModeOption,
-    PresetEraserKindOption, PresetEraserModeOption, PresetTextField, PresetToggleField,
-    RecorderDeviceKind, ReducedMotionOption, RenderProfileExportOption, RenderProfileMappingSide,
-    RenderProfileTextField, SessionCatalogActionResult, SessionCatalogItem,
-    SessionCompressionOption, SessionStorageModeOption, ShortcutManagerFilter, ShortcutManagerSort,
-    StatusPositionOption, TabId, TextField, ToggleField, ToolOption, ToolbarLayoutModeOption,
-    ToolbarOverrideField, ToolbarRebindModifierOption, UiTabId, UiThemeOption,
-    ZoomChipDisplayOption,
+    PdfTransparentBackgroundOption, PngCompressionOption, PresenterToolBehaviorOption,
+    PresenterToolbarModeOption, PresetEraserKindOption, PresetEraserModeOption, PresetTextField,
+    PresetToggleField, RecorderDeviceKind, ReducedMotionOption, RenderProfileExportOption,
+    RenderProfileMappingSide, RenderProfileTextField, SessionCatalogActionResult,
+    SessionCatalogItem, SessionCompressionOption, SessionStorageModeOption, ShortcutManagerFilter,
+    ShortcutManagerSort, StatusPositionOption, TabId, TextField, ToggleField, ToolOption,
+    ToolbarLayoutModeOption, ToolbarOverrideField, ToolbarRebindModifierOption, UiTabId,
+    UiThemeOption, ZoomChipDisplayOption,
 };
 #[cfg(feature = "tablet-input")]
 use crate::models::{PressureThicknessEditModeOption, PressureThicknessEntryModeOption};
@@ -157,6 +157,7 @@ pub enum Message {
     SessionCompressionChanged(SessionCompressionOption),
     PresenterToolBehaviorChanged(PresenterToolBehaviorOption),
     PresenterToolbarModeChanged(PresenterToolbarModeOption),
+    CapturePngCompressionChanged(PngCompressionOption),
     ExportPdfPageSizeChanged(PdfPageSizeOption),
     ExportPdfOrientationChanged(PdfOrientationOption),
     ExportPdfFitChanged(PdfFitModeOption),
@@ -1,6 +1,8 @@
 mod mapping;
 mod validation;
 
+use wayscriber::config::BoardGridConfig;
+
 use super::super::color::ColorTripletInput;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -68,5 +70,8 @@ pub struct BoardsDraft {
     pub show_pan_badge: bool,
     pub persist_customizations: bool,
     pub default_board: String,
+    /// Carried through unedited so saving from the configurator keeps the
+    /// `[boards.grid]` settings.
+    pub grid: BoardGridConfig,
     pub items: Vec<BoardItemDraft>,
 }
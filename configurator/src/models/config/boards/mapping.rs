@@ -120,6 +120,7 @@ impl BoardsDraft {
             show_pan_badge: boards.show_pan_badge,
             persist_customizations: boards.persist_customizations,
             default_board: boards.default_board.clone(),
+            grid: boards.grid.clone(),
             items: boards
                 .items
                 .iter()
@@ -143,6 +144,7 @@ impl BoardsDraft {
         if !default_board.is_empty() {
             config.default_board = default_board.to_string();
         }
+        config.grid = self.grid.clone();
 
         config.items = self
             .items
@@ -3,7 +3,7 @@ use super::super::super::fields::ZoomChipDisplayOption;
 use super::super::super::fields::{
     EraserModeOption, FontStyleOption, FontWeightOption, InputHudModeOption,
     InputHudPositionOption, PdfFitModeOption, PdfLabelContentModeOption, PdfLabelPositionOption,
-    PdfOrientationOption, PdfPageSizeOption, PdfTransparentBackgroundOption,
+    PdfOrientationOption, PdfPageSizeOption, PdfTransparentBackgroundOption, PngCompressionOption,
     PresenterToolBehaviorOption, PresenterToolbarModeOption, ReducedMotionOption,
     SessionCompressionOption, SessionStorageModeOption, StatusPositionOption, ToolOption,
     ToolbarLayoutModeOption, ToolbarRebindModifierOption, UiThemeOption,
@@ -247,7 +247,15 @@ impl ConfigDraft {
             capture_save_directory: config.capture.save_directory.clone(),
             capture_filename_template: config.capture.filename_template.clone(),
             capture_format: config.capture.format.clone(),
+            capture_canvas_export_format: config.capture.canvas_export_format.clone(),
+            capture_png_compression: PngCompressionOption::from_config(
+                config.capture.png_compression,
+            ),
+            capture_jpeg_quality: config.capture.jpeg_quality.to_string(),
+            capture_webp_near_lossless: config.capture.webp_near_lossless,
+            capture_webp_near_lossless_level: config.capture.webp_near_lossless_level.to_string(),
             capture_copy_to_clipboard: config.capture.copy_to_clipboard,
+            capture_copy_selection_backdrop: config.capture.copy_selection_backdrop,
             capture_exit_after: config.capture.exit_after_capture,
             capture_ocr_languages: config.capture.ocr_languages.clone(),
             export_pdf_filename_template: config
@@ -5,7 +5,7 @@ use super::super::fields::ZoomChipDisplayOption;
 use super::super::fields::{
     EraserModeOption, FontStyleOption, FontWeightOption, InputHudModeOption,
     InputHudPositionOption, PdfFitModeOption, PdfLabelContentModeOption, PdfLabelPositionOption,
-    PdfOrientationOption, PdfPageSizeOption, PdfTransparentBackgroundOption,
+    PdfOrientationOption, PdfPageSizeOption, PdfTransparentBackgroundOption, PngCompressionOption,
     PresenterToolBehaviorOption, PresenterToolbarModeOption, ReducedMotionOption,
     SessionCompressionOption, SessionStorageModeOption, StatusPositionOption, ToolOption,
     ToolbarLayoutModeOption, ToolbarRebindModifierOption, UiThemeOption,
@@ -180,7 +180,13 @@ pub struct ConfigDraft {
     pub capture_save_directory: String,
     pub capture_filename_template: String,
     pub capture_format: String,
+    pub capture_canvas_export_format: String,
+    pub capture_png_compression: PngCompressionOption,
+    pub capture_jpeg_quality: String,
+    pub capture_webp_near_lossless: bool,
+    pub capture_webp_near_lossless_level: String,
     pub capture_copy_to_clipboard: bool,
+    pub capture_copy_selection_backdrop: bool,
     pub capture_exit_after: bool,
     pub capture_ocr_languages: String,
     pub export_pdf_filename_template: String,
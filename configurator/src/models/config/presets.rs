@@ -9,6 +9,7 @@ use wayscriber::config::{
     Config, MouseDragToolsConfig, PRESET_SLOTS_MAX, PRESET_SLOTS_MIN, PresetSlotsConfig,
     PresetToolStatesConfig, ToolPresetConfig,
 };
+use wayscriber::draw::LineStyle;
 use wayscriber::input::Tool;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -28,6 +29,7 @@ pub struct PresetSlotDraft {
     pub arrow_angle: String,
     pub arrow_head_at_end: OverrideOption,
     pub polygon_sides: Option<u8>,
+    pub line_style: Option<LineStyle>,
     pub show_status_bar: OverrideOption,
     pub drag_tools: Option<MouseDragToolsConfig>,
     pub tool_settings: Option<PresetToolStatesConfig>,
@@ -54,6 +56,7 @@ impl PresetSlotDraft {
                 arrow_angle: preset.arrow_angle.map(format_float).unwrap_or_default(),
                 arrow_head_at_end: OverrideOption::from_option(preset.arrow_head_at_end),
                 polygon_sides: preset.polygon_sides,
+                line_style: preset.line_style,
                 show_status_bar: OverrideOption::from_option(preset.show_status_bar),
                 drag_tools: preset.drag_tools.clone(),
                 tool_settings: preset.tool_settings.clone(),
@@ -83,6 +86,7 @@ impl PresetSlotDraft {
             arrow_angle: String::new(),
             arrow_head_at_end: OverrideOption::Default,
             polygon_sides: None,
+            line_style: None,
             show_status_bar: OverrideOption::Default,
             drag_tools: None,
             tool_settings: None,
@@ -180,6 +184,7 @@ impl PresetSlotDraft {
             arrow_angle,
             arrow_head_at_end: self.arrow_head_at_end.to_option(),
             polygon_sides: self.polygon_sides,
+            line_style: self.line_style,
             show_status_bar: self.show_status_bar.to_option(),
             drag_tools: self.drag_tools.clone(),
         })
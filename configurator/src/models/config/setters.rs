@@ -257,7 +257,11 @@ impl ConfigDraft {
             }
             ToggleField::CaptureEnabled => self.capture_enabled = value,
             ToggleField::CaptureCopyToClipboard => self.capture_copy_to_clipboard = value,
+            ToggleField::CaptureCopySelectionBackdrop => {
+                self.capture_copy_selection_backdrop = value;
+            }
             ToggleField::CaptureExitAfter => self.capture_exit_after = value,
+            ToggleField::CaptureWebpNearLossless => self.capture_webp_near_lossless = value,
             ToggleField::ExportPdfLabelsEnabled => self.export_pdf_labels_enabled = value,
             ToggleField::ExportPdfLabelBackgroundEnabled => {
                 self.export_pdf_label_background_enabled = value;
@@ -368,6 +372,11 @@ impl ConfigDraft {
             TextField::CaptureSaveDirectory => self.capture_save_directory = value,
             TextField::CaptureFilename => self.capture_filename_template = value,
             TextField::CaptureFormat => self.capture_format = value,
+            TextField::CaptureCanvasExportFormat => self.capture_canvas_export_format = value,
+            TextField::CaptureJpegQuality => self.capture_jpeg_quality = value,
+            TextField::CaptureWebpNearLosslessLevel => {
+                self.capture_webp_near_lossless_level = value
+            }
             TextField::CaptureOcrLanguages => self.capture_ocr_languages = value,
             TextField::ExportPdfFilenameTemplate => self.export_pdf_filename_template = value,
             TextField::ExportPdfAllBoardsFilenameTemplate => {
@@ -2,8 +2,8 @@ use super::super::color::ColorInput;
 use super::super::fields::{
     DragMouseButton, DragToolField, DragToolOption, FontWeightOption, InputHudModeOption,
     InputHudPositionOption, OverrideOption, PdfFitModeOption, PdfLabelContentModeOption,
-    PdfOrientationOption, PdfPageSizeOption, PdfTransparentBackgroundOption, QuadField,
-    ReducedMotionOption, SessionStorageModeOption, TextField, ToggleField, ToolOption,
+    PdfOrientationOption, PdfPageSizeOption, PdfTransparentBackgroundOption, PngCompressionOption,
+    QuadField, ReducedMotionOption, SessionStorageModeOption, TextField, ToggleField, ToolOption,
     ToolbarLayoutModeOption, ToolbarOverrideField, ToolbarRebindModifierOption, UiThemeOption,
 };
 
@@ -60,11 +60,12 @@ use super::{ConfigDraft, RenderProfileSelectionOption};
 use wayscriber::config::{
     ColorSpec, Config, ConfigDocument, InputHudMode, InputHudPosition, PdfFitMode,
     PdfLabelContentMode, PdfLabelPosition, PdfOrientation, PdfPageSize, PdfTransparentBackground,
-    PresetToolStatesConfig, QuickColorConfig, ReducedMotion, RenderColorMappingConfig,
-    RenderProfileConfig, RenderProfileExportMode, ToolPresetConfig, ToolbarItemOrderConfig,
-    ToolbarItemOrderGroup, ToolbarItemsConfig, ToolbarSectionFlag, UiTheme, XdgFocusLossBehavior,
-    toolbar_item_ids as ids,
+    PngCompression, PresetToolStatesConfig, QuickColorConfig, ReducedMotion,
+    RenderColorMappingConfig, RenderProfileConfig, RenderProfileExportMode, ToolPresetConfig,
+    ToolbarItemOrderConfig, ToolbarItemOrderGroup, ToolbarItemsConfig, ToolbarSectionFlag, UiTheme,
+    XdgFocusLossBehavior, toolbar_item_ids as ids,
 };
+use wayscriber::draw::LineStyle;
 use wayscriber::input::{DragTool, PerToolDrawingSettings, Tool};
 
 #[test]
@@ -123,6 +124,36 @@ fn config_draft_rejects_path_escaping_save_names() {
     assert!(fields.contains(&"export.pdf.filename_template"));
 }
 
+#[test]
+fn config_draft_applies_capture_encoding_settings() {
+    let mut draft = ConfigDraft::from_config(&Config::default());
+    draft.capture_canvas_export_format = "WebP".to_string();
+    draft.capture_png_compression = PngCompressionOption::Best;
+    draft.capture_jpeg_quality = "75".to_string();
+    draft.capture_webp_near_lossless = true;
+    draft.capture_webp_near_lossless_level = "60".to_string();
+
+    let config = draft
+        .to_config(&Config::default())
+        .expect("valid encoding settings convert");
+    assert_eq!(config.capture.canvas_export_format, "webp");
+    assert_eq!(config.capture.png_compression, PngCompression::Best);
+    assert_eq!(config.capture.jpeg_quality, 75);
+    assert!(config.capture.webp_near_lossless);
+    assert_eq!(config.capture.webp_near_lossless_level, 60);
+
+    draft.capture_canvas_export_format = "gif".to_string();
+    draft.capture_jpeg_quality = "0".to_string();
+    draft.capture_webp_near_lossless_level = "101".to_string();
+    let errors = draft
+        .to_config(&Config::default())
+        .expect_err("unsupported formats and qualities must not save");
+    let fields: Vec<&str> = errors.iter().map(|err| err.field.as_str()).collect();
+    assert!(fields.contains(&"capture.canvas_export_format"));
+    assert!(fields.contains(&"capture.jpeg_quality"));
+    assert!(fields.contains(&"capture.webp_near_lossless_level"));
+}
+
 #[test]
 fn sparse_configurator_no_op_save_remains_byte_for_byte_sparse() {
     let temp = crate::test_temp::tempdir().expect("create temp directory");
@@ -1077,6 +1108,7 @@ fn config_draft_round_trips_presets_and_history() {
         arrow_angle: Some(30.0),
         arrow_head_at_end: Some(true),
         polygon_sides: Some(7),
+        line_style: Some(LineStyle::Dashed),
         show_status_bar: Some(false),
         drag_tools: None,
     };
@@ -1146,6 +1178,7 @@ fn preset_tool_change_loads_selected_tool_profile_values() {
             arrow_angle: None,
             arrow_head_at_end: None,
             polygon_sides: None,
+            line_style: None,
             show_status_bar: None,
             drag_tools: None,
         }),
@@ -1204,6 +1237,7 @@ fn preset_visible_edits_update_selected_tool_profile_only() {
             arrow_angle: None,
             arrow_head_at_end: None,
             polygon_sides: None,
+            line_style: None,
             show_status_bar: None,
             drag_tools: None,
         }),
@@ -1,4 +1,5 @@
 use super::super::draft::ConfigDraft;
+use super::super::parse::parse_u8_in_range;
 use crate::models::error::FormError;
 use wayscriber::config::{
     Config, validate_capture_format, validate_filename_template, validate_ocr_languages,
@@ -22,7 +23,33 @@ impl ConfigDraft {
                 format!("Image format: {reason}."),
             )),
         }
+        match validate_capture_format(&self.capture_canvas_export_format) {
+            Ok(format) => config.capture.canvas_export_format = format,
+            Err(reason) => errors.push(FormError::new(
+                "capture.canvas_export_format",
+                format!("Canvas export format: {reason}."),
+            )),
+        }
+        config.capture.png_compression = self.capture_png_compression.to_config();
+        parse_u8_in_range(
+            &self.capture_jpeg_quality,
+            "capture.jpeg_quality",
+            1,
+            100,
+            errors,
+            |value| config.capture.jpeg_quality = value,
+        );
+        config.capture.webp_near_lossless = self.capture_webp_near_lossless;
+        parse_u8_in_range(
+            &self.capture_webp_near_lossless_level,
+            "capture.webp_near_lossless_level",
+            1,
+            100,
+            errors,
+            |value| config.capture.webp_near_lossless_level = value,
+        );
         config.capture.copy_to_clipboard = self.capture_copy_to_clipboard;
+        config.capture.copy_selection_backdrop = self.capture_copy_selection_backdrop;
         config.capture.exit_after_capture = self.capture_exit_after;
         match validate_ocr_languages(&self.capture_ocr_languages) {
             Ok(languages) => config.capture.ocr_languages = languages,
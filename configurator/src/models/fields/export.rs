@@ -1,6 +1,6 @@
 use wayscriber::config::{
     PdfFitMode, PdfLabelContentMode, PdfLabelPosition, PdfOrientation, PdfPageSize,
-    PdfTransparentBackground,
+    PdfTransparentBackground, PngCompression,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -292,3 +292,46 @@ impl std::fmt::Display for PdfLabelContentModeOption {
         f.write_str(self.label())
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PngCompressionOption {
+    Default,
+    Fast,
+    Best,
+}
+
+impl PngCompressionOption {
+    pub fn list() -> Vec<Self> {
+        vec![Self::Default, Self::Fast, Self::Best]
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Default => "As rendered",
+            Self::Fast => "Fast",
+            Self::Best => "Smallest file",
+        }
+    }
+
+    pub fn to_config(self) -> PngCompression {
+        match self {
+            Self::Default => PngCompression::Default,
+            Self::Fast => PngCompression::Fast,
+            Self::Best => PngCompression::Best,
+        }
+    }
+
+    pub fn from_config(value: PngCompression) -> Self {
+        match value {
+            PngCompression::Default => Self::Default,
+            PngCompression::Fast => Self::Fast,
+            PngCompression::Best => Self::Best,
+        }
+    }
+}
+
+impl std::fmt::Display for PngCompressionOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
@@ -15,7 +15,7 @@ mod toolbar;
 pub use eraser::{EraserModeOption, PresetEraserKindOption, PresetEraserModeOption};
 pub use export::{
     PdfFitModeOption, PdfLabelContentModeOption, PdfLabelPositionOption, PdfOrientationOption,
-    PdfPageSizeOption, PdfTransparentBackgroundOption,
+    PdfPageSizeOption, PdfTransparentBackgroundOption, PngCompressionOption,
 };
 pub use font::{FontStyleOption, FontWeightOption};
 pub use input_hud::{InputHudModeOption, InputHudPositionOption};
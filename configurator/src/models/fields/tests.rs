@@ -1,8 +1,8 @@
 use super::*;
 use wayscriber::config::{
     PdfFitMode, PdfLabelContentMode, PdfLabelPosition, PdfOrientation, PdfPageSize,
-    PdfTransparentBackground, ReducedMotion, SessionCompression, SessionStorageMode,
-    StatusPosition, UiTheme,
+    PdfTransparentBackground, PngCompression, ReducedMotion, SessionCompression,
+    SessionStorageMode, StatusPosition, UiTheme,
 };
 
 #[test]
@@ -104,6 +104,10 @@ fn pdf_export_options_round_trip() {
         PdfLabelContentModeOption::from_config(PdfLabelContentMode::DocumentPage).to_config(),
         PdfLabelContentMode::DocumentPage
     );
+    assert_eq!(
+        PngCompressionOption::from_config(PngCompression::Best).to_config(),
+        PngCompression::Best
+    );
 }
 
 #[test]
@@ -63,7 +63,9 @@ pub enum ToggleField {
     BoardsPersistCustomizations,
     CaptureEnabled,
     CaptureCopyToClipboard,
+    CaptureCopySelectionBackdrop,
     CaptureExitAfter,
+    CaptureWebpNearLossless,
     ExportPdfLabelsEnabled,
     ExportPdfLabelBackgroundEnabled,
     SessionPersistTransparent,
@@ -138,6 +140,9 @@ pub enum TextField {
     CaptureSaveDirectory,
     CaptureFilename,
     CaptureFormat,
+    CaptureCanvasExportFormat,
+    CaptureJpegQuality,
+    CaptureWebpNearLosslessLevel,
     CaptureOcrLanguages,
     ExportPdfFilenameTemplate,
     ExportPdfAllBoardsFilenameTemplate,
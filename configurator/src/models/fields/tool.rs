@@ -57,6 +57,7 @@ pub enum ToolOption {
     Marker,
     StepMarker,
     Highlight,
+    Laser,
     Eraser,
 }
 
@@ -79,6 +80,7 @@ impl ToolOption {
             Self::Marker,
             Self::StepMarker,
             Self::Highlight,
+            Self::Laser,
             Self::Eraser,
         ]
     }
@@ -101,6 +103,7 @@ impl ToolOption {
             Self::Marker => "Marker",
             Self::StepMarker => "Step",
             Self::Highlight => "Highlight",
+            Self::Laser => "Laser pointer",
             Self::Eraser => "Eraser",
         }
     }
@@ -123,6 +126,7 @@ impl ToolOption {
             Self::Marker => Tool::Marker,
             Self::StepMarker => Tool::StepMarker,
             Self::Highlight => Tool::Highlight,
+            Self::Laser => Tool::Laser,
             Self::Eraser => Tool::Eraser,
         }
     }
@@ -145,6 +149,7 @@ impl ToolOption {
             Tool::Marker => Self::Marker,
             Tool::StepMarker => Self::StepMarker,
             Tool::Highlight => Self::Highlight,
+            Tool::Laser => Self::Laser,
             Tool::Eraser => Self::Eraser,
         }
     }
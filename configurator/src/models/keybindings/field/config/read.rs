@@ -12,6 +12,7 @@ impl KeybindingField {
             Self::EnterTextMode => &config.core.enter_text_mode,
             Self::EnterStickyNoteMode => &config.core.enter_sticky_note_mode,
             Self::ClearCanvas => &config.core.clear_canvas,
+            Self::ClearActiveLayer => &config.core.clear_active_layer,
             Self::Undo => &config.core.undo,
             Self::Redo => &config.core.redo,
             Self::UndoAll => &config.core.undo_all,
@@ -24,6 +25,10 @@ impl KeybindingField {
             Self::SelectAll => &config.selection.select_all,
             Self::MoveSelectionToFront => &config.selection.move_selection_to_front,
             Self::MoveSelectionToBack => &config.selection.move_selection_to_back,
+            Self::MoveSelectionToLayerAbove => &config.selection.move_selection_to_layer_above,
+            Self::MoveSelectionToLayerBelow => &config.selection.move_selection_to_layer_below,
+            Self::GroupSelection => &config.selection.group_selection,
+            Self::UngroupSelection => &config.selection.ungroup_selection,
             Self::NudgeSelectionUp => &config.selection.nudge_selection_up,
             Self::NudgeSelectionDown => &config.selection.nudge_selection_down,
             Self::NudgeSelectionLeft => &config.selection.nudge_selection_left,
@@ -34,6 +39,18 @@ impl KeybindingField {
             Self::MoveSelectionToEnd => &config.selection.move_selection_to_end,
             Self::MoveSelectionToTop => &config.selection.move_selection_to_top,
             Self::MoveSelectionToBottom => &config.selection.move_selection_to_bottom,
+            Self::AlignSelectionLeft => &config.selection.align_selection_left,
+            Self::AlignSelectionCenter => &config.selection.align_selection_center,
+            Self::AlignSelectionRight => &config.selection.align_selection_right,
+            Self::AlignSelectionTop => &config.selection.align_selection_top,
+            Self::AlignSelectionMiddle => &config.selection.align_selection_middle,
+            Self::AlignSelectionBottom => &config.selection.align_selection_bottom,
+            Self::DistributeSelectionHorizontally => {
+                &config.selection.distribute_selection_horizontally
+            }
+            Self::DistributeSelectionVertically => {
+                &config.selection.distribute_selection_vertically
+            }
             Self::DeleteSelection => &config.selection.delete_selection,
             Self::IncreaseThickness => &config.tools.increase_thickness,
             Self::DecreaseThickness => &config.tools.decrease_thickness,
@@ -57,6 +74,7 @@ impl KeybindingField {
             Self::SelectBlurTool => &config.tools.select_blur_tool,
             Self::SelectSpotlightTool => &config.tools.select_spotlight_tool,
             Self::CycleBlurStyle => &config.tools.cycle_blur_style,
+            Self::CycleLineStyle => &config.tools.cycle_line_style,
             Self::SelectHighlightTool => &config.tools.select_highlight_tool,
             Self::IncreaseFontSize => &config.tools.increase_font_size,
             Self::DecreaseFontSize => &config.tools.decrease_font_size,
@@ -68,6 +86,8 @@ impl KeybindingField {
             Self::PageNew => &config.board.page_new,
             Self::PageDuplicate => &config.board.page_duplicate,
             Self::PageDelete => &config.board.page_delete,
+            Self::ToggleGrid => &config.board.toggle_grid,
+            Self::ToggleSnapToGrid => &config.board.toggle_snap_to_grid,
             Self::Board1 => &config.board.board_1,
             Self::Board2 => &config.board.board_2,
             Self::Board3 => &config.board.board_3,
@@ -99,11 +119,22 @@ impl KeybindingField {
             Self::ToggleToolbar => &config.ui.toggle_toolbar,
             Self::CycleToolbarDisplay => &config.ui.cycle_toolbar_display,
             Self::TogglePresenterMode => &config.ui.toggle_presenter_mode,
+            Self::ToggleReplay => &config.ui.toggle_replay,
+            Self::StartBreakTimer => &config.ui.start_break_timer,
+            Self::StartTalkTimer => &config.ui.start_talk_timer,
+            Self::StartStopwatch => &config.ui.start_stopwatch,
+            Self::ToggleTimerPause => &config.ui.toggle_timer_pause,
+            Self::ResetTimer => &config.ui.reset_timer,
+            Self::TimerAddMinute => &config.ui.timer_add_minute,
+            Self::ToggleTimerDisplay => &config.ui.toggle_timer_display,
             Self::RenderProfileNext => &config.ui.render_profile_next,
             Self::RenderProfilePrevious => &config.ui.render_profile_previous,
             Self::RenderProfileOff => &config.ui.render_profile_off,
             Self::ToggleFill => &config.ui.toggle_fill,
+            Self::ToggleConnectorMode => &config.ui.toggle_connector_mode,
             Self::ToggleHighlightTool => &config.tools.toggle_highlight_tool,
+            Self::SelectLaserTool => &config.tools.select_laser_tool,
+            Self::ToggleFadeInk => &config.tools.toggle_fade_ink,
             Self::ToggleSelectionProperties => &config.ui.toggle_selection_properties,
             Self::OpenContextMenu => &config.ui.open_context_menu,
             Self::OpenConfigurator => &config.ui.open_configurator,
@@ -132,6 +163,10 @@ impl KeybindingField {
             Self::ExportCanvasClipboardAndFile => &config.capture.export_canvas_clipboard_and_file,
             Self::ExportBoardPdfFile => &config.capture.export_board_pdf_file,
             Self::ExportAllBoardsPdfFile => &config.capture.export_all_boards_pdf_file,
+            Self::ExportCanvasSvgFile => &config.capture.export_canvas_svg_file,
+            Self::ExportBoardSvgFile => &config.capture.export_board_svg_file,
+            Self::ExportReplayFrames => &config.capture.export_replay_frames,
+            Self::ToggleRecording => &config.capture.toggle_recording,
             Self::OpenCaptureFolder => &config.capture.open_capture_folder,
             Self::CopyTextFromScreen => &config.capture.copy_text_from_screen,
             Self::ToggleFrozenMode => &config.zoom.toggle_frozen_mode,
@@ -140,6 +175,9 @@ impl KeybindingField {
             Self::ResetZoom => &config.zoom.reset_zoom,
             Self::ToggleZoomLock => &config.zoom.toggle_zoom_lock,
             Self::RefreshZoomCapture => &config.zoom.refresh_zoom_capture,
+            Self::ToggleMagnifier => &config.zoom.toggle_magnifier,
+            Self::MagnifierZoomIn => &config.zoom.magnifier_zoom_in,
+            Self::MagnifierZoomOut => &config.zoom.magnifier_zoom_out,
             Self::ResetArrowLabels => &config.tools.reset_arrow_labels,
             Self::ResetStepMarkers => &config.tools.reset_step_markers,
             Self::ApplyPreset1 => &config.presets.apply_preset_1,
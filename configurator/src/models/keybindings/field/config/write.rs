@@ -13,6 +13,7 @@ impl KeybindingField {
             Self::EnterTextMode => config.core.enter_text_mode = value,
             Self::EnterStickyNoteMode => config.core.enter_sticky_note_mode = value,
             Self::ClearCanvas => config.core.clear_canvas = value,
+            Self::ClearActiveLayer => config.core.clear_active_layer = value,
             Self::Undo => config.core.undo = value,
             Self::Redo => config.core.redo = value,
             Self::UndoAll => config.core.undo_all = value,
@@ -25,6 +26,14 @@ impl KeybindingField {
             Self::SelectAll => config.selection.select_all = value,
             Self::MoveSelectionToFront => config.selection.move_selection_to_front = value,
             Self::MoveSelectionToBack => config.selection.move_selection_to_back = value,
+            Self::MoveSelectionToLayerAbove => {
+                config.selection.move_selection_to_layer_above = value
+            }
+            Self::MoveSelectionToLayerBelow => {
+                config.selection.move_selection_to_layer_below = value
+            }
+            Self::GroupSelection => config.selection.group_selection = value,
+            Self::UngroupSelection => config.selection.ungroup_selection = value,
             Self::NudgeSelectionUp => config.selection.nudge_selection_up = value,
             Self::NudgeSelectionDown => config.selection.nudge_selection_down = value,
             Self::NudgeSelectionLeft => config.selection.nudge_selection_left = value,
@@ -35,6 +44,18 @@ impl KeybindingField {
             Self::MoveSelectionToEnd => config.selection.move_selection_to_end = value,
             Self::MoveSelectionToTop => config.selection.move_selection_to_top = value,
             Self::MoveSelectionToBottom => config.selection.move_selection_to_bottom = value,
+            Self::AlignSelectionLeft => config.selection.align_selection_left = value,
+            Self::AlignSelectionCenter => config.selection.align_selection_center = value,
+            Self::AlignSelectionRight => config.selection.align_selection_right = value,
+            Self::AlignSelectionTop => config.selection.align_selection_top = value,
+            Self::AlignSelectionMiddle => config.selection.align_selection_middle = value,
+            Self::AlignSelectionBottom => config.selection.align_selection_bottom = value,
+            Self::DistributeSelectionHorizontally => {
+                config.selection.distribute_selection_horizontally = value
+            }
+            Self::DistributeSelectionVertically => {
+                config.selection.distribute_selection_vertically = value
+            }
             Self::DeleteSelection => config.selection.delete_selection = value,
             Self::IncreaseThickness => config.tools.increase_thickness = value,
             Self::DecreaseThickness => config.tools.decrease_thickness = value,
@@ -58,6 +79,7 @@ impl KeybindingField {
             Self::SelectBlurTool => config.tools.select_blur_tool = value,
             Self::SelectSpotlightTool => config.tools.select_spotlight_tool = value,
             Self::CycleBlurStyle => config.tools.cycle_blur_style = value,
+            Self::CycleLineStyle => config.tools.cycle_line_style = value,
             Self::SelectHighlightTool => config.tools.select_highlight_tool = value,
             Self::IncreaseFontSize => config.tools.increase_font_size = value,
             Self::DecreaseFontSize => config.tools.decrease_font_size = value,
@@ -69,6 +91,8 @@ impl KeybindingField {
             Self::PageNew => config.board.page_new = value,
             Self::PageDuplicate => config.board.page_duplicate = value,
             Self::PageDelete => config.board.page_delete = value,
+            Self::ToggleGrid => config.board.toggle_grid = value,
+            Self::ToggleSnapToGrid => config.board.toggle_snap_to_grid = value,
             Self::Board1 => config.board.board_1 = value,
             Self::Board2 => config.board.board_2 = value,
             Self::Board3 => config.board.board_3 = value,
@@ -100,11 +124,22 @@ impl KeybindingField {
             Self::ToggleToolbar => config.ui.toggle_toolbar = value,
             Self::CycleToolbarDisplay => config.ui.cycle_toolbar_display = value,
             Self::TogglePresenterMode => config.ui.toggle_presenter_mode = value,
+            Self::ToggleReplay => config.ui.toggle_replay = value,
+            Self::StartBreakTimer => config.ui.start_break_timer = value,
+            Self::StartTalkTimer => config.ui.start_talk_timer = value,
+            Self::StartStopwatch => config.ui.start_stopwatch = value,
+            Self::ToggleTimerPause => config.ui.toggle_timer_pause = value,
+            Self::ResetTimer => config.ui.reset_timer = value,
+            Self::TimerAddMinute => config.ui.timer_add_minute = value,
+            Self::ToggleTimerDisplay => config.ui.toggle_timer_display = value,
             Self::RenderProfileNext => config.ui.render_profile_next = value,
             Self::RenderProfilePrevious => config.ui.render_profile_previous = value,
             Self::RenderProfileOff => config.ui.render_profile_off = value,
             Self::ToggleFill => config.ui.toggle_fill = value,
+            Self::ToggleConnectorMode => config.ui.toggle_connector_mode = value,
             Self::ToggleHighlightTool => config.tools.toggle_highlight_tool = value,
+            Self::SelectLaserTool => config.tools.select_laser_tool = value,
+            Self::ToggleFadeInk => config.tools.toggle_fade_ink = value,
             Self::ToggleSelectionProperties => config.ui.toggle_selection_properties = value,
             Self::OpenContextMenu => config.ui.open_context_menu = value,
             Self::OpenConfigurator => config.ui.open_configurator = value,
@@ -135,6 +170,10 @@ impl KeybindingField {
             }
             Self::ExportBoardPdfFile => config.capture.export_board_pdf_file = value,
             Self::ExportAllBoardsPdfFile => config.capture.export_all_boards_pdf_file = value,
+            Self::ExportCanvasSvgFile => config.capture.export_canvas_svg_file = value,
+            Self::ExportBoardSvgFile => config.capture.export_board_svg_file = value,
+            Self::ExportReplayFrames => config.capture.export_replay_frames = value,
+            Self::ToggleRecording => config.capture.toggle_recording = value,
             Self::OpenCaptureFolder => config.capture.open_capture_folder = value,
             Self::CopyTextFromScreen => config.capture.copy_text_from_screen = value,
             Self::ToggleFrozenMode => config.zoom.toggle_frozen_mode = value,
@@ -143,6 +182,9 @@ impl KeybindingField {
             Self::ResetZoom => config.zoom.reset_zoom = value,
             Self::ToggleZoomLock => config.zoom.toggle_zoom_lock = value,
             Self::RefreshZoomCapture => config.zoom.refresh_zoom_capture = value,
+            Self::ToggleMagnifier => config.zoom.toggle_magnifier = value,
+            Self::MagnifierZoomIn => config.zoom.magnifier_zoom_in = value,
+            Self::MagnifierZoomOut => config.zoom.magnifier_zoom_out = value,
             Self::ResetArrowLabels => config.tools.reset_arrow_labels = value,
             Self::ResetStepMarkers => config.tools.reset_step_markers = value,
             Self::ApplyPreset1 => config.presets.apply_preset_1 = value,
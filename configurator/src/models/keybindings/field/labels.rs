@@ -22,6 +22,7 @@ impl KeybindingField {
             Self::EnterTextMode => "enter_text_mode",
             Self::EnterStickyNoteMode => "enter_sticky_note_mode",
             Self::ClearCanvas => "clear_canvas",
+            Self::ClearActiveLayer => "clear_active_layer",
             Self::Undo => "undo",
             Self::Redo => "redo",
             Self::UndoAll => "undo_all",
@@ -34,6 +35,10 @@ impl KeybindingField {
             Self::SelectAll => "select_all",
             Self::MoveSelectionToFront => "move_selection_to_front",
             Self::MoveSelectionToBack => "move_selection_to_back",
+            Self::MoveSelectionToLayerAbove => "move_selection_to_layer_above",
+            Self::MoveSelectionToLayerBelow => "move_selection_to_layer_below",
+            Self::GroupSelection => "group_selection",
+            Self::UngroupSelection => "ungroup_selection",
             Self::NudgeSelectionUp => "nudge_selection_up",
             Self::NudgeSelectionDown => "nudge_selection_down",
             Self::NudgeSelectionLeft => "nudge_selection_left",
@@ -44,6 +49,14 @@ impl KeybindingField {
             Self::MoveSelectionToEnd => "move_selection_to_end",
             Self::MoveSelectionToTop => "move_selection_to_top",
             Self::MoveSelectionToBottom => "move_selection_to_bottom",
+            Self::AlignSelectionLeft => "align_selection_left",
+            Self::AlignSelectionCenter => "align_selection_center",
+            Self::AlignSelectionRight => "align_selection_right",
+            Self::AlignSelectionTop => "align_selection_top",
+            Self::AlignSelectionMiddle => "align_selection_middle",
+            Self::AlignSelectionBottom => "align_selection_bottom",
+            Self::DistributeSelectionHorizontally => "distribute_selection_horizontally",
+            Self::DistributeSelectionVertically => "distribute_selection_vertically",
             Self::DeleteSelection => "delete_selection",
             Self::IncreaseThickness => "increase_thickness",
             Self::DecreaseThickness => "decrease_thickness",
@@ -67,6 +80,7 @@ impl KeybindingField {
             Self::SelectBlurTool => "select_blur_tool",
             Self::SelectSpotlightTool => "select_spotlight_tool",
             Self::CycleBlurStyle => "cycle_blur_style",
+            Self::CycleLineStyle => "cycle_line_style",
             Self::SelectHighlightTool => "select_highlight_tool",
             Self::IncreaseFontSize => "increase_font_size",
             Self::DecreaseFontSize => "decrease_font_size",
@@ -78,6 +92,8 @@ impl KeybindingField {
             Self::PageNew => "page_new",
             Self::PageDuplicate => "page_duplicate",
             Self::PageDelete => "page_delete",
+            Self::ToggleGrid => "toggle_grid",
+            Self::ToggleSnapToGrid => "toggle_snap_to_grid",
             Self::Board1 => "board_1",
             Self::Board2 => "board_2",
             Self::Board3 => "board_3",
@@ -109,11 +125,22 @@ impl KeybindingField {
             Self::ToggleToolbar => "toggle_toolbar",
             Self::CycleToolbarDisplay => "cycle_toolbar_display",
             Self::TogglePresenterMode => "toggle_presenter_mode",
+            Self::ToggleReplay => "toggle_replay",
+            Self::StartBreakTimer => "start_break_timer",
+            Self::StartTalkTimer => "start_talk_timer",
+            Self::StartStopwatch => "start_stopwatch",
+            Self::ToggleTimerPause => "toggle_timer_pause",
+            Self::ResetTimer => "reset_timer",
+            Self::TimerAddMinute => "timer_add_minute",
+            Self::ToggleTimerDisplay => "toggle_timer_display",
             Self::RenderProfileNext => "render_profile_next",
             Self::RenderProfilePrevious => "render_profile_previous",
             Self::RenderProfileOff => "render_profile_off",
             Self::ToggleFill => "toggle_fill",
+            Self::ToggleConnectorMode => "toggle_connector_mode",
             Self::ToggleHighlightTool => "toggle_highlight_tool",
+            Self::SelectLaserTool => "select_laser_tool",
+            Self::ToggleFadeInk => "toggle_fade_ink",
             Self::ToggleSelectionProperties => "toggle_selection_properties",
             Self::OpenContextMenu => "open_context_menu",
             Self::OpenConfigurator => "open_configurator",
@@ -142,6 +169,10 @@ impl KeybindingField {
             Self::ExportCanvasClipboardAndFile => "export_canvas_clipboard_and_file",
             Self::ExportBoardPdfFile => "export_board_pdf_file",
             Self::ExportAllBoardsPdfFile => "export_all_boards_pdf_file",
+            Self::ExportCanvasSvgFile => "export_canvas_svg_file",
+            Self::ExportBoardSvgFile => "export_board_svg_file",
+            Self::ExportReplayFrames => "export_replay_frames",
+            Self::ToggleRecording => "toggle_recording",
             Self::OpenCaptureFolder => "open_capture_folder",
             Self::CopyTextFromScreen => "copy_text_from_screen",
             Self::ToggleFrozenMode => "toggle_frozen_mode",
@@ -150,6 +181,9 @@ impl KeybindingField {
             Self::ResetZoom => "reset_zoom",
             Self::ToggleZoomLock => "toggle_zoom_lock",
             Self::RefreshZoomCapture => "refresh_zoom_capture",
+            Self::ToggleMagnifier => "toggle_magnifier",
+            Self::MagnifierZoomIn => "magnifier_zoom_in",
+            Self::MagnifierZoomOut => "magnifier_zoom_out",
             Self::ResetArrowLabels => "reset_arrow_labels",
             Self::ResetStepMarkers => "reset_step_markers",
             Self::ApplyPreset1 => "apply_preset_1",
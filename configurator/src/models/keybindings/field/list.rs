@@ -7,6 +7,7 @@ impl KeybindingField {
             Self::EnterTextMode,
             Self::EnterStickyNoteMode,
             Self::ClearCanvas,
+            Self::ClearActiveLayer,
             Self::Undo,
             Self::Redo,
             Self::UndoAll,
@@ -19,6 +20,10 @@ impl KeybindingField {
             Self::SelectAll,
             Self::MoveSelectionToFront,
             Self::MoveSelectionToBack,
+            Self::MoveSelectionToLayerAbove,
+            Self::MoveSelectionToLayerBelow,
+            Self::GroupSelection,
+            Self::UngroupSelection,
             Self::NudgeSelectionUp,
             Self::NudgeSelectionDown,
             Self::NudgeSelectionLeft,
@@ -29,6 +34,14 @@ impl KeybindingField {
             Self::MoveSelectionToEnd,
             Self::MoveSelectionToTop,
             Self::MoveSelectionToBottom,
+            Self::AlignSelectionLeft,
+            Self::AlignSelectionCenter,
+            Self::AlignSelectionRight,
+            Self::AlignSelectionTop,
+            Self::AlignSelectionMiddle,
+            Self::AlignSelectionBottom,
+            Self::DistributeSelectionHorizontally,
+            Self::DistributeSelectionVertically,
             Self::DeleteSelection,
             Self::IncreaseThickness,
             Self::DecreaseThickness,
@@ -52,6 +65,7 @@ impl KeybindingField {
             Self::SelectBlurTool,
             Self::SelectSpotlightTool,
             Self::CycleBlurStyle,
+            Self::CycleLineStyle,
             Self::SelectHighlightTool,
             Self::IncreaseFontSize,
             Self::DecreaseFontSize,
@@ -63,6 +77,8 @@ impl KeybindingField {
             Self::PageNew,
             Self::PageDuplicate,
             Self::PageDelete,
+            Self::ToggleGrid,
+            Self::ToggleSnapToGrid,
             Self::Board1,
             Self::Board2,
             Self::Board3,
@@ -94,11 +110,22 @@ impl KeybindingField {
             Self::ToggleRadialMenu,
             Self::CycleToolbarDisplay,
             Self::TogglePresenterMode,
+            Self::ToggleReplay,
+            Self::StartBreakTimer,
+            Self::StartTalkTimer,
+            Self::StartStopwatch,
+            Self::ToggleTimerPause,
+            Self::ResetTimer,
+            Self::TimerAddMinute,
+            Self::ToggleTimerDisplay,
             Self::RenderProfileNext,
             Self::RenderProfilePrevious,
             Self::RenderProfileOff,
             Self::ToggleFill,
+            Self::ToggleConnectorMode,
             Self::ToggleHighlightTool,
+            Self::SelectLaserTool,
+            Self::ToggleFadeInk,
             Self::ToggleSelectionProperties,
             Self::OpenContextMenu,
             Self::OpenConfigurator,
@@ -127,6 +154,10 @@ impl KeybindingField {
             Self::ExportCanvasClipboardAndFile,
             Self::ExportBoardPdfFile,
             Self::ExportAllBoardsPdfFile,
+            Self::ExportCanvasSvgFile,
+            Self::ExportBoardSvgFile,
+            Self::ExportReplayFrames,
+            Self::ToggleRecording,
             Self::OpenCaptureFolder,
             Self::CopyTextFromScreen,
             Self::ToggleFrozenMode,
@@ -135,6 +166,9 @@ impl KeybindingField {
             Self::ResetZoom,
             Self::ToggleZoomLock,
             Self::RefreshZoomCapture,
+            Self::ToggleMagnifier,
+            Self::MagnifierZoomIn,
+            Self::MagnifierZoomOut,
             Self::ResetArrowLabels,
             Self::ResetStepMarkers,
             Self::ApplyPreset1,
@@ -9,6 +9,7 @@ pub enum KeybindingField {
     EnterTextMode,
     EnterStickyNoteMode,
     ClearCanvas,
+    ClearActiveLayer,
     Undo,
     Redo,
     UndoAll,
@@ -21,6 +22,10 @@ pub enum KeybindingField {
     SelectAll,
     MoveSelectionToFront,
     MoveSelectionToBack,
+    MoveSelectionToLayerAbove,
+    MoveSelectionToLayerBelow,
+    GroupSelection,
+    UngroupSelection,
     NudgeSelectionUp,
     NudgeSelectionDown,
     NudgeSelectionLeft,
@@ -31,6 +36,14 @@ pub enum KeybindingField {
     MoveSelectionToEnd,
     MoveSelectionToTop,
     MoveSelectionToBottom,
+    AlignSelectionLeft,
+    AlignSelectionCenter,
+    AlignSelectionRight,
+    AlignSelectionTop,
+    AlignSelectionMiddle,
+    AlignSelectionBottom,
+    DistributeSelectionHorizontally,
+    DistributeSelectionVertically,
     DeleteSelection,
     IncreaseThickness,
     DecreaseThickness,
@@ -54,6 +67,7 @@ pub enum KeybindingField {
     SelectBlurTool,
     SelectSpotlightTool,
     CycleBlurStyle,
+    CycleLineStyle,
     SelectHighlightTool,
     IncreaseFontSize,
     DecreaseFontSize,
@@ -65,6 +79,8 @@ pub enum KeybindingField {
     PageNew,
     PageDuplicate,
     PageDelete,
+    ToggleGrid,
+    ToggleSnapToGrid,
     ToggleHelp,
     ToggleQuickHelp,
     ToggleStatusBar,
@@ -79,11 +95,22 @@ pub enum KeybindingField {
     ToggleRadialMenu,
     CycleToolbarDisplay,
     TogglePresenterMode,
+    ToggleReplay,
+    StartBreakTimer,
+    StartTalkTimer,
+    StartStopwatch,
+    ToggleTimerPause,
+    ResetTimer,
+    TimerAddMinute,
+    ToggleTimerDisplay,
     RenderProfileNext,
     RenderProfilePrevious,
     RenderProfileOff,
     ToggleFill,
+    ToggleConnectorMode,
     ToggleHighlightTool,
+    SelectLaserTool,
+    ToggleFadeInk,
     ToggleSelectionProperties,
     OpenContextMenu,
     OpenConfigurator,
@@ -112,6 +139,10 @@ pub enum KeybindingField {
     ExportCanvasClipboardAndFile,
     ExportBoardPdfFile,
     ExportAllBoardsPdfFile,
+    ExportCanvasSvgFile,
+    ExportBoardSvgFile,
+    ExportReplayFrames,
+    ToggleRecording,
     OpenCaptureFolder,
     CopyTextFromScreen,
     ToggleFrozenMode,
@@ -120,6 +151,9 @@ pub enum KeybindingField {
     ResetZoom,
     ToggleZoomLock,
     RefreshZoomCapture,
+    ToggleMagnifier,
+    MagnifierZoomIn,
+    MagnifierZoomOut,
     ResetArrowLabels,
     ResetStepMarkers,
     Board1,
@@ -8,6 +8,7 @@ impl KeybindingField {
             Self::EnterTextMode
             | Self::EnterStickyNoteMode
             | Self::ClearCanvas
+            | Self::ClearActiveLayer
             | Self::IncreaseThickness
             | Self::DecreaseThickness
             | Self::IncreaseMarkerOpacity
@@ -15,6 +16,7 @@ impl KeybindingField {
             | Self::IncreaseFontSize
             | Self::DecreaseFontSize
             | Self::ToggleFill
+            | Self::ToggleConnectorMode
             | Self::SetColorRed
             | Self::SetColorGreen
             | Self::SetColorBlue
@@ -42,8 +44,11 @@ impl KeybindingField {
             | Self::SelectBlurTool
             | Self::SelectSpotlightTool
             | Self::CycleBlurStyle
+            | Self::CycleLineStyle
             | Self::SelectHighlightTool
             | Self::ToggleHighlightTool
+            | Self::SelectLaserTool
+            | Self::ToggleFadeInk
             | Self::ResetArrowLabels
             | Self::ResetStepMarkers => KeybindingsTabId::Tools,
             Self::DuplicateSelection
@@ -52,10 +57,22 @@ impl KeybindingField {
             | Self::SelectAll
             | Self::MoveSelectionToFront
             | Self::MoveSelectionToBack
+            | Self::MoveSelectionToLayerAbove
+            | Self::MoveSelectionToLayerBelow
+            | Self::GroupSelection
+            | Self::UngroupSelection
             | Self::MoveSelectionToStart
             | Self::MoveSelectionToEnd
             | Self::MoveSelectionToTop
             | Self::MoveSelectionToBottom
+            | Self::AlignSelectionLeft
+            | Self::AlignSelectionCenter
+            | Self::AlignSelectionRight
+            | Self::AlignSelectionTop
+            | Self::AlignSelectionMiddle
+            | Self::AlignSelectionBottom
+            | Self::DistributeSelectionHorizontally
+            | Self::DistributeSelectionVertically
             | Self::NudgeSelectionUp
             | Self::NudgeSelectionDown
             | Self::NudgeSelectionLeft
@@ -77,6 +94,8 @@ impl KeybindingField {
             | Self::PageNew
             | Self::PageDuplicate
             | Self::PageDelete
+            | Self::ToggleGrid
+            | Self::ToggleSnapToGrid
             | Self::Board1
             | Self::Board2
             | Self::Board3
@@ -108,6 +127,14 @@ impl KeybindingField {
             | Self::ToggleRadialMenu
             | Self::CycleToolbarDisplay
             | Self::TogglePresenterMode
+            | Self::ToggleReplay
+            | Self::StartBreakTimer
+            | Self::StartTalkTimer
+            | Self::StartStopwatch
+            | Self::ToggleTimerPause
+            | Self::ResetTimer
+            | Self::TimerAddMinute
+            | Self::ToggleTimerDisplay
             | Self::RenderProfileNext
             | Self::RenderProfilePrevious
             | Self::RenderProfileOff
@@ -128,6 +155,10 @@ impl KeybindingField {
             | Self::ExportCanvasClipboardAndFile
             | Self::ExportBoardPdfFile
             | Self::ExportAllBoardsPdfFile
+            | Self::ExportCanvasSvgFile
+            | Self::ExportBoardSvgFile
+            | Self::ExportReplayFrames
+            | Self::ToggleRecording
             | Self::OpenCaptureFolder
             | Self::CopyTextFromScreen
             | Self::ToggleFrozenMode
@@ -135,7 +166,10 @@ impl KeybindingField {
             | Self::ZoomOut
             | Self::ResetZoom
             | Self::ToggleZoomLock
-            | Self::RefreshZoomCapture => KeybindingsTabId::CaptureView,
+            | Self::RefreshZoomCapture
+            | Self::ToggleMagnifier
+            | Self::MagnifierZoomIn
+            | Self::MagnifierZoomOut => KeybindingsTabId::CaptureView,
             Self::ApplyPreset1
             | Self::ApplyPreset2
             | Self::ApplyPreset3
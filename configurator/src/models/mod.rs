@@ -26,12 +26,12 @@ pub use fields::{
     DragColorOption, DragMouseButton, DragToolField, DragToolOption, EraserModeOption,
     FontStyleOption, FontWeightOption, InputHudModeOption, InputHudPositionOption, OverrideOption,
     PdfFitModeOption, PdfLabelContentModeOption, PdfLabelPositionOption, PdfOrientationOption,
-    PdfPageSizeOption, PdfTransparentBackgroundOption, PresenterToolBehaviorOption,
-    PresenterToolbarModeOption, PresetEraserKindOption, PresetEraserModeOption, PresetTextField,
-    PresetToggleField, QuadField, ReducedMotionOption, SessionCompressionOption,
-    SessionStorageModeOption, StatusPositionOption, TextField, ToggleField, ToolOption,
-    ToolbarLayoutModeOption, ToolbarOverrideField, ToolbarRebindModifierOption, UiThemeOption,
-    ZoomChipDisplayOption,
+    PdfPageSizeOption, PdfTransparentBackgroundOption, PngCompressionOption,
+    PresenterToolBehaviorOption, PresenterToolbarModeOption, PresetEraserKindOption,
+    PresetEraserModeOption, PresetTextField, PresetToggleField, QuadField, ReducedMotionOption,
+    SessionCompressionOption, SessionStorageModeOption, StatusPositionOption, TextField,
+    ToggleField, ToolOption, ToolbarLayoutModeOption, ToolbarOverrideField,
+    ToolbarRebindModifierOption, UiThemeOption, ZoomChipDisplayOption,
 };
 #[cfg(feature = "tablet-input")]
 pub use fields::{PressureThicknessEditModeOption, PressureThicknessEntryModeOption};
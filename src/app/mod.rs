@@ -163,6 +163,12 @@ pub fn run(cli: Cli) -> anyhow::Result<()> {
         return Ok(());
     }
 
+    // The watcher only talks to whichever overlay owns the control socket; it
+    // takes no locks and needs no broker.
+    if cli.watch {
+        return crate::control_socket::run_watch();
+    }
+
     // Runtime and update-checking modes create their process broker before
     // acquiring locks or starting threads. The guard spans the complete run.
     let _process_broker = needs_process_broker(&cli)
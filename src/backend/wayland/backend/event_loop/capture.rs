@@ -4,6 +4,7 @@ use std::time::{Duration, Instant};
 
 use super::super::super::state::{OverlaySuppression, WaylandState};
 use super::super::helpers::friendly_capture_error;
+use crate::capture::encode::ImageEncoding;
 use crate::capture::file::{FileSaveConfig, expand_tilde};
 use crate::capture::{CaptureOutcome, CapturePoll, ImageOperationKind};
 use crate::config::Action;
@@ -47,7 +48,10 @@ pub(super) fn capture_timeout(state: &WaylandState, now: Instant) -> Option<Dura
                 state.frozen.portal_timeout(now),
                 super::min_timeout(
                     state.zoom.portal_timeout(now),
-                    state.xdg_frozen_fullscreen_timeout(now),
+                    super::min_timeout(
+                        state.xdg_frozen_fullscreen_timeout(now),
+                        state.recording_timeout(now),
+                    ),
                 ),
             ),
         ),
@@ -98,8 +102,15 @@ pub(super) fn handle_pending_actions(
     state.poll_ocr_completion();
     state.poll_session_file_dialog_completion(qh);
     state.poll_desktop_open_completion();
+    state.poll_page_import_completion();
+    state.poll_drop_completion();
+    state.poll_recording_completion();
+    state.poll_recording(Instant::now());
+    state.own_pending_selection_clipboard(qh);
     state.drain_clipboard_requests();
     state.handle_pending_eyedropper_toggle();
+    state.handle_pending_magnifier_source();
+    state.handle_pending_timer_finished();
     state.handle_pending_ocr_request();
     // Copy/paste-hex requests from the color picker popup's pointer release are
     // drained here: unlike the toolbar/key paths, that release has no other
@@ -138,7 +149,11 @@ pub(super) fn handle_pending_actions(
             PendingBackendAction::BoardPdfExport(action) => {
                 state.handle_board_pdf_export_action(action);
             }
+            PendingBackendAction::SvgExport(action) => state.handle_svg_export_action(action),
+            PendingBackendAction::ReplayFrameExport => state.handle_replay_export_action(),
+            PendingBackendAction::ToggleRecording => state.handle_recording_toggle(),
             PendingBackendAction::DesktopOpen(request) => state.handle_desktop_open(request),
+            PendingBackendAction::PageImport(path) => state.handle_page_import(path),
             PendingBackendAction::ClearSavedToolState => {
                 state.handle_clear_saved_tool_state_action();
             }
@@ -246,6 +261,7 @@ fn handle_capture_results(state: &mut WaylandState) {
                     result.operation.saved_log_label(),
                     path.display()
                 );
+                state.publish_capture_saved(path);
                 if let Some(filename) = path.file_name() {
                     message_parts.push(format!("Saved as {}", filename.to_string_lossy()));
                 }
@@ -271,9 +287,15 @@ fn handle_capture_results(state: &mut WaylandState) {
                     save_directory: expand_tilde(&state.config.capture.save_directory),
                     filename_template: state.config.capture.filename_template.clone(),
                     format: state.config.capture.format.clone(),
+                    encoding: ImageEncoding::for_format(
+                        &state.config.capture.format,
+                        &state.config.capture,
+                    ),
                 };
                 if let Some(format) = result.fallback_format_override.as_ref() {
                     save_config.format = format.extension.clone();
+                    save_config.encoding =
+                        ImageEncoding::for_format(&format.extension, &state.config.capture);
                 }
                 // Pass exit_after_capture so we can exit after successful fallback save
                 state.input_state.set_clipboard_fallback(
@@ -312,9 +334,15 @@ fn handle_capture_results(state: &mut WaylandState) {
                     save_directory: expand_tilde(&state.config.capture.save_directory),
                     filename_template: state.config.capture.filename_template.clone(),
                     format: state.config.capture.format.clone(),
+                    encoding: ImageEncoding::for_format(
+                        &state.config.capture.format,
+                        &state.config.capture,
+                    ),
                 };
                 if let Some(format) = result.fallback_format_override.as_ref() {
                     save_config.format = format.extension.clone();
+                    save_config.encoding =
+                        ImageEncoding::for_format(&format.extension, &state.config.capture);
                 }
                 state.input_state.set_clipboard_fallback(
                     result.image_data.clone(),
@@ -352,6 +380,15 @@ fn handle_capture_results(state: &mut WaylandState) {
                         crate::capture::ImageOperationKind::AllBoardsPdfExport => {
                             "Boards exported".to_string()
                         }
+                        crate::capture::ImageOperationKind::CanvasSvgExport => {
+                            "Canvas exported as SVG".to_string()
+                        }
+                        crate::capture::ImageOperationKind::BoardSvgExport => {
+                            "Board exported as SVG".to_string()
+                        }
+                        crate::capture::ImageOperationKind::ReplayFrameExport => {
+                            "Replay exported as PNG frames".to_string()
+                        }
                     }
                 } else {
                     message_parts.join(" - ")
@@ -172,6 +172,8 @@ pub(super) fn run_event_loop(
         // A held key must wake the loop to fire its next auto-repeat.
         let timeout = min_timeout(timeout, state.key_repeat_timeout(now));
         let timeout = min_timeout(timeout, state.input_state.sequence_timeout(now));
+        // A running timer wakes the loop once a second to redraw its clock.
+        let timeout = min_timeout(timeout, state.input_state.timer_timeout(now));
         if let Err(e) =
             dispatch::dispatch_events(event_queue, state, runtime_wake, signal_state, timeout)
         {
@@ -192,6 +194,7 @@ pub(super) fn run_event_loop(
         }
 
         state.process_gtk_toolbar(conn, qh);
+        state.process_control_requests();
 
         // Check immediately after dispatch returns.
         if break_on_requested_exit(state) {
@@ -248,6 +251,9 @@ pub(super) fn run_event_loop(
         {
             state.input_state.needs_redraw = true;
         }
+        if state.input_state.tick_timer(Instant::now()) {
+            state.input_state.needs_redraw = true;
+        }
 
         // Synthesize auto-repeat for a held key (sctk's calloop repeat is not
         // wired to this manual loop). `dispatch_key_repeat` sets needs_redraw
@@ -276,6 +282,7 @@ pub(super) fn run_event_loop(
         }
         state.sync_overlay_interactivity();
         state.apply_onboarding_hints();
+        state.publish_control_events();
 
         // Hand changed palette recents to the dedicated persistence worker.
         // The event loop only replaces a bounded in-memory snapshot and wakes
@@ -343,7 +350,11 @@ pub(super) fn run_event_loop(
             // An edit made a moment before quitting still has to reach the file,
             // so teardown waits — briefly — for the worker's queue to drain.
             state.shutdown_config_edits();
+            // A running recording is stopped and given a moment to be saved.
+            state.shutdown_recording();
             state.shutdown_input_monitor();
+            // A copied selection the overlay still serves would vanish with it.
+            state.hand_off_selection_clipboard();
         },
         || match signals.as_mut() {
             Some(signal_state) => signal_state.stop_and_join(),
@@ -4,6 +4,7 @@ use smithay_client_toolkit::globals::ProvidesBoundGlobal;
 use smithay_client_toolkit::{
     activation::ActivationState,
     compositor::CompositorState,
+    data_device_manager::DataDeviceManagerState,
     output::OutputState,
     registry::RegistryState,
     seat::{
@@ -181,6 +182,22 @@ pub(super) fn setup_wayland() -> Result<WaylandSetup> {
         }
     };
 
+    // Drag-and-drop onto the overlay. Optional: without it the overlay simply
+    // rejects drags.
+    let data_device_manager = match DataDeviceManagerState::bind(&globals, &qh) {
+        Ok(manager) => {
+            debug!("Bound wl_data_device_manager");
+            Some(manager)
+        }
+        Err(err) => {
+            debug!(
+                "wl_data_device_manager not available; drag-and-drop disabled: {}",
+                err
+            );
+            None
+        }
+    };
+
     let layer_shell_available = layer_shell.is_some();
 
     let state_globals = WaylandGlobals {
@@ -194,6 +211,7 @@ pub(super) fn setup_wayland() -> Result<WaylandSetup> {
         relative_pointer_state,
         output_state,
         seat_state,
+        data_device_manager,
     };
 
     Ok(WaylandSetup {
@@ -97,6 +97,7 @@ pub(super) fn load(backend_exit_mode: ExitAfterCaptureMode) -> LoadedConfig {
     // this single call site covers both direct and daemon-managed overlays.
     crate::ui::theme::init(config.ui.theme.to_theme_mode());
     crate::ui::anim::set_motion_enabled(config.ui.reduced_motion.motion_enabled());
+    crate::i18n::set_language(&config.ui.language);
 
     let exit_after_capture_mode = match backend_exit_mode {
         ExitAfterCaptureMode::Auto if config.capture.exit_after_capture => {
@@ -406,6 +407,7 @@ fn config_path_display() -> String {
 
 fn log_config(config: &Config) {
     debug!("  Theme: {:?}", config.ui.theme);
+    debug!("  Language: {}", config.ui.language);
     debug!("  Reduced motion: {:?}", config.ui.reduced_motion);
     debug!("  Color: {:?}", config.drawing.default_color);
     debug!("  Thickness: {:.1}px", config.drawing.default_thickness);
@@ -3,7 +3,10 @@ use std::collections::HashMap;
 
 use crate::config::{Action, Config, KeybindingsConfig, QuickColorPalette, Shortcut};
 use crate::draw::{FontDescriptor, clamp_regular_sides};
-use crate::input::{ClickHighlightSettings, DragToolBindings, InputHudSettings, InputState};
+use crate::input::{
+    ClickHighlightSettings, DragToolBindings, InputHudSettings, InputState, LaserSettings,
+    MagnifierSettings, TimerSettings,
+};
 
 pub(super) fn build_input_state(config: &Config) -> InputState {
     let font_descriptor = FontDescriptor::new(
@@ -55,8 +58,16 @@ pub(super) fn build_input_state(config: &Config) -> InputState {
     input_state.set_undo_stack_limit(config.drawing.undo_stack_limit);
     input_state.polygon_sides = clamp_regular_sides(config.drawing.polygon_sides);
     input_state.blur_style = config.drawing.default_blur_style;
+    input_state.line_style = config.drawing.default_line_style;
+    input_state.smart_ink_enabled = config.drawing.smart_ink;
+    input_state.smart_ink_hold_ms = config.drawing.smart_ink_hold_ms;
+    input_state.stabilizer_strength = config.drawing.stabilizer_strength;
+    input_state.smooth_strokes = config.drawing.smooth_strokes;
     input_state.spotlight_dim_opacity = config.spotlight.dim_opacity;
     input_state.spotlight_feather = config.spotlight.feather;
+    input_state.init_laser_from_config(LaserSettings::from(&config.laser));
+    input_state.init_magnifier_from_config(MagnifierSettings::from(&config.magnifier));
+    input_state.init_timer_from_config(TimerSettings::from(&config.timer));
     input_state.set_context_menu_enabled(config.ui.context_menu.enabled);
     input_state.status_bar_interactive = config.ui.status_bar_interactive;
     input_state.show_status_selection_info = config.ui.show_status_selection_info;
@@ -10,6 +10,7 @@ use super::runtime_wake::RuntimeWakeSource;
 use super::setup::WaylandSetup;
 use crate::backend::wayland::portal_capture::screenshot_portal_available;
 use crate::env_vars::{DESKTOP_SESSION_ENV, XDG_CURRENT_DESKTOP_ENV, XDG_SESSION_DESKTOP_ENV};
+use crate::i18n::tr_args;
 use crate::{
     capture::CaptureManager,
     config::Config,
@@ -134,8 +135,9 @@ pub(super) fn init_state(backend: &WaylandBackend, setup: WaylandSetup) -> Resul
         input_state.push_toast(
             crate::input::state::ToastPriority::Critical,
             "onboarding.persistence",
-            crate::input::state::Toast::warning(format!(
-                "Automatic guidance is off because onboarding progress could not be saved: {error}"
+            crate::input::state::Toast::warning(tr_args(
+                "Automatic guidance is off because onboarding progress could not be saved: {error}",
+                &[("error", &error.to_string())],
             )),
         );
     }
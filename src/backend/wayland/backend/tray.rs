@@ -130,5 +130,10 @@ fn apply_tray_action(state: &mut WaylandState, action: TrayAction) {
             state.input_state.set_light_mode_drawing(false);
             state.input_state.needs_redraw = true;
         }
+        TrayAction::StartBreakTimer => state.input_state.handle_action(Action::StartBreakTimer),
+        TrayAction::StartTalkTimer => state.input_state.handle_action(Action::StartTalkTimer),
+        TrayAction::StartStopwatch => state.input_state.handle_action(Action::StartStopwatch),
+        TrayAction::ToggleTimerPause => state.input_state.handle_action(Action::ToggleTimerPause),
+        TrayAction::ResetTimer => state.input_state.handle_action(Action::ResetTimer),
     }
 }
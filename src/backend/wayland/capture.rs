@@ -19,6 +19,10 @@ enum CapturePreflight {
 }
 
 #[derive(Clone)]
+#[allow(
+    clippy::large_enum_variant,
+    reason = "one preflight request is queued at a time, so indirection buys nothing"
+)]
 pub(in crate::backend::wayland) enum CapturePreflightRequest {
     Screenshot(CaptureRequest),
     DesktopBackdrop(DesktopBackdropCaptureRequest),
@@ -216,6 +220,7 @@ mod tests {
             capture_type: CaptureType::FullScreen,
             destination: CaptureDestination::ClipboardOnly,
             save_config: None,
+            postprocess: None,
         };
 
         state.queue_preflight(CapturePreflightRequest::Screenshot(request));
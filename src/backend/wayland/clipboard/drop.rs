@@ -0,0 +1,299 @@
+//! Drag-and-drop transfers onto the overlay.
+//!
+//! Drops reuse the clipboard size limits and decoders: images keep the
+//! `MAX_CLIPBOARD_IMAGE_BYTES` cap, file lists go through the same regular-file
+//! checks as pasted file URIs, and plain text becomes a text annotation.
+
+use super::{
+    ClipboardPasteResult, MAX_CLIPBOARD_IMAGE_BYTES, file_list, image::decode_clipboard_image,
+    system::read_pipe_with_timeout, transfer::map_read_error,
+};
+use crate::file_uri;
+use crate::input::state::DropKind;
+use crate::session::SESSION_FILE_EXTENSION;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Dropped text becomes a single annotation; larger payloads are rejected.
+pub(in crate::backend::wayland) const MAX_DROP_TEXT_BYTES: usize = 256 * 1024;
+// The drag source writes the payload only after the drop, so allow more time
+// than a clipboard read whose owner is already serving the data.
+const DROP_READ_TIMEOUT: Duration = Duration::from_secs(3);
+
+const PREFERRED_IMAGE_MIMES: [&str; 3] = ["image/png", "image/jpeg", "image/jpg"];
+const TEXT_MIMES: [&str; 3] = ["text/plain;charset=utf-8", "text/plain", "UTF8_STRING"];
+
+#[derive(Debug)]
+pub(in crate::backend::wayland) enum DropPayload {
+    /// An image, or why the drop could not be read or decoded.
+    Paste(ClipboardPasteResult),
+    Text(String),
+    SessionFile(PathBuf),
+}
+
+impl DropPayload {
+    pub(in crate::backend::wayland) fn summary(&self) -> String {
+        match self {
+            Self::Paste(result) => result.summary(),
+            Self::Text(text) => format!("text bytes={}", text.len()),
+            Self::SessionFile(path) => format!("session-file path={}", path.display()),
+        }
+    }
+}
+
+/// Picks the MIME type to request from a drag offer: images first, since
+/// browsers offer an image next to its web URL, then local files, then text.
+pub(in crate::backend::wayland) fn choose_drop_mime(
+    offered: &[String],
+) -> Option<(String, DropKind)> {
+    let find = |candidate: &str| {
+        offered
+            .iter()
+            .find(|mime| mime.eq_ignore_ascii_case(candidate))
+            .cloned()
+    };
+
+    if let Some(mime) = PREFERRED_IMAGE_MIMES.into_iter().find_map(find) {
+        return Some((mime, DropKind::Image));
+    }
+    if let Some(mime) = offered.iter().find(|mime| is_image_mime(mime)) {
+        return Some((mime.clone(), DropKind::Image));
+    }
+    if let Some(mime) = offered
+        .iter()
+        .find(|mime| file_list::is_uri_list_mime(mime))
+    {
+        return Some((mime.clone(), DropKind::File));
+    }
+    TEXT_MIMES
+        .into_iter()
+        .find_map(find)
+        .map(|mime| (mime, DropKind::Text))
+}
+
+/// Reads a drop transfer and resolves it into something the overlay can add.
+pub(in crate::backend::wayland) fn resolve_drop<R>(mime_type: &str, reader: R) -> DropPayload
+where
+    R: Read + Send + 'static,
+{
+    let limit = if is_image_mime(mime_type) {
+        MAX_CLIPBOARD_IMAGE_BYTES
+    } else {
+        MAX_DROP_TEXT_BYTES
+    };
+    let bytes = match read_pipe_with_timeout(reader, limit, DROP_READ_TIMEOUT) {
+        Ok(bytes) => bytes,
+        Err(err) => return DropPayload::Paste(map_read_error(err)),
+    };
+
+    if is_image_mime(mime_type) {
+        DropPayload::Paste(decode_clipboard_image(mime_type, bytes))
+    } else if file_list::is_uri_list_mime(mime_type) {
+        resolve_dropped_uri_list(mime_type, bytes)
+    } else {
+        decode_dropped_text(bytes)
+    }
+}
+
+fn resolve_dropped_uri_list(mime_type: &str, bytes: Vec<u8>) -> DropPayload {
+    let first_local_path = match file_list::parse_clipboard_file_uris(mime_type, &bytes) {
+        Ok(uris) => uris
+            .iter()
+            .find_map(|uri| file_uri::decode_file_uri(uri).ok()),
+        Err(err) => return DropPayload::Paste(ClipboardPasteResult::DecodeFailed(err)),
+    };
+    let Some(path) = first_local_path else {
+        return DropPayload::Paste(ClipboardPasteResult::NoSupportedMime {
+            offered: vec![mime_type.to_string()],
+        });
+    };
+
+    if has_extension(&path, SESSION_FILE_EXTENSION) {
+        return DropPayload::SessionFile(path);
+    }
+    if has_extension(&path, "txt") {
+        return match file_list::read_clipboard_file_with_limit(&path, MAX_DROP_TEXT_BYTES) {
+            Ok(bytes) => decode_dropped_text(bytes),
+            Err(err) => DropPayload::Paste(file_list::map_clipboard_file_read_error(&path, err)),
+        };
+    }
+    DropPayload::Paste(file_list::decode_clipboard_uri_list(
+        mime_type,
+        bytes,
+        vec![mime_type.to_string()],
+    ))
+}
+
+fn decode_dropped_text(bytes: Vec<u8>) -> DropPayload {
+    let text = match String::from_utf8(bytes) {
+        Ok(text) => text,
+        Err(err) => {
+            return DropPayload::Paste(ClipboardPasteResult::DecodeFailed(format!(
+                "dropped text is not UTF-8: {err}"
+            )));
+        }
+    };
+    let text = text.replace("\r\n", "\n");
+    let text = text.trim_end_matches(['\n', '\r']);
+    if text.trim().is_empty() {
+        return DropPayload::Paste(ClipboardPasteResult::ClipboardEmpty);
+    }
+    DropPayload::Text(text.to_string())
+}
+
+fn is_image_mime(mime_type: &str) -> bool {
+    mime_type
+        .get(..6)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("image/"))
+}
+
+fn has_extension(path: &Path, extension: &str) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_temp::TempDir;
+    use std::fs;
+    use std::io::Cursor;
+
+    fn offered(mimes: &[&str]) -> Vec<String> {
+        mimes.iter().map(|mime| mime.to_string()).collect()
+    }
+
+    fn file_uri(path: &Path) -> String {
+        format!("file://{}", path.to_string_lossy().replace(' ', "%20"))
+    }
+
+    #[test]
+    fn choose_drop_mime_prefers_images_over_file_lists_and_text() {
+        let browser = offered(&["text/uri-list", "text/plain", "image/png"]);
+        assert_eq!(
+            choose_drop_mime(&browser),
+            Some(("image/png".to_string(), DropKind::Image))
+        );
+
+        let webp = offered(&["text/plain", "image/webp"]);
+        assert_eq!(
+            choose_drop_mime(&webp),
+            Some(("image/webp".to_string(), DropKind::Image))
+        );
+
+        let file_manager = offered(&["text/plain;charset=utf-8", "text/uri-list"]);
+        assert_eq!(
+            choose_drop_mime(&file_manager),
+            Some(("text/uri-list".to_string(), DropKind::File))
+        );
+
+        let editor = offered(&["UTF8_STRING", "text/plain"]);
+        assert_eq!(
+            choose_drop_mime(&editor),
+            Some(("text/plain".to_string(), DropKind::Text))
+        );
+
+        assert_eq!(choose_drop_mime(&offered(&["application/pdf"])), None);
+    }
+
+    #[test]
+    fn text_drop_normalizes_line_endings_and_trailing_newlines() {
+        let payload = resolve_drop("text/plain", Cursor::new(b"first\r\nsecond\r\n".to_vec()));
+        assert!(matches!(payload, DropPayload::Text(ref text) if text == "first\nsecond"));
+
+        let payload = resolve_drop("text/plain", Cursor::new(b" \n".to_vec()));
+        assert!(matches!(
+            payload,
+            DropPayload::Paste(ClipboardPasteResult::ClipboardEmpty)
+        ));
+    }
+
+    #[test]
+    fn text_drop_over_the_limit_is_rejected() {
+        let payload = resolve_drop(
+            "text/plain",
+            Cursor::new(vec![b'a'; MAX_DROP_TEXT_BYTES + 1]),
+        );
+        assert!(matches!(
+            payload,
+            DropPayload::Paste(ClipboardPasteResult::TooLarge { limit }) if limit == MAX_DROP_TEXT_BYTES
+        ));
+    }
+
+    #[test]
+    fn uri_list_drop_of_session_file_is_offered_for_opening() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("lecture.wayscriber-session");
+        let uris = format!("{}\r\n", file_uri(&path));
+
+        let payload = resolve_drop("text/uri-list", Cursor::new(uris.into_bytes()));
+
+        assert!(matches!(payload, DropPayload::SessionFile(ref dropped) if dropped == &path));
+    }
+
+    #[test]
+    fn uri_list_drop_of_text_file_reads_its_contents() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("notes file.txt");
+        fs::write(&path, "remember the milk\n").unwrap();
+
+        let payload = resolve_drop("text/uri-list", Cursor::new(file_uri(&path).into_bytes()));
+
+        assert!(matches!(payload, DropPayload::Text(ref text) if text == "remember the milk"));
+    }
+
+    #[test]
+    fn uri_list_drop_of_image_file_decodes_it() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("dot.png");
+        fs::write(&path, tiny_png()).unwrap();
+
+        let payload = resolve_drop("text/uri-list", Cursor::new(file_uri(&path).into_bytes()));
+
+        match payload {
+            DropPayload::Paste(ClipboardPasteResult::Image(image)) => {
+                assert_eq!(image.mime_type, "image/png");
+                assert_eq!((image.width, image.height), (1, 1));
+            }
+            other => panic!("expected image drop, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn uri_list_drop_without_local_files_is_unsupported() {
+        let payload = resolve_drop(
+            "text/uri-list",
+            Cursor::new(b"https://example.com/cat.png\n".to_vec()),
+        );
+        assert!(matches!(
+            payload,
+            DropPayload::Paste(ClipboardPasteResult::NoSupportedMime { .. })
+        ));
+    }
+
+    #[test]
+    fn image_drop_over_the_clipboard_limit_is_rejected() {
+        let payload = resolve_drop(
+            "image/png",
+            Cursor::new(vec![0; MAX_CLIPBOARD_IMAGE_BYTES + 1]),
+        );
+        assert!(matches!(
+            payload,
+            DropPayload::Paste(ClipboardPasteResult::TooLarge { limit })
+                if limit == MAX_CLIPBOARD_IMAGE_BYTES
+        ));
+    }
+
+    fn tiny_png() -> Vec<u8> {
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut bytes, 1, 1);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header().unwrap();
+            writer.write_image_data(&[255, 0, 0, 255]).unwrap();
+        }
+        bytes
+    }
+}
@@ -74,7 +74,10 @@ pub(super) fn is_uri_list_mime(mime_type: &str) -> bool {
         || mime_type.starts_with("text/uri-list;")
 }
 
-fn parse_clipboard_file_uris(mime_type: &str, bytes: &[u8]) -> Result<Vec<String>, String> {
+pub(super) fn parse_clipboard_file_uris(
+    mime_type: &str,
+    bytes: &[u8],
+) -> Result<Vec<String>, String> {
     let text = std::str::from_utf8(bytes)
         .map_err(|err| format!("clipboard URI list is not UTF-8: {err}"))?;
     let is_gnome = is_gnome_copied_files_mime(mime_type);
@@ -95,12 +98,22 @@ fn parse_clipboard_file_uris(mime_type: &str, bytes: &[u8]) -> Result<Vec<String
 }
 
 fn read_clipboard_file(path: &Path) -> Result<Vec<u8>, ClipboardReadError> {
-    ensure_regular_clipboard_path(path)?;
-    let file = open_regular_clipboard_file(path)?;
-    read_pipe_with_timeout(file, MAX_CLIPBOARD_IMAGE_BYTES, CLIPBOARD_READ_TIMEOUT)
+    read_clipboard_file_with_limit(path, MAX_CLIPBOARD_IMAGE_BYTES)
+}
+
+pub(super) fn read_clipboard_file_with_limit(
+    path: &Path,
+    limit: usize,
+) -> Result<Vec<u8>, ClipboardReadError> {
+    ensure_regular_clipboard_path(path, limit)?;
+    let file = open_regular_clipboard_file(path, limit)?;
+    read_pipe_with_timeout(file, limit, CLIPBOARD_READ_TIMEOUT)
 }
 
-fn map_clipboard_file_read_error(path: &Path, err: ClipboardReadError) -> ClipboardPasteResult {
+pub(super) fn map_clipboard_file_read_error(
+    path: &Path,
+    err: ClipboardReadError,
+) -> ClipboardPasteResult {
     match err {
         ClipboardReadError::TooLarge { limit } => ClipboardPasteResult::TooLarge { limit },
         ClipboardReadError::Empty => ClipboardPasteResult::DecodeFailed(format!(
@@ -121,7 +134,7 @@ fn map_clipboard_file_read_error(path: &Path, err: ClipboardReadError) -> Clipbo
     }
 }
 
-fn ensure_regular_clipboard_path(path: &Path) -> Result<(), ClipboardReadError> {
+fn ensure_regular_clipboard_path(path: &Path, limit: usize) -> Result<(), ClipboardReadError> {
     let metadata = fs::symlink_metadata(path).map_err(|err| {
         ClipboardReadError::Other(format!(
             "Failed to inspect clipboard file {}: {}",
@@ -129,12 +142,13 @@ fn ensure_regular_clipboard_path(path: &Path) -> Result<(), ClipboardReadError>
             err
         ))
     })?;
-    validate_clipboard_file_metadata(&metadata, path)
+    validate_clipboard_file_metadata(&metadata, path, limit)
 }
 
 fn validate_clipboard_file_metadata(
     metadata: &fs::Metadata,
     path: &Path,
+    limit: usize,
 ) -> Result<(), ClipboardReadError> {
     if !metadata.file_type().is_file() {
         return Err(ClipboardReadError::Other(format!(
@@ -142,16 +156,14 @@ fn validate_clipboard_file_metadata(
             path.display()
         )));
     }
-    if metadata.len() > MAX_CLIPBOARD_IMAGE_BYTES as u64 {
-        return Err(ClipboardReadError::TooLarge {
-            limit: MAX_CLIPBOARD_IMAGE_BYTES,
-        });
+    if metadata.len() > limit as u64 {
+        return Err(ClipboardReadError::TooLarge { limit });
     }
     Ok(())
 }
 
 #[cfg(unix)]
-fn open_regular_clipboard_file(path: &Path) -> Result<File, ClipboardReadError> {
+fn open_regular_clipboard_file(path: &Path, limit: usize) -> Result<File, ClipboardReadError> {
     let file = OpenOptions::new()
         .read(true)
         .custom_flags(libc::O_NOFOLLOW | libc::O_NONBLOCK)
@@ -163,12 +175,12 @@ fn open_regular_clipboard_file(path: &Path) -> Result<File, ClipboardReadError>
                 err
             ))
         })?;
-    ensure_regular_clipboard_file(&file, path)?;
+    ensure_regular_clipboard_file(&file, path, limit)?;
     Ok(file)
 }
 
 #[cfg(not(unix))]
-fn open_regular_clipboard_file(path: &Path) -> Result<File, ClipboardReadError> {
+fn open_regular_clipboard_file(path: &Path, limit: usize) -> Result<File, ClipboardReadError> {
     let file = File::open(path).map_err(|err| {
         ClipboardReadError::Other(format!(
             "Failed to open clipboard file {}: {}",
@@ -176,11 +188,15 @@ fn open_regular_clipboard_file(path: &Path) -> Result<File, ClipboardReadError>
             err
         ))
     })?;
-    ensure_regular_clipboard_file(&file, path)?;
+    ensure_regular_clipboard_file(&file, path, limit)?;
     Ok(file)
 }
 
-fn ensure_regular_clipboard_file(file: &File, path: &Path) -> Result<(), ClipboardReadError> {
+fn ensure_regular_clipboard_file(
+    file: &File,
+    path: &Path,
+    limit: usize,
+) -> Result<(), ClipboardReadError> {
     let metadata = file.metadata().map_err(|err| {
         ClipboardReadError::Other(format!(
             "Failed to inspect clipboard file {}: {}",
@@ -188,7 +204,7 @@ fn ensure_regular_clipboard_file(file: &File, path: &Path) -> Result<(), Clipboa
             err
         ))
     })?;
-    validate_clipboard_file_metadata(&metadata, path)
+    validate_clipboard_file_metadata(&metadata, path, limit)
 }
 
 fn is_gnome_copied_files_mime(mime_type: &str) -> bool {
@@ -11,8 +11,10 @@ pub(in crate::backend::wayland) use transfer::{
     FailedLocalSelectionProbe, PasteAction, TransferEffect, TransferPlan, TransferWarning,
 };
 
+pub(in crate::backend::wayland) mod drop;
 mod file_list;
 mod image;
+pub(in crate::backend::wayland) mod selection_formats;
 mod system;
 pub(in crate::backend::wayland) mod transfer;
 pub(super) const WAYSCRIBER_SELECTION_MIME: &str = "application/vnd.wayscriber.selection+json";
@@ -0,0 +1,404 @@
+//! Formats a copied selection is offered in besides the private wayscriber JSON.
+//!
+//! The overlay serves its own clipboard selection, so nothing is rendered at
+//! copy time: the PNG, SVG, or text form is produced only when another
+//! application asks for that MIME type.
+
+use super::WAYSCRIBER_SELECTION_MIME;
+use crate::canvas_export::{
+    BoardExportSnapshot, CanvasExportBackdropSnapshot, CanvasExportSnapshot, CanvasExportViewport,
+    SpotlightPassSnapshot, render_canvas_png, render_canvas_svg,
+};
+use crate::draw::{Frame, Shape};
+use crate::render_profiles::RenderColorProfile;
+use crate::util::Rect;
+use std::io::Write;
+use std::sync::Mutex;
+
+pub(in crate::backend::wayland) const PNG_MIME: &str = "image/png";
+pub(in crate::backend::wayland) const SVG_MIME: &str = "image/svg+xml";
+const TEXT_MIMES: [&str; 3] = ["text/plain;charset=utf-8", "text/plain", "UTF8_STRING"];
+// Room for antialiased edges that spill past a shape's bounding box.
+const EXPORT_PADDING: i32 = 2;
+
+/// How the image forms of a copied selection are painted.
+#[derive(Debug, Clone)]
+pub(in crate::backend::wayland) struct SelectionExportStyle {
+    pub(in crate::backend::wayland) backdrop: CanvasExportBackdropSnapshot,
+    pub(in crate::backend::wayland) scale: i32,
+    pub(in crate::backend::wayland) render_profile: Option<RenderColorProfile>,
+    pub(in crate::backend::wayland) spotlight: SpotlightPassSnapshot,
+}
+
+/// Everything needed to answer a clipboard request for one copied selection.
+#[derive(Debug)]
+pub(in crate::backend::wayland) struct SelectionClipboardContent {
+    generation: u64,
+    payload_json: String,
+    /// The copied shapes on a copy of their layers, so the image forms keep
+    /// shape and layer opacity and blend modes. Locked because requests are
+    /// answered on worker threads and a frame caches shape bounds.
+    frame: Mutex<Frame>,
+    style: SelectionExportStyle,
+    /// Padded canvas rectangle the image forms cover; `None` when no copied
+    /// shape has visible bounds.
+    bounds: Option<Rect>,
+    text: Option<String>,
+}
+
+impl SelectionClipboardContent {
+    pub(in crate::backend::wayland) fn new(
+        generation: u64,
+        payload_json: String,
+        shapes: &[Shape],
+        frame: Frame,
+        style: SelectionExportStyle,
+    ) -> Self {
+        Self {
+            generation,
+            payload_json,
+            bounds: padded_bounds(&frame),
+            text: selection_text(shapes),
+            frame: Mutex::new(frame),
+            style,
+        }
+    }
+
+    pub(in crate::backend::wayland) fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    pub(in crate::backend::wayland) fn payload_json(&self) -> &str {
+        &self.payload_json
+    }
+
+    /// MIME types to advertise, most specific first so wayscriber's own paste
+    /// keeps picking the lossless JSON.
+    pub(in crate::backend::wayland) fn mime_types(&self) -> Vec<&'static str> {
+        let mut mime_types = vec![WAYSCRIBER_SELECTION_MIME];
+        if self.bounds.is_some() {
+            mime_types.extend([PNG_MIME, SVG_MIME]);
+        }
+        if self.text.is_some() {
+            mime_types.extend(TEXT_MIMES);
+        }
+        mime_types
+    }
+
+    /// Renders the selection in the requested form.
+    pub(in crate::backend::wayland) fn render(&self, mime_type: &str) -> Result<Vec<u8>, String> {
+        if mime_type == WAYSCRIBER_SELECTION_MIME {
+            return Ok(self.payload_json.as_bytes().to_vec());
+        }
+        if TEXT_MIMES.contains(&mime_type) {
+            return self
+                .text
+                .as_ref()
+                .map(|text| text.as_bytes().to_vec())
+                .ok_or_else(|| "selection has no text".to_string());
+        }
+        if mime_type != PNG_MIME && mime_type != SVG_MIME {
+            return Err(format!("{mime_type} is not offered"));
+        }
+        let export = self
+            .export_snapshot()
+            .ok_or_else(|| "selection has no visible bounds".to_string())?;
+        if mime_type == PNG_MIME {
+            render_canvas_png(&export)
+                .map(|image| image.bytes)
+                .map_err(|err| err.to_string())
+        } else {
+            render_canvas_svg(&export).map_err(|err| err.to_string())
+        }
+    }
+
+    /// A canvas export of just the copied shapes, cropped to their bounds.
+    fn export_snapshot(&self) -> Option<CanvasExportSnapshot> {
+        let bounds = self.bounds?;
+        Some(CanvasExportSnapshot {
+            viewport: CanvasExportViewport {
+                logical_width: u32::try_from(bounds.width).ok()?,
+                logical_height: u32::try_from(bounds.height).ok()?,
+                scale: self.style.scale,
+                origin_x: bounds.x,
+                origin_y: bounds.y,
+            },
+            backdrop: self.style.backdrop.clone(),
+            board: BoardExportSnapshot {
+                frame: self
+                    .frame
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .clone(),
+            },
+            render_profile: self.style.render_profile.clone(),
+            spotlight: self.style.spotlight,
+        })
+    }
+}
+
+/// Answers one clipboard request, writing the rendered form to the requester.
+pub(in crate::backend::wayland) fn send_selection_content(
+    content: &SelectionClipboardContent,
+    mime_type: &str,
+    mut writer: impl Write,
+) {
+    let bytes = match content.render(mime_type) {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            log::warn!(
+                "Could not render copied selection {} as {mime_type}: {err}",
+                content.generation
+            );
+            return;
+        }
+    };
+    match writer.write_all(&bytes) {
+        Ok(()) => log::debug!(
+            "Sent copied selection {} as {mime_type} ({} bytes)",
+            content.generation,
+            bytes.len()
+        ),
+        // The requesting application may close its end early; that is its call.
+        Err(err) => log::debug!(
+            "Clipboard requester stopped reading {mime_type} for selection {}: {err}",
+            content.generation
+        ),
+    }
+}
+
+fn padded_bounds(frame: &Frame) -> Option<Rect> {
+    let (min_x, min_y, max_x, max_y) = frame
+        .shapes
+        .iter()
+        .filter_map(|drawn| drawn.shape.bounding_box())
+        .map(|bounds| {
+            (
+                bounds.x,
+                bounds.y,
+                bounds.x.saturating_add(bounds.width),
+                bounds.y.saturating_add(bounds.height),
+            )
+        })
+        .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))?;
+    Rect::from_min_max(
+        min_x.saturating_sub(EXPORT_PADDING),
+        min_y.saturating_sub(EXPORT_PADDING),
+        max_x.saturating_add(EXPORT_PADDING),
+        max_y.saturating_add(EXPORT_PADDING),
+    )
+}
+
+/// Text and sticky-note sources in selection order, one per line.
+fn selection_text(shapes: &[Shape]) -> Option<String> {
+    let texts: Vec<&str> = shapes
+        .iter()
+        .filter_map(|shape| match shape {
+            Shape::Text { text, .. } | Shape::StickyNote { text, .. } => Some(text.trim_end()),
+            _ => None,
+        })
+        .filter(|text| !text.trim().is_empty())
+        .collect();
+    (!texts.is_empty()).then(|| texts.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draw::{BLACK, FontDescriptor, LineStyle, RED, WHITE};
+    use crate::image_decode::{EncodedImageFormat, image_dimensions};
+
+    fn style(backdrop: CanvasExportBackdropSnapshot, scale: i32) -> SelectionExportStyle {
+        SelectionExportStyle {
+            backdrop,
+            scale,
+            render_profile: None,
+            spotlight: SpotlightPassSnapshot::default(),
+        }
+    }
+
+    fn rect(x: i32, y: i32, w: i32, h: i32) -> Shape {
+        Shape::Rect {
+            x,
+            y,
+            w,
+            h,
+            fill: true,
+            color: RED,
+            thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
+        }
+    }
+
+    fn text(x: i32, y: i32, text: &str) -> Shape {
+        Shape::Text {
+            x,
+            y,
+            text: text.to_string(),
+            color: BLACK,
+            size: 20.0,
+            font_descriptor: FontDescriptor::default(),
+            background_enabled: false,
+            wrap_width: None,
+            rotation: 0.0,
+        }
+    }
+
+    fn content(shapes: Vec<Shape>, style: SelectionExportStyle) -> SelectionClipboardContent {
+        let mut frame = Frame::new();
+        for shape in &shapes {
+            frame.add_shape(shape.clone());
+        }
+        SelectionClipboardContent::new(7, "{\"shapes\":[]}".to_string(), &shapes, frame, style)
+    }
+
+    fn center_alpha(copied: &SelectionClipboardContent) -> u8 {
+        let png = copied.render(PNG_MIME).unwrap();
+        let mut surface = cairo::ImageSurface::create_from_png(&mut png.as_slice()).unwrap();
+        let (width, height) = (surface.width() as usize, surface.height() as usize);
+        let stride = surface.stride() as usize;
+        let data = surface.data().unwrap();
+        data[(height / 2) * stride + (width / 2) * 4 + 3]
+    }
+
+    #[test]
+    fn text_types_are_offered_only_when_the_selection_has_text() {
+        let shapes_only = content(
+            vec![rect(0, 0, 10, 10)],
+            style(CanvasExportBackdropSnapshot::Transparent, 1),
+        );
+        assert_eq!(
+            shapes_only.mime_types(),
+            vec![WAYSCRIBER_SELECTION_MIME, PNG_MIME, SVG_MIME]
+        );
+
+        let with_text = content(
+            vec![rect(0, 0, 10, 10), text(0, 40, "Callout")],
+            style(CanvasExportBackdropSnapshot::Transparent, 1),
+        );
+        assert_eq!(with_text.mime_types()[0], WAYSCRIBER_SELECTION_MIME);
+        assert!(with_text.mime_types().contains(&"text/plain"));
+    }
+
+    #[test]
+    fn text_form_joins_text_shapes_in_selection_order() {
+        let copied = content(
+            vec![
+                text(0, 40, "First\n"),
+                rect(0, 0, 10, 10),
+                text(0, 80, "   "),
+                text(0, 120, "**Second**"),
+            ],
+            style(CanvasExportBackdropSnapshot::Transparent, 1),
+        );
+
+        assert_eq!(
+            copied.render("text/plain;charset=utf-8").unwrap(),
+            b"First\n**Second**".to_vec()
+        );
+        assert_eq!(
+            copied.render(WAYSCRIBER_SELECTION_MIME).unwrap(),
+            b"{\"shapes\":[]}".to_vec()
+        );
+    }
+
+    #[test]
+    fn png_form_is_cropped_to_the_selection_at_output_scale() {
+        let copied = content(
+            vec![rect(100, 200, 20, 10), rect(130, 205, 10, 15)],
+            style(CanvasExportBackdropSnapshot::Transparent, 2),
+        );
+        let bounds = copied.export_snapshot().unwrap().viewport;
+        let first = rect(100, 200, 20, 10).bounding_box().unwrap();
+        assert_eq!(
+            (bounds.origin_x, bounds.origin_y),
+            (first.x - EXPORT_PADDING, first.y - EXPORT_PADDING)
+        );
+
+        let png = copied.render(PNG_MIME).unwrap();
+        let (width, height) = image_dimensions(EncodedImageFormat::Png, &png).unwrap();
+        assert_eq!(
+            (width, height),
+            (bounds.logical_width * 2, bounds.logical_height * 2)
+        );
+    }
+
+    #[test]
+    fn png_backdrop_fills_the_corners_only_when_requested() {
+        let corner_alpha = |backdrop| {
+            let png = content(vec![rect(10, 10, 20, 20)], style(backdrop, 1))
+                .render(PNG_MIME)
+                .unwrap();
+            let surface = cairo::ImageSurface::create_from_png(&mut png.as_slice()).unwrap();
+            let data = surface.take_data().unwrap();
+            data[3]
+        };
+
+        assert_eq!(corner_alpha(CanvasExportBackdropSnapshot::Transparent), 0);
+        assert_eq!(
+            corner_alpha(CanvasExportBackdropSnapshot::Solid(WHITE)),
+            255
+        );
+    }
+
+    #[test]
+    fn png_form_keeps_shape_and_layer_opacity() {
+        let opaque = content(
+            vec![rect(10, 10, 40, 40)],
+            style(CanvasExportBackdropSnapshot::Transparent, 1),
+        );
+        assert_eq!(center_alpha(&opaque), 255);
+
+        let mut frame = Frame::new();
+        let id = frame.add_shape(rect(10, 10, 40, 40));
+        frame.shape_mut(id).unwrap().appearance.opacity = 0.5;
+        let faded_shape = SelectionClipboardContent::new(
+            7,
+            String::new(),
+            &[],
+            frame.excerpt(&[id]),
+            style(CanvasExportBackdropSnapshot::Transparent, 1),
+        );
+        assert!((120..=136).contains(&center_alpha(&faded_shape)));
+
+        let mut frame = Frame::new();
+        let id = frame.add_shape(rect(10, 10, 40, 40));
+        frame.set_layer_opacity(frame.active_layer_id(), 0.5);
+        let faded_layer = SelectionClipboardContent::new(
+            7,
+            String::new(),
+            &[],
+            frame.excerpt(&[id]),
+            style(CanvasExportBackdropSnapshot::Transparent, 1),
+        );
+        assert!((120..=136).contains(&center_alpha(&faded_layer)));
+    }
+
+    #[test]
+    fn svg_form_is_a_standalone_document() {
+        let copied = content(
+            vec![rect(10, 10, 20, 20)],
+            style(CanvasExportBackdropSnapshot::Transparent, 1),
+        );
+        let svg = String::from_utf8(copied.render(SVG_MIME).unwrap()).unwrap();
+
+        assert!(svg.contains("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn send_writes_the_rendered_form_to_the_requester() {
+        let copied = content(
+            vec![text(0, 40, "Hello")],
+            style(CanvasExportBackdropSnapshot::Transparent, 1),
+        );
+        let mut sent = Vec::new();
+        send_selection_content(&copied, "UTF8_STRING", &mut sent);
+        assert_eq!(sent, b"Hello");
+
+        let mut unsupported = Vec::new();
+        send_selection_content(&copied, "application/pdf", &mut unsupported);
+        assert!(unsupported.is_empty());
+    }
+}
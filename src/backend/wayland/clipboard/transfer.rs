@@ -3,9 +3,9 @@ use super::{
     ClipboardPublishCompletion, ClipboardReadError, MAX_CLIPBOARD_IMAGE_BYTES,
     MAX_CLIPBOARD_SELECTION_BYTES, WAYSCRIBER_SELECTION_MIME, file_list, image, system,
 };
-use crate::draw::{EmbeddedImage, Shape};
+use crate::draw::EmbeddedImage;
 use crate::input::state::{
-    ClipboardFingerprint, ClipboardPasteRequest, WayscriberClipboardSelection,
+    ClipboardFingerprint, ClipboardPasteRequest, ClipboardShape, WayscriberClipboardSelection,
 };
 
 #[derive(Debug, Clone)]
@@ -42,7 +42,7 @@ pub(in crate::backend::wayland) enum TransferEffect {
 pub(in crate::backend::wayland) enum PasteAction {
     UseLocalShapes {
         request: ClipboardPasteRequest,
-        shapes: Vec<Shape>,
+        shapes: Vec<ClipboardShape>,
         warning: Option<TransferWarning>,
     },
     ProbeSystemFingerprint {
@@ -58,7 +58,7 @@ pub(in crate::backend::wayland) enum PasteAction {
     },
     ApplyPrivateSelection {
         request: ClipboardPasteRequest,
-        shapes: Vec<Shape>,
+        shapes: Vec<ClipboardShape>,
     },
     ApplyExternalImage {
         request: ClipboardPasteRequest,
@@ -185,7 +185,7 @@ pub(in crate::backend::wayland) fn resolve_system_clipboard() -> ClipboardPasteR
 
 pub(in crate::backend::wayland) fn plan_paste_start(
     request: ClipboardPasteRequest,
-    pending_local_shapes: Option<Vec<Shape>>,
+    pending_local_shapes: Option<Vec<ClipboardShape>>,
     failed_probe: Option<FailedLocalSelectionProbe>,
 ) -> TransferPlan<PasteAction> {
     if let Some(shapes) = pending_local_shapes {
@@ -212,7 +212,7 @@ pub(in crate::backend::wayland) fn plan_after_fingerprint_probe(
     generation: u64,
     expected: Option<ClipboardFingerprint>,
     current: Option<ClipboardFingerprint>,
-    local_shapes: Option<Vec<Shape>>,
+    local_shapes: Option<Vec<ClipboardShape>>,
 ) -> TransferPlan<PasteAction> {
     match (expected.as_ref(), current.as_ref()) {
         (Some(previous), Some(current)) if previous == current => {
@@ -361,7 +361,7 @@ pub(in crate::backend::wayland) fn plan_paste_completion(
 pub(in crate::backend::wayland) struct PrivateSelectionResolution {
     pub(in crate::backend::wayland) payload_matches_local: bool,
     pub(in crate::backend::wayland) same_instance: bool,
-    pub(in crate::backend::wayland) shapes: Option<Vec<Shape>>,
+    pub(in crate::backend::wayland) shapes: Option<Vec<ClipboardShape>>,
 }
 
 fn plan_private_selection_completion(
@@ -402,7 +402,7 @@ fn supersede_request_generation(request: &ClipboardPasteRequest) -> Vec<Transfer
         .collect()
 }
 
-fn map_read_error(err: ClipboardReadError) -> ClipboardPasteResult {
+pub(super) fn map_read_error(err: ClipboardReadError) -> ClipboardPasteResult {
     match err {
         ClipboardReadError::Empty => ClipboardPasteResult::ClipboardEmpty,
         ClipboardReadError::TooLarge { limit } => ClipboardPasteResult::TooLarge { limit },
@@ -1,5 +1,5 @@
 use super::*;
-use crate::draw::Shape;
+use crate::draw::{LineStyle, Shape};
 use crate::util::Rect;
 
 #[test]
@@ -123,6 +123,24 @@ fn stale_domain_request_is_rejected_after_transport_matching() {
     ));
 }
 
+#[test]
+fn private_payload_reads_schema_one_bare_shapes_and_grouped_entries() {
+    let bare = serde_json::to_value(rect()).unwrap();
+    let payload = serde_json::json!({
+        "schema_version": 1,
+        "app_version": "test",
+        "app_instance_id": "other",
+        "copy_generation": 3,
+        "shapes": [bare.clone(), { "shape": bare, "group": 4 }],
+    });
+
+    let selection: WayscriberClipboardSelection = serde_json::from_value(payload).unwrap();
+
+    assert_eq!(selection.shapes[0], ClipboardShape::from(rect()));
+    assert_eq!(selection.shapes[1].shape, rect());
+    assert_eq!(selection.shapes[1].group, Some(4));
+}
+
 #[test]
 fn same_instance_nonmatching_generation_does_not_apply_private_selection() {
     let request = request_with_fallback_generation(Some(7));
@@ -133,7 +151,7 @@ fn same_instance_nonmatching_generation_does_not_apply_private_selection() {
             app_version: "test".to_string(),
             app_instance_id: "same".to_string(),
             copy_generation: 8,
-            shapes: vec![rect()],
+            shapes: vec![rect().into()],
         }),
     };
     let resolution = PrivateSelectionResolution {
@@ -168,13 +186,13 @@ fn different_instance_private_payload_supersedes_then_applies_shapes() {
             app_version: "test".to_string(),
             app_instance_id: "other".to_string(),
             copy_generation: 8,
-            shapes: vec![rect()],
+            shapes: vec![rect().into()],
         }),
     };
     let resolution = PrivateSelectionResolution {
         payload_matches_local: false,
         same_instance: false,
-        shapes: Some(vec![rect()]),
+        shapes: Some(vec![rect().into()]),
     };
 
     let plan = plan_paste_completion(completion, Some(42), Some(resolution));
@@ -224,5 +242,7 @@ fn rect() -> Shape {
         fill: false,
         color: crate::draw::BLACK,
         thick: 1.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     }
 }
@@ -45,6 +45,7 @@ fn preset(name: &str) -> Box<crate::config::ToolPresetConfig> {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: None,
         drag_tools: None,
     })
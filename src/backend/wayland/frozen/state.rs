@@ -9,6 +9,7 @@ use crate::backend::wayland::frozen::FrozenImage;
 use crate::backend::wayland::frozen_geometry::OutputGeometry;
 use crate::backend::wayland::portal_capture::{crop_argb, layout_token_matches};
 use crate::backend::wayland::portal_task::PortalTask;
+use crate::i18n::tr_args;
 use crate::input::InputState;
 use crate::input::state::{Toast, ToastPriority};
 
@@ -421,7 +422,10 @@ impl FrozenState {
                 Err(error) => {
                     return self.reject_pending_image(
                         input_state,
-                        format!("Freeze capture transform failed: {error}"),
+                        tr_args(
+                            "Freeze capture transform failed: {error}",
+                            &[("error", &error.to_string())],
+                        ),
                     );
                 }
             };
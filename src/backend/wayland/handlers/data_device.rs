@@ -0,0 +1,136 @@
+// Routes drag-and-drop events from the seat's data device into the overlay; the
+// logic lives in state/drag_drop.rs. The only data source Wayscriber creates is
+// the copied-selection clipboard source (state/clipboard/selection_source.rs);
+// it never starts drags, so the drag callbacks have nothing to do.
+use smithay_client_toolkit::data_device_manager::{
+    WritePipe,
+    data_device::DataDeviceHandler,
+    data_offer::{DataOfferHandler, DragOffer},
+    data_source::DataSourceHandler,
+};
+use wayland_client::{
+    Connection, QueueHandle,
+    protocol::{
+        wl_data_device::WlDataDevice, wl_data_device_manager::DndAction,
+        wl_data_source::WlDataSource, wl_surface::WlSurface,
+    },
+};
+
+use super::super::state::WaylandState;
+
+impl DataDeviceHandler for WaylandState {
+    fn enter(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        data_device: &WlDataDevice,
+        x: f64,
+        y: f64,
+        wl_surface: &WlSurface,
+    ) {
+        self.handle_drag_enter(data_device, x, y, wl_surface);
+    }
+
+    fn leave(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _data_device: &WlDataDevice) {
+        self.handle_drag_leave();
+    }
+
+    fn motion(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _data_device: &WlDataDevice,
+        x: f64,
+        y: f64,
+    ) {
+        self.handle_drag_motion(x, y);
+    }
+
+    // Clipboard reads go through wl-paste, which sees the selection directly.
+    fn selection(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _data_device: &WlDataDevice,
+    ) {
+    }
+
+    fn drop_performed(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        data_device: &WlDataDevice,
+    ) {
+        self.handle_drag_drop(data_device);
+    }
+}
+
+impl DataOfferHandler for WaylandState {
+    fn source_actions(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        offer: &mut DragOffer,
+        actions: DndAction,
+    ) {
+        // Dropped content is always copied; the source keeps its original.
+        if actions.contains(DndAction::Copy) && self.input_state.drop_target().is_some() {
+            offer.set_actions(DndAction::Copy, DndAction::Copy);
+        }
+    }
+
+    fn selected_action(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _offer: &mut DragOffer,
+        _actions: DndAction,
+    ) {
+    }
+}
+
+impl DataSourceHandler for WaylandState {
+    fn accept_mime(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _source: &WlDataSource,
+        _mime: Option<String>,
+    ) {
+    }
+
+    fn send_request(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        source: &WlDataSource,
+        mime: String,
+        fd: WritePipe,
+    ) {
+        self.handle_selection_send_request(source, mime, fd);
+    }
+
+    fn cancelled(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, source: &WlDataSource) {
+        self.handle_selection_source_cancelled(source);
+    }
+
+    fn dnd_dropped(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _source: &WlDataSource) {
+    }
+
+    fn dnd_finished(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _source: &WlDataSource,
+    ) {
+    }
+
+    fn action(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _source: &WlDataSource,
+        _action: DndAction,
+    ) {
+    }
+}
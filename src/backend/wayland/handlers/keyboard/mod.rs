@@ -141,9 +141,11 @@ impl KeyboardHandler for WaylandState {
         conn: &Connection,
         qh: &QueueHandle<Self>,
         _keyboard: &wl_keyboard::WlKeyboard,
-        _serial: u32,
+        serial: u32,
         event: KeyEvent,
     ) {
+        // A copy shortcut sets the clipboard selection with this serial.
+        self.set_last_activation_serial(Some(serial));
         // Block keybinds until overlay is fully ready (prevents Ctrl+W leaking to apps)
         if !self.is_overlay_ready() {
             debug!("Ignoring key press before overlay ready");
@@ -191,6 +193,11 @@ impl KeyboardHandler for WaylandState {
             self.input_state.needs_redraw = true;
             return;
         }
+        if matches!(key, Key::Escape) && self.input_state.magnifier_active() {
+            self.input_state.close_magnifier();
+            self.handle_pending_magnifier_source();
+            return;
+        }
         if self.zoom.is_engaged() {
             match key {
                 Key::Escape => {
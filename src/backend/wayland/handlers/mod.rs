@@ -1,9 +1,9 @@
 // Aggregates smithay handler implementations split across focused submodules and
 // wires them to `WaylandState` via the delegate macros.
 use smithay_client_toolkit::{
-    delegate_compositor, delegate_keyboard, delegate_layer, delegate_output, delegate_pointer,
-    delegate_pointer_constraints, delegate_registry, delegate_relative_pointer, delegate_seat,
-    delegate_shm, delegate_touch, delegate_xdg_shell, delegate_xdg_window,
+    delegate_compositor, delegate_data_device, delegate_keyboard, delegate_layer, delegate_output,
+    delegate_pointer, delegate_pointer_constraints, delegate_registry, delegate_relative_pointer,
+    delegate_seat, delegate_shm, delegate_touch, delegate_xdg_shell, delegate_xdg_window,
 };
 
 use super::state::WaylandState;
@@ -21,10 +21,12 @@ delegate_relative_pointer!(WaylandState);
 delegate_registry!(WaylandState);
 delegate_xdg_shell!(WaylandState);
 delegate_xdg_window!(WaylandState);
+delegate_data_device!(WaylandState);
 
 mod activation;
 mod buffer;
 mod compositor;
+mod data_device;
 mod ext_image_copy;
 pub(in crate::backend::wayland) mod keyboard;
 mod layer;
@@ -89,6 +89,18 @@ impl WaylandState {
             }
             return;
         }
+        // A plain wheel over the lens changes its magnification instead of
+        // the stroke thickness.
+        if self.input_state.magnifier_active()
+            && !self.input_state.modifiers.shift
+            && !self.input_state.modifiers.ctrl
+            && !self.input_state.modifiers.alt
+        {
+            if scroll_direction != 0 {
+                self.input_state.step_magnifier_zoom(scroll_direction < 0);
+            }
+            return;
+        }
 
         match scroll_direction.cmp(&0) {
             std::cmp::Ordering::Greater if self.input_state.modifiers.shift => {
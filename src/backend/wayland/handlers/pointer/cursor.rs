@@ -188,6 +188,7 @@ impl WaylandState {
                     }
                     SelectionHandle::Top | SelectionHandle::Bottom => CursorIcon::NsResize,
                     SelectionHandle::Left | SelectionHandle::Right => CursorIcon::EwResize,
+                    SelectionHandle::Rotate => CursorIcon::Grabbing,
                 };
             }
             // Idle - check for hover contexts
@@ -217,6 +218,7 @@ impl WaylandState {
                 SelectionHandle::TopRight | SelectionHandle::BottomLeft => CursorIcon::NeswResize,
                 SelectionHandle::Top | SelectionHandle::Bottom => CursorIcon::NsResize,
                 SelectionHandle::Left | SelectionHandle::Right => CursorIcon::EwResize,
+                SelectionHandle::Rotate => CursorIcon::Grab,
             };
         }
 
@@ -67,6 +67,13 @@ impl SeatHandler for WaylandState {
                     }
                 }
             }
+            // Drags follow the pointer, so the first pointer seat receives them.
+            if self.data_device.is_none()
+                && let Some(manager) = &self.data_device_manager
+            {
+                self.data_device = Some(manager.get_data_device(qh, &seat));
+                debug!("Data device created for drag-and-drop");
+            }
         }
 
         if capability == Capability::Touch {
@@ -118,6 +125,14 @@ impl SeatHandler for WaylandState {
 
     fn remove_seat(&mut self, _conn: &Connection, qh: &QueueHandle<Self>, seat: wl_seat::WlSeat) {
         self.remove_owned_text_input(&seat, qh);
+        if self
+            .data_device
+            .as_ref()
+            .is_some_and(|device| device.data().seat() == &seat)
+        {
+            self.data_device = None;
+            self.input_state.clear_drop_target();
+        }
         debug!("Seat removed");
     }
 }
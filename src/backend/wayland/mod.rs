@@ -33,11 +33,18 @@ pub(crate) use toolbar::view::top::build_top_view as build_top_toolbar_view;
 pub(crate) use toolbar::view::top::plan_top_strip;
 mod zoom;
 
+// `wayscriber render` builds its exports with the overlay's board snapshot
+// code, without a Wayland connection.
+pub(crate) use state::{
+    BoardPdfExportBuildContext, PdfExportScope, backdrop_from_background,
+    build_board_pdf_export_snapshot,
+};
+
 pub use backend::WaylandBackend;
 pub(crate) use backend::runtime_wake::{RuntimeWakeHandle, RuntimeWakeSource};
 pub(in crate::backend::wayland) use runtime_operation::{
     RuntimeOperationController, RuntimeOperationIdSource, RuntimeOperationPoll,
-    RuntimeOperationSubmitFailure,
+    RuntimeOperationSubmitError, RuntimeOperationSubmitFailure,
 };
 #[cfg(feature = "tablet-input")]
 pub use tablet_types::TabletToolType;
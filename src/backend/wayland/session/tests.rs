@@ -1,8 +1,8 @@
 use super::*;
 use crate::config::{Action, BoardsConfig, Config, PresenterModeConfig, Shortcut};
 use crate::draw::{
-    Color, EraserKind, FontDescriptor, Frame, PageDeleteOutcome, REGULAR_POLYGON_DEFAULT_SIDES,
-    Shape, ShapeId,
+    Color, EraserKind, FontDescriptor, Frame, LineStyle, PageDeleteOutcome,
+    REGULAR_POLYGON_DEFAULT_SIDES, Shape, ShapeId,
 };
 use crate::env_vars::{CATALOG_HOOKS_TEST_ENV, XDG_DATA_HOME_ENV};
 use crate::input::{
@@ -153,6 +153,7 @@ fn add_line(input: &mut InputState, x2: i32) -> ShapeId {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     })
 }
 
@@ -195,6 +196,7 @@ fn board_snapshot(id: &str, x2: i32) -> stored_session::BoardSnapshot {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
     stored_session::BoardSnapshot {
         id: id.to_string(),
@@ -218,6 +220,7 @@ fn sample_tool_state() -> stored_session::ToolStateSnapshot {
         eraser_kind: EraserKind::Circle,
         eraser_mode: EraserMode::Brush,
         blur_style: Default::default(),
+        line_style: Default::default(),
         recent_colors: Vec::new(),
         marker_opacity: Some(0.32),
         fill_enabled: Some(false),
@@ -1263,6 +1266,7 @@ fn runtime_open_saves_current_after_canceling_active_text_edit() {
         font_descriptor: input.font_descriptor.clone(),
         background_enabled: input.text_background_enabled,
         wrap_width: Some(180),
+        rotation: 0.0,
     });
     input.set_selection(vec![shape_id]);
     assert!(input.edit_selected_text());
@@ -1417,6 +1421,8 @@ fn runtime_open_current_save_failure_preserves_spatial_index_for_active_selectio
         fill: false,
         color: input.current_color,
         thick: input.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     input.ensure_spatial_index_for_active_frame();
     assert!(input.has_spatial_index());
@@ -1482,6 +1488,7 @@ fn runtime_open_clears_stale_selection_and_hit_cache() {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
     input.set_selection(vec![old_shape_id]);
     assert!(input.has_selection());
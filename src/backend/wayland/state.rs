@@ -6,6 +6,7 @@ use smithay_client_toolkit::seat::pointer::CursorIcon;
 use smithay_client_toolkit::{
     activation::{ActivationHandler, ActivationState, RequestData},
     compositor::CompositorState,
+    data_device_manager::{DataDeviceManagerState, data_device::DataDevice, data_offer::DragOffer},
     globals::ProvidesBoundGlobal,
     output::OutputState,
     registry::RegistryState,
@@ -23,6 +24,7 @@ use smithay_client_toolkit::{
 };
 use std::{
     collections::VecDeque,
+    path::PathBuf,
     time::{Duration, Instant},
 };
 use wayland_client::{
@@ -58,6 +60,7 @@ use crate::{
         CaptureDestination, CaptureManager, DesktopBackdropCaptureRequest,
         DesktopBackdropCaptureResult, DesktopBackdropGeometry, DesktopBackdropOutputGeometry,
         ImageFormatMetadata, ImageOperationKind, RenderedDocument,
+        encode::{ImageEncoding, RasterFormat},
         file::{FileSaveConfig, expand_tilde},
         types::CaptureType,
     },
@@ -69,6 +72,8 @@ use crate::{
     ui::toolbar::{ToolbarBindingHints, ToolbarEvent, ToolbarSnapshot},
 };
 
+use self::capture::RecordingState;
+use self::clipboard::OwnedSelectionClipboard;
 use self::data::{MoveDrag, StateData};
 pub use self::data::{
     MoveDragKind, OverlaySuppression, OverlaySuppressionKeyboardPolicy, XdgFrozenFullscreenState,
@@ -76,7 +81,7 @@ pub use self::data::{
 use super::{
     RuntimeOperationController, RuntimeOperationIdSource,
     capture::{CapturePreflightRequest, CaptureState, PendingPdfExport},
-    clipboard::{ClipboardPasteCompletion, ClipboardPublishCompletion},
+    clipboard::{ClipboardPasteCompletion, ClipboardPublishCompletion, drop::DropPayload},
     frozen::{ExtImageCopyManagers, FrozenState},
     overlay_passthrough::set_surface_clickthrough,
     session::SessionState,
@@ -98,9 +103,11 @@ mod canvas_layer;
 mod capture;
 mod clipboard;
 mod color_picker;
+mod control_socket;
 mod core;
 mod data;
 mod desktop_open;
+mod drag_drop;
 mod eyedropper;
 mod gtk_toolbar;
 mod helpers;
@@ -108,13 +115,21 @@ mod input_actions;
 mod input_hud;
 mod keybindings;
 pub(in crate::backend::wayland) use keybindings::queue_keybinding_edit;
+mod magnifier;
 mod ocr;
 mod onboarding;
+mod page_import;
 mod pdf_export;
+pub(crate) use pdf_export::{
+    BoardPdfExportBuildContext, PdfExportScope, backdrop_from_background,
+    build_board_pdf_export_snapshot,
+};
 mod perf;
 mod render;
 mod screen_image;
+mod svg_export;
 mod text_clipboard;
+mod timer;
 mod toolbar;
 #[cfg(feature = "toolbar-gtk")]
 pub(crate) use toolbar::clamp_floating_axis_offset;
@@ -149,6 +164,7 @@ pub(in crate::backend::wayland) struct WaylandGlobals {
     pub relative_pointer_state: RelativePointerState,
     pub output_state: OutputState,
     pub seat_state: SeatState,
+    pub data_device_manager: Option<DataDeviceManagerState>,
 }
 
 pub(in crate::backend::wayland) struct WaylandStateInit {
@@ -194,6 +210,13 @@ pub(super) struct WaylandState {
     pub(super) relative_pointer_state: RelativePointerState,
     pub(super) output_state: OutputState,
     pub(super) seat_state: SeatState,
+    /// `None` when the compositor lacks `wl_data_device_manager`; drag-and-drop
+    /// onto the overlay is then unavailable and copied selections go through
+    /// wl-copy as JSON only.
+    pub(super) data_device_manager: Option<DataDeviceManagerState>,
+    /// Data device for the first pointer seat; receives drag-and-drop offers
+    /// and carries the clipboard selection the overlay serves itself.
+    pub(super) data_device: Option<DataDevice>,
 
     // Surface and buffer management
     pub(super) surface: SurfaceState,
@@ -209,6 +232,12 @@ pub(super) struct WaylandState {
     /// on screen still holds the full-screen dim. One more full-damage frame is
     /// needed to wash it out, so the decision looks at the previous frame too.
     pub(super) spotlight_dimmed_last_frame: bool,
+    /// Whether the frame just rendered carried the magnifier lens; like the
+    /// spotlight flag, the frame after the lens closes still needs full damage.
+    pub(super) magnifier_shown_last_frame: bool,
+    /// Zoom was engaged by opening the magnifier over the live desktop, only
+    /// to capture the screen for the lens, and is handed back when it closes.
+    pub(super) magnifier_zoom_capture: bool,
 
     // Configuration
     pub(super) config: Config,
@@ -251,12 +280,30 @@ pub(super) struct WaylandState {
     /// already off.
     pub(super) last_input_hud_request: Option<(bool, crate::config::InputHudMode)>,
     pub(super) clipboard_publish: RuntimeOperationController<u64, ClipboardPublishCompletion>,
+    /// Copied selection the overlay is serving in every offered format; cleared
+    /// once another client takes the clipboard.
+    pub(super) owned_selection: Option<OwnedSelectionClipboard>,
     pub(super) clipboard_paste:
         RuntimeOperationController<ClipboardPasteRequest, ClipboardPasteCompletion>,
     pub(super) clipboard_hex_copy: RuntimeOperationController<String, Result<(), String>>,
     /// Desktop-open work completes off-dispatch; successful completion is what
     /// requests overlay exit, so runtime-owned broker teardown cannot race it.
     pub(super) desktop_open: RuntimeOperationController<DesktopOpenRequest, Result<(), String>>,
+    /// Document pages rasterized off-dispatch, keyed by the source path.
+    pub(super) page_import:
+        RuntimeOperationController<PathBuf, Result<Vec<crate::draw::PageBackground>, String>>,
+    /// Reads a dropped payload off-dispatch; the drag source writes it only
+    /// once the drop is requested.
+    pub(super) drop_read: RuntimeOperationController<ClipboardPasteRequest, DropPayload>,
+    /// Dropped offer kept alive until its transfer completes, then finished.
+    pub(super) pending_drop_offer: Option<DragOffer>,
+    /// Session file from the last drop, waiting for the "Open" toast chip.
+    pub(super) dropped_session_file: Option<PathBuf>,
+    /// Recording encoder; frames stream in over a channel owned by `recording`
+    /// and the outcome is the saved file.
+    pub(super) recording_worker:
+        RuntimeOperationController<crate::config::RecordingFormat, Result<PathBuf, String>>,
+    pub(super) recording: RecordingState,
     pub(super) pending_hex_copy: Option<String>,
     /// Async wl-copy pipeline for text-editor selections (Ctrl+C / Ctrl+X).
     pub(super) clipboard_text_copy:
@@ -279,6 +326,9 @@ pub(super) struct WaylandState {
     pub(super) palette_recents: crate::palette_recents::PaletteRecentsWriter,
     /// Off-dispatch writer for the three explicit `config.toml` edit gestures.
     pub(super) config_edits: crate::backend::wayland::config_edits::ConfigEditWorker,
+    /// Listener for `wayscriber ctl` and other scripts; `None` when the socket
+    /// could not be bound, which leaves the overlay fully usable by hand.
+    pub(super) control_socket: Option<crate::control_socket::ControlServer>,
     // Next scheduled tick for UI animations (toasts/highlights/preset feedback).
     pub(super) ui_animation_next_tick: Option<Instant>,
     // Animation interval; None means uncapped (render every frame while active).
@@ -127,6 +127,7 @@ impl WaylandState {
             && !self.input_state.board_is_transparent()
             && !self.zoom.active
             && !self.input_state.tour_active
+            && !self.input_state.replay_active()
             && !self.input_state.show_help
             && !self.input_state.command_palette_open
             && !self.input_state.is_board_picker_open()
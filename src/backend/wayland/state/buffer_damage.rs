@@ -38,6 +38,7 @@ pub(in crate::backend::wayland) enum FullDamageReason {
     Zoom,
     BoardPan,
     Spotlight,
+    Magnifier,
     CanvasClear,
     FirstRunOnboarding,
     InlineToolbar,
@@ -63,6 +64,7 @@ impl FullDamageReason {
             Self::Zoom => "zoom",
             Self::BoardPan => "board_pan",
             Self::Spotlight => "spotlight",
+            Self::Magnifier => "magnifier",
             Self::CanvasClear => "canvas_clear",
             Self::FirstRunOnboarding => "first_run_onboarding",
             Self::InlineToolbar => "inline_toolbar",
@@ -2,7 +2,7 @@
 //!
 //! Board pan forces full-surface damage every frame, which previously replayed
 //! every committed shape through Cairo per frame. This cache bakes the board
-//! background plus all committed shapes into a world-space offscreen surface
+//! background and grid plus all committed shapes into a world-space offscreen surface
 //! (view + margin) once, so pan frames become a single aligned blit; the cache
 //! is rebaked only when the view escapes the baked area or content changes.
 //!
@@ -12,7 +12,7 @@
 
 use log::debug;
 
-use crate::draw::{Color, ShapeId};
+use crate::draw::{BoardGrid, Color, ShapeId};
 use crate::util::Rect;
 
 use super::WaylandState;
@@ -36,6 +36,7 @@ pub(in crate::backend::wayland) struct CanvasLayerCache {
     shapes_len: usize,
     last_shape_id: Option<ShapeId>,
     background: Option<Color>,
+    grid: Option<BoardGrid>,
     board_key: (usize, usize),
     valid: bool,
 }
@@ -53,6 +54,7 @@ impl CanvasLayerCache {
             shapes_len: 0,
             last_shape_id: None,
             background: None,
+            grid: None,
             board_key: (0, 0),
             valid: false,
         }
@@ -105,6 +107,7 @@ pub(in crate::backend::wayland) fn render_committed_shape(
             h,
             strength,
             style,
+            rotation,
         } => {
             crate::draw::render_blur_rect(
                 ctx,
@@ -115,13 +118,16 @@ pub(in crate::backend::wayland) fn render_committed_shape(
                     h: *h,
                     strength: *strength,
                     style: *style,
+                    rotation: *rotation,
                     cacheable: true,
                 },
                 replay_ctx,
             );
         }
         other => {
-            crate::draw::render_shape(ctx, other);
+            crate::draw::with_appearance(ctx, drawn_shape.appearance, || {
+                crate::draw::render_shape(ctx, other);
+            });
         }
     }
 }
@@ -139,7 +145,10 @@ impl WaylandState {
     /// a board pan transform without zoom or a frozen backdrop image (whose
     /// screen-anchored transforms make world-space baking unsound).
     pub(in crate::backend::wayland) fn canvas_layer_cache_usable(&self) -> bool {
-        self.canvas_transform_active() && !self.zoom.active && self.frozen.image().is_none()
+        self.canvas_transform_active()
+            && !self.zoom.active
+            && self.frozen.image().is_none()
+            && !self.input_state.replay_active()
     }
 
     /// Ensures the layer cache covers the current view with current content,
@@ -165,6 +174,7 @@ impl WaylandState {
             crate::input::BoardBackground::Solid(color) => Some(*color),
             crate::input::BoardBackground::Transparent => None,
         };
+        let grid = self.input_state.board_grid();
         let board_key = (
             self.input_state.boards.active_index(),
             self.input_state.boards.active_page_index(),
@@ -182,6 +192,7 @@ impl WaylandState {
             && cache.shapes_len == shapes_len
             && cache.last_shape_id == last_shape_id
             && cache.background == background
+            && cache.grid == grid
             && cache.board_key == board_key;
         let covers_view = view_x >= cache.world_x
             && view_y >= cache.world_y
@@ -242,6 +253,14 @@ impl WaylandState {
             bake_ctx.scale(scale as f64, scale as f64);
             bake_ctx.translate(-(world_x as f64), -(world_y as f64));
 
+            if let Some(grid) = grid.as_ref() {
+                crate::draw::render_board_grid(&bake_ctx, grid);
+            }
+            let frame = self.input_state.boards.active_frame();
+            if let Some(page_background) = frame.background() {
+                crate::draw::render_page_background(&bake_ctx, page_background);
+            }
+
             // Erasers clear down to the baked solid background; blur rects have
             // no backdrop image in this mode (same as the direct render path).
             let replay_ctx = crate::draw::EraserReplayContext {
@@ -251,6 +270,8 @@ impl WaylandState {
                 bg_color: background,
                 logical_to_image_scale_x: 1.0,
                 logical_to_image_scale_y: 1.0,
+                page_background: frame.background(),
+                grid,
             };
 
             let bake_bounds = Rect {
@@ -259,14 +280,18 @@ impl WaylandState {
                 width: bake_w,
                 height: bake_h,
             };
-            let frame = self.input_state.boards.active_frame();
-            for drawn_shape in &frame.shapes {
-                if let Some(bbox) = drawn_shape.bounding_box()
-                    && rects_intersect(bbox, bake_bounds)
-                {
-                    render_committed_shape(&bake_ctx, drawn_shape, &replay_ctx);
-                }
-            }
+            crate::draw::render_frame_layers(
+                &bake_ctx,
+                frame,
+                &replay_ctx,
+                |drawn_shape, replay_ctx| {
+                    if let Some(bbox) = drawn_shape.bounding_box()
+                        && rects_intersect(bbox, bake_bounds)
+                    {
+                        render_committed_shape(&bake_ctx, drawn_shape, replay_ctx);
+                    }
+                },
+            );
         }
         if let Some(surface) = self.canvas_layer_cache.surface.as_ref() {
             surface.flush();
@@ -282,6 +307,7 @@ impl WaylandState {
         cache.shapes_len = shapes_len;
         cache.last_shape_id = last_shape_id;
         cache.background = background;
+        cache.grid = grid;
         cache.board_key = board_key;
         cache.valid = true;
         debug!(
@@ -1,13 +1,18 @@
 use super::*;
 use crate::backend::wayland::frozen::FrozenCaptureBackend;
-use crate::capture::{CaptureRequest, CaptureRequestId, CaptureSubmitError};
+use crate::capture::{CaptureRequest, CaptureRequestId, CaptureSubmitError, PostProcessPipeline};
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastPriority};
 
 mod backdrop;
 mod barrier;
 mod pdf;
+mod recording;
+mod replay;
+mod svg;
 
 pub(super) use barrier::OverlayCaptureBarrier;
+pub(super) use recording::RecordingState;
 
 fn should_exit_after_capture(mode: ExitAfterCaptureMode, destination: CaptureDestination) -> bool {
     let is_clipboard_only = matches!(destination, CaptureDestination::ClipboardOnly);
@@ -45,6 +50,7 @@ impl WaylandState {
                 crate::input::state::EyedropperCaptureSource::Frozen,
             );
             self.finish_pending_ocr_capture(crate::input::state::OcrCaptureSource::Frozen);
+            self.finish_pending_recording_capture();
         }
         if self.zoom.take_capture_done() {
             log::info!(
@@ -165,9 +171,19 @@ impl WaylandState {
                 save_directory: expand_tilde(&self.config.capture.save_directory),
                 filename_template: self.config.capture.filename_template.clone(),
                 format: self.config.capture.format.clone(),
+                encoding: ImageEncoding::for_format(
+                    &self.config.capture.format,
+                    &self.config.capture,
+                ),
             })
         };
 
+        let postprocess = self.capture_postprocess(match capture_type {
+            CaptureType::FullScreen => &self.config.capture.postprocess.full_screen,
+            CaptureType::ActiveWindow => &self.config.capture.postprocess.active_window,
+            CaptureType::Selection { .. } => &self.config.capture.postprocess.region,
+        });
+
         let exit_on_success = self.should_exit_after_capture(destination);
         self.capture.set_exit_on_success(exit_on_success);
 
@@ -191,6 +207,7 @@ impl WaylandState {
             capture_type,
             destination,
             save_config,
+            postprocess,
         };
 
         log::info!(
@@ -235,9 +252,12 @@ impl WaylandState {
             Some(FileSaveConfig {
                 save_directory: expand_tilde(&self.config.capture.save_directory),
                 filename_template: self.config.capture.filename_template.clone(),
-                // The delivery pipeline replaces this with the rendered
-                // image's real extension.
-                format: ImageFormatMetadata::png().extension,
+                // The PNG render is re-encoded into this format when saved.
+                format: self.config.capture.canvas_export_format.clone(),
+                encoding: ImageEncoding::for_format(
+                    &self.config.capture.canvas_export_format,
+                    &self.config.capture,
+                ),
             })
         };
 
@@ -250,7 +270,8 @@ impl WaylandState {
             destination,
             save_config,
             operation: ImageOperationKind::CanvasExport,
-            fallback_format_override: Some(ImageFormatMetadata::png()),
+            fallback_format_override: Some(self.canvas_export_format()),
+            postprocess: self.capture_postprocess(&self.config.capture.postprocess.canvas_export),
         };
 
         let submission = self
@@ -260,6 +281,29 @@ impl WaylandState {
         self.accept_capture_submission(submission, ImageOperationKind::CanvasExport);
     }
 
+    /// Format canvas exports are saved in, including the save-to-file
+    /// fallback offered when the clipboard copy fails.
+    fn canvas_export_format(&self) -> ImageFormatMetadata {
+        let extension = &self.config.capture.canvas_export_format;
+        match RasterFormat::from_extension(extension) {
+            Some(format) => ImageFormatMetadata {
+                extension: extension.to_ascii_lowercase(),
+                mime_type: format.mime_type().to_string(),
+            },
+            None => ImageFormatMetadata::png(),
+        }
+    }
+
+    /// Resolves the pipeline selected for one kind of capture in
+    /// `[capture.postprocess]`.
+    fn capture_postprocess(&self, selection: &Option<String>) -> Option<PostProcessPipeline> {
+        let id = selection.as_deref()?;
+        self.config
+            .capture
+            .pipeline(id)
+            .map(PostProcessPipeline::from_config)
+    }
+
     fn canvas_export_snapshot(&self) -> CanvasExportSnapshot {
         let (origin_x, origin_y) = self.board_view_offset();
         CanvasExportSnapshot {
@@ -306,6 +350,7 @@ impl WaylandState {
                         request.capture_type,
                         request.destination,
                         request.save_config,
+                        request.postprocess,
                     ),
                 )
             }
@@ -372,7 +417,10 @@ impl WaylandState {
         self.input_state.push_toast(
             ToastPriority::Critical,
             "capture",
-            Toast::error(format!("{} failed: {error}", operation.saved_log_label())),
+            Toast::error(tr_args(
+                "{operation} failed: {error}",
+                &[("operation", operation.saved_log_label()), ("error", error)],
+            )),
         );
     }
 }
@@ -213,6 +213,7 @@ impl WaylandState {
                     .resolved_filename_template(&self.config.capture)
             },
             format: "pdf".to_string(),
+            encoding: ImageEncoding::default(),
         }
     }
 
@@ -0,0 +1,445 @@
+//! Overlay recording to an animated GIF or APNG.
+//!
+//! Each frame is the canvas export snapshot — the frozen screen image or the
+//! solid board behind the live annotations, plus the stroke being drawn — so
+//! toolbars, toasts, and the REC badge never end up in the file. Rendering,
+//! diffing, and encoding all run on the recording worker; the event loop only
+//! pays for the snapshot. On a transparent board without a frozen image the
+//! recording freezes the screen first and releases that freeze when it stops,
+//! leaving a user-owned freeze untouched.
+
+use std::sync::Arc;
+use std::sync::mpsc::{SyncSender, TrySendError, sync_channel};
+
+use super::super::*;
+use crate::backend::wayland::{RuntimeOperationPoll, RuntimeOperationSubmitError};
+use crate::canvas_export::render_recording_frame;
+use crate::capture::recording::{RecordingMessage, run_recording};
+use crate::config::RecordingRegion;
+use crate::i18n::tr_args;
+use crate::input::state::{Toast, ToastPriority};
+
+const TOAST_SOURCE: &str = "recording";
+/// Frames waiting for the worker; a tick that finds the queue full is dropped
+/// and the previous frame simply stays on screen longer.
+const FRAME_QUEUE_CAPACITY: usize = 4;
+/// How long exit waits for the worker to encode and save a running recording.
+const SHUTDOWN_SAVE_WAIT: Duration = Duration::from_secs(30);
+const SHUTDOWN_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+#[derive(Default)]
+pub(in crate::backend::wayland) enum RecordingState {
+    #[default]
+    Idle,
+    /// Waiting for the freeze that supplies the screen image; `auto_froze`
+    /// records whether the recording requested it.
+    WaitingForFreeze {
+        auto_froze: bool,
+    },
+    Active(ActiveRecording),
+}
+
+pub(in crate::backend::wayland) struct ActiveRecording {
+    started_at: Instant,
+    next_frame_at: Instant,
+    frame_interval: Duration,
+    deadline: Instant,
+    region: Option<RecordingRegion>,
+    sender: SyncSender<RecordingMessage>,
+    auto_froze: bool,
+    /// Whole seconds last shown on the REC badge.
+    badge_secs: u64,
+    /// The frozen image as a shareable backdrop, keyed by its generation, so
+    /// the pixels are copied once per freeze rather than once per frame.
+    frozen_backdrop: Option<(u64, CanvasExportBackdropSnapshot)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StopReason {
+    Requested,
+    DurationLimit,
+    Exit,
+}
+
+impl WaylandState {
+    pub(in crate::backend::wayland) fn handle_recording_toggle(&mut self) {
+        match self.recording {
+            RecordingState::Active(_) => {
+                self.stop_recording(StopReason::Requested);
+                return;
+            }
+            RecordingState::WaitingForFreeze { auto_froze } => {
+                self.recording = RecordingState::Idle;
+                self.release_recording_freeze(auto_froze);
+                self.input_state.push_toast(
+                    ToastPriority::Info,
+                    TOAST_SOURCE,
+                    Toast::info("Recording cancelled"),
+                );
+                return;
+            }
+            RecordingState::Idle => {}
+        }
+
+        if !self.config.capture.enabled {
+            self.input_state.push_toast(
+                ToastPriority::Info,
+                TOAST_SOURCE,
+                Toast::warning("Recording is off because capture is disabled."),
+            );
+            return;
+        }
+        if self.recording_worker.is_active() {
+            self.input_state.push_toast(
+                ToastPriority::Info,
+                TOAST_SOURCE,
+                Toast::info("The previous recording is still being saved."),
+            );
+            return;
+        }
+        if !self.input_state.board_is_transparent() || self.frozen.image().is_some() {
+            self.begin_recording(false);
+            return;
+        }
+        if self.frozen.is_in_progress() {
+            // A freeze the user started is about to supply the image.
+            self.recording = RecordingState::WaitingForFreeze { auto_froze: false };
+            return;
+        }
+        if self.zoom.is_engaged() {
+            self.input_state.push_toast(
+                ToastPriority::Info,
+                TOAST_SOURCE,
+                Toast::warning("Leave zoom or freeze the screen before recording."),
+            );
+            return;
+        }
+        if !self.frozen_enabled() {
+            self.input_state.push_toast(
+                ToastPriority::Info,
+                TOAST_SOURCE,
+                Toast::warning("Recording is unavailable because screen capture is not available."),
+            );
+            return;
+        }
+        self.recording = RecordingState::WaitingForFreeze { auto_froze: true };
+        self.input_state.request_frozen_toggle();
+    }
+
+    /// Start recording once the freeze it waited for has landed.
+    pub(in crate::backend::wayland) fn finish_pending_recording_capture(&mut self) {
+        let RecordingState::WaitingForFreeze { auto_froze } = self.recording else {
+            return;
+        };
+        self.recording = RecordingState::Idle;
+        if self.frozen.image().is_some() {
+            self.begin_recording(auto_froze);
+        } else {
+            self.release_recording_freeze(auto_froze);
+            self.input_state.push_toast(
+                ToastPriority::Critical,
+                TOAST_SOURCE,
+                Toast::error("Recording could not capture the screen."),
+            );
+        }
+    }
+
+    fn begin_recording(&mut self, auto_froze: bool) {
+        let settings = &self.config.export.recording;
+        let format = settings.format;
+        let save_config = FileSaveConfig {
+            save_directory: expand_tilde(&self.config.capture.save_directory),
+            filename_template: settings.resolved_filename_template(&self.config.capture),
+            format: format.extension().to_string(),
+            encoding: ImageEncoding::default(),
+        };
+        let frame_interval = Duration::from_secs(1) / settings.fps.max(1);
+        let max_duration = Duration::from_secs(u64::from(settings.max_duration_secs.max(1)));
+        let region = settings.region;
+
+        let (sender, receiver) = sync_channel(FRAME_QUEUE_CAPACITY);
+        let submitted =
+            self.recording_worker
+                .try_submit(format, "wayscriber-recording", move || {
+                    run_recording(receiver, format, save_config).map_err(|err| err.to_string())
+                });
+        if let Err(failure) = submitted {
+            let (error, _) = failure.into_parts();
+            log::warn!("Failed to start overlay recording: {error}");
+            self.release_recording_freeze(auto_froze);
+            let message = if matches!(error, RuntimeOperationSubmitError::Busy { .. }) {
+                "The previous recording is still being saved."
+            } else {
+                "Could not start the recording."
+            };
+            self.input_state.push_toast(
+                ToastPriority::Critical,
+                TOAST_SOURCE,
+                Toast::error(message),
+            );
+            return;
+        }
+
+        let now = Instant::now();
+        log::info!(
+            "Recording overlay as {format:?} at {} fps (limit {}s)",
+            settings.fps,
+            max_duration.as_secs()
+        );
+        self.recording = RecordingState::Active(ActiveRecording {
+            started_at: now,
+            next_frame_at: now,
+            frame_interval,
+            deadline: now + max_duration,
+            region,
+            sender,
+            auto_froze,
+            badge_secs: 0,
+            frozen_backdrop: None,
+        });
+        self.input_state.set_recording_started_at(Some(now));
+        self.input_state.push_toast(
+            ToastPriority::Info,
+            TOAST_SOURCE,
+            Toast::info("Recording started"),
+        );
+        self.poll_recording(now);
+    }
+
+    /// Capture the frames that are due and enforce the duration limit.
+    pub(in crate::backend::wayland) fn poll_recording(&mut self, now: Instant) {
+        let RecordingState::Active(active) = &mut self.recording else {
+            return;
+        };
+        if now >= active.deadline {
+            self.stop_recording(StopReason::DurationLimit);
+            return;
+        }
+        let secs = now.duration_since(active.started_at).as_secs();
+        if secs != active.badge_secs {
+            active.badge_secs = secs;
+            self.input_state.needs_redraw = true;
+        }
+        if now < active.next_frame_at {
+            return;
+        }
+        // A stalled loop skips the missed ticks instead of bursting them.
+        active.next_frame_at += active.frame_interval;
+        if active.next_frame_at <= now {
+            active.next_frame_at = now + active.frame_interval;
+        }
+        let at = now.duration_since(active.started_at);
+        let region = active.region;
+
+        let snapshot = self.recording_frame_snapshot();
+        let RecordingState::Active(active) = &mut self.recording else {
+            return;
+        };
+        let message = RecordingMessage::Frame {
+            at,
+            render: Box::new(move || render_recording_frame(&snapshot, region)),
+        };
+        match active.sender.try_send(message) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_)) => {
+                log::debug!("Recording worker is behind; dropping a frame");
+            }
+            Err(TrySendError::Disconnected(_)) => {
+                log::warn!("Recording worker stopped unexpectedly");
+                self.stop_recording(StopReason::Requested);
+            }
+        }
+    }
+
+    pub(in crate::backend::wayland) fn recording_timeout(&self, now: Instant) -> Option<Duration> {
+        let RecordingState::Active(active) = &self.recording else {
+            return None;
+        };
+        let next_badge_tick =
+            active.started_at + Duration::from_secs(active.badge_secs.saturating_add(1));
+        let next = active
+            .next_frame_at
+            .min(active.deadline)
+            .min(next_badge_tick);
+        Some(next.saturating_duration_since(now))
+    }
+
+    fn recording_frame_snapshot(&mut self) -> CanvasExportSnapshot {
+        let mut snapshot = self.canvas_export_snapshot();
+        if self.input_state.board_is_transparent()
+            && let Some(backdrop) = self.recording_frozen_backdrop()
+        {
+            snapshot.backdrop = backdrop;
+        }
+        let (mouse_x, mouse_y) = self.current_mouse();
+        let (world_x, world_y) = self.canvas_world_coords(mouse_x as f64, mouse_y as f64);
+        if let Some(shape) = self
+            .input_state
+            .provisional_tool_stroke(world_x, world_y)
+            .to_shape()
+        {
+            snapshot.board.frame.add_shape(shape);
+        }
+        snapshot
+    }
+
+    fn recording_frozen_backdrop(&mut self) -> Option<CanvasExportBackdropSnapshot> {
+        let image = self.frozen.image()?;
+        let generation = self.frozen.image_generation();
+        let logical_width = f64::from(self.surface.width().max(1));
+        let logical_height = f64::from(self.surface.height().max(1));
+        let RecordingState::Active(active) = &mut self.recording else {
+            return None;
+        };
+        if let Some((cached, backdrop)) = &active.frozen_backdrop
+            && *cached == generation
+        {
+            return Some(backdrop.clone());
+        }
+        let backdrop = CanvasExportBackdropSnapshot::PersistedImage {
+            data: Arc::from(image.data.as_slice()),
+            width: image.width as i32,
+            height: image.height as i32,
+            stride: image.stride,
+            logical_to_image_scale_x: f64::from(image.width) / logical_width,
+            logical_to_image_scale_y: f64::from(image.height) / logical_height,
+        };
+        active.frozen_backdrop = Some((generation, backdrop.clone()));
+        Some(backdrop)
+    }
+
+    fn stop_recording(&mut self, reason: StopReason) {
+        let RecordingState::Active(active) = std::mem::take(&mut self.recording) else {
+            return;
+        };
+        let at = active.started_at.elapsed();
+        if let Err(err) = active.sender.try_send(RecordingMessage::Stop { at }) {
+            // Dropping the sender below still ends the recording; the last
+            // frame then just closes the animation without extra hold time.
+            log::debug!("Recording stop message not queued: {err}");
+        }
+        drop(active.sender);
+        self.input_state.set_recording_started_at(None);
+        self.release_recording_freeze(active.auto_froze);
+        log::info!(
+            "Recording stopped after {:.1}s ({reason:?})",
+            at.as_secs_f64()
+        );
+
+        let message = match reason {
+            StopReason::DurationLimit => tr_args(
+                "Recording reached its {seconds}s limit; saving...",
+                &[(
+                    "seconds",
+                    &self.config.export.recording.max_duration_secs.to_string(),
+                )],
+            ),
+            StopReason::Requested | StopReason::Exit => "Saving recording...".to_string(),
+        };
+        self.input_state
+            .push_toast(ToastPriority::Info, TOAST_SOURCE, Toast::info(message));
+    }
+
+    /// Give back the freeze a recording created. A user-owned freeze is never
+    /// touched here.
+    fn release_recording_freeze(&mut self, auto_froze: bool) {
+        if !auto_froze {
+            return;
+        }
+        self.restore_xdg_after_frozen();
+        if self.frozen.is_in_progress() {
+            self.frozen.cancel(&mut self.input_state);
+            self.exit_overlay_suppression(OverlaySuppression::Frozen);
+        } else if self.input_state.frozen_active() {
+            self.frozen.unfreeze(&mut self.input_state);
+        }
+    }
+
+    pub(in crate::backend::wayland) fn poll_recording_completion(&mut self) {
+        match self.recording_worker.poll() {
+            RuntimeOperationPoll::Idle | RuntimeOperationPoll::Pending { .. } => {}
+            RuntimeOperationPoll::Ready { outcome, .. } => match outcome {
+                Ok(path) => {
+                    log::info!("Recording saved to: {}", path.display());
+                    self.publish_capture_saved(&path);
+                    let open_folder_binding = self
+                        .config
+                        .keybindings
+                        .capture
+                        .open_capture_folder
+                        .first()
+                        .map(|binding| binding.as_str());
+                    self.input_state
+                        .set_capture_feedback(Some(&path), false, open_folder_binding);
+                    crate::notification::send_notification_async(
+                        &self.tokio_handle,
+                        "Recording saved".to_string(),
+                        path.file_name().map_or_else(
+                            || path.display().to_string(),
+                            |name| format!("Saved as {}", name.to_string_lossy()),
+                        ),
+                        Some("camera-video".to_string()),
+                    );
+                }
+                Err(message) => self.report_recording_failure(message),
+            },
+            RuntimeOperationPoll::ProducerFailed { reason, .. } => {
+                self.report_recording_failure(tr_args(
+                    "Recording worker failed: {reason}",
+                    &[("reason", &reason)],
+                ));
+            }
+            RuntimeOperationPoll::Disconnected { .. } => {
+                self.report_recording_failure("Recording worker disconnected".to_string());
+            }
+        }
+    }
+
+    fn report_recording_failure(&mut self, message: String) {
+        log::warn!("Recording failed: {message}");
+        // The worker can fail before the user stops; end the session so the
+        // REC badge does not keep claiming a recording that is gone.
+        if matches!(self.recording, RecordingState::Active(_))
+            && let RecordingState::Active(active) = std::mem::take(&mut self.recording)
+        {
+            self.input_state.set_recording_started_at(None);
+            self.release_recording_freeze(active.auto_froze);
+        }
+        self.input_state.push_toast(
+            ToastPriority::Critical,
+            TOAST_SOURCE,
+            Toast::error(tr_args(
+                "Recording not saved: {message}",
+                &[("message", &message)],
+            )),
+        );
+    }
+
+    /// Stop a running recording on exit and wait, briefly, for it to be saved.
+    pub(in crate::backend::wayland) fn shutdown_recording(&mut self) {
+        self.stop_recording(StopReason::Exit);
+        let waited_since = Instant::now();
+        while self.recording_worker.is_active() {
+            if waited_since.elapsed() >= SHUTDOWN_SAVE_WAIT {
+                log::warn!("Recording was still being saved at exit; abandoning it");
+                return;
+            }
+            match self.recording_worker.poll() {
+                RuntimeOperationPoll::Pending { .. } => {
+                    std::thread::sleep(SHUTDOWN_POLL_INTERVAL);
+                }
+                RuntimeOperationPoll::Ready {
+                    outcome: Ok(path), ..
+                } => log::info!("Recording saved to: {}", path.display()),
+                RuntimeOperationPoll::Ready {
+                    outcome: Err(message),
+                    ..
+                } => log::warn!("Recording not saved at exit: {message}"),
+                RuntimeOperationPoll::ProducerFailed { reason, .. } => {
+                    log::warn!("Recording worker failed at exit: {reason}");
+                }
+                RuntimeOperationPoll::Idle | RuntimeOperationPoll::Disconnected { .. } => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use super::super::*;
+use crate::canvas_export::ReplayExportSnapshot;
+use crate::capture::FrameSequenceExportRequest;
+use crate::input::state::{Toast, ToastPriority};
+
+impl WaylandState {
+    pub(in crate::backend::wayland) fn handle_replay_export_action(&mut self) {
+        if self.capture.is_in_progress() {
+            log::warn!(
+                "Replay frame export requested while another image operation is running; ignoring"
+            );
+            return;
+        }
+
+        let snapshot = ReplayExportSnapshot::new(
+            self.canvas_export_snapshot(),
+            self.config.export.replay.frames_per_shape,
+        );
+        let frame_count = snapshot.frame_count();
+        if frame_count == 0 {
+            self.input_state.push_toast(
+                ToastPriority::Info,
+                "replay",
+                Toast::info("Nothing to replay on this page"),
+            );
+            return;
+        }
+
+        let save_config = FileSaveConfig {
+            save_directory: expand_tilde(&self.config.capture.save_directory),
+            filename_template: self
+                .config
+                .export
+                .replay
+                .resolved_filename_template(&self.config.capture),
+            format: "png".to_string(),
+            encoding: ImageEncoding::default(),
+        };
+        let operation = ImageOperationKind::ReplayFrameExport;
+        self.capture
+            .set_exit_on_success(self.should_exit_after_capture(CaptureDestination::FileOnly));
+        self.capture.mark_in_progress();
+
+        // Every frame is rendered on the capture worker; the event loop only
+        // pays for the page snapshot.
+        let request = FrameSequenceExportRequest {
+            frame_count,
+            render_frame: Box::new(move |index| snapshot.render_frame_png(index)),
+            save_config,
+            operation,
+        };
+        let submission = self
+            .capture
+            .manager_mut()
+            .request_frame_sequence_export(request);
+        self.accept_capture_submission(submission, operation);
+    }
+}
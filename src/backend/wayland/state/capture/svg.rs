@@ -0,0 +1,80 @@
+use super::super::*;
+use crate::canvas_export::{render_board_svg, render_canvas_svg};
+
+impl WaylandState {
+    pub(in crate::backend::wayland) fn handle_svg_export_action(&mut self, action: Action) {
+        if self.capture.is_in_progress() {
+            log::warn!(
+                "SVG export action {:?} requested while another image operation is running; ignoring",
+                action
+            );
+            return;
+        }
+
+        let (operation, filename_template) = match action {
+            Action::ExportCanvasSvgFile => (
+                ImageOperationKind::CanvasSvgExport,
+                self.config
+                    .export
+                    .svg
+                    .resolved_filename_template(&self.config.capture),
+            ),
+            Action::ExportBoardSvgFile => (
+                ImageOperationKind::BoardSvgExport,
+                self.config
+                    .export
+                    .svg
+                    .resolved_board_filename_template(&self.config.capture),
+            ),
+            _ => {
+                log::error!(
+                    "Non-SVG-export action passed to handle_svg_export_action: {:?}",
+                    action
+                );
+                return;
+            }
+        };
+
+        // Snapshot on the event loop, render on the capture worker: text
+        // layout for a many-page board is not free.
+        let render: crate::capture::DocumentRenderJob =
+            if operation == ImageOperationKind::CanvasSvgExport {
+                let snapshot = self.canvas_export_snapshot();
+                Box::new(move || render_canvas_svg(&snapshot).map(svg_document))
+            } else {
+                let snapshot = self.board_svg_export_snapshot();
+                Box::new(move || render_board_svg(&snapshot).map(svg_document))
+            };
+
+        let save_config = FileSaveConfig {
+            save_directory: expand_tilde(&self.config.capture.save_directory),
+            filename_template,
+            format: "svg".to_string(),
+            encoding: ImageEncoding::default(),
+        };
+        let destination = CaptureDestination::FileOnly;
+        self.capture
+            .set_exit_on_success(self.should_exit_after_capture(destination));
+        self.capture.mark_in_progress();
+
+        let request = crate::capture::RenderedDocumentDeliveryRequest {
+            render,
+            destination,
+            save_config: Some(save_config),
+            operation,
+        };
+        let submission = self
+            .capture
+            .manager_mut()
+            .request_rendered_document_delivery(request);
+        self.accept_capture_submission(submission, operation);
+    }
+}
+
+fn svg_document(bytes: Vec<u8>) -> RenderedDocument {
+    RenderedDocument {
+        bytes,
+        extension: "svg".to_string(),
+        mime_type: "image/svg+xml".to_string(),
+    }
+}
@@ -9,17 +9,25 @@ use crate::backend::wayland::{
         transfer,
     },
 };
+use crate::draw::EmbeddedImage;
+use crate::i18n::tr_args;
 use crate::input::state::ClipboardPasteRequest;
 use crate::input::state::{Toast, ToastPriority};
 use std::time::{Duration, Instant};
 
+mod selection_source;
 mod session_paste;
 
+pub(super) use selection_source::OwnedSelectionClipboard;
 use session_paste::{PastePersistenceDecision, SessionPasteWarning};
 
 impl WaylandState {
     pub(in crate::backend::wayland) fn drain_clipboard_requests(&mut self) {
+        // A copy the overlay can serve itself waits for
+        // `own_pending_selection_clipboard`, which has the queue handle its
+        // data source needs.
         if !self.clipboard_publish.is_active()
+            && !self.can_own_selection_clipboard()
             && let Some(request) = self.input_state.take_pending_selection_clipboard_publish()
         {
             self.start_selection_clipboard_publish(request.generation, request.payload_json);
@@ -287,56 +295,7 @@ impl WaylandState {
                 }
             }
             PasteAction::ApplyExternalImage { request, image } => {
-                let mime_type = image.mime_type.clone();
-                let image_width = image.width;
-                let image_height = image.height;
-                let image_bytes = image.bytes.len();
-                let persistence_warning = match self
-                    .session_paste_preflight_message(&request, &image)
-                {
-                    Ok(PastePersistenceDecision::Allow { warning }) => warning,
-                    Ok(PastePersistenceDecision::Block { warning }) => {
-                        log::warn!(
-                            "External image paste request {} rejected by session size preflight: {}",
-                            request.id,
-                            warning.log_detail
-                        );
-                        self.show_session_paste_warning(warning);
-                        self.input_state.trigger_blocked_feedback();
-                        self.input_state.finish_clipboard_paste_request(request.id);
-                        return;
-                    }
-                    Err(err) => {
-                        log::warn!(
-                            "External image paste request {} could not be checked against session size limits: {}",
-                            request.id,
-                            err
-                        );
-                        Some(SessionPasteWarning::toast_only(
-                            "Could not check session size; this image may not persist.",
-                        ))
-                    }
-                };
-                let pasted = self
-                    .input_state
-                    .paste_external_image_from_request(&request, image);
-                log::info!(
-                    "Applied external image paste request {} to board '{}' page {}: success={}, mime={}, dimensions={}x{}, bytes={}",
-                    request.id,
-                    request.target_board_id,
-                    request.target_page_index,
-                    pasted,
-                    mime_type,
-                    image_width,
-                    image_height,
-                    image_bytes
-                );
-                if !pasted {
-                    self.input_state.trigger_blocked_feedback();
-                } else if let Some(warning) = persistence_warning {
-                    self.show_session_paste_warning(warning);
-                }
-                self.input_state.finish_clipboard_paste_request(request.id);
+                self.apply_external_image_paste(request, image);
             }
             PasteAction::TryFreshLocalFallbackOrWarn {
                 request,
@@ -364,6 +323,63 @@ impl WaylandState {
         }
     }
 
+    /// Adds an external image at the request anchor after the session size
+    /// preflight, then finishes the request.
+    pub(super) fn apply_external_image_paste(
+        &mut self,
+        request: ClipboardPasteRequest,
+        image: EmbeddedImage,
+    ) {
+        let mime_type = image.mime_type.clone();
+        let image_width = image.width;
+        let image_height = image.height;
+        let image_bytes = image.bytes.len();
+        let persistence_warning = match self.session_paste_preflight_message(&request, &image) {
+            Ok(PastePersistenceDecision::Allow { warning }) => warning,
+            Ok(PastePersistenceDecision::Block { warning }) => {
+                log::warn!(
+                    "External image paste request {} rejected by session size preflight: {}",
+                    request.id,
+                    warning.log_detail
+                );
+                self.show_session_paste_warning(warning);
+                self.input_state.trigger_blocked_feedback();
+                self.input_state.finish_clipboard_paste_request(request.id);
+                return;
+            }
+            Err(err) => {
+                log::warn!(
+                    "External image paste request {} could not be checked against session size limits: {}",
+                    request.id,
+                    err
+                );
+                Some(SessionPasteWarning::toast_only(
+                    "Could not check session size; this image may not persist.",
+                ))
+            }
+        };
+        let pasted = self
+            .input_state
+            .paste_external_image_from_request(&request, image);
+        log::info!(
+            "Applied external image paste request {} to board '{}' page {}: success={}, mime={}, dimensions={}x{}, bytes={}",
+            request.id,
+            request.target_board_id,
+            request.target_page_index,
+            pasted,
+            mime_type,
+            image_width,
+            image_height,
+            image_bytes
+        );
+        if !pasted {
+            self.input_state.trigger_blocked_feedback();
+        } else if let Some(warning) = persistence_warning {
+            self.show_session_paste_warning(warning);
+        }
+        self.input_state.finish_clipboard_paste_request(request.id);
+    }
+
     /// Probes the system clipboard fingerprint on the paste worker. The probe
     /// spawns `wl-paste` twice with a 300 ms timeout each; running it inline
     /// stalled event dispatch for up to ~600 ms.
@@ -529,9 +545,9 @@ impl WaylandState {
             TransferWarning::TooLarge { limit } => self.input_state.push_toast(
                 ToastPriority::Info,
                 "clipboard",
-                Toast::warning(format!(
-                    "Clipboard data is too large to paste (limit {} MB).",
-                    limit / 1024 / 1024
+                Toast::warning(tr_args(
+                    "Clipboard data is too large to paste (limit {limit} MB).",
+                    &[("limit", &(limit / 1024 / 1024).to_string())],
                 )),
             ),
             TransferWarning::TooManyPixels {
@@ -541,9 +557,13 @@ impl WaylandState {
             } => self.input_state.push_toast(
                 ToastPriority::Info,
                 "clipboard",
-                Toast::warning(format!(
-                    "Clipboard image is too large ({}x{}, limit {} pixels).",
-                    width, height, limit
+                Toast::warning(tr_args(
+                    "Clipboard image is too large ({width}x{height}, limit {limit} pixels).",
+                    &[
+                        ("width", &width.to_string()),
+                        ("height", &height.to_string()),
+                        ("limit", &limit.to_string()),
+                    ],
                 )),
             ),
             TransferWarning::DecodeFailed => self.input_state.push_toast(
@@ -0,0 +1,164 @@
+//! Serving a copied selection from the overlay's own `wl_data_source`.
+//!
+//! wl-copy can publish one MIME type, and only with the bytes in hand. Owning
+//! the selection lets the overlay advertise the private JSON next to PNG, SVG
+//! and plain text, and render each form only when another client asks for it.
+
+use std::fs::File;
+use std::os::fd::OwnedFd;
+use std::sync::Arc;
+
+use smithay_client_toolkit::data_device_manager::{WritePipe, data_source::CopyPasteSource};
+use wayland_client::{QueueHandle, protocol::wl_data_source::WlDataSource};
+
+use super::super::WaylandState;
+use crate::backend::wayland::clipboard::{
+    MAX_CLIPBOARD_SELECTION_BYTES,
+    selection_formats::{SelectionClipboardContent, SelectionExportStyle, send_selection_content},
+    transfer,
+};
+use crate::canvas_export::{CanvasExportBackdropSnapshot, SpotlightPassSnapshot};
+use crate::draw::WHITE;
+use crate::input::BoardBackground;
+
+/// The clipboard source the overlay currently owns and what it serves.
+pub(in crate::backend::wayland) struct OwnedSelectionClipboard {
+    source: CopyPasteSource,
+    content: Arc<SelectionClipboardContent>,
+}
+
+impl WaylandState {
+    /// Whether a copied selection can be served in-process. Without a data
+    /// device or an input serial to set the selection with, copies fall back
+    /// to publishing the JSON through wl-copy.
+    pub(super) fn can_own_selection_clipboard(&self) -> bool {
+        self.data_device_manager.is_some()
+            && self.data_device.is_some()
+            && self.last_activation_serial().is_some()
+    }
+
+    pub(in crate::backend::wayland) fn own_pending_selection_clipboard(
+        &mut self,
+        qh: &QueueHandle<Self>,
+    ) {
+        if self.clipboard_publish.is_active() || !self.can_own_selection_clipboard() {
+            return;
+        }
+        let Some(request) = self.input_state.take_pending_selection_clipboard_publish() else {
+            return;
+        };
+        if request.payload_json.len() > MAX_CLIPBOARD_SELECTION_BYTES {
+            // The wl-copy path owns the too-large warning and local fallback.
+            self.start_selection_clipboard_publish(request.generation, request.payload_json);
+            return;
+        }
+
+        let generation = request.generation;
+        let content = Arc::new(SelectionClipboardContent::new(
+            generation,
+            request.payload_json,
+            &request.shapes,
+            request.frame,
+            self.selection_export_style(),
+        ));
+        let (Some(manager), Some(device), Some(serial)) = (
+            self.data_device_manager.as_ref(),
+            self.data_device.as_ref(),
+            self.last_activation_serial(),
+        ) else {
+            return;
+        };
+        let mime_types = content.mime_types();
+        let source = manager.create_copy_paste_source(qh, mime_types.iter().copied());
+        source.set_selection(device, serial);
+        log::info!("Serving copied selection {generation} as {mime_types:?}");
+
+        self.owned_selection = Some(OwnedSelectionClipboard { source, content });
+        self.input_state
+            .complete_selection_clipboard_publish(generation, None, true);
+    }
+
+    pub(in crate::backend::wayland) fn handle_selection_send_request(
+        &mut self,
+        source: &WlDataSource,
+        mime_type: String,
+        pipe: WritePipe,
+    ) {
+        let Some(owned) = self
+            .owned_selection
+            .as_ref()
+            .filter(|owned| owned.source.inner() == source)
+        else {
+            log::debug!("Ignoring {mime_type} request for a replaced clipboard source");
+            return;
+        };
+        let content = Arc::clone(&owned.content);
+        let writer = File::from(OwnedFd::from(pipe));
+        // Rendering, and a requester that reads slowly, stay off the event loop.
+        let spawned = std::thread::Builder::new()
+            .name("wayscriber-clipboard-send".to_string())
+            .spawn(move || send_selection_content(&content, &mime_type, writer));
+        if let Err(err) = spawned {
+            log::warn!("Failed to start clipboard send worker: {err}");
+        }
+    }
+
+    pub(in crate::backend::wayland) fn handle_selection_source_cancelled(
+        &mut self,
+        source: &WlDataSource,
+    ) {
+        if self
+            .owned_selection
+            .as_ref()
+            .is_some_and(|owned| owned.source.inner() == source)
+        {
+            log::debug!("Another client took the clipboard selection");
+            self.owned_selection = None;
+        }
+    }
+
+    /// The overlay's clipboard source dies with it, so a selection it still
+    /// owns at exit is republished through wl-copy. Only the wayscriber JSON
+    /// survives the hand-off; the PNG, SVG and text forms need the overlay.
+    pub(in crate::backend::wayland) fn hand_off_selection_clipboard(&mut self) {
+        let Some(OwnedSelectionClipboard { source, content }) = self.owned_selection.take() else {
+            return;
+        };
+        drop(source);
+        let completion = transfer::resolve_selection_clipboard_publish(
+            content.generation(),
+            content.payload_json().to_string(),
+        );
+        if completion.copied {
+            log::info!(
+                "Handed copied selection {} to wl-copy at exit",
+                content.generation()
+            );
+        } else {
+            log::warn!(
+                "Copied selection {} could not be handed to wl-copy at exit",
+                content.generation()
+            );
+        }
+    }
+
+    fn selection_export_style(&self) -> SelectionExportStyle {
+        let backdrop = if self.config.capture.copy_selection_backdrop {
+            match self.input_state.boards.active_background() {
+                BoardBackground::Solid(color) => CanvasExportBackdropSnapshot::Solid(*color),
+                BoardBackground::Transparent => CanvasExportBackdropSnapshot::Solid(WHITE),
+            }
+        } else {
+            CanvasExportBackdropSnapshot::Transparent
+        };
+        SelectionExportStyle {
+            backdrop,
+            scale: self.surface.scale(),
+            render_profile: self.input_state.export_render_profile(),
+            spotlight: SpotlightPassSnapshot {
+                dim_opacity: self.input_state.spotlight_dim_opacity,
+                feather: self.input_state.spotlight_feather,
+            },
+        }
+    }
+}
@@ -190,6 +190,7 @@ fn snapshot_after_external_image_paste_from_input(
         w: i32::try_from(image.width).unwrap_or(i32::MAX).max(1),
         h: i32::try_from(image.height).unwrap_or(i32::MAX).max(1),
         data: image.clone(),
+        rotation: 0.0,
     };
     let id = frame.try_add_shape_with_id(shape, input.max_shapes_per_frame)?;
     let history_limit = options.effective_history_limit(input.undo_stack_limit);
@@ -1,5 +1,5 @@
 use super::*;
-use crate::draw::Color;
+use crate::draw::{Color, LineStyle};
 use crate::input::BOARD_ID_TRANSPARENT;
 use crate::input::state::{PasteAnchor, test_support::make_test_input_state};
 use crate::session::{CompressionMode, SnapshotPayloadEstimate, SnapshotSaveEstimate};
@@ -66,6 +66,8 @@ fn paste_preflight_skips_stale_target_page_generation() {
             a: 1.0,
         },
         thick: 1.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let mut options = session::SessionOptions::new(PathBuf::from("/tmp"), "display");
     options.persist_transparent = true;
@@ -6,6 +6,7 @@ use crate::clipboard_text::{
     ClipboardTextError, copy_text_via_command, read_clipboard_text_via_command,
 };
 use crate::draw::Color;
+use crate::i18n::tr_args;
 use crate::input::state::{HexPasteTarget, Toast, ToastPriority};
 use crate::input::state::{color_to_hex, parse_hex_color};
 use std::time::Duration;
@@ -43,7 +44,7 @@ impl WaylandState {
                 self.input_state.push_toast(
                     ToastPriority::Info,
                     "color_picker",
-                    Toast::info(format!("Copied {hex}")),
+                    Toast::info(tr_args("Copied {hex}", &[("hex", &hex)])),
                 );
             }
             RuntimeOperationPoll::Ready {
@@ -155,15 +156,15 @@ impl WaylandState {
             self.input_state.push_toast(
                 ToastPriority::Info,
                 "color_picker",
-                Toast::info(format!("Pasted {}", hex)),
+                Toast::info(tr_args("Pasted {hex}", &[("hex", &hex)])),
             );
         } else {
             self.input_state.push_toast(
                 ToastPriority::Info,
                 "color_picker",
-                Toast::warning(format!(
-                    "Invalid hex: {}",
-                    clipboard.chars().take(20).collect::<String>()
+                Toast::warning(tr_args(
+                    "Invalid hex: {text}",
+                    &[("text", &clipboard.chars().take(20).collect::<String>())],
                 )),
             );
         }
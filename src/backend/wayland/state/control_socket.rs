@@ -0,0 +1,76 @@
+use std::path::Path;
+
+use log::{info, warn};
+
+use super::WaylandState;
+use crate::backend::wayland::RuntimeWakeHandle;
+use crate::control_socket::{
+    ControlEvent, ControlEventKind, ControlServer, apply_request, current_status,
+};
+use crate::ui::StatusModel;
+
+/// Binds the control socket for this overlay run.
+///
+/// Failing to bind is logged and otherwise ignored: scripting is an add-on, and
+/// an overlay that cannot be scripted is still an overlay.
+pub(super) fn start_control_socket(wake: RuntimeWakeHandle) -> Option<ControlServer> {
+    let path = crate::paths::control_socket_path();
+    let started = ControlServer::start(path.clone(), move || {
+        if let Err(err) = wake.wake() {
+            log::error!("Failed to wake runtime for control request: {err}");
+        }
+    });
+    match started {
+        Ok(server) => {
+            info!("Control socket listening on {}", server.path().display());
+            Some(server)
+        }
+        Err(err) => {
+            warn!("Control socket unavailable at {}: {err}", path.display());
+            None
+        }
+    }
+}
+
+impl WaylandState {
+    /// Applies every queued control request in arrival order and answers each
+    /// with the status it left behind.
+    pub(in crate::backend::wayland) fn process_control_requests(&mut self) {
+        let Some(server) = self.control_socket.as_ref() else {
+            return;
+        };
+        let mut applied = false;
+        while let Some(pending) = server.try_recv() {
+            let response = apply_request(&mut self.input_state, pending.request.clone());
+            pending.respond(response);
+            applied = true;
+        }
+        if applied {
+            self.toolbar.mark_dirty();
+            self.input_state.needs_redraw = true;
+        }
+    }
+
+    /// Streams whatever changed since the previous pass to control subscribers.
+    ///
+    /// Runs once per event-loop pass after every input source has been drained,
+    /// so a burst of changes arrives as one batch of events in a stable order.
+    pub(in crate::backend::wayland) fn publish_control_events(&mut self) {
+        let input = &self.input_state;
+        if let Some(server) = self.control_socket.as_mut() {
+            server.publish_changes(|| StatusModel::new(input));
+        }
+    }
+
+    /// Tells control subscribers where a capture was written.
+    pub(in crate::backend::wayland) fn publish_capture_saved(&self, path: &Path) {
+        if let Some(server) = self.control_socket.as_ref() {
+            let mut event = ControlEvent::new(
+                ControlEventKind::CaptureSaved,
+                current_status(&self.input_state),
+            );
+            event.path = Some(path.to_path_buf());
+            server.publish(&event);
+        }
+    }
+}
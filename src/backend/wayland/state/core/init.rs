@@ -42,6 +42,7 @@ impl WaylandState {
             relative_pointer_state,
             output_state,
             seat_state,
+            data_device_manager,
         } = globals;
 
         #[cfg(feature = "tablet-input")]
@@ -110,6 +111,12 @@ impl WaylandState {
             RuntimeOperationController::new(runtime_operation_ids.clone(), runtime_wake.clone());
         let desktop_open =
             RuntimeOperationController::new(runtime_operation_ids.clone(), runtime_wake.clone());
+        let page_import =
+            RuntimeOperationController::new(runtime_operation_ids.clone(), runtime_wake.clone());
+        let drop_read =
+            RuntimeOperationController::new(runtime_operation_ids.clone(), runtime_wake.clone());
+        let recording_worker =
+            RuntimeOperationController::new(runtime_operation_ids.clone(), runtime_wake.clone());
         let clipboard_text_copy =
             RuntimeOperationController::new(runtime_operation_ids.clone(), runtime_wake.clone());
         let clipboard_text_paste =
@@ -127,12 +134,16 @@ impl WaylandState {
             relative_pointer_state,
             output_state,
             seat_state,
+            data_device_manager,
+            data_device: None,
             surface: SurfaceState::new(),
             toolbar: ToolbarSurfaceManager::new(),
             data,
             buffer_damage: BufferDamageTracker::new(buffer_count),
             canvas_layer_cache: super::super::canvas_layer::CanvasLayerCache::new(),
             spotlight_dimmed_last_frame: false,
+            magnifier_shown_last_frame: false,
+            magnifier_zoom_capture: false,
             config,
             runtime_ui,
             runtime_ui_unavailable,
@@ -140,9 +151,16 @@ impl WaylandState {
             input_state,
             palette_recents,
             clipboard_publish,
+            owned_selection: None,
             clipboard_paste,
             clipboard_hex_copy,
             desktop_open,
+            page_import,
+            drop_read,
+            pending_drop_offer: None,
+            dropped_session_file: None,
+            recording_worker,
+            recording: RecordingState::default(),
             pending_hex_copy: None,
             clipboard_text_copy,
             pending_text_copy: Default::default(),
@@ -154,6 +172,9 @@ impl WaylandState {
             config_edits: super::super::super::config_edits::ConfigEditWorker::new(
                 runtime_wake.clone(),
             ),
+            control_socket: super::super::control_socket::start_control_socket(
+                runtime_wake.clone(),
+            ),
             ui_animation_next_tick: None,
             ui_animation_interval,
             capture: CaptureState::new(capture_manager),
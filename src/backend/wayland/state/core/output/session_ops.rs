@@ -1,4 +1,5 @@
 use super::*;
+use crate::i18n::tr_args;
 
 impl WaylandState {
     pub(in crate::backend::wayland) fn load_configured_session_for_options(
@@ -92,9 +93,9 @@ impl WaylandState {
                 self.input_state.push_toast(
                     ToastPriority::Critical,
                     "session.corrupt",
-                    Toast::error(format!(
-                        "Previous session could not be read; a copy was saved to {}",
-                        backup_path.display()
+                    Toast::error(tr_args(
+                        "Previous session could not be read; a copy was saved to {path}",
+                        &[("path", &backup_path.display().to_string())],
                     ))
                     .duration_ms(20_000),
                 );
@@ -4,7 +4,7 @@ use super::{
 };
 use crate::{
     backend::wayland::session::SessionState,
-    draw::{Color, Frame, Shape},
+    draw::{Color, Frame, LineStyle, Shape},
     input::state::test_support::make_test_input_state,
     session::{BoardPagesSnapshot, BoardSnapshot, SessionOptions, SessionSnapshot},
 };
@@ -24,6 +24,7 @@ fn add_test_line(input: &mut crate::input::InputState) {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
 }
 
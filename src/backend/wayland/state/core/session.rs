@@ -1,3 +1,4 @@
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastPriority};
 use std::path::{Path, PathBuf};
 use std::time::Instant;
@@ -284,8 +285,11 @@ impl WaylandState {
                     .push_toast(ToastPriority::Info, "session", Toast::info(message));
             }
             Err(err) => {
-                let message = format!("Failed to reset tool defaults: {err:#}");
-                log::warn!("{message}");
+                log::warn!("Failed to reset tool defaults: {err:#}");
+                let message = tr_args(
+                    "Failed to reset tool defaults: {error}",
+                    &[("error", &format!("{err:#}"))],
+                );
                 self.input_state.push_toast(
                     ToastPriority::Critical,
                     "session",
@@ -0,0 +1,253 @@
+//! Drag-and-drop onto the overlay surface.
+//!
+//! A drag is accepted while it hovers the main overlay with an image, text, or
+//! file list, and the UI highlights the drop target. On drop the payload is
+//! read on a worker and then added at the drop point like a clipboard paste;
+//! a dropped session file is offered for opening instead.
+
+use std::fs::File;
+use std::os::fd::OwnedFd;
+use std::path::Path;
+
+use smithay_client_toolkit::data_device_manager::{
+    data_device::DataDeviceData, data_offer::DragOffer,
+};
+use wayland_client::{
+    Proxy,
+    protocol::{
+        wl_data_device::WlDataDevice, wl_data_device_manager::DndAction, wl_surface::WlSurface,
+    },
+};
+
+use super::WaylandState;
+use crate::backend::wayland::RuntimeOperationPoll;
+use crate::backend::wayland::clipboard::ClipboardPasteResult;
+use crate::backend::wayland::clipboard::drop::{DropPayload, choose_drop_mime, resolve_drop};
+use crate::i18n::{tr, tr_args};
+use crate::input::state::{ClipboardPasteRequest, DropTarget, Toast, ToastCommand, ToastPriority};
+
+const DROP_READ_FAILED: &str = "Could not read the dropped data.";
+
+impl WaylandState {
+    pub(in crate::backend::wayland) fn handle_drag_enter(
+        &mut self,
+        data_device: &WlDataDevice,
+        x: f64,
+        y: f64,
+        surface: &WlSurface,
+    ) {
+        let Some(offer) = drag_offer(data_device) else {
+            return;
+        };
+        let on_overlay = self.surface.wl_surface() == Some(surface);
+        let choice = on_overlay
+            .then(|| offer.with_mime_types(choose_drop_mime))
+            .flatten();
+        match choice {
+            Some((mime, kind)) => {
+                log::debug!("Accepting drag offer as {mime}");
+                offer.accept_mime_type(offer.serial, Some(mime));
+                offer.set_actions(DndAction::Copy, DndAction::Copy);
+                self.input_state.set_drop_target(DropTarget { x, y, kind });
+            }
+            None => {
+                offer.accept_mime_type(offer.serial, None);
+                offer.set_actions(DndAction::empty(), DndAction::empty());
+                self.input_state.clear_drop_target();
+            }
+        }
+    }
+
+    pub(in crate::backend::wayland) fn handle_drag_motion(&mut self, x: f64, y: f64) {
+        if let Some(target) = self.input_state.drop_target() {
+            self.input_state
+                .set_drop_target(DropTarget { x, y, ..target });
+        }
+    }
+
+    pub(in crate::backend::wayland) fn handle_drag_leave(&mut self) {
+        self.input_state.clear_drop_target();
+    }
+
+    pub(in crate::backend::wayland) fn handle_drag_drop(&mut self, data_device: &WlDataDevice) {
+        let target = self.input_state.drop_target();
+        self.input_state.clear_drop_target();
+        let Some(offer) = drag_offer(data_device) else {
+            return;
+        };
+        let mime = target.and_then(|_| offer.with_mime_types(choose_drop_mime));
+        let Some((mime, _)) = mime else {
+            offer.destroy();
+            return;
+        };
+
+        let pipe = match offer.receive(mime.clone()) {
+            Ok(pipe) => pipe,
+            Err(err) => {
+                log::warn!("Failed to receive dropped {mime}: {err}");
+                offer.destroy();
+                self.report_drop_failure(tr(DROP_READ_FAILED).to_string());
+                return;
+            }
+        };
+        let reader = File::from(OwnedFd::from(pipe));
+        let (canvas_x, canvas_y) = self.zoomed_world_coords(offer.x, offer.y);
+        let request = self.input_state.request_drop_paste(canvas_x, canvas_y);
+        log::info!(
+            "Reading dropped {mime} for paste request {} at ({canvas_x}, {canvas_y})",
+            request.id
+        );
+        match self
+            .drop_read
+            .try_submit(request, "wayscriber-drop-read", move || {
+                resolve_drop(&mime, reader)
+            }) {
+            Ok(_) => self.pending_drop_offer = Some(offer),
+            Err(failure) => {
+                let (error, request) = failure.into_parts();
+                log::warn!("Could not start reading drop {}: {error}", request.id);
+                offer.destroy();
+                self.input_state.finish_clipboard_paste_request(request.id);
+                self.report_drop_failure(tr("Another drop is still being read.").to_string());
+            }
+        }
+    }
+
+    pub(in crate::backend::wayland) fn poll_drop_completion(&mut self) {
+        let (request, payload) = match self.drop_read.poll() {
+            RuntimeOperationPoll::Idle | RuntimeOperationPoll::Pending { .. } => return,
+            RuntimeOperationPoll::Ready {
+                context: request,
+                outcome,
+                ..
+            } => (request, Some(outcome)),
+            RuntimeOperationPoll::ProducerFailed {
+                context: request,
+                reason,
+                ..
+            } => {
+                log::warn!("Drop read {} failed: {reason}", request.id);
+                (request, None)
+            }
+            RuntimeOperationPoll::Disconnected {
+                context: request, ..
+            } => {
+                log::warn!("Drop read {} disconnected", request.id);
+                (request, None)
+            }
+        };
+        if let Some(offer) = self.pending_drop_offer.take() {
+            offer.finish();
+            offer.destroy();
+        }
+        match payload {
+            Some(payload) => self.apply_drop_payload(request, payload),
+            None => {
+                self.input_state.finish_clipboard_paste_request(request.id);
+                self.report_drop_failure(tr(DROP_READ_FAILED).to_string());
+            }
+        }
+    }
+
+    pub(in crate::backend::wayland) fn open_dropped_session(&mut self) {
+        if let Some(path) = self.dropped_session_file.take() {
+            self.handle_toolbar_open_session_path(&path);
+        }
+    }
+
+    fn apply_drop_payload(&mut self, request: ClipboardPasteRequest, payload: DropPayload) {
+        log::info!(
+            "Applying drop for paste request {}: {}",
+            request.id,
+            payload.summary()
+        );
+        match payload {
+            DropPayload::Paste(ClipboardPasteResult::Image(image)) => {
+                self.apply_external_image_paste(request, image);
+            }
+            DropPayload::Paste(result) => {
+                self.input_state.finish_clipboard_paste_request(request.id);
+                self.report_drop_failure(drop_failure_message(&result));
+            }
+            DropPayload::Text(text) => {
+                let shape = self.input_state.dropped_text_shape(text);
+                let pasted = self
+                    .input_state
+                    .paste_clipboard_shapes_from_request(&request, vec![shape.into()]);
+                self.input_state.finish_clipboard_paste_request(request.id);
+                if pasted == 0 {
+                    self.input_state.trigger_blocked_feedback();
+                }
+            }
+            DropPayload::SessionFile(path) => {
+                self.input_state.finish_clipboard_paste_request(request.id);
+                self.offer_dropped_session(&path);
+                self.dropped_session_file = Some(path);
+            }
+        }
+    }
+
+    fn offer_dropped_session(&mut self, path: &Path) {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        self.input_state.push_toast(
+            ToastPriority::Info,
+            "drop",
+            Toast::info(tr_args("Open session {name}?", &[("name", &name)]))
+                .command("Open", ToastCommand::OpenDroppedSession),
+        );
+    }
+
+    fn report_drop_failure(&mut self, message: String) {
+        self.input_state
+            .push_toast(ToastPriority::Info, "drop", Toast::warning(message));
+        self.input_state.trigger_blocked_feedback();
+    }
+}
+
+fn drag_offer(data_device: &WlDataDevice) -> Option<DragOffer> {
+    data_device
+        .data::<DataDeviceData>()
+        .and_then(DataDeviceData::drag_offer)
+}
+
+fn drop_failure_message(result: &ClipboardPasteResult) -> String {
+    match result {
+        ClipboardPasteResult::TooLarge { limit } => tr_args(
+            "Dropped data is too large (limit {limit}).",
+            &[("limit", &format_byte_limit(*limit))],
+        ),
+        ClipboardPasteResult::TooManyPixels {
+            width,
+            height,
+            limit,
+        } => tr_args(
+            "Dropped image is too large ({width}x{height}, limit {limit} pixels).",
+            &[
+                ("width", &width.to_string()),
+                ("height", &height.to_string()),
+                ("limit", &limit.to_string()),
+            ],
+        ),
+        ClipboardPasteResult::DecodeFailed(_) => {
+            tr("Dropped content is not a supported image, text, or session file.").to_string()
+        }
+        ClipboardPasteResult::NoSupportedMime { .. } => {
+            tr("Only local files can be dropped.").to_string()
+        }
+        ClipboardPasteResult::ClipboardEmpty => tr("Dropped text is empty.").to_string(),
+        ClipboardPasteResult::ReadTimedOut => tr("Reading the dropped data timed out.").to_string(),
+        _ => tr(DROP_READ_FAILED).to_string(),
+    }
+}
+
+fn format_byte_limit(limit: usize) -> String {
+    const MIB: usize = 1024 * 1024;
+    if limit >= MIB {
+        format!("{} MB", limit / MIB)
+    } else {
+        format!("{} KB", limit / 1024)
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::i18n::tr_args;
 
 /// Why a shortcut edit was refused. Both arms leave the keymap untouched.
 #[derive(Debug)]
@@ -59,9 +60,12 @@ fn apply_keybinding_edit(
             });
         }
         if let Some(first) = requested.insert(binding, binding_text.clone()) {
-            return Err(KeybindingEditError::Edit(format!(
-                "Shortcut not changed — {first} is listed twice for {}.",
-                action_label(request.action)
+            return Err(KeybindingEditError::Edit(tr_args(
+                "Shortcut not changed — {binding} is listed twice for {action}.",
+                &[
+                    ("binding", &first),
+                    ("action", action_label(request.action)),
+                ],
             )));
         }
     }
@@ -72,19 +76,24 @@ fn apply_keybinding_edit(
 }
 
 fn shortcut_conflict_message(binding: &str, existing_action: Action) -> String {
-    format!(
-        "Shortcut not changed — {binding} is already assigned to {}.",
-        action_label(existing_action)
+    tr_args(
+        "Shortcut not changed — {binding} is already assigned to {action}.",
+        &[
+            ("binding", binding),
+            ("action", action_label(existing_action)),
+        ],
     )
 }
 
 /// What the user is told after an edit lands in the keymap and in the file.
 fn shortcut_applied_message(request: &KeybindingEditRequest) -> String {
-    let action = action_label(request.action);
+    let args = [("action", action_label(request.action))];
     match request.operation {
-        KeybindingEditOperation::Replace(_) => format!("Updated shortcut for {action}."),
-        KeybindingEditOperation::Delete => format!("Unbound {action}."),
-        KeybindingEditOperation::Reset => format!("Reset the shortcut for {action} to default."),
+        KeybindingEditOperation::Replace(_) => tr_args("Updated shortcut for {action}.", &args),
+        KeybindingEditOperation::Delete => tr_args("Unbound {action}.", &args),
+        KeybindingEditOperation::Reset => {
+            tr_args("Reset the shortcut for {action} to default.", &args)
+        }
     }
 }
 
@@ -94,19 +103,26 @@ fn shortcut_applied_message(request: &KeybindingEditRequest) -> String {
 /// whenever the action already resolves to the shipped shortcut — most often
 /// because the file omits it, which is the default state for most actions.
 fn shortcut_unchanged_message(request: &KeybindingEditRequest) -> String {
-    let action = action_label(request.action);
+    let args = [("action", action_label(request.action))];
     match request.operation {
-        KeybindingEditOperation::Replace(_) => format!("{action} already uses that shortcut."),
-        KeybindingEditOperation::Delete => format!("{action} was already unbound."),
-        KeybindingEditOperation::Reset => format!("{action} already uses the default shortcut."),
+        KeybindingEditOperation::Replace(_) => {
+            tr_args("{action} already uses that shortcut.", &args)
+        }
+        KeybindingEditOperation::Delete => tr_args("{action} was already unbound.", &args),
+        KeybindingEditOperation::Reset => {
+            tr_args("{action} already uses the default shortcut.", &args)
+        }
     }
 }
 
 /// What the user is told when the file, not this run's keymap, owns the chord.
 fn shortcut_claimed_on_disk_message(binding: &str, existing_action: Action) -> String {
-    format!(
-        "Shortcut not changed — config.toml now assigns {binding} to {}.",
-        action_label(existing_action)
+    tr_args(
+        "Shortcut not changed — config.toml now assigns {binding} to {action}.",
+        &[
+            ("binding", binding),
+            ("action", action_label(existing_action)),
+        ],
     )
 }
 
@@ -237,7 +253,12 @@ fn prepare_keybinding_edit(
     let mut next = keybindings.clone();
     for projected in in_flight {
         next.set_bindings_for_action(projected.action, projected.bindings.clone())
-            .map_err(|err| format!("Shortcut not changed: {err}"))?;
+            .map_err(|err| {
+                tr_args(
+                    "Shortcut not changed: {error}",
+                    &[("error", &err.to_string())],
+                )
+            })?;
     }
     if let Err(error) = apply_keybinding_edit(&mut next, &request) {
         return Err(match error {
@@ -249,10 +270,18 @@ fn prepare_keybinding_edit(
         });
     }
 
-    next.build_action_map()
-        .map_err(|err| format!("Shortcut not changed: {err}"))?;
-    next.build_action_bindings()
-        .map_err(|err| format!("Shortcut not changed: {err}"))?;
+    next.build_action_map().map_err(|err| {
+        tr_args(
+            "Shortcut not changed: {error}",
+            &[("error", &err.to_string())],
+        )
+    })?;
+    next.build_action_bindings().map_err(|err| {
+        tr_args(
+            "Shortcut not changed: {error}",
+            &[("error", &err.to_string())],
+        )
+    })?;
     // What the keymap would end up with, which is what the file should say.
     let bindings = next
         .bindings_for_action(request.action)
@@ -0,0 +1,49 @@
+use crate::input::state::{Toast, ToastPriority};
+
+use super::screen_image::displayed_screen_image;
+use super::*;
+
+impl WaylandState {
+    /// Keeps the screen image under the magnifier in step with the lens.
+    ///
+    /// Over the live desktop the overlay holds no pixels to enlarge, so opening
+    /// the lens engages zoom at 1x: that captures the screen through the usual
+    /// screencopy or portal path and then displays it unchanged. Closing the
+    /// lens hands that zoom back, unless it has been zoomed in since.
+    pub(in crate::backend::wayland) fn handle_pending_magnifier_source(&mut self) {
+        if !self.input_state.take_pending_magnifier_source() {
+            return;
+        }
+        if !self.input_state.magnifier_active() {
+            if std::mem::take(&mut self.magnifier_zoom_capture) && self.zoom.scale <= 1.0 {
+                self.exit_zoom();
+            }
+            return;
+        }
+
+        let board_is_transparent = self.input_state.board_is_transparent();
+        if !board_is_transparent
+            || self.zoom.is_engaged()
+            || displayed_screen_image(&self.zoom, &self.frozen, board_is_transparent).is_some()
+        {
+            return;
+        }
+        self.zoom.locked = false;
+        self.zoom.reset_view();
+        self.zoom.request_activation();
+        if let Err(err) = self.start_zoom_capture(false) {
+            warn!("Magnifier capture failed to start: {}", err);
+            self.zoom.deactivate(&mut self.input_state);
+            self.exit_overlay_suppression(OverlaySuppression::Zoom);
+            self.input_state.push_toast(
+                ToastPriority::Info,
+                "magnifier",
+                Toast::warning(
+                    "Screen capture is unavailable; the magnifier only enlarges annotations.",
+                ),
+            );
+            return;
+        }
+        self.magnifier_zoom_capture = true;
+    }
+}
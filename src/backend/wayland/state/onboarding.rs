@@ -1,5 +1,6 @@
 use crate::config::keybindings::Action;
 use crate::domain::OnboardingTip;
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastCommand, ToastPriority};
 use crate::onboarding::DEFERRED_HINT_REPEAT_MAX;
 use std::time::{Duration, Instant};
@@ -155,6 +156,7 @@ impl WaylandState {
                     self.show_onboarding_persistence_warning(&error);
                 }
             }
+            ToastCommand::OpenDroppedSession => self.open_dropped_session(),
         }
     }
 
@@ -245,9 +247,12 @@ impl WaylandState {
             return;
         };
 
-        let message = format!(
-            "Tip: press {shortcut} for {}.",
-            crate::config::action_label(action)
+        let message = tr_args(
+            "Tip: press {shortcut} for {action}.",
+            &[
+                ("shortcut", &shortcut),
+                ("action", crate::config::action_label(action)),
+            ],
         );
         let outcome = self.input_state.push_toast(
             ToastPriority::Hint,
@@ -383,22 +388,26 @@ impl WaylandState {
             let (tip, message) = match hint {
                 ContextualHint::Help => (
                     OnboardingTip::Help,
-                    format!(
-                        "Press {} for all shortcuts.",
-                        self.shortcut_label(Action::ToggleHelp, "Help")
+                    tr_args(
+                        "Press {shortcut} for all shortcuts.",
+                        &[("shortcut", &self.shortcut_label(Action::ToggleHelp, "Help"))],
                     ),
                 ),
                 ContextualHint::CommandPalette => (
                     OnboardingTip::CommandPalette,
-                    format!(
-                        "Press {} to search actions.",
-                        self.shortcut_label(Action::ToggleCommandPalette, "Command Palette")
+                    tr_args(
+                        "Press {shortcut} to search actions.",
+                        &[(
+                            "shortcut",
+                            &self.shortcut_label(Action::ToggleCommandPalette, "Command Palette"),
+                        )],
                     ),
                 ),
                 ContextualHint::StatusBar(entry) => (
                     OnboardingTip::StatusBar,
-                    format!(
-                        "Click the {entry} segment in the status bar to switch boards and pages."
+                    tr_args(
+                        "Click the {entry} segment in the status bar to switch boards and pages.",
+                        &[("entry", entry)],
                     ),
                 ),
                 ContextualHint::CanvasPopover => (
@@ -410,11 +419,10 @@ impl WaylandState {
                 ContextualHint::ZoomChip => (
                     OnboardingTip::ZoomChip,
                     match self.shortcut_label_opt(Action::ZoomIn) {
-                        Some(key) => {
-                            format!(
-                                "Zoom from the chip in the bottom-right corner, or press {key}."
-                            )
-                        }
+                        Some(key) => tr_args(
+                            "Zoom from the chip in the bottom-right corner, or press {key}.",
+                            &[("key", &key)],
+                        ),
                         None => "Zoom from the chip in the bottom-right corner.".to_string(),
                     },
                 ),
@@ -422,9 +430,13 @@ impl WaylandState {
                     let context = self.shortcut_label_opt(Action::OpenContextMenu);
                     let radial = self.shortcut_label_opt(Action::ToggleRadialMenu);
                     let message = match (context, radial) {
-                        (Some(c), Some(r)) => format!("Try quick access: {c} or {r}."),
-                        (Some(c), None) => format!("Try quick access: {c}."),
-                        (None, Some(r)) => format!("Try quick access: {r}."),
+                        (Some(c), Some(r)) => tr_args(
+                            "Try quick access: {context} or {radial}.",
+                            &[("context", &c), ("radial", &r)],
+                        ),
+                        (Some(key), None) | (None, Some(key)) => {
+                            tr_args("Try quick access: {key}.", &[("key", &key)])
+                        }
                         (None, None) => {
                             "Quick-access menus are available from toolbar actions.".to_string()
                         }
@@ -462,7 +474,10 @@ impl WaylandState {
             ToastPriority::Hint,
             "onboarding.toolbar",
             Toast::info("Toolbars hidden")
-                .action(format!("Show ({toolbar_binding})"), Action::ToggleToolbar)
+                .action(
+                    tr_args("Show ({binding})", &[("binding", &toolbar_binding)]),
+                    Action::ToggleToolbar,
+                )
                 .secondary_command(
                     "Tip settings…",
                     ToastCommand::AcknowledgeTip {
@@ -499,8 +514,9 @@ impl WaylandState {
         self.input_state.push_toast(
             ToastPriority::Critical,
             "onboarding.persistence",
-            Toast::warning(format!(
-                "Automatic guidance is off because onboarding progress could not be saved: {error}"
+            Toast::warning(tr_args(
+                "Automatic guidance is off because onboarding progress could not be saved: {error}",
+                &[("error", &error.to_string())],
             ))
             .once_per_content(),
         );
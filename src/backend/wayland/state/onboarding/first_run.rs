@@ -1,6 +1,7 @@
 use crate::backend::wayland::state::WaylandState;
 use crate::config::{RadialMenuMouseBinding, ToolbarRebindModifier, keybindings::Action};
 use crate::draw::DirtyFullReason;
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastPriority};
 use crate::input::{Key, state::PendingOnboardingUsage};
 use crate::onboarding::{FirstRunStep, OnboardingState};
@@ -30,17 +31,18 @@ impl WaylandState {
                     self.input_state.push_toast(
                         ToastPriority::Info,
                         "onboarding.first_run",
-                        Toast::info(format!(
-                            "Background mode enabled. Service file: {}",
-                            summary.service_path.display()
+                        Toast::info(tr_args(
+                            "Background mode enabled. Service file: {path}",
+                            &[("path", &summary.service_path.display().to_string())],
                         )),
                     );
                 }
                 Err(err) => {
                     mark_background_mode_prompt(self.onboarding.state_mut(), false);
                     self.save_onboarding_state();
-                    self.input_state.push_toast(ToastPriority::Critical, "onboarding.first_run", Toast::error(format!(
-                            "Background mode setup failed: {err}. You can set this up later in Background Mode settings."
+                    self.input_state.push_toast(ToastPriority::Critical, "onboarding.first_run", Toast::error(tr_args(
+                            "Background mode setup failed: {error}. You can set this up later in Background Mode settings.",
+                            &[("error", &err.to_string())],
                         )));
                 }
             }
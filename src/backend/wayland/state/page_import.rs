@@ -0,0 +1,88 @@
+//! Runtime-owned document page import.
+//!
+//! Rasterizing a PDF can take seconds, so the converter runs on a worker and
+//! the pages are added to the board when the completion is polled.
+
+use std::path::PathBuf;
+
+use super::WaylandState;
+use crate::backend::wayland::{RuntimeOperationPoll, RuntimeOperationSubmitError};
+use crate::input::state::{Toast, ToastPriority};
+use crate::page_import::{PageImportRequest, import_pages};
+
+impl WaylandState {
+    pub(in crate::backend::wayland) fn handle_page_import(&mut self, path: PathBuf) {
+        let request = PageImportRequest {
+            path: path.clone(),
+            viewport_width: self.surface.width(),
+            viewport_height: self.surface.height(),
+            scale: f64::from(self.surface.scale().max(1)),
+        };
+        match self
+            .page_import
+            .try_submit(path, "wayscriber-page-import", move || {
+                import_pages(&request).map_err(|error| error.message())
+            }) {
+            Ok(_) => {
+                self.input_state.push_toast(
+                    ToastPriority::Info,
+                    "page.import",
+                    Toast::info("Importing pages..."),
+                );
+            }
+            Err(failure) => {
+                let (error, path) = failure.into_parts();
+                log::warn!(
+                    "Failed to start page import for {}: {error}",
+                    path.display()
+                );
+                let message = if matches!(error, RuntimeOperationSubmitError::Busy { .. }) {
+                    "Another page import is still running."
+                } else {
+                    "Could not start the page import."
+                };
+                self.report_page_import_failure(message.to_string());
+            }
+        }
+    }
+
+    pub(in crate::backend::wayland) fn poll_page_import_completion(&mut self) {
+        match self.page_import.poll() {
+            RuntimeOperationPoll::Idle | RuntimeOperationPoll::Pending { .. } => {}
+            RuntimeOperationPoll::Ready {
+                context: path,
+                outcome,
+                ..
+            } => match outcome {
+                Ok(pages) => {
+                    log::info!("Imported {} page(s) from {}", pages.len(), path.display());
+                    self.input_state.apply_imported_pages(pages);
+                }
+                Err(message) => {
+                    log::warn!("Failed to import pages from {}: {message}", path.display());
+                    self.report_page_import_failure(message);
+                }
+            },
+            RuntimeOperationPoll::ProducerFailed {
+                context: path,
+                reason,
+                ..
+            } => {
+                log::warn!("Page import worker for {} failed: {reason}", path.display());
+                self.report_page_import_failure("Page import failed.".to_string());
+            }
+            RuntimeOperationPoll::Disconnected { context: path, .. } => {
+                log::warn!("Page import worker for {} disconnected", path.display());
+                self.report_page_import_failure("Page import failed.".to_string());
+            }
+        }
+    }
+
+    fn report_page_import_failure(&mut self, message: String) {
+        self.input_state.push_toast(
+            ToastPriority::Critical,
+            "page.import",
+            Toast::error(message),
+        );
+    }
+}
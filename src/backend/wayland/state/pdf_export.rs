@@ -61,24 +61,24 @@ impl WaylandState {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum PdfExportScope {
+pub(crate) enum PdfExportScope {
     ActiveBoard,
     AllBoards,
 }
 
-struct BoardPdfExportBuildContext<'a> {
-    logical_width: u32,
-    logical_height: u32,
-    boards: &'a [BoardState],
-    active_board_index: usize,
-    pan_enabled: bool,
-    scope: PdfExportScope,
-    config: &'a crate::config::PdfExportConfig,
-    desktop_backdrop: Option<CanvasExportBackdropSnapshot>,
-    spotlight: SpotlightPassSnapshot,
+pub(crate) struct BoardPdfExportBuildContext<'a> {
+    pub(crate) logical_width: u32,
+    pub(crate) logical_height: u32,
+    pub(crate) boards: &'a [BoardState],
+    pub(crate) active_board_index: usize,
+    pub(crate) pan_enabled: bool,
+    pub(crate) scope: PdfExportScope,
+    pub(crate) config: &'a crate::config::PdfExportConfig,
+    pub(crate) desktop_backdrop: Option<CanvasExportBackdropSnapshot>,
+    pub(crate) spotlight: SpotlightPassSnapshot,
 }
 
-fn build_board_pdf_export_snapshot(
+pub(crate) fn build_board_pdf_export_snapshot(
     context: BoardPdfExportBuildContext<'_>,
 ) -> Result<BoardPdfExportSnapshot, crate::capture::CaptureError> {
     let BoardPdfExportBuildContext {
@@ -230,7 +230,7 @@ fn pdf_export_scope_has_transparent_pages(
         .any(|board| board.spec.background.is_transparent() && !board.pages.pages().is_empty())
 }
 
-fn backdrop_from_background(
+pub(crate) fn backdrop_from_background(
     background: &BoardBackground,
     desktop_backdrop: Option<&CanvasExportBackdropSnapshot>,
 ) -> CanvasExportBackdropSnapshot {
@@ -249,10 +249,16 @@ fn frame_content_bounds(frame: &Frame) -> Option<CanvasExportRect> {
     let mut max_y = i32::MIN;
     let mut found = false;
 
-    for drawn in &frame.shapes {
-        let Some(bounds) = drawn.bounding_box() else {
-            continue;
-        };
+    let page_background = frame
+        .background()
+        .and_then(|background| background.bounds());
+    for bounds in frame
+        .shapes
+        .iter()
+        .filter(|drawn| frame.is_shape_visible(drawn))
+        .filter_map(|drawn| drawn.bounding_box())
+        .chain(page_background)
+    {
         min_x = min_x.min(bounds.x);
         min_y = min_y.min(bounds.y);
         max_x = max_x.max(bounds.x.saturating_add(bounds.width));
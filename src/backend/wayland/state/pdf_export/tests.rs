@@ -1,6 +1,6 @@
 use super::*;
 use crate::config::{PdfExportConfig, PdfFitMode};
-use crate::draw::{RED, Shape, WHITE};
+use crate::draw::{LineStyle, RED, Shape, WHITE};
 use crate::input::BoardSpec;
 use std::sync::Arc;
 
@@ -143,6 +143,8 @@ fn fit_content_snapshot_uses_content_bounds() {
         fill: true,
         color: RED,
         thick: 1.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let boards = vec![board(
         "white",
@@ -7,12 +7,22 @@ pub(super) struct CanvasEraserContext {
     pattern: Option<cairo::SurfacePattern>,
     backdrop_cache_key: Option<u64>,
     bg_color: Option<Color>,
+    grid: Option<crate::draw::BoardGrid>,
     logical_to_image_scale_x: f64,
     logical_to_image_scale_y: f64,
 }
 
 impl CanvasEraserContext {
-    pub(super) fn replay_context(&self) -> crate::draw::EraserReplayContext<'_> {
+    /// Grid painted under the shapes; only solid boards without a frozen or
+    /// zoomed backdrop have one.
+    pub(super) fn grid(&self) -> Option<crate::draw::BoardGrid> {
+        self.grid
+    }
+
+    pub(super) fn replay_context<'a>(
+        &'a self,
+        page_background: Option<&'a crate::draw::PageBackground>,
+    ) -> crate::draw::EraserReplayContext<'a> {
         crate::draw::EraserReplayContext {
             pattern: self.pattern.as_ref().map(|p| p as &cairo::Pattern),
             surface: self.surface.as_ref(),
@@ -20,6 +30,8 @@ impl CanvasEraserContext {
             bg_color: self.bg_color,
             logical_to_image_scale_x: self.logical_to_image_scale_x,
             logical_to_image_scale_y: self.logical_to_image_scale_y,
+            page_background,
+            grid: self.grid,
         }
     }
 }
@@ -36,6 +48,7 @@ impl WaylandState {
         let mut eraser_pattern: Option<cairo::SurfacePattern> = None;
         let mut backdrop_cache_key: Option<u64> = None;
         let mut eraser_bg_color: Option<Color> = None;
+        let mut eraser_grid: Option<crate::draw::BoardGrid> = None;
         let mut logical_to_image_scale_x = 1.0;
         let mut logical_to_image_scale_y = 1.0;
 
@@ -116,6 +129,7 @@ impl WaylandState {
                     ctx.set_source_rgba(color.r, color.g, color.b, color.a);
                     let _ = ctx.paint();
                     eraser_bg_color = Some(*color);
+                    eraser_grid = self.input_state.board_grid();
                 }
                 crate::input::BoardBackground::Transparent => {}
             }
@@ -126,6 +140,7 @@ impl WaylandState {
             pattern: eraser_pattern,
             backdrop_cache_key,
             bg_color: eraser_bg_color,
+            grid: eraser_grid,
             logical_to_image_scale_x,
             logical_to_image_scale_y,
         })
@@ -20,7 +20,7 @@ impl WaylandState {
     ) -> Result<()> {
         let canvas_transform_active = self.canvas_transform_active();
         let (canvas_origin_x, canvas_origin_y) = self.canvas_view_origin();
-        let shapes_total = self.input_state.boards.active_frame().shapes.len();
+        let shapes_total = self.input_state.canvas_frame().shapes.len();
 
         // For pure pan transforms, serve the board background and committed
         // shapes from the baked layer cache: pan frames force full damage, so
@@ -62,7 +62,8 @@ impl WaylandState {
             ctx.translate(-canvas_origin_x, -canvas_origin_y);
         }
 
-        let replay_ctx = eraser_ctx.replay_context();
+        let page_background = self.input_state.canvas_frame().background();
+        let replay_ctx = eraser_ctx.replay_context(page_background);
 
         let completed_shapes_start = perf.as_ref().map(|_| Instant::now());
         if layer_cache_ready && self.canvas_layer_cache.blit(ctx) {
@@ -73,9 +74,16 @@ impl WaylandState {
                 perf.canvas_layer_cache_used = true;
             }
         } else {
-            // Render all completed shapes from active frame
+            // Render the grid and imported page background, then all completed shapes
             debug!("Rendering {} completed shapes", shapes_total);
-            let shapes = &self.input_state.boards.active_frame().shapes;
+            if let Some(grid) = eraser_ctx.grid() {
+                crate::draw::render_board_grid(ctx, &grid);
+            }
+            if let Some(page_background) = page_background {
+                crate::draw::render_page_background(ctx, page_background);
+            }
+            let frame = self.input_state.canvas_frame();
+            let shapes = &frame.shapes;
             if let Some(perf) = perf.as_mut() {
                 perf.shapes_total = shapes.len();
             }
@@ -84,9 +92,11 @@ impl WaylandState {
             // Cairo's internal clipping is efficient for rasterization, but sending
             // thousands of shapes to Cairo still incurs overhead for geometry processing.
             // A simple bounding box check here eliminates that overhead.
-            let render_drawn_shape = |drawn_shape: &crate::draw::DrawnShape| {
-                super::super::canvas_layer::render_committed_shape(ctx, drawn_shape, &replay_ctx)
-            };
+            let render_drawn_shape =
+                |drawn_shape: &crate::draw::DrawnShape,
+                 replay_ctx: &crate::draw::EraserReplayContext<'_>| {
+                    super::super::canvas_layer::render_committed_shape(ctx, drawn_shape, replay_ctx)
+                };
 
             // Compute bounding box of all damage regions for fast rejection
             // (Union of all dirty rects). These bounds are in world coordinates.
@@ -143,30 +153,35 @@ impl WaylandState {
                 if let Some(safe_bounds) = safe_bounds {
                     let mut shapes_tested = 0usize;
                     let mut shapes_rendered = 0usize;
-                    for drawn_shape in shapes {
-                        shapes_tested += 1;
-                        // If shape has no bounding box (e.g. empty freehand), skip it.
-                        // If it has one, check intersection. Uses the per-shape
-                        // memoized bounds to avoid O(points) recomputation per frame.
-                        if let Some(bbox) = drawn_shape.bounding_box() {
-                            // Check intersection:
-                            // !(bbox.left > safe.right || bbox.right < safe.left || ...)
-                            let bbox_right = bbox.x.saturating_add(bbox.width);
-                            let bbox_bottom = bbox.y.saturating_add(bbox.height);
-                            let safe_right = safe_bounds.x.saturating_add(safe_bounds.width);
-                            let safe_bottom = safe_bounds.y.saturating_add(safe_bounds.height);
+                    crate::draw::render_frame_layers(
+                        ctx,
+                        frame,
+                        &replay_ctx,
+                        |drawn_shape, replay_ctx| {
+                            shapes_tested += 1;
+                            // If shape has no bounding box (e.g. empty freehand), skip it.
+                            // If it has one, check intersection. Uses the per-shape
+                            // memoized bounds to avoid O(points) recomputation per frame.
+                            if let Some(bbox) = drawn_shape.bounding_box() {
+                                // Check intersection:
+                                // !(bbox.left > safe.right || bbox.right < safe.left || ...)
+                                let bbox_right = bbox.x.saturating_add(bbox.width);
+                                let bbox_bottom = bbox.y.saturating_add(bbox.height);
+                                let safe_right = safe_bounds.x.saturating_add(safe_bounds.width);
+                                let safe_bottom = safe_bounds.y.saturating_add(safe_bounds.height);
 
-                            let intersects = !(bbox.x >= safe_right
-                                || bbox_right <= safe_bounds.x
-                                || bbox.y >= safe_bottom
-                                || bbox_bottom <= safe_bounds.y);
+                                let intersects = !(bbox.x >= safe_right
+                                    || bbox_right <= safe_bounds.x
+                                    || bbox.y >= safe_bottom
+                                    || bbox_bottom <= safe_bounds.y);
 
-                            if intersects {
-                                render_drawn_shape(drawn_shape);
-                                shapes_rendered += 1;
+                                if intersects {
+                                    render_drawn_shape(drawn_shape, replay_ctx);
+                                    shapes_rendered += 1;
+                                }
                             }
-                        }
-                    }
+                        },
+                    );
                     if let Some(perf) = perf.as_mut() {
                         perf.shapes_tested = shapes_tested;
                         perf.shapes_rendered = shapes_rendered;
@@ -175,10 +190,15 @@ impl WaylandState {
             } else {
                 // If we don't have damage bounds, render everything to stay correct.
                 let mut shapes_rendered = 0usize;
-                for drawn_shape in shapes {
-                    render_drawn_shape(drawn_shape);
-                    shapes_rendered += 1;
-                }
+                crate::draw::render_frame_layers(
+                    ctx,
+                    frame,
+                    &replay_ctx,
+                    |drawn_shape, replay_ctx| {
+                        render_drawn_shape(drawn_shape, replay_ctx);
+                        shapes_rendered += 1;
+                    },
+                );
                 if let Some(perf) = perf.as_mut() {
                     perf.shapes_tested = shapes.len();
                     perf.shapes_rendered = shapes_rendered;
@@ -233,7 +253,7 @@ impl WaylandState {
         let provisional_start = perf.as_ref().map(|_| Instant::now());
         let rendered_provisional = match provisional {
             crate::input::tool::ProvisionalToolStroke::BlurReplayPreview(params) => {
-                crate::draw::render_blur_rect(ctx, params, &replay_ctx);
+                crate::draw::render_blur_rect(ctx, params, &eraser_ctx.replay_context(None));
                 true
             }
             _ => self
@@ -259,10 +279,40 @@ impl WaylandState {
         // Render click highlight overlays before UI so status/help remain legible
         self.input_state.render_click_highlights(ctx, now);
 
+        // The laser trail is drawn last so it stays on top of everything it points at
+        self.input_state.render_laser(ctx, now);
+
         if canvas_transform_active {
             let _ = ctx.restore();
         }
 
+        if self.input_state.board_rulers_visible()
+            && let Some(grid) = eraser_ctx.grid()
+        {
+            let (origin, zoom) = if canvas_transform_active {
+                let zoom = if self.zoom.active {
+                    self.zoom.scale
+                } else {
+                    1.0
+                };
+                ((canvas_origin_x, canvas_origin_y), zoom)
+            } else {
+                ((0.0, 0.0), 1.0)
+            };
+            crate::draw::render_board_rulers(ctx, &grid, origin, zoom, width as f64, height as f64);
+        }
+
+        // The magnifier enlarges the finished canvas, rulers included, so it
+        // goes on after everything else in the canvas layer.
+        let lens = self
+            .has_pointer_focus()
+            .then(|| self.input_state.magnifier_lens(self.current_mouse()))
+            .flatten();
+        if let Some(lens) = &lens {
+            crate::draw::render_magnifier_lens(ctx, scale as f64, lens);
+        }
+        self.magnifier_shown_last_frame = lens.is_some();
+
         let _ = ctx.restore();
 
         Ok(())
@@ -3,6 +3,10 @@ use std::collections::HashSet;
 
 impl WaylandState {
     pub(super) fn render_selection_overlays(&mut self, ctx: &cairo::Context) {
+        if matches!(self.input_state.state, DrawingState::MovingSelection { .. }) {
+            crate::draw::render_alignment_guides(ctx, &self.input_state.alignment_guides);
+        }
+
         // Use cached HashSet from selection state to avoid allocation every render
         let has_selection = if let Some(selected) = self.input_state.selected_shape_ids_set() {
             let frame = self.input_state.boards.active_frame();
@@ -43,7 +43,7 @@ impl WaylandState {
             );
             match self.input_state.text_input_mode {
                 crate::input::TextInputMode::Plain => {
-                    crate::draw::render_text(
+                    crate::draw::render_text_preview(
                         ctx,
                         *x,
                         *y,
@@ -203,6 +203,13 @@ impl WaylandState {
         let _ = ctx.save();
         // Apply transparency to show it as a ghost
         ctx.push_group();
+        // The ghost marks where the original sits, so it keeps its rotation
+        // even though the text being edited is laid out upright.
+        if let Some((cx, cy)) = original_shape.rotation_pivot() {
+            ctx.translate(cx, cy);
+            ctx.rotate(original_shape.rotation().to_radians());
+            ctx.translate(-cx, -cy);
+        }
 
         match original_shape {
             Shape::Text {
@@ -214,6 +221,7 @@ impl WaylandState {
                 font_descriptor,
                 background_enabled,
                 wrap_width,
+                ..
             } if !text.is_empty() => {
                 crate::draw::render_text(
                     ctx,
@@ -1,5 +1,16 @@
 use std::ops::Range;
 
+use crate::draw::shape::{TextMarkup, configured_layout};
+
+/// The editor layout's markup attributes, copied so decoration attributes can
+/// be added without replacing the styling the caret geometry was measured with.
+fn editor_attributes(layout: &pango::Layout) -> pango::AttrList {
+    layout
+        .attributes()
+        .and_then(|attrs| attrs.copy())
+        .unwrap_or_default()
+}
+
 /// Paint a Pango-backed highlight for a non-collapsed preedit cursor range.
 pub(super) fn paint_preedit_selection(
     ctx: &cairo::Context,
@@ -10,18 +21,9 @@ pub(super) fn paint_preedit_selection(
     font_desc: &str,
     wrap_width: Option<i32>,
 ) {
-    let layout = pangocairo::functions::create_layout(ctx);
-    let font_desc = pango::FontDescription::from_string(font_desc);
-    layout.set_font_description(Some(&font_desc));
-    layout.set_text(text);
-    if let Some(width) = wrap_width {
-        let width = width.max(1);
-        let width_pango = (i64::from(width) * i64::from(pango::SCALE)).min(i64::from(i32::MAX));
-        layout.set_width(width_pango as i32);
-        layout.set_wrap(pango::WrapMode::WordChar);
-    }
+    let layout = configured_layout(ctx, text, font_desc, wrap_width, TextMarkup::Source);
 
-    let attrs = pango::AttrList::new();
+    let attrs = editor_attributes(&layout);
     let mut background = pango::AttrColor::new_background(0x2f2f, 0x9e9e, 0xb7b7);
     background.set_start_index(selection.start);
     background.set_end_index(selection.end);
@@ -64,9 +66,9 @@ pub(super) fn paint_preedit_underline(
 
     // Same layout configuration as measurement and rendering, so the underline
     // lands on the glyph run the caret and damage were computed against.
-    let layout = crate::draw::shape::configured_layout(ctx, text, font_desc, wrap_width);
+    let layout = configured_layout(ctx, text, font_desc, wrap_width, TextMarkup::Source);
 
-    let attrs = pango::AttrList::new();
+    let attrs = editor_attributes(&layout);
     let mut underline = pango::AttrInt::new_underline(pango::Underline::Single);
     underline.set_start_index(range.start);
     underline.set_end_index(range.end);
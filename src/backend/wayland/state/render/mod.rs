@@ -102,6 +102,8 @@ impl WaylandState {
             blocked_feedback_active,
             text_edit_entry_active,
             input_hud_animating,
+            replay_playing,
+            laser_active,
         ) = record_stage!(advance_animations, {
             (
                 self.input_state.advance_click_highlights(now),
@@ -110,6 +112,8 @@ impl WaylandState {
                 self.input_state.advance_blocked_feedback(now),
                 self.input_state.advance_text_edit_entry_feedback(now),
                 self.input_state.advance_input_hud(now),
+                self.input_state.advance_replay(now),
+                self.input_state.advance_laser(now),
             )
         });
         let ui_animation_active = highlight_active
@@ -117,7 +121,9 @@ impl WaylandState {
             || ui_toast_active
             || blocked_feedback_active
             || text_edit_entry_active
-            || input_hud_animating;
+            || input_hud_animating
+            || replay_playing
+            || laser_active;
         self.update_ui_animation_tick(now, ui_animation_active);
         let keep_rendering = ui_animation_active && self.ui_animation_interval.is_none();
 
@@ -472,6 +478,10 @@ impl WaylandState {
             // or undone the flag is already false, yet the buffer on screen still
             // holds its dim layer and only the former opening would be redrawn.
             Some(FullDamageReason::Spotlight)
+        } else if self.input_state.magnifier_active() || self.magnifier_shown_last_frame {
+            // The lens moves with the pointer and shows pixels from around it,
+            // so it is redrawn in full, including once more after it closes.
+            Some(FullDamageReason::Magnifier)
         } else {
             None
         }
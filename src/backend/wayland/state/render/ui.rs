@@ -57,13 +57,14 @@ impl WaylandState {
             // intentional status/chip hide would make these badges reappear.
             let fallback_mode_badges_visible = self.input_state.fallback_mode_badges_visible();
             let status_hud_visible = self.input_state.status_hud_effectively_visible();
+            let mut top_badge_offset = 0.0;
             if self.input_state.frozen_active()
                 && !self.zoom.active
                 && self.config.ui.show_frozen_badge
                 && !status_hud_visible
                 && fallback_mode_badges_visible
             {
-                crate::ui::render_frozen_badge(ctx, width, height);
+                top_badge_offset += crate::ui::render_frozen_badge(ctx, width, height);
             }
             // Render a zoom badge when the status bar is hidden.
             // Badge renderers return the vertical space they consume (measured
@@ -75,7 +76,6 @@ impl WaylandState {
             // chip and this badge would both show the zoom percentage. When the
             // chip is absent (zoom actions off or master-hidden), this passive
             // badge remains the hidden-status-bar zoom indicator.
-            let mut top_badge_offset = 0.0;
             if self.input_state.zoom_active()
                 && !status_hud_visible
                 && !self.zoom_chip_visible()
@@ -103,6 +103,30 @@ impl WaylandState {
                     top_badge_offset,
                 );
             }
+            // Focus Mode does not hide REC: a running recording must stay
+            // visible to the presenter.
+            if let Some(elapsed) = self.input_state.recording_elapsed()
+                && !status_hud_visible
+            {
+                top_badge_offset += crate::ui::render_recording_badge(
+                    ctx,
+                    width,
+                    height,
+                    elapsed,
+                    top_badge_offset,
+                );
+            }
+            // Like REC, the compact timer ignores Focus Mode: it exists to be
+            // glanced at mid-talk.
+            if let Some(view) = self
+                .input_state
+                .timer_view(std::time::Instant::now())
+                .filter(|view| view.compact)
+                && !status_hud_visible
+            {
+                top_badge_offset +=
+                    crate::ui::render_timer_badge(ctx, width, height, &view, top_badge_offset);
+            }
             // Render editing badge when in text edit mode
             if matches!(self.input_state.state, DrawingState::TextInput { .. })
                 && self.input_state.text_edit_target.is_some()
@@ -168,6 +192,16 @@ impl WaylandState {
                 );
             }
 
+            // Render the large timer readout over the board.
+            if self.input_state.timer_active() {
+                crate::ui::render_timer_overlay(ctx, &self.input_state, width, height);
+            }
+
+            // Render the replay transport bar over the replayed page.
+            if self.input_state.replay_active() {
+                crate::ui::render_replay_bar(ctx, &self.input_state, width, height);
+            }
+
             // Render help overlay if toggled
             if self.input_state.show_help {
                 let bindings = crate::ui::HelpOverlayBindings::from_input_state(&self.input_state);
@@ -244,6 +278,9 @@ impl WaylandState {
                 self.input_state.clear_radial_menu_layout();
             }
 
+            // Render the drop-target highlight while a drag hovers the overlay.
+            crate::ui::render_drop_target(ctx, &self.input_state, width, height);
+
             let toast_geometry = crate::ui::render_ui_toast(ctx, &self.input_state, width, height);
             self.input_state.ui_toast_bounds = toast_geometry.map(|geometry| geometry.0);
             self.input_state.ui_toast_action_bounds = toast_geometry
@@ -0,0 +1,77 @@
+use crate::canvas_export::{
+    BoardSvgExportSnapshot, CanvasPageExportSnapshot, SpotlightPassSnapshot,
+};
+use crate::draw::Frame;
+use crate::input::boards::BoardState;
+use crate::render_profiles::RenderColorProfile;
+
+use super::WaylandState;
+use super::pdf_export::backdrop_from_background;
+
+impl WaylandState {
+    pub(in crate::backend::wayland) fn board_svg_export_snapshot(&self) -> BoardSvgExportSnapshot {
+        build_board_svg_export_snapshot(
+            self.surface.width(),
+            self.surface.height(),
+            self.input_state.boards.board_states(),
+            self.input_state.boards.active_index(),
+            self.input_state.boards.pan_enabled(),
+            SpotlightPassSnapshot {
+                dim_opacity: self.input_state.spotlight_dim_opacity,
+                feather: self.input_state.spotlight_feather,
+            },
+            self.input_state.export_render_profile(),
+        )
+    }
+}
+
+/// Every page of the active board at the live viewport size, positioned the
+/// way the board PDF export positions them.
+fn build_board_svg_export_snapshot(
+    logical_width: u32,
+    logical_height: u32,
+    boards: &[BoardState],
+    active_board_index: usize,
+    pan_enabled: bool,
+    spotlight: SpotlightPassSnapshot,
+    render_profile: Option<RenderColorProfile>,
+) -> BoardSvgExportSnapshot {
+    let board = boards.get(active_board_index).or_else(|| boards.first());
+    let mut pages = Vec::new();
+    if let Some(board) = board {
+        for frame in board.pages.pages() {
+            let use_page_offsets = pan_enabled && !board.spec.background.is_transparent();
+            let (origin_x, origin_y) = if use_page_offsets {
+                frame.view_offset()
+            } else {
+                (0, 0)
+            };
+            pages.push(CanvasPageExportSnapshot {
+                frame: frame.clone_without_history(),
+                backdrop: backdrop_from_background(&board.spec.background, None),
+                viewport_width: logical_width,
+                viewport_height: logical_height,
+                origin_x,
+                origin_y,
+                spotlight,
+            });
+        }
+    }
+
+    if pages.is_empty() {
+        pages.push(CanvasPageExportSnapshot {
+            frame: Frame::new(),
+            backdrop: crate::canvas_export::CanvasExportBackdropSnapshot::Transparent,
+            viewport_width: logical_width,
+            viewport_height: logical_height,
+            origin_x: 0,
+            origin_y: 0,
+            spotlight,
+        });
+    }
+
+    BoardSvgExportSnapshot {
+        pages,
+        render_profile,
+    }
+}
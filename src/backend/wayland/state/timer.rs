@@ -0,0 +1,32 @@
+use crate::input::TimerKind;
+
+use super::*;
+
+impl WaylandState {
+    /// Posts the desktop notification and plays the sound for a countdown
+    /// that just reached zero. The flash and toast are already up by then.
+    pub(in crate::backend::wayland) fn handle_pending_timer_finished(&mut self) {
+        let Some(kind) = self.input_state.take_pending_timer_finished() else {
+            return;
+        };
+        let settings = self.input_state.timer_settings();
+        let sound_file = settings.sound_file.clone();
+        if settings.notify {
+            let (summary, body) = match kind {
+                TimerKind::Break => ("Break is over", "The break timer has run out."),
+                _ => ("Time is up", "The talk timer has run out."),
+            };
+            crate::notification::send_notification_async(
+                &self.tokio_handle,
+                summary.to_string(),
+                body.to_string(),
+                Some("alarm-symbolic".to_string()),
+            );
+        }
+        if let Some(path) = sound_file
+            && let Err(err) = crate::timer_sound::play_in_background(path)
+        {
+            warn!("Timer sound could not start: {err:#}");
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use super::*;
 use crate::backend::wayland::config_edits::{ConfigEdit, ConfigEditWorker};
 use crate::config::{Config, ConfigEditNotReadBack, ConfigEditOutcome, ConfigEditWrite};
+use crate::i18n::tr_args;
 use crate::input::state::{PresetAction, Toast, ToastPriority};
 
 /// Apply an accepted preset save/clear to the effective config.
@@ -22,8 +23,12 @@ fn apply_preset_action(config: &mut Config, action: &PresetAction) {
 /// What the user is told once the file has taken the slot.
 fn preset_saved_message(action: &PresetAction) -> String {
     match action {
-        PresetAction::Save { slot, .. } => format!("Saved preset {slot}."),
-        PresetAction::Clear { slot } => format!("Cleared preset {slot}."),
+        PresetAction::Save { slot, .. } => {
+            tr_args("Saved preset {slot}.", &[("slot", &slot.to_string())])
+        }
+        PresetAction::Clear { slot } => {
+            tr_args("Cleared preset {slot}.", &[("slot", &slot.to_string())])
+        }
     }
 }
 
@@ -34,15 +39,29 @@ fn preset_saved_message(action: &PresetAction) -> String {
 /// already agrees rather than claiming a save that did not happen.
 fn preset_unchanged_message(action: &PresetAction) -> String {
     match action {
-        PresetAction::Save { slot, .. } => format!("Preset {slot} already holds these settings."),
-        PresetAction::Clear { slot } => format!("Preset {slot} was already empty in config.toml."),
+        PresetAction::Save { slot, .. } => tr_args(
+            "Preset {slot} already holds these settings.",
+            &[("slot", &slot.to_string())],
+        ),
+        PresetAction::Clear { slot } => tr_args(
+            "Preset {slot} was already empty in config.toml.",
+            &[("slot", &slot.to_string())],
+        ),
     }
 }
 
 /// What the user is told when the slot changed but the file did not.
 fn preset_save_failed_message(action: &PresetAction) -> String {
-    let (slot, verb) = preset_slot_and_verb(action);
-    format!("Preset {slot} {verb} for this run, but saving to config.toml failed (see logs).")
+    let slot = preset_slot(action).to_string();
+    let msgid = match action {
+        PresetAction::Save { .. } => {
+            "Preset {slot} saved for this run, but saving to config.toml failed (see logs)."
+        }
+        PresetAction::Clear { .. } => {
+            "Preset {slot} cleared for this run, but saving to config.toml failed (see logs)."
+        }
+    };
+    tr_args(msgid, &[("slot", &slot)])
 }
 
 /// What the user is told when the write landed but the slot does not read back.
@@ -50,17 +69,21 @@ fn preset_save_failed_message(action: &PresetAction) -> String {
 /// The file changed, so this cannot borrow the wording above: a value the save
 /// clamped is fixed in the configurator, not by retrying the gesture.
 fn preset_write_unverified_message(action: &PresetAction) -> String {
-    let (slot, verb) = preset_slot_and_verb(action);
-    format!(
-        "Preset {slot} {verb} for this run, but config.toml was written and does not read back \
-         with it (see logs)."
-    )
+    let slot = preset_slot(action).to_string();
+    let msgid = match action {
+        PresetAction::Save { .. } => {
+            "Preset {slot} saved for this run, but config.toml was written and does not read back with it (see logs)."
+        }
+        PresetAction::Clear { .. } => {
+            "Preset {slot} cleared for this run, but config.toml was written and does not read back with it (see logs)."
+        }
+    };
+    tr_args(msgid, &[("slot", &slot)])
 }
 
-fn preset_slot_and_verb(action: &PresetAction) -> (usize, &'static str) {
+fn preset_slot(action: &PresetAction) -> usize {
     match action {
-        PresetAction::Save { slot, .. } => (*slot, "saved"),
-        PresetAction::Clear { slot } => (*slot, "cleared"),
+        PresetAction::Save { slot, .. } | PresetAction::Clear { slot } => *slot,
     }
 }
 
@@ -105,7 +128,7 @@ impl WaylandState {
                     .push_toast(ToastPriority::Info, "presets", Toast::info(message));
             }
             Err(error) => {
-                let slot = preset_slot_and_verb(action).0;
+                let slot = preset_slot(action);
                 log::warn!("Failed to save preset slot {slot}: {error:#}");
                 let message = match error.downcast_ref::<ConfigEditNotReadBack>() {
                     Some(_) => preset_write_unverified_message(action),
@@ -157,6 +180,7 @@ mod tests {
             arrow_angle: None,
             arrow_head_at_end: None,
             polygon_sides: None,
+            line_style: None,
             show_status_bar: None,
             drag_tools: None,
         })
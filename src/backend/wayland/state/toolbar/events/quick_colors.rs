@@ -3,11 +3,15 @@ use crate::backend::wayland::config_edits::{ConfigEdit, ConfigEditWorker};
 use crate::config::{
     Config, ConfigEditNotReadBack, ConfigEditOutcome, ConfigEditWrite, QuickColorSlotMissing,
 };
+use crate::i18n::tr_args;
 use crate::input::state::{QuickColorEdit, Toast, ToastPriority};
 
 /// What the user is told when the swatch and the file both took the recolor.
 fn quick_color_saved_message(index: usize) -> String {
-    format!("Updated quick color {}.", index + 1)
+    tr_args(
+        "Updated quick color {slot}.",
+        &[("slot", &(index + 1).to_string())],
+    )
 }
 
 /// What the user is told when the slot already painted this color.
@@ -16,24 +20,27 @@ fn quick_color_saved_message(index: usize) -> String {
 /// with, or "Default" on a slot that never moved — and there is no delta to
 /// write, so the message must not claim one.
 fn quick_color_unchanged_message(index: usize) -> String {
-    format!("Quick color {} already uses that color.", index + 1)
+    tr_args(
+        "Quick color {slot} already uses that color.",
+        &[("slot", &(index + 1).to_string())],
+    )
 }
 
 /// What the user is told when the swatch changed but the file did not.
 fn quick_color_save_failed_message(index: usize) -> String {
-    format!(
-        "Quick color {} changed for this run, but saving to config.toml failed (see logs).",
-        index + 1
+    tr_args(
+        "Quick color {slot} changed for this run, but saving to config.toml failed (see logs).",
+        &[("slot", &(index + 1).to_string())],
     )
 }
 
 /// What the user is told when the write landed but the slot does not read back.
 /// The file changed, so this cannot borrow the wording above.
 fn quick_color_write_unverified_message(index: usize) -> String {
-    format!(
-        "Quick color {} changed for this run, but config.toml was written and does not read back \
+    tr_args(
+        "Quick color {slot} changed for this run, but config.toml was written and does not read back \
          with it (see logs).",
-        index + 1
+        &[("slot", &(index + 1).to_string())],
     )
 }
 
@@ -44,9 +51,9 @@ fn quick_color_write_unverified_message(index: usize) -> String {
 /// swatch keeps it for the run, and the message says which slot vanished
 /// rather than reporting a save failure the user cannot act on.
 fn quick_color_slot_missing_message(index: usize) -> String {
-    format!(
-        "Quick color {} is no longer in config.toml, so the new color applies to this run only.",
-        index + 1
+    tr_args(
+        "Quick color {slot} is no longer in config.toml, so the new color applies to this run only.",
+        &[("slot", &(index + 1).to_string())],
     )
 }
 
@@ -1,4 +1,5 @@
 use super::*;
+use crate::i18n::{tr, tr_args};
 use crate::input::state::{Toast, ToastPriority};
 use crate::session::catalog;
 use anyhow::{Context, Error as AnyhowError, Result, anyhow};
@@ -33,36 +34,51 @@ pub(super) fn session_info_summary(inspection: &crate::session::SessionInspectio
     if !inspection.exists {
         return if inspection.backup_exists {
             match inspection.backup_size_bytes {
-                Some(size) => format!(
-                    "Session {name}: no primary file, backup {}",
-                    format_byte_count(size)
+                Some(size) => tr_args(
+                    "Session {name}: no primary file, backup {size}",
+                    &[("name", &name), ("size", &format_byte_count(size))],
+                ),
+                None => tr_args(
+                    "Session {name}: no primary file, backup present",
+                    &[("name", &name)],
                 ),
-                None => format!("Session {name}: no primary file, backup present"),
             }
         } else {
-            format!("Session {name}: no saved file yet")
+            tr_args("Session {name}: no saved file yet", &[("name", &name)])
         };
     }
 
     let size = inspection
         .size_bytes
         .map(format_byte_count)
-        .unwrap_or_else(|| "unknown size".to_string());
+        .unwrap_or_else(|| tr("unknown size").to_string());
     let shapes = inspection
         .frame_counts
         .map(|counts| {
-            format!(
-                ", shapes T/W/B {}/{}/{}",
-                counts.transparent, counts.whiteboard, counts.blackboard
+            tr_args(
+                ", shapes T/W/B {transparent}/{whiteboard}/{blackboard}",
+                &[
+                    ("transparent", &counts.transparent.to_string()),
+                    ("whiteboard", &counts.whiteboard.to_string()),
+                    ("blackboard", &counts.blackboard.to_string()),
+                ],
             )
         })
         .unwrap_or_default();
     let history = if inspection.history_present {
-        "history"
+        tr("history")
     } else {
-        "no history"
+        tr("no history")
     };
-    format!("Session {name}: {size}{shapes}, {history}")
+    tr_args(
+        "Session {name}: {size}{shapes}, {history}",
+        &[
+            ("name", &name),
+            ("size", &size),
+            ("shapes", &shapes),
+            ("history", history),
+        ],
+    )
 }
 
 fn format_byte_count(bytes: u64) -> String {
@@ -288,34 +304,46 @@ impl WaylandState {
             conn,
             qh,
         ) {
-            self.set_session_toolbar_error(format!("Open session failed: {err:#}"));
+            self.set_session_toolbar_error(tr_args(
+                "Open session failed: {error}",
+                &[("error", &format!("{err:#}"))],
+            ));
         }
     }
 
-    fn handle_toolbar_open_session_path(&mut self, path: &Path) {
+    pub(in crate::backend::wayland::state) fn handle_toolbar_open_session_path(
+        &mut self,
+        path: &Path,
+    ) {
         self.clear_toolbar_save_as_overwrite_prompt();
         match self.open_named_session_runtime(path) {
-            Ok(report) => self.set_session_toolbar_info(format!(
-                "Opened session {}",
-                session_display_name(&report.opened_path)
+            Ok(report) => self.set_session_toolbar_info(tr_args(
+                "Opened session {name}",
+                &[("name", &session_display_name(&report.opened_path))],
             )),
             Err(err) if missing_session_error_matches_path(path, &err) => {
                 match self.forget_named_session_by_path(path.to_path_buf()) {
-                    Ok(true) => self.set_session_toolbar_error(format!(
-                        "Session file missing; removed from recent sessions: {}",
-                        session_display_name(path)
+                    Ok(true) => self.set_session_toolbar_error(tr_args(
+                        "Session file missing; removed from recent sessions: {name}",
+                        &[("name", &session_display_name(path))],
                     )),
-                    Ok(false) => self.set_session_toolbar_error(format!(
-                        "Session file missing; no recent-session entry matched: {}",
-                        session_display_name(path)
+                    Ok(false) => self.set_session_toolbar_error(tr_args(
+                        "Session file missing; no recent-session entry matched: {name}",
+                        &[("name", &session_display_name(path))],
                     )),
-                    Err(catalog_err) => self.set_session_toolbar_error(format!(
-                        "Session file missing and recent-session cleanup failed for {}: {catalog_err:#}",
-                        session_display_name(path)
+                    Err(catalog_err) => self.set_session_toolbar_error(tr_args(
+                        "Session file missing and recent-session cleanup failed for {name}: {error}",
+                        &[
+                            ("name", &session_display_name(path)),
+                            ("error", &format!("{catalog_err:#}")),
+                        ],
                     )),
                 }
             }
-            Err(err) => self.set_session_toolbar_error(format!("Open session failed: {err:#}")),
+            Err(err) => self.set_session_toolbar_error(tr_args(
+                "Open session failed: {error}",
+                &[("error", &format!("{err:#}"))],
+            )),
         }
     }
 
@@ -332,7 +360,10 @@ impl WaylandState {
             conn,
             qh,
         ) {
-            self.set_session_toolbar_error(format!("Save session failed: {err:#}"));
+            self.set_session_toolbar_error(tr_args(
+                "Save session failed: {error}",
+                &[("error", &format!("{err:#}"))],
+            ));
         }
     }
 
@@ -345,12 +376,18 @@ impl WaylandState {
             Ok(None) => return,
             Err(error) => {
                 let _ = self.exit_external_dialog_suppression(None, Some(qh));
-                self.set_session_toolbar_error(format!("Session dialog failed: {error:#}"));
+                self.set_session_toolbar_error(tr_args(
+                    "Session dialog failed: {error}",
+                    &[("error", &format!("{error:#}"))],
+                ));
                 return;
             }
         };
         if let Err(error) = self.exit_external_dialog_suppression(None, Some(qh)) {
-            self.set_session_toolbar_error(format!("Session dialog restoration failed: {error:#}"));
+            self.set_session_toolbar_error(tr_args(
+                "Session dialog restoration failed: {error}",
+                &[("error", &format!("{error:#}"))],
+            ));
             return;
         }
         match (completion.mode, completion.result) {
@@ -360,10 +397,16 @@ impl WaylandState {
             (SessionFileDialogMode::Open, Ok(None)) | (SessionFileDialogMode::SaveAs, Ok(None)) => {
             }
             (SessionFileDialogMode::Open, Err(error)) => {
-                self.set_session_toolbar_error(format!("Open session failed: {error}"));
+                self.set_session_toolbar_error(tr_args(
+                    "Open session failed: {error}",
+                    &[("error", &error.to_string())],
+                ));
             }
             (SessionFileDialogMode::SaveAs, Err(error)) => {
-                self.set_session_toolbar_error(format!("Save session failed: {error}"));
+                self.set_session_toolbar_error(tr_args(
+                    "Save session failed: {error}",
+                    &[("error", &error.to_string())],
+                ));
             }
             (SessionFileDialogMode::SaveAs, Ok(Some(path))) => {
                 self.handle_selected_save_as_path(ensure_save_as_extension(path));
@@ -375,16 +418,19 @@ impl WaylandState {
         match self.save_named_session_as_requires_overwrite(&path) {
             Ok(true) => {
                 self.input_state.set_pending_save_as_overwrite(path.clone());
-                self.set_session_toolbar_info(format!(
-                    "Replace existing session {}?",
-                    session_display_name(&path)
+                self.set_session_toolbar_info(tr_args(
+                    "Replace existing session {name}?",
+                    &[("name", &session_display_name(&path))],
                 ));
             }
             Ok(false) => {
                 self.commit_toolbar_save_session_as(&path, crate::session::SaveAsOverwrite::Deny)
             }
             Err(err) => {
-                self.set_session_toolbar_error(format!("Save session failed: {err:#}"));
+                self.set_session_toolbar_error(tr_args(
+                    "Save session failed: {error}",
+                    &[("error", &format!("{err:#}"))],
+                ));
             }
         }
     }
@@ -422,14 +468,17 @@ impl WaylandState {
         match self.save_named_session_as_runtime(path, overwrite) {
             Ok(report) => {
                 self.clear_toolbar_save_as_overwrite_prompt();
-                self.set_session_toolbar_info(format!(
-                    "Saved session as {}",
-                    session_display_name(&report.saved_path)
+                self.set_session_toolbar_info(tr_args(
+                    "Saved session as {name}",
+                    &[("name", &session_display_name(&report.saved_path))],
                 ));
             }
             Err(err) => {
                 self.clear_toolbar_save_as_overwrite_prompt();
-                self.set_session_toolbar_error(format!("Save session failed: {err:#}"));
+                self.set_session_toolbar_error(tr_args(
+                    "Save session failed: {error}",
+                    &[("error", &format!("{err:#}"))],
+                ));
             }
         }
     }
@@ -437,18 +486,24 @@ impl WaylandState {
     fn handle_toolbar_session_info(&mut self) {
         match self.inspect_active_session() {
             Ok(inspection) => self.set_session_toolbar_info(session_info_summary(&inspection)),
-            Err(err) => self.set_session_toolbar_error(format!("Session info failed: {err:#}")),
+            Err(err) => self.set_session_toolbar_error(tr_args(
+                "Session info failed: {error}",
+                &[("error", &format!("{err:#}"))],
+            )),
         }
     }
 
     fn handle_toolbar_clear_session(&mut self) {
         self.clear_toolbar_save_as_overwrite_prompt();
         match self.clear_current_session_runtime() {
-            Ok(report) => self.set_session_toolbar_info(format!(
-                "Cleared session {}",
-                session_display_name(&report.cleared_path)
+            Ok(report) => self.set_session_toolbar_info(tr_args(
+                "Cleared session {name}",
+                &[("name", &session_display_name(&report.cleared_path))],
+            )),
+            Err(err) => self.set_session_toolbar_error(tr_args(
+                "Clear session failed: {error}",
+                &[("error", &format!("{err:#}"))],
             )),
-            Err(err) => self.set_session_toolbar_error(format!("Clear session failed: {err:#}")),
         }
     }
 
@@ -4,7 +4,7 @@ use std::path::{Path, PathBuf};
 use std::sync::mpsc;
 use std::time::Duration;
 
-const SESSION_FILE_EXTENSION: &str = "wayscriber-session";
+use crate::session::SESSION_FILE_EXTENSION;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(in crate::backend::wayland::state) enum SessionFileDialogMode {
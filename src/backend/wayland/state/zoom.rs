@@ -182,7 +182,7 @@ impl WaylandState {
         }
     }
 
-    fn start_zoom_capture(&mut self, force: bool) -> Result<()> {
+    pub(super) fn start_zoom_capture(&mut self, force: bool) -> Result<()> {
         if self.zoom.is_in_progress() {
             return Ok(());
         }
@@ -479,6 +479,7 @@ fn build_top_micro_chip(
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ShapeOptionRow {
     Fill,
+    LineStyle,
     PolygonSides,
 }
 
@@ -487,6 +488,7 @@ fn shape_option_rows(snapshot: &ToolbarSnapshot) -> Vec<ShapeOptionRow> {
     if model::top_fill_visible(snapshot) {
         rows.push(ShapeOptionRow::Fill);
     }
+    rows.push(ShapeOptionRow::LineStyle);
     if snapshot.active_tool == Tool::RegularPolygon
         || snapshot.tool_override == Some(Tool::RegularPolygon)
     {
@@ -526,6 +528,29 @@ fn push_option_row(
                 )),
             ));
         }
+        ShapeOptionRow::LineStyle => {
+            tree.push(WidgetNode::new(
+                "top.options.line-style",
+                rect,
+                WidgetKind::TextButton {
+                    label: LabelSpec::new(
+                        format!("Line: {}", snapshot.line_style.label()),
+                        TOP_LABEL_FONT_SIZE,
+                        true,
+                    ),
+                    style: ButtonStyle::plain(),
+                },
+                Some(Interaction::click(
+                    ToolbarEvent::SetLineStyle(snapshot.line_style.next()),
+                    Some(format_binding_label(
+                        action_label(Action::CycleLineStyle),
+                        snapshot
+                            .binding_hints
+                            .binding_for_action(Action::CycleLineStyle),
+                    )),
+                )),
+            ));
+        }
         ShapeOptionRow::PolygonSides => {
             let btn = h;
             tree.push(WidgetNode::new(
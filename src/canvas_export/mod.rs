@@ -2,6 +2,10 @@ mod page;
 mod pdf;
 mod pdf_labels;
 mod png;
+mod recording;
+mod replay;
+mod svg;
+mod svg_shapes;
 
 pub use page::{
     CanvasExportBackdropSnapshot, CanvasExportRect, CanvasPageExportSnapshot, SpotlightPassSnapshot,
@@ -12,6 +16,9 @@ pub use pdf::{
     render_board_pdf, resolve_pdf_page_layout,
 };
 pub use png::{BoardExportSnapshot, CanvasExportSnapshot, CanvasExportViewport, render_canvas_png};
+pub use recording::render_recording_frame;
+pub use replay::ReplayExportSnapshot;
+pub use svg::{BoardSvgExportSnapshot, render_board_svg, render_canvas_svg};
 
 #[cfg(test)]
 mod tests {
@@ -21,7 +28,7 @@ mod tests {
     use crate::canvas_export::page::draw_canvas_page;
     use crate::canvas_export::png::render_canvas_surface;
     use crate::config::{PdfExportConfig, RenderColorMappingConfig, RenderProfileConfig};
-    use crate::draw::{BLACK, BlurStyle, Frame, RED, Shape, WHITE};
+    use crate::draw::{BLACK, BlurStyle, Frame, LineStyle, RED, Shape, WHITE};
     use crate::render_profiles::RenderColorProfile;
 
     fn snapshot(frame: Frame, viewport: CanvasExportViewport) -> CanvasExportSnapshot {
@@ -78,6 +85,8 @@ mod tests {
             fill: true,
             color: RED,
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         let mut surface = render_canvas_surface(&snapshot(
             frame,
@@ -94,6 +103,44 @@ mod tests {
         assert_ne!(pixel(&mut surface, 3, 3), 0);
     }
 
+    #[test]
+    fn recording_frame_crops_to_scaled_region_as_straight_rgba() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Rect {
+            x: 4,
+            y: 4,
+            w: 4,
+            h: 4,
+            fill: true,
+            color: RED,
+            thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
+        });
+        let canvas = snapshot(
+            frame,
+            CanvasExportViewport {
+                logical_width: 10,
+                logical_height: 10,
+                scale: 2,
+                origin_x: 0,
+                origin_y: 0,
+            },
+        );
+        let region = crate::config::RecordingRegion {
+            x: 4,
+            y: 4,
+            width: 20,
+            height: 2,
+        };
+
+        let recorded = render_recording_frame(&canvas, Some(region)).expect("frame");
+
+        assert_eq!((recorded.width, recorded.height), (12, 4));
+        assert_eq!(&recorded.rgba[8..12], &[255, 0, 0, 255]);
+        assert_eq!(&recorded.rgba[recorded.rgba.len() - 4..], &[0, 0, 0, 0]);
+    }
+
     #[test]
     fn export_scale_creates_physical_surface_and_scales_geometry() {
         let mut frame = Frame::new();
@@ -105,6 +152,8 @@ mod tests {
             fill: true,
             color: RED,
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         let mut surface = render_canvas_surface(&snapshot(
             frame,
@@ -124,6 +173,54 @@ mod tests {
         assert_eq!(pixel(&mut surface, 0, 0), 0);
     }
 
+    #[test]
+    fn replay_export_frames_build_the_page_up_to_the_final_render() {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Rect {
+            x: 1,
+            y: 1,
+            w: 4,
+            h: 4,
+            fill: true,
+            color: RED,
+            thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
+        });
+        frame.add_shape(Shape::Line {
+            x1: 0,
+            y1: 8,
+            x2: 9,
+            y2: 8,
+            color: BLACK,
+            thick: 2.0,
+            line_style: LineStyle::Solid,
+        });
+        let viewport = CanvasExportViewport {
+            logical_width: 10,
+            logical_height: 10,
+            scale: 1,
+            origin_x: 0,
+            origin_y: 0,
+        };
+        let replay = ReplayExportSnapshot::new(snapshot(frame, viewport), 2);
+        assert_eq!(replay.frame_count(), 5);
+
+        let mut empty = render_canvas_surface(&replay.frame_snapshot(0)).expect("surface");
+        assert_eq!(pixel(&mut empty, 3, 3), 0);
+        // Half way through the line: the rectangle is done, the line has
+        // only reached the middle.
+        let mut partial = render_canvas_surface(&replay.frame_snapshot(3)).expect("surface");
+        assert_ne!(pixel(&mut partial, 3, 3), 0);
+        assert_ne!(pixel(&mut partial, 2, 8), 0);
+        assert_eq!(pixel(&mut partial, 8, 8), 0);
+        let mut last = render_canvas_surface(&replay.frame_snapshot(4)).expect("surface");
+        assert_ne!(pixel(&mut last, 8, 8), 0);
+
+        let empty_page = ReplayExportSnapshot::new(snapshot(Frame::new(), viewport), 2);
+        assert_eq!(empty_page.frame_count(), 0);
+    }
+
     #[test]
     fn draw_canvas_page_uses_explicit_output_scale() {
         let mut frame = Frame::new();
@@ -135,6 +232,8 @@ mod tests {
             fill: true,
             color: RED,
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         let mut surface =
             cairo::ImageSurface::create(cairo::Format::ARgb32, 20, 20).expect("surface");
@@ -191,6 +290,8 @@ mod tests {
             fill: true,
             color: BLACK,
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         let profile = RenderColorProfile::from_config(&RenderProfileConfig {
             id: "print".to_string(),
@@ -229,6 +330,8 @@ mod tests {
             fill: true,
             color: RED,
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         frame.add_shape(Shape::EraserStroke {
             points: vec![(6, 6)],
@@ -264,6 +367,8 @@ mod tests {
             fill: true,
             color: RED,
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         frame.add_shape(Shape::EraserStroke {
             points: vec![(6, 6)],
@@ -355,6 +460,7 @@ mod tests {
             h: 8,
             strength: 12.0,
             style: BlurStyle::Gaussian,
+            rotation: 0.0,
         });
         let mut surface = render_canvas_surface(&snapshot(
             frame,
@@ -395,6 +501,7 @@ mod tests {
             h: 8,
             strength: 12.0,
             style: BlurStyle::Gaussian,
+            rotation: 0.0,
         });
         let mut export = snapshot(
             frame,
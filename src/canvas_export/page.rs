@@ -2,8 +2,9 @@ use std::sync::Arc;
 
 use crate::capture::CaptureError;
 use crate::draw::{
-    BlurRectParams, Color, EraserReplayContext, Frame, Shape, SpotlightPass, render_blur_rect,
-    render_eraser_stroke, render_shape, render_spotlight_pass, spotlight_regions_for_frame,
+    BlurRectParams, Color, EraserReplayContext, Frame, PageBackground, Shape, SpotlightPass,
+    render_blur_rect, render_eraser_stroke, render_frame_layers, render_page_background,
+    render_shape, render_spotlight_pass, spotlight_regions_for_frame, with_appearance,
 };
 
 #[derive(Debug, Clone)]
@@ -237,7 +238,10 @@ impl ExportBackdrop {
         let _ = ctx.restore();
     }
 
-    fn replay_context(&self) -> EraserReplayContext<'_> {
+    fn replay_context<'a>(
+        &'a self,
+        page_background: Option<&'a PageBackground>,
+    ) -> EraserReplayContext<'a> {
         EraserReplayContext {
             pattern: self.pattern.as_ref().map(|p| p as &cairo::Pattern),
             surface: self.surface.as_ref(),
@@ -245,6 +249,8 @@ impl ExportBackdrop {
             bg_color: self.bg_color,
             logical_to_image_scale_x: self.logical_to_image_scale_x,
             logical_to_image_scale_y: self.logical_to_image_scale_y,
+            page_background,
+            grid: None,
         }
     }
 }
@@ -258,12 +264,19 @@ fn draw_canvas_page_contents(
     if paint_backdrop {
         backdrop.paint(ctx);
     }
-    let replay_ctx = backdrop.replay_context();
+    let page_background = page.frame.background();
+    if let Some(page_background) = page_background {
+        render_page_background(ctx, page_background);
+    }
+    let replay_ctx = backdrop.replay_context(page_background);
 
-    for drawn_shape in &page.frame.shapes {
-        match &drawn_shape.shape {
+    render_frame_layers(
+        ctx,
+        &page.frame,
+        &replay_ctx,
+        |drawn_shape, replay_ctx| match &drawn_shape.shape {
             Shape::EraserStroke { points, brush } => {
-                render_eraser_stroke(ctx, points, brush, &replay_ctx);
+                render_eraser_stroke(ctx, points, brush, replay_ctx);
             }
             Shape::BlurRect {
                 x,
@@ -272,6 +285,7 @@ fn draw_canvas_page_contents(
                 h,
                 strength,
                 style,
+                rotation,
             } => render_blur_rect(
                 ctx,
                 BlurRectParams {
@@ -281,13 +295,14 @@ fn draw_canvas_page_contents(
                     h: *h,
                     strength: *strength,
                     style: *style,
+                    rotation: *rotation,
                     cacheable: false,
                 },
-                &replay_ctx,
+                replay_ctx,
             ),
-            other => render_shape(ctx, other),
-        }
-    }
+            other => with_appearance(ctx, drawn_shape.appearance, || render_shape(ctx, other)),
+        },
+    );
 
     // After the shapes, matching the live canvas: eraser strokes clear their path
     // and replay the backdrop, so a dim layer painted earlier would be punched
@@ -108,7 +108,9 @@ pub(crate) fn render_canvas_surface(
     Ok(surface)
 }
 
-fn canvas_page_from_snapshot(snapshot: &CanvasExportSnapshot) -> CanvasPageExportSnapshot {
+pub(super) fn canvas_page_from_snapshot(
+    snapshot: &CanvasExportSnapshot,
+) -> CanvasPageExportSnapshot {
     CanvasPageExportSnapshot {
         frame: snapshot.board.frame.clone_without_history(),
         backdrop: snapshot.backdrop.clone(),
@@ -0,0 +1,60 @@
+use crate::capture::CaptureError;
+use crate::capture::recording::RecordingFrame;
+use crate::config::RecordingRegion;
+
+use super::png::{CanvasExportSnapshot, render_canvas_surface};
+
+/// Render the canvas as one recording frame, cropped to `region` (logical
+/// pixels, clipped to the viewport) or covering the whole viewport.
+pub fn render_recording_frame(
+    snapshot: &CanvasExportSnapshot,
+    region: Option<RecordingRegion>,
+) -> Result<RecordingFrame, CaptureError> {
+    let mut surface = render_canvas_surface(snapshot)?;
+    let scale = snapshot.viewport.scale.max(1) as usize;
+    let surface_width = surface.width().max(0) as usize;
+    let surface_height = surface.height().max(0) as usize;
+    let (x, y, width, height) = match region {
+        Some(region) => {
+            let x = (region.x as usize * scale).min(surface_width);
+            let y = (region.y as usize * scale).min(surface_height);
+            let width = (region.width as usize * scale).min(surface_width - x);
+            let height = (region.height as usize * scale).min(surface_height - y);
+            (x, y, width, height)
+        }
+        None => (0, 0, surface_width, surface_height),
+    };
+    if width == 0 || height == 0 {
+        return Err(CaptureError::ImageError(
+            "Recording region lies outside the output".to_string(),
+        ));
+    }
+
+    surface.flush();
+    let stride = surface.stride().max(0) as usize;
+    let data = surface.data().map_err(|err| {
+        CaptureError::ImageError(format!("Failed to access canvas pixels: {err}"))
+    })?;
+    let mut rgba = Vec::with_capacity(width * height * 4);
+    for row in data.chunks_exact(stride).skip(y).take(height) {
+        for pixel in row[x * 4..(x + width) * 4].chunks_exact(4) {
+            let argb = u32::from_ne_bytes([pixel[0], pixel[1], pixel[2], pixel[3]]);
+            let alpha = (argb >> 24) as u8;
+            let unpremultiply = |shift: u32| {
+                let value = ((argb >> shift) & 0xff) as u16;
+                if alpha == 0 {
+                    0
+                } else {
+                    ((value * 255 + u16::from(alpha) / 2) / u16::from(alpha)).min(255) as u8
+                }
+            };
+            rgba.extend_from_slice(&[unpremultiply(16), unpremultiply(8), unpremultiply(0), alpha]);
+        }
+    }
+
+    Ok(RecordingFrame {
+        width: width as u32,
+        height: height as u32,
+        rgba,
+    })
+}
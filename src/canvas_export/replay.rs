@@ -0,0 +1,48 @@
+use crate::capture::{CaptureError, RenderedImage};
+use crate::draw::frame::ReplayTimeline;
+
+use super::png::{CanvasExportSnapshot, render_canvas_png};
+
+/// A page replay exported as canvas PNG frames.
+///
+/// Frame 0 is the empty page, each shape then takes `frames_per_shape`
+/// frames to appear, and the last frame is the finished page.
+#[derive(Debug, Clone)]
+pub struct ReplayExportSnapshot {
+    canvas: CanvasExportSnapshot,
+    timeline: ReplayTimeline,
+    frames_per_shape: u32,
+}
+
+impl ReplayExportSnapshot {
+    /// Replays the page held by `canvas`, rendered with its viewport,
+    /// backdrop, and render profile.
+    pub fn new(canvas: CanvasExportSnapshot, frames_per_shape: u32) -> Self {
+        let timeline = ReplayTimeline::new(&canvas.board.frame);
+        Self {
+            canvas,
+            timeline,
+            frames_per_shape: frames_per_shape.max(1),
+        }
+    }
+
+    /// Number of frames in the export; zero for an empty page.
+    pub fn frame_count(&self) -> usize {
+        if self.timeline.is_empty() {
+            return 0;
+        }
+        self.timeline.len() * self.frames_per_shape as usize + 1
+    }
+
+    /// The canvas as it looks at frame `index`.
+    pub fn frame_snapshot(&self, index: usize) -> CanvasExportSnapshot {
+        let position = index as f64 / f64::from(self.frames_per_shape);
+        let mut snapshot = self.canvas.clone();
+        snapshot.board.frame = self.timeline.frame_at(position);
+        snapshot
+    }
+
+    pub fn render_frame_png(&self, index: usize) -> Result<RenderedImage, CaptureError> {
+        render_canvas_png(&self.frame_snapshot(index))
+    }
+}
@@ -0,0 +1,282 @@
+use std::fmt::Write as _;
+
+use crate::capture::CaptureError;
+use crate::draw::{Color, spotlight_regions_for_frame};
+use crate::render_profiles::RenderColorProfile;
+use crate::util::Rect;
+
+use super::page::{CanvasExportBackdropSnapshot, CanvasPageExportSnapshot};
+use super::png::{CanvasExportSnapshot, canvas_page_from_snapshot};
+use super::svg_shapes::{Escaped, Num, Paint, SvgPageWriter, image_element};
+
+/// Vertical space between stacked pages in a board SVG.
+const BOARD_PAGE_GAP: u32 = 32;
+
+/// Every page of one board, laid out top to bottom in a single SVG document.
+#[derive(Debug, Clone)]
+pub struct BoardSvgExportSnapshot {
+    pub pages: Vec<CanvasPageExportSnapshot>,
+    pub render_profile: Option<RenderColorProfile>,
+}
+
+/// Renders the visible viewport as a standalone SVG document.
+///
+/// The active render profile is applied to shape colors and to the backdrop,
+/// matching what the PNG export bakes into its pixels.
+pub fn render_canvas_svg(snapshot: &CanvasExportSnapshot) -> Result<Vec<u8>, CaptureError> {
+    let page = canvas_page_from_snapshot(snapshot);
+    if page.viewport_width == 0 || page.viewport_height == 0 {
+        return Err(CaptureError::ImageError(
+            "Canvas export requires a configured non-empty surface".to_string(),
+        ));
+    }
+
+    let mut document = String::new();
+    open_document(&mut document, page.viewport_width, page.viewport_height);
+    write_page(
+        &mut document,
+        &page,
+        "canvas",
+        0,
+        snapshot.render_profile.as_ref(),
+    )?;
+    document.push_str("</svg>\n");
+    Ok(document.into_bytes())
+}
+
+/// Renders every page of a board as one SVG, each page in its own nested `<svg>`.
+///
+/// Like the canvas export, every page is drawn through the active render profile.
+pub fn render_board_svg(snapshot: &BoardSvgExportSnapshot) -> Result<Vec<u8>, CaptureError> {
+    if snapshot.pages.is_empty() {
+        return Err(CaptureError::ImageError(
+            "Board SVG export requires at least one page".to_string(),
+        ));
+    }
+    let width = snapshot
+        .pages
+        .iter()
+        .map(|page| page.viewport_width)
+        .max()
+        .unwrap_or(0);
+    let height = snapshot
+        .pages
+        .iter()
+        .map(|page| page.viewport_height)
+        .fold(0u32, |total, page_height| total.saturating_add(page_height))
+        .saturating_add(BOARD_PAGE_GAP.saturating_mul(snapshot.pages.len() as u32 - 1));
+    if width == 0 || height == 0 {
+        return Err(CaptureError::ImageError(
+            "Board SVG export requires a configured non-empty surface".to_string(),
+        ));
+    }
+
+    let mut document = String::new();
+    open_document(&mut document, width, height);
+    let mut offset_y = 0u32;
+    for (index, page) in snapshot.pages.iter().enumerate() {
+        write_page(
+            &mut document,
+            page,
+            &format!("page-{}", index + 1),
+            offset_y,
+            snapshot.render_profile.as_ref(),
+        )?;
+        offset_y = offset_y
+            .saturating_add(page.viewport_height)
+            .saturating_add(BOARD_PAGE_GAP);
+    }
+    document.push_str("</svg>\n");
+    Ok(document.into_bytes())
+}
+
+fn open_document(document: &mut String, width: u32, height: u32) {
+    document.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        document,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" version="1.1" width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#
+    );
+}
+
+/// Writes one page as a nested `<svg>` whose viewBox is the page's viewport in
+/// canvas coordinates, so shapes keep their original coordinates.
+fn write_page(
+    document: &mut String,
+    page: &CanvasPageExportSnapshot,
+    id: &str,
+    offset_y: u32,
+    profile: Option<&RenderColorProfile>,
+) -> Result<(), CaptureError> {
+    let origin_x = page.origin_x;
+    let origin_y = page.origin_y;
+    let width = page.viewport_width;
+    let height = page.viewport_height;
+
+    let mut writer = SvgPageWriter::new(id.to_string(), profile);
+    for layer in page.frame.layers().iter().filter(|layer| layer.visible) {
+        writer.write_layer(layer, page.frame.shapes_on_layer(layer.id));
+    }
+    write_spotlight(&mut writer, page, origin_x, origin_y, width, height);
+    let backdrop = backdrop_element(&page.backdrop, origin_x, origin_y, width, height, profile)?;
+    let (defs, body) = writer.finish();
+
+    let _ = writeln!(
+        document,
+        r#"<svg id="{}" x="0" y="{offset_y}" width="{width}" height="{height}" viewBox="{origin_x} {origin_y} {width} {height}" overflow="hidden">"#,
+        Escaped(id),
+    );
+    if !defs.is_empty() {
+        let _ = write!(document, "<defs>\n{defs}</defs>\n");
+    }
+    document.push_str(&backdrop);
+    // Outside the shape body, so eraser masks never cut into the imported page.
+    if let Some(background) = page.frame.background() {
+        document.push_str(&image_element(
+            background.x,
+            background.y,
+            background.width,
+            background.height,
+            &background.image,
+        ));
+    }
+    document.push_str(&body);
+    document.push_str("</svg>\n");
+    Ok(())
+}
+
+/// Dim layer masked by one feathered radial opening per spotlight, matching
+/// the Cairo pass that punches the openings out of an isolated group.
+fn write_spotlight(
+    writer: &mut SvgPageWriter<'_>,
+    page: &CanvasPageExportSnapshot,
+    origin_x: i32,
+    origin_y: i32,
+    width: u32,
+    height: u32,
+) {
+    let regions = spotlight_regions_for_frame(&page.frame);
+    let dim = page.spotlight.dim_opacity.clamp(0.0, 1.0);
+    if regions.is_empty() || dim <= f64::EPSILON {
+        return;
+    }
+    let solid_until = (1.0 - page.spotlight.feather.clamp(0.0, 0.9)).clamp(0.0, 1.0);
+
+    let mask_id = writer.fresh_id("spotlight");
+    let mut mask = format!(
+        r#"<mask id="{mask_id}" maskUnits="userSpaceOnUse" x="{origin_x}" y="{origin_y}" width="{width}" height="{height}"><rect x="{origin_x}" y="{origin_y}" width="{width}" height="{height}" fill="white"/>"#
+    );
+    for region in regions {
+        let gradient_id = writer.fresh_id("opening");
+        let rx = region.rx.max(1.0);
+        let ry = region.ry.max(1.0);
+        writer.push_def(&format!(
+            r#"<radialGradient id="{gradient_id}" gradientUnits="userSpaceOnUse" cx="0" cy="0" r="1" gradientTransform="translate({} {}) scale({} {})"><stop offset="0" stop-color="black"/><stop offset="{}" stop-color="black"/><stop offset="1" stop-color="white"/></radialGradient>"#,
+            Num(region.cx),
+            Num(region.cy),
+            Num(rx),
+            Num(ry),
+            Num(solid_until),
+        ));
+        let _ = write!(
+            mask,
+            r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}" fill="url(#{gradient_id})"/>"#,
+            Num(region.cx),
+            Num(region.cy),
+            Num(rx),
+            Num(ry),
+        );
+    }
+    mask.push_str("</mask>\n");
+    writer.push_def(&mask);
+    writer.push_body(&format!(
+        r#"<rect x="{origin_x}" y="{origin_y}" width="{width}" height="{height}" {} mask="url(#{mask_id})"/>
+"#,
+        Paint::fill(Color::new(0.0, 0.0, 0.0, dim)),
+    ));
+}
+
+fn backdrop_element(
+    backdrop: &CanvasExportBackdropSnapshot,
+    origin_x: i32,
+    origin_y: i32,
+    width: u32,
+    height: u32,
+    profile: Option<&RenderColorProfile>,
+) -> Result<String, CaptureError> {
+    match backdrop {
+        CanvasExportBackdropSnapshot::Transparent => Ok(String::new()),
+        CanvasExportBackdropSnapshot::Solid(color) => {
+            let color = match profile {
+                Some(profile) => profile.remap_color(*color),
+                None => *color,
+            };
+            Ok(format!(
+                "<rect x=\"{origin_x}\" y=\"{origin_y}\" width=\"{width}\" height=\"{height}\" {}/>\n",
+                Paint::fill(color),
+            ))
+        }
+        CanvasExportBackdropSnapshot::PersistedImage {
+            data,
+            width: image_width,
+            height: image_height,
+            stride,
+            logical_to_image_scale_x,
+            logical_to_image_scale_y,
+        } => {
+            let png = encode_backdrop_png(data, *image_width, *image_height, *stride, profile)?;
+            Ok(format!(
+                "<image x=\"0\" y=\"0\" width=\"{}\" height=\"{}\" preserveAspectRatio=\"none\" xlink:href=\"data:image/png;base64,{}\"/>\n",
+                Num(f64::from(*image_width) / logical_to_image_scale_x.max(f64::MIN_POSITIVE)),
+                Num(f64::from(*image_height) / logical_to_image_scale_y.max(f64::MIN_POSITIVE)),
+                crate::base64::encode_standard(&png),
+            ))
+        }
+    }
+}
+
+fn encode_backdrop_png(
+    data: &[u8],
+    width: i32,
+    height: i32,
+    stride: i32,
+    profile: Option<&RenderColorProfile>,
+) -> Result<Vec<u8>, CaptureError> {
+    let required = usize::try_from(stride)
+        .ok()
+        .zip(usize::try_from(height).ok())
+        .and_then(|(stride, height)| stride.checked_mul(height))
+        .filter(|required| width > 0 && height > 0 && *required <= data.len())
+        .ok_or_else(|| {
+            CaptureError::ImageError(format!(
+                "Invalid export backdrop: {width}x{height} with stride {stride}"
+            ))
+        })?;
+    let mut pixels = data[..required].to_vec();
+    if let Some(profile) = profile {
+        profile.remap_argb8888_regions(
+            &mut pixels,
+            width,
+            height,
+            stride,
+            &[Rect {
+                x: 0,
+                y: 0,
+                width,
+                height,
+            }],
+        );
+    }
+    let surface =
+        cairo::ImageSurface::create_for_data(pixels, cairo::Format::ARgb32, width, height, stride)
+            .map_err(|err| {
+                CaptureError::ImageError(format!("Failed to create export backdrop: {err}"))
+            })?;
+    let mut png = Vec::new();
+    surface.write_to_png(&mut png).map_err(|err| {
+        CaptureError::ImageError(format!("Failed to encode export backdrop: {err}"))
+    })?;
+    Ok(png)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,330 @@
+use super::*;
+use crate::canvas_export::{BoardExportSnapshot, CanvasExportViewport};
+use crate::config::{RenderColorMappingConfig, RenderProfileConfig};
+use crate::draw::{
+    BLACK, BlendMode, EraserBrush, EraserKind, FontDescriptor, Frame, LineStyle, RED, Shape,
+    ShapeAppearance, WHITE,
+};
+
+fn canvas_snapshot(frame: Frame) -> CanvasExportSnapshot {
+    CanvasExportSnapshot {
+        viewport: CanvasExportViewport {
+            logical_width: 200,
+            logical_height: 100,
+            scale: 2,
+            origin_x: 10,
+            origin_y: 20,
+        },
+        backdrop: CanvasExportBackdropSnapshot::Transparent,
+        board: BoardExportSnapshot { frame },
+        render_profile: None,
+        spotlight: Default::default(),
+    }
+}
+
+fn page(frame: Frame, height: u32) -> CanvasPageExportSnapshot {
+    CanvasPageExportSnapshot {
+        frame,
+        backdrop: CanvasExportBackdropSnapshot::Solid(WHITE),
+        viewport_width: 120,
+        viewport_height: height,
+        origin_x: 0,
+        origin_y: 0,
+        spotlight: Default::default(),
+    }
+}
+
+fn swap_red_for_green() -> RenderColorProfile {
+    RenderColorProfile::from_config(&RenderProfileConfig {
+        id: "swap".to_string(),
+        name: "Swap".to_string(),
+        mappings: vec![RenderColorMappingConfig {
+            from: "#FF0000".to_string(),
+            to: "#00FF00".to_string(),
+        }],
+    })
+    .expect("profile")
+}
+
+fn red_line() -> Shape {
+    Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 10,
+        y2: 10,
+        color: RED,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    }
+}
+
+fn render(frame: Frame) -> String {
+    String::from_utf8(render_canvas_svg(&canvas_snapshot(frame)).expect("svg")).expect("utf8")
+}
+
+#[test]
+fn canvas_svg_uses_logical_viewport_and_origin() {
+    let svg = render(Frame::new());
+
+    assert!(svg.starts_with("<?xml"));
+    assert!(svg.contains(r#"width="200" height="100" viewBox="0 0 200 100""#));
+    assert!(svg.contains(r#"viewBox="10 20 200 100""#));
+    assert!(svg.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+fn shapes_become_vector_elements() {
+    let mut frame = Frame::new();
+    frame.add_shape(Shape::Rect {
+        x: 30,
+        y: 40,
+        w: -10,
+        h: 5,
+        fill: true,
+        color: RED,
+        thick: 2.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
+    });
+    frame.add_shape(Shape::Freehand {
+        points: vec![(0, 0), (5, 5), (10, 0)],
+        color: BLACK,
+        thick: 3.0,
+        line_style: LineStyle::Solid,
+    });
+    let svg = render(frame);
+
+    assert!(svg.contains(r##"<rect x="20" y="40" width="10" height="5" fill="#ff0000""##));
+    assert!(svg.contains(r#"d="M0 0 L5 5 L10 0""#));
+}
+
+#[test]
+fn styled_strokes_carry_a_dash_array() {
+    let mut frame = Frame::new();
+    frame.add_shape(Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 50,
+        y2: 0,
+        color: BLACK,
+        thick: 2.0,
+        line_style: LineStyle::Dashed,
+    });
+    frame.add_shape(Shape::Ellipse {
+        cx: 20,
+        cy: 20,
+        rx: 10,
+        ry: 5,
+        fill: false,
+        color: RED,
+        thick: 2.0,
+        rotation: 0.0,
+        line_style: LineStyle::Dotted,
+    });
+    let svg = render(frame);
+
+    assert!(svg.contains(r#"stroke-dasharray="6 8""#));
+    assert!(svg.contains(r#"stroke-dasharray="0 5" stroke-linecap="round""#));
+}
+
+#[test]
+fn shape_appearance_wraps_the_shape_in_a_blended_group() {
+    let mut frame = Frame::new();
+    let id = frame.add_shape(Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 50,
+        y2: 0,
+        color: BLACK,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    });
+    frame.add_shape(Shape::Line {
+        x1: 0,
+        y1: 10,
+        x2: 50,
+        y2: 10,
+        color: RED,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    });
+    frame.shape_mut(id).expect("line").appearance = ShapeAppearance {
+        opacity: 0.5,
+        blend: BlendMode::Multiply,
+    };
+    let svg = render(frame);
+
+    assert!(svg.contains(r#"<g opacity="0.5" style="mix-blend-mode:multiply">"#));
+    assert_eq!(svg.matches("mix-blend-mode").count(), 1);
+}
+
+#[test]
+fn text_is_escaped_and_kept_as_text() {
+    let mut frame = Frame::new();
+    frame.add_shape(Shape::Text {
+        x: 10,
+        y: 40,
+        text: "a < b & \"c\"".to_string(),
+        color: BLACK,
+        size: 24.0,
+        font_descriptor: FontDescriptor::default(),
+        background_enabled: false,
+        wrap_width: None,
+        rotation: 0.0,
+    });
+    let svg = render(frame);
+
+    assert!(svg.contains("a &lt; b &amp; &quot;c&quot;"));
+    assert!(svg.contains("<tspan"));
+}
+
+#[test]
+fn text_markup_becomes_styled_spans_without_markers() {
+    let mut frame = Frame::new();
+    frame.add_shape(Shape::Text {
+        x: 10,
+        y: 40,
+        text: "a **bold** word\n- item".to_string(),
+        color: BLACK,
+        size: 24.0,
+        font_descriptor: FontDescriptor::default(),
+        background_enabled: false,
+        wrap_width: None,
+        rotation: 0.0,
+    });
+    let svg = render(frame);
+
+    assert!(svg.contains(r#"<tspan font-weight="bold">bold</tspan>"#));
+    assert!(svg.contains("• item"));
+    assert!(!svg.contains("**"));
+}
+
+#[test]
+fn eraser_masks_earlier_shapes_only() {
+    let mut frame = Frame::new();
+    frame.add_shape(Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 50,
+        y2: 50,
+        color: RED,
+        thick: 4.0,
+        line_style: LineStyle::Solid,
+    });
+    frame.add_shape(Shape::EraserStroke {
+        points: vec![(10, 10), (20, 20)],
+        brush: EraserBrush {
+            size: 8.0,
+            kind: EraserKind::Circle,
+        },
+    });
+    frame.add_shape(Shape::Line {
+        x1: 0,
+        y1: 50,
+        x2: 50,
+        y2: 0,
+        color: BLACK,
+        thick: 4.0,
+        line_style: LineStyle::Solid,
+    });
+    let svg = render(frame);
+
+    let group = svg
+        .find("<g mask=\"url(#canvas-erase1)\">")
+        .expect("masked group");
+    let close = svg[group..].find("</g>").expect("group end") + group;
+    assert!(svg[group..close].contains("stroke=\"#ff0000\""));
+    assert!(svg[close..].contains("stroke=\"#000000\""));
+}
+
+#[test]
+fn layers_group_their_shapes_and_keep_erasers_to_their_own_layer() {
+    let line = |color| Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 50,
+        y2: 50,
+        color,
+        thick: 4.0,
+        line_style: LineStyle::Solid,
+    };
+    let mut frame = Frame::new();
+    frame.add_shape(line(RED));
+    let ink = frame.add_layer().expect("ink layer");
+    frame.add_shape(Shape::EraserStroke {
+        points: vec![(10, 10), (20, 20)],
+        brush: EraserBrush {
+            size: 8.0,
+            kind: EraserKind::Circle,
+        },
+    });
+    frame.add_shape(line(BLACK));
+    assert!(frame.set_layer_opacity(ink, 0.5));
+    let hidden = frame.add_layer().expect("hidden layer");
+    frame.add_shape(line(WHITE));
+    assert!(frame.set_layer_visible(hidden, false));
+    let svg = render(frame);
+
+    let layer = svg.find(r#"<g opacity="0.5">"#).expect("faded layer group");
+    assert!(svg[..layer].contains("stroke=\"#ff0000\""));
+    assert!(!svg[layer..].contains("stroke=\"#ff0000\""));
+    assert!(svg[layer..].contains("stroke=\"#000000\""));
+    assert!(!svg.contains("stroke=\"#ffffff\""));
+}
+
+#[test]
+fn render_profile_remaps_shape_colors() {
+    let mut frame = Frame::new();
+    frame.add_shape(red_line());
+    let mut snapshot = canvas_snapshot(frame);
+    snapshot.render_profile = Some(swap_red_for_green());
+    let svg = String::from_utf8(render_canvas_svg(&snapshot).expect("svg")).expect("utf8");
+
+    assert!(svg.contains("stroke=\"#00ff00\""));
+    assert!(!svg.contains("stroke=\"#ff0000\""));
+}
+
+#[test]
+fn board_svg_stacks_pages_vertically() {
+    let snapshot = BoardSvgExportSnapshot {
+        pages: vec![page(Frame::new(), 80), page(Frame::new(), 60)],
+        render_profile: None,
+    };
+    let svg = String::from_utf8(render_board_svg(&snapshot).expect("svg")).expect("utf8");
+
+    let total = 80 + 60 + BOARD_PAGE_GAP;
+    assert!(svg.contains(&format!(r#"width="120" height="{total}""#)));
+    assert!(svg.contains(r#"<svg id="page-1" x="0" y="0""#));
+    assert!(svg.contains(&format!(
+        r#"<svg id="page-2" x="0" y="{}""#,
+        80 + BOARD_PAGE_GAP
+    )));
+    assert!(svg.contains(r##"fill="#ffffff""##));
+}
+
+#[test]
+fn board_svg_without_pages_is_rejected() {
+    assert!(
+        render_board_svg(&BoardSvgExportSnapshot {
+            pages: Vec::new(),
+            render_profile: None,
+        })
+        .is_err()
+    );
+}
+
+#[test]
+fn board_svg_applies_the_render_profile_to_every_page() {
+    let mut first = Frame::new();
+    first.add_shape(red_line());
+    let mut second = Frame::new();
+    second.add_shape(red_line());
+    let snapshot = BoardSvgExportSnapshot {
+        pages: vec![page(first, 80), page(second, 60)],
+        render_profile: Some(swap_red_for_green()),
+    };
+    let svg = String::from_utf8(render_board_svg(&snapshot).expect("svg")).expect("utf8");
+
+    assert_eq!(svg.matches("stroke=\"#00ff00\"").count(), 2);
+    assert!(!svg.contains("stroke=\"#ff0000\""));
+}
@@ -0,0 +1,1065 @@
+use std::fmt::{self, Write as _};
+
+use crate::draw::render::{PLACEHOLDER_FILL, PLACEHOLDER_STROKE, REDACTION_EDGE};
+use crate::draw::shape::{
+    ARROW_LABEL_BACKGROUND, CODE_FONT_FAMILY, SpanStyle, TextMarkup, arrow_label_layout,
+    font_pixel_size, has_minimum_distinct_points, heading_scale, measure_text_cached, parse_markup,
+    step_marker_colors, step_marker_outline_thickness, step_marker_radius, sticky_note_layout,
+    visual_lines,
+};
+use crate::draw::{
+    BlendMode, Color, DrawnShape, EmbeddedImage, EraserBrush, EraserKind, FontDescriptor, Layer,
+    LineStyle, Shape, ShapeAppearance, sticky_note_foreground, text_outline_color,
+};
+use crate::render_profiles::RenderColorProfile;
+use crate::util::{calculate_arrow_outline, calculate_dashed_arrow};
+
+/// Writes the elements of one canvas page.
+///
+/// Shapes accumulate into `body` in z-order. Eraser strokes cannot punch holes
+/// into vector content, so each one wraps everything drawn so far in a group
+/// masked by the stroke; the backdrop sits outside that group and shows through,
+/// which is what the Cairo replay paints back into the cleared path.
+pub(super) struct SvgPageWriter<'a> {
+    id_prefix: String,
+    next_id: usize,
+    defs: String,
+    body: String,
+    profile: Option<&'a RenderColorProfile>,
+}
+
+impl<'a> SvgPageWriter<'a> {
+    pub(super) fn new(id_prefix: String, profile: Option<&'a RenderColorProfile>) -> Self {
+        Self {
+            id_prefix,
+            next_id: 0,
+            defs: String::new(),
+            body: String::new(),
+            profile,
+        }
+    }
+
+    pub(super) fn fresh_id(&mut self, kind: &str) -> String {
+        self.next_id += 1;
+        format!("{}-{kind}{}", self.id_prefix, self.next_id)
+    }
+
+    pub(super) fn push_def(&mut self, def: &str) {
+        self.defs.push_str(def);
+    }
+
+    pub(super) fn push_body(&mut self, element: &str) {
+        self.body.push_str(element);
+    }
+
+    pub(super) fn finish(self) -> (String, String) {
+        (self.defs, self.body)
+    }
+
+    fn color(&self, color: Color) -> Color {
+        match self.profile {
+            Some(profile) => profile.remap_color(color),
+            None => color,
+        }
+    }
+
+    /// Writes one layer's shapes as a group of their own, so its eraser
+    /// strokes only mask shapes on the same layer, faded by the layer opacity.
+    pub(super) fn write_layer(&mut self, layer: &Layer, shapes: &[DrawnShape]) {
+        let below = std::mem::take(&mut self.body);
+        for drawn in shapes {
+            self.write_drawn_shape(drawn);
+        }
+        let layer_body = std::mem::replace(&mut self.body, below);
+        let opacity = layer.appearance().clamped_opacity();
+        if opacity < 1.0 && !layer_body.is_empty() {
+            let _ = writeln!(self.body, r#"<g opacity="{}">"#, Num(opacity));
+            self.body.push_str(&layer_body);
+            self.body.push_str("</g>\n");
+        } else {
+            self.body.push_str(&layer_body);
+        }
+    }
+
+    /// Writes a committed shape, wrapped in a group carrying its opacity and
+    /// blend mode when it has either.
+    pub(super) fn write_drawn_shape(&mut self, drawn: &DrawnShape) {
+        let appearance = drawn.appearance;
+        if appearance.is_default() || !ShapeAppearance::applies_to(&drawn.shape) {
+            self.write_shape(&drawn.shape);
+            return;
+        }
+        self.body.push_str("<g");
+        if appearance.clamped_opacity() < 1.0 {
+            let _ = write!(
+                self.body,
+                r#" opacity="{}""#,
+                Num(appearance.clamped_opacity())
+            );
+        }
+        if appearance.blend != BlendMode::Normal {
+            let _ = write!(
+                self.body,
+                r#" style="mix-blend-mode:{}""#,
+                appearance.blend.css_name()
+            );
+        }
+        self.body.push_str(">\n");
+        self.write_shape(&drawn.shape);
+        self.body.push_str("</g>\n");
+    }
+
+    pub(super) fn write_shape(&mut self, shape: &Shape) {
+        let Some((cx, cy)) = shape.rotation_pivot() else {
+            self.write_unrotated_shape(shape);
+            return;
+        };
+        let _ = writeln!(
+            self.body,
+            r#"<g transform="rotate({} {} {})">"#,
+            Num(shape.rotation()),
+            Num(cx),
+            Num(cy),
+        );
+        self.write_unrotated_shape(shape);
+        self.body.push_str("</g>\n");
+    }
+
+    fn write_unrotated_shape(&mut self, shape: &Shape) {
+        match shape {
+            Shape::Freehand {
+                points,
+                color,
+                thick,
+                line_style,
+            } => self.write_polyline(points, *color, *thick, *line_style),
+            Shape::FreehandPressure { points, color } => {
+                self.write_pressure_stroke(points, *color);
+            }
+            Shape::Line {
+                x1,
+                y1,
+                x2,
+                y2,
+                color,
+                thick,
+                line_style,
+            } => {
+                let _ = writeln!(
+                    self.body,
+                    r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" {} stroke-width="{}"{} stroke-linecap="round"/>"#,
+                    Paint::stroke(self.color(*color)),
+                    Num(*thick),
+                    DashArray(*line_style, *thick),
+                );
+            }
+            Shape::Rect {
+                x,
+                y,
+                w,
+                h,
+                fill,
+                color,
+                thick,
+                line_style,
+                ..
+            } => {
+                let (left, top, width, height) = normalized_rect(*x, *y, *w, *h);
+                let color = self.color(*color);
+                let _ = writeln!(
+                    self.body,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" {} {} stroke-width="{}"{}{} stroke-linejoin="miter"/>"#,
+                    Num(left),
+                    Num(top),
+                    Num(width),
+                    Num(height),
+                    fill_or_none(*fill, color),
+                    Paint::stroke(color),
+                    Num(*thick),
+                    DashArray(*line_style, *thick),
+                    DashCaps(*line_style),
+                );
+            }
+            Shape::Ellipse {
+                cx,
+                cy,
+                rx,
+                ry,
+                fill,
+                color,
+                thick,
+                line_style,
+                ..
+            } => {
+                if *rx == 0 || *ry == 0 {
+                    return;
+                }
+                let color = self.color(*color);
+                let _ = writeln!(
+                    self.body,
+                    r#"<ellipse cx="{cx}" cy="{cy}" rx="{}" ry="{}" {} {} stroke-width="{}"{}{}/>"#,
+                    rx.unsigned_abs(),
+                    ry.unsigned_abs(),
+                    fill_or_none(*fill, color),
+                    Paint::stroke(color),
+                    Num(*thick),
+                    DashArray(*line_style, *thick),
+                    DashCaps(*line_style),
+                );
+            }
+            Shape::Polygon {
+                points,
+                fill,
+                color,
+                thick,
+                line_style,
+                ..
+            } => {
+                if !has_minimum_distinct_points(points) {
+                    return;
+                }
+                let color = self.color(*color);
+                let _ = writeln!(
+                    self.body,
+                    r#"<polygon points="{}" {} {} stroke-width="{}"{} stroke-linecap="round" stroke-linejoin="round"/>"#,
+                    PointList(points),
+                    fill_or_none(*fill, color),
+                    Paint::stroke(color),
+                    Num(*thick),
+                    DashArray(*line_style, *thick),
+                );
+            }
+            Shape::Arrow {
+                x1,
+                y1,
+                x2,
+                y2,
+                color,
+                thick,
+                arrow_length,
+                arrow_angle,
+                head_at_end,
+                label,
+                line_style,
+            } => {
+                let (tip_x, tip_y, tail_x, tail_y) = if *head_at_end {
+                    (*x2, *y2, *x1, *y1)
+                } else {
+                    (*x1, *y1, *x2, *y2)
+                };
+                let color = self.color(*color);
+                if !line_style.is_solid() {
+                    self.write_dashed_arrow(
+                        (tip_x, tip_y, tail_x, tail_y),
+                        color,
+                        *thick,
+                        (*arrow_length, *arrow_angle),
+                        *line_style,
+                    );
+                } else if let Some(outline) = calculate_arrow_outline(
+                    tip_x,
+                    tip_y,
+                    tail_x,
+                    tail_y,
+                    *thick,
+                    *arrow_length,
+                    *arrow_angle,
+                ) {
+                    let mut points = String::new();
+                    for (index, (x, y)) in outline.points.iter().enumerate() {
+                        if index > 0 {
+                            points.push(' ');
+                        }
+                        let _ = write!(points, "{},{}", Num(*x), Num(*y));
+                    }
+                    let _ = writeln!(
+                        self.body,
+                        r#"<polygon points="{points}" {}/>"#,
+                        Paint::fill(color),
+                    );
+                }
+                if let Some(label) = label {
+                    let label_text = label.value.to_string();
+                    if let Some(layout) = arrow_label_layout(
+                        tip_x,
+                        tip_y,
+                        tail_x,
+                        tail_y,
+                        *thick,
+                        &label_text,
+                        label.size,
+                        &label.font_descriptor,
+                    ) {
+                        self.write_text(TextRun {
+                            x: layout.x,
+                            y: layout.y,
+                            text: &label_text,
+                            color,
+                            size: label.size,
+                            font_descriptor: &label.font_descriptor,
+                            background_enabled: ARROW_LABEL_BACKGROUND,
+                            wrap_width: None,
+                        });
+                    }
+                }
+            }
+            Shape::BlurRect {
+                x, y, w, h, style, ..
+            } => {
+                // Vector output has no captured pixels to sample, so the
+                // backdrop styles fall back to the same placeholder the PNG
+                // export uses without a persisted backdrop.
+                let (left, top, width, height) = normalized_rect(*x, *y, *w, *h);
+                let (fill, stroke) = if style.needs_backdrop() {
+                    (rgba(PLACEHOLDER_FILL), rgba(PLACEHOLDER_STROKE))
+                } else {
+                    (Color::new(0.0, 0.0, 0.0, 1.0), rgba(REDACTION_EDGE))
+                };
+                let _ = writeln!(
+                    self.body,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" {} {} stroke-width="1"/>"#,
+                    Num(left),
+                    Num(top),
+                    Num(width),
+                    Num(height),
+                    Paint::fill(fill),
+                    Paint::stroke(stroke),
+                );
+            }
+            Shape::Spotlight { .. } => {
+                // Consumed by the page-wide spotlight pass.
+            }
+            Shape::Text {
+                x,
+                y,
+                text,
+                color,
+                size,
+                font_descriptor,
+                background_enabled,
+                wrap_width,
+                ..
+            } => {
+                let color = self.color(*color);
+                self.write_text(TextRun {
+                    x: *x,
+                    y: *y,
+                    text,
+                    color,
+                    size: *size,
+                    font_descriptor,
+                    background_enabled: *background_enabled,
+                    wrap_width: *wrap_width,
+                });
+            }
+            Shape::StepMarker { x, y, color, label } => {
+                self.write_step_marker(*x, *y, *color, label);
+            }
+            Shape::StickyNote {
+                x,
+                y,
+                text,
+                background,
+                size,
+                font_descriptor,
+                wrap_width,
+            } => {
+                let background = self.color(*background);
+                self.write_sticky_note(
+                    *x,
+                    *y,
+                    text,
+                    background,
+                    *size,
+                    font_descriptor,
+                    *wrap_width,
+                );
+            }
+            Shape::MarkerStroke {
+                points,
+                color,
+                thick,
+            } => self.write_marker_stroke(points, *color, *thick),
+            Shape::EraserStroke { points, brush } => self.apply_eraser(points, brush),
+            Shape::Image {
+                x, y, w, h, data, ..
+            } => self.write_image(*x, *y, *w, *h, data),
+        }
+    }
+
+    fn write_polyline(
+        &mut self,
+        points: &[(i32, i32)],
+        color: Color,
+        thick: f64,
+        line_style: LineStyle,
+    ) {
+        if points.is_empty() {
+            return;
+        }
+        let _ = writeln!(
+            self.body,
+            r#"<path d="{}" fill="none" {} stroke-width="{}"{} stroke-linecap="round" stroke-linejoin="round"/>"#,
+            PathData(points),
+            Paint::stroke(self.color(color)),
+            Num(thick),
+            DashArray(line_style, thick),
+        );
+    }
+
+    /// Mirrors the Cairo dashed arrow: a dashed shaft from the tail plus the
+    /// head triangle as its own fill. `color` is already profile-mapped.
+    fn write_dashed_arrow(
+        &mut self,
+        (tip_x, tip_y, tail_x, tail_y): (i32, i32, i32, i32),
+        color: Color,
+        thick: f64,
+        (arrow_length, arrow_angle): (f64, f64),
+        line_style: LineStyle,
+    ) {
+        let Some(arrow) = calculate_dashed_arrow(
+            tip_x,
+            tip_y,
+            tail_x,
+            tail_y,
+            thick,
+            arrow_length,
+            arrow_angle,
+        ) else {
+            return;
+        };
+        if let Some((end_x, end_y)) = arrow.shaft_end {
+            let _ = writeln!(
+                self.body,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" {} stroke-width="{}"{} stroke-linecap="round"/>"#,
+                Num(arrow.tail.0),
+                Num(arrow.tail.1),
+                Num(end_x),
+                Num(end_y),
+                Paint::stroke(color),
+                Num(thick),
+                DashArray(line_style, thick),
+            );
+        }
+        let head = arrow.head;
+        let _ = writeln!(
+            self.body,
+            r#"<polygon points="{},{} {},{} {},{}" {}/>"#,
+            Num(head.tip.0),
+            Num(head.tip.1),
+            Num(head.left.0),
+            Num(head.left.1),
+            Num(head.right.0),
+            Num(head.right.1),
+            Paint::fill(color),
+        );
+    }
+
+    /// Pressure strokes become one round-capped segment per point pair, each at
+    /// the mean of its endpoint widths. The segments are opaque inside a group
+    /// carrying the alpha, so their overlapping caps do not darken.
+    fn write_pressure_stroke(&mut self, points: &[(i32, i32, f32)], color: Color) {
+        if points.is_empty() {
+            return;
+        }
+        let color = self.color(color);
+        let _ = writeln!(
+            self.body,
+            r#"<g opacity="{}" stroke="{}" stroke-linecap="round" fill="none">"#,
+            Num(color.a.clamp(0.0, 1.0)),
+            Hex(color),
+        );
+        if let [(x, y, thick)] = points {
+            let _ = writeln!(
+                self.body,
+                r#"<circle cx="{x}" cy="{y}" r="{}" fill="{}" stroke="none"/>"#,
+                Num(f64::from(*thick) / 2.0),
+                Hex(color),
+            );
+        }
+        for pair in points.windows(2) {
+            let (x1, y1, t1) = pair[0];
+            let (x2, y2, t2) = pair[1];
+            let width = (f64::from(t1) + f64::from(t2)) / 2.0;
+            let _ = writeln!(
+                self.body,
+                r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke-width="{}"/>"#,
+                Num(width),
+            );
+        }
+        self.body.push_str("</g>\n");
+    }
+
+    /// Mirrors the Cairo marker: a soft wide pass and a core pass, both
+    /// screen-blended, with the ink alpha reduced so text underneath stays legible.
+    fn write_marker_stroke(&mut self, points: &[(i32, i32)], color: Color, thick: f64) {
+        if points.is_empty() {
+            return;
+        }
+        let color = self.color(color);
+        let base_alpha = (color.a * 0.32).clamp(0.05, 0.85);
+        let soft_width = (thick * 1.25).max(thick + 1.0);
+        let _ = writeln!(
+            self.body,
+            r#"<g style="mix-blend-mode:screen" fill="none" stroke="{}" stroke-linecap="round" stroke-linejoin="round">"#,
+            Hex(color),
+        );
+        for (width, alpha) in [(soft_width, base_alpha * 0.7), (thick, base_alpha)] {
+            let _ = writeln!(
+                self.body,
+                r#"<path d="{}" stroke-width="{}" stroke-opacity="{}"/>"#,
+                PathData(points),
+                Num(width),
+                Num(alpha),
+            );
+        }
+        self.body.push_str("</g>\n");
+    }
+
+    fn apply_eraser(&mut self, points: &[(i32, i32)], brush: &EraserBrush) {
+        if points.is_empty() || self.body.is_empty() {
+            return;
+        }
+        let id = self.fresh_id("erase");
+        let width = brush.size.max(1.0);
+        let half = width / 2.0;
+        let mut mask = format!(
+            r#"<mask id="{id}" maskUnits="userSpaceOnUse" x="-100000" y="-100000" width="200000" height="200000"><rect x="-100000" y="-100000" width="200000" height="200000" fill="white"/>"#
+        );
+        match (points, brush.kind) {
+            ([(x, y)], EraserKind::Circle) => {
+                let _ = write!(
+                    mask,
+                    r#"<circle cx="{x}" cy="{y}" r="{}" fill="black"/>"#,
+                    Num(half)
+                );
+            }
+            ([(x, y)], EraserKind::Rect) => {
+                let _ = write!(
+                    mask,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="black"/>"#,
+                    Num(f64::from(*x) - half),
+                    Num(f64::from(*y) - half),
+                    Num(width),
+                    Num(width),
+                );
+            }
+            (_, kind) => {
+                let (cap, join) = match kind {
+                    EraserKind::Circle => ("round", "round"),
+                    EraserKind::Rect => ("square", "miter"),
+                };
+                let _ = write!(
+                    mask,
+                    r#"<path d="{}" fill="none" stroke="black" stroke-width="{}" stroke-linecap="{cap}" stroke-linejoin="{join}"/>"#,
+                    PathData(points),
+                    Num(width),
+                );
+            }
+        }
+        mask.push_str("</mask>\n");
+        self.defs.push_str(&mask);
+        let erased = std::mem::take(&mut self.body);
+        self.body = format!("<g mask=\"url(#{id})\">\n{erased}</g>\n");
+    }
+
+    /// Text follows the Cairo run: optional translucent backing box, a soft
+    /// drop shadow, then the glyphs filled over a contrasting outline.
+    fn write_text(&mut self, run: TextRun<'_>) {
+        if run.text.is_empty() {
+            return;
+        }
+        let font_desc_str = run.font_descriptor.to_pango_string(run.size);
+        let Some(measurement) = measure_text_cached(
+            run.text,
+            &font_desc_str,
+            run.size,
+            run.wrap_width,
+            TextMarkup::Rendered,
+        ) else {
+            return;
+        };
+        let outline = text_outline_color(run.color);
+        let x = f64::from(run.x);
+        let y = f64::from(run.y);
+
+        let content = measurement.content_extents(run.wrap_width);
+        if run.background_enabled && content.width > 0.0 && content.height > 0.0 {
+            let padding = run.size * 0.15;
+            let top = y - measurement.baseline;
+            let _ = writeln!(
+                self.body,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" {}/>"#,
+                Num(x + content.x - padding),
+                Num(top + content.y - padding),
+                Num(content.width + padding * 2.0),
+                Num(content.height + padding * 2.0),
+                Paint::fill(Color { a: 0.3, ..outline }),
+            );
+        }
+
+        let shadow_offset = run.size * 0.04;
+        let shadow = Color::new(0.0, 0.0, 0.0, 0.4);
+        self.write_text_lines(
+            x + shadow_offset,
+            y + shadow_offset,
+            run.text,
+            run.size,
+            run.font_descriptor,
+            run.wrap_width,
+            &Paint::fill(shadow).to_string(),
+            TextMarkup::Rendered,
+        );
+        let paint = format!(
+            r#"{} {} stroke-width="{}" stroke-linejoin="round" paint-order="stroke""#,
+            Paint::fill(run.color),
+            Paint::stroke(outline),
+            Num(run.size * 0.06),
+        );
+        self.write_text_lines(
+            x,
+            y,
+            run.text,
+            run.size,
+            run.font_descriptor,
+            run.wrap_width,
+            &paint,
+            TextMarkup::Rendered,
+        );
+    }
+
+    /// Emits one `<text>` element whose `<tspan>`s sit on Pango's visual lines,
+    /// so wrapping and line spacing match the canvas and the text stays
+    /// selectable in viewers. Markup styles become nested `<tspan>`s.
+    #[allow(clippy::too_many_arguments)]
+    fn write_text_lines(
+        &mut self,
+        x: f64,
+        baseline_y: f64,
+        text: &str,
+        size: f64,
+        font_descriptor: &FontDescriptor,
+        wrap_width: Option<i32>,
+        paint: &str,
+        markup: TextMarkup,
+    ) {
+        let font_desc_str = font_descriptor.to_pango_string(size);
+        let rich = parse_markup(text, markup);
+        let Some(lines) = visual_lines(&rich, &font_desc_str, wrap_width) else {
+            return;
+        };
+        let font_size = font_pixel_size(size.round());
+        let _ = write!(
+            self.body,
+            r#"<text xml:space="preserve" font-family="{}" font-size="{}" font-weight="{}" font-style="{}" {paint}>"#,
+            Escaped(&font_descriptor.family),
+            Num(font_size),
+            svg_font_weight(&font_descriptor.weight),
+            svg_font_style(&font_descriptor.style),
+        );
+        for line in lines {
+            let Some(content) = rich.text.get(line.range.clone()) else {
+                continue;
+            };
+            let content_len = content.trim_end_matches(['\n', '\r']).len();
+            let _ = write!(
+                self.body,
+                r#"<tspan x="{}" y="{}">"#,
+                Num(x + line.x),
+                Num(baseline_y + line.baseline_from_first),
+            );
+            for (range, style) in rich.runs_in(line.range.start..line.range.start + content_len) {
+                let piece = Escaped(&rich.text[range]);
+                if style.is_plain() {
+                    let _ = write!(self.body, "{piece}");
+                } else {
+                    let _ = write!(
+                        self.body,
+                        "<tspan{}>{piece}</tspan>",
+                        SpanAttributes(style, font_size)
+                    );
+                }
+            }
+            self.body.push_str("</tspan>");
+        }
+        self.body.push_str("</text>\n");
+    }
+
+    fn write_step_marker(
+        &mut self,
+        x: i32,
+        y: i32,
+        color: Color,
+        label: &crate::draw::StepMarkerLabel,
+    ) {
+        let label_text = label.value.to_string();
+        let radius = step_marker_radius(label.value, label.size, &label.font_descriptor).max(1.0);
+        let outline_thickness = step_marker_outline_thickness(label.size);
+        let colors = step_marker_colors(self.color(color));
+        let _ = writeln!(
+            self.body,
+            r#"<circle cx="{x}" cy="{y}" r="{}" {} {} stroke-width="{}"/>"#,
+            Num(radius),
+            Paint::fill(colors.fill),
+            Paint::stroke(colors.outline),
+            Num(outline_thickness),
+        );
+        let font_desc_str = label.font_descriptor.to_pango_string(label.size);
+        if let Some(metrics) = measure_text_cached(
+            &label_text,
+            &font_desc_str,
+            label.size,
+            None,
+            TextMarkup::Plain,
+        ) {
+            let center_offset_x = metrics.ink_x + metrics.ink_width / 2.0;
+            let center_offset_y = metrics.ink_y + metrics.ink_height / 2.0;
+            let baseline_x = (f64::from(x) - center_offset_x).round();
+            let baseline_y = (f64::from(y) - center_offset_y + metrics.baseline).round();
+            let paint = Paint::fill(colors.text).to_string();
+            self.write_text_lines(
+                baseline_x,
+                baseline_y,
+                &label_text,
+                label.size,
+                &label.font_descriptor,
+                None,
+                &paint,
+                TextMarkup::Plain,
+            );
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn write_sticky_note(
+        &mut self,
+        x: i32,
+        y: i32,
+        text: &str,
+        background: Color,
+        size: f64,
+        font_descriptor: &FontDescriptor,
+        wrap_width: Option<i32>,
+    ) {
+        if text.is_empty() {
+            return;
+        }
+        let font_desc_str = font_descriptor.to_pango_string(size);
+        let Some(measurement) =
+            measure_text_cached(text, &font_desc_str, size, wrap_width, TextMarkup::Rendered)
+        else {
+            return;
+        };
+        let base_x = f64::from(x);
+        let base_y = f64::from(y) - measurement.baseline;
+        let content = measurement.content_extents(wrap_width);
+        let note = sticky_note_layout(
+            base_x,
+            base_y,
+            content.x,
+            content.y,
+            content.width,
+            content.height,
+            size,
+        );
+        let radius = note
+            .corner_radius
+            .min(note.note_width / 2.0)
+            .min(note.note_height / 2.0);
+        let shadow_alpha = (0.25 * background.a).clamp(0.0, 0.35);
+        for (offset, paint) in [
+            (
+                note.shadow_offset,
+                Paint::fill(Color::new(0.0, 0.0, 0.0, shadow_alpha)),
+            ),
+            (0.0, Paint::fill(background)),
+        ] {
+            let _ = writeln!(
+                self.body,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" rx="{r}" ry="{r}" {paint}/>"#,
+                Num(note.note_x + offset),
+                Num(note.note_y + offset),
+                Num(note.note_width),
+                Num(note.note_height),
+                r = Num(radius),
+            );
+        }
+        let paint = Paint::fill(sticky_note_foreground(background)).to_string();
+        self.write_text_lines(
+            base_x,
+            f64::from(y),
+            text,
+            size,
+            font_descriptor,
+            wrap_width,
+            &paint,
+            TextMarkup::Rendered,
+        );
+    }
+
+    fn write_image(&mut self, x: i32, y: i32, w: i32, h: i32, data: &EmbeddedImage) {
+        self.body.push_str(&image_element(x, y, w, h, data));
+    }
+}
+
+/// An embedded raster as one `<image>` line.
+pub(super) fn image_element(x: i32, y: i32, w: i32, h: i32, data: &EmbeddedImage) -> String {
+    let (left, top, width, height) = normalized_rect(x, y, w, h);
+    format!(
+        "<image x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" preserveAspectRatio=\"none\" xlink:href=\"data:{};base64,{}\"/>\n",
+        Num(left),
+        Num(top),
+        Num(width),
+        Num(height),
+        Escaped(&data.mime_type),
+        crate::base64::encode_standard(&data.bytes),
+    )
+}
+
+struct TextRun<'a> {
+    x: i32,
+    y: i32,
+    text: &'a str,
+    color: Color,
+    size: f64,
+    font_descriptor: &'a FontDescriptor,
+    background_enabled: bool,
+    wrap_width: Option<i32>,
+}
+
+fn normalized_rect(x: i32, y: i32, w: i32, h: i32) -> (f64, f64, f64, f64) {
+    let (left, width) = if w >= 0 {
+        (f64::from(x), f64::from(w))
+    } else {
+        (f64::from(x) + f64::from(w), -f64::from(w))
+    };
+    let (top, height) = if h >= 0 {
+        (f64::from(y), f64::from(h))
+    } else {
+        (f64::from(y) + f64::from(h), -f64::from(h))
+    };
+    (left, top, width, height)
+}
+
+fn rgba((r, g, b, a): (f64, f64, f64, f64)) -> Color {
+    Color::new(r, g, b, a)
+}
+
+fn fill_or_none(fill: bool, color: Color) -> String {
+    if fill {
+        Paint::fill(color).to_string()
+    } else {
+        r#"fill="none""#.to_string()
+    }
+}
+
+fn svg_font_weight(weight: &str) -> &str {
+    let trimmed = weight.trim();
+    if !trimmed.is_empty() && trimmed.bytes().all(|byte| byte.is_ascii_digit()) {
+        return trimmed;
+    }
+    match trimmed
+        .to_ascii_lowercase()
+        .replace(['-', '_', ' '], "")
+        .as_str()
+    {
+        "thin" => "100",
+        "ultralight" | "extralight" => "200",
+        "light" => "300",
+        "book" | "normal" | "regular" => "normal",
+        "medium" => "500",
+        "semibold" | "demibold" => "600",
+        "bold" => "bold",
+        "ultrabold" | "extrabold" => "800",
+        "heavy" | "black" => "900",
+        _ => "normal",
+    }
+}
+
+fn svg_font_style(style: &str) -> &'static str {
+    match style.trim().to_ascii_lowercase().as_str() {
+        "italic" => "italic",
+        "oblique" => "oblique",
+        _ => "normal",
+    }
+}
+
+/// Coordinate formatter: at most two decimals, trailing zeros dropped.
+pub(super) struct Num(pub f64);
+
+impl fmt::Display for Num {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = if self.0.is_finite() { self.0 } else { 0.0 };
+        let text = format!("{value:.2}");
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        if text == "-0" || text.is_empty() {
+            f.write_str("0")
+        } else {
+            f.write_str(text)
+        }
+    }
+}
+
+/// `#rrggbb` for a color, ignoring alpha.
+pub(super) struct Hex(pub Color);
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        write!(
+            f,
+            "#{:02x}{:02x}{:02x}",
+            channel(self.0.r),
+            channel(self.0.g),
+            channel(self.0.b)
+        )
+    }
+}
+
+/// A `fill` or `stroke` attribute pair: the color plus its opacity when not opaque.
+pub(super) struct Paint {
+    attribute: &'static str,
+    color: Color,
+}
+
+impl Paint {
+    pub(super) fn fill(color: Color) -> Self {
+        Self {
+            attribute: "fill",
+            color,
+        }
+    }
+
+    pub(super) fn stroke(color: Color) -> Self {
+        Self {
+            attribute: "stroke",
+            color,
+        }
+    }
+}
+
+impl fmt::Display for Paint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, r#"{}="{}""#, self.attribute, Hex(self.color))?;
+        let alpha = self.color.a.clamp(0.0, 1.0);
+        if alpha < 1.0 {
+            write!(f, r#" {}-opacity="{}""#, self.attribute, Num(alpha))?;
+        }
+        Ok(())
+    }
+}
+
+/// A leading-space `stroke-dasharray` attribute for a styled stroke `.1`
+/// pixels wide; nothing for solid strokes.
+struct DashArray(LineStyle, f64);
+
+impl fmt::Display for DashArray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pattern = self.0.dash_pattern(self.1);
+        if pattern.is_empty() {
+            return Ok(());
+        }
+        f.write_str(r#" stroke-dasharray=""#)?;
+        for (index, length) in pattern.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{}", Num(*length))?;
+        }
+        f.write_str("\"")
+    }
+}
+
+/// Presentation attributes of a markup span, inherited on top of the
+/// enclosing `<text>` element's base font.
+struct SpanAttributes(SpanStyle, f64);
+
+impl fmt::Display for SpanAttributes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let SpanAttributes(style, base_size) = self;
+        if style.bold {
+            f.write_str(r#" font-weight="bold""#)?;
+        }
+        if style.italic {
+            f.write_str(r#" font-style="italic""#)?;
+        }
+        if style.code {
+            write!(
+                f,
+                r#" font-family="{}, monospace""#,
+                Escaped(CODE_FONT_FAMILY)
+            )?;
+        }
+        if style.heading > 0 {
+            write!(
+                f,
+                r#" font-size="{}""#,
+                Num(base_size * heading_scale(style.heading))
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Round caps for styled outlines of shapes that otherwise set none; dots are
+/// zero-length dashes and disappear without them.
+struct DashCaps(LineStyle);
+
+impl fmt::Display for DashCaps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_solid() {
+            return Ok(());
+        }
+        f.write_str(r#" stroke-linecap="round""#)
+    }
+}
+
+struct PathData<'a>(&'a [(i32, i32)]);
+
+impl fmt::Display for PathData<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (x, y)) in self.0.iter().enumerate() {
+            let command = if index == 0 { 'M' } else { 'L' };
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{command}{x} {y}")?;
+        }
+        Ok(())
+    }
+}
+
+struct PointList<'a>(&'a [(i32, i32)]);
+
+impl fmt::Display for PointList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (x, y)) in self.0.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{x},{y}")?;
+        }
+        Ok(())
+    }
+}
+
+/// XML-escaped text for attribute values and character data.
+pub(super) struct Escaped<'a>(pub &'a str);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ch in self.0.chars() {
+            match ch {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&apos;")?,
+                // XML 1.0 forbids most control characters even when escaped.
+                ch if (ch as u32) < 0x20 && !matches!(ch, '\t' | '\n' | '\r') => {}
+                ch => write!(f, "{ch}")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,343 @@
+//! Output encoding for saved captures and canvas exports.
+//!
+//! Captures and exports are produced as PNG. When they are written to disk
+//! they are re-encoded into the configured format — PNG at another
+//! compression level, JPEG, or lossless/near-lossless WebP — with pure-Rust
+//! encoders. The clipboard always receives the PNG.
+
+use std::borrow::Cow;
+
+use crate::capture::types::CaptureError;
+use crate::config::{CaptureConfig, PngCompression};
+use crate::image_decode::{DecodedImage, EncodedImageFormat, decode_rgba};
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+/// Most low bits near-lossless WebP drops from each color channel, at level 1.
+const WEBP_MAX_DROPPED_BITS: u32 = 5;
+
+/// Raster formats a PNG can be re-encoded into when it is saved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RasterFormat {
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl RasterFormat {
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.trim().to_ascii_lowercase().as_str() {
+            "png" => Some(Self::Png),
+            "jpg" | "jpeg" => Some(Self::Jpeg),
+            "webp" => Some(Self::Webp),
+            _ => None,
+        }
+    }
+
+    pub fn mime_type(self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+        }
+    }
+}
+
+/// How a PNG is encoded when it is saved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImageEncoding {
+    /// Format to re-encode PNG bytes into; `None` saves them unchanged.
+    pub target: Option<RasterFormat>,
+    pub png_compression: PngCompression,
+    pub jpeg_quality: u8,
+    pub webp_near_lossless: bool,
+    pub webp_near_lossless_level: u8,
+}
+
+impl Default for ImageEncoding {
+    fn default() -> Self {
+        let config = CaptureConfig::default();
+        Self {
+            target: None,
+            png_compression: config.png_compression,
+            jpeg_quality: config.jpeg_quality,
+            webp_near_lossless: config.webp_near_lossless,
+            webp_near_lossless_level: config.webp_near_lossless_level,
+        }
+    }
+}
+
+impl ImageEncoding {
+    /// Encoding that writes `format` with the compression settings from
+    /// `[capture]`.
+    pub fn for_format(format: &str, config: &CaptureConfig) -> Self {
+        Self {
+            target: RasterFormat::from_extension(format),
+            png_compression: config.png_compression,
+            jpeg_quality: config.jpeg_quality.clamp(1, 100),
+            webp_near_lossless: config.webp_near_lossless,
+            webp_near_lossless_level: config.webp_near_lossless_level.clamp(1, 100),
+        }
+    }
+
+    /// Re-encodes a PNG into the target format. Bytes that are not PNG, and
+    /// PNGs with nothing to change, are returned as they are.
+    pub fn encode<'a>(&self, bytes: &'a [u8]) -> Result<Cow<'a, [u8]>, CaptureError> {
+        let Some(target) = self.target else {
+            return Ok(Cow::Borrowed(bytes));
+        };
+        if !bytes.starts_with(PNG_SIGNATURE) {
+            return Ok(Cow::Borrowed(bytes));
+        }
+        if target == RasterFormat::Png && self.png_compression == PngCompression::Default {
+            return Ok(Cow::Borrowed(bytes));
+        }
+
+        let image = decode_rgba(EncodedImageFormat::Png, bytes)
+            .map_err(|err| CaptureError::ImageError(format!("Failed to decode PNG: {err}")))?;
+        let encoded = match target {
+            RasterFormat::Png => encode_png(&image, self.png_compression),
+            RasterFormat::Jpeg => encode_jpeg(&image, self.jpeg_quality),
+            RasterFormat::Webp => encode_webp(
+                image,
+                self.webp_near_lossless
+                    .then_some(self.webp_near_lossless_level),
+            ),
+        }?;
+        log::debug!(
+            "Re-encoded {} byte PNG as {:?} ({} bytes)",
+            bytes.len(),
+            target,
+            encoded.len()
+        );
+        Ok(Cow::Owned(encoded))
+    }
+}
+
+fn encode_error(format: &str, err: impl std::fmt::Display) -> CaptureError {
+    CaptureError::ImageError(format!("Failed to encode {format}: {err}"))
+}
+
+fn is_opaque(image: &DecodedImage) -> bool {
+    image.rgba.chunks_exact(4).all(|pixel| pixel[3] == u8::MAX)
+}
+
+fn png_compression_level(compression: PngCompression) -> png::Compression {
+    match compression {
+        PngCompression::Fast => png::Compression::Fast,
+        PngCompression::Default => png::Compression::Balanced,
+        PngCompression::Best => png::Compression::High,
+    }
+}
+
+fn encode_png(image: &DecodedImage, compression: PngCompression) -> Result<Vec<u8>, CaptureError> {
+    let mut bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, image.width, image.height);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_compression(png_compression_level(compression));
+    // Opaque screenshots lose nothing by dropping the alpha channel.
+    let opaque = is_opaque(image);
+    let data: Cow<'_, [u8]> = if opaque {
+        encoder.set_color(png::ColorType::Rgb);
+        Cow::Owned(
+            image
+                .rgba
+                .chunks_exact(4)
+                .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+                .collect(),
+        )
+    } else {
+        encoder.set_color(png::ColorType::Rgba);
+        Cow::Borrowed(image.rgba.as_slice())
+    };
+    let mut writer = encoder
+        .write_header()
+        .map_err(|err| encode_error("PNG", err))?;
+    writer
+        .write_image_data(&data)
+        .map_err(|err| encode_error("PNG", err))?;
+    writer.finish().map_err(|err| encode_error("PNG", err))?;
+    Ok(bytes)
+}
+
+/// JPEG has no alpha channel, so translucent pixels are flattened onto white.
+fn encode_jpeg(image: &DecodedImage, quality: u8) -> Result<Vec<u8>, CaptureError> {
+    let (Ok(width), Ok(height)) = (u16::try_from(image.width), u16::try_from(image.height)) else {
+        return Err(encode_error(
+            "JPEG",
+            format!(
+                "{}x{} exceeds the 65535 pixel JPEG limit",
+                image.width, image.height
+            ),
+        ));
+    };
+    let rgb: Vec<u8> = image
+        .rgba
+        .chunks_exact(4)
+        .flat_map(|pixel| {
+            let alpha = pixel[3] as u32;
+            let over_white =
+                |channel: u8| ((channel as u32 * alpha + 255 * (255 - alpha) + 127) / 255) as u8;
+            [
+                over_white(pixel[0]),
+                over_white(pixel[1]),
+                over_white(pixel[2]),
+            ]
+        })
+        .collect();
+    let mut bytes = Vec::new();
+    jpeg_encoder::Encoder::new(&mut bytes, quality)
+        .encode(&rgb, width, height, jpeg_encoder::ColorType::Rgb)
+        .map_err(|err| encode_error("JPEG", err))?;
+    Ok(bytes)
+}
+
+/// The pure-Rust WebP encoder only writes the lossless bitstream. With a
+/// near-lossless level, color precision is reduced first, which the lossless
+/// coder then compresses better; there is no lossy (VP8) output.
+fn encode_webp(
+    mut image: DecodedImage,
+    near_lossless_level: Option<u8>,
+) -> Result<Vec<u8>, CaptureError> {
+    if let Some(level) = near_lossless_level {
+        quantize_for_webp(&mut image.rgba, level);
+    }
+    let opaque = is_opaque(&image);
+    let (data, color): (Cow<'_, [u8]>, _) = if opaque {
+        (
+            Cow::Owned(
+                image
+                    .rgba
+                    .chunks_exact(4)
+                    .flat_map(|pixel| [pixel[0], pixel[1], pixel[2]])
+                    .collect(),
+            ),
+            image_webp::ColorType::Rgb8,
+        )
+    } else {
+        (
+            Cow::Borrowed(image.rgba.as_slice()),
+            image_webp::ColorType::Rgba8,
+        )
+    };
+    let mut bytes = Vec::new();
+    image_webp::WebPEncoder::new(&mut bytes)
+        .encode(&data, image.width, image.height, color)
+        .map_err(|err| encode_error("WebP", err))?;
+    Ok(bytes)
+}
+
+/// Number of low bits near-lossless WebP drops from each color channel.
+fn webp_dropped_bits(level: u8) -> u32 {
+    let loss = 100 - level.clamp(1, 100) as u32;
+    (loss * WEBP_MAX_DROPPED_BITS).div_ceil(99)
+}
+
+fn quantize_for_webp(rgba: &mut [u8], level: u8) {
+    let bits = webp_dropped_bits(level);
+    if bits == 0 {
+        return;
+    }
+    let step = 1u32 << bits;
+    for pixel in rgba.chunks_exact_mut(4) {
+        if pixel[3] == 0 {
+            // Color under full transparency is invisible; zero it so it
+            // compresses to nothing.
+            pixel[..3].fill(0);
+            continue;
+        }
+        for channel in &mut pixel[..3] {
+            let rounded = (*channel as u32 + step / 2) / step * step;
+            *channel = rounded.min(u8::MAX as u32) as u8;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_png(alpha: u8) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut encoder = png::Encoder::new(&mut bytes, 4, 2);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        let data: Vec<u8> = (0..8u8)
+            .flat_map(|index| [index * 30, 200 - index * 20, 77, alpha])
+            .collect();
+        writer.write_image_data(&data).unwrap();
+        writer.finish().unwrap();
+        bytes
+    }
+
+    fn encoding(format: &str) -> ImageEncoding {
+        ImageEncoding::for_format(format, &CaptureConfig::default())
+    }
+
+    #[test]
+    fn untouched_png_and_foreign_bytes_pass_through() {
+        let png = sample_png(255);
+        assert!(matches!(encoding("png").encode(&png), Ok(Cow::Borrowed(_))));
+        assert!(matches!(
+            ImageEncoding::default().encode(&png),
+            Ok(Cow::Borrowed(_))
+        ));
+        let not_png = b"not-a-png".to_vec();
+        assert!(matches!(
+            encoding("webp").encode(&not_png),
+            Ok(Cow::Borrowed(bytes)) if bytes == not_png.as_slice()
+        ));
+    }
+
+    #[test]
+    fn jpeg_and_webp_targets_write_their_signatures() {
+        let png = sample_png(128);
+        let jpeg = encoding("jpeg").encode(&png).unwrap();
+        assert!(jpeg.starts_with(&[0xff, 0xd8, 0xff]));
+
+        let webp = encoding("webp").encode(&png).unwrap();
+        assert_eq!(&webp[0..4], b"RIFF");
+        assert_eq!(&webp[8..12], b"WEBP");
+    }
+
+    #[test]
+    fn recompressed_png_decodes_to_the_same_pixels() {
+        let png = sample_png(255);
+        let encoded = ImageEncoding {
+            png_compression: PngCompression::Best,
+            ..encoding("png")
+        }
+        .encode(&png)
+        .unwrap();
+        let before = decode_rgba(EncodedImageFormat::Png, &png).unwrap();
+        let after = decode_rgba(EncodedImageFormat::Png, &encoded).unwrap();
+        assert_eq!(before.rgba, after.rgba);
+    }
+
+    #[test]
+    fn png_compression_settings_map_to_distinct_levels() {
+        assert!(matches!(
+            png_compression_level(PngCompression::Fast),
+            png::Compression::Fast
+        ));
+        assert!(matches!(
+            png_compression_level(PngCompression::Default),
+            png::Compression::Balanced
+        ));
+        assert!(matches!(
+            png_compression_level(PngCompression::Best),
+            png::Compression::High
+        ));
+    }
+
+    #[test]
+    fn near_lossless_level_maps_to_dropped_bits() {
+        assert_eq!(webp_dropped_bits(100), 0);
+        assert_eq!(webp_dropped_bits(80), 2);
+        assert_eq!(webp_dropped_bits(1), WEBP_MAX_DROPPED_BITS);
+
+        let mut pixel = [13, 250, 0, 255];
+        quantize_for_webp(&mut pixel, 80);
+        assert_eq!(pixel, [12, 252, 0, 255]);
+    }
+}
@@ -1,5 +1,6 @@
 //! File saving functionality for screenshots.
 
+use super::encode::ImageEncoding;
 use super::types::CaptureError;
 use crate::durable_io::{AtomicWriteOptions, OverwriteMode, PermissionPolicy, SymlinkPolicy};
 use crate::paths::{expand_tilde as expand_tilde_global, home_dir, pictures_dir};
@@ -16,6 +17,8 @@ pub struct FileSaveConfig {
     pub filename_template: String,
     /// Image format extension.
     pub format: String,
+    /// How PNG bytes are re-encoded before they are written.
+    pub encoding: ImageEncoding,
 }
 
 impl Default for FileSaveConfig {
@@ -27,6 +30,7 @@ impl Default for FileSaveConfig {
                 .join("Wayscriber"),
             filename_template: "screenshot_%Y-%m-%d_%H%M%S".to_string(),
             format: "png".to_string(),
+            encoding: ImageEncoding::default(),
         }
     }
 }
@@ -47,7 +51,11 @@ pub fn generate_filename(template: &str, format: &str) -> String {
 
 fn sanitize_save_extension(format: &str) -> Option<String> {
     let normalized = format.trim().to_ascii_lowercase();
-    matches!(normalized.as_str(), "png" | "jpg" | "jpeg" | "pdf").then_some(normalized)
+    matches!(
+        normalized.as_str(),
+        "png" | "jpg" | "jpeg" | "webp" | "pdf" | "svg" | "gif"
+    )
+    .then_some(normalized)
 }
 
 fn save_file_name(template: &str, format: &str) -> Result<String, CaptureError> {
@@ -158,6 +166,7 @@ pub fn save_screenshot(
 
     // Generate filename
     let file_path = generate_file_path(&directory, &config.filename_template, &config.format)?;
+    let image_data = config.encoding.encode(image_data)?;
 
     log::info!(
         "Saving screenshot to: {} ({} bytes)",
@@ -168,7 +177,7 @@ pub fn save_screenshot(
     // Write file
     crate::durable_io::write_atomic(
         &file_path,
-        image_data,
+        &image_data,
         AtomicWriteOptions {
             overwrite: OverwriteMode::Replace,
             permissions: PermissionPolicy::FixedMode(0o600),
@@ -188,6 +197,74 @@ pub fn save_screenshot(
     Ok(file_path)
 }
 
+/// Save a numbered PNG frame sequence (`frame-00001.png`, ...) into a new
+/// folder named from the template, rendering each frame only when it is
+/// written.
+///
+/// # Returns
+/// Path to the folder holding the frames
+pub fn save_frame_sequence(
+    config: &FileSaveConfig,
+    frame_count: usize,
+    mut render: impl FnMut(usize) -> Result<Vec<u8>, CaptureError>,
+) -> Result<PathBuf, CaptureError> {
+    let directory = ensure_directory_exists(&config.save_directory)?;
+    let folder = create_unique_folder(&directory, &config.filename_template)?;
+
+    log::info!("Saving {} frames to: {}", frame_count, folder.display());
+
+    for index in 0..frame_count {
+        let bytes = render(index)?;
+        crate::durable_io::write_atomic(
+            &folder.join(frame_file_name(index)),
+            &bytes,
+            AtomicWriteOptions {
+                overwrite: OverwriteMode::Replace,
+                permissions: PermissionPolicy::FixedMode(0o600),
+                symlink: SymlinkPolicy::Reject,
+                sync_file: false,
+                sync_parent: false,
+            },
+        )
+        .map_err(|err| CaptureError::SaveError(std::io::Error::other(err)))?;
+    }
+    // Frames skip per-file syncs; one sync of the folder covers them all.
+    fs::File::open(&folder)?.sync_all()?;
+
+    log::info!("Frame sequence saved successfully: {}", folder.display());
+
+    Ok(folder)
+}
+
+fn frame_file_name(index: usize) -> String {
+    format!("frame-{:05}.png", index + 1)
+}
+
+fn create_unique_folder(directory: &Path, template: &str) -> Result<PathBuf, CaptureError> {
+    let stem = format_with_template(now_local(), template);
+    if !crate::paths::is_single_path_component(&stem) {
+        return Err(CaptureError::SaveError(std::io::Error::other(
+            "filename template must expand to a single file name",
+        )));
+    }
+    for suffix in 0..=UNIQUE_NAME_ATTEMPTS {
+        let name = if suffix == 0 {
+            stem.clone()
+        } else {
+            format!("{stem}-{suffix}")
+        };
+        let candidate = directory.join(name);
+        match fs::create_dir(&candidate) {
+            Ok(()) => return Ok(candidate),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(err) => return Err(err.into()),
+        }
+    }
+    Err(CaptureError::SaveError(std::io::Error::other(
+        "no free folder name for the frame sequence",
+    )))
+}
+
 /// Expand tilde (~) in path strings.
 pub fn expand_tilde(path: &str) -> PathBuf {
     expand_tilde_global(path)
@@ -249,6 +326,7 @@ mod tests {
         assert!(save_file_name("shot", "exe").is_err());
         assert_eq!(save_file_name("shot", "png").unwrap(), "shot.png");
         assert_eq!(save_file_name("shot", "JPEG").unwrap(), "shot.jpeg");
+        assert_eq!(save_file_name("board", "svg").unwrap(), "board.svg");
     }
 
     #[test]
@@ -263,4 +341,37 @@ mod tests {
         );
         assert!(generate_file_path(directory, "../evil", "png").is_err());
     }
+
+    #[test]
+    fn save_frame_sequence_writes_numbered_frames_into_a_new_folder() {
+        let temp = crate::test_temp::tempdir().unwrap();
+        let config = FileSaveConfig {
+            save_directory: temp.path().to_path_buf(),
+            filename_template: "replay".to_string(),
+            format: "png".to_string(),
+            encoding: ImageEncoding::default(),
+        };
+
+        let first =
+            save_frame_sequence(&config, 3, |index| Ok(vec![index as u8])).expect("frames saved");
+        assert_eq!(first.file_name().unwrap(), "replay");
+        assert_eq!(fs::read(first.join("frame-00001.png")).unwrap(), vec![0]);
+        assert_eq!(fs::read(first.join("frame-00003.png")).unwrap(), vec![2]);
+        assert!(!first.join("frame-00004.png").exists());
+
+        let second = save_frame_sequence(&config, 1, |_| Ok(Vec::new())).expect("frames saved");
+        assert_eq!(second.file_name().unwrap(), "replay-1");
+    }
+
+    #[test]
+    fn save_frame_sequence_rejects_path_escaping_templates() {
+        let temp = crate::test_temp::tempdir().unwrap();
+        let config = FileSaveConfig {
+            save_directory: temp.path().to_path_buf(),
+            filename_template: "../replay".to_string(),
+            format: "png".to_string(),
+            encoding: ImageEncoding::default(),
+        };
+        assert!(save_frame_sequence(&config, 1, |_| Ok(Vec::new())).is_err());
+    }
 }
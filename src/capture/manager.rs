@@ -12,11 +12,14 @@ use crate::capture::{
     pipeline::{
         CaptureManagerRequest, CaptureManagerResult, CaptureRequest, deliver_document,
         deliver_image, perform_capture, render_and_deliver_document, render_and_deliver_image,
+        render_frame_sequence,
     },
+    postprocess::PostProcessPipeline,
     types::{
         CaptureDestination, CaptureError, CaptureOutcome, CaptureType,
-        DesktopBackdropCaptureRequest, DocumentDeliveryRequest, ImageDeliveryRequest,
-        ImageOperationKind, RenderedDocumentDeliveryRequest, RenderedImageDeliveryRequest,
+        DesktopBackdropCaptureRequest, DocumentDeliveryRequest, FrameSequenceExportRequest,
+        ImageDeliveryRequest, ImageOperationKind, RenderedDocumentDeliveryRequest,
+        RenderedImageDeliveryRequest,
     },
 };
 
@@ -189,11 +192,13 @@ impl CaptureManager {
         capture_type: CaptureType,
         destination: CaptureDestination,
         save_config: Option<FileSaveConfig>,
+        postprocess: Option<PostProcessPipeline>,
     ) -> Result<CaptureRequestId, CaptureSubmitError> {
         self.try_submit(CaptureManagerRequest::Capture(CaptureRequest {
             capture_type,
             destination,
             save_config,
+            postprocess,
         }))
     }
 
@@ -232,6 +237,13 @@ impl CaptureManager {
         self.try_submit(CaptureManagerRequest::RenderAndDeliverDocument(request))
     }
 
+    pub fn request_frame_sequence_export(
+        &mut self,
+        request: FrameSequenceExportRequest,
+    ) -> Result<CaptureRequestId, CaptureSubmitError> {
+        self.try_submit(CaptureManagerRequest::RenderFrameSequence(request))
+    }
+
     fn try_submit(
         &mut self,
         request: CaptureManagerRequest,
@@ -415,6 +427,9 @@ async fn run_capture_worker(
                     .await
                     .map(CaptureManagerResult::Capture)
             }
+            CaptureManagerRequest::RenderFrameSequence(request) => render_frame_sequence(request)
+                .await
+                .map(CaptureManagerResult::Capture),
         };
         let outcome = outcome_from_result(result, operation);
         if !guard.publish(CaptureCompletion {
@@ -523,6 +538,7 @@ mod transport_tests {
             capture_type: CaptureType::FullScreen,
             destination: CaptureDestination::ClipboardOnly,
             save_config: None,
+            postprocess: None,
         })
     }
 
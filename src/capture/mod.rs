@@ -8,9 +8,11 @@
 //! - File saving with configurable formats
 
 pub mod clipboard;
+pub mod encode;
 pub mod file;
 #[cfg(feature = "portal")]
 pub mod portal;
+pub mod recording;
 pub mod sources;
 pub mod types;
 
@@ -18,17 +20,20 @@ mod dependencies;
 mod desktop_backdrop;
 mod manager;
 mod pipeline;
+mod postprocess;
 #[cfg(test)]
 mod tests;
 
 pub use manager::{CaptureManager, CapturePoll, CaptureRequestId, CaptureSubmitError};
 #[allow(unused_imports)]
 pub(crate) use pipeline::CaptureRequest;
+pub use postprocess::PostProcessPipeline;
 #[allow(unused_imports)]
 pub use types::{
     CaptureDestination, CaptureError, CaptureOutcome, CaptureResult, CaptureType,
     DesktopBackdropCaptureRequest, DesktopBackdropCaptureResult, DesktopBackdropGeometry,
-    DesktopBackdropOutputGeometry, DocumentDeliveryRequest, DocumentRenderJob,
-    ImageDeliveryRequest, ImageFormatMetadata, ImageOperationKind, ImageRenderJob,
-    RenderedDocument, RenderedDocumentDeliveryRequest, RenderedImage, RenderedImageDeliveryRequest,
+    DesktopBackdropOutputGeometry, DocumentDeliveryRequest, DocumentRenderJob, FrameRenderJob,
+    FrameSequenceExportRequest, ImageDeliveryRequest, ImageFormatMetadata, ImageOperationKind,
+    ImageRenderJob, RenderedDocument, RenderedDocumentDeliveryRequest, RenderedImage,
+    RenderedImageDeliveryRequest,
 };
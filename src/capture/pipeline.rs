@@ -3,13 +3,15 @@ use std::{fmt, path::PathBuf, sync::Arc};
 use crate::capture::{
     dependencies::{CaptureClipboard, CaptureDependencies, CaptureFileSaver},
     file::FileSaveConfig,
+    postprocess::PostProcessPipeline,
     types::{
         CaptureDestination, CaptureError, CaptureResult, CaptureType,
         DesktopBackdropCaptureRequest, DesktopBackdropCaptureResult, DocumentDeliveryRequest,
-        ImageDeliveryRequest, ImageOperationKind, RenderedDocumentDeliveryRequest,
-        RenderedImageDeliveryRequest,
+        FrameSequenceExportRequest, ImageDeliveryRequest, ImageFormatMetadata, ImageOperationKind,
+        RenderedDocumentDeliveryRequest, RenderedImage, RenderedImageDeliveryRequest,
     },
 };
+use crate::image_decode::{EncodedImageFormat, image_dimensions};
 use tokio::task;
 
 #[derive(Clone)]
@@ -17,6 +19,7 @@ pub(crate) struct CaptureRequest {
     pub(crate) capture_type: CaptureType,
     pub(crate) destination: CaptureDestination,
     pub(crate) save_config: Option<FileSaveConfig>,
+    pub(crate) postprocess: Option<PostProcessPipeline>,
 }
 
 impl fmt::Debug for CaptureRequest {
@@ -31,6 +34,10 @@ impl fmt::Debug for CaptureRequest {
                     .as_ref()
                     .map(|cfg| cfg.filename_template.clone()),
             )
+            .field(
+                "postprocess",
+                &self.postprocess.as_ref().map(PostProcessPipeline::id),
+            )
             .finish()
     }
 }
@@ -42,6 +49,7 @@ pub(crate) enum CaptureManagerRequest {
     DeliverDocument(DocumentDeliveryRequest),
     RenderAndDeliverImage(RenderedImageDeliveryRequest),
     RenderAndDeliverDocument(RenderedDocumentDeliveryRequest),
+    RenderFrameSequence(FrameSequenceExportRequest),
 }
 
 impl CaptureManagerRequest {
@@ -53,6 +61,7 @@ impl CaptureManagerRequest {
             Self::DeliverDocument(request) => request.operation,
             Self::RenderAndDeliverImage(request) => request.operation,
             Self::RenderAndDeliverDocument(request) => request.operation,
+            Self::RenderFrameSequence(request) => request.operation,
         }
     }
 }
@@ -94,6 +103,11 @@ impl fmt::Debug for CaptureManagerRequest {
                 .field("destination", &request.destination)
                 .field("operation", &request.operation)
                 .finish(),
+            Self::RenderFrameSequence(request) => f
+                .debug_struct("RenderFrameSequence")
+                .field("frame_count", &request.frame_count)
+                .field("operation", &request.operation)
+                .finish(),
         }
     }
 }
@@ -110,7 +124,7 @@ pub(crate) async fn perform_capture(
     log::info!("Starting capture: {:?}", request.capture_type);
 
     // Step 1: Capture image bytes (prefer compositor-specific path where possible)
-    let image_data = match dependencies.source.capture(request.capture_type).await {
+    let mut image_data = match dependencies.source.capture(request.capture_type).await {
         Ok(data) => data,
         Err(CaptureError::Cancelled(reason)) => {
             log::info!("Capture cancelled: {}", reason);
@@ -127,6 +141,27 @@ pub(crate) async fn perform_capture(
         request.capture_type
     );
 
+    // Step 2: Post-process (if a pipeline is configured for this capture kind)
+    if let Some(pipeline) = request.postprocess.clone() {
+        // Capture sources deliver PNG.
+        match image_dimensions(EncodedImageFormat::Png, &image_data) {
+            Ok((width, height)) => {
+                let image = RenderedImage {
+                    bytes: image_data,
+                    format: ImageFormatMetadata::png(),
+                    width,
+                    height,
+                };
+                image_data = postprocess_image(pipeline, image).await?.bytes;
+            }
+            Err(err) => log::warn!(
+                "Skipping post-processing pipeline '{}' for unreadable capture: {}",
+                pipeline.id(),
+                err
+            ),
+        }
+    }
+
     // Step 3: Save to file (if requested)
     let mut save_error = None;
     let saved_path = match request.destination {
@@ -218,9 +253,30 @@ pub(crate) async fn deliver_image(
         request.image.bytes.len()
     );
 
-    let image_data = request.image.bytes;
+    let image = match request.postprocess {
+        Some(pipeline) if request.image.format == ImageFormatMetadata::png() => {
+            postprocess_image(pipeline, request.image).await?
+        }
+        Some(pipeline) => {
+            log::info!(
+                "Skipping post-processing pipeline '{}' for {} image",
+                pipeline.id(),
+                request.image.format.extension
+            );
+            request.image
+        }
+        None => request.image,
+    };
+    let image_data = image.bytes;
     let save_config = request.save_config.map(|mut config| {
-        config.format = request.image.format.extension.clone();
+        // A PNG is re-encoded into the requested format on save; anything
+        // else is written as rendered, under its own extension.
+        let transcodes =
+            config.encoding.target.is_some() && image.format == ImageFormatMetadata::png();
+        if !transcodes {
+            config.format = image.format.extension.clone();
+            config.encoding.target = None;
+        }
         config
     });
 
@@ -304,12 +360,43 @@ pub(crate) async fn render_and_deliver_image(
             save_config: request.save_config,
             operation: request.operation,
             fallback_format_override: request.fallback_format_override,
+            postprocess: request.postprocess,
         },
         dependencies,
     )
     .await
 }
 
+/// Runs a post-processing pipeline on a blocking worker. A pipeline that
+/// fails is logged and the image delivered as it was, so a bad watermark or
+/// an undecodable frame never costs the user the capture itself.
+async fn postprocess_image(
+    pipeline: PostProcessPipeline,
+    image: RenderedImage,
+) -> Result<RenderedImage, CaptureError> {
+    task::spawn_blocking(move || match pipeline.apply(&image.bytes) {
+        Ok(processed) => {
+            log::info!(
+                "Applied post-processing pipeline '{}' ({}x{})",
+                pipeline.id(),
+                processed.width,
+                processed.height
+            );
+            processed
+        }
+        Err(err) => {
+            log::warn!(
+                "Post-processing pipeline '{}' failed: {}; delivering the original image",
+                pipeline.id(),
+                err
+            );
+            image
+        }
+    })
+    .await
+    .map_err(|e| CaptureError::ImageError(format!("Post-processing task failed: {}", e)))
+}
+
 /// Runs the deferred render on a blocking worker, then delivers like
 /// [`deliver_document`]. Keeps export render + encode off the submitting
 /// thread.
@@ -334,6 +421,45 @@ pub(crate) async fn render_and_deliver_document(
     .await
 }
 
+/// Renders and saves a numbered frame sequence on a blocking worker. Frames
+/// are written one at a time, so only one encoded frame is held in memory.
+pub(crate) async fn render_frame_sequence(
+    request: FrameSequenceExportRequest,
+) -> Result<CaptureResult, CaptureError> {
+    log::info!(
+        "Starting frame sequence export: {:?} ({} frames)",
+        request.operation,
+        request.frame_count
+    );
+    if request.save_config.save_directory.as_os_str().is_empty() {
+        return Err(CaptureError::ImageError(
+            "Frame sequence export requires a save directory".to_string(),
+        ));
+    }
+    let FrameSequenceExportRequest {
+        frame_count,
+        mut render_frame,
+        save_config,
+        operation,
+    } = request;
+    let folder = task::spawn_blocking(move || {
+        crate::capture::file::save_frame_sequence(&save_config, frame_count, |index| {
+            render_frame(index).map(|image| image.bytes)
+        })
+    })
+    .await
+    .map_err(|e| CaptureError::ImageError(format!("Render task failed: {}", e)))??;
+
+    Ok(CaptureResult {
+        image_data: Vec::new(),
+        operation,
+        fallback_format_override: None,
+        saved_path: Some(folder),
+        copied_to_clipboard: false,
+        save_error: None,
+    })
+}
+
 pub(crate) async fn deliver_document(
     request: DocumentDeliveryRequest,
     dependencies: Arc<CaptureDependencies>,
@@ -0,0 +1,487 @@
+//! Post-processing applied to a captured image before it is saved or copied.
+//!
+//! A [`PostProcessPipeline`] is resolved from a `[[capture.pipelines]]` entry
+//! when the capture is requested, then runs on the capture worker: the PNG is
+//! decoded, trimmed, framed with padding, rounded corners, a border and a drop
+//! shadow, stamped with a watermark, and encoded again.
+
+use std::path::PathBuf;
+
+use crate::capture::types::{CaptureError, ImageFormatMetadata, RenderedImage};
+use crate::config::{CapturePipelineConfig, WatermarkPosition};
+use crate::domain::Color;
+use crate::ui::draw_rounded_rect;
+use crate::ui_text::{UiTextStyle, text_layout};
+
+/// Distance between a watermark and the image edges it is anchored to.
+const WATERMARK_INSET: f64 = 12.0;
+/// Box blur passes; three approximate a gaussian closely enough for a shadow.
+const SHADOW_BLUR_PASSES: usize = 3;
+
+#[derive(Debug, Clone)]
+struct Shadow {
+    radius: f64,
+    offset_y: f64,
+    color: Color,
+}
+
+#[derive(Debug, Clone)]
+enum WatermarkContent {
+    Text(String),
+    Image(PathBuf),
+}
+
+#[derive(Debug, Clone)]
+struct Watermark {
+    content: WatermarkContent,
+    position: WatermarkPosition,
+    opacity: f64,
+    size: f64,
+    color: Color,
+}
+
+/// A capture pipeline with colors and paths resolved, ready to run on the
+/// capture worker.
+#[derive(Debug, Clone)]
+pub struct PostProcessPipeline {
+    id: String,
+    trim: bool,
+    padding: f64,
+    background: Option<Color>,
+    corner_radius: f64,
+    border_width: f64,
+    border_color: Color,
+    shadow: Option<Shadow>,
+    watermark: Option<Watermark>,
+}
+
+impl PostProcessPipeline {
+    pub fn from_config(config: &CapturePipelineConfig) -> Self {
+        let content = match (&config.watermark_image, &config.watermark_text) {
+            (Some(path), _) => Some(WatermarkContent::Image(crate::paths::expand_tilde(path))),
+            (None, Some(text)) => Some(WatermarkContent::Text(text.clone())),
+            (None, None) => None,
+        };
+        Self {
+            id: config.id.clone(),
+            trim: config.trim,
+            padding: config.padding as f64,
+            background: config.background.as_ref().map(|color| color.to_color()),
+            corner_radius: config.corner_radius.max(0.0),
+            border_width: config.border_width.max(0.0),
+            border_color: config.border_color.to_color(),
+            shadow: config.shadow.then(|| Shadow {
+                radius: config.shadow_radius.max(0.0),
+                offset_y: config.shadow_offset_y,
+                color: config.shadow_color.to_color(),
+            }),
+            watermark: content.map(|content| Watermark {
+                content,
+                position: config.watermark_position,
+                opacity: config.watermark_opacity.clamp(0.0, 1.0),
+                size: config.watermark_size,
+                color: config.watermark_color.to_color(),
+            }),
+        }
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Runs every step over a PNG and returns the processed PNG.
+    pub fn apply(&self, png: &[u8]) -> Result<RenderedImage, CaptureError> {
+        let mut image = decode_png(png)?;
+        if self.trim {
+            image = trim_to_content(image)?;
+        }
+
+        let image_width = image.width() as f64;
+        let image_height = image.height() as f64;
+        let framed_width = image_width + self.border_width * 2.0;
+        let framed_height = image_height + self.border_width * 2.0;
+        // The shadow needs room to fade out, whatever the padding says.
+        let shadow_extent = self
+            .shadow
+            .as_ref()
+            .map_or(0.0, |shadow| shadow.radius + shadow.offset_y.abs());
+        let margin = self.padding.max(shadow_extent).ceil();
+        let width = (framed_width + margin * 2.0).ceil() as i32;
+        let height = (framed_height + margin * 2.0).ceil() as i32;
+
+        let output = create_surface(width, height)?;
+        let ctx = cairo::Context::new(&output).map_err(image_error)?;
+
+        if let Some(background) = self.background {
+            set_color(&ctx, background, 1.0);
+            ctx.paint().map_err(image_error)?;
+        }
+
+        let frame_radius = if self.corner_radius > 0.0 {
+            self.corner_radius + self.border_width
+        } else {
+            0.0
+        };
+        if let Some(shadow) = &self.shadow {
+            let mask = shadow_mask(
+                width,
+                height,
+                (
+                    margin,
+                    margin + shadow.offset_y,
+                    framed_width,
+                    framed_height,
+                ),
+                frame_radius,
+                shadow.radius,
+            )?;
+            set_color(&ctx, shadow.color, 1.0);
+            ctx.mask_surface(&mask, 0.0, 0.0).map_err(image_error)?;
+        }
+
+        if self.border_width > 0.0 {
+            draw_rounded_rect(
+                &ctx,
+                margin,
+                margin,
+                framed_width,
+                framed_height,
+                frame_radius,
+            );
+            set_color(&ctx, self.border_color, 1.0);
+            ctx.fill().map_err(image_error)?;
+        }
+
+        let image_x = margin + self.border_width;
+        let image_y = margin + self.border_width;
+        ctx.save().map_err(image_error)?;
+        draw_rounded_rect(
+            &ctx,
+            image_x,
+            image_y,
+            image_width,
+            image_height,
+            self.corner_radius,
+        );
+        ctx.clip();
+        ctx.set_source_surface(&image, image_x, image_y)
+            .map_err(image_error)?;
+        ctx.paint().map_err(image_error)?;
+        ctx.restore().map_err(image_error)?;
+
+        if let Some(watermark) = &self.watermark {
+            let area = (image_x, image_y, image_width, image_height);
+            // A missing watermark file should not cost the user the capture.
+            if let Err(err) = draw_watermark(&ctx, watermark, area) {
+                log::warn!("Skipping watermark for pipeline '{}': {}", self.id, err);
+            }
+        }
+        drop(ctx);
+
+        let mut bytes = Vec::new();
+        output
+            .write_to_png(&mut bytes)
+            .map_err(|err| CaptureError::ImageError(format!("Failed to encode PNG: {err}")))?;
+        Ok(RenderedImage {
+            bytes,
+            format: ImageFormatMetadata::png(),
+            width: width as u32,
+            height: height as u32,
+        })
+    }
+}
+
+fn image_error(err: impl std::fmt::Display) -> CaptureError {
+    CaptureError::ImageError(format!("Post-processing failed: {err}"))
+}
+
+fn create_surface(width: i32, height: i32) -> Result<cairo::ImageSurface, CaptureError> {
+    cairo::ImageSurface::create(cairo::Format::ARgb32, width.max(1), height.max(1))
+        .map_err(image_error)
+}
+
+fn set_color(ctx: &cairo::Context, color: Color, opacity: f64) {
+    ctx.set_source_rgba(color.r, color.g, color.b, color.a * opacity);
+}
+
+/// Decodes into an ARGB32 surface, whatever format the PNG was stored in.
+fn decode_png(png: &[u8]) -> Result<cairo::ImageSurface, CaptureError> {
+    let decoded = cairo::ImageSurface::create_from_png(&mut std::io::Cursor::new(png))
+        .map_err(|err| CaptureError::ImageError(format!("Failed to decode PNG: {err}")))?;
+    if decoded.format() == cairo::Format::ARgb32 {
+        return Ok(decoded);
+    }
+    copy_region(&decoded, 0, 0, decoded.width(), decoded.height())
+}
+
+fn copy_region(
+    source: &cairo::ImageSurface,
+    x: i32,
+    y: i32,
+    width: i32,
+    height: i32,
+) -> Result<cairo::ImageSurface, CaptureError> {
+    let surface = create_surface(width, height)?;
+    let ctx = cairo::Context::new(&surface).map_err(image_error)?;
+    ctx.set_source_surface(source, -x as f64, -y as f64)
+        .map_err(image_error)?;
+    ctx.paint().map_err(image_error)?;
+    drop(ctx);
+    Ok(surface)
+}
+
+/// Crops away the margins that match the top-left pixel. An image of one
+/// uniform color is left as it is.
+fn trim_to_content(mut image: cairo::ImageSurface) -> Result<cairo::ImageSurface, CaptureError> {
+    image.flush();
+    let width = image.width() as usize;
+    let height = image.height() as usize;
+    let stride = image.stride() as usize;
+    let bounds = {
+        let data = image.data().map_err(image_error)?;
+        let corner = &data[0..4];
+        let mut bounds: Option<(usize, usize, usize, usize)> = None;
+        for y in 0..height {
+            let row = &data[y * stride..y * stride + width * 4];
+            for (x, pixel) in row.chunks_exact(4).enumerate() {
+                if pixel == corner {
+                    continue;
+                }
+                bounds = Some(match bounds {
+                    Some((left, top, right, bottom)) => {
+                        (left.min(x), top.min(y), right.max(x), bottom.max(y))
+                    }
+                    None => (x, y, x, y),
+                });
+            }
+        }
+        bounds
+    };
+    match bounds {
+        Some((left, top, right, bottom)) => copy_region(
+            &image,
+            left as i32,
+            top as i32,
+            (right - left + 1) as i32,
+            (bottom - top + 1) as i32,
+        ),
+        None => Ok(image),
+    }
+}
+
+/// Alpha mask of the framed image, blurred into a soft shadow.
+fn shadow_mask(
+    width: i32,
+    height: i32,
+    (x, y, rect_width, rect_height): (f64, f64, f64, f64),
+    corner_radius: f64,
+    blur_radius: f64,
+) -> Result<cairo::ImageSurface, CaptureError> {
+    let mut mask = cairo::ImageSurface::create(cairo::Format::A8, width.max(1), height.max(1))
+        .map_err(image_error)?;
+    {
+        let ctx = cairo::Context::new(&mask).map_err(image_error)?;
+        draw_rounded_rect(&ctx, x, y, rect_width, rect_height, corner_radius);
+        ctx.set_source_rgba(0.0, 0.0, 0.0, 1.0);
+        ctx.fill().map_err(image_error)?;
+    }
+    mask.flush();
+    // Each pass spreads by its radius, so the passes together stay within
+    // the configured blur radius.
+    let pass_radius = (blur_radius / SHADOW_BLUR_PASSES as f64).round() as usize;
+    if pass_radius > 0 {
+        let stride = mask.stride() as usize;
+        let (mask_width, mask_height) = (mask.width() as usize, mask.height() as usize);
+        let mut data = mask.data().map_err(image_error)?;
+        for _ in 0..SHADOW_BLUR_PASSES {
+            box_blur_alpha(&mut data, mask_width, mask_height, stride, pass_radius);
+        }
+    }
+    mask.mark_dirty();
+    Ok(mask)
+}
+
+/// One horizontal and one vertical box blur pass over an 8-bit alpha plane.
+fn box_blur_alpha(data: &mut [u8], width: usize, height: usize, stride: usize, radius: usize) {
+    let mut line = Vec::new();
+    for y in 0..height {
+        line.clear();
+        line.extend((0..width).map(|x| data[y * stride + x]));
+        blur_line(&line, radius, |x, value| data[y * stride + x] = value);
+    }
+    for x in 0..width {
+        line.clear();
+        line.extend((0..height).map(|y| data[y * stride + x]));
+        blur_line(&line, radius, |y, value| data[y * stride + x] = value);
+    }
+}
+
+fn blur_line(line: &[u8], radius: usize, mut write: impl FnMut(usize, u8)) {
+    let window = (radius * 2 + 1) as u32;
+    let len = line.len();
+    // Samples past either end count as transparent.
+    let mut sum: u32 = line.iter().take(radius + 1).map(|&v| v as u32).sum();
+    for index in 0..len {
+        write(index, (sum / window) as u8);
+        if let Some(&entering) = line.get(index + radius + 1) {
+            sum += entering as u32;
+        }
+        if index >= radius {
+            sum -= line[index - radius] as u32;
+        }
+    }
+}
+
+fn draw_watermark(
+    ctx: &cairo::Context,
+    watermark: &Watermark,
+    (x, y, width, height): (f64, f64, f64, f64),
+) -> Result<(), CaptureError> {
+    match &watermark.content {
+        WatermarkContent::Text(text) => {
+            let style = UiTextStyle {
+                family: "Sans",
+                slant: cairo::FontSlant::Normal,
+                weight: cairo::FontWeight::Bold,
+                size: watermark.size,
+            };
+            let layout = text_layout(ctx, style, text, None);
+            let extents = layout.ink_extents();
+            let (left, top) = anchor(
+                watermark.position,
+                (x, y, width, height),
+                extents.width(),
+                extents.height(),
+            );
+            set_color(ctx, watermark.color, watermark.opacity);
+            layout.show_at_baseline(ctx, left - extents.x_bearing(), top - extents.y_bearing());
+        }
+        WatermarkContent::Image(path) => {
+            let mut file = std::fs::File::open(path).map_err(|err| {
+                CaptureError::ImageError(format!("cannot open {}: {err}", path.display()))
+            })?;
+            let stamp = cairo::ImageSurface::create_from_png(&mut file).map_err(|err| {
+                CaptureError::ImageError(format!("cannot decode {}: {err}", path.display()))
+            })?;
+            // Shrink a stamp wider than the image; never enlarge one.
+            let available = (width - WATERMARK_INSET * 2.0).max(1.0);
+            let scale = (available / stamp.width().max(1) as f64).min(1.0);
+            let stamp_width = stamp.width() as f64 * scale;
+            let stamp_height = stamp.height() as f64 * scale;
+            let (left, top) = anchor(
+                watermark.position,
+                (x, y, width, height),
+                stamp_width,
+                stamp_height,
+            );
+            ctx.save().map_err(image_error)?;
+            ctx.translate(left, top);
+            ctx.scale(scale, scale);
+            ctx.set_source_surface(&stamp, 0.0, 0.0)
+                .map_err(image_error)?;
+            ctx.paint_with_alpha(watermark.opacity)
+                .map_err(image_error)?;
+            ctx.restore().map_err(image_error)?;
+        }
+    }
+    Ok(())
+}
+
+/// Top-left corner for a watermark of the given size inside `area`.
+fn anchor(
+    position: WatermarkPosition,
+    (x, y, width, height): (f64, f64, f64, f64),
+    mark_width: f64,
+    mark_height: f64,
+) -> (f64, f64) {
+    let left = x + WATERMARK_INSET;
+    let right = x + width - WATERMARK_INSET - mark_width;
+    let top = y + WATERMARK_INSET;
+    let bottom = y + height - WATERMARK_INSET - mark_height;
+    match position {
+        WatermarkPosition::TopLeft => (left, top),
+        WatermarkPosition::TopRight => (right, top),
+        WatermarkPosition::BottomLeft => (left, bottom),
+        WatermarkPosition::BottomRight => (right, bottom),
+        WatermarkPosition::Center => (
+            x + (width - mark_width) / 2.0,
+            y + (height - mark_height) / 2.0,
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::enums::ColorSpec;
+
+    /// A `width`x`height` white PNG with a red `inner` square at (x, y).
+    fn framed_png(width: i32, height: i32, inner: (i32, i32, i32)) -> Vec<u8> {
+        let surface = create_surface(width, height).unwrap();
+        let ctx = cairo::Context::new(&surface).unwrap();
+        ctx.set_source_rgb(1.0, 1.0, 1.0);
+        ctx.paint().unwrap();
+        ctx.set_source_rgb(1.0, 0.0, 0.0);
+        ctx.rectangle(
+            inner.0 as f64,
+            inner.1 as f64,
+            inner.2 as f64,
+            inner.2 as f64,
+        );
+        ctx.fill().unwrap();
+        drop(ctx);
+        let mut bytes = Vec::new();
+        surface.write_to_png(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn trim_crops_to_the_content_and_padding_grows_it_back() {
+        let mut config = CapturePipelineConfig::named("ticket");
+        config.trim = true;
+        let trimmed = PostProcessPipeline::from_config(&config)
+            .apply(&framed_png(100, 80, (30, 20, 10)))
+            .unwrap();
+        assert_eq!((trimmed.width, trimmed.height), (10, 10));
+
+        config.padding = 16;
+        config.border_width = 2.0;
+        let padded = PostProcessPipeline::from_config(&config)
+            .apply(&framed_png(100, 80, (30, 20, 10)))
+            .unwrap();
+        assert_eq!((padded.width, padded.height), (46, 46));
+    }
+
+    #[test]
+    fn shadow_reserves_room_beyond_small_padding() {
+        let mut config = CapturePipelineConfig::named("shadow");
+        config.padding = 4;
+        config.shadow = true;
+        config.shadow_radius = 12.0;
+        config.shadow_offset_y = 6.0;
+        config.background = Some(ColorSpec::Name("#ffffff".to_string()));
+        let image = PostProcessPipeline::from_config(&config)
+            .apply(&framed_png(40, 30, (0, 0, 5)))
+            .unwrap();
+        assert_eq!((image.width, image.height), (76, 66));
+    }
+
+    #[test]
+    fn a_missing_watermark_image_still_delivers_the_capture() {
+        let mut config = CapturePipelineConfig::named("stamp");
+        config.watermark_image = Some("/nonexistent/wayscriber-watermark.png".to_string());
+        let image = PostProcessPipeline::from_config(&config)
+            .apply(&framed_png(64, 48, (8, 8, 8)))
+            .unwrap();
+        assert_eq!((image.width, image.height), (64, 48));
+        assert_eq!(image.format, ImageFormatMetadata::png());
+    }
+
+    #[test]
+    fn blur_line_spreads_a_spike_evenly() {
+        let line = [0, 0, 90, 0, 0];
+        let mut out = [0u8; 5];
+        blur_line(&line, 1, |index, value| out[index] = value);
+        assert_eq!(out, [0, 30, 30, 30, 0]);
+    }
+}
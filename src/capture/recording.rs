@@ -0,0 +1,547 @@
+//! Animated GIF/APNG recording of the annotated overlay.
+//!
+//! The backend sends one [`RecordingMessage::Frame`] per tick; the worker
+//! renders it, keeps only the rectangle that changed since the previous frame
+//! (deflated, so long recordings stay small in memory), and encodes the whole
+//! animation once the recording stops.
+
+use super::file::{FileSaveConfig, save_screenshot};
+use super::types::CaptureError;
+use crate::config::RecordingFormat;
+use flate2::Compression;
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+/// GIF viewers treat delays below two centiseconds as "as slow as possible".
+const GIF_MIN_DELAY_CENTISECS: u16 = 2;
+/// Speed handed to the GIF quantizer (1 = best, 30 = fastest).
+const GIF_QUANTIZE_SPEED: i32 = 10;
+
+/// One rendered frame in straight (non-premultiplied) RGBA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordingFrame {
+    pub width: u32,
+    pub height: u32,
+    pub rgba: Vec<u8>,
+}
+
+/// Deferred render of a frame, run on the recording worker.
+pub type RecordingFrameJob = Box<dyn FnOnce() -> Result<RecordingFrame, CaptureError> + Send>;
+
+pub enum RecordingMessage {
+    /// A frame captured `at` after the recording started.
+    Frame {
+        at: Duration,
+        render: RecordingFrameJob,
+    },
+    /// Recording ended `at` after it started; encode and save.
+    Stop { at: Duration },
+}
+
+/// Collect frames until a stop message (or the sender goes away), then encode
+/// and save the animation.
+///
+/// # Returns
+/// Path to the saved animation
+pub fn run_recording(
+    receiver: Receiver<RecordingMessage>,
+    format: RecordingFormat,
+    save_config: FileSaveConfig,
+) -> Result<PathBuf, CaptureError> {
+    let mut animation: Option<RecordedAnimation> = None;
+    let mut stop_at = None;
+    while let Ok(message) = receiver.recv() {
+        match message {
+            RecordingMessage::Frame { at, render } => {
+                let frame = match render() {
+                    Ok(frame) => frame,
+                    Err(err) => {
+                        log::warn!("Skipping recording frame: {err}");
+                        continue;
+                    }
+                };
+                match animation.as_mut() {
+                    Some(animation) => animation.push(at, &frame)?,
+                    None => animation = Some(RecordedAnimation::new(at, frame)?),
+                }
+            }
+            RecordingMessage::Stop { at } => {
+                stop_at = Some(at);
+                break;
+            }
+        }
+    }
+
+    let animation = animation.ok_or_else(|| {
+        CaptureError::ImageError("recording ended before any frame was captured".to_string())
+    })?;
+    let stop_at = stop_at.unwrap_or_else(|| animation.last_frame_at());
+    let bytes = match format {
+        RecordingFormat::Gif => animation.encode_gif(stop_at)?,
+        RecordingFormat::Apng => animation.encode_apng(stop_at)?,
+    };
+    let config = FileSaveConfig {
+        format: format.extension().to_string(),
+        ..save_config
+    };
+    save_screenshot(&bytes, &config)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FrameRect {
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+}
+
+impl FrameRect {
+    fn union(self, other: Self) -> Self {
+        let x = self.x.min(other.x);
+        let y = self.y.min(other.y);
+        let right = (self.x + self.width).max(other.x + other.width);
+        let bottom = (self.y + self.height).max(other.y + other.height);
+        Self {
+            x,
+            y,
+            width: right - x,
+            height: bottom - y,
+        }
+    }
+}
+
+/// A GIF frame held back until the frame after it is known, since that frame
+/// decides whether this one must clear its area when it is disposed.
+struct PendingGifFrame {
+    rect: FrameRect,
+    delay: Duration,
+    dispose: gif::DisposalMethod,
+}
+
+struct StoredFrame {
+    at: Duration,
+    rect: FrameRect,
+    deflated: Vec<u8>,
+}
+
+/// Frames kept as deflated deltas against the frame before them.
+struct RecordedAnimation {
+    width: u32,
+    height: u32,
+    previous: Vec<u8>,
+    frames: Vec<StoredFrame>,
+}
+
+impl RecordedAnimation {
+    fn new(at: Duration, frame: RecordingFrame) -> Result<Self, CaptureError> {
+        if frame.width == 0 || frame.height == 0 {
+            return Err(CaptureError::ImageError(
+                "recording region is empty".to_string(),
+            ));
+        }
+        let rect = FrameRect {
+            x: 0,
+            y: 0,
+            width: frame.width,
+            height: frame.height,
+        };
+        let deflated = deflate(&frame.rgba)?;
+        Ok(Self {
+            width: frame.width,
+            height: frame.height,
+            previous: frame.rgba,
+            frames: vec![StoredFrame { at, rect, deflated }],
+        })
+    }
+
+    fn push(&mut self, at: Duration, frame: &RecordingFrame) -> Result<(), CaptureError> {
+        if frame.width != self.width || frame.height != self.height {
+            log::warn!(
+                "Skipping {}x{} recording frame; the recording is {}x{}",
+                frame.width,
+                frame.height,
+                self.width,
+                self.height
+            );
+            return Ok(());
+        }
+        // An unchanged frame just lengthens the previous frame's delay.
+        let Some(rect) = changed_rect(&self.previous, &frame.rgba, self.width, self.height) else {
+            return Ok(());
+        };
+        let deflated = deflate(&crop(&frame.rgba, self.width, rect))?;
+        self.previous.copy_from_slice(&frame.rgba);
+        self.frames.push(StoredFrame { at, rect, deflated });
+        Ok(())
+    }
+
+    fn last_frame_at(&self) -> Duration {
+        self.frames.last().map(|frame| frame.at).unwrap_or_default()
+    }
+
+    /// How long each frame stays on screen; the last one lasts until `stop_at`.
+    fn delays(&self, stop_at: Duration) -> Vec<Duration> {
+        let mut delays: Vec<Duration> = self
+            .frames
+            .windows(2)
+            .map(|pair| pair[1].at.saturating_sub(pair[0].at))
+            .collect();
+        delays.push(stop_at.saturating_sub(self.last_frame_at()));
+        delays
+    }
+
+    /// Encode as GIF, writing each frame as the area that changed.
+    ///
+    /// A GIF frame cannot make a pixel transparent: its transparent pixels leave
+    /// whatever is underneath. When a frame erases pixels, the frame before it
+    /// is grown to cover them and disposed to background, and this frame then
+    /// repaints that whole cleared area.
+    fn encode_gif(&self, stop_at: Duration) -> Result<Vec<u8>, CaptureError> {
+        let (width, height) = match (u16::try_from(self.width), u16::try_from(self.height)) {
+            (Ok(width), Ok(height)) => (width, height),
+            _ => {
+                return Err(CaptureError::ImageError(format!(
+                    "{}x{} is too large for a GIF",
+                    self.width, self.height
+                )));
+            }
+        };
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = gif::Encoder::new(&mut bytes, width, height, &[])
+                .map_err(|err| CaptureError::ImageError(format!("GIF encode failed: {err}")))?;
+            encoder
+                .set_repeat(gif::Repeat::Infinite)
+                .map_err(|err| CaptureError::ImageError(format!("GIF encode failed: {err}")))?;
+            let canvas_len = self.width as usize * self.height as usize * 4;
+            let mut previous = vec![0; canvas_len];
+            let mut canvas = vec![0; canvas_len];
+            let mut pending: Option<PendingGifFrame> = None;
+            for (stored, delay) in self.frames.iter().zip(self.delays(stop_at)) {
+                paste(
+                    &mut canvas,
+                    self.width,
+                    stored.rect,
+                    &inflate(&stored.deflated)?,
+                );
+                let mut rect = stored.rect;
+                if let Some(mut held) = pending.take() {
+                    if let Some(cleared) = cleared_rect(&previous, &canvas, self.width) {
+                        held.rect = held.rect.union(cleared);
+                        held.dispose = gif::DisposalMethod::Background;
+                        rect = rect.union(held.rect);
+                    }
+                    self.write_gif_frame(&mut encoder, &previous, &held)?;
+                }
+                previous.copy_from_slice(&canvas);
+                pending = Some(PendingGifFrame {
+                    rect,
+                    delay,
+                    dispose: gif::DisposalMethod::Keep,
+                });
+            }
+            if let Some(held) = pending {
+                self.write_gif_frame(&mut encoder, &previous, &held)?;
+            }
+        }
+        Ok(bytes)
+    }
+
+    fn write_gif_frame(
+        &self,
+        encoder: &mut gif::Encoder<&mut Vec<u8>>,
+        canvas: &[u8],
+        pending: &PendingGifFrame,
+    ) -> Result<(), CaptureError> {
+        let rect = pending.rect;
+        let mut rgba = crop(canvas, self.width, rect);
+        // The rect lies inside a frame whose size already fit in u16.
+        let mut frame = gif::Frame::from_rgba_speed(
+            rect.width as u16,
+            rect.height as u16,
+            &mut rgba,
+            GIF_QUANTIZE_SPEED,
+        );
+        frame.left = rect.x as u16;
+        frame.top = rect.y as u16;
+        frame.delay = centisecs(pending.delay).max(GIF_MIN_DELAY_CENTISECS);
+        frame.dispose = pending.dispose;
+        encoder
+            .write_frame(&frame)
+            .map_err(|err| CaptureError::ImageError(format!("GIF encode failed: {err}")))
+    }
+
+    fn encode_apng(&self, stop_at: Duration) -> Result<Vec<u8>, CaptureError> {
+        let map_err = |err: png::EncodingError| {
+            CaptureError::ImageError(format!("APNG encode failed: {err}"))
+        };
+        let frame_count = u32::try_from(self.frames.len())
+            .map_err(|_| CaptureError::ImageError("too many recording frames".to_string()))?;
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut bytes, self.width, self.height);
+            encoder.set_color(png::ColorType::Rgba);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.set_compression(png::Compression::Fast);
+            encoder.set_animated(frame_count, 0).map_err(map_err)?;
+            let mut writer = encoder.write_header().map_err(map_err)?;
+            for (stored, delay) in self.frames.iter().zip(self.delays(stop_at)) {
+                let rgba = inflate(&stored.deflated)?;
+                writer.reset_frame_position().map_err(map_err)?;
+                writer
+                    .set_frame_dimension(stored.rect.width, stored.rect.height)
+                    .map_err(map_err)?;
+                writer
+                    .set_frame_position(stored.rect.x, stored.rect.y)
+                    .map_err(map_err)?;
+                writer
+                    .set_frame_delay(centisecs(delay), 100)
+                    .map_err(map_err)?;
+                writer
+                    .set_dispose_op(png::DisposeOp::None)
+                    .map_err(map_err)?;
+                writer.set_blend_op(png::BlendOp::Source).map_err(map_err)?;
+                writer.write_image_data(&rgba).map_err(map_err)?;
+            }
+            writer.finish().map_err(map_err)?;
+        }
+        Ok(bytes)
+    }
+}
+
+fn centisecs(delay: Duration) -> u16 {
+    let centisecs = (delay.as_millis() + 5) / 10;
+    u16::try_from(centisecs).unwrap_or(u16::MAX)
+}
+
+/// Smallest rectangle holding every pixel that differs, or `None` when the
+/// frames are identical.
+fn changed_rect(previous: &[u8], next: &[u8], width: u32, height: u32) -> Option<FrameRect> {
+    let row_len = width as usize * 4;
+    let rows = || {
+        previous
+            .chunks_exact(row_len)
+            .zip(next.chunks_exact(row_len))
+    };
+    let top = rows().position(|(a, b)| a != b)?;
+    let bottom = height as usize - 1 - rows().rev().position(|(a, b)| a != b)?;
+
+    let mut left = width as usize;
+    let mut right = 0;
+    for (a, b) in rows().skip(top).take(bottom - top + 1) {
+        let pixels = || a.chunks_exact(4).zip(b.chunks_exact(4));
+        if let Some(first) = pixels().position(|(pa, pb)| pa != pb) {
+            left = left.min(first);
+            let last = width as usize - 1 - pixels().rev().position(|(pa, pb)| pa != pb)?;
+            right = right.max(last);
+        }
+    }
+    Some(FrameRect {
+        x: left as u32,
+        y: top as u32,
+        width: (right - left + 1) as u32,
+        height: (bottom - top + 1) as u32,
+    })
+}
+
+/// Bounding box of the pixels that are transparent in `next` but were not in
+/// `previous`, or `None` when nothing was erased.
+fn cleared_rect(previous: &[u8], next: &[u8], width: u32) -> Option<FrameRect> {
+    let mut bounds: Option<FrameRect> = None;
+    for (index, (old, new)) in previous
+        .chunks_exact(4)
+        .zip(next.chunks_exact(4))
+        .enumerate()
+    {
+        if new[3] != 0 || old[3] == 0 {
+            continue;
+        }
+        let pixel = FrameRect {
+            x: (index % width as usize) as u32,
+            y: (index / width as usize) as u32,
+            width: 1,
+            height: 1,
+        };
+        bounds = Some(bounds.map_or(pixel, |bounds| bounds.union(pixel)));
+    }
+    bounds
+}
+
+fn paste(canvas: &mut [u8], width: u32, rect: FrameRect, rgba: &[u8]) {
+    let row_len = width as usize * 4;
+    let start = rect.x as usize * 4;
+    let end = start + rect.width as usize * 4;
+    for (row, source) in canvas
+        .chunks_exact_mut(row_len)
+        .skip(rect.y as usize)
+        .zip(rgba.chunks_exact(rect.width as usize * 4))
+    {
+        row[start..end].copy_from_slice(source);
+    }
+}
+
+fn crop(rgba: &[u8], width: u32, rect: FrameRect) -> Vec<u8> {
+    let row_len = width as usize * 4;
+    let start = rect.x as usize * 4;
+    let end = start + rect.width as usize * 4;
+    rgba.chunks_exact(row_len)
+        .skip(rect.y as usize)
+        .take(rect.height as usize)
+        .flat_map(|row| &row[start..end])
+        .copied()
+        .collect()
+}
+
+fn deflate(data: &[u8]) -> Result<Vec<u8>, CaptureError> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::fast());
+    encoder.write_all(data)?;
+    Ok(encoder.finish()?)
+}
+
+fn inflate(data: &[u8]) -> Result<Vec<u8>, CaptureError> {
+    let mut decoded = Vec::new();
+    ZlibDecoder::new(data).read_to_end(&mut decoded)?;
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(width: u32, height: u32, rgba: [u8; 4]) -> RecordingFrame {
+        RecordingFrame {
+            width,
+            height,
+            rgba: rgba.repeat((width * height) as usize),
+        }
+    }
+
+    fn paint(frame: &mut RecordingFrame, x: u32, y: u32, rgba: [u8; 4]) {
+        let offset = ((y * frame.width + x) * 4) as usize;
+        frame.rgba[offset..offset + 4].copy_from_slice(&rgba);
+    }
+
+    #[test]
+    fn changed_rect_covers_only_the_changed_pixels() {
+        let base = solid(8, 6, [0, 0, 0, 255]);
+        let mut next = base.clone();
+        assert_eq!(changed_rect(&base.rgba, &next.rgba, 8, 6), None);
+
+        paint(&mut next, 2, 1, [255, 0, 0, 255]);
+        paint(&mut next, 5, 3, [0, 255, 0, 255]);
+        assert_eq!(
+            changed_rect(&base.rgba, &next.rgba, 8, 6),
+            Some(FrameRect {
+                x: 2,
+                y: 1,
+                width: 4,
+                height: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn identical_frames_extend_the_previous_delay() {
+        let first = solid(4, 4, [10, 20, 30, 255]);
+        let mut animation = RecordedAnimation::new(Duration::ZERO, first.clone()).unwrap();
+        animation.push(Duration::from_millis(100), &first).unwrap();
+        let mut changed = first;
+        paint(&mut changed, 1, 1, [200, 0, 0, 255]);
+        animation
+            .push(Duration::from_millis(200), &changed)
+            .unwrap();
+
+        assert_eq!(animation.frames.len(), 2);
+        assert_eq!(
+            animation.delays(Duration::from_millis(500)),
+            vec![Duration::from_millis(200), Duration::from_millis(300)]
+        );
+    }
+
+    #[test]
+    fn gif_and_apng_encodings_carry_every_stored_frame() {
+        let first = solid(6, 5, [0, 0, 0, 255]);
+        let mut animation = RecordedAnimation::new(Duration::ZERO, first.clone()).unwrap();
+        let mut changed = first;
+        paint(&mut changed, 4, 3, [255, 255, 255, 255]);
+        animation
+            .push(Duration::from_millis(100), &changed)
+            .unwrap();
+        let stop = Duration::from_millis(300);
+
+        let gif_bytes = animation.encode_gif(stop).unwrap();
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(gif_bytes.as_slice())
+            .unwrap();
+        let mut gif_frames = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            gif_frames.push((frame.left, frame.top, frame.width, frame.delay));
+        }
+        assert_eq!(gif_frames, vec![(0, 0, 6, 10), (4, 3, 1, 20)]);
+
+        let apng_bytes = animation.encode_apng(stop).unwrap();
+        let reader = png::Decoder::new(std::io::Cursor::new(apng_bytes))
+            .read_info()
+            .unwrap();
+        let control = reader.info().animation_control().unwrap();
+        assert_eq!(control.num_frames, 2);
+    }
+
+    #[test]
+    fn gif_playback_clears_pixels_a_later_frame_erases() {
+        let first = solid(4, 3, [0, 0, 0, 255]);
+        let mut marked = first.clone();
+        paint(&mut marked, 3, 2, [255, 0, 0, 255]);
+        let mut erased = marked.clone();
+        paint(&mut erased, 0, 0, [0, 0, 0, 0]);
+        paint(&mut erased, 1, 0, [0, 0, 0, 0]);
+        let mut animation = RecordedAnimation::new(Duration::ZERO, first.clone()).unwrap();
+        animation.push(Duration::from_millis(100), &marked).unwrap();
+        animation.push(Duration::from_millis(200), &erased).unwrap();
+
+        let gif_bytes = animation.encode_gif(Duration::from_millis(300)).unwrap();
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(gif_bytes.as_slice()).unwrap();
+        let mut screen = vec![0u8; 4 * 3 * 4];
+        let mut disposal: Option<(gif::DisposalMethod, FrameRect)> = None;
+        let mut shown = Vec::new();
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            let rect = FrameRect {
+                x: frame.left.into(),
+                y: frame.top.into(),
+                width: frame.width.into(),
+                height: frame.height.into(),
+            };
+            if let Some((gif::DisposalMethod::Background, cleared)) = disposal {
+                let blank = vec![0; (cleared.width * cleared.height * 4) as usize];
+                paste(&mut screen, 4, cleared, &blank);
+            }
+            let mut drawn = crop(&screen, 4, rect);
+            for (under, over) in drawn.chunks_exact_mut(4).zip(frame.buffer.chunks_exact(4)) {
+                if over[3] != 0 {
+                    under.copy_from_slice(over);
+                }
+            }
+            paste(&mut screen, 4, rect, &drawn);
+            disposal = Some((frame.dispose, rect));
+            shown.push(screen.clone());
+        }
+
+        assert_eq!(shown, vec![first.rgba, marked.rgba, erased.rgba]);
+    }
+
+    #[test]
+    fn mismatched_frame_sizes_are_skipped() {
+        let mut animation =
+            RecordedAnimation::new(Duration::ZERO, solid(4, 4, [0, 0, 0, 255])).unwrap();
+        animation
+            .push(Duration::from_millis(100), &solid(5, 4, [1, 1, 1, 255]))
+            .unwrap();
+        assert_eq!(animation.frames.len(), 1);
+    }
+}
@@ -116,6 +116,7 @@ async fn completion_is_observable_when_the_notifier_runs() {
             CaptureType::FullScreen,
             CaptureDestination::ClipboardOnly,
             None,
+            None,
         )
         .unwrap();
 
@@ -161,6 +162,7 @@ async fn completion_wakes_an_already_blocked_runtime_poll_after_publication() {
             CaptureType::FullScreen,
             CaptureDestination::ClipboardOnly,
             None,
+            None,
         )
         .unwrap();
     started.notified().await;
@@ -218,6 +220,7 @@ async fn worker_panic_wakes_and_reports_the_active_operation_once() {
             CaptureType::FullScreen,
             CaptureDestination::ClipboardOnly,
             None,
+            None,
         )
         .unwrap();
 
@@ -284,6 +287,7 @@ async fn test_capture_manager_with_dependencies() {
             CaptureType::FullScreen,
             CaptureDestination::ClipboardOnly,
             None,
+            None,
         )
         .unwrap();
 
@@ -307,6 +311,7 @@ fn request_capture_returns_error_when_channel_closed() {
             CaptureType::FullScreen,
             CaptureDestination::ClipboardOnly,
             None,
+            None,
         )
         .expect_err("should fail when channel closed");
     assert!(
@@ -344,6 +349,7 @@ async fn capture_manager_records_failure_status() {
             CaptureType::FullScreen,
             CaptureDestination::FileOnly,
             Some(FileSaveConfig::default()),
+            None,
         )
         .unwrap();
 
@@ -387,6 +393,7 @@ async fn capture_manager_preserves_user_cancellation_as_a_terminal_outcome() {
             CaptureType::FullScreen,
             CaptureDestination::ClipboardOnly,
             None,
+            None,
         )
         .unwrap();
 
@@ -502,6 +509,7 @@ async fn request_image_delivery_queues_manager_backed_path() {
             }),
             operation: ImageOperationKind::CanvasExport,
             fallback_format_override: Some(ImageFormatMetadata::png()),
+            postprocess: None,
         })
         .unwrap();
 
@@ -603,6 +611,7 @@ async fn request_image_delivery_records_canvas_save_failure() {
             save_config: Some(FileSaveConfig::default()),
             operation: ImageOperationKind::CanvasExport,
             fallback_format_override: Some(ImageFormatMetadata::png()),
+            postprocess: None,
         })
         .unwrap();
 
@@ -708,6 +717,7 @@ async fn request_image_delivery_preserves_clipboard_success_when_file_fails() {
             save_config: Some(FileSaveConfig::default()),
             operation: ImageOperationKind::CanvasExport,
             fallback_format_override: Some(ImageFormatMetadata::png()),
+            postprocess: None,
         })
         .unwrap();
 
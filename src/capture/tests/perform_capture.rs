@@ -5,6 +5,7 @@ use std::{
 
 use crate::capture::{
     dependencies::{CaptureClipboard, CaptureDependencies, CaptureFileSaver},
+    encode::ImageEncoding,
     file::FileSaveConfig,
     pipeline::{CaptureRequest, deliver_document, deliver_image, perform_capture},
     types::{
@@ -103,6 +104,7 @@ async fn test_perform_capture_clipboard_only_success() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::ClipboardOnly,
         save_config: None,
+        postprocess: None,
     };
 
     let result = perform_capture(request, Arc::new(deps.clone()))
@@ -148,6 +150,7 @@ async fn deliver_image_file_only_saves_rendered_format_extension() {
         }),
         operation: ImageOperationKind::CanvasExport,
         fallback_format_override: Some(ImageFormatMetadata::png()),
+        postprocess: None,
     };
 
     let result = deliver_image(request, Arc::new(deps)).await.unwrap();
@@ -162,6 +165,48 @@ async fn deliver_image_file_only_saves_rendered_format_extension() {
     assert_eq!(result.saved_path, Some(PathBuf::from("/tmp/canvas.png")));
 }
 
+#[tokio::test]
+async fn deliver_image_keeps_a_requested_reencoding_format_for_png() {
+    let configs = Arc::new(Mutex::new(Vec::new()));
+    let saver = RecordingSaver {
+        should_fail: false,
+        path: PathBuf::from("/tmp/canvas.webp"),
+        calls: Arc::new(Mutex::new(0)),
+        configs: configs.clone(),
+    };
+    let deps = CaptureDependencies {
+        source: Arc::new(MockSource {
+            data: Vec::new(),
+            error: Arc::new(Mutex::new(None)),
+            captured_types: Arc::new(Mutex::new(Vec::new())),
+        }),
+        saver: Arc::new(saver.clone()),
+        clipboard: Arc::new(RecordingClipboard {
+            should_fail: false,
+            calls: Arc::new(Mutex::new(0)),
+            copied: Arc::new(Mutex::new(Vec::new())),
+        }),
+    };
+    let request = ImageDeliveryRequest {
+        image: rendered_png(vec![137, 80, 78, 71]),
+        destination: CaptureDestination::FileOnly,
+        save_config: Some(FileSaveConfig {
+            format: "webp".to_string(),
+            encoding: ImageEncoding::for_format("webp", &crate::config::CaptureConfig::default()),
+            ..FileSaveConfig::default()
+        }),
+        operation: ImageOperationKind::CanvasExport,
+        fallback_format_override: Some(ImageFormatMetadata::png()),
+        postprocess: None,
+    };
+
+    deliver_image(request, Arc::new(deps)).await.unwrap();
+
+    let configs = configs.lock().unwrap();
+    assert_eq!(configs[0].format, "webp");
+    assert!(configs[0].encoding.target.is_some());
+}
+
 #[tokio::test]
 async fn deliver_document_file_only_saves_pdf_bytes_with_pdf_extension() {
     let configs = Arc::new(Mutex::new(Vec::new()));
@@ -349,6 +394,7 @@ async fn deliver_image_clipboard_only_copies_png_bytes() {
         save_config: None,
         operation: ImageOperationKind::CanvasExport,
         fallback_format_override: Some(ImageFormatMetadata::png()),
+        postprocess: None,
     };
 
     let result = deliver_image(request, Arc::new(deps)).await.unwrap();
@@ -385,6 +431,7 @@ async fn deliver_image_clipboard_and_file_keeps_file_success_when_clipboard_fail
         save_config: Some(FileSaveConfig::default()),
         operation: ImageOperationKind::CanvasExport,
         fallback_format_override: Some(ImageFormatMetadata::png()),
+        postprocess: None,
     };
 
     let result = deliver_image(request, Arc::new(deps)).await.unwrap();
@@ -420,6 +467,7 @@ async fn deliver_image_clipboard_and_file_keeps_clipboard_success_when_file_fail
         save_config: Some(FileSaveConfig::default()),
         operation: ImageOperationKind::CanvasExport,
         fallback_format_override: Some(ImageFormatMetadata::png()),
+        postprocess: None,
     };
 
     let result = deliver_image(request, Arc::new(deps)).await.unwrap();
@@ -460,6 +508,7 @@ async fn test_perform_capture_file_only_success() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::FileOnly,
         save_config: Some(FileSaveConfig::default()),
+        postprocess: None,
     };
 
     let result = perform_capture(request, Arc::new(deps.clone()))
@@ -497,6 +546,7 @@ async fn test_perform_capture_clipboard_failure() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::ClipboardOnly,
         save_config: None,
+        postprocess: None,
     };
 
     let result = perform_capture(request, Arc::new(deps.clone()))
@@ -532,6 +582,7 @@ async fn test_perform_capture_save_failure() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::FileOnly,
         save_config: Some(FileSaveConfig::default()),
+        postprocess: None,
     };
 
     let err = perform_capture(request, Arc::new(deps.clone()))
@@ -569,6 +620,7 @@ async fn test_perform_capture_clipboard_and_file_success() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::ClipboardAndFile,
         save_config: Some(FileSaveConfig::default()),
+        postprocess: None,
     };
 
     let result = perform_capture(request, Arc::new(deps)).await.unwrap();
@@ -605,6 +657,7 @@ async fn test_perform_capture_clipboard_and_file_save_failure_still_copies() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::ClipboardAndFile,
         save_config: Some(FileSaveConfig::default()),
+        postprocess: None,
     };
 
     let result = perform_capture(request, Arc::new(deps)).await.unwrap();
@@ -645,6 +698,7 @@ async fn perform_capture_propagates_source_error() {
         capture_type: CaptureType::FullScreen,
         destination: CaptureDestination::ClipboardOnly,
         save_config: None,
+        postprocess: None,
     };
 
     let err = perform_capture(request, Arc::new(deps)).await.unwrap_err();
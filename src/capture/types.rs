@@ -10,6 +10,9 @@ pub enum ImageOperationKind {
     CanvasExport,
     BoardPdfExport,
     AllBoardsPdfExport,
+    CanvasSvgExport,
+    BoardSvgExport,
+    ReplayFrameExport,
 }
 
 impl ImageOperationKind {
@@ -19,6 +22,9 @@ impl ImageOperationKind {
             Self::CanvasExport => "Canvas exported",
             Self::BoardPdfExport => "Board exported",
             Self::AllBoardsPdfExport => "Boards exported",
+            Self::CanvasSvgExport => "Canvas exported",
+            Self::BoardSvgExport => "Board exported",
+            Self::ReplayFrameExport => "Replay exported",
         }
     }
 
@@ -28,6 +34,9 @@ impl ImageOperationKind {
             Self::CanvasExport => "Canvas export failed",
             Self::BoardPdfExport => "Board PDF export failed",
             Self::AllBoardsPdfExport => "All boards PDF export failed",
+            Self::CanvasSvgExport => "Canvas SVG export failed",
+            Self::BoardSvgExport => "Board SVG export failed",
+            Self::ReplayFrameExport => "Replay frame export failed",
         }
     }
 
@@ -37,6 +46,9 @@ impl ImageOperationKind {
             Self::CanvasExport => "Canvas file not saved",
             Self::BoardPdfExport => "Board PDF not saved",
             Self::AllBoardsPdfExport => "All boards PDF not saved",
+            Self::CanvasSvgExport => "Canvas SVG not saved",
+            Self::BoardSvgExport => "Board SVG not saved",
+            Self::ReplayFrameExport => "Replay frames not saved",
         }
     }
 
@@ -46,6 +58,9 @@ impl ImageOperationKind {
             Self::CanvasExport => "Canvas clipboard failed",
             Self::BoardPdfExport => "Board PDF clipboard failed",
             Self::AllBoardsPdfExport => "All boards PDF clipboard failed",
+            Self::CanvasSvgExport => "Canvas SVG clipboard failed",
+            Self::BoardSvgExport => "Board SVG clipboard failed",
+            Self::ReplayFrameExport => "Replay frames clipboard failed",
         }
     }
 
@@ -55,6 +70,9 @@ impl ImageOperationKind {
             Self::CanvasExport => "Canvas clipboard failed",
             Self::BoardPdfExport => "Board PDF clipboard failed",
             Self::AllBoardsPdfExport => "All boards PDF clipboard failed",
+            Self::CanvasSvgExport => "Canvas SVG clipboard failed",
+            Self::BoardSvgExport => "Board SVG clipboard failed",
+            Self::ReplayFrameExport => "Replay frames clipboard failed",
         }
     }
 
@@ -64,6 +82,9 @@ impl ImageOperationKind {
             Self::CanvasExport => "Canvas export",
             Self::BoardPdfExport => "Board PDF export",
             Self::AllBoardsPdfExport => "All boards PDF export",
+            Self::CanvasSvgExport => "Canvas SVG export",
+            Self::BoardSvgExport => "Board SVG export",
+            Self::ReplayFrameExport => "Replay frame export",
         }
     }
 
@@ -109,6 +130,45 @@ impl ImageOperationKind {
                 }
                 other => other.to_string(),
             },
+            Self::CanvasSvgExport => match err {
+                CaptureError::SaveError(err) => {
+                    format!("Failed to save canvas SVG export: {err}")
+                }
+                CaptureError::ClipboardError(err) => {
+                    format!("Canvas SVG export clipboard operation failed: {err}")
+                }
+                CaptureError::ImageError(err) => format!("Canvas SVG export failed: {err}"),
+                CaptureError::Cancelled(reason) => {
+                    format!("Canvas SVG export cancelled: {reason}")
+                }
+                other => other.to_string(),
+            },
+            Self::BoardSvgExport => match err {
+                CaptureError::SaveError(err) => {
+                    format!("Failed to save board SVG export: {err}")
+                }
+                CaptureError::ClipboardError(err) => {
+                    format!("Board SVG export clipboard operation failed: {err}")
+                }
+                CaptureError::ImageError(err) => format!("Board SVG export failed: {err}"),
+                CaptureError::Cancelled(reason) => {
+                    format!("Board SVG export cancelled: {reason}")
+                }
+                other => other.to_string(),
+            },
+            Self::ReplayFrameExport => match err {
+                CaptureError::SaveError(err) => {
+                    format!("Failed to save replay frames: {err}")
+                }
+                CaptureError::ClipboardError(err) => {
+                    format!("Replay frame export clipboard operation failed: {err}")
+                }
+                CaptureError::ImageError(err) => format!("Replay frame export failed: {err}"),
+                CaptureError::Cancelled(reason) => {
+                    format!("Replay frame export cancelled: {reason}")
+                }
+                other => other.to_string(),
+            },
         }
     }
 }
@@ -143,6 +203,8 @@ pub struct ImageDeliveryRequest {
     pub save_config: Option<crate::capture::file::FileSaveConfig>,
     pub operation: ImageOperationKind,
     pub fallback_format_override: Option<ImageFormatMetadata>,
+    /// Post-processing applied to PNG images before delivery.
+    pub postprocess: Option<crate::capture::PostProcessPipeline>,
 }
 
 /// Rendering deferred onto the capture worker, so export render + encode work
@@ -161,6 +223,21 @@ pub struct RenderedImageDeliveryRequest {
     pub save_config: Option<crate::capture::file::FileSaveConfig>,
     pub operation: ImageOperationKind,
     pub fallback_format_override: Option<ImageFormatMetadata>,
+    /// Post-processing applied to PNG images before delivery.
+    pub postprocess: Option<crate::capture::PostProcessPipeline>,
+}
+
+/// Renders one frame of a sequence by index; called once per frame, in order,
+/// on the capture worker.
+pub type FrameRenderJob = Box<dyn FnMut(usize) -> Result<RenderedImage, CaptureError> + Send>;
+
+/// A numbered PNG frame sequence, rendered and saved frame by frame on the
+/// capture worker into a new folder named from `save_config`.
+pub struct FrameSequenceExportRequest {
+    pub frame_count: usize,
+    pub render_frame: FrameRenderJob,
+    pub save_config: crate::capture::file::FileSaveConfig,
+    pub operation: ImageOperationKind,
 }
 
 /// [`DocumentDeliveryRequest`], with the document rendered on the capture
@@ -1,6 +1,10 @@
 use std::ffi::OsString;
 use std::path::PathBuf;
 
+use crate::control_socket::{
+    BoardTarget, ControlRequest, parse_action_name, parse_control_color, parse_tool_name,
+};
+use crate::headless_render::{DEFAULT_RENDER_SIZE, MAX_RENDER_SIDE, RenderFormat, RenderRequest};
 use crate::tray_action::TrayAction;
 
 #[derive(Debug, Default)]
@@ -76,11 +80,18 @@ pub struct Cli {
 
     /// Print compiled runtime capabilities for companion tools
     pub runtime_capabilities: bool,
+
+    /// Print overlay events as JSON lines (waybar custom module) until killed
+    pub watch: bool,
 }
 
 #[derive(Debug)]
 pub(crate) enum CliOutcome {
     Run(Cli),
+    /// `wayscriber ctl ...`: one request for the running overlay's control socket.
+    Ctl(ControlRequest),
+    /// `wayscriber render ...`: draw a session file to PNG or PDF offscreen.
+    Render(RenderRequest),
     Help,
     Version,
 }
@@ -108,6 +119,13 @@ impl Cli {
             args.push("wayscriber".to_string());
         }
 
+        if args.get(1).is_some_and(|arg| arg == "ctl") {
+            return parse_ctl(&args[2..]);
+        }
+        if args.get(1).is_some_and(|arg| arg == "render") {
+            return parse_render(&args[2..]);
+        }
+
         let mut cli = Cli::default();
         let mut index = 1;
         while index < args.len() {
@@ -151,6 +169,7 @@ impl Cli {
                 "--no-resume-session" => cli.no_resume_session = true,
                 "--about" => cli.about = true,
                 "--check-update" => cli.check_update = true,
+                "--watch" => cli.watch = true,
                 crate::runtime_capabilities::RUNTIME_CAPABILITIES_FLAG => {
                     cli.runtime_capabilities = true;
                 }
@@ -436,11 +455,214 @@ impl Cli {
         if self.check_update && self.selects_a_launch_command() {
             return Err("--check-update conflicts with the selected command".to_string());
         }
+        if self.watch
+            && (self.selects_a_launch_command()
+                || self.about
+                || self.check_update
+                || self.runtime_capabilities)
+        {
+            return Err("--watch conflicts with the selected command".to_string());
+        }
 
         Ok(())
     }
 }
 
+/// Parses `wayscriber ctl COMMAND [VALUE]`.
+///
+/// Names are checked here as well as by the overlay so a typo fails with the
+/// usage hint and exit status 2 instead of a round trip to the socket.
+fn parse_ctl(args: &[String]) -> Result<CliOutcome, String> {
+    let Some((command, rest)) = args.split_first() else {
+        return Err("ctl requires a command".to_string());
+    };
+    let value = || match rest {
+        [value] => Ok(value.as_str()),
+        [] => Err(format!("ctl {command} requires a value")),
+        _ => Err(format!("ctl {command} takes exactly one value")),
+    };
+    let request = match command.as_str() {
+        "-h" | "--help" => return Ok(CliOutcome::Help),
+        "status" => {
+            if !rest.is_empty() {
+                return Err("ctl status takes no value".to_string());
+            }
+            ControlRequest::Status
+        }
+        "action" => {
+            let name = value()?;
+            if parse_action_name(name).is_none() {
+                return Err(format!("unknown action '{name}'"));
+            }
+            ControlRequest::Action {
+                name: name.to_string(),
+            }
+        }
+        "color" => {
+            let color = value()?;
+            if parse_control_color(color).is_none() {
+                return Err(format!("invalid color '{color}'"));
+            }
+            ControlRequest::SetColor {
+                color: color.to_string(),
+            }
+        }
+        "thickness" => {
+            let raw = value()?;
+            let thickness = raw
+                .parse::<f64>()
+                .ok()
+                .filter(|thickness| thickness.is_finite() && *thickness > 0.0)
+                .ok_or_else(|| format!("invalid thickness '{raw}'"))?;
+            ControlRequest::SetThickness { thickness }
+        }
+        "tool" => {
+            let tool = value()?;
+            if parse_tool_name(tool).is_none() {
+                return Err(format!("unknown tool '{tool}'"));
+            }
+            ControlRequest::SetTool {
+                tool: tool.to_string(),
+            }
+        }
+        "board" => ControlRequest::SwitchBoard {
+            board: BoardTarget::parse(value()?),
+        },
+        "page" => {
+            let raw = value()?;
+            let page = raw
+                .parse::<usize>()
+                .ok()
+                .filter(|page| *page > 0)
+                .ok_or_else(|| format!("invalid page '{raw}'"))?;
+            ControlRequest::SwitchPage { page }
+        }
+        "background" => match value()? {
+            "--clear" => ControlRequest::ClearBackground,
+            raw => {
+                let path = std::path::Path::new(raw);
+                if !crate::page_import::is_supported_path(path) {
+                    return Err(format!("'{raw}' is not a PDF, SVG, PNG, or JPEG file"));
+                }
+                // The overlay resolves paths against its own working directory.
+                let path = std::path::absolute(path)
+                    .map_err(|err| format!("invalid background path '{raw}': {err}"))?;
+                ControlRequest::ImportBackground { path }
+            }
+        },
+        other => return Err(format!("unknown ctl command '{other}'")),
+    };
+    Ok(CliOutcome::Ctl(request))
+}
+
+const RENDER_OPTIONS: [&str; 7] = [
+    "--session-file",
+    "--board",
+    "--page",
+    "--format",
+    "--out",
+    "--size",
+    "--scale",
+];
+
+/// Parses `wayscriber render --session-file PATH --out PATH [OPTIONS]`.
+///
+/// The format falls back to the `--out` extension when `--format` is absent.
+fn parse_render(args: &[String]) -> Result<CliOutcome, String> {
+    let mut session_file = None;
+    let mut board = None;
+    let mut page = None;
+    let mut format = None;
+    let mut out = None;
+    let mut size = DEFAULT_RENDER_SIZE;
+    let mut scale = 1;
+
+    let mut index = 0;
+    while index < args.len() {
+        let arg = &args[index];
+        if matches!(arg.as_str(), "-h" | "--help") {
+            return Ok(CliOutcome::Help);
+        }
+        let name = match arg.split_once('=') {
+            Some((name, _)) if name.starts_with("--") => name,
+            _ => arg.as_str(),
+        };
+        if !RENDER_OPTIONS.contains(&name) {
+            return Err(format!("unknown render option '{name}'"));
+        }
+        let value = if name.len() < arg.len() {
+            value_from_equals(arg, name)?
+        } else {
+            index += 1;
+            value_after(args, index, name)?
+        };
+        match name {
+            "--session-file" => session_file = Some(PathBuf::from(value)),
+            "--board" => board = Some(value),
+            "--page" => {
+                page = Some(
+                    value
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|page| *page > 0)
+                        .ok_or_else(|| format!("invalid page '{value}'"))?,
+                );
+            }
+            "--format" => {
+                format =
+                    Some(RenderFormat::parse(&value).ok_or_else(|| {
+                        format!("invalid format '{value}' (expected png or pdf)")
+                    })?);
+            }
+            "--out" => out = Some(PathBuf::from(value)),
+            "--size" => {
+                size = value
+                    .split_once(['x', 'X'])
+                    .and_then(|(width, height)| {
+                        Some((width.parse::<u32>().ok()?, height.parse::<u32>().ok()?))
+                    })
+                    .filter(|(width, height)| *width > 0 && *height > 0)
+                    .ok_or_else(|| format!("invalid size '{value}' (expected WIDTHxHEIGHT)"))?;
+                if size.0 > MAX_RENDER_SIDE || size.1 > MAX_RENDER_SIDE {
+                    return Err(format!(
+                        "invalid size '{value}' (each side must be at most {MAX_RENDER_SIDE})"
+                    ));
+                }
+            }
+            "--scale" => {
+                scale = value
+                    .parse::<i32>()
+                    .ok()
+                    .filter(|scale| (1..=8).contains(scale))
+                    .ok_or_else(|| format!("invalid scale '{value}' (expected 1-8)"))?;
+            }
+            _ => unreachable!("render options are checked above"),
+        }
+        index += 1;
+    }
+
+    let session_file = session_file.ok_or("render requires --session-file")?;
+    let out = out.ok_or("render requires --out")?;
+    let format = match format {
+        Some(format) => format,
+        None => out
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(RenderFormat::parse)
+            .ok_or("render requires --format png|pdf when --out has no .png/.pdf extension")?,
+    };
+    Ok(CliOutcome::Render(RenderRequest {
+        session_file,
+        board,
+        page,
+        format,
+        out,
+        width: size.0,
+        height: size.1,
+        scale,
+    }))
+}
+
 fn is_short_option_cluster(arg: &str) -> bool {
     arg.starts_with('-') && !arg.starts_with("--") && arg.len() > 2
 }
@@ -538,6 +760,9 @@ pub(crate) fn print_help() {
     println!("  wayscriber --clear-tool-state [--session-file PATH]");
     println!("  wayscriber --about");
     println!("  wayscriber --check-update");
+    println!("  wayscriber --watch");
+    println!("  wayscriber ctl COMMAND [VALUE]");
+    println!("  wayscriber render --session-file PATH --out PATH [RENDER OPTIONS]");
     println!();
     println!("Options:");
     println!("  -d, --daemon                  Run as background daemon");
@@ -563,8 +788,29 @@ pub(crate) fn print_help() {
     println!("      --session-file PATH       Use a named session file");
     println!("      --about                   Show the About window");
     println!("      --check-update            Check wayscriber.com for a newer release");
+    println!("      --watch                   Print overlay events as JSON lines (waybar)");
     println!("  -h, --help                    Show help");
     println!("  -V, --version                 Show version");
+    println!();
+    println!("Control commands (running overlay, JSON response on stdout):");
+    println!("  ctl status                    Show tool, color, board, page, visibility");
+    println!("  ctl action NAME               Run any action by its keybinding name");
+    println!("  ctl color COLOR               Set color (name, #RRGGBB, #RRGGBBAA)");
+    println!("  ctl thickness PX              Set the active tool's size");
+    println!("  ctl tool TOOL                 Select a tool (pen, marker, rect, ...)");
+    println!("  ctl board SLOT|ID             Switch board by 1-based slot or id");
+    println!("  ctl page N                    Switch to 1-based page N");
+    println!("  ctl background FILE           Import PDF/SVG/PNG pages as page backgrounds");
+    println!("  ctl background --clear        Remove the active page's background");
+    println!();
+    println!("Render options (no compositor needed; exits non-zero on errors):");
+    println!("      --session-file PATH       Session file to read");
+    println!("      --out PATH                File to write");
+    println!("      --format png|pdf          Output format (default: from --out extension)");
+    println!("      --board ID                Board to render (default: the active board)");
+    println!("      --page N                  1-based page (default: active page; PDF: all)");
+    println!("      --size WxH                Canvas size in logical pixels (default: 1920x1080)");
+    println!("      --scale N                 PNG pixels per logical pixel, 1-8 (default: 1)");
 }
 
 pub(crate) fn print_version() {
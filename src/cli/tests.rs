@@ -1,6 +1,8 @@
 use std::path::PathBuf;
 
 use super::{Cli, CliOutcome};
+use crate::control_socket::{BoardTarget, ControlRequest};
+use crate::headless_render::{RenderFormat, RenderRequest};
 use crate::tray_action::TrayAction;
 
 fn parse_cli<const N: usize>(args: [&str; N]) -> Cli {
@@ -348,6 +350,15 @@ fn offline_session_commands_conflict_with_each_other() {
     );
 }
 
+#[test]
+fn daemon_action_accepts_timer_actions() {
+    let cli = parse_cli(["wayscriber", "--daemon-action=start_break_timer"]);
+    assert_eq!(
+        cli.daemon_overlay_action().unwrap(),
+        Some(TrayAction::StartBreakTimer)
+    );
+}
+
 #[test]
 fn daemon_action_accepts_light_mode_actions() {
     let cli = parse_cli(["wayscriber", "--daemon-action", "light_draw_toggle"]);
@@ -454,7 +465,12 @@ fn print_and_exit_commands_reject_every_launch_flag() {
         "--no-resume-session",
     ];
 
-    for command in ["--about", "--check-update", "--runtime-capabilities"] {
+    for command in [
+        "--about",
+        "--check-update",
+        "--runtime-capabilities",
+        "--watch",
+    ] {
         for flag in launch_flags {
             let result = Cli::try_parse_from(["wayscriber", command, flag]);
             assert!(
@@ -478,6 +494,19 @@ fn print_and_exit_commands_reject_each_other() {
     );
 }
 
+#[test]
+fn watch_parses_on_its_own_and_rejects_print_commands() {
+    assert!(parse_cli(["wayscriber", "--watch"]).watch);
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "--watch", "--about"]).unwrap_err(),
+        "--watch conflicts with the selected command"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "--watch", "--mode", "whiteboard"]).unwrap_err(),
+        "--watch conflicts with the selected command"
+    );
+}
+
 #[test]
 fn check_update_rejects_valued_launch_flags() {
     assert_eq!(
@@ -506,3 +535,198 @@ fn check_update_rejects_valued_launch_flags() {
         "--check-update conflicts with the selected command"
     );
 }
+
+fn parse_ctl<const N: usize>(args: [&str; N]) -> ControlRequest {
+    match Cli::try_parse_from(args).unwrap() {
+        CliOutcome::Ctl(request) => request,
+        outcome => panic!("expected ctl outcome, got {outcome:?}"),
+    }
+}
+
+#[test]
+fn ctl_subcommands_build_control_requests() {
+    assert_eq!(
+        parse_ctl(["wayscriber", "ctl", "status"]),
+        ControlRequest::Status
+    );
+    assert_eq!(
+        parse_ctl(["wayscriber", "ctl", "action", "toggle_whiteboard"]),
+        ControlRequest::Action {
+            name: "toggle_whiteboard".to_string()
+        }
+    );
+    assert_eq!(
+        parse_ctl(["wayscriber", "ctl", "thickness", "6.5"]),
+        ControlRequest::SetThickness { thickness: 6.5 }
+    );
+    assert_eq!(
+        parse_ctl(["wayscriber", "ctl", "board", "3"]),
+        ControlRequest::SwitchBoard {
+            board: BoardTarget::Slot(3)
+        }
+    );
+    assert_eq!(
+        parse_ctl(["wayscriber", "ctl", "board", "blackboard"]),
+        ControlRequest::SwitchBoard {
+            board: BoardTarget::Id("blackboard".to_string())
+        }
+    );
+    assert_eq!(
+        parse_ctl(["wayscriber", "ctl", "page", "2"]),
+        ControlRequest::SwitchPage { page: 2 }
+    );
+    assert_eq!(
+        parse_ctl(["wayscriber", "ctl", "background", "/tmp/slides.pdf"]),
+        ControlRequest::ImportBackground {
+            path: "/tmp/slides.pdf".into()
+        }
+    );
+    match parse_ctl(["wayscriber", "ctl", "background", "deck/slides.pdf"]) {
+        ControlRequest::ImportBackground { path } => {
+            assert!(path.is_absolute());
+            assert!(path.ends_with("deck/slides.pdf"));
+        }
+        other => panic!("expected import request, got {other:?}"),
+    }
+    assert_eq!(
+        parse_ctl(["wayscriber", "ctl", "background", "--clear"]),
+        ControlRequest::ClearBackground
+    );
+    assert!(matches!(
+        Cli::try_parse_from(["wayscriber", "ctl", "--help"]).unwrap(),
+        CliOutcome::Help
+    ));
+}
+
+#[test]
+fn ctl_rejects_unknown_names_before_connecting() {
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "ctl"]).unwrap_err(),
+        "ctl requires a command"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "ctl", "action", "fly"]).unwrap_err(),
+        "unknown action 'fly'"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "ctl", "tool", "chisel"]).unwrap_err(),
+        "unknown tool 'chisel'"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "ctl", "page", "0"]).unwrap_err(),
+        "invalid page '0'"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "ctl", "color"]).unwrap_err(),
+        "ctl color requires a value"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "ctl", "status", "now"]).unwrap_err(),
+        "ctl status takes no value"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "ctl", "background", "notes.txt"]).unwrap_err(),
+        "'notes.txt' is not a PDF, SVG, PNG, or JPEG file"
+    );
+    // `ctl` is a subcommand only in first position.
+    assert!(Cli::try_parse_from(["wayscriber", "--active", "ctl"]).is_err());
+}
+
+fn parse_render<const N: usize>(args: [&str; N]) -> RenderRequest {
+    match Cli::try_parse_from(args).unwrap() {
+        CliOutcome::Render(request) => request,
+        outcome => panic!("expected render outcome, got {outcome:?}"),
+    }
+}
+
+#[test]
+fn render_subcommand_builds_a_render_request() {
+    assert_eq!(
+        parse_render([
+            "wayscriber",
+            "render",
+            "--session-file",
+            "docs/demo.wayscriber",
+            "--board",
+            "whiteboard",
+            "--page=2",
+            "--format",
+            "pdf",
+            "--out",
+            "demo.out",
+            "--size",
+            "1280x720",
+            "--scale",
+            "2",
+        ]),
+        RenderRequest {
+            session_file: PathBuf::from("docs/demo.wayscriber"),
+            board: Some("whiteboard".to_string()),
+            page: Some(2),
+            format: RenderFormat::Pdf,
+            out: PathBuf::from("demo.out"),
+            width: 1280,
+            height: 720,
+            scale: 2,
+        }
+    );
+
+    let request = parse_render([
+        "wayscriber",
+        "render",
+        "--session-file",
+        "demo.wayscriber",
+        "--out",
+        "demo.PNG",
+    ]);
+    assert_eq!(request.format, RenderFormat::Png);
+    assert_eq!(
+        (request.width, request.height, request.scale),
+        (1920, 1080, 1)
+    );
+    assert_eq!((request.board, request.page), (None, None));
+}
+
+#[test]
+fn render_rejects_incomplete_or_invalid_options() {
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "render", "--out", "a.png"]).unwrap_err(),
+        "render requires --session-file"
+    );
+    assert_eq!(
+        Cli::try_parse_from([
+            "wayscriber",
+            "render",
+            "--session-file",
+            "s",
+            "--out",
+            "a.svg"
+        ])
+        .unwrap_err(),
+        "render requires --format png|pdf when --out has no .png/.pdf extension"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "render", "--size", "wide"]).unwrap_err(),
+        "invalid size 'wide' (expected WIDTHxHEIGHT)"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "render", "--size", "32768x1080"]).unwrap_err(),
+        "invalid size '32768x1080' (each side must be at most 32767)"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "render", "--size", "1920x4294967295"]).unwrap_err(),
+        "invalid size '1920x4294967295' (each side must be at most 32767)"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "render", "--page", "0"]).unwrap_err(),
+        "invalid page '0'"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "render", "--board"]).unwrap_err(),
+        "--board requires a value"
+    );
+    assert_eq!(
+        Cli::try_parse_from(["wayscriber", "render", "--active"]).unwrap_err(),
+        "unknown render option '--active'"
+    );
+}
@@ -91,6 +91,26 @@ pub const ENTRIES: &[ActionMeta] = &[
         true,
         false
     ),
+    meta!(
+        ToggleGrid,
+        "Toggle Board Grid",
+        Some("Grid"),
+        "Show or hide the grid on solid-color boards",
+        Board,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        ToggleSnapToGrid,
+        "Toggle Snap to Grid",
+        Some("Snap"),
+        "Snap shapes to the board grid while drawing, moving, and resizing",
+        Board,
+        true,
+        false,
+        false
+    ),
     meta!(
         Board1,
         "Board 1",
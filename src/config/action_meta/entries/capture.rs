@@ -158,6 +158,62 @@ pub const ENTRIES: &[ActionMeta] = &[
             "pdf"
         ]
     ),
+    meta!(
+        ExportCanvasSvgFile,
+        "Export Canvas to SVG",
+        Some("Canvas to SVG"),
+        "Save the visible canvas as an editable SVG",
+        Capture,
+        true,
+        true,
+        false,
+        &[
+            "export canvas svg",
+            "canvas svg",
+            "svg",
+            "vector",
+            "export svg"
+        ]
+    ),
+    meta!(
+        ExportBoardSvgFile,
+        "Export Board to SVG",
+        Some("Board to SVG"),
+        "Export active board pages as one SVG",
+        Capture,
+        true,
+        true,
+        false,
+        &["export board svg", "board svg", "svg", "vector"]
+    ),
+    meta!(
+        ExportReplayFrames,
+        "Export Replay Frames",
+        Some("Replay Frames"),
+        "Save the page replay as numbered PNG frames",
+        Capture,
+        true,
+        true,
+        false,
+        &[
+            "export replay",
+            "replay frames",
+            "png sequence",
+            "timelapse",
+            "animation"
+        ]
+    ),
+    meta!(
+        ToggleRecording,
+        "Toggle Recording",
+        Some("Record"),
+        "Start or stop recording the overlay to GIF/APNG",
+        Capture,
+        true,
+        true,
+        false,
+        &["record", "recording", "screencast", "gif", "apng", "video"]
+    ),
     meta!(
         OpenCaptureFolder,
         "Open Capture Folder",
@@ -91,4 +91,14 @@ pub const ENTRIES: &[ActionMeta] = &[
         true,
         true
     ),
+    meta!(
+        ToggleConnectorMode,
+        "Toggle Connector Mode",
+        Some("Connect"),
+        "Attach new lines and arrows to the shapes at their ends",
+        Drawing,
+        true,
+        true,
+        false
+    ),
 ];
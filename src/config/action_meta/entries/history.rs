@@ -11,6 +11,16 @@ pub const ENTRIES: &[ActionMeta] = &[
         true,
         true
     ),
+    meta!(
+        ClearActiveLayer,
+        "Clear Active Layer",
+        Some("Clear Layer"),
+        "Remove the drawings on the active layer only",
+        History,
+        true,
+        false,
+        false
+    ),
     meta!(
         Undo,
         "Undo",
@@ -71,6 +71,46 @@ pub const ENTRIES: &[ActionMeta] = &[
         false,
         false
     ),
+    meta!(
+        MoveSelectionToLayerAbove,
+        "Move Selection to Layer Above",
+        Some("Layer Up"),
+        "Move selected shapes onto the next layer up",
+        Selection,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        MoveSelectionToLayerBelow,
+        "Move Selection to Layer Below",
+        Some("Layer Down"),
+        "Move selected shapes onto the next layer down",
+        Selection,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        GroupSelection,
+        "Group Selection",
+        Some("Group"),
+        "Join selected shapes into a group that selects as one",
+        Selection,
+        true,
+        true,
+        false
+    ),
+    meta!(
+        UngroupSelection,
+        "Ungroup Selection",
+        Some("Ungroup"),
+        "Split selected groups back into individual shapes",
+        Selection,
+        true,
+        true,
+        false
+    ),
     meta!(
         NudgeSelectionUp,
         "Nudge Selection Up",
@@ -171,4 +211,84 @@ pub const ENTRIES: &[ActionMeta] = &[
         false,
         false
     ),
+    meta!(
+        AlignSelectionLeft,
+        "Align Selection Left",
+        Some("Align Left"),
+        "Line up selected shapes on their leftmost edge",
+        Selection,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        AlignSelectionCenter,
+        "Align Selection Center",
+        Some("Align Center"),
+        "Line up selected shapes on their horizontal centre",
+        Selection,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        AlignSelectionRight,
+        "Align Selection Right",
+        Some("Align Right"),
+        "Line up selected shapes on their rightmost edge",
+        Selection,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        AlignSelectionTop,
+        "Align Selection Top",
+        Some("Align Top"),
+        "Line up selected shapes on their topmost edge",
+        Selection,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        AlignSelectionMiddle,
+        "Align Selection Middle",
+        Some("Align Middle"),
+        "Line up selected shapes on their vertical middle",
+        Selection,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        AlignSelectionBottom,
+        "Align Selection Bottom",
+        Some("Align Bottom"),
+        "Line up selected shapes on their bottom edge",
+        Selection,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        DistributeSelectionHorizontally,
+        "Distribute Selection Horizontally",
+        Some("Distribute H"),
+        "Space selected shapes evenly from left to right",
+        Selection,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        DistributeSelectionVertically,
+        "Distribute Selection Vertically",
+        Some("Distribute V"),
+        "Space selected shapes evenly from top to bottom",
+        Selection,
+        true,
+        false,
+        false
+    ),
 ];
@@ -171,6 +171,27 @@ pub const ENTRIES: &[ActionMeta] = &[
         true,
         true
     ),
+    meta!(
+        SelectLaserTool,
+        "Laser Pointer",
+        Some("Laser"),
+        "Point with a bright dot and a fading trail",
+        Tools,
+        true,
+        true,
+        true,
+        icon: crate::toolbar_icons::draw_icon_laser
+    ),
+    meta!(
+        ToggleFadeInk,
+        "Toggle Fade Ink",
+        Some("Fade Ink"),
+        "Make new pen strokes vanish after a few seconds",
+        Tools,
+        true,
+        false,
+        false
+    ),
     meta!(
         SelectMarkerTool,
         "Marker Tool",
@@ -234,4 +255,14 @@ pub const ENTRIES: &[ActionMeta] = &[
         true,
         true
     ),
+    meta!(
+        CycleLineStyle,
+        "Cycle Line Style",
+        None,
+        "Solid, dashed, dotted, dash-dot lines",
+        Tools,
+        true,
+        true,
+        true
+    ),
 ];
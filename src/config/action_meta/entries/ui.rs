@@ -100,6 +100,94 @@ pub const ENTRIES: &[ActionMeta] = &[
         true,
         false
     ),
+    meta!(
+        ToggleReplay,
+        "Replay Page",
+        Some("Replay"),
+        "Replay the page in the order it was drawn",
+        UI,
+        true,
+        true,
+        false,
+        &["replay", "playback", "timelapse", "history", "animate"]
+    ),
+    meta!(
+        StartBreakTimer,
+        "Start Break Timer",
+        Some("Break Timer"),
+        "Count down a break on screen",
+        UI,
+        true,
+        true,
+        false,
+        &["timer", "countdown", "break", "pause", "coffee"]
+    ),
+    meta!(
+        StartTalkTimer,
+        "Start Talk Timer",
+        Some("Talk Timer"),
+        "Count down the time left for a talk",
+        UI,
+        true,
+        true,
+        false,
+        &["timer", "countdown", "presentation", "time left"]
+    ),
+    meta!(
+        StartStopwatch,
+        "Start Stopwatch",
+        Some("Stopwatch"),
+        "Count up from zero on screen",
+        UI,
+        true,
+        true,
+        false,
+        &["timer", "elapsed", "count up"]
+    ),
+    meta!(
+        ToggleTimerPause,
+        "Pause/Resume Timer",
+        Some("Pause Timer"),
+        "Pause or resume the running timer",
+        UI,
+        true,
+        true,
+        false,
+        &["timer", "stopwatch", "resume"]
+    ),
+    meta!(
+        ResetTimer,
+        "Reset Timer",
+        None,
+        "Stop the timer and hide it",
+        UI,
+        true,
+        true,
+        false,
+        &["timer", "stopwatch", "stop", "cancel"]
+    ),
+    meta!(
+        TimerAddMinute,
+        "Timer +1 Minute",
+        Some("+1 Minute"),
+        "Add a minute to the running countdown",
+        UI,
+        true,
+        false,
+        false,
+        &["timer", "extend", "more time"]
+    ),
+    meta!(
+        ToggleTimerDisplay,
+        "Timer Display",
+        None,
+        "Switch the timer between large digits and a compact pill",
+        UI,
+        true,
+        false,
+        false,
+        &["timer", "compact", "overlay", "big clock"]
+    ),
     meta!(
         ToggleLightMode,
         "Light Mode",
@@ -61,4 +61,35 @@ pub const ENTRIES: &[ActionMeta] = &[
         true,
         false
     ),
+    meta!(
+        ToggleMagnifier,
+        "Toggle Magnifier",
+        Some("Magnifier"),
+        "Show a magnifying lens that follows the pointer",
+        Zoom,
+        true,
+        true,
+        false,
+        icon: crate::toolbar_icons::draw_icon_magnifier
+    ),
+    meta!(
+        MagnifierZoomIn,
+        "Magnifier Zoom In",
+        None,
+        "Increase the magnifier lens magnification",
+        Zoom,
+        true,
+        false,
+        false
+    ),
+    meta!(
+        MagnifierZoomOut,
+        "Magnifier Zoom Out",
+        None,
+        "Decrease the magnifier lens magnification",
+        Zoom,
+        true,
+        false,
+        false
+    ),
 ];
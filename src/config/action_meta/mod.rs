@@ -165,23 +165,31 @@ pub fn action_meta(action: Action) -> Option<&'static ActionMeta> {
     action_meta_iter().find(|meta| meta.action == action)
 }
 
+/// The action's label in the UI language.
 pub fn action_label(action: Action) -> &'static str {
-    action_meta(action)
-        .map(|meta| meta.label)
-        .unwrap_or("Action")
+    crate::i18n::tr(english_label(action))
 }
 
 pub fn action_short_label(action: Action) -> &'static str {
-    action_meta(action)
-        .map(|meta| meta.short_label())
-        .unwrap_or("Action")
+    crate::i18n::tr(
+        action_meta(action)
+            .map(|meta| meta.short_label())
+            .unwrap_or("Action"),
+    )
 }
 
 pub fn action_display_label(action: Action) -> &'static str {
+    crate::i18n::tr(english_display_label(action))
+}
+
+/// The label with tool/mode/toggle wording trimmed, before translation.
+pub(crate) fn english_display_label(action: Action) -> &'static str {
     if matches!(action, Action::SelectEllipseTool) {
-        return action_short_label(action);
+        return action_meta(action)
+            .map(|meta| meta.short_label())
+            .unwrap_or("Action");
     }
-    let label = action_label(action);
+    let label = english_label(action);
     if let Some(stripped) = label.strip_suffix(" Tool") {
         return stripped;
     }
@@ -197,9 +205,15 @@ pub fn action_display_label(action: Action) -> &'static str {
 #[allow(dead_code)]
 pub fn action_description(action: Action) -> &'static str {
     action_meta(action)
-        .map(|meta| meta.description)
+        .map(|meta| crate::i18n::tr(meta.description))
         .unwrap_or("")
 }
 
+fn english_label(action: Action) -> &'static str {
+    action_meta(action)
+        .map(|meta| meta.label)
+        .unwrap_or("Action")
+}
+
 #[cfg(test)]
 mod tests;
@@ -49,6 +49,7 @@ const HELP_ACTIONS: &[Action] = &[
     Action::ToggleHighlightTool,
     Action::SelectMarkerTool,
     Action::SelectStepMarkerTool,
+    Action::SelectLaserTool,
     Action::SelectEraserTool,
     Action::IncreaseThickness,
     Action::DecreaseThickness,
@@ -57,17 +58,21 @@ const HELP_ACTIONS: &[Action] = &[
     Action::CopySelection,
     Action::PasteSelection,
     Action::DeleteSelection,
+    Action::GroupSelection,
+    Action::UngroupSelection,
     Action::ToggleSelectionProperties,
     Action::IncreaseFontSize,
     Action::DecreaseFontSize,
     Action::EnterTextMode,
     Action::EnterStickyNoteMode,
     Action::ToggleFill,
+    Action::ToggleConnectorMode,
     Action::ZoomIn,
     Action::ZoomOut,
     Action::ResetZoom,
     Action::ToggleZoomLock,
     Action::RefreshZoomCapture,
+    Action::ToggleMagnifier,
     Action::ClearCanvas,
     Action::Undo,
     Action::ToggleClickHighlight,
@@ -77,6 +82,12 @@ const HELP_ACTIONS: &[Action] = &[
     Action::ToggleHelp,
     Action::ToggleToolbar,
     Action::TogglePresenterMode,
+    Action::ToggleReplay,
+    Action::StartBreakTimer,
+    Action::StartTalkTimer,
+    Action::StartStopwatch,
+    Action::ToggleTimerPause,
+    Action::ResetTimer,
     Action::OpenConfigurator,
     Action::OpenAbout,
     Action::ClearSavedToolState,
@@ -89,6 +100,10 @@ const HELP_ACTIONS: &[Action] = &[
     Action::ExportCanvasClipboardAndFile,
     Action::ExportBoardPdfFile,
     Action::ExportAllBoardsPdfFile,
+    Action::ExportCanvasSvgFile,
+    Action::ExportBoardSvgFile,
+    Action::ExportReplayFrames,
+    Action::ToggleRecording,
     Action::CaptureClipboardSelection,
     Action::CaptureFileSelection,
     Action::CaptureActiveWindow,
@@ -107,6 +122,7 @@ const TOOLBAR_ACTIONS: &[Action] = &[
     Action::SelectMarkerTool,
     Action::SelectStepMarkerTool,
     Action::SelectHighlightTool,
+    Action::SelectLaserTool,
     Action::SelectEraserTool,
     Action::EnterTextMode,
     Action::EnterStickyNoteMode,
@@ -155,6 +171,7 @@ const EXPECTED_COMMAND_PALETTE_ACTIONS: &[Action] = &[
     Action::EnterTextMode,
     Action::EnterStickyNoteMode,
     Action::ClearCanvas,
+    Action::ClearActiveLayer,
     Action::Undo,
     Action::Redo,
     Action::SelectSelectionTool,
@@ -172,10 +189,13 @@ const EXPECTED_COMMAND_PALETTE_ACTIONS: &[Action] = &[
     Action::SelectHighlightTool,
     Action::SelectMarkerTool,
     Action::SelectStepMarkerTool,
+    Action::SelectLaserTool,
+    Action::ToggleFadeInk,
     Action::SelectEraserTool,
     Action::ToggleEraserMode,
     Action::SelectSpotlightTool,
     Action::CycleBlurStyle,
+    Action::CycleLineStyle,
     Action::IncreaseThickness,
     Action::DecreaseThickness,
     Action::IncreaseMarkerOpacity,
@@ -185,6 +205,7 @@ const EXPECTED_COMMAND_PALETTE_ACTIONS: &[Action] = &[
     Action::ResetArrowLabelCounter,
     Action::ResetStepMarkerCounter,
     Action::ToggleFill,
+    Action::ToggleConnectorMode,
     Action::ToggleWhiteboard,
     Action::ToggleBlackboard,
     Action::ReturnToTransparent,
@@ -194,6 +215,8 @@ const EXPECTED_COMMAND_PALETTE_ACTIONS: &[Action] = &[
     Action::PageDuplicate,
     Action::PageDelete,
     Action::PageRestoreDeleted,
+    Action::ToggleGrid,
+    Action::ToggleSnapToGrid,
     Action::Board1,
     Action::Board2,
     Action::Board3,
@@ -222,6 +245,14 @@ const EXPECTED_COMMAND_PALETTE_ACTIONS: &[Action] = &[
     Action::ToggleZoomChip,
     Action::ToggleFocusMode,
     Action::TogglePresenterMode,
+    Action::ToggleReplay,
+    Action::StartBreakTimer,
+    Action::StartTalkTimer,
+    Action::StartStopwatch,
+    Action::ToggleTimerPause,
+    Action::ResetTimer,
+    Action::TimerAddMinute,
+    Action::ToggleTimerDisplay,
     Action::ToggleLightMode,
     Action::ToggleLightModeDrawing,
     Action::RenderProfileNext,
@@ -258,6 +289,10 @@ const EXPECTED_COMMAND_PALETTE_ACTIONS: &[Action] = &[
     Action::ExportCanvasClipboardAndFile,
     Action::ExportBoardPdfFile,
     Action::ExportAllBoardsPdfFile,
+    Action::ExportCanvasSvgFile,
+    Action::ExportBoardSvgFile,
+    Action::ExportReplayFrames,
+    Action::ToggleRecording,
     Action::OpenCaptureFolder,
     Action::CopyTextFromScreen,
     Action::ToggleFrozenMode,
@@ -266,11 +301,26 @@ const EXPECTED_COMMAND_PALETTE_ACTIONS: &[Action] = &[
     Action::ResetZoom,
     Action::ToggleZoomLock,
     Action::RefreshZoomCapture,
+    Action::ToggleMagnifier,
+    Action::MagnifierZoomIn,
+    Action::MagnifierZoomOut,
     Action::SelectAll,
     Action::DeleteSelection,
     Action::DuplicateSelection,
     Action::CopySelection,
     Action::PasteSelection,
+    Action::GroupSelection,
+    Action::UngroupSelection,
+    Action::MoveSelectionToLayerAbove,
+    Action::MoveSelectionToLayerBelow,
+    Action::AlignSelectionLeft,
+    Action::AlignSelectionCenter,
+    Action::AlignSelectionRight,
+    Action::AlignSelectionTop,
+    Action::AlignSelectionMiddle,
+    Action::AlignSelectionBottom,
+    Action::DistributeSelectionHorizontally,
+    Action::DistributeSelectionVertically,
     Action::ApplyPreset1,
     Action::ApplyPreset2,
     Action::ApplyPreset3,
@@ -375,8 +425,10 @@ const EXPECTED_ICON_ACTIONS: &[Action] = &[
     Action::SelectSelectionTool,
     Action::SelectEraserTool,
     Action::SelectStepMarkerTool,
+    Action::SelectLaserTool,
     Action::EnterTextMode,
     Action::EnterStickyNoteMode,
+    Action::ToggleMagnifier,
 ];
 
 #[test]
@@ -405,6 +457,7 @@ fn icons_reuse_the_shared_semantic_tool_painters() {
         let expected = match action {
             Action::EnterTextMode => top_toolbar_icon_painter(TopToolbarIcon::Text),
             Action::EnterStickyNoteMode => top_toolbar_icon_painter(TopToolbarIcon::StickyNote),
+            Action::ToggleMagnifier => crate::toolbar_icons::draw_icon_magnifier,
             _ => {
                 let tool = Tool::from_select_action(*action)
                     .unwrap_or_else(|| panic!("{:?} should select a tool", action));
@@ -3,8 +3,9 @@ use super::keybindings::{Action, KeybindingAuthorship, KeybindingsConfig};
 use super::types::TabletInputConfig;
 use super::types::{
     ArrowConfig, BoardConfig, BoardsConfig, CaptureConfig, DrawingConfig, ExportConfig,
-    HistoryConfig, PerformanceConfig, PresenterModeConfig, PresetSlotsConfig, RenderProfilesConfig,
-    SessionConfig, SpotlightConfig, TrayConfig, UiConfig, UpdatesConfig,
+    HistoryConfig, LaserConfig, MagnifierConfig, PerformanceConfig, PresenterModeConfig,
+    PresetSlotsConfig, RenderProfilesConfig, SessionConfig, SpotlightConfig, TimerConfig,
+    TrayConfig, UiConfig, UpdatesConfig,
 };
 use serde::{Deserialize, Serialize};
 
@@ -100,6 +101,18 @@ pub struct Config {
     #[serde(default)]
     pub spotlight: SpotlightConfig,
 
+    /// Laser pointer and fade ink settings
+    #[serde(default)]
+    pub laser: LaserConfig,
+
+    /// Magnifier lens settings
+    #[serde(default)]
+    pub magnifier: MagnifierConfig,
+
+    /// Break timer, talk timer, and stopwatch settings
+    #[serde(default)]
+    pub timer: TimerConfig,
+
     /// Performance tuning options
     #[serde(default)]
     pub performance: PerformanceConfig,
@@ -164,6 +177,9 @@ impl Default for Config {
             history: HistoryConfig::default(),
             arrow: ArrowConfig::default(),
             spotlight: SpotlightConfig::default(),
+            laser: LaserConfig::default(),
+            magnifier: MagnifierConfig::default(),
+            timer: TimerConfig::default(),
             performance: PerformanceConfig::default(),
             ui: UiConfig::default(),
             tray: TrayConfig::default(),
@@ -225,6 +241,7 @@ impl Config {
                 show_pan_badge: boards.show_pan_badge,
                 persist_customizations: boards.persist_customizations,
                 default_board: boards.default_board.clone(),
+                grid: boards.grid.clone(),
                 ..BoardsConfig::default()
             },
             None => BoardsConfig::from_legacy(&self.board),
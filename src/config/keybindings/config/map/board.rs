@@ -18,6 +18,8 @@ impl KeybindingsConfig {
         inserter.insert_all(&self.board.page_new, Action::PageNew)?;
         inserter.insert_all(&self.board.page_duplicate, Action::PageDuplicate)?;
         inserter.insert_all(&self.board.page_delete, Action::PageDelete)?;
+        inserter.insert_all(&self.board.toggle_grid, Action::ToggleGrid)?;
+        inserter.insert_all(&self.board.toggle_snap_to_grid, Action::ToggleSnapToGrid)?;
         inserter.insert_all(&self.board.board_1, Action::Board1)?;
         inserter.insert_all(&self.board.board_2, Action::Board2)?;
         inserter.insert_all(&self.board.board_3, Action::Board3)?;
@@ -48,6 +48,19 @@ impl KeybindingsConfig {
             &self.capture.export_all_boards_pdf_file,
             Action::ExportAllBoardsPdfFile,
         )?;
+        inserter.insert_all(
+            &self.capture.export_canvas_svg_file,
+            Action::ExportCanvasSvgFile,
+        )?;
+        inserter.insert_all(
+            &self.capture.export_board_svg_file,
+            Action::ExportBoardSvgFile,
+        )?;
+        inserter.insert_all(
+            &self.capture.export_replay_frames,
+            Action::ExportReplayFrames,
+        )?;
+        inserter.insert_all(&self.capture.toggle_recording, Action::ToggleRecording)?;
         inserter.insert_all(&self.capture.open_capture_folder, Action::OpenCaptureFolder)?;
         inserter.insert_all(
             &self.capture.copy_text_from_screen,
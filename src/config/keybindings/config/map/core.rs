@@ -14,6 +14,7 @@ impl KeybindingsConfig {
             Action::EnterStickyNoteMode,
         )?;
         inserter.insert_all(&self.core.clear_canvas, Action::ClearCanvas)?;
+        inserter.insert_all(&self.core.clear_active_layer, Action::ClearActiveLayer)?;
         inserter.insert_all(&self.core.undo, Action::Undo)?;
         inserter.insert_all(&self.core.redo, Action::Redo)?;
         inserter.insert_all(&self.core.undo_all, Action::UndoAll)?;
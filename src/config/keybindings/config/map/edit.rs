@@ -66,6 +66,7 @@ define_action_binding_accessors! {
     EnterTextMode => core.enter_text_mode,
     EnterStickyNoteMode => core.enter_sticky_note_mode,
     ClearCanvas => core.clear_canvas,
+    ClearActiveLayer => core.clear_active_layer,
     Undo => core.undo,
     Redo => core.redo,
     UndoAll => core.undo_all,
@@ -78,6 +79,10 @@ define_action_binding_accessors! {
     SelectAll => selection.select_all,
     MoveSelectionToFront => selection.move_selection_to_front,
     MoveSelectionToBack => selection.move_selection_to_back,
+    MoveSelectionToLayerAbove => selection.move_selection_to_layer_above,
+    MoveSelectionToLayerBelow => selection.move_selection_to_layer_below,
+    GroupSelection => selection.group_selection,
+    UngroupSelection => selection.ungroup_selection,
     NudgeSelectionUp => selection.nudge_selection_up,
     NudgeSelectionDown => selection.nudge_selection_down,
     NudgeSelectionLeft => selection.nudge_selection_left,
@@ -88,6 +93,14 @@ define_action_binding_accessors! {
     MoveSelectionToEnd => selection.move_selection_to_end,
     MoveSelectionToTop => selection.move_selection_to_top,
     MoveSelectionToBottom => selection.move_selection_to_bottom,
+    AlignSelectionLeft => selection.align_selection_left,
+    AlignSelectionCenter => selection.align_selection_center,
+    AlignSelectionRight => selection.align_selection_right,
+    AlignSelectionTop => selection.align_selection_top,
+    AlignSelectionMiddle => selection.align_selection_middle,
+    AlignSelectionBottom => selection.align_selection_bottom,
+    DistributeSelectionHorizontally => selection.distribute_selection_horizontally,
+    DistributeSelectionVertically => selection.distribute_selection_vertically,
     DeleteSelection => selection.delete_selection,
     IncreaseThickness => tools.increase_thickness,
     DecreaseThickness => tools.decrease_thickness,
@@ -99,6 +112,7 @@ define_action_binding_accessors! {
     SelectEraserTool => tools.select_eraser_tool,
     ToggleEraserMode => tools.toggle_eraser_mode,
     CycleBlurStyle => tools.cycle_blur_style,
+    CycleLineStyle => tools.cycle_line_style,
     SelectPenTool => tools.select_pen_tool,
     SelectLineTool => tools.select_line_tool,
     SelectRectTool => tools.select_rect_tool,
@@ -113,6 +127,8 @@ define_action_binding_accessors! {
     SelectSpotlightTool => tools.select_spotlight_tool,
     SelectHighlightTool => tools.select_highlight_tool,
     ToggleHighlightTool => tools.toggle_highlight_tool,
+    SelectLaserTool => tools.select_laser_tool,
+    ToggleFadeInk => tools.toggle_fade_ink,
     IncreaseFontSize => tools.increase_font_size,
     DecreaseFontSize => tools.decrease_font_size,
     ResetArrowLabelCounter => tools.reset_arrow_labels,
@@ -142,6 +158,8 @@ define_action_binding_accessors! {
     PageNew => board.page_new,
     PageDuplicate => board.page_duplicate,
     PageDelete => board.page_delete,
+    ToggleGrid => board.toggle_grid,
+    ToggleSnapToGrid => board.toggle_snap_to_grid,
     ToggleHelp => ui.toggle_help,
     ToggleQuickHelp => ui.toggle_quick_help,
     ToggleStatusBar => ui.toggle_status_bar,
@@ -153,12 +171,21 @@ define_action_binding_accessors! {
     ToggleToolbar => ui.toggle_toolbar,
     CycleToolbarDisplay => ui.cycle_toolbar_display,
     TogglePresenterMode => ui.toggle_presenter_mode,
+    ToggleReplay => ui.toggle_replay,
+    StartBreakTimer => ui.start_break_timer,
+    StartTalkTimer => ui.start_talk_timer,
+    StartStopwatch => ui.start_stopwatch,
+    ToggleTimerPause => ui.toggle_timer_pause,
+    ResetTimer => ui.reset_timer,
+    TimerAddMinute => ui.timer_add_minute,
+    ToggleTimerDisplay => ui.toggle_timer_display,
     ToggleLightMode => ui.toggle_light_mode,
     ToggleLightModeDrawing => ui.toggle_light_mode_drawing,
     RenderProfileNext => ui.render_profile_next,
     RenderProfilePrevious => ui.render_profile_previous,
     RenderProfileOff => ui.render_profile_off,
     ToggleFill => ui.toggle_fill,
+    ToggleConnectorMode => ui.toggle_connector_mode,
     ToggleRadialMenu => ui.toggle_radial_menu,
     ToggleSelectionProperties => ui.toggle_selection_properties,
     OpenContextMenu => ui.open_context_menu,
@@ -188,6 +215,10 @@ define_action_binding_accessors! {
     ExportCanvasClipboardAndFile => capture.export_canvas_clipboard_and_file,
     ExportBoardPdfFile => capture.export_board_pdf_file,
     ExportAllBoardsPdfFile => capture.export_all_boards_pdf_file,
+    ExportCanvasSvgFile => capture.export_canvas_svg_file,
+    ExportBoardSvgFile => capture.export_board_svg_file,
+    ExportReplayFrames => capture.export_replay_frames,
+    ToggleRecording => capture.toggle_recording,
     OpenCaptureFolder => capture.open_capture_folder,
     CopyTextFromScreen => capture.copy_text_from_screen,
     ToggleFrozenMode => zoom.toggle_frozen_mode,
@@ -196,6 +227,9 @@ define_action_binding_accessors! {
     ResetZoom => zoom.reset_zoom,
     ToggleZoomLock => zoom.toggle_zoom_lock,
     RefreshZoomCapture => zoom.refresh_zoom_capture,
+    ToggleMagnifier => zoom.toggle_magnifier,
+    MagnifierZoomIn => zoom.magnifier_zoom_in,
+    MagnifierZoomOut => zoom.magnifier_zoom_out,
     ApplyPreset1 => presets.apply_preset_1,
     ApplyPreset2 => presets.apply_preset_2,
     ApplyPreset3 => presets.apply_preset_3,
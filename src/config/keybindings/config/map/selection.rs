@@ -22,6 +22,16 @@ impl KeybindingsConfig {
             &self.selection.move_selection_to_back,
             Action::MoveSelectionToBack,
         )?;
+        inserter.insert_all(
+            &self.selection.move_selection_to_layer_above,
+            Action::MoveSelectionToLayerAbove,
+        )?;
+        inserter.insert_all(
+            &self.selection.move_selection_to_layer_below,
+            Action::MoveSelectionToLayerBelow,
+        )?;
+        inserter.insert_all(&self.selection.group_selection, Action::GroupSelection)?;
+        inserter.insert_all(&self.selection.ungroup_selection, Action::UngroupSelection)?;
         inserter.insert_all(&self.selection.nudge_selection_up, Action::NudgeSelectionUp)?;
         inserter.insert_all(
             &self.selection.nudge_selection_down,
@@ -59,6 +69,38 @@ impl KeybindingsConfig {
             &self.selection.move_selection_to_bottom,
             Action::MoveSelectionToBottom,
         )?;
+        inserter.insert_all(
+            &self.selection.align_selection_left,
+            Action::AlignSelectionLeft,
+        )?;
+        inserter.insert_all(
+            &self.selection.align_selection_center,
+            Action::AlignSelectionCenter,
+        )?;
+        inserter.insert_all(
+            &self.selection.align_selection_right,
+            Action::AlignSelectionRight,
+        )?;
+        inserter.insert_all(
+            &self.selection.align_selection_top,
+            Action::AlignSelectionTop,
+        )?;
+        inserter.insert_all(
+            &self.selection.align_selection_middle,
+            Action::AlignSelectionMiddle,
+        )?;
+        inserter.insert_all(
+            &self.selection.align_selection_bottom,
+            Action::AlignSelectionBottom,
+        )?;
+        inserter.insert_all(
+            &self.selection.distribute_selection_horizontally,
+            Action::DistributeSelectionHorizontally,
+        )?;
+        inserter.insert_all(
+            &self.selection.distribute_selection_vertically,
+            Action::DistributeSelectionVertically,
+        )?;
         inserter.insert_all(&self.selection.delete_selection, Action::DeleteSelection)?;
         Ok(())
     }
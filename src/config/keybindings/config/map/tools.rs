@@ -29,6 +29,7 @@ impl KeybindingsConfig {
         inserter.insert_all(&self.tools.select_eraser_tool, Action::SelectEraserTool)?;
         inserter.insert_all(&self.tools.toggle_eraser_mode, Action::ToggleEraserMode)?;
         inserter.insert_all(&self.tools.cycle_blur_style, Action::CycleBlurStyle)?;
+        inserter.insert_all(&self.tools.cycle_line_style, Action::CycleLineStyle)?;
         inserter.insert_all(&self.tools.select_pen_tool, Action::SelectPenTool)?;
         inserter.insert_all(&self.tools.select_line_tool, Action::SelectLineTool)?;
         inserter.insert_all(&self.tools.select_rect_tool, Action::SelectRectTool)?;
@@ -61,6 +62,8 @@ impl KeybindingsConfig {
             &self.tools.toggle_highlight_tool,
             Action::ToggleHighlightTool,
         )?;
+        inserter.insert_all(&self.tools.select_laser_tool, Action::SelectLaserTool)?;
+        inserter.insert_all(&self.tools.toggle_fade_ink, Action::ToggleFadeInk)?;
         inserter.insert_all(&self.tools.increase_font_size, Action::IncreaseFontSize)?;
         inserter.insert_all(&self.tools.decrease_font_size, Action::DecreaseFontSize)?;
         inserter.insert_all(
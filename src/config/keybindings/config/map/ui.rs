@@ -18,6 +18,14 @@ impl KeybindingsConfig {
         inserter.insert_all(&self.ui.toggle_toolbar, Action::ToggleToolbar)?;
         inserter.insert_all(&self.ui.cycle_toolbar_display, Action::CycleToolbarDisplay)?;
         inserter.insert_all(&self.ui.toggle_presenter_mode, Action::TogglePresenterMode)?;
+        inserter.insert_all(&self.ui.toggle_replay, Action::ToggleReplay)?;
+        inserter.insert_all(&self.ui.start_break_timer, Action::StartBreakTimer)?;
+        inserter.insert_all(&self.ui.start_talk_timer, Action::StartTalkTimer)?;
+        inserter.insert_all(&self.ui.start_stopwatch, Action::StartStopwatch)?;
+        inserter.insert_all(&self.ui.toggle_timer_pause, Action::ToggleTimerPause)?;
+        inserter.insert_all(&self.ui.reset_timer, Action::ResetTimer)?;
+        inserter.insert_all(&self.ui.timer_add_minute, Action::TimerAddMinute)?;
+        inserter.insert_all(&self.ui.toggle_timer_display, Action::ToggleTimerDisplay)?;
         inserter.insert_all(&self.ui.toggle_light_mode, Action::ToggleLightMode)?;
         inserter.insert_all(
             &self.ui.toggle_light_mode_drawing,
@@ -30,6 +38,7 @@ impl KeybindingsConfig {
         )?;
         inserter.insert_all(&self.ui.render_profile_off, Action::RenderProfileOff)?;
         inserter.insert_all(&self.ui.toggle_fill, Action::ToggleFill)?;
+        inserter.insert_all(&self.ui.toggle_connector_mode, Action::ToggleConnectorMode)?;
         inserter.insert_all(&self.ui.toggle_radial_menu, Action::ToggleRadialMenu)?;
         inserter.insert_all(
             &self.ui.toggle_selection_properties,
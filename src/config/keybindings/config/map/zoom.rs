@@ -13,6 +13,9 @@ impl KeybindingsConfig {
         inserter.insert_all(&self.zoom.reset_zoom, Action::ResetZoom)?;
         inserter.insert_all(&self.zoom.toggle_zoom_lock, Action::ToggleZoomLock)?;
         inserter.insert_all(&self.zoom.refresh_zoom_capture, Action::RefreshZoomCapture)?;
+        inserter.insert_all(&self.zoom.toggle_magnifier, Action::ToggleMagnifier)?;
+        inserter.insert_all(&self.zoom.magnifier_zoom_in, Action::MagnifierZoomIn)?;
+        inserter.insert_all(&self.zoom.magnifier_zoom_out, Action::MagnifierZoomOut)?;
         Ok(())
     }
 }
@@ -29,6 +29,12 @@ pub struct BoardKeybindingsConfig {
     #[serde(default = "default_page_delete")]
     pub page_delete: Vec<String>,
 
+    #[serde(default)]
+    pub toggle_grid: Vec<String>,
+
+    #[serde(default)]
+    pub toggle_snap_to_grid: Vec<String>,
+
     #[serde(default = "default_board_1")]
     pub board_1: Vec<String>,
 
@@ -92,6 +98,8 @@ impl Default for BoardKeybindingsConfig {
             page_new: default_page_new(),
             page_duplicate: default_page_duplicate(),
             page_delete: default_page_delete(),
+            toggle_grid: Vec::new(),
+            toggle_snap_to_grid: Vec::new(),
             board_1: default_board_1(),
             board_2: default_board_2(),
             board_3: default_board_3(),
@@ -47,6 +47,18 @@ pub struct CaptureKeybindingsConfig {
     #[serde(default = "default_export_all_boards_pdf_file")]
     pub export_all_boards_pdf_file: Vec<String>,
 
+    #[serde(default = "default_export_canvas_svg_file")]
+    pub export_canvas_svg_file: Vec<String>,
+
+    #[serde(default = "default_export_board_svg_file")]
+    pub export_board_svg_file: Vec<String>,
+
+    #[serde(default = "default_export_replay_frames")]
+    pub export_replay_frames: Vec<String>,
+
+    #[serde(default = "default_toggle_recording")]
+    pub toggle_recording: Vec<String>,
+
     #[serde(default = "default_open_capture_folder")]
     pub open_capture_folder: Vec<String>,
 
@@ -73,6 +85,10 @@ impl Default for CaptureKeybindingsConfig {
             export_canvas_clipboard_and_file: default_export_canvas_clipboard_and_file(),
             export_board_pdf_file: default_export_board_pdf_file(),
             export_all_boards_pdf_file: default_export_all_boards_pdf_file(),
+            export_canvas_svg_file: default_export_canvas_svg_file(),
+            export_board_svg_file: default_export_board_svg_file(),
+            export_replay_frames: default_export_replay_frames(),
+            toggle_recording: default_toggle_recording(),
             open_capture_folder: default_open_capture_folder(),
             copy_text_from_screen: default_copy_text_from_screen(),
         }
@@ -17,6 +17,9 @@ pub struct CoreKeybindingsConfig {
     #[serde(default = "default_clear_canvas")]
     pub clear_canvas: Vec<String>,
 
+    #[serde(default)]
+    pub clear_active_layer: Vec<String>,
+
     #[serde(default = "default_undo")]
     pub undo: Vec<String>,
 
@@ -43,6 +46,7 @@ impl Default for CoreKeybindingsConfig {
             enter_text_mode: default_enter_text_mode(),
             enter_sticky_note_mode: default_enter_sticky_note_mode(),
             clear_canvas: default_clear_canvas(),
+            clear_active_layer: Vec::new(),
             undo: default_undo(),
             redo: default_redo(),
             undo_all: Vec::new(),
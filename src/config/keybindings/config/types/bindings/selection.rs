@@ -23,6 +23,18 @@ pub struct SelectionKeybindingsConfig {
     #[serde(default = "default_move_selection_to_back")]
     pub move_selection_to_back: Vec<String>,
 
+    #[serde(default)]
+    pub move_selection_to_layer_above: Vec<String>,
+
+    #[serde(default)]
+    pub move_selection_to_layer_below: Vec<String>,
+
+    #[serde(default = "default_group_selection")]
+    pub group_selection: Vec<String>,
+
+    #[serde(default = "default_ungroup_selection")]
+    pub ungroup_selection: Vec<String>,
+
     #[serde(default = "default_nudge_selection_up")]
     pub nudge_selection_up: Vec<String>,
 
@@ -53,6 +65,30 @@ pub struct SelectionKeybindingsConfig {
     #[serde(default = "default_move_selection_to_bottom")]
     pub move_selection_to_bottom: Vec<String>,
 
+    #[serde(default)]
+    pub align_selection_left: Vec<String>,
+
+    #[serde(default)]
+    pub align_selection_center: Vec<String>,
+
+    #[serde(default)]
+    pub align_selection_right: Vec<String>,
+
+    #[serde(default)]
+    pub align_selection_top: Vec<String>,
+
+    #[serde(default)]
+    pub align_selection_middle: Vec<String>,
+
+    #[serde(default)]
+    pub align_selection_bottom: Vec<String>,
+
+    #[serde(default)]
+    pub distribute_selection_horizontally: Vec<String>,
+
+    #[serde(default)]
+    pub distribute_selection_vertically: Vec<String>,
+
     #[serde(default = "default_delete_selection")]
     pub delete_selection: Vec<String>,
 }
@@ -66,6 +102,10 @@ impl Default for SelectionKeybindingsConfig {
             select_all: default_select_all(),
             move_selection_to_front: default_move_selection_to_front(),
             move_selection_to_back: default_move_selection_to_back(),
+            move_selection_to_layer_above: Vec::new(),
+            move_selection_to_layer_below: Vec::new(),
+            group_selection: default_group_selection(),
+            ungroup_selection: default_ungroup_selection(),
             nudge_selection_up: default_nudge_selection_up(),
             nudge_selection_down: default_nudge_selection_down(),
             nudge_selection_left: default_nudge_selection_left(),
@@ -76,6 +116,14 @@ impl Default for SelectionKeybindingsConfig {
             move_selection_to_end: default_move_selection_to_end(),
             move_selection_to_top: default_move_selection_to_top(),
             move_selection_to_bottom: default_move_selection_to_bottom(),
+            align_selection_left: Vec::new(),
+            align_selection_center: Vec::new(),
+            align_selection_right: Vec::new(),
+            align_selection_top: Vec::new(),
+            align_selection_middle: Vec::new(),
+            align_selection_bottom: Vec::new(),
+            distribute_selection_horizontally: Vec::new(),
+            distribute_selection_vertically: Vec::new(),
             delete_selection: default_delete_selection(),
         }
     }
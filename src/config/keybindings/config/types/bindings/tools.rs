@@ -35,6 +35,9 @@ pub struct ToolKeybindingsConfig {
     #[serde(default = "default_cycle_blur_style")]
     pub cycle_blur_style: Vec<String>,
 
+    #[serde(default = "default_cycle_line_style")]
+    pub cycle_line_style: Vec<String>,
+
     #[serde(default = "default_select_pen_tool")]
     pub select_pen_tool: Vec<String>,
 
@@ -77,6 +80,12 @@ pub struct ToolKeybindingsConfig {
     #[serde(default = "default_toggle_highlight_tool")]
     pub toggle_highlight_tool: Vec<String>,
 
+    #[serde(default = "default_select_laser_tool")]
+    pub select_laser_tool: Vec<String>,
+
+    #[serde(default = "default_toggle_fade_ink")]
+    pub toggle_fade_ink: Vec<String>,
+
     #[serde(default = "default_increase_font_size")]
     pub increase_font_size: Vec<String>,
 
@@ -103,6 +112,7 @@ impl Default for ToolKeybindingsConfig {
             select_eraser_tool: default_select_eraser_tool(),
             toggle_eraser_mode: default_toggle_eraser_mode(),
             cycle_blur_style: default_cycle_blur_style(),
+            cycle_line_style: default_cycle_line_style(),
             select_pen_tool: default_select_pen_tool(),
             select_line_tool: default_select_line_tool(),
             select_rect_tool: default_select_rect_tool(),
@@ -117,6 +127,8 @@ impl Default for ToolKeybindingsConfig {
             select_spotlight_tool: default_select_spotlight_tool(),
             select_highlight_tool: default_select_highlight_tool(),
             toggle_highlight_tool: default_toggle_highlight_tool(),
+            select_laser_tool: default_select_laser_tool(),
+            toggle_fade_ink: default_toggle_fade_ink(),
             increase_font_size: default_increase_font_size(),
             decrease_font_size: default_decrease_font_size(),
             reset_arrow_labels: default_reset_arrow_labels(),
@@ -38,6 +38,30 @@ pub struct UiKeybindingsConfig {
     #[serde(default = "default_toggle_presenter_mode")]
     pub toggle_presenter_mode: Vec<String>,
 
+    #[serde(default = "default_toggle_replay")]
+    pub toggle_replay: Vec<String>,
+
+    #[serde(default = "default_start_break_timer")]
+    pub start_break_timer: Vec<String>,
+
+    #[serde(default = "default_start_talk_timer")]
+    pub start_talk_timer: Vec<String>,
+
+    #[serde(default = "default_start_stopwatch")]
+    pub start_stopwatch: Vec<String>,
+
+    #[serde(default = "default_toggle_timer_pause")]
+    pub toggle_timer_pause: Vec<String>,
+
+    #[serde(default = "default_reset_timer")]
+    pub reset_timer: Vec<String>,
+
+    #[serde(default = "default_timer_add_minute")]
+    pub timer_add_minute: Vec<String>,
+
+    #[serde(default = "default_toggle_timer_display")]
+    pub toggle_timer_display: Vec<String>,
+
     #[serde(default = "default_toggle_light_mode")]
     pub toggle_light_mode: Vec<String>,
 
@@ -56,6 +80,9 @@ pub struct UiKeybindingsConfig {
     #[serde(default = "default_toggle_fill")]
     pub toggle_fill: Vec<String>,
 
+    #[serde(default = "default_toggle_connector_mode")]
+    pub toggle_connector_mode: Vec<String>,
+
     #[serde(default = "default_toggle_radial_menu")]
     pub toggle_radial_menu: Vec<String>,
 
@@ -89,12 +116,21 @@ impl Default for UiKeybindingsConfig {
             toggle_toolbar: default_toggle_toolbar(),
             cycle_toolbar_display: default_cycle_toolbar_display(),
             toggle_presenter_mode: default_toggle_presenter_mode(),
+            toggle_replay: default_toggle_replay(),
+            start_break_timer: default_start_break_timer(),
+            start_talk_timer: default_start_talk_timer(),
+            start_stopwatch: default_start_stopwatch(),
+            toggle_timer_pause: default_toggle_timer_pause(),
+            reset_timer: default_reset_timer(),
+            timer_add_minute: default_timer_add_minute(),
+            toggle_timer_display: default_toggle_timer_display(),
             toggle_light_mode: default_toggle_light_mode(),
             toggle_light_mode_drawing: default_toggle_light_mode_drawing(),
             render_profile_next: default_render_profile_next(),
             render_profile_previous: default_render_profile_previous(),
             render_profile_off: default_render_profile_off(),
             toggle_fill: default_toggle_fill(),
+            toggle_connector_mode: default_toggle_connector_mode(),
             toggle_radial_menu: default_toggle_radial_menu(),
             toggle_selection_properties: default_toggle_selection_properties(),
             open_context_menu: default_open_context_menu(),
@@ -22,6 +22,15 @@ pub struct ZoomKeybindingsConfig {
 
     #[serde(default = "default_refresh_zoom_capture")]
     pub refresh_zoom_capture: Vec<String>,
+
+    #[serde(default = "default_toggle_magnifier")]
+    pub toggle_magnifier: Vec<String>,
+
+    #[serde(default = "default_magnifier_zoom_in")]
+    pub magnifier_zoom_in: Vec<String>,
+
+    #[serde(default = "default_magnifier_zoom_out")]
+    pub magnifier_zoom_out: Vec<String>,
 }
 
 impl Default for ZoomKeybindingsConfig {
@@ -33,6 +42,9 @@ impl Default for ZoomKeybindingsConfig {
             reset_zoom: default_reset_zoom(),
             toggle_zoom_lock: default_toggle_zoom_lock(),
             refresh_zoom_capture: default_refresh_zoom_capture(),
+            toggle_magnifier: default_toggle_magnifier(),
+            magnifier_zoom_in: default_magnifier_zoom_in(),
+            magnifier_zoom_out: default_magnifier_zoom_out(),
         }
     }
 }
@@ -54,6 +54,22 @@ pub(crate) fn default_export_all_boards_pdf_file() -> Vec<String> {
     Vec::new()
 }
 
+pub(crate) fn default_export_canvas_svg_file() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_export_board_svg_file() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_export_replay_frames() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_toggle_recording() -> Vec<String> {
+    Vec::new()
+}
+
 pub(crate) fn default_open_capture_folder() -> Vec<String> {
     vec!["Ctrl+Alt+O".to_string()]
 }
@@ -22,6 +22,14 @@ pub(crate) fn default_move_selection_to_back() -> Vec<String> {
     vec!["[".to_string()]
 }
 
+pub(crate) fn default_group_selection() -> Vec<String> {
+    vec!["Ctrl+G".to_string()]
+}
+
+pub(crate) fn default_ungroup_selection() -> Vec<String> {
+    vec!["Ctrl+Shift+G".to_string()]
+}
+
 pub(crate) fn default_nudge_selection_up() -> Vec<String> {
     vec!["ArrowUp".to_string()]
 }
@@ -40,6 +40,10 @@ pub(crate) fn default_cycle_blur_style() -> Vec<String> {
     Vec::new()
 }
 
+pub(crate) fn default_cycle_line_style() -> Vec<String> {
+    Vec::new()
+}
+
 pub(crate) fn default_select_pen_tool() -> Vec<String> {
     vec!["F".to_string()]
 }
@@ -97,6 +101,14 @@ pub(crate) fn default_toggle_highlight_tool() -> Vec<String> {
     vec!["Ctrl+Alt+H".to_string()]
 }
 
+pub(crate) fn default_select_laser_tool() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_toggle_fade_ink() -> Vec<String> {
+    Vec::new()
+}
+
 pub(crate) fn default_increase_font_size() -> Vec<String> {
     vec!["Ctrl+Shift++".to_string(), "Ctrl+Shift+=".to_string()]
 }
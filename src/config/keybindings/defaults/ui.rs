@@ -45,6 +45,40 @@ pub(crate) fn default_toggle_presenter_mode() -> Vec<String> {
     vec!["Ctrl+Shift+M".to_string()]
 }
 
+pub(crate) fn default_toggle_replay() -> Vec<String> {
+    Vec::new()
+}
+
+/// The timer actions are unbound by default; they are one palette search or
+/// tray click away, and `--daemon-action` covers a global shortcut.
+pub(crate) fn default_start_break_timer() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_start_talk_timer() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_start_stopwatch() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_toggle_timer_pause() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_reset_timer() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_timer_add_minute() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_toggle_timer_display() -> Vec<String> {
+    Vec::new()
+}
+
 pub(crate) fn default_toggle_light_mode() -> Vec<String> {
     vec!["F6".to_string()]
 }
@@ -69,6 +103,10 @@ pub(crate) fn default_toggle_fill() -> Vec<String> {
     Vec::new()
 }
 
+pub(crate) fn default_toggle_connector_mode() -> Vec<String> {
+    Vec::new()
+}
+
 pub(crate) fn default_toggle_radial_menu() -> Vec<String> {
     Vec::new()
 }
@@ -21,3 +21,15 @@ pub(crate) fn default_toggle_zoom_lock() -> Vec<String> {
 pub(crate) fn default_refresh_zoom_capture() -> Vec<String> {
     vec!["Ctrl+Alt+R".to_string()]
 }
+
+pub(crate) fn default_toggle_magnifier() -> Vec<String> {
+    vec!["Ctrl+Alt+M".to_string()]
+}
+
+pub(crate) fn default_magnifier_zoom_in() -> Vec<String> {
+    Vec::new()
+}
+
+pub(crate) fn default_magnifier_zoom_out() -> Vec<String> {
+    Vec::new()
+}
@@ -435,6 +435,8 @@ fn build_action_map_includes_canvas_export_bindings() {
     config.capture.export_canvas_clipboard_and_file = vec!["Ctrl+Alt+Shift+B".to_string()];
     config.capture.export_board_pdf_file = vec!["Ctrl+Alt+Shift+P".to_string()];
     config.capture.export_all_boards_pdf_file = vec!["Ctrl+Alt+Shift+A".to_string()];
+    config.capture.export_canvas_svg_file = vec!["Ctrl+Alt+Shift+V".to_string()];
+    config.capture.export_board_svg_file = vec!["Ctrl+Alt+Shift+G".to_string()];
 
     let map = config.build_action_map().unwrap();
 
@@ -458,6 +460,14 @@ fn build_action_map_includes_canvas_export_bindings() {
         map.get(&Shortcut::parse("Ctrl+Alt+Shift+A").unwrap()),
         Some(&Action::ExportAllBoardsPdfFile)
     );
+    assert_eq!(
+        map.get(&Shortcut::parse("Ctrl+Alt+Shift+V").unwrap()),
+        Some(&Action::ExportCanvasSvgFile)
+    );
+    assert_eq!(
+        map.get(&Shortcut::parse("Ctrl+Alt+Shift+G").unwrap()),
+        Some(&Action::ExportBoardSvgFile)
+    );
 }
 
 #[test]
@@ -542,6 +552,18 @@ fn canvas_export_actions_deserialize_from_config_names() {
             .action,
         Action::ExportAllBoardsPdfFile
     );
+    assert_eq!(
+        toml::from_str::<ActionFixture>("action = \"export_canvas_svg_file\"")
+            .unwrap()
+            .action,
+        Action::ExportCanvasSvgFile
+    );
+    assert_eq!(
+        toml::from_str::<ActionFixture>("action = \"export_board_svg_file\"")
+            .unwrap()
+            .action,
+        Action::ExportBoardSvgFile
+    );
 }
 
 /// The shipped defaults must never contend with each other. Omitted actions
@@ -659,6 +681,7 @@ const DEFAULT_BINDING_SNAPSHOT: &[(&str, &[&str])] = &[
     ("enter_text_mode", &["T"]),
     ("enter_sticky_note_mode", &["N"]),
     ("clear_canvas", &["E"]),
+    ("clear_active_layer", &[]),
     ("undo", &["Ctrl+Z"]),
     ("redo", &["Ctrl+Shift+Z", "Ctrl+Y"]),
     ("undo_all", &[]),
@@ -671,6 +694,10 @@ const DEFAULT_BINDING_SNAPSHOT: &[(&str, &[&str])] = &[
     ("select_all", &["Ctrl+A"]),
     ("move_selection_to_front", &["]"]),
     ("move_selection_to_back", &["["]),
+    ("move_selection_to_layer_above", &[]),
+    ("move_selection_to_layer_below", &[]),
+    ("group_selection", &["Ctrl+G"]),
+    ("ungroup_selection", &["Ctrl+Shift+G"]),
     ("nudge_selection_up", &["ArrowUp"]),
     ("nudge_selection_down", &["ArrowDown"]),
     ("nudge_selection_left", &["ArrowLeft", "Shift+PageUp"]),
@@ -681,6 +708,14 @@ const DEFAULT_BINDING_SNAPSHOT: &[(&str, &[&str])] = &[
     ("move_selection_to_end", &["End"]),
     ("move_selection_to_top", &["Ctrl+Home"]),
     ("move_selection_to_bottom", &["Ctrl+End"]),
+    ("align_selection_left", &[]),
+    ("align_selection_center", &[]),
+    ("align_selection_right", &[]),
+    ("align_selection_top", &[]),
+    ("align_selection_middle", &[]),
+    ("align_selection_bottom", &[]),
+    ("distribute_selection_horizontally", &[]),
+    ("distribute_selection_vertically", &[]),
     ("delete_selection", &["Delete"]),
     ("increase_thickness", &["+", "="]),
     ("decrease_thickness", &["-", "_"]),
@@ -692,6 +727,7 @@ const DEFAULT_BINDING_SNAPSHOT: &[(&str, &[&str])] = &[
     ("select_eraser_tool", &["D"]),
     ("toggle_eraser_mode", &["Ctrl+Shift+E"]),
     ("cycle_blur_style", &[]),
+    ("cycle_line_style", &[]),
     ("select_pen_tool", &["F"]),
     ("select_line_tool", &[]),
     ("select_rect_tool", &[]),
@@ -706,6 +742,8 @@ const DEFAULT_BINDING_SNAPSHOT: &[(&str, &[&str])] = &[
     ("select_spotlight_tool", &[]),
     ("select_highlight_tool", &[]),
     ("toggle_highlight_tool", &["Ctrl+Alt+H"]),
+    ("select_laser_tool", &[]),
+    ("toggle_fade_ink", &[]),
     ("increase_font_size", &["Ctrl+Shift++", "Ctrl+Shift+="]),
     ("decrease_font_size", &["Ctrl+Shift+-", "Ctrl+Shift+_"]),
     ("reset_arrow_labels", &["Ctrl+Shift+R"]),
@@ -735,6 +773,8 @@ const DEFAULT_BINDING_SNAPSHOT: &[(&str, &[&str])] = &[
     ("page_new", &["Ctrl+Alt+N"]),
     ("page_duplicate", &["Ctrl+Alt+D"]),
     ("page_delete", &["Ctrl+Alt+Delete"]),
+    ("toggle_grid", &[]),
+    ("toggle_snap_to_grid", &[]),
     ("toggle_help", &["F10", "F1"]),
     ("toggle_quick_help", &["Shift+F1"]),
     ("toggle_status_bar", &["F12", "F4"]),
@@ -746,12 +786,21 @@ const DEFAULT_BINDING_SNAPSHOT: &[(&str, &[&str])] = &[
     ("toggle_toolbar", &["F9"]),
     ("cycle_toolbar_display", &["F2"]),
     ("toggle_presenter_mode", &["Ctrl+Shift+M"]),
+    ("toggle_replay", &[]),
+    ("start_break_timer", &[]),
+    ("start_talk_timer", &[]),
+    ("start_stopwatch", &[]),
+    ("toggle_timer_pause", &[]),
+    ("reset_timer", &[]),
+    ("timer_add_minute", &[]),
+    ("toggle_timer_display", &[]),
     ("toggle_light_mode", &["F6"]),
     ("toggle_light_mode_drawing", &[]),
     ("render_profile_next", &[]),
     ("render_profile_previous", &[]),
     ("render_profile_off", &[]),
     ("toggle_fill", &[]),
+    ("toggle_connector_mode", &[]),
     ("toggle_radial_menu", &[]),
     ("toggle_selection_properties", &["Ctrl+Alt+P"]),
     ("open_context_menu", &["Shift+F10", "Menu"]),
@@ -781,6 +830,10 @@ const DEFAULT_BINDING_SNAPSHOT: &[(&str, &[&str])] = &[
     ("export_canvas_clipboard_and_file", &[]),
     ("export_board_pdf_file", &[]),
     ("export_all_boards_pdf_file", &[]),
+    ("export_canvas_svg_file", &[]),
+    ("export_board_svg_file", &[]),
+    ("export_replay_frames", &[]),
+    ("toggle_recording", &[]),
     ("open_capture_folder", &["Ctrl+Alt+O"]),
     // Intentionally unbound: `O` is the orange quick color, and no other
     // conflict-free chord is obviously right, so the user picks one.
@@ -791,6 +844,10 @@ const DEFAULT_BINDING_SNAPSHOT: &[(&str, &[&str])] = &[
     ("reset_zoom", &["Ctrl+Alt+0"]),
     ("toggle_zoom_lock", &["Ctrl+Alt+L"]),
     ("refresh_zoom_capture", &["Ctrl+Alt+R"]),
+    ("toggle_magnifier", &["Ctrl+Alt+M"]),
+    // Scrolling over the lens already changes its magnification.
+    ("magnifier_zoom_in", &[]),
+    ("magnifier_zoom_out", &[]),
     ("apply_preset_1", &["1"]),
     ("apply_preset_2", &["2"]),
     ("apply_preset_3", &["3"]),
@@ -59,30 +59,35 @@ pub use keybindings::{
 pub use migration::{MigrationChange, MigrationPreview};
 #[allow(unused_imports)]
 pub use types::{
-    ArrowConfig, BoardBackgroundConfig, BoardColorConfig, BoardConfig, BoardItemConfig,
-    BoardsConfig, CaptureConfig, ClickHighlightConfig, DEFAULT_OCR_LANGUAGES, DragButtonConfig,
+    ArrowConfig, BoardBackgroundConfig, BoardColorConfig, BoardConfig, BoardGridConfig,
+    BoardGridStyle, BoardItemConfig, BoardsConfig, CaptureConfig, CapturePipelineConfig,
+    CapturePostprocessConfig, ClickHighlightConfig, DEFAULT_OCR_LANGUAGES, DragButtonConfig,
     DrawingConfig, ExportConfig, HelpOverlayStyle, HistoryConfig, InputHudConfig, InputHudMode,
-    InputHudPosition, MouseDragToolsConfig, PDF_LABEL_APP_BOARD, PDF_LABEL_APP_BOARDS,
-    PDF_LABEL_BOARD_NAME, PDF_LABEL_DEFAULT_TEMPLATE, PDF_LABEL_DOCUMENT_PAGE,
-    PDF_LABEL_DOCUMENT_PAGES, PDF_LABEL_EXPORT_BOARD, PDF_LABEL_EXPORT_BOARDS, PDF_LABEL_PAGE,
-    PDF_LABEL_PAGE_NAME, PDF_LABEL_PAGES, PDF_LABEL_PLACEHOLDERS, PRESET_SLOTS_MAX,
-    PRESET_SLOTS_MIN, PdfExportConfig, PdfFitMode, PdfLabelConfig, PdfLabelContentMode,
-    PdfLabelPosition, PdfOrientation, PdfPageSize, PdfTransparentBackground, PerformanceConfig,
-    PresenterModeConfig, PresenterToolBehavior, PresenterToolbarMode, PresetSlotsConfig,
-    PresetToolSettingConfig, PresetToolStatesConfig, QUICK_COLOR_RENDER_LIMIT, QuickColorConfig,
-    QuickColorPalette, QuickColorPaletteEntry, QuickColorSlot, QuickColorWrite, QuickColorsConfig,
-    RenderColorMappingConfig, RenderProfileConfig, RenderProfileExportMode, RenderProfilesConfig,
+    InputHudPosition, LaserConfig, MAGNIFIER_MIN_MAGNIFICATION, MagnifierConfig, MagnifierShape,
+    MouseDragToolsConfig, PDF_LABEL_APP_BOARD, PDF_LABEL_APP_BOARDS, PDF_LABEL_BOARD_NAME,
+    PDF_LABEL_DEFAULT_TEMPLATE, PDF_LABEL_DOCUMENT_PAGE, PDF_LABEL_DOCUMENT_PAGES,
+    PDF_LABEL_EXPORT_BOARD, PDF_LABEL_EXPORT_BOARDS, PDF_LABEL_PAGE, PDF_LABEL_PAGE_NAME,
+    PDF_LABEL_PAGES, PDF_LABEL_PLACEHOLDERS, PRESET_SLOTS_MAX, PRESET_SLOTS_MIN, PdfExportConfig,
+    PdfFitMode, PdfLabelConfig, PdfLabelContentMode, PdfLabelPosition, PdfOrientation, PdfPageSize,
+    PdfTransparentBackground, PerformanceConfig, PngCompression, PresenterModeConfig,
+    PresenterToolBehavior, PresenterToolbarMode, PresetSlotsConfig, PresetToolSettingConfig,
+    PresetToolStatesConfig, QUICK_COLOR_RENDER_LIMIT, QuickColorConfig, QuickColorPalette,
+    QuickColorPaletteEntry, QuickColorSlot, QuickColorWrite, QuickColorsConfig,
+    RECORDING_FPS_DEFAULT, RECORDING_FPS_MAX, RECORDING_MAX_DURATION_SECS_DEFAULT,
+    RECORDING_MAX_DURATION_SECS_MAX, REPLAY_FRAMES_PER_SHAPE_DEFAULT, REPLAY_FRAMES_PER_SHAPE_MAX,
+    RecordingExportConfig, RecordingFormat, RecordingRegion, RenderColorMappingConfig,
+    RenderProfileConfig, RenderProfileExportMode, RenderProfilesConfig, ReplayExportConfig,
     ResolvedToolbarItems, SessionCompression, SessionConfig, SessionStorageMode, SpotlightConfig,
-    StatusBarItem, StatusBarStyle, ToolPresetConfig, ToolbarBackendKind, ToolbarConfig,
-    ToolbarGroupId, ToolbarItemCategory, ToolbarItemDefinition, ToolbarItemId,
-    ToolbarItemOrderConfig, ToolbarItemOrderGroup, ToolbarItemSurface, ToolbarItemsConfig,
-    ToolbarLayoutMode, ToolbarModeOverride, ToolbarModeOverrides, ToolbarRebindModifier,
-    ToolbarSectionFlag, ToolbarSectionVisibility, TopDisplayMode, TrayConfig, TrayIconStyle,
-    UiConfig, UpdatesConfig, ZoomChipDisplay, default_quick_color_for_index,
-    fold_legacy_section_flags, resolve_section_visibility, section_flag_for_item,
-    set_section_visibility, toolbar_item_definitions, toolbar_item_ids, toolbar_item_order_group,
-    validate_capture_format, validate_filename_template, validate_ocr_languages,
-    validate_pdf_label_template,
+    StatusBarItem, StatusBarStyle, SvgExportConfig, TimerConfig, TimerDisplay, ToolPresetConfig,
+    ToolbarBackendKind, ToolbarConfig, ToolbarGroupId, ToolbarItemCategory, ToolbarItemDefinition,
+    ToolbarItemId, ToolbarItemOrderConfig, ToolbarItemOrderGroup, ToolbarItemSurface,
+    ToolbarItemsConfig, ToolbarLayoutMode, ToolbarModeOverride, ToolbarModeOverrides,
+    ToolbarRebindModifier, ToolbarSectionFlag, ToolbarSectionVisibility, TopDisplayMode,
+    TrayConfig, TrayIconStyle, UiConfig, UpdatesConfig, WatermarkPosition, ZoomChipDisplay,
+    default_quick_color_for_index, fold_legacy_section_flags, resolve_section_visibility,
+    section_flag_for_item, set_section_visibility, toolbar_item_definitions, toolbar_item_ids,
+    toolbar_item_order_group, validate_capture_format, validate_filename_template,
+    validate_ocr_languages, validate_pdf_label_template,
 };
 #[cfg(feature = "tablet-input")]
 #[allow(unused_imports)]
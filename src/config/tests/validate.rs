@@ -85,6 +85,29 @@ fn drawing_polygon_sides_validation_keeps_supported_bounds() {
     assert_eq!(config.drawing.polygon_sides, 12);
 }
 
+#[test]
+fn drawing_smart_ink_hold_is_clamped() {
+    let mut config = Config::default();
+    assert!(!config.drawing.smart_ink);
+    config.drawing.smart_ink_hold_ms = 60_000;
+    config.validate_and_clamp();
+    assert_eq!(config.drawing.smart_ink_hold_ms, 5000);
+}
+
+#[test]
+fn drawing_stabilizer_strength_is_clamped() {
+    let mut config = Config::default();
+    assert_eq!(config.drawing.stabilizer_strength, 0.0);
+    assert!(!config.drawing.smooth_strokes);
+    config.drawing.stabilizer_strength = 3.0;
+    config.validate_and_clamp();
+    assert_eq!(config.drawing.stabilizer_strength, 1.0);
+
+    config.drawing.stabilizer_strength = f64::NAN;
+    config.validate_and_clamp();
+    assert_eq!(config.drawing.stabilizer_strength, 0.0);
+}
+
 #[test]
 fn default_overlay_item_is_the_transparent_board() {
     let overlay = BoardsConfig::default_overlay_item();
@@ -108,6 +131,7 @@ fn validate_boards_uses_boundary_id_normalization() {
             show_pan_badge: true,
             persist_customizations: true,
             default_board: "transparent".to_string(),
+            grid: Default::default(),
             items: vec![
                 BoardItemConfig {
                     id: " Transparent ".to_string(),
@@ -356,6 +380,45 @@ fn pdf_filename_template_falls_back_to_capture_template() {
     );
 }
 
+#[test]
+fn svg_filename_templates_fall_back_to_canvas_then_capture_template() {
+    let mut config = Config::default();
+    config.capture.filename_template = "capture_%Y".to_string();
+
+    assert_eq!(
+        config
+            .export
+            .svg
+            .resolved_board_filename_template(&config.capture),
+        "capture_%Y"
+    );
+
+    config.export.svg.filename_template = Some(" canvas_%Y ".to_string());
+    assert_eq!(
+        config
+            .export
+            .svg
+            .resolved_filename_template(&config.capture),
+        "canvas_%Y"
+    );
+    assert_eq!(
+        config
+            .export
+            .svg
+            .resolved_board_filename_template(&config.capture),
+        "canvas_%Y"
+    );
+
+    config.export.svg.board_filename_template = Some("board_%Y".to_string());
+    assert_eq!(
+        config
+            .export
+            .svg
+            .resolved_board_filename_template(&config.capture),
+        "board_%Y"
+    );
+}
+
 /// `[export]` used to be the config tree's only table that rejected unknown
 /// keys, so one typo failed the entire file. It now follows the same contract
 /// as every other section: the key is ignored in memory, reported as an
@@ -416,6 +479,116 @@ fn validate_export_pdf_sanitizes_numbers_colors_and_bad_templates() {
     );
 }
 
+#[test]
+fn validate_export_replay_clamps_frames_and_drops_bad_templates() {
+    let mut config = Config::default();
+    config.capture.filename_template = "capture_%Y".to_string();
+    config.export.replay.frames_per_shape = 0;
+    config.export.replay.filename_template = Some("../replay".to_string());
+
+    config.validate_and_clamp();
+
+    assert_eq!(config.export.replay.frames_per_shape, 1);
+    assert_eq!(config.export.replay.filename_template, None);
+    assert_eq!(
+        config
+            .export
+            .replay
+            .resolved_filename_template(&config.capture),
+        "capture_%Y"
+    );
+
+    config.export.replay.frames_per_shape = 1_000;
+    config.validate_and_clamp();
+    assert_eq!(
+        config.export.replay.frames_per_shape,
+        REPLAY_FRAMES_PER_SHAPE_MAX
+    );
+}
+
+#[test]
+fn validate_export_recording_clamps_rates_and_drops_empty_region() {
+    let mut config = Config::default();
+    config.export.recording.fps = 0;
+    config.export.recording.max_duration_secs = 100_000;
+    config.export.recording.region = Some(RecordingRegion {
+        x: 10,
+        y: 10,
+        width: 0,
+        height: 200,
+    });
+    config.export.recording.filename_template = Some("a/b".to_string());
+
+    config.validate_and_clamp();
+
+    assert_eq!(config.export.recording.fps, 1);
+    assert_eq!(
+        config.export.recording.max_duration_secs,
+        RECORDING_MAX_DURATION_SECS_MAX
+    );
+    assert_eq!(config.export.recording.region, None);
+    assert_eq!(config.export.recording.filename_template, None);
+
+    config.export.recording.fps = 240;
+    config.validate_and_clamp();
+    assert_eq!(config.export.recording.fps, RECORDING_FPS_MAX);
+}
+
+#[test]
+fn validate_capture_encoding_clamps_quality_and_checks_formats() {
+    let mut config = Config::default();
+    config.capture.format = " WEBP ".to_string();
+    config.capture.canvas_export_format = "bmp".to_string();
+    config.capture.jpeg_quality = 0;
+    config.capture.webp_near_lossless_level = 250;
+
+    config.validate_and_clamp();
+
+    assert_eq!(config.capture.format, "webp");
+    assert_eq!(config.capture.canvas_export_format, "png");
+    assert_eq!(config.capture.jpeg_quality, 1);
+    assert_eq!(config.capture.webp_near_lossless_level, 100);
+}
+
+#[test]
+fn validate_capture_pipelines_clamps_steps_and_drops_unknown_selections() {
+    let mut config = config_from_toml(
+        r#"
+[[capture.pipelines]]
+id = " ticket "
+padding = 4000
+corner_radius = -3.0
+watermark_opacity = 2.5
+watermark_text = "   "
+
+[[capture.pipelines]]
+id = "ticket"
+padding = 8
+
+[[capture.pipelines]]
+id = ""
+
+[capture.postprocess]
+region = "ticket"
+full_screen = "missing"
+canvas_export = ""
+"#,
+    );
+
+    config.validate_and_clamp();
+
+    assert_eq!(config.capture.pipelines.len(), 1);
+    let pipeline = &config.capture.pipelines[0];
+    assert_eq!(pipeline.id, "ticket");
+    assert_eq!(pipeline.padding, 512);
+    assert_eq!(pipeline.corner_radius, 0.0);
+    assert_eq!(pipeline.watermark_opacity, 1.0);
+    assert_eq!(pipeline.watermark_text, None);
+    assert_eq!(config.capture.postprocess.region.as_deref(), Some("ticket"));
+    assert_eq!(config.capture.postprocess.full_screen, None);
+    assert_eq!(config.capture.postprocess.canvas_export, None);
+}
+
 #[test]
 fn validate_and_clamp_rejects_path_escaping_save_names() {
     let mut config = Config::default();
@@ -423,6 +596,8 @@ fn validate_and_clamp_rejects_path_escaping_save_names() {
     config.capture.format = "png/../../x".to_string();
     config.export.pdf.filename_template = Some("foo/bar".to_string());
     config.export.pdf.all_boards_filename_template = Some("/tmp/x".to_string());
+    config.export.svg.filename_template = Some("../svg".to_string());
+    config.export.svg.board_filename_template = Some("a/b".to_string());
 
     config.validate_and_clamp();
 
@@ -433,6 +608,8 @@ fn validate_and_clamp_rejects_path_escaping_save_names() {
     assert_eq!(config.capture.format, "png");
     assert_eq!(config.export.pdf.filename_template, None);
     assert_eq!(config.export.pdf.all_boards_filename_template, None);
+    assert_eq!(config.export.svg.filename_template, None);
+    assert_eq!(config.export.svg.board_filename_template, None);
 }
 
 #[test]
@@ -512,6 +689,7 @@ fn validate_clamps_preset_fields() {
         arrow_angle: Some(5.0),
         arrow_head_at_end: None,
         polygon_sides: Some(2),
+        line_style: None,
         show_status_bar: None,
         drag_tools: None,
     });
@@ -563,6 +741,18 @@ fn validate_and_clamp_clamps_input_hud_fields() {
     assert_eq!(upper.ui.input_hud.font_size, 18.0);
 }
 
+#[test]
+fn validate_and_clamp_resets_unsupported_ui_language() {
+    let mut config = Config::default();
+    config.ui.language = "de_DE.UTF-8".to_string();
+    config.validate_and_clamp();
+    assert_eq!(config.ui.language, "de_DE.UTF-8");
+
+    config.ui.language = "tlh".to_string();
+    config.validate_and_clamp();
+    assert_eq!(config.ui.language, "auto");
+}
+
 #[test]
 fn validate_and_clamp_clamps_ui_and_session_fields() {
     let mut config = Config::default();
@@ -664,6 +854,84 @@ fn validate_and_clamp_resets_non_finite_spotlight_settings() {
     }
 }
 
+#[test]
+fn validate_and_clamp_bounds_laser_settings() {
+    let mut config = Config::default();
+    config.laser.color = [1.5, -0.2, 0.5, 2.0];
+    config.laser.width = 100.0;
+    config.laser.fade_ms = 10;
+    config.laser.fade_ink_seconds = f64::NAN;
+
+    config.validate_and_clamp();
+
+    assert_eq!(config.laser.color, [1.0, 0.0, 0.5, 1.0]);
+    assert_eq!(config.laser.width, 40.0);
+    assert_eq!(config.laser.fade_ms, 100);
+    assert_eq!(
+        config.laser.fade_ink_seconds,
+        Config::default().laser.fade_ink_seconds
+    );
+}
+
+#[test]
+fn validate_and_clamp_bounds_magnifier_settings() {
+    let mut config = Config::default();
+    config.magnifier.size = 10.0;
+    config.magnifier.aspect_ratio = f64::INFINITY;
+    config.magnifier.max_magnification = 4.0;
+    config.magnifier.magnification = 6.0;
+    config.magnifier.zoom_step = 3.0;
+
+    config.validate_and_clamp();
+
+    assert_eq!(config.magnifier.size, 80.0);
+    assert_eq!(
+        config.magnifier.aspect_ratio,
+        Config::default().magnifier.aspect_ratio
+    );
+    assert_eq!(
+        config.magnifier.magnification, 4.0,
+        "the opening magnification never exceeds the maximum"
+    );
+    assert_eq!(config.magnifier.zoom_step, 2.0);
+}
+
+#[test]
+fn magnifier_shape_parses_from_kebab_case() {
+    let config = config_from_toml("[magnifier]\nshape = \"rectangle\"\n");
+    assert_eq!(config.magnifier.shape, MagnifierShape::Rectangle);
+}
+
+#[test]
+fn validate_and_clamp_bounds_timer_settings() {
+    let mut config = Config::default();
+    config.timer.break_minutes = 0;
+    config.timer.talk_minutes = 600;
+    config.timer.sound_file = Some("  ".to_string());
+
+    config.validate_and_clamp();
+
+    assert_eq!(config.timer.break_minutes, 1);
+    assert_eq!(config.timer.talk_minutes, 240);
+    assert_eq!(config.timer.sound_file, None);
+}
+
+#[test]
+fn timer_break_board_must_name_a_configured_board() {
+    let mut config =
+        config_from_toml("[timer]\nbreak_board = \" Blackboard \"\ndisplay = \"compact\"\n");
+    config.validate_and_clamp();
+    assert_eq!(config.timer.break_board, "blackboard");
+    assert_eq!(config.timer.display, TimerDisplay::Compact);
+
+    config.timer.break_board = "chalkboard".to_string();
+    config.validate_and_clamp();
+    assert_eq!(
+        config.timer.break_board, "",
+        "an unknown board keeps the current one"
+    );
+}
+
 /// The migration recipes are no longer part of loading — they are the material
 /// an explicit configurator review proposes — so they are exercised directly
 /// from here on.
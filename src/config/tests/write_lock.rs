@@ -56,6 +56,7 @@ fn preset(name: &str) -> ToolPresetConfig {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: None,
         drag_tools: None,
     }
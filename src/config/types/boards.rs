@@ -36,6 +36,10 @@ pub struct BoardsConfig {
     #[serde(default = "default_boards_default_board")]
     pub default_board: String,
 
+    /// Background grid, snapping, and alignment aids for solid-color boards.
+    #[serde(default)]
+    pub grid: BoardGridConfig,
+
     /// Board definitions.
     #[serde(default)]
     pub items: Vec<BoardItemConfig>,
@@ -51,6 +55,7 @@ impl Default for BoardsConfig {
             show_pan_badge: default_boards_show_pan_badge(),
             persist_customizations: default_boards_persist_customizations(),
             default_board: default_boards_default_board(),
+            grid: BoardGridConfig::default(),
             items: Self::default_items(),
         }
     }
@@ -109,11 +114,66 @@ impl BoardsConfig {
             show_pan_badge: default_boards_show_pan_badge(),
             persist_customizations: default_boards_persist_customizations(),
             default_board: legacy.default_mode.clone(),
+            grid: BoardGridConfig::default(),
             items,
         }
     }
 }
 
+/// Grid drawn behind the content of solid-color boards, plus the placement
+/// aids that work against it.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoardGridConfig {
+    /// Grid style: "off", "lines", or "dots".
+    #[serde(default)]
+    pub style: BoardGridStyle,
+
+    /// Distance between grid lines in logical pixels.
+    #[serde(default = "default_grid_spacing")]
+    pub spacing: f64,
+
+    /// Snap shape creation, moves, and resizes to the grid while it is shown.
+    #[serde(default = "default_grid_snap")]
+    pub snap: bool,
+
+    /// Show alignment guides against other shapes while moving a selection.
+    #[serde(default = "default_grid_guides")]
+    pub guides: bool,
+
+    /// Draw rulers along the top and left edges while the grid is shown.
+    #[serde(default)]
+    pub rulers: bool,
+}
+
+impl BoardGridConfig {
+    pub const MIN_SPACING: f64 = 8.0;
+    pub const MAX_SPACING: f64 = 256.0;
+}
+
+impl Default for BoardGridConfig {
+    fn default() -> Self {
+        Self {
+            style: BoardGridStyle::default(),
+            spacing: default_grid_spacing(),
+            snap: default_grid_snap(),
+            guides: default_grid_guides(),
+            rulers: false,
+        }
+    }
+}
+
+/// How the board grid is drawn.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum BoardGridStyle {
+    #[default]
+    Off,
+    Lines,
+    Dots,
+}
+
 /// Single board definition.
 #[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -206,6 +266,18 @@ fn default_boards_default_board() -> String {
     "transparent".to_string()
 }
 
+fn default_grid_spacing() -> f64 {
+    32.0
+}
+
+fn default_grid_snap() -> bool {
+    true
+}
+
+fn default_grid_guides() -> bool {
+    true
+}
+
 fn default_board_background() -> BoardBackgroundConfig {
     BoardBackgroundConfig::Transparent("transparent".to_string())
 }
@@ -1,5 +1,21 @@
 use serde::{Deserialize, Serialize};
 
+use super::capture_pipeline::{CapturePipelineConfig, CapturePostprocessConfig};
+
+/// How hard PNG output is compressed.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum PngCompression {
+    /// Keep the PNG as rendered, without re-encoding it
+    #[default]
+    Default,
+    /// Re-encode quickly; larger files
+    Fast,
+    /// Re-encode with the strongest compression; smallest files, slowest
+    Best,
+}
+
 /// Screenshot capture configuration.
 ///
 /// Controls the behavior of screenshot capture features including file saving,
@@ -19,14 +35,41 @@ pub struct CaptureConfig {
     #[serde(default = "default_capture_filename")]
     pub filename_template: String,
 
-    /// Image format for saved screenshots (e.g., "png", "jpg")
+    /// Image format for saved screenshots: "png", "jpg", "jpeg", or "webp"
     #[serde(default = "default_capture_format")]
     pub format: String,
 
+    /// Image format for saved canvas exports: "png", "jpg", "jpeg", or "webp"
+    #[serde(default = "default_capture_format")]
+    pub canvas_export_format: String,
+
+    /// PNG optimisation level: "default", "fast", or "best"
+    #[serde(default)]
+    pub png_compression: PngCompression,
+
+    /// JPEG quality (valid range: 1 - 100)
+    #[serde(default = "default_capture_jpeg_quality")]
+    pub jpeg_quality: u8,
+
+    /// Write near-lossless WebP: color precision is reduced according to
+    /// `webp_near_lossless_level` before lossless compression. When false,
+    /// WebP is fully lossless.
+    #[serde(default)]
+    pub webp_near_lossless: bool,
+
+    /// Near-lossless WebP level (valid range: 1 - 100; 100 drops nothing)
+    #[serde(default = "default_capture_webp_near_lossless_level")]
+    pub webp_near_lossless_level: u8,
+
     /// Automatically copy screenshots to clipboard
     #[serde(default = "default_capture_clipboard")]
     pub copy_to_clipboard: bool,
 
+    /// Paint the board background behind a selection copied to other apps as
+    /// PNG or SVG; the transparent overlay board uses white
+    #[serde(default)]
+    pub copy_selection_backdrop: bool,
+
     /// Exit the overlay after any capture completes (forces exit for all capture types).
     /// When false, clipboard-only captures still auto-exit by default.
     #[serde(default = "default_capture_exit_after")]
@@ -37,6 +80,14 @@ pub struct CaptureConfig {
     /// Tesseract language packages must be installed.
     #[serde(default = "default_capture_ocr_languages")]
     pub ocr_languages: String,
+
+    /// Named post-processing pipelines (`[[capture.pipelines]]`)
+    #[serde(default)]
+    pub pipelines: Vec<CapturePipelineConfig>,
+
+    /// Which pipeline each kind of capture runs through
+    #[serde(default)]
+    pub postprocess: CapturePostprocessConfig,
 }
 
 impl Default for CaptureConfig {
@@ -46,14 +97,27 @@ impl Default for CaptureConfig {
             save_directory: default_capture_directory(),
             filename_template: default_capture_filename(),
             format: default_capture_format(),
+            canvas_export_format: default_capture_format(),
+            png_compression: PngCompression::default(),
+            jpeg_quality: default_capture_jpeg_quality(),
+            webp_near_lossless: false,
+            webp_near_lossless_level: default_capture_webp_near_lossless_level(),
             copy_to_clipboard: default_capture_clipboard(),
+            copy_selection_backdrop: false,
             exit_after_capture: default_capture_exit_after(),
             ocr_languages: default_capture_ocr_languages(),
+            pipelines: Vec::new(),
+            postprocess: CapturePostprocessConfig::default(),
         }
     }
 }
 
 impl CaptureConfig {
+    /// Looks up a pipeline by id.
+    pub fn pipeline(&self, id: &str) -> Option<&CapturePipelineConfig> {
+        self.pipelines.iter().find(|pipeline| pipeline.id == id)
+    }
+
     /// The configured OCR languages, or the default when the authored value is
     /// unusable. Recognition never passes an unvalidated string to the engine.
     pub fn resolved_ocr_languages(&self) -> String {
@@ -117,12 +181,13 @@ pub fn validate_filename_template(template: &str) -> Result<(), String> {
     Ok(())
 }
 
-/// Screenshot save format. PDF exports use a separate extension at save time.
+/// Screenshot and canvas export save format. PDF exports use a separate
+/// extension at save time.
 pub fn validate_capture_format(format: &str) -> Result<String, String> {
     let normalized = format.trim().to_ascii_lowercase();
     match normalized.as_str() {
-        "png" | "jpg" | "jpeg" => Ok(normalized),
-        _ => Err("must be png, jpg, or jpeg".to_string()),
+        "png" | "jpg" | "jpeg" | "webp" => Ok(normalized),
+        _ => Err("must be png, jpg, jpeg, or webp".to_string()),
     }
 }
 
@@ -146,6 +211,14 @@ fn default_capture_format() -> String {
     "png".to_string()
 }
 
+fn default_capture_jpeg_quality() -> u8 {
+    90
+}
+
+fn default_capture_webp_near_lossless_level() -> u8 {
+    80
+}
+
 fn default_capture_clipboard() -> bool {
     true
 }
@@ -218,6 +291,7 @@ mod tests {
     fn capture_format_is_allowlisted() {
         assert_eq!(validate_capture_format(" PNG ").unwrap(), "png");
         assert_eq!(validate_capture_format("jpeg").unwrap(), "jpeg");
+        assert_eq!(validate_capture_format("WebP").unwrap(), "webp");
         assert!(validate_capture_format("pdf").is_err());
         assert!(validate_capture_format("png/../../x").is_err());
         assert!(validate_capture_format("exe").is_err());
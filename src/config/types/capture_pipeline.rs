@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+
+use crate::config::enums::ColorSpec;
+
+/// Corner or center of the image a watermark is anchored to.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum WatermarkPosition {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+    Center,
+}
+
+/// One named post-processing pipeline, applied to a capture after it is taken
+/// and before it is saved or copied.
+///
+/// Steps run in a fixed order: trim, padding, rounded corners, border, drop
+/// shadow, then the watermark. A step whose setting is zero or unset is
+/// skipped, so an empty pipeline delivers the image unchanged.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapturePipelineConfig {
+    /// Name referenced from `[capture.postprocess]`
+    pub id: String,
+
+    /// Crop away uniform margins matching the top-left pixel
+    #[serde(default)]
+    pub trim: bool,
+
+    /// Space added around the image, in pixels (valid range: 0 - 512)
+    #[serde(default)]
+    pub padding: u32,
+
+    /// Fill behind the image and padding; transparent when unset
+    #[serde(default)]
+    pub background: Option<ColorSpec>,
+
+    /// Radius of the image's rounded corners, in pixels
+    /// (valid range: 0.0 - 256.0)
+    #[serde(default)]
+    pub corner_radius: f64,
+
+    /// Width of the border drawn around the image, in pixels
+    /// (valid range: 0.0 - 64.0)
+    #[serde(default)]
+    pub border_width: f64,
+
+    /// Border color
+    #[serde(default = "default_border_color")]
+    pub border_color: ColorSpec,
+
+    /// Draw a soft drop shadow under the image
+    #[serde(default)]
+    pub shadow: bool,
+
+    /// Blur radius of the drop shadow, in pixels (valid range: 0.0 - 128.0)
+    #[serde(default = "default_shadow_radius")]
+    pub shadow_radius: f64,
+
+    /// Vertical offset of the drop shadow, in pixels
+    /// (valid range: -128.0 - 128.0)
+    #[serde(default = "default_shadow_offset_y")]
+    pub shadow_offset_y: f64,
+
+    /// Drop shadow color; use `#RRGGBBAA` for a translucent shadow
+    #[serde(default = "default_shadow_color")]
+    pub shadow_color: ColorSpec,
+
+    /// Text stamped onto the image
+    #[serde(default)]
+    pub watermark_text: Option<String>,
+
+    /// PNG stamped onto the image (supports ~ expansion); drawn instead of
+    /// the text when both are set
+    #[serde(default)]
+    pub watermark_image: Option<String>,
+
+    /// Where the watermark sits on the image
+    #[serde(default)]
+    pub watermark_position: WatermarkPosition,
+
+    /// Watermark opacity (valid range: 0.0 - 1.0)
+    #[serde(default = "default_watermark_opacity")]
+    pub watermark_opacity: f64,
+
+    /// Watermark text size, in pixels (valid range: 6.0 - 200.0)
+    #[serde(default = "default_watermark_size")]
+    pub watermark_size: f64,
+
+    /// Watermark text color
+    #[serde(default = "default_watermark_color")]
+    pub watermark_color: ColorSpec,
+}
+
+impl CapturePipelineConfig {
+    /// A pipeline with every step turned off.
+    pub fn named(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            trim: false,
+            padding: 0,
+            background: None,
+            corner_radius: 0.0,
+            border_width: 0.0,
+            border_color: default_border_color(),
+            shadow: false,
+            shadow_radius: default_shadow_radius(),
+            shadow_offset_y: default_shadow_offset_y(),
+            shadow_color: default_shadow_color(),
+            watermark_text: None,
+            watermark_image: None,
+            watermark_position: WatermarkPosition::default(),
+            watermark_opacity: default_watermark_opacity(),
+            watermark_size: default_watermark_size(),
+            watermark_color: default_watermark_color(),
+        }
+    }
+}
+
+/// The pipeline each kind of capture runs through, by pipeline id. Kinds
+/// without an entry are delivered as captured.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CapturePostprocessConfig {
+    /// Pipeline for full screen captures
+    #[serde(default)]
+    pub full_screen: Option<String>,
+
+    /// Pipeline for active window captures
+    #[serde(default)]
+    pub active_window: Option<String>,
+
+    /// Pipeline for region captures
+    #[serde(default)]
+    pub region: Option<String>,
+
+    /// Pipeline for canvas PNG exports
+    #[serde(default)]
+    pub canvas_export: Option<String>,
+}
+
+impl CapturePostprocessConfig {
+    pub(crate) fn selections_mut(&mut self) -> [(&'static str, &mut Option<String>); 4] {
+        [
+            ("full_screen", &mut self.full_screen),
+            ("active_window", &mut self.active_window),
+            ("region", &mut self.region),
+            ("canvas_export", &mut self.canvas_export),
+        ]
+    }
+}
+
+fn default_border_color() -> ColorSpec {
+    ColorSpec::Name("#d0d0d0".to_string())
+}
+
+fn default_shadow_radius() -> f64 {
+    16.0
+}
+
+fn default_shadow_offset_y() -> f64 {
+    6.0
+}
+
+fn default_shadow_color() -> ColorSpec {
+    ColorSpec::Name("#00000080".to_string())
+}
+
+fn default_watermark_opacity() -> f64 {
+    0.7
+}
+
+fn default_watermark_size() -> f64 {
+    16.0
+}
+
+fn default_watermark_color() -> ColorSpec {
+    ColorSpec::Name("#ffffff".to_string())
+}
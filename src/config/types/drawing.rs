@@ -1,6 +1,6 @@
 use crate::config::enums::ColorSpec;
 use crate::domain::{Action, Color, DragBindableTool, DragTool, EraserMode};
-use crate::draw::shape::{BlurStyle, REGULAR_POLYGON_DEFAULT_SIDES};
+use crate::draw::shape::{BlurStyle, LineStyle, REGULAR_POLYGON_DEFAULT_SIDES};
 use serde::{Deserialize, Serialize};
 
 /// Maximum quick colors rendered by dense palette UIs.
@@ -42,6 +42,10 @@ pub struct DrawingConfig {
     #[serde(default = "default_blur_style")]
     pub default_blur_style: BlurStyle,
 
+    /// Dash style for new lines, outlines, arrows, and pen strokes
+    #[serde(default)]
+    pub default_line_style: LineStyle,
+
     /// Default marker opacity multiplier (0.05 - 0.9), applied to the current color alpha
     #[serde(default = "default_marker_opacity")]
     pub marker_opacity: f64,
@@ -54,6 +58,28 @@ pub struct DrawingConfig {
     #[serde(default = "default_polygon_sides")]
     pub polygon_sides: u8,
 
+    /// Replace pen strokes that look like a line, arrow, rectangle, ellipse or
+    /// triangle with the matching shape when the stroke is finished
+    #[serde(default)]
+    pub smart_ink: bool,
+
+    /// With smart ink on, only recognize strokes where the pointer rested at
+    /// the end for at least this many milliseconds (0 = every pen stroke,
+    /// valid range: 0 - 5000)
+    #[serde(default)]
+    pub smart_ink_hold_ms: u64,
+
+    /// Stabilizer for pen and marker strokes: the ink trails the pointer on a
+    /// lazy-brush string, up to 32 pixels long at full strength (0.0 = off,
+    /// valid range: 0.0 - 1.0)
+    #[serde(default)]
+    pub stabilizer_strength: f64,
+
+    /// Fit a smooth curve through pen and marker strokes when they are
+    /// finished
+    #[serde(default)]
+    pub smooth_strokes: bool,
+
     /// Default font size for text mode in points (valid range: 8.0 - 72.0)
     #[serde(default = "default_font_size")]
     pub default_font_size: f64,
@@ -126,9 +152,14 @@ impl Default for DrawingConfig {
             default_eraser_size: default_eraser_size(),
             default_eraser_mode: default_eraser_mode(),
             default_blur_style: default_blur_style(),
+            default_line_style: LineStyle::default(),
             marker_opacity: default_marker_opacity(),
             default_fill_enabled: default_fill_enabled(),
             polygon_sides: default_polygon_sides(),
+            smart_ink: false,
+            smart_ink_hold_ms: 0,
+            stabilizer_strength: 0.0,
+            smooth_strokes: false,
             default_font_size: default_font_size(),
             hit_test_tolerance: default_hit_test_tolerance(),
             hit_test_linear_threshold: default_hit_test_threshold(),
@@ -35,6 +35,9 @@ pub const PDF_LABEL_PLACEHOLDERS: &[&str] = &[
 #[serde(default)]
 pub struct ExportConfig {
     pub pdf: PdfExportConfig,
+    pub svg: SvgExportConfig,
+    pub replay: ReplayExportConfig,
+    pub recording: RecordingExportConfig,
 }
 
 #[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
@@ -98,6 +101,154 @@ impl PdfExportConfig {
     }
 }
 
+/// Filename templates for the SVG exports. Both fall back to
+/// `capture.filename_template`; the board template also falls back to the
+/// canvas one.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct SvgExportConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub board_filename_template: Option<String>,
+}
+
+impl SvgExportConfig {
+    pub fn resolved_filename_template(&self, capture: &CaptureConfig) -> String {
+        self.filename_template
+            .as_deref()
+            .map(str::trim)
+            .filter(|template| !template.is_empty())
+            .unwrap_or(&capture.filename_template)
+            .to_string()
+    }
+
+    pub fn resolved_board_filename_template(&self, capture: &CaptureConfig) -> String {
+        self.board_filename_template
+            .as_deref()
+            .map(str::trim)
+            .filter(|template| !template.is_empty())
+            .or_else(|| {
+                self.filename_template
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|template| !template.is_empty())
+            })
+            .unwrap_or(&capture.filename_template)
+            .to_string()
+    }
+}
+
+pub const REPLAY_FRAMES_PER_SHAPE_DEFAULT: u32 = 4;
+pub const REPLAY_FRAMES_PER_SHAPE_MAX: u32 = 60;
+
+/// Settings for exporting a page replay as a PNG frame sequence. The folder
+/// name template falls back to `capture.filename_template`.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReplayExportConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename_template: Option<String>,
+    /// Frames written while each shape is drawn.
+    pub frames_per_shape: u32,
+}
+
+impl Default for ReplayExportConfig {
+    fn default() -> Self {
+        Self {
+            filename_template: None,
+            frames_per_shape: REPLAY_FRAMES_PER_SHAPE_DEFAULT,
+        }
+    }
+}
+
+impl ReplayExportConfig {
+    pub fn resolved_filename_template(&self, capture: &CaptureConfig) -> String {
+        self.filename_template
+            .as_deref()
+            .map(str::trim)
+            .filter(|template| !template.is_empty())
+            .unwrap_or(&capture.filename_template)
+            .to_string()
+    }
+}
+
+pub const RECORDING_FPS_DEFAULT: u32 = 10;
+pub const RECORDING_FPS_MAX: u32 = 30;
+pub const RECORDING_MAX_DURATION_SECS_DEFAULT: u32 = 120;
+pub const RECORDING_MAX_DURATION_SECS_MAX: u32 = 1_800;
+
+/// Settings for recording the annotated overlay to an animated GIF or APNG.
+/// The filename template falls back to `capture.filename_template`.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecordingExportConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename_template: Option<String>,
+    pub format: RecordingFormat,
+    /// Frames captured per second.
+    pub fps: u32,
+    /// Recording stops and saves on its own after this many seconds.
+    pub max_duration_secs: u32,
+    /// Logical-pixel rectangle to record; the whole output when unset.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<RecordingRegion>,
+}
+
+impl Default for RecordingExportConfig {
+    fn default() -> Self {
+        Self {
+            filename_template: None,
+            format: RecordingFormat::default(),
+            fps: RECORDING_FPS_DEFAULT,
+            max_duration_secs: RECORDING_MAX_DURATION_SECS_DEFAULT,
+            region: None,
+        }
+    }
+}
+
+impl RecordingExportConfig {
+    pub fn resolved_filename_template(&self, capture: &CaptureConfig) -> String {
+        self.filename_template
+            .as_deref()
+            .map(str::trim)
+            .filter(|template| !template.is_empty())
+            .unwrap_or(&capture.filename_template)
+            .to_string()
+    }
+}
+
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum RecordingFormat {
+    #[default]
+    Gif,
+    Apng,
+}
+
+impl RecordingFormat {
+    /// File extension; APNG keeps `.png` so every PNG viewer opens it.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Apng => "png",
+        }
+    }
+}
+
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordingRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
 #[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "kebab-case")]
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// Laser pointer settings.
+///
+/// The laser pointer shows a bright dot under the cursor that leaves a short
+/// trail behind it. Nothing it draws is ever added to the page.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaserConfig {
+    /// Dot and trail color as [R, G, B, A] (0.0 - 1.0)
+    #[serde(default = "default_laser_color")]
+    pub color: [f64; 4],
+
+    /// Trail width in pixels; the dot is drawn slightly larger
+    /// (valid range: 1.0 - 40.0)
+    #[serde(default = "default_laser_width")]
+    pub width: f64,
+
+    /// How long each part of the trail takes to fade out, in milliseconds
+    /// (valid range: 100 - 5000)
+    #[serde(default = "default_laser_fade_ms")]
+    pub fade_ms: u64,
+
+    /// Start with fade ink on: pen strokes vanish a few seconds after they
+    /// are drawn and never enter undo history
+    #[serde(default)]
+    pub fade_ink: bool,
+
+    /// How long a fade-ink stroke stays before it is gone, in seconds
+    /// (valid range: 0.5 - 60.0)
+    #[serde(default = "default_fade_ink_seconds")]
+    pub fade_ink_seconds: f64,
+}
+
+impl Default for LaserConfig {
+    fn default() -> Self {
+        Self {
+            color: default_laser_color(),
+            width: default_laser_width(),
+            fade_ms: default_laser_fade_ms(),
+            fade_ink: false,
+            fade_ink_seconds: default_fade_ink_seconds(),
+        }
+    }
+}
+
+fn default_laser_color() -> [f64; 4] {
+    [1.0, 0.15, 0.15, 0.9]
+}
+
+fn default_laser_width() -> f64 {
+    6.0
+}
+
+fn default_laser_fade_ms() -> u64 {
+    700
+}
+
+fn default_fade_ink_seconds() -> f64 {
+    3.0
+}
@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+/// Lowest magnification the lens shows; below it the lens would only get in
+/// the way of what it covers.
+pub const MAGNIFIER_MIN_MAGNIFICATION: f64 = 1.25;
+
+/// Outline of the magnifier lens.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum MagnifierShape {
+    #[default]
+    Circle,
+    Rectangle,
+}
+
+/// Magnifier lens settings.
+///
+/// The magnifier draws an enlarged copy of whatever is under the pointer in
+/// a lens that follows it, while drawing carries on as usual. Over the live
+/// desktop the screen is captured through the same path zoom uses when the
+/// lens opens.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MagnifierConfig {
+    /// Lens outline: "circle" or "rectangle"
+    #[serde(default)]
+    pub shape: MagnifierShape,
+
+    /// Lens diameter, or rectangle width, in pixels
+    /// (valid range: 80.0 - 800.0)
+    #[serde(default = "default_magnifier_size")]
+    pub size: f64,
+
+    /// Width divided by height for the rectangular lens
+    /// (valid range: 1.0 - 4.0)
+    #[serde(default = "default_magnifier_aspect_ratio")]
+    pub aspect_ratio: f64,
+
+    /// Magnification the lens opens with
+    /// (valid range: 1.25 - `max_magnification`)
+    #[serde(default = "default_magnifier_magnification")]
+    pub magnification: f64,
+
+    /// Highest magnification scrolling can reach
+    /// (valid range: 2.0 - 16.0)
+    #[serde(default = "default_magnifier_max_magnification")]
+    pub max_magnification: f64,
+
+    /// Factor one scroll step multiplies or divides the magnification by
+    /// (valid range: 1.05 - 2.0)
+    #[serde(default = "default_magnifier_zoom_step")]
+    pub zoom_step: f64,
+}
+
+impl Default for MagnifierConfig {
+    fn default() -> Self {
+        Self {
+            shape: MagnifierShape::default(),
+            size: default_magnifier_size(),
+            aspect_ratio: default_magnifier_aspect_ratio(),
+            magnification: default_magnifier_magnification(),
+            max_magnification: default_magnifier_max_magnification(),
+            zoom_step: default_magnifier_zoom_step(),
+        }
+    }
+}
+
+fn default_magnifier_size() -> f64 {
+    240.0
+}
+
+fn default_magnifier_aspect_ratio() -> f64 {
+    1.6
+}
+
+fn default_magnifier_magnification() -> f64 {
+    2.0
+}
+
+fn default_magnifier_max_magnification() -> f64 {
+    8.0
+}
+
+fn default_magnifier_zoom_step() -> f64 {
+    1.25
+}
@@ -4,6 +4,7 @@ mod arrow;
 mod board;
 mod boards;
 mod capture;
+mod capture_pipeline;
 mod click_highlight;
 mod context_menu;
 mod drawing;
@@ -11,6 +12,8 @@ mod export;
 mod help_overlay;
 mod history;
 mod input_hud;
+mod laser;
+mod magnifier;
 mod performance;
 mod presenter_mode;
 mod presets;
@@ -20,6 +23,7 @@ mod spotlight;
 mod status_bar;
 #[cfg(feature = "tablet-input")]
 mod tablet;
+mod timer;
 mod toolbar;
 mod tray;
 mod ui;
@@ -27,11 +31,15 @@ mod updates;
 
 pub use arrow::ArrowConfig;
 pub use board::BoardConfig;
-pub use boards::{BoardBackgroundConfig, BoardColorConfig, BoardItemConfig, BoardsConfig};
+pub use boards::{
+    BoardBackgroundConfig, BoardColorConfig, BoardGridConfig, BoardGridStyle, BoardItemConfig,
+    BoardsConfig,
+};
 pub use capture::{
-    CaptureConfig, DEFAULT_OCR_LANGUAGES, validate_capture_format, validate_filename_template,
-    validate_ocr_languages,
+    CaptureConfig, DEFAULT_OCR_LANGUAGES, PngCompression, validate_capture_format,
+    validate_filename_template, validate_ocr_languages,
 };
+pub use capture_pipeline::{CapturePipelineConfig, CapturePostprocessConfig, WatermarkPosition};
 pub use click_highlight::ClickHighlightConfig;
 pub use context_menu::ContextMenuUiConfig;
 pub use drawing::{
@@ -45,11 +53,16 @@ pub use export::{
     PDF_LABEL_EXPORT_BOARD, PDF_LABEL_EXPORT_BOARDS, PDF_LABEL_PAGE, PDF_LABEL_PAGE_NAME,
     PDF_LABEL_PAGES, PDF_LABEL_PLACEHOLDERS, PdfExportConfig, PdfFitMode, PdfLabelConfig,
     PdfLabelContentMode, PdfLabelPosition, PdfOrientation, PdfPageSize, PdfTransparentBackground,
+    RECORDING_FPS_DEFAULT, RECORDING_FPS_MAX, RECORDING_MAX_DURATION_SECS_DEFAULT,
+    RECORDING_MAX_DURATION_SECS_MAX, REPLAY_FRAMES_PER_SHAPE_DEFAULT, REPLAY_FRAMES_PER_SHAPE_MAX,
+    RecordingExportConfig, RecordingFormat, RecordingRegion, ReplayExportConfig, SvgExportConfig,
     validate_pdf_label_template,
 };
 pub use help_overlay::HelpOverlayStyle;
 pub use history::HistoryConfig;
 pub use input_hud::{InputHudConfig, InputHudMode, InputHudPosition};
+pub use laser::LaserConfig;
+pub use magnifier::{MAGNIFIER_MIN_MAGNIFICATION, MagnifierConfig, MagnifierShape};
 pub use performance::PerformanceConfig;
 pub use presenter_mode::{PresenterModeConfig, PresenterToolBehavior, PresenterToolbarMode};
 pub use presets::{
@@ -64,6 +77,7 @@ pub use spotlight::SpotlightConfig;
 pub use status_bar::{StatusBarItem, StatusBarStyle};
 #[cfg(feature = "tablet-input")]
 pub use tablet::{StylusButtonBinding, TabletInputConfig};
+pub use timer::{TimerConfig, TimerDisplay};
 pub use toolbar::ids as toolbar_item_ids;
 #[allow(unused_imports)]
 pub use toolbar::{
@@ -80,7 +80,8 @@ pub enum PresenterToolBehavior {
     /// Switch to highlight on entry, but allow tool changes afterward.
     #[default]
     ForceHighlight,
-    /// Switch to highlight and prevent tool changes while presenting.
+    /// Switch to highlight and prevent tool changes while presenting; only
+    /// the laser pointer may still be picked.
     ForceHighlightLocked,
 }
 
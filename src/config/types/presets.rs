@@ -1,6 +1,6 @@
 use crate::config::{MouseDragToolsConfig, enums::ColorSpec};
 use crate::domain::{Color, EraserMode, Tool};
-use crate::draw::EraserKind;
+use crate::draw::{EraserKind, LineStyle};
 use crate::input::tool::{
     PerToolDrawingSettings, ToolDrawingSettings, ToolSettingsSlot, ToolSizeSource,
 };
@@ -70,6 +70,10 @@ pub struct ToolPresetConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub polygon_sides: Option<u8>,
 
+    /// Optional line style override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_style: Option<LineStyle>,
+
     /// Optional status bar visibility override.
     #[serde(default)]
     pub show_status_bar: Option<bool>,
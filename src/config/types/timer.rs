@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+/// Where a running timer is shown.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum TimerDisplay {
+    /// Large digits in the middle of the active board.
+    #[default]
+    Overlay,
+    /// A small TIMER pill next to the status bar (or in the top corner when
+    /// the status bar is hidden).
+    Compact,
+}
+
+/// Break timer, talk timer, and stopwatch settings.
+///
+/// A countdown runs from the configured length down to zero and then flashes,
+/// posts a desktop notification, and plays a sound if one is set. The
+/// stopwatch counts up until it is reset.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimerConfig {
+    /// Length of the break countdown in minutes
+    /// (valid range: 1 - 240)
+    #[serde(default = "default_break_minutes")]
+    pub break_minutes: u32,
+
+    /// Length of the talk countdown in minutes
+    /// (valid range: 1 - 240)
+    #[serde(default = "default_talk_minutes")]
+    pub talk_minutes: u32,
+
+    /// How a running timer is shown: "overlay" or "compact"
+    #[serde(default)]
+    pub display: TimerDisplay,
+
+    /// Flash the timer when a countdown reaches zero
+    #[serde(default = "default_true")]
+    pub flash: bool,
+
+    /// Post a desktop notification when a countdown reaches zero
+    #[serde(default = "default_true")]
+    pub notify: bool,
+
+    /// Sound file played with `pw-play` (or `paplay`) when a countdown
+    /// reaches zero; leave unset for silence
+    #[serde(default)]
+    pub sound_file: Option<String>,
+
+    /// Board to switch to while the break timer runs ("blackboard",
+    /// "whiteboard", or any configured board id); the previous board comes
+    /// back when the timer is reset. Leave empty to stay on the current board.
+    #[serde(default)]
+    pub break_board: String,
+}
+
+impl Default for TimerConfig {
+    fn default() -> Self {
+        Self {
+            break_minutes: default_break_minutes(),
+            talk_minutes: default_talk_minutes(),
+            display: TimerDisplay::default(),
+            flash: default_true(),
+            notify: default_true(),
+            sound_file: None,
+            break_board: String::new(),
+        }
+    }
+}
+
+fn default_break_minutes() -> u32 {
+    10
+}
+
+fn default_talk_minutes() -> u32 {
+    20
+}
+
+fn default_true() -> bool {
+    true
+}
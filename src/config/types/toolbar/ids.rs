@@ -11,6 +11,7 @@ pub const TOP_TOOL_PEN: ToolbarItemId = ToolbarItemId::from_known("top.tool.pen"
 pub const TOP_TOOL_MARKER: ToolbarItemId = ToolbarItemId::from_known("top.tool.marker");
 pub const TOP_TOOL_STEP_MARKER: ToolbarItemId = ToolbarItemId::from_known("top.tool.step-marker");
 pub const TOP_TOOL_ERASER: ToolbarItemId = ToolbarItemId::from_known("top.tool.eraser");
+pub const TOP_TOOL_LASER: ToolbarItemId = ToolbarItemId::from_known("top.tool.laser");
 pub const TOP_TOOL_LINE: ToolbarItemId = ToolbarItemId::from_known("top.tool.line");
 pub const TOP_TOOL_RECT: ToolbarItemId = ToolbarItemId::from_known("top.tool.rect");
 pub const TOP_TOOL_ELLIPSE: ToolbarItemId = ToolbarItemId::from_known("top.tool.ellipse");
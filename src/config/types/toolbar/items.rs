@@ -62,7 +62,8 @@ const DEFAULT_HIDDEN_TOOLBAR_ITEM_IDS: &[ToolbarItemId] = &[ids::TOP_UTILITY_SCR
 /// default-hidden id reaches new configs and silently exposes the item on every
 /// installed one. Screenshot stays list-driven because every existing config
 /// already names it; anything added afterwards belongs here instead.
-const BASELINE_HIDDEN_TOOLBAR_ITEM_IDS: &[ToolbarItemId] = &[ids::TOP_UTILITY_OCR];
+const BASELINE_HIDDEN_TOOLBAR_ITEM_IDS: &[ToolbarItemId] =
+    &[ids::TOP_UTILITY_OCR, ids::TOP_TOOL_LASER];
 
 /// Whether `id` is hidden unless the user explicitly shows it.
 pub(crate) fn toolbar_item_hidden_by_baseline(id: ToolbarItemId) -> bool {
@@ -75,6 +76,7 @@ const DEFAULT_TOP_TOOLS_ORDER: &[ToolbarItemId] = &[
     ids::TOP_TOOL_MARKER,
     ids::TOP_TOOL_STEP_MARKER,
     ids::TOP_TOOL_ERASER,
+    ids::TOP_TOOL_LASER,
     ids::TOP_TOOL_LINE,
     ids::TOP_TOOL_RECT,
     ids::TOP_TOOL_ELLIPSE,
@@ -21,6 +21,7 @@ const TOOLBAR_ITEM_DEFINITIONS: &[ToolbarItemDefinition] = &[
     item(ids::TOP_TOOL_MARKER, "Marker", Top, Tool, None),
     item(ids::TOP_TOOL_STEP_MARKER, "Step marker", Top, Tool, None),
     item(ids::TOP_TOOL_ERASER, "Eraser", Top, Tool, None),
+    item(ids::TOP_TOOL_LASER, "Laser pointer", Top, Tool, None),
     item(ids::TOP_TOOL_LINE, "Line", Top, Tool, None),
     item(ids::TOP_TOOL_RECT, "Rectangle", Top, Tool, None),
     item(ids::TOP_TOOL_ELLIPSE, "Ellipse", Top, Tool, None),
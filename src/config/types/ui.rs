@@ -28,6 +28,12 @@ pub struct UiConfig {
     #[serde(default)]
     pub reduced_motion: ReducedMotion,
 
+    /// Language of overlay text: `auto` (default) follows `LANGUAGE`/`LANG`,
+    /// or a bundled language code such as `en` or `de`. Missing translations
+    /// fall back to English.
+    #[serde(default = "default_language")]
+    pub language: String,
+
     /// Show the status bar and its configured content
     #[serde(default = "default_show_status")]
     pub show_status_bar: bool,
@@ -178,6 +184,7 @@ impl Default for UiConfig {
         Self {
             theme: UiTheme::default(),
             reduced_motion: ReducedMotion::default(),
+            language: default_language(),
             show_status_bar: default_show_status(),
             status_bar_interactive: default_status_bar_interactive(),
             show_status_selection_info: default_status_bar_item_visible(),
@@ -248,6 +255,10 @@ impl UiConfig {
     }
 }
 
+fn default_language() -> String {
+    crate::i18n::AUTO_LANGUAGE.to_string()
+}
+
 fn default_show_status() -> bool {
     true
 }
@@ -1,4 +1,6 @@
-use crate::config::types::{BoardBackgroundConfig, BoardColorConfig, BoardsConfig};
+use crate::config::types::{
+    BoardBackgroundConfig, BoardColorConfig, BoardGridConfig, BoardsConfig,
+};
 use crate::input::boards::{BoundaryBoardIdSet, clamp_board_rgb};
 use log::warn;
 
@@ -19,6 +21,22 @@ impl Config {
             boards.max_count = 1;
         }
 
+        let spacing = boards.grid.spacing;
+        if !(BoardGridConfig::MIN_SPACING..=BoardGridConfig::MAX_SPACING).contains(&spacing) {
+            let clamped = if spacing.is_finite() {
+                spacing.clamp(BoardGridConfig::MIN_SPACING, BoardGridConfig::MAX_SPACING)
+            } else {
+                BoardGridConfig::default().spacing
+            };
+            warn!(
+                "Invalid boards.grid.spacing {:.1}, clamping to {:.0}-{:.0} range",
+                spacing,
+                BoardGridConfig::MIN_SPACING,
+                BoardGridConfig::MAX_SPACING
+            );
+            boards.grid.spacing = clamped;
+        }
+
         let mut seen = BoundaryBoardIdSet::new();
         for (index, item) in boards.items.iter_mut().enumerate() {
             let raw_id = item.id.clone();
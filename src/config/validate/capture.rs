@@ -1,4 +1,108 @@
-use super::super::{CaptureConfig, Config, validate_capture_format, validate_filename_template};
+use std::collections::HashSet;
+
+use super::super::{
+    CaptureConfig, CapturePipelineConfig, Config, validate_capture_format,
+    validate_filename_template,
+};
+
+/// Resets a non-finite value to its default and clamps the rest into range.
+fn clamp_pipeline_setting(value: &mut f64, default: f64, min: f64, max: f64, name: &str, id: &str) {
+    if !value.is_finite() {
+        log::warn!(
+            "Non-finite capture pipeline '{}' {} {:?}, resetting to {:.2}",
+            id,
+            name,
+            value,
+            default
+        );
+        *value = default;
+    } else if !(min..=max).contains(value) {
+        log::warn!(
+            "Invalid capture pipeline '{}' {} {:.2}, clamping to {:.2}-{:.2} range",
+            id,
+            name,
+            value,
+            min,
+            max
+        );
+        *value = value.clamp(min, max);
+    }
+}
+
+fn validate_pipeline(pipeline: &mut CapturePipelineConfig) {
+    let defaults = CapturePipelineConfig::named("");
+    let id = pipeline.id.clone();
+    if pipeline.padding > 512 {
+        log::warn!(
+            "Invalid capture pipeline '{}' padding {}, clamping to 512",
+            id,
+            pipeline.padding
+        );
+        pipeline.padding = 512;
+    }
+    clamp_pipeline_setting(
+        &mut pipeline.corner_radius,
+        defaults.corner_radius,
+        0.0,
+        256.0,
+        "corner_radius",
+        &id,
+    );
+    clamp_pipeline_setting(
+        &mut pipeline.border_width,
+        defaults.border_width,
+        0.0,
+        64.0,
+        "border_width",
+        &id,
+    );
+    clamp_pipeline_setting(
+        &mut pipeline.shadow_radius,
+        defaults.shadow_radius,
+        0.0,
+        128.0,
+        "shadow_radius",
+        &id,
+    );
+    clamp_pipeline_setting(
+        &mut pipeline.shadow_offset_y,
+        defaults.shadow_offset_y,
+        -128.0,
+        128.0,
+        "shadow_offset_y",
+        &id,
+    );
+    clamp_pipeline_setting(
+        &mut pipeline.watermark_opacity,
+        defaults.watermark_opacity,
+        0.0,
+        1.0,
+        "watermark_opacity",
+        &id,
+    );
+    clamp_pipeline_setting(
+        &mut pipeline.watermark_size,
+        defaults.watermark_size,
+        6.0,
+        200.0,
+        "watermark_size",
+        &id,
+    );
+    if pipeline
+        .watermark_text
+        .as_deref()
+        .is_some_and(|text| text.trim().is_empty())
+    {
+        pipeline.watermark_text = None;
+    }
+    if pipeline
+        .watermark_image
+        .as_deref()
+        .is_some_and(|path| path.trim().is_empty())
+    {
+        pipeline.watermark_image = None;
+    }
+}
 
 impl Config {
     pub(super) fn validate_capture(&mut self) {
@@ -13,5 +117,65 @@ impl Config {
                 self.capture.format = CaptureConfig::default().format;
             }
         }
+        match validate_capture_format(&self.capture.canvas_export_format) {
+            Ok(format) => self.capture.canvas_export_format = format,
+            Err(reason) => {
+                log::warn!("Invalid capture.canvas_export_format ({reason}); resetting to png");
+                self.capture.canvas_export_format = CaptureConfig::default().canvas_export_format;
+            }
+        }
+        for (name, quality) in [
+            ("jpeg_quality", &mut self.capture.jpeg_quality),
+            (
+                "webp_near_lossless_level",
+                &mut self.capture.webp_near_lossless_level,
+            ),
+        ] {
+            if !(1..=100).contains(quality) {
+                log::warn!(
+                    "Invalid capture.{} {}, clamping to 1-100 range",
+                    name,
+                    quality
+                );
+                *quality = (*quality).clamp(1, 100);
+            }
+        }
+        self.validate_capture_pipelines();
+    }
+
+    fn validate_capture_pipelines(&mut self) {
+        let mut seen = HashSet::new();
+        self.capture.pipelines.retain_mut(|pipeline| {
+            pipeline.id = pipeline.id.trim().to_string();
+            if pipeline.id.is_empty() {
+                log::warn!("Ignoring capture pipeline without an id");
+                return false;
+            }
+            if !seen.insert(pipeline.id.clone()) {
+                log::warn!("Ignoring duplicate capture pipeline '{}'", pipeline.id);
+                return false;
+            }
+            validate_pipeline(pipeline);
+            true
+        });
+
+        let pipelines = &self.capture.pipelines;
+        for (kind, selection) in self.capture.postprocess.selections_mut() {
+            let Some(id) = selection.as_deref().map(str::trim) else {
+                continue;
+            };
+            if id.is_empty() {
+                *selection = None;
+            } else if pipelines.iter().any(|pipeline| pipeline.id == id) {
+                *selection = Some(id.to_string());
+            } else {
+                log::warn!(
+                    "Unknown capture pipeline '{}' for capture.postprocess.{}; delivering captures unchanged",
+                    id,
+                    kind
+                );
+                *selection = None;
+            }
+        }
     }
 }
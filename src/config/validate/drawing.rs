@@ -2,6 +2,8 @@ use super::Config;
 use crate::draw::shape::{REGULAR_POLYGON_MAX_SIDES, REGULAR_POLYGON_MIN_SIDES};
 use crate::input::state::{MAX_STROKE_THICKNESS, MIN_STROKE_THICKNESS};
 
+const MAX_SMART_INK_HOLD_MS: u64 = 5000;
+
 impl Config {
     pub(super) fn validate_drawing(&mut self) {
         // Thickness: 1.0 - 50.0
@@ -68,6 +70,27 @@ impl Config {
                 .clamp(REGULAR_POLYGON_MIN_SIDES, REGULAR_POLYGON_MAX_SIDES);
         }
 
+        if self.drawing.smart_ink_hold_ms > MAX_SMART_INK_HOLD_MS {
+            log::warn!(
+                "Invalid smart_ink_hold_ms {}, clamping to {}",
+                self.drawing.smart_ink_hold_ms,
+                MAX_SMART_INK_HOLD_MS
+            );
+            self.drawing.smart_ink_hold_ms = MAX_SMART_INK_HOLD_MS;
+        }
+
+        if !(0.0..=1.0).contains(&self.drawing.stabilizer_strength) {
+            log::warn!(
+                "Invalid stabilizer_strength {:.2}, clamping to 0.0-1.0 range",
+                self.drawing.stabilizer_strength
+            );
+            self.drawing.stabilizer_strength = if self.drawing.stabilizer_strength.is_nan() {
+                0.0
+            } else {
+                self.drawing.stabilizer_strength.clamp(0.0, 1.0)
+            };
+        }
+
         if !(1.0..=20.0).contains(&self.drawing.hit_test_tolerance) {
             log::warn!(
                 "Invalid hit_test_tolerance {:.1}, clamping to 1.0-20.0 range",
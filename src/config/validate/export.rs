@@ -1,6 +1,7 @@
 use super::super::{
-    Config, PDF_LABEL_DEFAULT_TEMPLATE, PdfLabelConfig, PdfLabelContentMode,
-    validate_filename_template, validate_pdf_label_template,
+    Config, PDF_LABEL_DEFAULT_TEMPLATE, PdfLabelConfig, PdfLabelContentMode, RECORDING_FPS_MAX,
+    RECORDING_MAX_DURATION_SECS_MAX, REPLAY_FRAMES_PER_SHAPE_MAX, validate_filename_template,
+    validate_pdf_label_template,
 };
 
 const PDF_DIMENSION_MIN: f64 = 1.0;
@@ -43,6 +44,56 @@ impl Config {
             &mut self.export.pdf.all_boards_filename_template,
             "export.pdf.all_boards_filename_template",
         );
+        sanitize_optional_filename_template(
+            &mut self.export.svg.filename_template,
+            "export.svg.filename_template",
+        );
+        sanitize_optional_filename_template(
+            &mut self.export.svg.board_filename_template,
+            "export.svg.board_filename_template",
+        );
+        sanitize_optional_filename_template(
+            &mut self.export.replay.filename_template,
+            "export.replay.filename_template",
+        );
+        let frames_per_shape = self.export.replay.frames_per_shape;
+        let clamped = frames_per_shape.clamp(1, REPLAY_FRAMES_PER_SHAPE_MAX);
+        if clamped != frames_per_shape {
+            log::warn!(
+                "Clamping export.replay.frames_per_shape from {frames_per_shape} to {clamped}"
+            );
+            self.export.replay.frames_per_shape = clamped;
+        }
+        self.validate_export_recording();
+    }
+
+    fn validate_export_recording(&mut self) {
+        let recording = &mut self.export.recording;
+        sanitize_optional_filename_template(
+            &mut recording.filename_template,
+            "export.recording.filename_template",
+        );
+        let fps = recording.fps;
+        let clamped = fps.clamp(1, RECORDING_FPS_MAX);
+        if clamped != fps {
+            log::warn!("Clamping export.recording.fps from {fps} to {clamped}");
+            recording.fps = clamped;
+        }
+        let max_duration = recording.max_duration_secs;
+        let clamped = max_duration.clamp(1, RECORDING_MAX_DURATION_SECS_MAX);
+        if clamped != max_duration {
+            log::warn!(
+                "Clamping export.recording.max_duration_secs from {max_duration} to {clamped}"
+            );
+            recording.max_duration_secs = clamped;
+        }
+        if recording
+            .region
+            .is_some_and(|region| region.width == 0 || region.height == 0)
+        {
+            log::warn!("Ignoring export.recording.region with zero width or height");
+            recording.region = None;
+        }
     }
 }
 
@@ -0,0 +1,68 @@
+use super::Config;
+use crate::config::LaserConfig;
+
+impl Config {
+    pub(super) fn validate_laser(&mut self) {
+        let defaults = LaserConfig::default();
+
+        for i in 0..4 {
+            let component = self.laser.color[i];
+            if !component.is_finite() {
+                log::warn!(
+                    "Non-finite laser color[{}] {:?}, resetting to {:.2}",
+                    i,
+                    component,
+                    defaults.color[i]
+                );
+                self.laser.color[i] = defaults.color[i];
+            } else if !(0.0..=1.0).contains(&component) {
+                log::warn!(
+                    "Invalid laser color[{}] = {:.3}, clamping to 0.0-1.0",
+                    i,
+                    component
+                );
+                self.laser.color[i] = component.clamp(0.0, 1.0);
+            }
+        }
+
+        // Width: 1.0 - 40.0. Thinner than a pixel the dot disappears; wider
+        // than 40 it hides what it points at.
+        if !self.laser.width.is_finite() {
+            log::warn!(
+                "Non-finite laser width {:?}, resetting to {:.1}",
+                self.laser.width,
+                defaults.width
+            );
+            self.laser.width = defaults.width;
+        } else if !(1.0..=40.0).contains(&self.laser.width) {
+            log::warn!(
+                "Invalid laser width {:.1}, clamping to 1.0-40.0 range",
+                self.laser.width
+            );
+            self.laser.width = self.laser.width.clamp(1.0, 40.0);
+        }
+
+        if !(100..=5000).contains(&self.laser.fade_ms) {
+            log::warn!(
+                "Invalid laser fade duration {}ms, clamping to 100-5000ms range",
+                self.laser.fade_ms
+            );
+            self.laser.fade_ms = self.laser.fade_ms.clamp(100, 5000);
+        }
+
+        if !self.laser.fade_ink_seconds.is_finite() {
+            log::warn!(
+                "Non-finite fade ink duration {:?}, resetting to {:.1}",
+                self.laser.fade_ink_seconds,
+                defaults.fade_ink_seconds
+            );
+            self.laser.fade_ink_seconds = defaults.fade_ink_seconds;
+        } else if !(0.5..=60.0).contains(&self.laser.fade_ink_seconds) {
+            log::warn!(
+                "Invalid fade ink duration {:.1}s, clamping to 0.5-60.0s range",
+                self.laser.fade_ink_seconds
+            );
+            self.laser.fade_ink_seconds = self.laser.fade_ink_seconds.clamp(0.5, 60.0);
+        }
+    }
+}
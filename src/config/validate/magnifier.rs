@@ -0,0 +1,63 @@
+use super::Config;
+use crate::config::{MAGNIFIER_MIN_MAGNIFICATION, MagnifierConfig};
+
+/// Resets a non-finite value to its default and clamps the rest into range.
+fn clamp_setting(value: &mut f64, default: f64, min: f64, max: f64, name: &str) {
+    if !value.is_finite() {
+        log::warn!(
+            "Non-finite magnifier {} {:?}, resetting to {:.2}",
+            name,
+            value,
+            default
+        );
+        *value = default;
+    } else if !(min..=max).contains(value) {
+        log::warn!(
+            "Invalid magnifier {} {:.2}, clamping to {:.2}-{:.2} range",
+            name,
+            value,
+            min,
+            max
+        );
+        *value = value.clamp(min, max);
+    }
+}
+
+impl Config {
+    pub(super) fn validate_magnifier(&mut self) {
+        let defaults = MagnifierConfig::default();
+        let magnifier = &mut self.magnifier;
+
+        clamp_setting(&mut magnifier.size, defaults.size, 80.0, 800.0, "size");
+        clamp_setting(
+            &mut magnifier.aspect_ratio,
+            defaults.aspect_ratio,
+            1.0,
+            4.0,
+            "aspect_ratio",
+        );
+        clamp_setting(
+            &mut magnifier.max_magnification,
+            defaults.max_magnification,
+            2.0,
+            16.0,
+            "max_magnification",
+        );
+        // The opening magnification is clamped after the maximum so a lowered
+        // maximum also caps it.
+        clamp_setting(
+            &mut magnifier.magnification,
+            defaults.magnification.min(magnifier.max_magnification),
+            MAGNIFIER_MIN_MAGNIFICATION,
+            magnifier.max_magnification,
+            "magnification",
+        );
+        clamp_setting(
+            &mut magnifier.zoom_step,
+            defaults.zoom_step,
+            1.05,
+            2.0,
+            "zoom_step",
+        );
+    }
+}
@@ -9,6 +9,8 @@ mod export;
 mod fonts;
 mod history;
 mod keybindings;
+mod laser;
+mod magnifier;
 mod performance;
 mod presets;
 mod render_profiles;
@@ -16,6 +18,7 @@ mod session;
 mod spotlight;
 #[cfg(feature = "tablet-input")]
 mod tablet;
+mod timer;
 mod ui;
 mod updates;
 
@@ -62,6 +65,16 @@ impl Config {
     /// - `arrow.angle_degrees`: 15.0 - 60.0
     /// - `spotlight.dim_opacity`: 0.1 - 0.95
     /// - `spotlight.feather`: 0.0 - 0.9
+    /// - `laser.width`: 1.0 - 40.0
+    /// - `laser.fade_ms`: 100 - 5000
+    /// - `laser.fade_ink_seconds`: 0.5 - 60.0
+    /// - `magnifier.size`: 80.0 - 800.0
+    /// - `magnifier.aspect_ratio`: 1.0 - 4.0
+    /// - `magnifier.max_magnification`: 2.0 - 16.0
+    /// - `magnifier.magnification`: 1.25 - `magnifier.max_magnification`
+    /// - `magnifier.zoom_step`: 1.05 - 2.0
+    /// - `timer.break_minutes`: 1 - 240
+    /// - `timer.talk_minutes`: 1 - 240
     /// - `buffer_count`: 2 - 4
     ///
     /// Returns what the user should be told about: a clamp is a silent
@@ -75,9 +88,12 @@ impl Config {
         self.validate_history();
         self.validate_arrow();
         self.validate_spotlight();
+        self.validate_laser();
+        self.validate_magnifier();
         self.validate_performance();
         self.validate_fonts();
         self.validate_boards();
+        self.validate_timer();
         self.validate_board();
         self.validate_ui();
         self.validate_render_profiles();
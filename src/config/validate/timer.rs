@@ -0,0 +1,50 @@
+use super::Config;
+
+impl Config {
+    pub(super) fn validate_timer(&mut self) {
+        // Countdown lengths: 1 - 240 minutes. Zero would finish on start, and
+        // anything past four hours is no longer a break or a talk.
+        if !(1..=240).contains(&self.timer.break_minutes) {
+            log::warn!(
+                "Invalid timer break_minutes {}, clamping to 1-240 range",
+                self.timer.break_minutes
+            );
+            self.timer.break_minutes = self.timer.break_minutes.clamp(1, 240);
+        }
+        if !(1..=240).contains(&self.timer.talk_minutes) {
+            log::warn!(
+                "Invalid timer talk_minutes {}, clamping to 1-240 range",
+                self.timer.talk_minutes
+            );
+            self.timer.talk_minutes = self.timer.talk_minutes.clamp(1, 240);
+        }
+
+        if self
+            .timer
+            .sound_file
+            .as_deref()
+            .is_some_and(|path| path.trim().is_empty())
+        {
+            self.timer.sound_file = None;
+        }
+
+        // Board ids are normalized to trimmed lowercase by `validate_boards`,
+        // which runs first, so the break board is matched the same way.
+        let break_board = self.timer.break_board.trim().to_lowercase();
+        if !break_board.is_empty()
+            && !self
+                .resolved_boards()
+                .items
+                .iter()
+                .any(|item| item.id == break_board)
+        {
+            log::warn!(
+                "Timer break_board '{}' is not a configured board; the break timer will keep the current board",
+                self.timer.break_board
+            );
+            self.timer.break_board = String::new();
+            return;
+        }
+        self.timer.break_board = break_board;
+    }
+}
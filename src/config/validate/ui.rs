@@ -2,6 +2,21 @@ use super::Config;
 
 impl Config {
     pub(super) fn validate_ui(&mut self) {
+        let language = self.ui.language.trim();
+        if !language.is_empty()
+            && language != crate::i18n::AUTO_LANGUAGE
+            && !crate::i18n::is_supported_language(language)
+        {
+            log::warn!(
+                "Unsupported ui.language '{}' (bundled: {}), falling back to 'auto'",
+                self.ui.language,
+                crate::i18n::supported_languages()
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            self.ui.language = crate::i18n::AUTO_LANGUAGE.to_string();
+        }
+
         // Validate click highlight settings
         if !(16.0..=160.0).contains(&self.ui.click_highlight.radius) {
             log::warn!(
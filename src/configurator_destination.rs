@@ -90,6 +90,7 @@ pub fn keybindings_section_for_action(action: Action) -> Option<KeybindingsSecti
         Action::EnterTextMode
         | Action::EnterStickyNoteMode
         | Action::ClearCanvas
+        | Action::ClearActiveLayer
         | Action::IncreaseThickness
         | Action::DecreaseThickness
         | Action::IncreaseMarkerOpacity
@@ -97,6 +98,7 @@ pub fn keybindings_section_for_action(action: Action) -> Option<KeybindingsSecti
         | Action::IncreaseFontSize
         | Action::DecreaseFontSize
         | Action::ToggleFill
+        | Action::ToggleConnectorMode
         | Action::SetColorRed
         | Action::SetColorGreen
         | Action::SetColorBlue
@@ -124,8 +126,11 @@ pub fn keybindings_section_for_action(action: Action) -> Option<KeybindingsSecti
         | Action::SelectBlurTool
         | Action::SelectSpotlightTool
         | Action::CycleBlurStyle
+        | Action::CycleLineStyle
         | Action::SelectHighlightTool
         | Action::ToggleHighlightTool
+        | Action::SelectLaserTool
+        | Action::ToggleFadeInk
         | Action::ResetArrowLabelCounter
         | Action::ResetStepMarkerCounter => KeybindingsSection::Tools,
         Action::DuplicateSelection
@@ -134,10 +139,22 @@ pub fn keybindings_section_for_action(action: Action) -> Option<KeybindingsSecti
         | Action::SelectAll
         | Action::MoveSelectionToFront
         | Action::MoveSelectionToBack
+        | Action::MoveSelectionToLayerAbove
+        | Action::MoveSelectionToLayerBelow
+        | Action::GroupSelection
+        | Action::UngroupSelection
         | Action::MoveSelectionToStart
         | Action::MoveSelectionToEnd
         | Action::MoveSelectionToTop
         | Action::MoveSelectionToBottom
+        | Action::AlignSelectionLeft
+        | Action::AlignSelectionCenter
+        | Action::AlignSelectionRight
+        | Action::AlignSelectionTop
+        | Action::AlignSelectionMiddle
+        | Action::AlignSelectionBottom
+        | Action::DistributeSelectionHorizontally
+        | Action::DistributeSelectionVertically
         | Action::NudgeSelectionUp
         | Action::NudgeSelectionDown
         | Action::NudgeSelectionLeft
@@ -159,6 +176,8 @@ pub fn keybindings_section_for_action(action: Action) -> Option<KeybindingsSecti
         | Action::PageNew
         | Action::PageDuplicate
         | Action::PageDelete
+        | Action::ToggleGrid
+        | Action::ToggleSnapToGrid
         | Action::Board1
         | Action::Board2
         | Action::Board3
@@ -190,6 +209,14 @@ pub fn keybindings_section_for_action(action: Action) -> Option<KeybindingsSecti
         | Action::ToggleRadialMenu
         | Action::CycleToolbarDisplay
         | Action::TogglePresenterMode
+        | Action::ToggleReplay
+        | Action::StartBreakTimer
+        | Action::StartTalkTimer
+        | Action::StartStopwatch
+        | Action::ToggleTimerPause
+        | Action::ResetTimer
+        | Action::TimerAddMinute
+        | Action::ToggleTimerDisplay
         | Action::RenderProfileNext
         | Action::RenderProfilePrevious
         | Action::RenderProfileOff
@@ -210,6 +237,10 @@ pub fn keybindings_section_for_action(action: Action) -> Option<KeybindingsSecti
         | Action::ExportCanvasClipboardAndFile
         | Action::ExportBoardPdfFile
         | Action::ExportAllBoardsPdfFile
+        | Action::ExportCanvasSvgFile
+        | Action::ExportBoardSvgFile
+        | Action::ExportReplayFrames
+        | Action::ToggleRecording
         | Action::OpenCaptureFolder
         | Action::CopyTextFromScreen
         | Action::ToggleFrozenMode
@@ -217,7 +248,10 @@ pub fn keybindings_section_for_action(action: Action) -> Option<KeybindingsSecti
         | Action::ZoomOut
         | Action::ResetZoom
         | Action::ToggleZoomLock
-        | Action::RefreshZoomCapture => KeybindingsSection::CaptureView,
+        | Action::RefreshZoomCapture
+        | Action::ToggleMagnifier
+        | Action::MagnifierZoomIn
+        | Action::MagnifierZoomOut => KeybindingsSection::CaptureView,
         Action::ApplyPreset1
         | Action::ApplyPreset2
         | Action::ApplyPreset3
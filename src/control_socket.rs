@@ -0,0 +1,38 @@
+//! Scriptable control channel for a running overlay.
+//!
+//! The overlay listens on a Unix socket under `XDG_RUNTIME_DIR`
+//! (`$XDG_RUNTIME_DIR/wayscriber/control.sock`, see
+//! [`crate::paths::control_socket_path`]) and speaks JSON lines: every request
+//! is one JSON object on its own line and is answered by exactly one JSON
+//! object on its own line. `wayscriber ctl` is the bundled client, but anything
+//! that can write to a Unix socket — `socat`, a shell script, a Stream Deck
+//! plugin — drives the overlay the same way.
+//!
+//! Requests are decoded on the listener's threads and applied on the event
+//! loop, so a scripted change takes the same `InputState` path as the key press
+//! or palette entry it stands in for and never races the renderer.
+//!
+//! A `subscribe` request turns its connection into an event stream: one
+//! [`ControlEvent`] line whenever the tool, color, size, board, page, or a
+//! freeze/zoom/presenter mode changes, or a capture is saved.
+//! `wayscriber --watch` follows that stream across overlay restarts and prints
+//! it in the shape a waybar `custom` module reads.
+
+mod apply;
+mod client;
+mod events;
+mod protocol;
+mod server;
+mod watch;
+
+pub(crate) use apply::{apply_request, current_status};
+pub(crate) use client::run_ctl;
+pub(crate) use protocol::{
+    BoardTarget, ControlEvent, ControlEventKind, ControlRequest, parse_action_name,
+    parse_control_color, parse_tool_name,
+};
+pub(crate) use server::ControlServer;
+pub(crate) use watch::run_watch;
+
+#[cfg(test)]
+mod tests;
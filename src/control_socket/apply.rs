@@ -0,0 +1,140 @@
+use crate::input::InputState;
+use crate::input::state::color_to_hex;
+use crate::ui::StatusModel;
+
+use super::protocol::{
+    BoardTarget, ControlRequest, ControlResponse, ControlStatus, OverlayStatus, parse_action_name,
+    parse_control_color, parse_tool_name,
+};
+
+/// Applies one request to the live input state and answers it.
+///
+/// Every mutation goes through the method the matching key, palette entry, or
+/// toolbar control already uses, so side effects such as session dirtiness,
+/// toasts, and toolbar persistence stay identical to doing it by hand.
+pub(crate) fn apply_request(input: &mut InputState, request: ControlRequest) -> ControlResponse {
+    match apply(input, request) {
+        Ok(()) => ControlResponse::success(current_status(input)),
+        Err(error) => ControlResponse::failure(error),
+    }
+}
+
+fn apply(input: &mut InputState, request: ControlRequest) -> Result<(), String> {
+    match request {
+        ControlRequest::Action { name } => {
+            let action =
+                parse_action_name(&name).ok_or_else(|| format!("unknown action '{name}'"))?;
+            input.handle_action(action);
+        }
+        ControlRequest::SetColor { color } => {
+            let parsed =
+                parse_control_color(&color).ok_or_else(|| format!("invalid color '{color}'"))?;
+            input.set_color(parsed);
+        }
+        ControlRequest::SetThickness { thickness } => {
+            if !thickness.is_finite() || thickness <= 0.0 {
+                return Err(format!("invalid thickness {thickness}"));
+            }
+            input.set_thickness_for_active_tool(thickness);
+        }
+        ControlRequest::SetTool { tool } => {
+            let parsed = parse_tool_name(&tool).ok_or_else(|| format!("unknown tool '{tool}'"))?;
+            // Prefer the tool's own select action: picking the highlight tool,
+            // for one, also switches the click highlight on.
+            match parsed.action() {
+                Some(action) => input.handle_action(action),
+                None => {
+                    input.set_tool_override(Some(parsed));
+                }
+            }
+            if input.active_tool() != parsed {
+                return Err(format!("tool '{tool}' is locked out right now"));
+            }
+        }
+        ControlRequest::SwitchBoard { board } => switch_board(input, board)?,
+        ControlRequest::SwitchPage { page } => {
+            let count = input.boards.page_count();
+            if page == 0 || page > count {
+                return Err(format!("page {page} is out of range (1-{count})"));
+            }
+            input.switch_to_page(page - 1);
+        }
+        ControlRequest::ImportBackground { path } => {
+            if !path.is_absolute() {
+                return Err(format!(
+                    "background path '{}' is not absolute",
+                    path.display()
+                ));
+            }
+            if !crate::page_import::is_supported_path(&path) {
+                return Err(format!(
+                    "'{}' is not a PDF, SVG, PNG, or JPEG file",
+                    path.display()
+                ));
+            }
+            if !path.is_file() {
+                return Err(format!("no such file '{}'", path.display()));
+            }
+            input.request_page_import(path);
+        }
+        ControlRequest::ClearBackground => {
+            if !input.clear_page_background() {
+                return Err("the active page has no imported background".to_string());
+            }
+        }
+        ControlRequest::Status | ControlRequest::Subscribe => {}
+    }
+    Ok(())
+}
+
+fn switch_board(input: &mut InputState, board: BoardTarget) -> Result<(), String> {
+    match board {
+        BoardTarget::Slot(slot) => {
+            if slot == 0 || slot > input.boards.max_count() {
+                return Err(format!(
+                    "board slot {slot} is out of range (1-{})",
+                    input.boards.max_count()
+                ));
+            }
+            input.switch_board_slot(slot - 1);
+            if input.boards.active_index() != slot - 1 {
+                return Err(format!("board slot {slot} is empty"));
+            }
+        }
+        BoardTarget::Id(id) => {
+            if !input.boards.has_board(&id) {
+                return Err(format!("unknown board '{id}'"));
+            }
+            input.switch_board_force(&id);
+        }
+    }
+    Ok(())
+}
+
+/// Status of a running overlay; only the overlay itself can answer this.
+pub(crate) fn current_status(input: &InputState) -> ControlStatus {
+    overlay_status(&StatusModel::new(input))
+}
+
+/// The status HUD's snapshot in its wire form.
+pub(super) fn overlay_status(status: &StatusModel) -> ControlStatus {
+    ControlStatus {
+        visible: true,
+        daemon: false,
+        overlay: Some(OverlayStatus {
+            tool: status.tool,
+            color: color_to_hex(status.color),
+            thickness: status.size,
+            board_id: status.board_id.clone(),
+            board_name: status.board_name.clone(),
+            board: status.board,
+            board_count: status.board_count,
+            page: status.page,
+            page_count: status.page_count,
+            frozen: status.frozen,
+            zoomed: status.zoomed,
+            presenter: status.presenter,
+            recording: status.recording,
+        }),
+    }
+}
@@ -0,0 +1,62 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use super::protocol::{ControlRequest, ControlResponse, ControlStatus, encode_line};
+
+/// A little longer than the server's own reply timeout, so a stuck overlay is
+/// reported by the overlay's answer rather than by the client giving up first.
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(6);
+
+/// `wayscriber ctl`: sends one request and prints the response line.
+///
+/// Exits 0 when the overlay accepted the request, 1 when it refused it, and 1
+/// with an error when no overlay is listening — except for `status`, where
+/// "not running" is an answer (`{"ok":true,"status":{"visible":false}}`, plus
+/// `"daemon":true` when a daemon can show it).
+pub(crate) fn run_ctl(request: ControlRequest) -> ExitCode {
+    let path = crate::paths::control_socket_path();
+    let response = match send_request(&path, &request) {
+        Ok(response) => response,
+        Err(err) if request == ControlRequest::Status && overlay_absent(&err) => ControlResponse {
+            ok: true,
+            error: None,
+            status: Some(ControlStatus::hidden_now()),
+        },
+        Err(err) if overlay_absent(&err) => {
+            ControlResponse::failure(format!("no overlay is running ({})", path.display()))
+        }
+        Err(err) => ControlResponse::failure(format!("control socket error: {err}")),
+    };
+    print!("{}", encode_line(&response));
+    if response.ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn overlay_absent(err: &io::Error) -> bool {
+    matches!(
+        err.kind(),
+        io::ErrorKind::NotFound | io::ErrorKind::ConnectionRefused
+    )
+}
+
+pub(super) fn send_request(path: &Path, request: &ControlRequest) -> io::Result<ControlResponse> {
+    let mut stream = UnixStream::connect(path)?;
+    stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+    stream.write_all(encode_line(request).as_bytes())?;
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    if line.trim().is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "overlay closed the connection without answering",
+        ));
+    }
+    serde_json::from_str(line.trim()).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
@@ -0,0 +1,27 @@
+use crate::ui::{StatusChange, StatusModel};
+
+use super::protocol::ControlEventKind;
+
+/// The events that take one published status to the next, in a fixed order.
+///
+/// Comparing the status HUD's snapshots rather than instrumenting every setter
+/// is what keeps the stream honest: a tool change made by a key, the toolbar, a
+/// preset, the palette, or a script all reach subscribers the same way, and a
+/// change that is undone within one event-loop pass is correctly never
+/// reported.
+pub(super) fn changes(previous: &StatusModel, next: &StatusModel) -> Vec<ControlEventKind> {
+    next.changes_since(previous)
+        .into_iter()
+        .map(|change| match change {
+            StatusChange::Tool => ControlEventKind::ToolChanged,
+            StatusChange::Color => ControlEventKind::ColorChanged,
+            StatusChange::Size => ControlEventKind::ThicknessChanged,
+            StatusChange::Board => ControlEventKind::BoardChanged,
+            StatusChange::Page => ControlEventKind::PageChanged,
+            StatusChange::Frozen => ControlEventKind::FreezeChanged,
+            StatusChange::Zoomed => ControlEventKind::ZoomChanged,
+            StatusChange::Presenter => ControlEventKind::PresenterChanged,
+            StatusChange::Recording => ControlEventKind::RecordingChanged,
+        })
+        .collect()
+}
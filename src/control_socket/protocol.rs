@@ -0,0 +1,209 @@
+use std::path::PathBuf;
+
+use serde::de::IntoDeserializer;
+use serde::de::value::Error as ValueError;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{Action, Color, Tool};
+
+/// One request line.
+///
+/// The `command` field selects the variant and the remaining fields are its
+/// arguments, e.g. `{"command":"action","name":"toggle_whiteboard"}` or
+/// `{"command":"switch_page","page":2}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlRequest {
+    /// Dispatch any bindable action by its `[keybindings]` name.
+    ///
+    /// Kept as a string rather than an [`Action`] so an unknown name is
+    /// answered with "unknown action" instead of serde's list of every variant.
+    Action { name: String },
+    /// Set the active tool's color: a named color or `#RRGGBB` / `#RRGGBBAA`.
+    SetColor { color: String },
+    /// Set the active tool's size in pixels (eraser size for the eraser).
+    SetThickness { thickness: f64 },
+    /// Select a drawing tool by its config name (`pen`, `regular-polygon`, ...).
+    SetTool { tool: String },
+    /// Switch to a board by 1-based slot or by id.
+    SwitchBoard { board: BoardTarget },
+    /// Switch to a 1-based page of the active board.
+    SwitchPage { page: usize },
+    /// Import a PDF, SVG, PNG, or JPEG file as page backgrounds on the active
+    /// board. The path must be absolute: the overlay does not share the
+    /// client's working directory.
+    ImportBackground { path: PathBuf },
+    /// Remove the imported background from the active page.
+    ClearBackground,
+    /// Change nothing; answer with the current status.
+    Status,
+    /// Answer with the current status, then keep the connection open and
+    /// stream one [`ControlEvent`] line per change until either side hangs up.
+    Subscribe,
+}
+
+/// A board addressed by its 1-based slot (`2`) or its id (`"whiteboard"`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum BoardTarget {
+    Slot(usize),
+    Id(String),
+}
+
+impl BoardTarget {
+    /// Reads a CLI argument: all digits is a slot, anything else an id.
+    pub fn parse(value: &str) -> Self {
+        match value.parse::<usize>() {
+            Ok(slot) => Self::Slot(slot),
+            Err(_) => Self::Id(value.to_string()),
+        }
+    }
+}
+
+/// One response line. Successful requests carry the status after they were
+/// applied, so a script never needs a second round trip to see the effect.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlResponse {
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<ControlStatus>,
+}
+
+impl ControlResponse {
+    pub fn success(status: ControlStatus) -> Self {
+        Self {
+            ok: true,
+            error: None,
+            status: Some(status),
+        }
+    }
+
+    pub fn failure(error: impl Into<String>) -> Self {
+        Self {
+            ok: false,
+            error: Some(error.into()),
+            status: None,
+        }
+    }
+}
+
+/// Overlay visibility plus, while it is up, what it is drawing with and where.
+///
+/// Only a running overlay owns the socket, so `visible: false` is what the
+/// client reports on its own when nobody answers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlStatus {
+    pub visible: bool,
+    /// While hidden, whether a daemon is running that can show the overlay
+    /// again, going by its runtime record.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub daemon: bool,
+    #[serde(flatten, default, skip_serializing_if = "Option::is_none")]
+    pub overlay: Option<OverlayStatus>,
+}
+
+impl ControlStatus {
+    pub fn hidden(daemon: bool) -> Self {
+        Self {
+            visible: false,
+            daemon,
+            overlay: None,
+        }
+    }
+
+    /// Hidden, with `daemon` read from the daemon's runtime record.
+    pub fn hidden_now() -> Self {
+        Self::hidden(crate::daemon::protocol_v2::runtime_record_is_live(
+            &crate::paths::daemon_pid_file(),
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverlayStatus {
+    pub tool: Tool,
+    /// Active tool color as `#RRGGBB`, widened to `#RRGGBBAA` when translucent.
+    pub color: String,
+    pub thickness: f64,
+    pub board_id: String,
+    pub board_name: String,
+    /// 1-based slot of the active board.
+    pub board: usize,
+    pub board_count: usize,
+    /// 1-based index of the active page.
+    pub page: usize,
+    pub page_count: usize,
+    pub frozen: bool,
+    pub zoomed: bool,
+    pub presenter: bool,
+    pub recording: bool,
+}
+
+/// What a [`ControlEvent`] reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ControlEventKind {
+    OverlayShown,
+    OverlayHidden,
+    ToolChanged,
+    ColorChanged,
+    ThicknessChanged,
+    BoardChanged,
+    PageChanged,
+    FreezeChanged,
+    ZoomChanged,
+    PresenterChanged,
+    RecordingChanged,
+    CaptureSaved,
+}
+
+/// One line of a subscription stream.
+///
+/// Every event carries the full status after the change, so a consumer that
+/// only renders state (a status bar module) can ignore `event` entirely.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ControlEvent {
+    pub event: ControlEventKind,
+    /// Where a capture or export was written, for `capture_saved`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    pub status: ControlStatus,
+}
+
+impl ControlEvent {
+    pub fn new(event: ControlEventKind, status: ControlStatus) -> Self {
+        Self {
+            event,
+            path: None,
+            status,
+        }
+    }
+}
+
+/// Resolves an action by the same snake_case name `[keybindings]` uses.
+pub fn parse_action_name(name: &str) -> Option<Action> {
+    Action::deserialize(IntoDeserializer::<ValueError>::into_deserializer(name)).ok()
+}
+
+/// Resolves a tool by the same kebab-case name the config uses.
+pub fn parse_tool_name(name: &str) -> Option<Tool> {
+    Tool::deserialize(IntoDeserializer::<ValueError>::into_deserializer(name)).ok()
+}
+
+/// Accepts the palette's color names and the hex forms the color picker takes.
+pub fn parse_control_color(value: &str) -> Option<Color> {
+    crate::util::name_to_color(value.trim()).or_else(|| crate::input::state::parse_hex_color(value))
+}
+
+pub(super) fn decode_request(line: &str) -> Result<ControlRequest, String> {
+    serde_json::from_str(line).map_err(|err| format!("invalid request: {err}"))
+}
+
+pub(super) fn encode_line<T: Serialize>(value: &T) -> String {
+    let mut line = serde_json::to_string(value)
+        .unwrap_or_else(|err| format!(r#"{{"ok":false,"error":"encode failed: {err}"}}"#));
+    line.push('\n');
+    line
+}
@@ -0,0 +1,384 @@
+use std::fs::{self, Permissions};
+use std::io::{self, BufRead, BufReader, ErrorKind, Read, Write};
+use std::os::unix::fs::{FileTypeExt, PermissionsExt};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{Receiver, Sender, SyncSender, channel, sync_channel};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use log::{debug, warn};
+
+use crate::ui::StatusModel;
+
+use super::apply::overlay_status;
+use super::events::changes;
+use super::protocol::{
+    ControlEvent, ControlEventKind, ControlRequest, ControlResponse, ControlStatus, decode_request,
+    encode_line,
+};
+
+/// How long a connection waits for the event loop to answer. A loop blocked
+/// this long is stuck behind something the caller cannot see, and the script
+/// is better told so than left hanging.
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest accepted request line. Real requests are well under 200 bytes; the
+/// cap only stops a stray writer from growing the buffer without bound.
+const MAX_REQUEST_LINE: u64 = 64 * 1024;
+/// Back-off after a failed `accept`, so descriptor exhaustion cannot spin.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(100);
+/// Connections served at once, each on its own thread. Scripts open one at a
+/// time and subscribers give theirs back once they are streaming, so this is
+/// only reached by a client that opens connections and never speaks.
+pub(super) const MAX_CONNECTIONS: usize = 16;
+
+/// How long the first write to a new subscriber may block. Later event writes
+/// never block the event loop at all; see [`ControlServer::publish`].
+const SUBSCRIBE_WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+type WakeFn = Arc<dyn Fn() + Send + Sync>;
+type Subscribers = Arc<Mutex<Vec<UnixStream>>>;
+
+/// A decoded request waiting for the event loop, with the way back to its
+/// connection.
+pub(crate) struct PendingControlRequest {
+    pub(crate) request: ControlRequest,
+    /// `None` tells the connection thread the event loop answered on the
+    /// stream itself and now owns it as a subscriber.
+    reply: SyncSender<Option<ControlResponse>>,
+    subscription: Option<(UnixStream, Subscribers)>,
+}
+
+impl PendingControlRequest {
+    /// Answers the connection. A client that hung up in the meantime is not an
+    /// error: the request was still applied.
+    ///
+    /// A subscription is answered from here, on the event loop, and joins the
+    /// subscriber list in the same step. Answering from the connection thread
+    /// instead would let an event published in between reach the client ahead
+    /// of the status it is a change to.
+    pub(crate) fn respond(self, response: ControlResponse) {
+        match self.subscription {
+            Some((stream, subscribers)) if response.ok => {
+                subscribe(stream, &subscribers, &response);
+                let _ = self.reply.send(None);
+            }
+            _ => {
+                let _ = self.reply.send(Some(response));
+            }
+        }
+    }
+}
+
+fn subscribe(mut stream: UnixStream, subscribers: &Subscribers, response: &ControlResponse) {
+    let accepted = stream
+        .set_write_timeout(Some(SUBSCRIBE_WRITE_TIMEOUT))
+        .and_then(|()| stream.write_all(encode_line(response).as_bytes()))
+        .and_then(|()| stream.set_nonblocking(true));
+    match accepted {
+        Ok(()) => lock(subscribers).push(stream),
+        Err(err) => debug!("Control subscriber left before its first line: {err}"),
+    }
+}
+
+fn lock(subscribers: &Subscribers) -> std::sync::MutexGuard<'_, Vec<UnixStream>> {
+    subscribers
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The event loop's end of the control socket.
+///
+/// A listener thread accepts connections and gives each its own thread, so a
+/// client that keeps its connection open cannot hold up the next one. At most
+/// [`MAX_CONNECTIONS`] are served at once; beyond that a connection is answered
+/// with an error line and closed. Decoded
+/// requests reach the loop through a channel plus the shared runtime wake;
+/// dropping the server tells subscribers the overlay is gone, stops the
+/// listener, and removes the socket file.
+pub(crate) struct ControlServer {
+    path: PathBuf,
+    requests: Receiver<PendingControlRequest>,
+    subscribers: Subscribers,
+    /// The status subscribers last saw, which the next one is diffed against.
+    /// Cleared while nobody listens so a new subscriber starts a fresh baseline.
+    published: Option<StatusModel>,
+    shutdown: Arc<AtomicBool>,
+    listener: Option<JoinHandle<()>>,
+}
+
+impl ControlServer {
+    /// Binds `path` (owner-only) and starts accepting connections.
+    ///
+    /// A socket file nobody answers on is left over from an overlay that did
+    /// not exit cleanly and is replaced. One that does answer belongs to a live
+    /// overlay and is left alone.
+    pub(crate) fn start(
+        path: PathBuf,
+        wake: impl Fn() + Send + Sync + 'static,
+    ) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        remove_stale_socket(&path)?;
+        let listener = UnixListener::bind(&path)?;
+        if let Err(err) = fs::set_permissions(&path, Permissions::from_mode(0o600)) {
+            let _ = fs::remove_file(&path);
+            return Err(err);
+        }
+
+        let (sender, requests) = channel();
+        let subscribers = Subscribers::default();
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let connections = Connections {
+            sender,
+            wake: Arc::new(wake),
+            subscribers: Arc::clone(&subscribers),
+            active: Arc::default(),
+        };
+        let accept_shutdown = Arc::clone(&shutdown);
+        let spawned = thread::Builder::new()
+            .name("control-socket".to_string())
+            .spawn(move || accept_loop(listener, connections, accept_shutdown));
+        let listener = match spawned {
+            Ok(handle) => handle,
+            Err(err) => {
+                let _ = fs::remove_file(&path);
+                return Err(err);
+            }
+        };
+
+        Ok(Self {
+            path,
+            requests,
+            subscribers,
+            published: None,
+            shutdown,
+            listener: Some(listener),
+        })
+    }
+
+    /// Takes the next request without blocking.
+    pub(crate) fn try_recv(&self) -> Option<PendingControlRequest> {
+        self.requests.try_recv().ok()
+    }
+
+    pub(crate) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Streams the changes between the last published status and the current
+    /// one. `status` is only evaluated while somebody is subscribed.
+    pub(crate) fn publish_changes(&mut self, status: impl FnOnce() -> StatusModel) {
+        if lock(&self.subscribers).is_empty() {
+            self.published = None;
+            return;
+        }
+        let next = status();
+        if let Some(previous) = self.published.as_ref() {
+            let kinds = changes(previous, &next);
+            if !kinds.is_empty() {
+                let status = overlay_status(&next);
+                for kind in kinds {
+                    self.publish(&ControlEvent::new(kind, status.clone()));
+                }
+            }
+        }
+        self.published = Some(next);
+    }
+
+    /// Writes one event line to every subscriber.
+    ///
+    /// Subscriber sockets are non-blocking: a consumer that stops reading
+    /// until its socket buffer fills is disconnected rather than allowed to
+    /// stall drawing. It can reconnect and pick up from a fresh status.
+    pub(crate) fn publish(&self, event: &ControlEvent) {
+        let line = encode_line(event);
+        lock(&self.subscribers).retain_mut(|stream| match stream.write_all(line.as_bytes()) {
+            Ok(()) => true,
+            Err(err) => {
+                debug!("Dropping control subscriber: {err}");
+                false
+            }
+        });
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        self.publish(&ControlEvent::new(
+            ControlEventKind::OverlayHidden,
+            ControlStatus::hidden_now(),
+        ));
+        lock(&self.subscribers).clear();
+        self.shutdown.store(true, Ordering::SeqCst);
+        // `accept` has no timeout; a throwaway connection is what lets the
+        // listener see the flag.
+        let _ = UnixStream::connect(&self.path);
+        if let Some(listener) = self.listener.take()
+            && listener.join().is_err()
+        {
+            warn!("Control socket listener panicked");
+        }
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+fn remove_stale_socket(path: &Path) -> io::Result<()> {
+    let metadata = match fs::symlink_metadata(path) {
+        Ok(metadata) => metadata,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} exists and is not a socket", path.display()),
+        ));
+    }
+    if UnixStream::connect(path).is_ok() {
+        return Err(io::Error::new(
+            ErrorKind::AddrInUse,
+            format!("another overlay is listening on {}", path.display()),
+        ));
+    }
+    fs::remove_file(path)
+}
+
+/// What every connection thread needs to reach the event loop.
+#[derive(Clone)]
+struct Connections {
+    sender: Sender<PendingControlRequest>,
+    wake: WakeFn,
+    subscribers: Subscribers,
+    /// Connection threads currently running.
+    active: Arc<AtomicUsize>,
+}
+
+/// One connection thread's claim on [`MAX_CONNECTIONS`], returned when the
+/// thread ends however it ends.
+struct ConnectionSlot(Arc<AtomicUsize>);
+
+impl ConnectionSlot {
+    fn claim(active: &Arc<AtomicUsize>) -> Option<Self> {
+        active
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |count| {
+                (count < MAX_CONNECTIONS).then_some(count + 1)
+            })
+            .ok()
+            .map(|_| Self(Arc::clone(active)))
+    }
+}
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn accept_loop(listener: UnixListener, connections: Connections, shutdown: Arc<AtomicBool>) {
+    for stream in listener.incoming() {
+        if shutdown.load(Ordering::SeqCst) {
+            break;
+        }
+        match stream {
+            Ok(stream) => {
+                let Some(slot) = ConnectionSlot::claim(&connections.active) else {
+                    reject(stream);
+                    continue;
+                };
+                let connections = connections.clone();
+                let spawned = thread::Builder::new()
+                    .name("control-client".to_string())
+                    .spawn(move || {
+                        let _slot = slot;
+                        if let Err(err) = serve_connection(stream, &connections) {
+                            debug!("Control connection closed: {err}");
+                        }
+                    });
+                if let Err(err) = spawned {
+                    warn!("Failed to start control connection thread: {err}");
+                }
+            }
+            Err(err) => {
+                warn!("Control socket accept failed: {err}");
+                thread::sleep(ACCEPT_RETRY_DELAY);
+            }
+        }
+    }
+}
+
+/// Turns away a connection over [`MAX_CONNECTIONS`]. The error line fits any
+/// socket buffer, so the write never has to wait and never holds up `accept`.
+fn reject(mut stream: UnixStream) {
+    let response = ControlResponse::failure("too many control connections");
+    let rejected = stream
+        .set_nonblocking(true)
+        .and_then(|()| stream.write_all(encode_line(&response).as_bytes()));
+    if let Err(err) = rejected {
+        debug!("Rejected control connection left early: {err}");
+    }
+}
+
+fn serve_connection(stream: UnixStream, connections: &Connections) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = stream;
+    loop {
+        let mut line = String::new();
+        let read = (&mut reader)
+            .take(MAX_REQUEST_LINE + 1)
+            .read_line(&mut line)?;
+        if read == 0 {
+            return Ok(());
+        }
+        if read as u64 > MAX_REQUEST_LINE {
+            let response = ControlResponse::failure("request line too long");
+            writer.write_all(encode_line(&response).as_bytes())?;
+            return Ok(());
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let response = match decode_request(line) {
+            Ok(request) => {
+                let subscription = match request {
+                    ControlRequest::Subscribe => {
+                        Some((writer.try_clone()?, Arc::clone(&connections.subscribers)))
+                    }
+                    _ => None,
+                };
+                match forward(request, subscription, connections) {
+                    Some(response) => response,
+                    // The event loop answered and now streams to this client;
+                    // anything else it sends is not read.
+                    None => return Ok(()),
+                }
+            }
+            Err(error) => ControlResponse::failure(error),
+        };
+        writer.write_all(encode_line(&response).as_bytes())?;
+    }
+}
+
+fn forward(
+    request: ControlRequest,
+    subscription: Option<(UnixStream, Subscribers)>,
+    connections: &Connections,
+) -> Option<ControlResponse> {
+    let (reply, response) = sync_channel(1);
+    let pending = PendingControlRequest {
+        request,
+        reply,
+        subscription,
+    };
+    if connections.sender.send(pending).is_err() {
+        return Some(ControlResponse::failure("overlay is shutting down"));
+    }
+    (connections.wake)();
+    response
+        .recv_timeout(REPLY_TIMEOUT)
+        .unwrap_or_else(|_| Some(ControlResponse::failure("overlay did not answer in time")))
+}
@@ -0,0 +1,441 @@
+use std::time::{Duration, Instant};
+
+use super::apply::apply_request;
+use super::client::send_request;
+use super::events::changes;
+use super::protocol::{
+    BoardTarget, ControlEvent, ControlEventKind, ControlRequest, ControlResponse, ControlStatus,
+    decode_request, encode_line, parse_action_name, parse_control_color, parse_tool_name,
+};
+use super::server::{ControlServer, MAX_CONNECTIONS};
+use super::watch::watch_lines;
+use crate::domain::{Action, Tool};
+use crate::input::state::test_support::make_test_input_state;
+use crate::ui::StatusModel;
+
+#[test]
+fn requests_decode_from_their_wire_form() {
+    assert_eq!(
+        decode_request(r#"{"command":"action","name":"toggle_whiteboard"}"#).unwrap(),
+        ControlRequest::Action {
+            name: "toggle_whiteboard".to_string()
+        }
+    );
+    assert_eq!(
+        decode_request(r#"{"command":"switch_board","board":2}"#).unwrap(),
+        ControlRequest::SwitchBoard {
+            board: BoardTarget::Slot(2)
+        }
+    );
+    assert_eq!(
+        decode_request(r#"{"command":"switch_board","board":"blackboard"}"#).unwrap(),
+        ControlRequest::SwitchBoard {
+            board: BoardTarget::Id("blackboard".to_string())
+        }
+    );
+    assert_eq!(
+        decode_request(r#"{"command":"status"}"#).unwrap(),
+        ControlRequest::Status
+    );
+    assert!(decode_request(r#"{"command":"launch_rockets"}"#).is_err());
+    assert!(decode_request("not json").is_err());
+}
+
+#[test]
+fn names_resolve_like_the_config_file() {
+    assert_eq!(parse_action_name("undo"), Some(Action::Undo));
+    assert_eq!(parse_action_name("board_1"), Some(Action::Board1));
+    assert_eq!(parse_action_name("Undo"), None);
+    assert_eq!(
+        parse_tool_name("regular-polygon"),
+        Some(Tool::RegularPolygon)
+    );
+    assert_eq!(parse_tool_name("regular_polygon"), None);
+    assert!(parse_control_color("red").is_some());
+    assert!(parse_control_color("#11223380").is_some());
+    assert!(parse_control_color("not-a-color").is_none());
+}
+
+#[test]
+fn hidden_status_is_a_single_visible_flag() {
+    let response = ControlResponse {
+        ok: true,
+        error: None,
+        status: Some(ControlStatus::hidden(false)),
+    };
+    assert_eq!(
+        encode_line(&response),
+        "{\"ok\":true,\"status\":{\"visible\":false}}\n"
+    );
+    assert_eq!(
+        encode_line(&ControlStatus::hidden(true)),
+        "{\"visible\":false,\"daemon\":true}\n"
+    );
+}
+
+#[test]
+fn setters_apply_and_report_the_resulting_status() {
+    let mut input = make_test_input_state();
+
+    let response = apply_request(
+        &mut input,
+        ControlRequest::SetTool {
+            tool: "marker".to_string(),
+        },
+    );
+    assert!(response.ok, "{response:?}");
+    assert_eq!(input.active_tool(), Tool::Marker);
+
+    let response = apply_request(
+        &mut input,
+        ControlRequest::SetColor {
+            color: "#00FF00".to_string(),
+        },
+    );
+    let overlay = response.status.unwrap().overlay.unwrap();
+    assert_eq!(overlay.tool, Tool::Marker);
+    assert_eq!(overlay.color, "#00FF00");
+
+    let response = apply_request(&mut input, ControlRequest::SetThickness { thickness: 12.0 });
+    assert_eq!(response.status.unwrap().overlay.unwrap().thickness, 12.0);
+}
+
+#[test]
+fn boards_and_pages_switch_by_one_based_position_or_id() {
+    let mut input = make_test_input_state();
+
+    let response = apply_request(
+        &mut input,
+        ControlRequest::SwitchBoard {
+            board: BoardTarget::Id("whiteboard".to_string()),
+        },
+    );
+    let overlay = response.status.unwrap().overlay.unwrap();
+    assert_eq!(overlay.board_id, "whiteboard");
+    assert_eq!(overlay.board, 2);
+
+    let response = apply_request(
+        &mut input,
+        ControlRequest::SwitchBoard {
+            board: BoardTarget::Slot(1),
+        },
+    );
+    assert_eq!(response.status.unwrap().overlay.unwrap().board, 1);
+
+    let response = apply_request(
+        &mut input,
+        ControlRequest::Action {
+            name: "page_new".to_string(),
+        },
+    );
+    let overlay = response.status.unwrap().overlay.unwrap();
+    assert_eq!((overlay.page, overlay.page_count), (2, 2));
+
+    let response = apply_request(&mut input, ControlRequest::SwitchPage { page: 1 });
+    assert_eq!(response.status.unwrap().overlay.unwrap().page, 1);
+}
+
+#[test]
+fn invalid_targets_fail_without_changing_state() {
+    let mut input = make_test_input_state();
+
+    for request in [
+        ControlRequest::Action {
+            name: "no_such_action".to_string(),
+        },
+        ControlRequest::SetColor {
+            color: "mauve-ish".to_string(),
+        },
+        ControlRequest::SetThickness { thickness: -1.0 },
+        ControlRequest::SetTool {
+            tool: "chisel".to_string(),
+        },
+        ControlRequest::SwitchBoard {
+            board: BoardTarget::Id("no-such-board".to_string()),
+        },
+        ControlRequest::SwitchBoard {
+            board: BoardTarget::Slot(0),
+        },
+        ControlRequest::SwitchPage { page: 3 },
+        ControlRequest::ImportBackground {
+            path: "slides.pdf".into(),
+        },
+        ControlRequest::ImportBackground {
+            path: "/tmp/wayscriber-missing-deck.pdf".into(),
+        },
+        ControlRequest::ImportBackground {
+            path: "/etc/hostname".into(),
+        },
+        ControlRequest::ClearBackground,
+    ] {
+        let response = apply_request(&mut input, request.clone());
+        assert!(!response.ok, "{request:?} should fail");
+        assert!(response.error.is_some());
+        assert!(response.status.is_none());
+    }
+    assert_eq!(input.boards.active_index(), 0);
+    assert_eq!(input.boards.page_count(), 1);
+    assert!(input.take_pending_backend_action().is_none());
+}
+
+#[test]
+fn server_forwards_requests_to_the_loop_and_cleans_up() {
+    let dir = crate::test_temp::tempdir().unwrap();
+    let path = dir.path().join("wayscriber").join("control.sock");
+    let server = ControlServer::start(path.clone(), || {}).unwrap();
+
+    let client_path = path.clone();
+    let client = std::thread::spawn(move || {
+        send_request(
+            &client_path,
+            &ControlRequest::SetTool {
+                tool: "rect".to_string(),
+            },
+        )
+    });
+
+    let mut input = make_test_input_state();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        if let Some(pending) = server.try_recv() {
+            let response = apply_request(&mut input, pending.request.clone());
+            pending.respond(response);
+            break;
+        }
+        assert!(Instant::now() < deadline, "request never reached the loop");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+
+    let response = client.join().unwrap().unwrap();
+    assert!(response.ok);
+    assert_eq!(response.status.unwrap().overlay.unwrap().tool, Tool::Rect);
+
+    drop(server);
+    assert!(!path.exists(), "dropping the server removes the socket");
+}
+
+#[test]
+fn stale_socket_files_are_replaced() {
+    let dir = crate::test_temp::tempdir().unwrap();
+    let path = dir.path().join("control.sock");
+    // A bound-then-dropped listener leaves exactly the file a crashed overlay
+    // would: a socket nobody answers on.
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+    assert!(path.exists());
+
+    let server = ControlServer::start(path.clone(), || {}).unwrap();
+    assert!(ControlServer::start(path.clone(), || {}).is_err());
+    drop(server);
+}
+
+#[test]
+fn connections_over_the_limit_are_turned_away_with_an_error() {
+    use std::io::{BufRead, BufReader};
+    use std::os::unix::net::UnixStream;
+
+    let dir = crate::test_temp::tempdir().unwrap();
+    let path = dir.path().join("control.sock");
+    let server = ControlServer::start(path.clone(), || {}).unwrap();
+
+    let mut idle: Vec<UnixStream> = (0..MAX_CONNECTIONS)
+        .map(|_| UnixStream::connect(&path).unwrap())
+        .collect();
+    let extra = UnixStream::connect(&path).unwrap();
+    extra
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    let mut line = String::new();
+    BufReader::new(extra).read_line(&mut line).unwrap();
+    let response: ControlResponse = serde_json::from_str(line.trim()).unwrap();
+    assert!(!response.ok);
+    assert_eq!(
+        response.error.as_deref(),
+        Some("too many control connections")
+    );
+
+    // A connection that hangs up gives its slot back.
+    drop(idle.pop());
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let status = loop {
+        let client_path = path.clone();
+        let client =
+            std::thread::spawn(move || send_request(&client_path, &ControlRequest::Status));
+        let mut input = make_test_input_state();
+        let answered = loop {
+            if let Some(pending) = server.try_recv() {
+                let response = apply_request(&mut input, pending.request.clone());
+                pending.respond(response);
+                break true;
+            }
+            if client.is_finished() {
+                break false;
+            }
+            std::thread::sleep(Duration::from_millis(5));
+        };
+        let response = client.join().unwrap().unwrap();
+        if answered {
+            break response;
+        }
+        assert!(Instant::now() < deadline, "the freed slot was never reused");
+        std::thread::sleep(Duration::from_millis(10));
+    };
+    assert!(status.ok);
+    drop(idle);
+    drop(server);
+}
+
+/// Answers every queued request the way the event loop does, until one came in.
+fn serve_one(server: &ControlServer, input: &mut crate::input::InputState) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        if let Some(pending) = server.try_recv() {
+            let response = apply_request(input, pending.request.clone());
+            pending.respond(response);
+            return;
+        }
+        assert!(Instant::now() < deadline, "request never reached the loop");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn snapshot_diffs_name_each_change_once() {
+    let mut input = make_test_input_state();
+    let before = StatusModel::new(&input);
+
+    input.set_thickness_for_active_tool(before.size + 4.0);
+    input.switch_board_force("whiteboard");
+    let after = StatusModel::new(&input);
+    // The whiteboard swaps in a pen color that shows on white, and that is
+    // reported as the color change it is.
+    assert_eq!(
+        changes(&before, &after),
+        vec![
+            ControlEventKind::ColorChanged,
+            ControlEventKind::ThicknessChanged,
+            ControlEventKind::BoardChanged
+        ]
+    );
+    assert!(changes(&after, &after).is_empty());
+
+    apply_request(
+        &mut input,
+        ControlRequest::Action {
+            name: "page_new".to_string(),
+        },
+    );
+    let paged = StatusModel::new(&input);
+    assert_eq!(changes(&after, &paged), vec![ControlEventKind::PageChanged]);
+
+    input.set_recording_started_at(Some(Instant::now()));
+    let recording = StatusModel::new(&input);
+    assert!(recording.recording);
+    assert_eq!(
+        changes(&paged, &recording),
+        vec![ControlEventKind::RecordingChanged]
+    );
+}
+
+#[test]
+fn subscribers_get_the_status_then_one_line_per_change() {
+    use std::io::{BufRead, BufReader, Write};
+
+    let dir = crate::test_temp::tempdir().unwrap();
+    let path = dir.path().join("control.sock");
+    let mut server = ControlServer::start(path.clone(), || {}).unwrap();
+    let mut input = make_test_input_state();
+
+    let mut stream = std::os::unix::net::UnixStream::connect(&path).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(5)))
+        .unwrap();
+    stream
+        .write_all(encode_line(&ControlRequest::Subscribe).as_bytes())
+        .unwrap();
+    serve_one(&server, &mut input);
+
+    server.publish_changes(|| StatusModel::new(&input));
+    input.set_tool_override(Some(Tool::Rect));
+    server.publish_changes(|| StatusModel::new(&input));
+    drop(server);
+
+    let lines: Vec<String> = BufReader::new(stream)
+        .lines()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(lines.len(), 3, "{lines:?}");
+    let response: ControlResponse = serde_json::from_str(&lines[0]).unwrap();
+    assert!(response.ok);
+    let changed: ControlEvent = serde_json::from_str(&lines[1]).unwrap();
+    assert_eq!(changed.event, ControlEventKind::ToolChanged);
+    assert_eq!(changed.status.overlay.unwrap().tool, Tool::Rect);
+    let hidden: ControlEvent = serde_json::from_str(&lines[2]).unwrap();
+    assert_eq!(hidden.event, ControlEventKind::OverlayHidden);
+    assert!(!hidden.status.visible);
+    assert!(hidden.status.overlay.is_none());
+}
+
+#[test]
+fn watch_reports_a_missing_overlay_once() {
+    let dir = crate::test_temp::tempdir().unwrap();
+    let record = dir.path().join("wayscriber.pid");
+    let lines = watch_lines(&dir.path().join("control.sock"), &record, 3);
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    let line: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+    assert_eq!(line["event"], "overlay_hidden");
+    assert_eq!(line["status"], serde_json::json!({"visible": false}));
+    assert_eq!(line["class"], serde_json::json!(["hidden", "stopped"]));
+}
+
+#[test]
+fn watch_reads_a_running_daemon_from_its_runtime_record() {
+    let dir = crate::test_temp::tempdir().unwrap();
+    let record = dir.path().join("wayscriber.pid");
+    std::fs::write(&record, std::process::id().to_string()).unwrap();
+    let lines = watch_lines(&dir.path().join("control.sock"), &record, 2);
+    assert_eq!(lines.len(), 1, "{lines:?}");
+    let line: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+    assert_eq!(line["event"], "overlay_hidden");
+    assert_eq!(line["status"]["daemon"], true);
+    assert_eq!(line["class"], serde_json::json!(["hidden"]));
+}
+
+#[test]
+fn watch_follows_an_overlay_run_with_waybar_fields() {
+    let dir = crate::test_temp::tempdir().unwrap();
+    let path = dir.path().join("control.sock");
+    let mut server = ControlServer::start(path.clone(), || {}).unwrap();
+    let mut input = make_test_input_state();
+
+    let watch_path = path.clone();
+    let record = dir.path().join("wayscriber.pid");
+    let watcher = std::thread::spawn(move || watch_lines(&watch_path, &record, 1));
+    serve_one(&server, &mut input);
+    server.publish_changes(|| StatusModel::new(&input));
+    input.switch_board_force("whiteboard");
+    server.publish_changes(|| StatusModel::new(&input));
+    drop(server);
+
+    let lines: Vec<serde_json::Value> = watcher
+        .join()
+        .unwrap()
+        .iter()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let events: Vec<&str> = lines
+        .iter()
+        .map(|line| line["event"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        events,
+        [
+            "overlay_shown",
+            "color_changed",
+            "board_changed",
+            "overlay_hidden"
+        ]
+    );
+    assert_eq!(lines[2]["status"]["board_id"], "whiteboard");
+    assert_eq!(lines[2]["class"], serde_json::json!(["visible"]));
+    assert!(lines[2]["text"].as_str().unwrap().contains("1/1"));
+}
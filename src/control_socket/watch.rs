@@ -0,0 +1,201 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::daemon::protocol_v2::runtime_record_is_live;
+
+use super::protocol::{
+    ControlEvent, ControlEventKind, ControlRequest, ControlResponse, ControlStatus, encode_line,
+};
+
+/// How often `--watch` looks for an overlay while none is running. Short
+/// enough that a status bar catches up with a freshly shown overlay before the
+/// user looks at it, long enough to cost nothing while idle.
+const RECONNECT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// `wayscriber --watch`: prints one JSON line per overlay event until stdout
+/// goes away.
+///
+/// The overlay process only exists while it is shown, so the watcher outlives
+/// it: it reports `overlay_hidden` whenever no overlay answers, subscribes as
+/// soon as one does, and reports `overlay_shown` with the status it subscribed
+/// at. While hidden, the daemon's runtime record tells a bar whether anything
+/// can show the overlay again, and a daemon starting or stopping is reported
+/// as another `overlay_hidden` line. Each line is the [`ControlEvent`] plus the `text`, `tooltip`, and
+/// `class` fields a waybar `custom` module with `"return-type": "json"` reads.
+pub(crate) fn run_watch() -> anyhow::Result<()> {
+    let path = crate::paths::control_socket_path();
+    let mut watcher = Watcher::new(io::stdout().lock(), crate::paths::daemon_pid_file());
+    loop {
+        if watcher.follow(&path).is_err() {
+            // Stdout is closed: the bar that started us is gone.
+            return Ok(());
+        }
+        thread::sleep(RECONNECT_INTERVAL);
+    }
+}
+
+struct Watcher<W: Write> {
+    out: W,
+    runtime_record: PathBuf,
+    /// Visibility and daemon liveness as of the last printed line, so a hidden
+    /// overlay is printed once rather than on every reconnect attempt.
+    printed: Option<(bool, bool)>,
+}
+
+impl<W: Write> Watcher<W> {
+    fn new(out: W, runtime_record: PathBuf) -> Self {
+        Self {
+            out,
+            runtime_record,
+            printed: None,
+        }
+    }
+
+    /// Follows one overlay run from connect to hang-up. Only output errors
+    /// are returned; an overlay that is absent or goes away is just reported.
+    fn follow(&mut self, path: &Path) -> io::Result<()> {
+        let Ok(stream) = subscribe(path) else {
+            return self.hidden();
+        };
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        if reader.read_line(&mut line).unwrap_or(0) == 0 {
+            return self.hidden();
+        }
+        let status = match serde_json::from_str::<ControlResponse>(line.trim()) {
+            Ok(ControlResponse {
+                ok: true,
+                status: Some(status),
+                ..
+            }) => status,
+            _ => return self.hidden(),
+        };
+        self.print(&ControlEvent::new(ControlEventKind::OverlayShown, status))?;
+
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                return self.hidden();
+            }
+            // A line this build does not understand comes from a newer overlay;
+            // skipping it beats dropping the subscription.
+            if let Ok(event) = serde_json::from_str::<ControlEvent>(line.trim()) {
+                self.print(&event)?;
+            }
+        }
+    }
+
+    fn hidden(&mut self) -> io::Result<()> {
+        let status = ControlStatus::hidden(runtime_record_is_live(&self.runtime_record));
+        if self.printed == Some((false, status.daemon)) {
+            return Ok(());
+        }
+        self.print(&ControlEvent::new(ControlEventKind::OverlayHidden, status))
+    }
+
+    fn print(&mut self, event: &ControlEvent) -> io::Result<()> {
+        self.printed = Some((event.status.visible, event.status.daemon));
+        self.out
+            .write_all(encode_line(&WatchLine::new(event)).as_bytes())?;
+        self.out.flush()
+    }
+}
+
+fn subscribe(path: &Path) -> io::Result<UnixStream> {
+    let mut stream = UnixStream::connect(path)?;
+    stream.write_all(encode_line(&ControlRequest::Subscribe).as_bytes())?;
+    Ok(stream)
+}
+
+/// An event dressed for waybar. The event's own fields stay alongside, so
+/// scripts and bars share one stream.
+#[derive(Serialize)]
+struct WatchLine<'a> {
+    text: String,
+    tooltip: String,
+    class: Vec<&'static str>,
+    #[serde(flatten)]
+    event: &'a ControlEvent,
+}
+
+impl<'a> WatchLine<'a> {
+    fn new(event: &'a ControlEvent) -> Self {
+        let Some(overlay) = event.status.overlay.as_ref() else {
+            let (tooltip, class) = if event.status.daemon {
+                ("Wayscriber overlay is hidden", vec!["hidden"])
+            } else {
+                ("Wayscriber is not running", vec!["hidden", "stopped"])
+            };
+            return Self {
+                text: "off".to_string(),
+                tooltip: tooltip.to_string(),
+                class,
+                event,
+            };
+        };
+
+        let mut class = vec!["visible"];
+        let mut modes = Vec::new();
+        for (active, name, label) in [
+            (overlay.frozen, "frozen", "Frozen"),
+            (overlay.zoomed, "zoomed", "Zoomed"),
+            (overlay.presenter, "presenter", "Presenter mode"),
+            (overlay.recording, "recording", "Recording"),
+        ] {
+            if active {
+                class.push(name);
+                modes.push(label);
+            }
+        }
+
+        let mut tooltip = format!(
+            "Tool: {}\nColor: {}\nSize: {:.0}px\nBoard: {} ({}/{})\nPage: {}/{}",
+            overlay.tool.display_label(),
+            overlay.color,
+            overlay.thickness,
+            overlay.board_name,
+            overlay.board,
+            overlay.board_count,
+            overlay.page,
+            overlay.page_count,
+        );
+        if !modes.is_empty() {
+            tooltip.push('\n');
+            tooltip.push_str(&modes.join(", "));
+        }
+        if let Some(path) = event.path.as_ref() {
+            tooltip.push_str(&format!("\nSaved: {}", path.display()));
+        }
+
+        Self {
+            text: format!(
+                "{} · {} {}/{}",
+                overlay.tool.short_label(),
+                overlay.board_name,
+                overlay.page,
+                overlay.page_count
+            ),
+            tooltip,
+            class,
+            event,
+        }
+    }
+}
+
+#[cfg(test)]
+pub(super) fn watch_lines(path: &Path, runtime_record: &Path, attempts: usize) -> Vec<String> {
+    let mut watcher = Watcher::new(Vec::new(), runtime_record.to_path_buf());
+    for _ in 0..attempts {
+        watcher.follow(path).unwrap();
+    }
+    String::from_utf8(watcher.out)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
@@ -21,7 +21,9 @@ pub(crate) use linux::{
     wait_for_pidfd_exit,
 };
 pub(crate) use mode::DaemonControlProtocolMode;
-pub(crate) use runtime::{ClassifiedRuntimeRecord, read_runtime_record, write_runtime_record_v2};
+pub(crate) use runtime::{
+    ClassifiedRuntimeRecord, read_runtime_record, runtime_record_is_live, write_runtime_record_v2,
+};
 pub(crate) use wire::EffectKind;
 pub(crate) use wire::{DaemonRequestV2, DaemonRuntimeRecordV2};
 
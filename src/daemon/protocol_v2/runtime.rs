@@ -71,6 +71,17 @@ fn is_v2_shaped(bytes: &[u8]) -> bool {
     .any(|field| fields.contains_key(*field))
 }
 
+/// Whether the runtime record at `path` names a daemon that is still running.
+/// A missing, stale, or unreadable record all mean no daemon.
+pub(crate) fn runtime_record_is_live(path: &Path) -> bool {
+    match read_runtime_record(path) {
+        // Reading a v2 record already checks its pid against boot and start time.
+        Ok(ClassifiedRuntimeRecord::V2(_)) => true,
+        Ok(ClassifiedRuntimeRecord::LegacyV1 { pid, .. }) => super::linux::open_pidfd(pid).is_ok(),
+        Err(_) => false,
+    }
+}
+
 pub(crate) fn write_runtime_record_v2(path: &Path, record: &DaemonRuntimeRecordV2) -> Result<()> {
     record.validate()?;
     let bytes = canonical_json(record, MAX_RUNTIME_RECORD_BYTES)?;
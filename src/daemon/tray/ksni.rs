@@ -222,6 +222,72 @@ impl ksni::Tray for WayscriberTray {
                         ..Default::default()
                     }
                     .into(),
+                    MenuItem::Separator,
+                    SubMenu {
+                        label: "Timer".to_string(),
+                        icon_name: menu_icon_name("alarm-symbolic", use_theme_icons),
+                        submenu: vec![
+                            StandardItem {
+                                label: format_binding_label(
+                                    action_label(Action::StartBreakTimer),
+                                    None,
+                                ),
+                                icon_name: menu_icon_name("alarm-symbolic", use_theme_icons),
+                                activate: Box::new(|this: &mut Self| {
+                                    this.dispatch_overlay_action(TrayAction::StartBreakTimer);
+                                }),
+                                ..Default::default()
+                            }
+                            .into(),
+                            StandardItem {
+                                label: format_binding_label(
+                                    action_label(Action::StartTalkTimer),
+                                    None,
+                                ),
+                                icon_name: menu_icon_name("alarm-symbolic", use_theme_icons),
+                                activate: Box::new(|this: &mut Self| {
+                                    this.dispatch_overlay_action(TrayAction::StartTalkTimer);
+                                }),
+                                ..Default::default()
+                            }
+                            .into(),
+                            StandardItem {
+                                label: format_binding_label(
+                                    action_label(Action::StartStopwatch),
+                                    None,
+                                ),
+                                icon_name: menu_icon_name("appointment-soon", use_theme_icons),
+                                activate: Box::new(|this: &mut Self| {
+                                    this.dispatch_overlay_action(TrayAction::StartStopwatch);
+                                }),
+                                ..Default::default()
+                            }
+                            .into(),
+                            StandardItem {
+                                label: format_binding_label(
+                                    action_label(Action::ToggleTimerPause),
+                                    None,
+                                ),
+                                icon_name: menu_icon_name("media-playback-pause", use_theme_icons),
+                                activate: Box::new(|this: &mut Self| {
+                                    this.dispatch_overlay_action(TrayAction::ToggleTimerPause);
+                                }),
+                                ..Default::default()
+                            }
+                            .into(),
+                            StandardItem {
+                                label: format_binding_label(action_label(Action::ResetTimer), None),
+                                icon_name: menu_icon_name("edit-clear", use_theme_icons),
+                                activate: Box::new(|this: &mut Self| {
+                                    this.dispatch_overlay_action(TrayAction::ResetTimer);
+                                }),
+                                ..Default::default()
+                            }
+                            .into(),
+                        ],
+                        ..Default::default()
+                    }
+                    .into(),
                 ],
                 ..Default::default()
             }
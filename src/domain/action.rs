@@ -12,6 +12,7 @@ pub enum Action {
     EnterTextMode,
     EnterStickyNoteMode,
     ClearCanvas,
+    ClearActiveLayer,
     Undo,
     Redo,
     UndoAll,
@@ -24,6 +25,10 @@ pub enum Action {
     SelectAll,
     MoveSelectionToFront,
     MoveSelectionToBack,
+    MoveSelectionToLayerAbove,
+    MoveSelectionToLayerBelow,
+    GroupSelection,
+    UngroupSelection,
     NudgeSelectionUp,
     NudgeSelectionDown,
     NudgeSelectionLeft,
@@ -34,6 +39,14 @@ pub enum Action {
     MoveSelectionToEnd,
     MoveSelectionToTop,
     MoveSelectionToBottom,
+    AlignSelectionLeft,
+    AlignSelectionCenter,
+    AlignSelectionRight,
+    AlignSelectionTop,
+    AlignSelectionMiddle,
+    AlignSelectionBottom,
+    DistributeSelectionHorizontally,
+    DistributeSelectionVertically,
     DeleteSelection,
 
     // Thickness controls
@@ -47,6 +60,7 @@ pub enum Action {
     SelectEraserTool,
     ToggleEraserMode,
     CycleBlurStyle,
+    CycleLineStyle,
     SelectPenTool,
     SelectLineTool,
     SelectRectTool,
@@ -60,6 +74,7 @@ pub enum Action {
     SelectBlurTool,
     SelectSpotlightTool,
     SelectHighlightTool,
+    SelectLaserTool,
     IncreaseFontSize,
     DecreaseFontSize,
     ResetArrowLabelCounter,
@@ -108,6 +123,10 @@ pub enum Action {
     PageDelete,
     PageRestoreDeleted,
 
+    // Board grid
+    ToggleGrid,
+    ToggleSnapToGrid,
+
     // UI toggles
     ToggleHelp,
     ToggleQuickHelp,
@@ -128,13 +147,32 @@ pub enum Action {
     /// Cycle the top toolbar's display: full strip → micro chip → hidden.
     CycleToolbarDisplay,
     TogglePresenterMode,
+    /// Replay the active page shape by shape in the order it was drawn.
+    ToggleReplay,
+    /// Start (or restart) the break countdown.
+    StartBreakTimer,
+    /// Start (or restart) the talk countdown.
+    StartTalkTimer,
+    /// Start (or restart) a stopwatch counting up from zero.
+    StartStopwatch,
+    /// Pause or resume the running timer.
+    ToggleTimerPause,
+    /// Stop the timer and hide it.
+    ResetTimer,
+    /// Add a minute to the running countdown.
+    TimerAddMinute,
+    /// Switch the timer between the large overlay and the compact pill.
+    ToggleTimerDisplay,
     ToggleLightMode,
     ToggleLightModeDrawing,
     RenderProfileNext,
     RenderProfilePrevious,
     RenderProfileOff,
     ToggleHighlightTool,
+    /// Make new pen strokes vanish a few seconds after they are drawn.
+    ToggleFadeInk,
     ToggleFill,
+    ToggleConnectorMode,
     ToggleRadialMenu,
     ToggleSelectionProperties,
     OpenContextMenu,
@@ -182,6 +220,12 @@ pub enum Action {
     ExportCanvasClipboardAndFile,
     ExportBoardPdfFile,
     ExportAllBoardsPdfFile,
+    ExportCanvasSvgFile,
+    ExportBoardSvgFile,
+    /// Save the replay of the active page as numbered PNG frames.
+    ExportReplayFrames,
+    /// Start or stop recording the annotated overlay to an animated GIF/APNG.
+    ToggleRecording,
     OpenCaptureFolder,
     /// Select a screen region and copy the text recognized in it.
     CopyTextFromScreen,
@@ -191,6 +235,10 @@ pub enum Action {
     ResetZoom,
     ToggleZoomLock,
     RefreshZoomCapture,
+    /// Show or hide the magnifier lens that follows the pointer.
+    ToggleMagnifier,
+    MagnifierZoomIn,
+    MagnifierZoomOut,
 
     // Preset slots
     ApplyPreset1,
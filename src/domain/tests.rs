@@ -50,6 +50,7 @@ fn action_serialization_matches_established_contract() {
         (Action::EnterTextMode, "enter_text_mode"),
         (Action::EnterStickyNoteMode, "enter_sticky_note_mode"),
         (Action::ClearCanvas, "clear_canvas"),
+        (Action::ClearActiveLayer, "clear_active_layer"),
         (Action::Undo, "undo"),
         (Action::Redo, "redo"),
         (Action::UndoAll, "undo_all"),
@@ -62,6 +63,16 @@ fn action_serialization_matches_established_contract() {
         (Action::SelectAll, "select_all"),
         (Action::MoveSelectionToFront, "move_selection_to_front"),
         (Action::MoveSelectionToBack, "move_selection_to_back"),
+        (
+            Action::MoveSelectionToLayerAbove,
+            "move_selection_to_layer_above",
+        ),
+        (
+            Action::MoveSelectionToLayerBelow,
+            "move_selection_to_layer_below",
+        ),
+        (Action::GroupSelection, "group_selection"),
+        (Action::UngroupSelection, "ungroup_selection"),
         (Action::NudgeSelectionUp, "nudge_selection_up"),
         (Action::NudgeSelectionDown, "nudge_selection_down"),
         (Action::NudgeSelectionLeft, "nudge_selection_left"),
@@ -75,6 +86,20 @@ fn action_serialization_matches_established_contract() {
         (Action::MoveSelectionToEnd, "move_selection_to_end"),
         (Action::MoveSelectionToTop, "move_selection_to_top"),
         (Action::MoveSelectionToBottom, "move_selection_to_bottom"),
+        (Action::AlignSelectionLeft, "align_selection_left"),
+        (Action::AlignSelectionCenter, "align_selection_center"),
+        (Action::AlignSelectionRight, "align_selection_right"),
+        (Action::AlignSelectionTop, "align_selection_top"),
+        (Action::AlignSelectionMiddle, "align_selection_middle"),
+        (Action::AlignSelectionBottom, "align_selection_bottom"),
+        (
+            Action::DistributeSelectionHorizontally,
+            "distribute_selection_horizontally",
+        ),
+        (
+            Action::DistributeSelectionVertically,
+            "distribute_selection_vertically",
+        ),
         (Action::DeleteSelection, "delete_selection"),
         (Action::IncreaseThickness, "increase_thickness"),
         (Action::DecreaseThickness, "decrease_thickness"),
@@ -86,6 +111,7 @@ fn action_serialization_matches_established_contract() {
         (Action::SelectEraserTool, "select_eraser_tool"),
         (Action::ToggleEraserMode, "toggle_eraser_mode"),
         (Action::CycleBlurStyle, "cycle_blur_style"),
+        (Action::CycleLineStyle, "cycle_line_style"),
         (Action::SelectPenTool, "select_pen_tool"),
         (Action::SelectLineTool, "select_line_tool"),
         (Action::SelectRectTool, "select_rect_tool"),
@@ -105,6 +131,7 @@ fn action_serialization_matches_established_contract() {
         (Action::SelectBlurTool, "select_blur_tool"),
         (Action::SelectSpotlightTool, "select_spotlight_tool"),
         (Action::SelectHighlightTool, "select_highlight_tool"),
+        (Action::SelectLaserTool, "select_laser_tool"),
         (Action::IncreaseFontSize, "increase_font_size"),
         (Action::DecreaseFontSize, "decrease_font_size"),
         (Action::ResetArrowLabelCounter, "reset_arrow_label_counter"),
@@ -137,6 +164,8 @@ fn action_serialization_matches_established_contract() {
         (Action::PageDuplicate, "page_duplicate"),
         (Action::PageDelete, "page_delete"),
         (Action::PageRestoreDeleted, "page_restore_deleted"),
+        (Action::ToggleGrid, "toggle_grid"),
+        (Action::ToggleSnapToGrid, "toggle_snap_to_grid"),
         (Action::ToggleHelp, "toggle_help"),
         (Action::ToggleQuickHelp, "toggle_quick_help"),
         (Action::ToggleStatusBar, "toggle_status_bar"),
@@ -148,13 +177,23 @@ fn action_serialization_matches_established_contract() {
         (Action::ToggleToolbar, "toggle_toolbar"),
         (Action::CycleToolbarDisplay, "cycle_toolbar_display"),
         (Action::TogglePresenterMode, "toggle_presenter_mode"),
+        (Action::ToggleReplay, "toggle_replay"),
+        (Action::StartBreakTimer, "start_break_timer"),
+        (Action::StartTalkTimer, "start_talk_timer"),
+        (Action::StartStopwatch, "start_stopwatch"),
+        (Action::ToggleTimerPause, "toggle_timer_pause"),
+        (Action::ResetTimer, "reset_timer"),
+        (Action::TimerAddMinute, "timer_add_minute"),
+        (Action::ToggleTimerDisplay, "toggle_timer_display"),
         (Action::ToggleLightMode, "toggle_light_mode"),
         (Action::ToggleLightModeDrawing, "toggle_light_mode_drawing"),
         (Action::RenderProfileNext, "render_profile_next"),
         (Action::RenderProfilePrevious, "render_profile_previous"),
         (Action::RenderProfileOff, "render_profile_off"),
         (Action::ToggleHighlightTool, "toggle_highlight_tool"),
+        (Action::ToggleFadeInk, "toggle_fade_ink"),
         (Action::ToggleFill, "toggle_fill"),
+        (Action::ToggleConnectorMode, "toggle_connector_mode"),
         (Action::ToggleRadialMenu, "toggle_radial_menu"),
         (
             Action::ToggleSelectionProperties,
@@ -207,6 +246,10 @@ fn action_serialization_matches_established_contract() {
         ),
         (Action::ExportBoardPdfFile, "export_board_pdf_file"),
         (Action::ExportAllBoardsPdfFile, "export_all_boards_pdf_file"),
+        (Action::ExportCanvasSvgFile, "export_canvas_svg_file"),
+        (Action::ExportBoardSvgFile, "export_board_svg_file"),
+        (Action::ExportReplayFrames, "export_replay_frames"),
+        (Action::ToggleRecording, "toggle_recording"),
         (Action::OpenCaptureFolder, "open_capture_folder"),
         (Action::CopyTextFromScreen, "copy_text_from_screen"),
         (Action::ToggleFrozenMode, "toggle_frozen_mode"),
@@ -215,6 +258,9 @@ fn action_serialization_matches_established_contract() {
         (Action::ResetZoom, "reset_zoom"),
         (Action::ToggleZoomLock, "toggle_zoom_lock"),
         (Action::RefreshZoomCapture, "refresh_zoom_capture"),
+        (Action::ToggleMagnifier, "toggle_magnifier"),
+        (Action::MagnifierZoomIn, "magnifier_zoom_in"),
+        (Action::MagnifierZoomOut, "magnifier_zoom_out"),
         (Action::ApplyPreset1, "apply_preset1"),
         (Action::ApplyPreset2, "apply_preset2"),
         (Action::ApplyPreset3, "apply_preset3"),
@@ -253,6 +299,7 @@ fn tool_serialization_matches_established_contract() {
         (Tool::Blur, "blur"),
         (Tool::Marker, "marker"),
         (Tool::Highlight, "highlight"),
+        (Tool::Laser, "laser"),
         (Tool::StepMarker, "step-marker"),
         (Tool::Eraser, "eraser"),
     ]);
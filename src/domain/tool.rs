@@ -38,6 +38,8 @@ pub enum Tool {
     Marker,
     /// Highlight-only tool (no drawing, emits click highlight)
     Highlight,
+    /// Laser pointer: a bright dot with a short fading trail, never a shape
+    Laser,
     /// Numbered step marker tool (places auto-incrementing bubbles)
     StepMarker,
     /// Eraser brush that removes content within its stroke
@@ -180,6 +182,8 @@ impl DragBindableTool {
             Tool::Spotlight => None,
             Tool::Marker => Some(Self::Marker),
             Tool::Highlight => Some(Self::Highlight),
+            // Pointing, not drawing; select it explicitly like the spotlight.
+            Tool::Laser => None,
             Tool::StepMarker => Some(Self::StepMarker),
             Tool::Eraser => Some(Self::Eraser),
         }
@@ -281,7 +281,7 @@ impl BoardPages {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::draw::{RED, Shape};
+    use crate::draw::{LineStyle, RED, Shape};
 
     #[test]
     fn page_generation_changes_on_identity_or_order_updates() {
@@ -318,6 +318,7 @@ mod tests {
             y2: 10,
             color: RED,
             thick: 2.0,
+            line_style: LineStyle::Solid,
         });
         assert_eq!(pages.generation(), start);
     }
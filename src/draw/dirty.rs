@@ -121,7 +121,7 @@ impl DirtyTracker {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::draw::{Color, Shape};
+    use crate::draw::{Color, LineStyle, Shape};
 
     #[test]
     fn mark_shape_records_rectangles() {
@@ -138,6 +138,7 @@ mod tests {
                 a: 1.0,
             },
             thick: 2.0,
+            line_style: LineStyle::Solid,
         });
 
         let rects = tracker.take_regions(100, 100);
@@ -162,6 +163,8 @@ mod tests {
                 a: 1.0,
             },
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         tracker.mark_full();
         tracker.mark_shape(&Shape::Rect {
@@ -177,6 +180,8 @@ mod tests {
                 a: 1.0,
             },
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
 
         let rects = tracker.take_regions(200, 100);
@@ -0,0 +1,97 @@
+use crate::draw::shape::Shape;
+use serde::{Deserialize, Serialize};
+
+/// How a shape is composited onto what is already drawn beneath it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    /// Darkens like ink on paper; white disappears, so marker strokes over a
+    /// whiteboard read as highlighter.
+    Multiply,
+    /// The lightening counterpart of multiply; black disappears.
+    Screen,
+    Difference,
+}
+
+impl BlendMode {
+    /// Every mode, in the order the properties panel cycles through them.
+    pub const ALL: [Self; 4] = [Self::Normal, Self::Multiply, Self::Screen, Self::Difference];
+
+    /// Short human-readable name for the properties panel and toasts.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Normal => "Normal",
+            Self::Multiply => "Multiply",
+            Self::Screen => "Screen",
+            Self::Difference => "Difference",
+        }
+    }
+
+    /// Value of the CSS `mix-blend-mode` property used by SVG export.
+    pub fn css_name(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Multiply => "multiply",
+            Self::Screen => "screen",
+            Self::Difference => "difference",
+        }
+    }
+}
+
+/// Whole-shape opacity and blend mode, applied on top of the shape's own
+/// colors.
+///
+/// Unlike a color's alpha, opacity fades a shape as one layer: a filled
+/// rectangle's outline does not show through its fill, and a sticky note's
+/// text fades together with its paper.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ShapeAppearance {
+    /// 0.0 (invisible) to 1.0 (as drawn).
+    #[serde(default = "default_opacity")]
+    pub opacity: f64,
+    #[serde(default)]
+    pub blend: BlendMode,
+}
+
+impl ShapeAppearance {
+    /// Lowest opacity the editing controls step down to, so a shape never
+    /// becomes impossible to find again.
+    pub const MIN_OPACITY: f64 = 0.1;
+
+    /// True when the shape renders exactly as its colors say, which lets the
+    /// renderer skip the offscreen group and sessions omit the field.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether `shape` can carry an appearance. Eraser strokes punch through
+    /// the canvas and blur rects hide content, so fading or blending either
+    /// would defeat its purpose.
+    pub fn applies_to(shape: &Shape) -> bool {
+        !matches!(shape, Shape::EraserStroke { .. } | Shape::BlurRect { .. })
+    }
+
+    /// Opacity clamped to the range the renderer accepts.
+    pub fn clamped_opacity(&self) -> f64 {
+        if self.opacity.is_finite() {
+            self.opacity.clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+impl Default for ShapeAppearance {
+    fn default() -> Self {
+        Self {
+            opacity: default_opacity(),
+            blend: BlendMode::Normal,
+        }
+    }
+}
+
+fn default_opacity() -> f64 {
+    1.0
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::draw::shape::EmbeddedImage;
+use crate::util::Rect;
+
+/// An imported document page painted beneath a board page's shapes.
+///
+/// The page is rasterized once at import time and embedded, so a session keeps
+/// rendering after the source file moves. `x`/`y`/`width`/`height` place it in
+/// canvas coordinates: annotations stay attached to the slide while panning.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PageBackground {
+    pub source: PageBackgroundSource,
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub image: EmbeddedImage,
+}
+
+/// Where an imported page background came from, kept for display only.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageBackgroundSource {
+    pub path: PathBuf,
+    /// 1-based page number for multi-page documents.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+}
+
+impl PageBackground {
+    /// Places `image` centered in a `viewport_width` x `viewport_height`
+    /// viewport at the canvas origin, scaled to fit while keeping its aspect.
+    pub fn fitted(
+        source: PageBackgroundSource,
+        image: EmbeddedImage,
+        viewport_width: u32,
+        viewport_height: u32,
+    ) -> Self {
+        let viewport_width = f64::from(viewport_width.max(1));
+        let viewport_height = f64::from(viewport_height.max(1));
+        let image_width = f64::from(image.width.max(1));
+        let image_height = f64::from(image.height.max(1));
+        let scale = (viewport_width / image_width).min(viewport_height / image_height);
+        let width = (image_width * scale).round().max(1.0);
+        let height = (image_height * scale).round().max(1.0);
+        Self {
+            source,
+            x: ((viewport_width - width) / 2.0).round() as i32,
+            y: ((viewport_height - height) / 2.0).round() as i32,
+            width: width as i32,
+            height: height as i32,
+            image,
+        }
+    }
+
+    pub fn bounds(&self) -> Option<Rect> {
+        Rect::new(self.x, self.y, self.width, self.height)
+    }
+
+    /// Short label for toasts: the file name plus the page number, if any.
+    pub fn label(&self) -> String {
+        let name = self
+            .source
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| self.source.path.display().to_string());
+        match self.source.page {
+            Some(page) => format!("{name} p.{page}"),
+            None => name,
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use super::core::Frame;
+use super::types::ShapeId;
+use crate::draw::shape::Shape;
+use crate::util::Rect;
+use serde::{Deserialize, Serialize};
+
+/// Point on a target's bounding box that a connector end sticks to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorAnchor {
+    Center,
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+impl ConnectorAnchor {
+    const ALL: [Self; 5] = [
+        Self::Center,
+        Self::Top,
+        Self::Right,
+        Self::Bottom,
+        Self::Left,
+    ];
+
+    /// Canvas position of this anchor on `bounds`.
+    pub fn point_on(self, bounds: Rect) -> (i32, i32) {
+        let mid_x = bounds.x + bounds.width / 2;
+        let mid_y = bounds.y + bounds.height / 2;
+        match self {
+            Self::Center => (mid_x, mid_y),
+            Self::Top => (mid_x, bounds.y),
+            Self::Right => (bounds.x + bounds.width, mid_y),
+            Self::Bottom => (mid_x, bounds.y + bounds.height),
+            Self::Left => (bounds.x, mid_y),
+        }
+    }
+
+    /// Anchor on `bounds` closest to `point`.
+    pub fn nearest(bounds: Rect, point: (i32, i32)) -> Self {
+        Self::ALL
+            .into_iter()
+            .min_by_key(|anchor| {
+                let (x, y) = anchor.point_on(bounds);
+                let dx = i64::from(x) - i64::from(point.0);
+                let dy = i64::from(y) - i64::from(point.1);
+                dx * dx + dy * dy
+            })
+            .unwrap_or(Self::Center)
+    }
+}
+
+/// One attached end of a connector.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectorEnd {
+    pub shape_id: ShapeId,
+    pub anchor: ConnectorAnchor,
+}
+
+/// Attachments of a line or arrow whose ends follow other shapes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Connector {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<ConnectorEnd>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<ConnectorEnd>,
+}
+
+impl Connector {
+    /// Builds a connector from its ends, or `None` when neither end is attached.
+    pub fn from_ends(start: Option<ConnectorEnd>, end: Option<ConnectorEnd>) -> Option<Self> {
+        (start.is_some() || end.is_some()).then_some(Self { start, end })
+    }
+
+    /// Ids of the shapes this connector is attached to.
+    pub fn targets(&self) -> impl Iterator<Item = ShapeId> {
+        self.start
+            .into_iter()
+            .chain(self.end)
+            .map(|attached| attached.shape_id)
+    }
+
+    /// Drops the ends whose target fails `keep`, returning what remains attached.
+    pub fn retain_targets(self, keep: impl Fn(ShapeId) -> bool) -> Option<Self> {
+        let keep_end = |attached: Option<ConnectorEnd>| attached.filter(|e| keep(e.shape_id));
+        Self::from_ends(keep_end(self.start), keep_end(self.end))
+    }
+}
+
+/// A connector whose geometry moved during [`Frame::reroute_connectors`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReroutedConnector {
+    pub shape_id: ShapeId,
+    pub before: Option<Rect>,
+    pub after: Option<Rect>,
+}
+
+impl Frame {
+    /// Moves every attached connector end onto the current anchor of its
+    /// target. Connectors in `skip` are left alone, as are locked connectors
+    /// and ends whose target no longer exists.
+    pub fn reroute_connectors(&mut self, skip: &HashSet<ShapeId>) -> Vec<ReroutedConnector> {
+        let targets: HashSet<ShapeId> = self
+            .shapes
+            .iter()
+            .filter_map(|shape| shape.connector)
+            .flat_map(|connector| connector.targets().collect::<Vec<_>>())
+            .collect();
+        if targets.is_empty() {
+            return Vec::new();
+        }
+        let target_bounds: HashMap<ShapeId, Rect> = self
+            .shapes
+            .iter()
+            .filter(|shape| targets.contains(&shape.id))
+            .filter_map(|shape| Some((shape.id, shape.bounding_box()?)))
+            .collect();
+        let anchor_point = |attached: Option<ConnectorEnd>| {
+            let attached = attached?;
+            let bounds = target_bounds.get(&attached.shape_id)?;
+            Some(attached.anchor.point_on(*bounds))
+        };
+
+        let mut rerouted = Vec::new();
+        for drawn in &mut self.shapes {
+            let Some(connector) = drawn.connector else {
+                continue;
+            };
+            if drawn.locked || skip.contains(&drawn.id) {
+                continue;
+            }
+            let start = anchor_point(connector.start);
+            let end = anchor_point(connector.end);
+            let Some(shape) = routed_shape(&drawn.shape, start, end) else {
+                continue;
+            };
+            let before = drawn.bounding_box();
+            drawn.set_shape(shape);
+            rerouted.push(ReroutedConnector {
+                shape_id: drawn.id,
+                before,
+                after: drawn.bounding_box(),
+            });
+        }
+        rerouted
+    }
+}
+
+/// Copy of a line or arrow with its ends moved, or `None` when nothing moves.
+fn routed_shape(
+    shape: &Shape,
+    start: Option<(i32, i32)>,
+    end: Option<(i32, i32)>,
+) -> Option<Shape> {
+    let mut routed = shape.clone();
+    let (x1, y1, x2, y2) = match &mut routed {
+        Shape::Line { x1, y1, x2, y2, .. } | Shape::Arrow { x1, y1, x2, y2, .. } => {
+            (x1, y1, x2, y2)
+        }
+        _ => return None,
+    };
+    let mut changed = false;
+    if let Some((x, y)) = start
+        && (*x1, *y1) != (x, y)
+    {
+        (*x1, *y1) = (x, y);
+        changed = true;
+    }
+    if let Some((x, y)) = end
+        && (*x2, *y2) != (x, y)
+    {
+        (*x2, *y2) = (x, y);
+        changed = true;
+    }
+    changed.then_some(routed)
+}
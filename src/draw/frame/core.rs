@@ -1,16 +1,31 @@
+use super::background::PageBackground;
 use super::frame_storage;
-use super::types::{DrawnShape, ShapeId, UndoAction};
+use super::layer::{self, Layer, LayerId};
+use super::types::{DrawnShape, GroupId, ShapeId, UndoAction};
 use crate::draw::shape::Shape;
 use serde::Serialize;
+use std::collections::HashSet;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Frame {
+    /// Shapes in stacking order, bottom first. Shapes are kept grouped by
+    /// layer in the order of [`Frame::layers`].
     #[serde(with = "frame_storage")]
     pub shapes: Vec<DrawnShape>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub page_name: Option<String>,
     #[serde(default, skip_serializing_if = "is_origin_offset")]
     pub view_offset: (i32, i32),
+    /// Imported document page painted beneath the shapes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background: Option<PageBackground>,
+    #[serde(
+        default = "layer::default_stack",
+        skip_serializing_if = "layer::is_default_stack"
+    )]
+    pub(super) layers: Vec<Layer>,
+    #[serde(default, skip_serializing_if = "layer::is_base_layer")]
+    pub(super) active_layer: LayerId,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub(super) undo_stack: Vec<UndoAction>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -32,6 +47,9 @@ impl Frame {
             shapes: Vec::new(),
             page_name: None,
             view_offset: (0, 0),
+            background: None,
+            layers: layer::default_stack(),
+            active_layer: 0,
             undo_stack: Vec::new(),
             redo_stack: Vec::new(),
             next_shape_id: 1,
@@ -45,6 +63,8 @@ impl Frame {
         self.undo_stack.clear();
         self.redo_stack.clear();
         self.view_offset = (0, 0);
+        self.layers = layer::default_stack();
+        self.active_layer = 0;
         self.next_shape_id = 1;
     }
 
@@ -54,6 +74,26 @@ impl Frame {
         frame.shapes = self.shapes.clone();
         frame.page_name = self.page_name.clone();
         frame.view_offset = self.view_offset;
+        frame.background = self.background.clone();
+        frame.layers = self.layers.clone();
+        frame.active_layer = self.active_layer;
+        frame.rebuild_next_id();
+        frame
+    }
+
+    /// A history-free frame holding only the shapes in `ids`, in stacking
+    /// order, on a copy of this frame's layers so they composite as drawn.
+    pub fn excerpt(&self, ids: &[ShapeId]) -> Self {
+        let ids: HashSet<ShapeId> = ids.iter().copied().collect();
+        let mut frame = Frame::new();
+        frame.shapes = self
+            .shapes
+            .iter()
+            .filter(|shape| ids.contains(&shape.id))
+            .cloned()
+            .collect();
+        frame.layers = self.layers.clone();
+        frame.active_layer = self.active_layer;
         frame.rebuild_next_id();
         frame
     }
@@ -91,6 +131,8 @@ impl Frame {
         !self.shapes.is_empty()
             || self.page_name.is_some()
             || self.view_offset != (0, 0)
+            || self.background.is_some()
+            || !layer::is_default_stack(&self.layers)
             || !self.undo_stack.is_empty()
             || !self.redo_stack.is_empty()
     }
@@ -99,9 +141,40 @@ impl Frame {
         !self.shapes.is_empty()
             || self.page_name.is_some()
             || self.view_offset != (0, 0)
+            || self.background.is_some()
+            || !layer::is_default_stack(&self.layers)
             || (history_limit > 0 && (!self.undo_stack.is_empty() || !self.redo_stack.is_empty()))
     }
 
+    pub fn background(&self) -> Option<&PageBackground> {
+        self.background.as_ref()
+    }
+
+    /// Replaces the imported page background; returns false if unchanged.
+    pub fn set_background(&mut self, background: Option<PageBackground>) -> bool {
+        if self.background == background {
+            return false;
+        }
+        self.background = background;
+        true
+    }
+
+    /// Replaces the imported page background as one undoable step; returns
+    /// false if unchanged.
+    pub fn replace_background(
+        &mut self,
+        background: Option<PageBackground>,
+        undo_limit: usize,
+    ) -> bool {
+        let from = self.background.clone();
+        if !self.set_background(background) {
+            return false;
+        }
+        let to = self.background.clone();
+        self.push_undo_action(UndoAction::Background { from, to }, undo_limit);
+        true
+    }
+
     pub fn view_offset(&self) -> (i32, i32) {
         self.view_offset
     }
@@ -121,9 +194,9 @@ impl Frame {
         self.set_view_offset(next_x, next_y)
     }
 
-    /// Adds a shape at the end of the stack and returns its identifier.
+    /// Adds a shape on top of the active layer and returns its identifier.
     pub fn add_shape(&mut self, shape: Shape) -> ShapeId {
-        let index = self.shapes.len();
+        let index = self.layer_span(self.active_layer_id()).end;
         let id = self.insert_new_shape(index, shape);
         self.redo_stack.clear();
         id
@@ -145,9 +218,9 @@ impl Frame {
     }
 
     #[allow(dead_code)]
-    /// Inserts a shape at the given index and returns its identifier.
+    /// Inserts a shape on the active layer at the given index, clamped into
+    /// that layer, and returns its identifier.
     pub fn insert_shape_at(&mut self, index: usize, shape: Shape) -> ShapeId {
-        let index = index.min(self.shapes.len());
         let id = self.insert_new_shape(index, shape);
         self.redo_stack.clear();
         id
@@ -174,9 +247,36 @@ impl Frame {
         Some((index, self.shapes.remove(index)))
     }
 
-    /// Moves a shape from one index to another.
+    /// Ids of every shape in `group`, in stacking order.
+    pub fn group_members(&self, group: GroupId) -> impl Iterator<Item = ShapeId> + '_ {
+        self.shapes
+            .iter()
+            .filter(move |shape| shape.group == Some(group))
+            .map(|shape| shape.id)
+    }
+
+    /// Returns a group id that no shape and no history entry uses, so undoing
+    /// an older ungroup can never merge its shapes into a newer group.
+    pub fn next_group_id(&self) -> GroupId {
+        let shapes_max = self
+            .shapes
+            .iter()
+            .filter_map(|shape| shape.group)
+            .max()
+            .unwrap_or(0);
+        let history_max = self
+            .undo_stack
+            .iter()
+            .chain(self.redo_stack.iter())
+            .filter_map(|action| action.max_group_id())
+            .max()
+            .unwrap_or(0);
+        shapes_max.max(history_max).saturating_add(1)
+    }
+
+    /// Moves a shape from one index to another within its layer.
     pub fn move_shape(&mut self, from: usize, to: usize) -> Option<()> {
-        if from >= self.shapes.len() || to >= self.shapes.len() {
+        if from >= self.shapes.len() || !self.layer_span(self.shapes[from].layer).contains(&to) {
             return None;
         }
         if from == to {
@@ -193,13 +293,20 @@ impl Frame {
 
     pub(super) fn insert_new_shape(&mut self, index: usize, shape: Shape) -> ShapeId {
         let id = self.generate_id();
-        let drawn = DrawnShape::new(id, shape);
+        let mut drawn = DrawnShape::new(id, shape);
+        drawn.layer = self.active_layer_id();
         self.insert_existing(index, drawn);
         id
     }
 
-    pub(super) fn insert_existing(&mut self, index: usize, drawn: DrawnShape) {
+    /// Inserts `drawn` as close to `index` as its layer allows. Shapes whose
+    /// layer has since been deleted land on the active layer.
+    pub(super) fn insert_existing(&mut self, index: usize, mut drawn: DrawnShape) {
         self.mark_id_used(drawn.id);
+        if self.layer(drawn.layer).is_none() {
+            drawn.layer = self.active_layer_id();
+        }
+        let index = self.clamp_into_layer(index, &drawn);
         self.shapes.insert(index, drawn);
     }
 
@@ -1,4 +1,7 @@
-use super::types::{DrawnShape, ShapeId, current_timestamp_ms};
+use super::appearance::ShapeAppearance;
+use super::connector::Connector;
+use super::layer::{LayerId, is_base_layer};
+use super::types::{DrawnShape, GroupId, ShapeId, current_timestamp_ms};
 use crate::draw::shape::Shape;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -13,6 +16,10 @@ where
             shape: &shape.shape,
             created_at: shape.created_at,
             locked: shape.locked,
+            group: shape.group,
+            connector: shape.connector,
+            appearance: shape.appearance,
+            layer: shape.layer,
         })
         .collect();
     helper.serialize(serializer)
@@ -35,12 +42,12 @@ where
                 }
                 let created_at = helper.created_at.unwrap_or_else(current_timestamp_ms);
                 let locked = helper.locked.unwrap_or(false);
-                shapes.push(DrawnShape::with_metadata(
-                    id,
-                    helper.shape,
-                    created_at,
-                    locked,
-                ));
+                let mut shape = DrawnShape::with_metadata(id, helper.shape, created_at, locked);
+                shape.group = helper.group;
+                shape.connector = helper.connector;
+                shape.appearance = helper.appearance;
+                shape.layer = helper.layer;
+                shapes.push(shape);
                 next_id = next_id.max(id.saturating_add(1));
             }
             ShapeElement::Legacy(shape) => {
@@ -65,6 +72,14 @@ struct SerializedDrawnShape<'a> {
     shape: &'a Shape,
     created_at: u64,
     locked: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<GroupId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    connector: Option<Connector>,
+    #[serde(skip_serializing_if = "ShapeAppearance::is_default")]
+    appearance: ShapeAppearance,
+    #[serde(skip_serializing_if = "is_base_layer")]
+    layer: LayerId,
 }
 
 #[derive(Deserialize)]
@@ -83,4 +98,12 @@ struct WithMeta {
     created_at: Option<u64>,
     #[serde(default)]
     locked: Option<bool>,
+    #[serde(default)]
+    group: Option<GroupId>,
+    #[serde(default)]
+    connector: Option<Connector>,
+    #[serde(default)]
+    appearance: ShapeAppearance,
+    #[serde(default)]
+    layer: LayerId,
 }
@@ -1,4 +1,5 @@
 use super::super::super::core::Frame;
+use super::super::super::layer::LayerId;
 use super::super::super::types::{DrawnShape, ShapeId, UndoAction};
 
 impl Frame {
@@ -32,6 +33,35 @@ impl Frame {
             } => {
                 self.move_shape_to(*shape_id, *to);
             }
+            UndoAction::Regroup { shape_id, to, .. } => {
+                if let Some(target) = self.shape_mut(*shape_id) {
+                    target.group = *to;
+                }
+            }
+            UndoAction::Reconnect { shape_id, to, .. } => {
+                if let Some(target) = self.shape_mut(*shape_id) {
+                    target.connector = *to;
+                }
+            }
+            UndoAction::Restyle { shape_id, to, .. } => {
+                if let Some(target) = self.shape_mut(*shape_id) {
+                    target.appearance = *to;
+                }
+            }
+            UndoAction::Relayer {
+                shape_id,
+                to_layer,
+                to,
+                ..
+            } => {
+                self.place_on_layer(*shape_id, *to_layer, *to);
+            }
+            UndoAction::LayerChange { after, .. } => {
+                self.restore_layer_table(after);
+            }
+            UndoAction::Background { to, .. } => {
+                self.set_background(to.clone());
+            }
             UndoAction::Compound { actions } => {
                 for action in actions {
                     self.apply_action(action);
@@ -66,6 +96,35 @@ impl Frame {
             UndoAction::Reorder { shape_id, from, .. } => {
                 self.move_shape_to(*shape_id, *from);
             }
+            UndoAction::Regroup { shape_id, from, .. } => {
+                if let Some(target) = self.shape_mut(*shape_id) {
+                    target.group = *from;
+                }
+            }
+            UndoAction::Reconnect { shape_id, from, .. } => {
+                if let Some(target) = self.shape_mut(*shape_id) {
+                    target.connector = *from;
+                }
+            }
+            UndoAction::Restyle { shape_id, from, .. } => {
+                if let Some(target) = self.shape_mut(*shape_id) {
+                    target.appearance = *from;
+                }
+            }
+            UndoAction::Relayer {
+                shape_id,
+                from_layer,
+                from,
+                ..
+            } => {
+                self.place_on_layer(*shape_id, *from_layer, *from);
+            }
+            UndoAction::LayerChange { before, .. } => {
+                self.restore_layer_table(before);
+            }
+            UndoAction::Background { from, .. } => {
+                self.set_background(from.clone());
+            }
             UndoAction::Compound { actions } => {
                 for action in actions.iter().rev() {
                     self.apply_inverse(action);
@@ -92,22 +151,38 @@ impl Frame {
             if index < insert_index && insert_index > 0 {
                 insert_index -= 1;
             }
+            let insert_index = self.clamp_into_layer(insert_index, &shape);
             self.shapes.insert(insert_index, shape);
         }
     }
 
+    fn place_on_layer(&mut self, shape_id: ShapeId, layer: LayerId, target: usize) {
+        if let Some((_, mut shape)) = self.remove_shape_by_id(shape_id) {
+            shape.layer = layer;
+            self.insert_existing(target, shape);
+        }
+    }
+
     fn apply_image_bounds(
         &mut self,
         shape_id: ShapeId,
         bounds: super::super::super::types::ImageBoundsSnapshot,
     ) {
         if let Some(target) = self.shape_mut(shape_id)
-            && let crate::draw::shape::Shape::Image { x, y, w, h, .. } = &mut target.shape
+            && let crate::draw::shape::Shape::Image {
+                x,
+                y,
+                w,
+                h,
+                rotation,
+                ..
+            } = &mut target.shape
         {
             *x = bounds.x;
             *y = bounds.y;
             *w = bounds.w;
             *h = bounds.h;
+            *rotation = bounds.rotation;
             target.locked = bounds.locked;
             target.invalidate_bounds();
         }
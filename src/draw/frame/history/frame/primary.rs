@@ -13,7 +13,13 @@ impl Frame {
             UndoAction::Delete { shapes } => shapes.first().map(|(_, s)| s.shape.clone()),
             UndoAction::Modify { before, .. } => Some(before.shape.clone()),
             UndoAction::ModifyImageBounds { .. } => None,
-            UndoAction::Reorder { .. } => None,
+            UndoAction::Reorder { .. }
+            | UndoAction::Regroup { .. }
+            | UndoAction::Reconnect { .. }
+            | UndoAction::Restyle { .. }
+            | UndoAction::Relayer { .. }
+            | UndoAction::LayerChange { .. }
+            | UndoAction::Background { .. } => None,
             UndoAction::Compound { actions } => {
                 actions.iter().rev().find_map(Self::primary_shape_for_undo)
             }
@@ -27,7 +33,13 @@ impl Frame {
             UndoAction::Delete { shapes } => shapes.first().map(|(_, s)| s.shape.clone()),
             UndoAction::Modify { after, .. } => Some(after.shape.clone()),
             UndoAction::ModifyImageBounds { .. } => None,
-            UndoAction::Reorder { .. } => None,
+            UndoAction::Reorder { .. }
+            | UndoAction::Regroup { .. }
+            | UndoAction::Reconnect { .. }
+            | UndoAction::Restyle { .. }
+            | UndoAction::Relayer { .. }
+            | UndoAction::LayerChange { .. }
+            | UndoAction::Background { .. } => None,
             UndoAction::Compound { actions } => {
                 actions.iter().find_map(Self::primary_shape_for_redo)
             }
@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use super::super::types::{ShapeId, UndoAction};
+use super::super::types::{GroupId, ShapeId, UndoAction};
 
 impl UndoAction {
     pub(super) fn depth(&self) -> usize {
@@ -23,7 +23,12 @@ impl UndoAction {
             }
             UndoAction::Modify { shape_id, .. }
             | UndoAction::ModifyImageBounds { shape_id, .. } => Some(*shape_id),
-            UndoAction::Reorder { shape_id, .. } => Some(*shape_id),
+            UndoAction::Reorder { shape_id, .. }
+            | UndoAction::Regroup { shape_id, .. }
+            | UndoAction::Reconnect { shape_id, .. }
+            | UndoAction::Restyle { shape_id, .. }
+            | UndoAction::Relayer { shape_id, .. } => Some(*shape_id),
+            UndoAction::LayerChange { .. } | UndoAction::Background { .. } => None,
             UndoAction::Compound { actions } => actions
                 .iter()
                 .filter_map(|action| action.max_shape_id())
@@ -31,6 +36,27 @@ impl UndoAction {
         }
     }
 
+    pub(in crate::draw::frame) fn max_group_id(&self) -> Option<GroupId> {
+        match self {
+            UndoAction::Create { shapes } | UndoAction::Delete { shapes } => {
+                shapes.iter().filter_map(|(_, shape)| shape.group).max()
+            }
+            UndoAction::Regroup { from, to, .. } => (*from).max(*to),
+            UndoAction::Modify { .. }
+            | UndoAction::ModifyImageBounds { .. }
+            | UndoAction::Reorder { .. }
+            | UndoAction::Reconnect { .. }
+            | UndoAction::Restyle { .. }
+            | UndoAction::Relayer { .. }
+            | UndoAction::LayerChange { .. }
+            | UndoAction::Background { .. } => None,
+            UndoAction::Compound { actions } => actions
+                .iter()
+                .filter_map(|action| action.max_group_id())
+                .max(),
+        }
+    }
+
     pub(super) fn prune_removed_shapes(&mut self, removed: &HashSet<ShapeId>) -> bool {
         match self {
             UndoAction::Create { shapes } | UndoAction::Delete { shapes } => {
@@ -39,7 +65,12 @@ impl UndoAction {
             }
             UndoAction::Modify { shape_id, .. }
             | UndoAction::ModifyImageBounds { shape_id, .. }
-            | UndoAction::Reorder { shape_id, .. } => !removed.contains(shape_id),
+            | UndoAction::Reorder { shape_id, .. }
+            | UndoAction::Regroup { shape_id, .. }
+            | UndoAction::Reconnect { shape_id, .. }
+            | UndoAction::Restyle { shape_id, .. }
+            | UndoAction::Relayer { shape_id, .. } => !removed.contains(shape_id),
+            UndoAction::LayerChange { .. } | UndoAction::Background { .. } => true,
             UndoAction::Compound { actions } => {
                 actions.retain_mut(|action| action.prune_removed_shapes(removed));
                 !actions.is_empty()
@@ -49,10 +80,17 @@ impl UndoAction {
 
     pub(super) fn validate_against_shapes(&mut self, ids: &HashSet<ShapeId>) -> bool {
         match self {
-            UndoAction::Create { .. } | UndoAction::Delete { .. } => true,
+            UndoAction::Create { .. }
+            | UndoAction::Delete { .. }
+            | UndoAction::LayerChange { .. }
+            | UndoAction::Background { .. } => true,
             UndoAction::Modify { shape_id, .. }
             | UndoAction::ModifyImageBounds { shape_id, .. }
-            | UndoAction::Reorder { shape_id, .. } => ids.contains(shape_id),
+            | UndoAction::Reorder { shape_id, .. }
+            | UndoAction::Regroup { shape_id, .. }
+            | UndoAction::Reconnect { shape_id, .. }
+            | UndoAction::Restyle { shape_id, .. }
+            | UndoAction::Relayer { shape_id, .. } => ids.contains(shape_id),
             UndoAction::Compound { actions } => {
                 actions.retain_mut(|action| action.validate_against_shapes(ids));
                 !actions.is_empty()
@@ -69,9 +107,14 @@ impl UndoAction {
             }
             UndoAction::Modify { shape_id, .. }
             | UndoAction::ModifyImageBounds { shape_id, .. }
-            | UndoAction::Reorder { shape_id, .. } => {
+            | UndoAction::Reorder { shape_id, .. }
+            | UndoAction::Regroup { shape_id, .. }
+            | UndoAction::Reconnect { shape_id, .. }
+            | UndoAction::Restyle { shape_id, .. }
+            | UndoAction::Relayer { shape_id, .. } => {
                 ids.insert(*shape_id);
             }
+            UndoAction::LayerChange { .. } | UndoAction::Background { .. } => {}
             UndoAction::Compound { actions } => {
                 for action in actions {
                     action.collect_ids(ids);
@@ -0,0 +1,391 @@
+use std::ops::Range;
+
+use serde::{Deserialize, Serialize};
+
+use super::appearance::{BlendMode, ShapeAppearance};
+use super::core::Frame;
+use super::types::{DrawnShape, ShapeId, UndoAction};
+
+/// Identifier of a layer within a frame. The base layer every page starts
+/// with, and that sessions from before layers existed load into, is `0`.
+pub type LayerId = u64;
+
+/// Most layers a single page can hold.
+pub const MAX_LAYERS: usize = 8;
+
+/// A named slice of a page's shapes that can be hidden, locked, or faded as a
+/// whole.
+///
+/// Layers stack in the order they appear in [`Frame::layers`], bottom first.
+/// Each layer is composited on its own, so an eraser stroke only cuts through
+/// ink on its layer and a faded layer fades as one sheet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Layer {
+    #[serde(default)]
+    pub id: LayerId,
+    pub name: String,
+    #[serde(default = "default_visible", skip_serializing_if = "is_true")]
+    pub visible: bool,
+    /// Locked layers still render but cannot be selected, erased, or cleared.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub locked: bool,
+    #[serde(default = "default_opacity", skip_serializing_if = "is_opaque")]
+    pub opacity: f64,
+}
+
+impl Layer {
+    pub fn new(id: LayerId, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            visible: true,
+            locked: false,
+            opacity: default_opacity(),
+        }
+    }
+
+    /// The layer every frame starts with.
+    pub fn base() -> Self {
+        Self::new(0, "Layer 1")
+    }
+
+    /// True when the layer's shapes can be hit-tested, selected, and erased.
+    pub fn editable(&self) -> bool {
+        self.visible && !self.locked
+    }
+
+    /// How the layer's pixels are composited onto the layers beneath it.
+    pub fn appearance(&self) -> ShapeAppearance {
+        ShapeAppearance {
+            opacity: self.opacity,
+            blend: BlendMode::Normal,
+        }
+    }
+
+    fn is_default_base(&self) -> bool {
+        *self == Self::base()
+    }
+}
+
+/// A page's layer stack and active layer, as a layer undo step records them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayerTable {
+    pub layers: Vec<Layer>,
+    pub active_layer: LayerId,
+}
+
+impl Frame {
+    /// The page's layers, bottom first. Never empty.
+    pub fn layers(&self) -> &[Layer] {
+        &self.layers
+    }
+
+    pub fn layer(&self, id: LayerId) -> Option<&Layer> {
+        self.layers.iter().find(|layer| layer.id == id)
+    }
+
+    /// Stacking position of a layer, 0 being the bottom.
+    pub fn layer_position(&self, id: LayerId) -> Option<usize> {
+        self.layers.iter().position(|layer| layer.id == id)
+    }
+
+    /// The layer new shapes are drawn onto.
+    pub fn active_layer(&self) -> &Layer {
+        self.layer(self.active_layer)
+            .unwrap_or_else(|| &self.layers[0])
+    }
+
+    pub fn active_layer_id(&self) -> LayerId {
+        self.active_layer().id
+    }
+
+    /// Makes `id` the layer new shapes go to; returns false if unchanged.
+    pub fn set_active_layer(&mut self, id: LayerId) -> bool {
+        if self.active_layer == id || self.layer(id).is_none() {
+            return false;
+        }
+        self.active_layer = id;
+        true
+    }
+
+    /// True when shapes on `id` can be hit-tested, selected, and erased.
+    /// Shapes on a layer the frame no longer knows count as editable so they
+    /// can always be cleaned up.
+    pub fn is_layer_editable(&self, id: LayerId) -> bool {
+        self.layer(id).is_none_or(Layer::editable)
+    }
+
+    /// True when `drawn` sits on a visible, unlocked layer.
+    pub fn is_shape_editable(&self, drawn: &DrawnShape) -> bool {
+        self.is_layer_editable(drawn.layer)
+    }
+
+    /// True when `drawn` sits on a layer that renders.
+    pub fn is_shape_visible(&self, drawn: &DrawnShape) -> bool {
+        self.layer(drawn.layer).is_none_or(|layer| layer.visible)
+    }
+
+    /// Index range of the shapes on `id` within [`Frame::shapes`]. For an
+    /// empty layer the range is empty and starts where its first shape would
+    /// be inserted.
+    pub fn layer_span(&self, id: LayerId) -> Range<usize> {
+        let Some(position) = self.layer_position(id) else {
+            return self.shapes.len()..self.shapes.len();
+        };
+        let start = self
+            .shapes
+            .iter()
+            .take_while(|drawn| self.layer_position(drawn.layer).unwrap_or(0) < position)
+            .count();
+        let len = self.shapes[start..]
+            .iter()
+            .take_while(|drawn| drawn.layer == id)
+            .count();
+        start..start + len
+    }
+
+    /// Shapes on `id`, in stacking order.
+    pub fn shapes_on_layer(&self, id: LayerId) -> &[DrawnShape] {
+        &self.shapes[self.layer_span(id)]
+    }
+
+    /// Adds an empty layer on top of the stack and makes it active. Returns
+    /// `None` once the page holds [`MAX_LAYERS`].
+    pub fn add_layer(&mut self) -> Option<LayerId> {
+        if self.layers.len() >= MAX_LAYERS {
+            return None;
+        }
+        let id = self
+            .layers
+            .iter()
+            .map(|layer| layer.id)
+            .max()
+            .unwrap_or(0)
+            .saturating_add(1);
+        let mut number = self.layers.len() + 1;
+        while self
+            .layers
+            .iter()
+            .any(|layer| layer.name == format!("Layer {number}"))
+        {
+            number += 1;
+        }
+        self.layers.push(Layer::new(id, format!("Layer {number}")));
+        self.active_layer = id;
+        Some(id)
+    }
+
+    /// Removes an empty layer. The last remaining layer and layers that still
+    /// hold shapes are kept, so deleting a layer never deletes ink.
+    pub fn remove_layer(&mut self, id: LayerId) -> bool {
+        let Some(position) = self.layer_position(id) else {
+            return false;
+        };
+        if self.layers.len() <= 1 || !self.layer_span(id).is_empty() {
+            return false;
+        }
+        self.layers.remove(position);
+        if self.active_layer == id {
+            self.active_layer = self.layers[position.saturating_sub(1)].id;
+        }
+        true
+    }
+
+    pub fn rename_layer(&mut self, id: LayerId, name: &str) -> bool {
+        let name = name.trim();
+        match self.layers.iter_mut().find(|layer| layer.id == id) {
+            Some(layer) if !name.is_empty() && layer.name != name => {
+                layer.name = name.to_string();
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn set_layer_visible(&mut self, id: LayerId, visible: bool) -> bool {
+        self.update_layer(id, |layer| {
+            let changed = layer.visible != visible;
+            layer.visible = visible;
+            changed
+        })
+    }
+
+    pub fn set_layer_locked(&mut self, id: LayerId, locked: bool) -> bool {
+        self.update_layer(id, |layer| {
+            let changed = layer.locked != locked;
+            layer.locked = locked;
+            changed
+        })
+    }
+
+    pub fn set_layer_opacity(&mut self, id: LayerId, opacity: f64) -> bool {
+        let opacity = opacity.clamp(ShapeAppearance::MIN_OPACITY, 1.0);
+        self.update_layer(id, |layer| {
+            let changed = (layer.opacity - opacity).abs() > f64::EPSILON;
+            layer.opacity = opacity;
+            changed
+        })
+    }
+
+    /// Moves a shape onto the top of `layer` and returns the undo step, or
+    /// `None` when the shape or layer is missing or the shape is already there.
+    pub fn move_shape_to_layer(&mut self, id: ShapeId, layer: LayerId) -> Option<UndoAction> {
+        let from_layer = self.shape(id)?.layer;
+        if from_layer == layer || self.layer(layer).is_none() {
+            return None;
+        }
+        let (from, mut drawn) = self.remove_shape_by_id(id)?;
+        drawn.layer = layer;
+        let to = self.layer_span(layer).end;
+        self.shapes.insert(to, drawn);
+        Some(UndoAction::Relayer {
+            shape_id: id,
+            from_layer,
+            to_layer: layer,
+            from,
+            to,
+        })
+    }
+
+    /// The nearest editable layer above (`up`) or below `from`, skipping
+    /// hidden and locked ones.
+    pub fn neighbor_editable_layer(&self, from: LayerId, up: bool) -> Option<LayerId> {
+        let position = self.layer_position(from)?;
+        if up {
+            self.layers[position + 1..]
+                .iter()
+                .find(|layer| layer.editable())
+                .map(|layer| layer.id)
+        } else {
+            self.layers[..position]
+                .iter()
+                .rev()
+                .find(|layer| layer.editable())
+                .map(|layer| layer.id)
+        }
+    }
+
+    /// When the active layer is hidden or locked, moves the active layer to
+    /// the nearest editable one, preferring the layers beneath. Returns the
+    /// new active layer if it changed.
+    pub fn ensure_active_layer_editable(&mut self) -> Option<LayerId> {
+        let active = self.active_layer_id();
+        if self.is_layer_editable(active) {
+            return None;
+        }
+        let next = self
+            .neighbor_editable_layer(active, false)
+            .or_else(|| self.neighbor_editable_layer(active, true))?;
+        self.active_layer = next;
+        Some(next)
+    }
+
+    /// Index at which `drawn` may be inserted so shapes stay grouped by
+    /// layer: `index` clamped into the span of the shape's layer.
+    pub(super) fn clamp_into_layer(&self, index: usize, drawn: &DrawnShape) -> usize {
+        let span = self.layer_span(drawn.layer);
+        index.clamp(span.start, span.end)
+    }
+
+    /// Repairs the layer stack after loading: guarantees at least one layer,
+    /// moves shapes on unknown layers onto the bottom layer, and regroups
+    /// shapes by layer while keeping their order within each layer.
+    pub(super) fn normalize_layers(&mut self) {
+        let mut seen = std::collections::HashSet::new();
+        self.layers.retain(|layer| seen.insert(layer.id));
+        self.layers.truncate(MAX_LAYERS);
+        if self.layers.is_empty() {
+            self.layers.push(Layer::base());
+        }
+        let bottom = self.layers[0].id;
+        for drawn in &mut self.shapes {
+            if !self.layers.iter().any(|layer| layer.id == drawn.layer) {
+                drawn.layer = bottom;
+            }
+        }
+        let positions: Vec<usize> = self
+            .shapes
+            .iter()
+            .map(|drawn| self.layer_position(drawn.layer).unwrap_or(0))
+            .collect();
+        if positions.windows(2).any(|pair| pair[0] > pair[1]) {
+            let mut ordered: Vec<_> = positions
+                .into_iter()
+                .zip(std::mem::take(&mut self.shapes))
+                .collect();
+            ordered.sort_by_key(|(position, _)| *position);
+            self.shapes = ordered.into_iter().map(|(_, drawn)| drawn).collect();
+        }
+        if self.layer(self.active_layer).is_none() {
+            self.active_layer = bottom;
+        }
+        if !self.layers.iter().any(Layer::editable) {
+            let active = self.active_layer;
+            self.update_layer(active, |layer| {
+                layer.visible = true;
+                layer.locked = false;
+                true
+            });
+        } else {
+            self.ensure_active_layer_editable();
+        }
+    }
+
+    /// Snapshot of the layer stack for [`UndoAction::LayerChange`].
+    pub fn layer_table(&self) -> LayerTable {
+        LayerTable {
+            layers: self.layers.clone(),
+            active_layer: self.active_layer,
+        }
+    }
+
+    /// Puts back a recorded layer stack. Shapes on a layer the table lacks
+    /// move to the bottom layer, as when loading.
+    pub(super) fn restore_layer_table(&mut self, table: &LayerTable) {
+        self.layers = table.layers.clone();
+        self.active_layer = table.active_layer;
+        self.normalize_layers();
+    }
+
+    fn update_layer(&mut self, id: LayerId, update: impl FnOnce(&mut Layer) -> bool) -> bool {
+        self.layers
+            .iter_mut()
+            .find(|layer| layer.id == id)
+            .is_some_and(update)
+    }
+}
+
+/// Serde skip predicate: a lone, untouched base layer is what sessions
+/// written before layers existed implicitly have.
+pub(super) fn is_default_stack(layers: &[Layer]) -> bool {
+    matches!(layers, [layer] if layer.is_default_base())
+}
+
+/// Serde skip predicate for shapes and frames on the base layer.
+pub(super) fn is_base_layer(id: &LayerId) -> bool {
+    *id == 0
+}
+
+pub(super) fn default_stack() -> Vec<Layer> {
+    vec![Layer::base()]
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+fn default_opacity() -> f64 {
+    1.0
+}
+
+fn is_true(value: &bool) -> bool {
+    *value
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+fn is_opaque(value: &f64) -> bool {
+    *value >= 1.0
+}
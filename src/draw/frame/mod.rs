@@ -1,17 +1,27 @@
 //! Frame container for managing collections of shapes with undo/redo support.
 
+mod appearance;
+mod background;
+mod connector;
 mod core;
 mod frame_storage;
 mod history;
+mod layer;
+mod replay;
 mod serde;
 mod types;
 
 #[cfg(test)]
 mod tests;
 
+pub use appearance::{BlendMode, ShapeAppearance};
+pub use background::{PageBackground, PageBackgroundSource};
+pub use connector::{Connector, ConnectorAnchor, ConnectorEnd, ReroutedConnector};
 pub use core::Frame;
+pub use layer::{Layer, LayerId, LayerTable, MAX_LAYERS};
+pub use replay::ReplayTimeline;
 #[allow(unused_imports)]
 pub use types::{
-    DrawnShape, HistoryTrimStats, ImageBoundsSnapshot, MAX_COMPOUND_DEPTH, ShapeId, ShapeSnapshot,
-    UndoAction,
+    DrawnShape, GroupId, HistoryTrimStats, ImageBoundsSnapshot, MAX_COMPOUND_DEPTH, ShapeId,
+    ShapeSnapshot, UndoAction,
 };
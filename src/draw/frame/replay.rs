@@ -0,0 +1,106 @@
+use std::collections::HashSet;
+
+use super::core::Frame;
+use crate::draw::shape::Shape;
+
+/// The shapes of a page in the order they were drawn, for replaying how the
+/// page was built.
+///
+/// Positions are measured in shapes: at position `n` the first `n` shapes
+/// are on the page, and the fractional part draws the next stroke part-way.
+/// Every shape keeps its final stacking order while it appears.
+#[derive(Debug, Clone)]
+pub struct ReplayTimeline {
+    frame: Frame,
+    order: Vec<usize>,
+}
+
+impl ReplayTimeline {
+    pub fn new(frame: &Frame) -> Self {
+        let frame = frame.clone_without_history();
+        let mut order: Vec<usize> = (0..frame.shapes.len()).collect();
+        order.sort_by_key(|&index| {
+            let drawn = &frame.shapes[index];
+            (drawn.created_at, drawn.id)
+        });
+        Self { frame, order }
+    }
+
+    /// Number of shapes the replay steps through.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// The page as it looked `position` shapes into the replay.
+    pub fn frame_at(&self, position: f64) -> Frame {
+        let position = position.clamp(0.0, self.len() as f64);
+        let complete = position.floor() as usize;
+        let visible: HashSet<usize> = self.order[..complete].iter().copied().collect();
+        let partial = self
+            .order
+            .get(complete)
+            .map(|&index| (index, position - complete as f64))
+            .filter(|(_, fraction)| *fraction > 0.0);
+
+        let mut frame = Frame::new();
+        frame.page_name = self.frame.page_name.clone();
+        frame.view_offset = self.frame.view_offset;
+        frame.background = self.frame.background.clone();
+        frame.layers = self.frame.layers.clone();
+        frame.active_layer = self.frame.active_layer;
+        for (index, drawn) in self.frame.shapes.iter().enumerate() {
+            if visible.contains(&index) {
+                frame.shapes.push(drawn.clone());
+            } else if let Some((partial_index, fraction)) = partial
+                && partial_index == index
+                && let Some(shape) = partial_shape(&drawn.shape, fraction)
+            {
+                let mut drawn = drawn.clone();
+                drawn.set_shape(shape);
+                frame.shapes.push(drawn);
+            }
+        }
+        frame.rebuild_next_id();
+        frame
+    }
+}
+
+/// The first `fraction` of a stroke or line, or `None` for shapes that only
+/// appear once complete.
+fn partial_shape(shape: &Shape, fraction: f64) -> Option<Shape> {
+    fn prefix<T: Clone>(points: &[T], fraction: f64) -> Vec<T> {
+        let count = ((points.len() as f64 * fraction).ceil() as usize).clamp(1, points.len());
+        points[..count].to_vec()
+    }
+    fn lerp(from: i32, to: i32, fraction: f64) -> i32 {
+        from + ((to - from) as f64 * fraction).round() as i32
+    }
+
+    let mut partial = shape.clone();
+    match &mut partial {
+        Shape::Freehand { points, .. }
+        | Shape::MarkerStroke { points, .. }
+        | Shape::EraserStroke { points, .. } => {
+            if points.is_empty() {
+                return None;
+            }
+            *points = prefix(points, fraction);
+        }
+        Shape::FreehandPressure { points, .. } => {
+            if points.is_empty() {
+                return None;
+            }
+            *points = prefix(points, fraction);
+        }
+        Shape::Line { x1, y1, x2, y2, .. } | Shape::Arrow { x1, y1, x2, y2, .. } => {
+            *x2 = lerp(*x1, *x2, fraction);
+            *y2 = lerp(*y1, *y2, fraction);
+        }
+        _ => return None,
+    }
+    Some(partial)
+}
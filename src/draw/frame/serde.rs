@@ -1,6 +1,10 @@
+use super::appearance::ShapeAppearance;
+use super::background::PageBackground;
+use super::connector::Connector;
 use super::core::Frame;
 use super::frame_storage;
-use super::types::{DrawnShape, ShapeId, UndoAction};
+use super::layer::{Layer, LayerId, is_base_layer};
+use super::types::{DrawnShape, GroupId, ShapeId, UndoAction};
 use crate::draw::shape::Shape;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
@@ -29,6 +33,14 @@ struct PersistedDrawnShape {
     shape: Shape,
     created_at: u64,
     locked: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    group: Option<GroupId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    connector: Option<Connector>,
+    #[serde(default, skip_serializing_if = "ShapeAppearance::is_default")]
+    appearance: ShapeAppearance,
+    #[serde(default, skip_serializing_if = "is_base_layer")]
+    layer: LayerId,
 }
 
 impl From<&DrawnShape> for PersistedDrawnShape {
@@ -38,13 +50,23 @@ impl From<&DrawnShape> for PersistedDrawnShape {
             shape: value.shape.clone(),
             created_at: value.created_at,
             locked: value.locked,
+            group: value.group,
+            connector: value.connector,
+            appearance: value.appearance,
+            layer: value.layer,
         }
     }
 }
 
 impl From<PersistedDrawnShape> for DrawnShape {
     fn from(value: PersistedDrawnShape) -> Self {
-        DrawnShape::with_metadata(value.id, value.shape, value.created_at, value.locked)
+        let mut shape =
+            DrawnShape::with_metadata(value.id, value.shape, value.created_at, value.locked);
+        shape.group = value.group;
+        shape.connector = value.connector;
+        shape.appearance = value.appearance;
+        shape.layer = value.layer;
+        shape
     }
 }
 
@@ -62,6 +84,12 @@ impl<'de> Deserialize<'de> for Frame {
             #[serde(default)]
             view_offset: (i32, i32),
             #[serde(default)]
+            background: Option<PageBackground>,
+            #[serde(default)]
+            layers: Vec<Layer>,
+            #[serde(default)]
+            active_layer: LayerId,
+            #[serde(default)]
             undo_stack: Vec<UndoAction>,
             #[serde(default)]
             redo_stack: Vec<UndoAction>,
@@ -72,10 +100,14 @@ impl<'de> Deserialize<'de> for Frame {
             shapes: helper.shapes,
             page_name: helper.page_name,
             view_offset: helper.view_offset,
+            background: helper.background,
+            layers: helper.layers,
+            active_layer: helper.active_layer,
             undo_stack: helper.undo_stack,
             redo_stack: helper.redo_stack,
             next_shape_id: 1,
         };
+        frame.normalize_layers();
         frame.rebuild_next_id();
         Ok(frame)
     }
@@ -1,5 +1,5 @@
 use crate::draw::frame::{Frame, ImageBoundsSnapshot, UndoAction};
-use crate::draw::{EmbeddedImage, Shape, color::BLACK};
+use crate::draw::{EmbeddedImage, LineStyle, Shape, color::BLACK};
 
 fn rect_at(x: i32) -> Shape {
     Shape::Rect {
@@ -10,6 +10,8 @@ fn rect_at(x: i32) -> Shape {
         fill: false,
         color: BLACK,
         thick: 2.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     }
 }
 
@@ -23,6 +25,7 @@ fn undo_and_redo_cycle_shapes() {
         y2: 10,
         color: BLACK,
         thick: 2.0,
+        line_style: LineStyle::Solid,
     };
 
     let id = frame.add_shape(shape.clone());
@@ -120,6 +123,8 @@ fn adding_new_shape_clears_redo_stack() {
         fill: false,
         color: BLACK,
         thick: 2.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     };
     let id = frame.add_shape(first);
     frame.push_undo_action(
@@ -142,6 +147,8 @@ fn adding_new_shape_clears_redo_stack() {
         fill: false,
         color: BLACK,
         thick: 2.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     };
     frame.add_shape(second);
     assert_eq!(frame.redo_stack_len(), 0);
@@ -161,6 +168,7 @@ fn modify_image_bounds_undo_redo_changes_geometry_without_replacing_payload() {
             height: 8,
             bytes: vec![1, 2, 3, 4],
         },
+        rotation: 0.0,
     });
     if let Shape::Image { x, y, w, h, .. } = &mut frame.shape_mut(id).unwrap().shape {
         *x = 20;
@@ -178,6 +186,7 @@ fn modify_image_bounds_undo_redo_changes_geometry_without_replacing_payload() {
                 w: 10,
                 h: 8,
                 locked: false,
+                rotation: 0.0,
             },
             after: ImageBoundsSnapshot {
                 x: 20,
@@ -185,6 +194,7 @@ fn modify_image_bounds_undo_redo_changes_geometry_without_replacing_payload() {
                 w: 40,
                 h: 32,
                 locked: false,
+                rotation: 0.0,
             },
         },
         10,
@@ -192,7 +202,9 @@ fn modify_image_bounds_undo_redo_changes_geometry_without_replacing_payload() {
 
     frame.undo_last();
     match &frame.shape(id).unwrap().shape {
-        Shape::Image { x, y, w, h, data } => {
+        Shape::Image {
+            x, y, w, h, data, ..
+        } => {
             assert_eq!((*x, *y, *w, *h), (0, 0, 10, 8));
             assert_eq!(data.bytes, vec![1, 2, 3, 4]);
         }
@@ -201,10 +213,39 @@ fn modify_image_bounds_undo_redo_changes_geometry_without_replacing_payload() {
 
     frame.redo_last();
     match &frame.shape(id).unwrap().shape {
-        Shape::Image { x, y, w, h, data } => {
+        Shape::Image {
+            x, y, w, h, data, ..
+        } => {
             assert_eq!((*x, *y, *w, *h), (20, 30, 40, 32));
             assert_eq!(data.bytes, vec![1, 2, 3, 4]);
         }
         _ => panic!("expected image"),
     }
 }
+
+#[test]
+fn relayer_undo_returns_the_shape_to_its_layer_and_slot() {
+    let mut frame = Frame::new();
+    let bottom = frame.add_shape(rect_at(0));
+    let moved = frame.add_shape(rect_at(20));
+    let top_layer = frame.add_layer().expect("second layer");
+    let above = frame.add_shape(rect_at(40));
+
+    let action = frame
+        .move_shape_to_layer(bottom, top_layer)
+        .expect("shape moves to the new layer");
+    frame.push_undo_action(action, 10);
+    let order: Vec<_> = frame.shapes.iter().map(|drawn| drawn.id).collect();
+    assert_eq!(order, vec![moved, above, bottom]);
+    assert_eq!(frame.shape(bottom).unwrap().layer, top_layer);
+    assert!(!frame.remove_layer(top_layer));
+
+    frame.undo_last();
+    let order: Vec<_> = frame.shapes.iter().map(|drawn| drawn.id).collect();
+    assert_eq!(order, vec![bottom, moved, above]);
+    assert_eq!(frame.shape(bottom).unwrap().layer, 0);
+
+    frame.redo_last();
+    assert_eq!(frame.shape(bottom).unwrap().layer, top_layer);
+    assert_eq!(frame.layer_span(top_layer), 1..3);
+}
@@ -1,5 +1,5 @@
 use crate::draw::frame::{DrawnShape, Frame, UndoAction};
-use crate::draw::{Shape, color::BLACK};
+use crate::draw::{LineStyle, Shape, color::BLACK};
 
 #[test]
 fn undo_stack_respects_limit() {
@@ -12,6 +12,7 @@ fn undo_stack_respects_limit() {
             y2: 10,
             color: BLACK,
             thick: 2.0,
+            line_style: LineStyle::Solid,
         };
         let id = frame.add_shape(shape);
         let index = frame.find_index(id).unwrap();
@@ -38,6 +39,7 @@ fn clamp_history_depth_clears_both_stacks() {
             y2: 10,
             color: BLACK,
             thick: 2.0,
+            line_style: LineStyle::Solid,
         };
         let id = frame.add_shape(shape);
         let index = frame.find_index(id).unwrap();
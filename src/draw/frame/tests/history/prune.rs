@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use crate::draw::frame::{DrawnShape, Frame, ShapeSnapshot, UndoAction};
-use crate::draw::{Shape, color::BLACK};
+use crate::draw::{LineStyle, Shape, color::BLACK};
 
 #[test]
 fn prune_history_for_removed_ids_prunes_shapes_and_actions() {
@@ -13,6 +13,8 @@ fn prune_history_for_removed_ids_prunes_shapes_and_actions() {
         fill: false,
         color: BLACK,
         thick: 1.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     };
 
     let shape1 = DrawnShape::with_metadata(1, base_shape.clone(), 0, false);
@@ -68,6 +70,7 @@ fn prune_history_against_shapes_drops_actions_for_missing_ids() {
         y2: 10,
         color: BLACK,
         thick: 1.0,
+        line_style: LineStyle::Solid,
     });
 
     let existing_snapshot = ShapeSnapshot {
@@ -78,6 +81,7 @@ fn prune_history_against_shapes_drops_actions_for_missing_ids() {
             y2: 10,
             color: BLACK,
             thick: 1.0,
+            line_style: LineStyle::Solid,
         },
         locked: false,
     };
@@ -90,6 +94,7 @@ fn prune_history_against_shapes_drops_actions_for_missing_ids() {
             y2: 15,
             color: BLACK,
             thick: 1.0,
+            line_style: LineStyle::Solid,
         },
         locked: false,
     };
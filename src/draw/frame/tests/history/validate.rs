@@ -1,5 +1,5 @@
 use crate::draw::frame::{DrawnShape, Frame, UndoAction};
-use crate::draw::{Shape, color::BLACK};
+use crate::draw::{LineStyle, Shape, color::BLACK};
 
 #[test]
 fn validate_history_drops_actions_exceeding_compound_depth() {
@@ -10,6 +10,7 @@ fn validate_history_drops_actions_exceeding_compound_depth() {
         y2: 10,
         color: BLACK,
         thick: 1.0,
+        line_style: LineStyle::Solid,
     };
 
     let shallow_drawn = DrawnShape::with_metadata(1, base_shape.clone(), 0, false);
@@ -0,0 +1,77 @@
+use crate::draw::frame::{Frame, ReplayTimeline, ShapeId};
+use crate::draw::{LineStyle, Shape, color::BLACK};
+
+fn line(x: i32) -> Shape {
+    Shape::Line {
+        x1: x,
+        y1: 0,
+        x2: x + 40,
+        y2: 20,
+        color: BLACK,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    }
+}
+
+fn ids(frame: &Frame) -> Vec<ShapeId> {
+    frame.shapes.iter().map(|drawn| drawn.id).collect()
+}
+
+#[test]
+fn replay_follows_creation_order_but_keeps_stacking_order() {
+    let mut frame = Frame::new();
+    let bottom = frame.add_shape(line(0));
+    let stroke = frame.add_shape(Shape::Freehand {
+        points: vec![(0, 0), (10, 0), (20, 0), (30, 0)],
+        color: BLACK,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    });
+    let top = frame.add_shape(line(100));
+    // The top shape was drawn first and later raised above the others.
+    for (id, created_at) in [(bottom, 200), (stroke, 300), (top, 100)] {
+        frame.shape_mut(id).unwrap().created_at = created_at;
+    }
+
+    let timeline = ReplayTimeline::new(&frame);
+    assert_eq!(timeline.len(), 3);
+    assert!(ids(&timeline.frame_at(0.0)).is_empty());
+    assert_eq!(ids(&timeline.frame_at(1.0)), vec![top]);
+    assert_eq!(ids(&timeline.frame_at(2.0)), vec![bottom, top]);
+
+    let partial = timeline.frame_at(2.5);
+    assert_eq!(ids(&partial), vec![bottom, stroke, top]);
+    match &partial.shape(stroke).unwrap().shape {
+        Shape::Freehand { points, .. } => assert_eq!(points, &vec![(0, 0), (10, 0)]),
+        other => panic!("expected freehand, got {other:?}"),
+    }
+
+    assert_eq!(ids(&timeline.frame_at(99.0)), ids(&frame));
+}
+
+#[test]
+fn partial_lines_grow_from_their_start_and_other_shapes_wait() {
+    let mut frame = Frame::new();
+    let drawn_line = frame.add_shape(line(0));
+    frame.add_shape(Shape::Rect {
+        x: 0,
+        y: 0,
+        w: 10,
+        h: 10,
+        fill: false,
+        color: BLACK,
+        thick: 2.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
+    });
+    let timeline = ReplayTimeline::new(&frame);
+
+    let half = timeline.frame_at(0.5);
+    match &half.shape(drawn_line).unwrap().shape {
+        Shape::Line { x1, y1, x2, y2, .. } => assert_eq!((*x1, *y1, *x2, *y2), (0, 0, 20, 10)),
+        other => panic!("expected line, got {other:?}"),
+    }
+    // Shapes without a drawing path only show up once complete.
+    assert_eq!(timeline.frame_at(1.9).shapes.len(), 1);
+    assert_eq!(timeline.frame_at(2.0).shapes.len(), 2);
+}
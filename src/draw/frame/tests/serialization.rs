@@ -1,5 +1,8 @@
-use crate::draw::frame::{Frame, ImageBoundsSnapshot, UndoAction};
-use crate::draw::{EmbeddedImage, Shape, color::BLACK};
+use crate::draw::frame::{
+    BlendMode, Connector, ConnectorAnchor, ConnectorEnd, Frame, ImageBoundsSnapshot, Layer,
+    PageBackground, PageBackgroundSource, ShapeAppearance, UndoAction,
+};
+use crate::draw::{EmbeddedImage, LineStyle, Shape, color::BLACK};
 
 #[test]
 fn frame_serializes_history() {
@@ -11,6 +14,7 @@ fn frame_serializes_history() {
         y2: 10,
         color: BLACK,
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
     let first_index = frame.find_index(first).unwrap();
     frame.push_undo_action(
@@ -27,6 +31,7 @@ fn frame_serializes_history() {
         y2: 5,
         color: BLACK,
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
     let second_index = frame.find_index(second).unwrap();
     frame.push_undo_action(
@@ -53,6 +58,7 @@ fn frame_serializes_history() {
         y2: 6,
         color: BLACK,
         thick: 1.0,
+        line_style: LineStyle::Solid,
     });
     assert!(new_id > second);
 }
@@ -67,6 +73,7 @@ fn frame_with_history_is_persistable_even_without_shapes() {
         y2: 20,
         color: BLACK,
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
     let index = frame.find_index(id).unwrap();
     frame.push_undo_action(
@@ -106,6 +113,7 @@ fn try_add_shape_respects_limit() {
             y2: 1,
             color: BLACK,
             thick: 2.0,
+            line_style: LineStyle::Solid,
         },
         1
     ));
@@ -117,6 +125,7 @@ fn try_add_shape_respects_limit() {
             y2: 2,
             color: BLACK,
             thick: 2.0,
+            line_style: LineStyle::Solid,
         },
         1
     ));
@@ -138,6 +147,7 @@ fn image_bounds_history_serializes_without_duplicate_image_payloads() {
             height: 16,
             bytes,
         },
+        rotation: 0.0,
     });
     let index = frame.find_index(id).unwrap();
     frame.push_undo_action(
@@ -155,6 +165,7 @@ fn image_bounds_history_serializes_without_duplicate_image_payloads() {
                 w: 16,
                 h: 16,
                 locked: false,
+                rotation: 0.0,
             },
             after: ImageBoundsSnapshot {
                 x: 4,
@@ -162,6 +173,7 @@ fn image_bounds_history_serializes_without_duplicate_image_payloads() {
                 w: 32,
                 h: 32,
                 locked: false,
+                rotation: 0.0,
             },
         },
         100,
@@ -175,3 +187,239 @@ fn image_bounds_history_serializes_without_duplicate_image_payloads() {
         "visible shape plus create history should contain image bytes, bounds history should not"
     );
 }
+
+#[test]
+fn groups_survive_a_round_trip_and_are_not_reused() {
+    let mut frame = Frame::new();
+    let line = Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 10,
+        y2: 10,
+        color: BLACK,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    };
+    let first = frame.add_shape(line.clone());
+    let second = frame.add_shape(line);
+    for id in [first, second] {
+        frame.shape_mut(id).unwrap().group = Some(1);
+    }
+    // An ungroup still on the undo stack keeps its group id reserved.
+    frame.push_undo_action(
+        UndoAction::Regroup {
+            shape_id: first,
+            from: Some(7),
+            to: Some(1),
+        },
+        100,
+    );
+
+    let json = serde_json::to_string(&frame).expect("serialize frame");
+    let restored: Frame = serde_json::from_str(&json).expect("deserialize frame");
+    assert_eq!(
+        restored.group_members(1).collect::<Vec<_>>(),
+        vec![first, second]
+    );
+    assert_eq!(restored.next_group_id(), 8);
+}
+
+#[test]
+fn connectors_survive_a_round_trip() {
+    let mut frame = Frame::new();
+    let line = Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 10,
+        y2: 10,
+        color: BLACK,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    };
+    let target = frame.add_shape(line.clone());
+    let attached = frame.add_shape(line);
+    let connector = Connector::from_ends(
+        Some(ConnectorEnd {
+            shape_id: target,
+            anchor: ConnectorAnchor::Top,
+        }),
+        None,
+    );
+    frame.shape_mut(attached).unwrap().connector = connector;
+
+    let json = serde_json::to_string(&frame).expect("serialize frame");
+    assert!(json.contains("\"anchor\":\"top\""));
+    let restored: Frame = serde_json::from_str(&json).expect("deserialize frame");
+    assert_eq!(restored.shape(attached).unwrap().connector, connector);
+    assert_eq!(restored.shape(target).unwrap().connector, None);
+}
+
+#[test]
+fn appearance_round_trips_with_its_history_and_is_omitted_when_default() {
+    let mut frame = Frame::new();
+    let line = Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 10,
+        y2: 10,
+        color: BLACK,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    };
+    let plain = frame.add_shape(line.clone());
+    let faded = frame.add_shape(line);
+    let appearance = ShapeAppearance {
+        opacity: 0.4,
+        blend: BlendMode::Multiply,
+    };
+    frame.shape_mut(faded).unwrap().appearance = appearance;
+    frame.push_undo_action(
+        UndoAction::Restyle {
+            shape_id: faded,
+            from: ShapeAppearance::default(),
+            to: appearance,
+        },
+        100,
+    );
+
+    let json = serde_json::to_string(&frame).expect("serialize frame");
+    assert_eq!(json.matches("\"appearance\"").count(), 1);
+    assert!(json.contains("\"blend\":\"multiply\""));
+    let mut restored: Frame = serde_json::from_str(&json).expect("deserialize frame");
+    assert_eq!(restored.shape(faded).unwrap().appearance, appearance);
+    assert!(restored.shape(plain).unwrap().appearance.is_default());
+
+    restored.undo_last();
+    assert!(restored.shape(faded).unwrap().appearance.is_default());
+}
+
+#[test]
+fn page_background_round_trips_and_is_omitted_when_absent() {
+    let plain = serde_json::to_string(&Frame::new()).expect("serialize plain frame");
+    assert!(!plain.contains("background"));
+
+    let mut frame = Frame::new();
+    let background = PageBackground::fitted(
+        PageBackgroundSource {
+            path: "/tmp/deck.pdf".into(),
+            page: Some(3),
+        },
+        EmbeddedImage {
+            mime_type: "image/png".to_string(),
+            width: 400,
+            height: 300,
+            bytes: vec![7u8; 32],
+        },
+        800,
+        800,
+    );
+    assert_eq!(
+        (
+            background.x,
+            background.y,
+            background.width,
+            background.height
+        ),
+        (0, 100, 800, 600)
+    );
+    assert!(frame.set_background(Some(background.clone())));
+    assert!(frame.has_persistable_data());
+
+    let json = serde_json::to_string(&frame).expect("serialize frame with background");
+    let restored: Frame = serde_json::from_str(&json).expect("deserialize frame");
+    assert_eq!(restored.background(), Some(&background));
+}
+
+#[test]
+fn background_history_round_trips_and_undoes_after_load() {
+    let background = PageBackground::fitted(
+        PageBackgroundSource {
+            path: "/tmp/slide.png".into(),
+            page: None,
+        },
+        EmbeddedImage {
+            mime_type: "image/png".to_string(),
+            width: 4,
+            height: 3,
+            bytes: vec![1, 2, 3],
+        },
+        400,
+        300,
+    );
+    let mut frame = Frame::new();
+    assert!(frame.replace_background(Some(background.clone()), 100));
+    assert!(!frame.replace_background(Some(background.clone()), 100));
+
+    let json = serde_json::to_string(&frame).expect("serialize frame");
+    assert!(json.contains("\"kind\":\"background\""));
+    let mut restored: Frame = serde_json::from_str(&json).expect("deserialize frame");
+    assert_eq!(restored.background(), Some(&background));
+
+    restored.undo_last();
+    assert!(restored.background().is_none());
+    restored.redo_last();
+    assert_eq!(restored.background(), Some(&background));
+}
+
+#[test]
+fn sessions_without_layers_load_into_the_base_layer() {
+    let mut frame = Frame::new();
+    for x in [0, 20] {
+        frame.add_shape(Shape::Line {
+            x1: x,
+            y1: 0,
+            x2: x + 10,
+            y2: 10,
+            color: BLACK,
+            thick: 2.0,
+            line_style: LineStyle::Solid,
+        });
+    }
+    let mut json = serde_json::to_value(&frame).expect("serialize frame");
+    assert!(json.get("layers").is_none());
+    // A shape pointing at a layer the page does not have.
+    json["shapes"][1]["layer"] = serde_json::json!(9);
+
+    let frame: Frame = serde_json::from_value(json).expect("deserialize legacy frame");
+    assert_eq!(frame.layers(), &[Layer::base()]);
+    assert_eq!(frame.active_layer_id(), 0);
+    assert!(frame.shapes.iter().all(|drawn| drawn.layer == 0));
+
+    let rewritten = serde_json::to_string(&frame).expect("serialize frame");
+    assert!(!rewritten.contains("layer"));
+}
+
+#[test]
+fn layers_round_trip_and_keep_shapes_grouped_by_layer() {
+    let line = |x: i32| Shape::Line {
+        x1: x,
+        y1: 0,
+        x2: x + 10,
+        y2: 10,
+        color: BLACK,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    };
+    let mut frame = Frame::new();
+    let sketch = frame.add_shape(line(0));
+    let ink_layer = frame.add_layer().expect("second layer");
+    let ink = frame.add_shape(line(20));
+    assert!(frame.set_active_layer(0));
+    let late_sketch = frame.add_shape(line(40));
+    assert!(frame.set_layer_locked(0, true));
+    assert!(frame.set_layer_opacity(ink_layer, 0.5));
+    assert!(frame.rename_layer(0, "Background sketch"));
+    assert_eq!(frame.ensure_active_layer_editable(), Some(ink_layer));
+
+    let order: Vec<_> = frame.shapes.iter().map(|drawn| drawn.id).collect();
+    assert_eq!(order, vec![sketch, late_sketch, ink]);
+    assert!(frame.has_persistable_data());
+
+    let json = serde_json::to_string(&frame).expect("serialize frame");
+    let restored: Frame = serde_json::from_str(&json).expect("deserialize frame");
+    assert_eq!(restored.layers(), frame.layers());
+    assert_eq!(restored.active_layer_id(), ink_layer);
+    assert_eq!(restored.shape(ink).unwrap().layer, ink_layer);
+    assert_eq!(restored.layer_span(0), 0..2);
+    assert!(!restored.is_layer_editable(0));
+}
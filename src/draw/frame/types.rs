@@ -1,4 +1,8 @@
-use crate::draw::shape::Shape;
+use super::appearance::ShapeAppearance;
+use super::background::PageBackground;
+use super::connector::Connector;
+use super::layer::{LayerId, LayerTable};
+use crate::draw::shape::{Shape, rotated_rect_bounds};
 use crate::util::Rect;
 use serde::{Deserialize, Serialize};
 use std::cell::Cell;
@@ -7,6 +11,9 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// Unique identifier for a drawn shape within a frame.
 pub type ShapeId = u64;
 
+/// Identifier shared by the members of a shape group within a frame.
+pub type GroupId = u64;
+
 /// Maximum allowed compound nesting depth in persisted history.
 pub const MAX_COMPOUND_DEPTH: usize = 16;
 
@@ -25,6 +32,18 @@ pub struct DrawnShape {
     pub shape: Shape,
     pub created_at: u64,
     pub locked: bool,
+    /// Group this shape belongs to. Selecting any member selects every shape
+    /// that shares the id, so the group moves, reorders, and deletes as one.
+    pub group: Option<GroupId>,
+    /// Shapes this line or arrow stays attached to. Moving an attached shape
+    /// re-routes the connector end onto the same anchor.
+    pub connector: Option<Connector>,
+    /// Whole-shape opacity and blend mode, composited on top of the shape's
+    /// own colors.
+    pub appearance: ShapeAppearance,
+    /// Layer the shape is drawn on. [`super::Frame::shapes`] keeps shapes
+    /// grouped by layer, so change it through the frame rather than in place.
+    pub layer: LayerId,
     /// Memoized `shape.bounding_box()`. Recomputing bounds is O(points) for
     /// strokes and hits the text-measurement cache for text shapes, and the
     /// render culling loop queries every shape every frame — so memoize.
@@ -39,6 +58,10 @@ impl DrawnShape {
             shape,
             created_at: current_timestamp_ms(),
             locked: false,
+            group: None,
+            connector: None,
+            appearance: ShapeAppearance::default(),
+            layer: 0,
             cached_bounds: Cell::new(CachedBounds::Unknown),
         }
     }
@@ -49,6 +72,10 @@ impl DrawnShape {
             shape,
             created_at,
             locked,
+            group: None,
+            connector: None,
+            appearance: ShapeAppearance::default(),
+            layer: 0,
             cached_bounds: Cell::new(CachedBounds::Unknown),
         }
     }
@@ -92,23 +119,35 @@ pub struct ShapeSnapshot {
 }
 
 /// Geometry snapshot for image-only move/resize undo without duplicating bytes.
-#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
 pub struct ImageBoundsSnapshot {
     pub x: i32,
     pub y: i32,
     pub w: i32,
     pub h: i32,
+    /// Clockwise rotation in degrees; absent from history written before
+    /// images could turn.
+    #[serde(default)]
+    pub rotation: f64,
     pub locked: bool,
 }
 
 impl ImageBoundsSnapshot {
     pub fn from_shape(shape: &Shape, locked: bool) -> Option<Self> {
         match shape {
-            Shape::Image { x, y, w, h, .. } => Some(Self {
+            Shape::Image {
+                x,
+                y,
+                w,
+                h,
+                rotation,
+                ..
+            } => Some(Self {
                 x: *x,
                 y: *y,
                 w: *w,
                 h: *h,
+                rotation: *rotation,
                 locked,
             }),
             _ => None,
@@ -126,11 +165,20 @@ impl ImageBoundsSnapshot {
         } else {
             self.y
         };
-        Rect::new(
+        let upright = Rect::new(
             min_x,
             min_y,
             self.w.saturating_abs().max(1),
             self.h.saturating_abs().max(1),
+        )?;
+        if self.rotation == 0.0 {
+            return Some(upright);
+        }
+        rotated_rect_bounds(
+            upright,
+            f64::from(self.x) + f64::from(self.w) / 2.0,
+            f64::from(self.y) + f64::from(self.h) / 2.0,
+            self.rotation,
         )
     }
 }
@@ -161,6 +209,46 @@ pub enum UndoAction {
         from: usize,
         to: usize,
     },
+    Regroup {
+        shape_id: ShapeId,
+        #[serde(default)]
+        from: Option<GroupId>,
+        #[serde(default)]
+        to: Option<GroupId>,
+    },
+    Reconnect {
+        shape_id: ShapeId,
+        #[serde(default)]
+        from: Option<Connector>,
+        #[serde(default)]
+        to: Option<Connector>,
+    },
+    Restyle {
+        shape_id: ShapeId,
+        from: ShapeAppearance,
+        to: ShapeAppearance,
+    },
+    /// Move of a shape onto another layer; `from`/`to` are stack indices as
+    /// in [`UndoAction::Reorder`].
+    Relayer {
+        shape_id: ShapeId,
+        from_layer: LayerId,
+        to_layer: LayerId,
+        from: usize,
+        to: usize,
+    },
+    /// Creating, deleting, renaming, hiding, locking, or fading a layer.
+    LayerChange {
+        before: LayerTable,
+        after: LayerTable,
+    },
+    /// Import or removal of the page's imported background.
+    Background {
+        #[serde(default)]
+        from: Option<PageBackground>,
+        #[serde(default)]
+        to: Option<PageBackground>,
+    },
     Compound {
         actions: Vec<UndoAction>,
     },
@@ -231,7 +319,7 @@ pub(super) fn current_timestamp_ms() -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::draw::Color;
+    use crate::draw::{Color, LineStyle};
 
     fn line(x1: i32, y1: i32, x2: i32, y2: i32) -> Shape {
         Shape::Line {
@@ -246,6 +334,7 @@ mod tests {
                 a: 1.0,
             },
             thick: 2.0,
+            line_style: LineStyle::Solid,
         }
     }
 
@@ -20,22 +20,27 @@ pub use canvas_set::{BoardPages, PageDeleteOutcome};
 pub use color::Color;
 pub use dirty::{DirtyFullReason, DirtyRegionReport, DirtyTracker};
 pub use font::FontDescriptor;
-pub use frame::{DrawnShape, Frame, ShapeId};
+pub use frame::{
+    BlendMode, DrawnShape, Frame, Layer, LayerId, MAX_LAYERS, PageBackground, PageBackgroundSource,
+    ShapeAppearance, ShapeId,
+};
 #[allow(unused_imports)]
 pub(crate) use render::render_eraser_stroke;
-pub(crate) use render::render_sticky_note_preview;
 #[allow(unused_imports)]
 pub use render::{
-    BlurRectParams, EraserReplayContext, SpotlightPass, SpotlightRegion, caret_line_width,
-    caret_outline_width, render_blur_rect, render_board_background, render_click_highlight,
-    render_freehand_borrowed, render_marker_stroke_borrowed, render_selection_halo,
-    render_selection_handles, render_shape, render_spotlight_pass, render_sticky_note, render_text,
-    selection_handle_rects, spotlight_regions_for_frame, sticky_note_foreground,
-    text_outline_color,
+    AlignmentGuide, BlurRectParams, BoardGrid, EraserReplayContext, GridPattern, MagnifierLens,
+    SpotlightPass, SpotlightRegion, caret_line_width, caret_outline_width, render_alignment_guides,
+    render_blur_rect, render_board_background, render_board_grid, render_board_rulers,
+    render_click_highlight, render_frame_layers, render_freehand_borrowed, render_laser,
+    render_magnifier_lens, render_marker_stroke_borrowed, render_page_background,
+    render_selection_halo, render_selection_handles, render_shape, render_spotlight_pass,
+    render_sticky_note, render_text, selection_handle_rects, spotlight_regions_for_frame,
+    sticky_note_foreground, text_outline_color,
 };
+pub(crate) use render::{render_sticky_note_preview, render_text_preview, with_appearance};
 #[allow(unused_imports)]
 pub use shape::{
-    ArrowLabel, BlurStyle, EmbeddedImage, EraserBrush, EraserKind, PolygonKind,
+    ArrowLabel, BlurStyle, EmbeddedImage, EraserBrush, EraserKind, LineStyle, PolygonKind,
     REGULAR_POLYGON_DEFAULT_SIDES, REGULAR_POLYGON_MAX_SIDES, REGULAR_POLYGON_MIN_SIDES, Shape,
     StepMarkerLabel, clamp_regular_sides,
 };
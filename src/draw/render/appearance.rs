@@ -0,0 +1,34 @@
+use crate::draw::{BlendMode, ShapeAppearance};
+
+/// Runs `draw` so that everything it paints is composited as one layer with
+/// `appearance`'s opacity and blend mode.
+///
+/// Shapes with the default appearance draw straight onto the target; the
+/// offscreen group is only paid for when it changes the result.
+pub(crate) fn with_appearance(
+    ctx: &cairo::Context,
+    appearance: ShapeAppearance,
+    draw: impl FnOnce(),
+) {
+    if appearance.is_default() {
+        draw();
+        return;
+    }
+    let _ = ctx.save();
+    ctx.push_group();
+    draw();
+    if ctx.pop_group_to_source().is_ok() {
+        ctx.set_operator(blend_operator(appearance.blend));
+        let _ = ctx.paint_with_alpha(appearance.clamped_opacity());
+    }
+    let _ = ctx.restore();
+}
+
+fn blend_operator(blend: BlendMode) -> cairo::Operator {
+    match blend {
+        BlendMode::Normal => cairo::Operator::Over,
+        BlendMode::Multiply => cairo::Operator::Multiply,
+        BlendMode::Screen => cairo::Operator::Screen,
+        BlendMode::Difference => cairo::Operator::Difference,
+    }
+}
@@ -1,4 +1,7 @@
 use crate::domain::BoardBackground;
+use crate::draw::frame::PageBackground;
+
+use super::image::render_image_shape;
 
 /// Renders board background for solid board modes.
 ///
@@ -20,6 +23,21 @@ pub fn render_board_background(ctx: &cairo::Context, background: &BoardBackgroun
     // If None (Transparent mode), do nothing - background stays transparent
 }
 
+/// Renders an imported page background at its canvas position.
+///
+/// Should be called after the board background and before the page's shapes,
+/// with the context already in canvas coordinates.
+pub fn render_page_background(ctx: &cairo::Context, background: &PageBackground) {
+    render_image_shape(
+        ctx,
+        background.x,
+        background.y,
+        background.width,
+        background.height,
+        &background.image,
+    );
+}
+
 /// Fills the entire surface with a semi-transparent tinted background.
 ///
 /// Creates a barely visible dark tint (0.05 alpha) to confirm the overlay is active
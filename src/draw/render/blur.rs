@@ -5,10 +5,10 @@ use std::{
     collections::{HashMap, VecDeque},
 };
 
-const PLACEHOLDER_FILL: (f64, f64, f64, f64) = (0.12, 0.15, 0.2, 0.82);
-const PLACEHOLDER_STROKE: (f64, f64, f64, f64) = (0.92, 0.94, 0.98, 0.35);
+pub(crate) const PLACEHOLDER_FILL: (f64, f64, f64, f64) = (0.12, 0.15, 0.2, 0.82);
+pub(crate) const PLACEHOLDER_STROKE: (f64, f64, f64, f64) = (0.92, 0.94, 0.98, 0.35);
 /// Faint edge drawn around every blur region so it reads as deliberate.
-const REDACTION_EDGE: (f64, f64, f64, f64) = (0.92, 0.94, 0.98, 0.22);
+pub(crate) const REDACTION_EDGE: (f64, f64, f64, f64) = (0.92, 0.94, 0.98, 0.22);
 const BLUR_CACHE_MAX_ENTRIES: usize = 8;
 const BLUR_CACHE_MAX_BYTES: usize = 64 * 1024 * 1024;
 type Rgba = (f64, f64, f64, f64);
@@ -60,6 +60,8 @@ pub struct BlurRectParams {
     pub h: i32,
     pub strength: f64,
     pub style: BlurStyle,
+    /// Clockwise rotation in degrees about the rectangle's center.
+    pub rotation: f64,
     pub cacheable: bool,
 }
 
@@ -513,31 +515,41 @@ pub fn render_blur_rect(
         h,
         strength,
         style,
+        rotation,
         cacheable,
     } = params;
     let Some((left, top, width, height)) = normalize_rect(x, y, w, h) else {
         return;
     };
+    let turn = RegionTurn {
+        cx: left + width / 2.0,
+        cy: top + height / 2.0,
+        radians: rotation.to_radians(),
+    };
 
     // Black out needs no backdrop, so it also works before any capture exists.
     let Some(style) = BackdropStyle::from_style(style) else {
-        render_black_out(ctx, left, top, width, height);
+        turn.paint(ctx, || render_black_out(ctx, left, top, width, height));
         return;
     };
 
     let Some(surface) = replay_ctx.surface else {
-        render_blur_placeholder(ctx, x, y, w, h, false);
+        turn.paint(ctx, || render_blur_placeholder(ctx, x, y, w, h, false));
         return;
     };
 
+    // A turned region samples the upright box around its footprint; the
+    // backdrop itself never turns, only the outline it is clipped to.
+    let (sample_left, sample_top, sample_right, sample_bottom) =
+        turn.footprint(left, top, width, height);
     let scale_x = replay_ctx.logical_to_image_scale_x.max(f64::MIN_POSITIVE);
     let scale_y = replay_ctx.logical_to_image_scale_y.max(f64::MIN_POSITIVE);
     let recipe = blur_recipe(strength, style);
 
-    let src_x = ((left * scale_x).floor() as i32).saturating_sub(recipe.padding_px);
-    let src_y = ((top * scale_y).floor() as i32).saturating_sub(recipe.padding_px);
-    let src_x2 = ((left + width) * scale_x).ceil() as i32 + recipe.padding_px;
-    let src_y2 = ((top + height) * scale_y).ceil() as i32 + recipe.padding_px;
+    let src_x = ((sample_left * scale_x).floor() as i32).saturating_sub(recipe.padding_px);
+    let src_y = ((sample_top * scale_y).floor() as i32).saturating_sub(recipe.padding_px);
+    let src_x2 = (sample_right * scale_x).ceil() as i32 + recipe.padding_px;
+    let src_y2 = (sample_bottom * scale_y).ceil() as i32 + recipe.padding_px;
 
     let src_x = src_x.clamp(0, surface.width().saturating_sub(1));
     let src_y = src_y.clamp(0, surface.height().saturating_sub(1));
@@ -551,7 +563,7 @@ pub fn render_blur_rect(
     let Some(blurred) = cacheable_blur_entry(cache_key, || {
         render_blur_region(surface, src_x, src_y, src_w, src_h, recipe, style)
     }) else {
-        render_blur_placeholder(ctx, x, y, w, h, false);
+        turn.paint(ctx, || render_blur_placeholder(ctx, x, y, w, h, false));
         return;
     };
     let overlay_palette = blur_overlay_palette(blurred.stats, recipe.overlay_alpha);
@@ -562,7 +574,7 @@ pub fn render_blur_rect(
     let dest_h = src_h as f64 / scale_y;
 
     let _ = ctx.save();
-    ctx.rectangle(left, top, width, height);
+    turn.outline(ctx, left, top, width, height);
     ctx.clip();
     ctx.translate(dest_x, dest_y);
     ctx.scale(dest_w / src_w.max(1) as f64, dest_h / src_h.max(1) as f64);
@@ -573,7 +585,7 @@ pub fn render_blur_rect(
     let _ = ctx.restore();
 
     let _ = ctx.save();
-    ctx.rectangle(left, top, width, height);
+    turn.outline(ctx, left, top, width, height);
     ctx.set_source_rgba(
         overlay_palette.0.0,
         overlay_palette.0.1,
@@ -592,6 +604,65 @@ pub fn render_blur_rect(
     let _ = ctx.restore();
 }
 
+/// Rotation of a blur region about its center.
+#[derive(Clone, Copy, Debug)]
+struct RegionTurn {
+    cx: f64,
+    cy: f64,
+    radians: f64,
+}
+
+impl RegionTurn {
+    fn is_upright(self) -> bool {
+        self.radians == 0.0
+    }
+
+    fn apply(self, ctx: &cairo::Context) {
+        ctx.translate(self.cx, self.cy);
+        ctx.rotate(self.radians);
+        ctx.translate(-self.cx, -self.cy);
+    }
+
+    /// Runs a painter that draws the upright region, turned into place.
+    fn paint(self, ctx: &cairo::Context, painter: impl FnOnce()) {
+        if self.is_upright() {
+            painter();
+            return;
+        }
+        let _ = ctx.save();
+        self.apply(ctx);
+        painter();
+        let _ = ctx.restore();
+    }
+
+    /// Adds the region's outline to the current path. The path keeps the turn
+    /// while the transform is restored, so strokes keep their usual width.
+    fn outline(self, ctx: &cairo::Context, left: f64, top: f64, width: f64, height: f64) {
+        let matrix = ctx.matrix();
+        if !self.is_upright() {
+            self.apply(ctx);
+        }
+        ctx.rectangle(left, top, width, height);
+        ctx.set_matrix(matrix);
+    }
+
+    /// `(left, top, right, bottom)` of the upright box around the turned region.
+    fn footprint(self, left: f64, top: f64, width: f64, height: f64) -> (f64, f64, f64, f64) {
+        if self.is_upright() {
+            return (left, top, left + width, top + height);
+        }
+        let (sin, cos) = self.radians.sin_cos();
+        let half_w = (width * cos).abs() / 2.0 + (height * sin).abs() / 2.0;
+        let half_h = (width * sin).abs() / 2.0 + (height * cos).abs() / 2.0;
+        (
+            self.cx - half_w,
+            self.cy - half_h,
+            self.cx + half_w,
+            self.cy + half_h,
+        )
+    }
+}
+
 #[cfg(test)]
 mod style_tests;
 #[cfg(test)]
@@ -21,6 +21,8 @@ fn no_backdrop<'a>() -> EraserReplayContext<'a> {
         bg_color: None,
         logical_to_image_scale_x: 1.0,
         logical_to_image_scale_y: 1.0,
+        page_background: None,
+        grid: None,
     }
 }
 
@@ -151,6 +153,7 @@ fn black_out_renders_without_any_captured_backdrop() {
             h: 30,
             strength: 12.0,
             style: BlurStyle::BlackOut,
+            rotation: 0.0,
             cacheable: false,
         },
         &replay_ctx,
@@ -180,6 +183,7 @@ fn secure_style_leaves_no_variation_across_the_region() {
             h: 48,
             strength: 12.0,
             style: BlurStyle::Secure,
+            rotation: 0.0,
             cacheable: false,
         },
         &replay_ctx,
@@ -217,6 +221,7 @@ fn pixelate_keeps_variation_that_secure_removes() {
                 h: 64,
                 strength: 1.0,
                 style,
+                rotation: 0.0,
                 cacheable: false,
             },
             &replay_ctx,
@@ -259,6 +264,7 @@ fn secure_style_stays_visible_against_the_content_it_covers() {
             h: 32,
             strength: 12.0,
             style: BlurStyle::Secure,
+            rotation: 0.0,
             cacheable: false,
         },
         &replay_ctx,
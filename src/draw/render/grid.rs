@@ -0,0 +1,223 @@
+use crate::draw::Color;
+
+/// How a board grid is drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridPattern {
+    Lines,
+    Dots,
+}
+
+/// Grid painted behind a solid board's content, anchored at the canvas origin
+/// so it pans with the shapes on it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoardGrid {
+    pub pattern: GridPattern,
+    /// Distance between grid lines in canvas pixels.
+    pub spacing: f64,
+    /// Ink the grid and rulers are drawn with, chosen to contrast with the
+    /// board background.
+    pub color: Color,
+}
+
+impl BoardGrid {
+    /// Nearest grid coordinate to `value`.
+    pub fn snap(&self, value: f64) -> f64 {
+        (value / self.spacing).round() * self.spacing
+    }
+}
+
+/// A line drawn while dragging a selection to show which edge or centre it
+/// lines up with. Coordinates are in canvas space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AlignmentGuide {
+    Vertical { x: i32, y0: i32, y1: i32 },
+    Horizontal { y: i32, x0: i32, x1: i32 },
+}
+
+const GRID_LINE_ALPHA: f64 = 0.12;
+const GRID_DOT_ALPHA: f64 = 0.35;
+const GRID_DOT_SIZE: f64 = 2.0;
+const RULER_THICKNESS: f64 = 18.0;
+/// Every this many grid steps the ruler draws a long tick with a label.
+const RULER_LABEL_EVERY: i64 = 4;
+
+/// Paints the grid over the context's current clip region.
+///
+/// Should be called after the board background and before the page's shapes,
+/// with the context already in canvas coordinates.
+pub fn render_board_grid(ctx: &cairo::Context, grid: &BoardGrid) {
+    if !(grid.spacing.is_finite() && grid.spacing >= 1.0) {
+        return;
+    }
+    let Ok((x1, y1, x2, y2)) = ctx.clip_extents() else {
+        return;
+    };
+    let first_col = (x1 / grid.spacing).floor() as i64;
+    let last_col = (x2 / grid.spacing).ceil() as i64;
+    let first_row = (y1 / grid.spacing).floor() as i64;
+    let last_row = (y2 / grid.spacing).ceil() as i64;
+    let color = grid.color;
+
+    let _ = ctx.save();
+    ctx.set_operator(cairo::Operator::Over);
+    match grid.pattern {
+        GridPattern::Lines => {
+            ctx.set_source_rgba(color.r, color.g, color.b, GRID_LINE_ALPHA);
+            ctx.set_line_width(1.0);
+            for col in first_col..=last_col {
+                let x = col as f64 * grid.spacing + 0.5;
+                ctx.move_to(x, y1);
+                ctx.line_to(x, y2);
+            }
+            for row in first_row..=last_row {
+                let y = row as f64 * grid.spacing + 0.5;
+                ctx.move_to(x1, y);
+                ctx.line_to(x2, y);
+            }
+            let _ = ctx.stroke();
+        }
+        GridPattern::Dots => {
+            ctx.set_source_rgba(color.r, color.g, color.b, GRID_DOT_ALPHA);
+            let half = GRID_DOT_SIZE / 2.0;
+            for col in first_col..=last_col {
+                for row in first_row..=last_row {
+                    ctx.rectangle(
+                        col as f64 * grid.spacing - half,
+                        row as f64 * grid.spacing - half,
+                        GRID_DOT_SIZE,
+                        GRID_DOT_SIZE,
+                    );
+                }
+            }
+            let _ = ctx.fill();
+        }
+    }
+    let _ = ctx.restore();
+}
+
+/// Draws rulers along the top and left edges of the view, ticked at the grid
+/// spacing and labelled in canvas pixels.
+///
+/// Expects a context in logical screen coordinates; `origin` is the canvas
+/// point shown at the top-left corner and `zoom` the canvas-to-screen scale.
+pub fn render_board_rulers(
+    ctx: &cairo::Context,
+    grid: &BoardGrid,
+    origin: (f64, f64),
+    zoom: f64,
+    width: f64,
+    height: f64,
+) {
+    let step = grid.spacing * zoom;
+    if !(step.is_finite() && step >= 2.0) {
+        return;
+    }
+    let color = grid.color;
+    let band = RULER_THICKNESS;
+
+    let _ = ctx.save();
+    ctx.set_source_rgba(1.0 - color.r, 1.0 - color.g, 1.0 - color.b, 0.85);
+    ctx.rectangle(0.0, 0.0, width, band);
+    ctx.rectangle(0.0, band, band, height - band);
+    let _ = ctx.fill();
+
+    ctx.set_source_rgba(color.r, color.g, color.b, 0.7);
+    ctx.set_line_width(1.0);
+    ctx.select_font_face("Sans", cairo::FontSlant::Normal, cairo::FontWeight::Normal);
+    ctx.set_font_size(9.0);
+
+    let first_col = (origin.0 / grid.spacing).ceil() as i64;
+    let last_col = ((origin.0 + width / zoom) / grid.spacing).floor() as i64;
+    for col in first_col..=last_col {
+        let x = ((col as f64 * grid.spacing - origin.0) * zoom).round() + 0.5;
+        if x < band {
+            continue;
+        }
+        let major = col % RULER_LABEL_EVERY == 0;
+        let tick = if major { band * 0.6 } else { band * 0.3 };
+        ctx.move_to(x, band);
+        ctx.line_to(x, band - tick);
+        if major {
+            let _ = ctx.stroke();
+            ctx.move_to(x + 2.0, 9.0);
+            let _ = ctx.show_text(&format!("{}", (col as f64 * grid.spacing).round()));
+        }
+    }
+    let _ = ctx.stroke();
+
+    let first_row = (origin.1 / grid.spacing).ceil() as i64;
+    let last_row = ((origin.1 + height / zoom) / grid.spacing).floor() as i64;
+    for row in first_row..=last_row {
+        let y = ((row as f64 * grid.spacing - origin.1) * zoom).round() + 0.5;
+        if y < band {
+            continue;
+        }
+        let major = row % RULER_LABEL_EVERY == 0;
+        let tick = if major { band * 0.6 } else { band * 0.3 };
+        ctx.move_to(band, y);
+        ctx.line_to(band - tick, y);
+        if major {
+            let _ = ctx.stroke();
+            let _ = ctx.save();
+            ctx.move_to(9.0, y - 2.0);
+            ctx.rotate(-std::f64::consts::FRAC_PI_2);
+            let _ = ctx.show_text(&format!("{}", (row as f64 * grid.spacing).round()));
+            let _ = ctx.restore();
+        }
+    }
+    let _ = ctx.stroke();
+
+    ctx.move_to(0.0, band + 0.5);
+    ctx.line_to(width, band + 0.5);
+    ctx.move_to(band + 0.5, band);
+    ctx.line_to(band + 0.5, height);
+    let _ = ctx.stroke();
+    let _ = ctx.restore();
+}
+
+/// Draws the alignment guides of an in-progress selection move.
+pub fn render_alignment_guides(ctx: &cairo::Context, guides: &[AlignmentGuide]) {
+    if guides.is_empty() {
+        return;
+    }
+    let _ = ctx.save();
+    ctx.set_source_rgba(0.95, 0.25, 0.55, 0.9);
+    ctx.set_line_width(1.0);
+    ctx.set_dash(&[4.0, 3.0], 0.0);
+    for guide in guides {
+        match *guide {
+            AlignmentGuide::Vertical { x, y0, y1 } => {
+                ctx.move_to(x as f64 + 0.5, y0 as f64);
+                ctx.line_to(x as f64 + 0.5, y1 as f64);
+            }
+            AlignmentGuide::Horizontal { y, x0, x1 } => {
+                ctx.move_to(x0 as f64, y as f64 + 0.5);
+                ctx.line_to(x1 as f64, y as f64 + 0.5);
+            }
+        }
+    }
+    let _ = ctx.stroke();
+    let _ = ctx.restore();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_rounds_to_the_nearest_grid_line() {
+        let grid = BoardGrid {
+            pattern: GridPattern::Lines,
+            spacing: 20.0,
+            color: Color {
+                r: 0.0,
+                g: 0.0,
+                b: 0.0,
+                a: 1.0,
+            },
+        };
+        assert_eq!(grid.snap(29.0), 20.0);
+        assert_eq!(grid.snap(31.0), 40.0);
+        assert_eq!(grid.snap(-11.0), -20.0);
+    }
+}
@@ -0,0 +1,48 @@
+use crate::draw::Color;
+
+/// Draws a laser pointer trail and its dot.
+///
+/// `trail` holds `(x, y, opacity)` samples from oldest to newest; each segment
+/// takes the opacity of its older end and narrows with it, so the tail thins
+/// out as it fades. The dot gets a soft glow around a bright core.
+pub fn render_laser(
+    ctx: &cairo::Context,
+    trail: &[(f64, f64, f64)],
+    dot: Option<(f64, f64)>,
+    color: Color,
+    width: f64,
+) {
+    if trail.is_empty() && dot.is_none() {
+        return;
+    }
+
+    let _ = ctx.save();
+    ctx.set_operator(cairo::Operator::Over);
+    ctx.set_line_cap(cairo::LineCap::Round);
+    for pair in trail.windows(2) {
+        let (x0, y0, opacity) = pair[0];
+        let (x1, y1, _) = pair[1];
+        if opacity <= 0.0 {
+            continue;
+        }
+        ctx.set_source_rgba(color.r, color.g, color.b, color.a * opacity.clamp(0.0, 1.0));
+        ctx.set_line_width((width * (0.35 + 0.65 * opacity)).max(1.0));
+        ctx.move_to(x0, y0);
+        ctx.line_to(x1, y1);
+        let _ = ctx.stroke();
+    }
+
+    if let Some((x, y)) = dot {
+        let tau = std::f64::consts::PI * 2.0;
+        ctx.set_source_rgba(color.r, color.g, color.b, color.a * 0.3);
+        ctx.arc(x, y, width * 1.5, 0.0, tau);
+        let _ = ctx.fill();
+        ctx.set_source_rgba(color.r, color.g, color.b, color.a);
+        ctx.arc(x, y, width * 0.75, 0.0, tau);
+        let _ = ctx.fill();
+        ctx.set_source_rgba(1.0, 1.0, 1.0, 0.8 * color.a);
+        ctx.arc(x, y, width * 0.3, 0.0, tau);
+        let _ = ctx.fill();
+    }
+    let _ = ctx.restore();
+}
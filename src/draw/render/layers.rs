@@ -0,0 +1,57 @@
+use crate::draw::{DrawnShape, Frame};
+
+use super::appearance::with_appearance;
+use super::types::EraserReplayContext;
+
+/// Renders a frame's shapes layer by layer, bottom first, skipping hidden
+/// layers. `render_shape` draws one committed shape with the replay context
+/// it is handed.
+///
+/// The bottom layer draws straight onto the target with `replay_ctx`, so its
+/// erasers reveal the backdrop. Every layer above is composited on its own
+/// with its opacity, and its erasers clear to transparency instead: they
+/// show the layers beneath rather than painting the backdrop over them.
+pub fn render_frame_layers<'a>(
+    ctx: &cairo::Context,
+    frame: &'a Frame,
+    replay_ctx: &EraserReplayContext<'_>,
+    mut render_shape: impl FnMut(&'a DrawnShape, &EraserReplayContext<'_>),
+) {
+    let layer_replay_ctx = EraserReplayContext {
+        pattern: None,
+        surface: replay_ctx.surface,
+        backdrop_cache_key: replay_ctx.backdrop_cache_key,
+        bg_color: None,
+        logical_to_image_scale_x: replay_ctx.logical_to_image_scale_x,
+        logical_to_image_scale_y: replay_ctx.logical_to_image_scale_y,
+        page_background: None,
+        grid: None,
+    };
+
+    for (position, layer) in frame.layers().iter().enumerate() {
+        if !layer.visible {
+            continue;
+        }
+        let shapes = frame.shapes_on_layer(layer.id);
+        if shapes.is_empty() {
+            continue;
+        }
+        if position == 0 {
+            with_appearance(ctx, layer.appearance(), || {
+                for drawn in shapes {
+                    render_shape(drawn, replay_ctx);
+                }
+            });
+            continue;
+        }
+        let _ = ctx.save();
+        ctx.push_group();
+        for drawn in shapes {
+            render_shape(drawn, &layer_replay_ctx);
+        }
+        if ctx.pop_group_to_source().is_ok() {
+            let _ = ctx.paint_with_alpha(layer.appearance().clamped_opacity());
+        }
+        let _ = ctx.restore();
+    }
+}
@@ -0,0 +1,150 @@
+/// A magnifying lens centred on a point of the view. Sizes are in the
+/// context's logical pixels.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MagnifierLens {
+    pub center: (f64, f64),
+    pub width: f64,
+    pub height: f64,
+    /// Draw the lens as an ellipse filling `width` x `height` rather than a
+    /// rectangle.
+    pub round: bool,
+    pub magnification: f64,
+}
+
+impl MagnifierLens {
+    /// Left, top, width, and height of the lens itself.
+    pub fn bounds(&self) -> (f64, f64, f64, f64) {
+        (
+            self.center.0 - self.width / 2.0,
+            self.center.1 - self.height / 2.0,
+            self.width,
+            self.height,
+        )
+    }
+
+    /// Left, top, width, and height of the area the lens shows enlarged: the
+    /// lens shrunk by the magnification around the same centre.
+    pub fn source_bounds(&self) -> (f64, f64, f64, f64) {
+        let magnification = self.magnification.max(1.0);
+        let width = self.width / magnification;
+        let height = self.height / magnification;
+        (
+            self.center.0 - width / 2.0,
+            self.center.1 - height / 2.0,
+            width,
+            height,
+        )
+    }
+
+    fn path(&self, ctx: &cairo::Context) {
+        let (x, y, width, height) = self.bounds();
+        ctx.new_path();
+        if self.round {
+            let _ = ctx.save();
+            ctx.translate(self.center.0, self.center.1);
+            ctx.scale(width / 2.0, height / 2.0);
+            ctx.arc(0.0, 0.0, 1.0, 0.0, std::f64::consts::PI * 2.0);
+            let _ = ctx.restore();
+        } else {
+            ctx.rectangle(x, y, width, height);
+        }
+    }
+}
+
+/// Draws the lens over what the context's target already holds.
+///
+/// The area under the lens is copied out of the target before anything is
+/// painted, so the lens shows the finished canvas and never its own pixels.
+/// `device_scale` converts the context's logical units to target pixels.
+pub fn render_magnifier_lens(ctx: &cairo::Context, device_scale: f64, lens: &MagnifierLens) {
+    let device_scale = device_scale.max(1.0);
+    let (source_x, source_y, source_w, source_h) = lens.source_bounds();
+    let snapshot_w = (source_w * device_scale).ceil() as i32;
+    let snapshot_h = (source_h * device_scale).ceil() as i32;
+    if snapshot_w <= 0 || snapshot_h <= 0 {
+        return;
+    }
+    let Ok(snapshot) = cairo::ImageSurface::create(cairo::Format::ARgb32, snapshot_w, snapshot_h)
+    else {
+        return;
+    };
+    {
+        let Ok(snapshot_ctx) = cairo::Context::new(&snapshot) else {
+            return;
+        };
+        snapshot_ctx.set_operator(cairo::Operator::Source);
+        if snapshot_ctx
+            .set_source_surface(
+                ctx.group_target(),
+                -source_x * device_scale,
+                -source_y * device_scale,
+            )
+            .is_err()
+        {
+            return;
+        }
+        let _ = snapshot_ctx.paint();
+    }
+
+    let (x, y, _, _) = lens.bounds();
+    let zoom = lens.magnification.max(1.0) / device_scale;
+    let _ = ctx.save();
+    lens.path(ctx);
+    ctx.clip();
+    ctx.translate(x, y);
+    ctx.scale(zoom, zoom);
+    if ctx.set_source_surface(&snapshot, 0.0, 0.0).is_ok() {
+        let _ = ctx.paint();
+    }
+    let _ = ctx.restore();
+
+    let _ = ctx.save();
+    lens.path(ctx);
+    ctx.set_source_rgba(0.0, 0.0, 0.0, 0.55);
+    ctx.set_line_width(3.5);
+    let _ = ctx.stroke_preserve();
+    ctx.set_source_rgba(1.0, 1.0, 1.0, 0.9);
+    ctx.set_line_width(1.5);
+    let _ = ctx.stroke();
+    let _ = ctx.restore();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lens(round: bool) -> MagnifierLens {
+        MagnifierLens {
+            center: (50.0, 50.0),
+            width: 40.0,
+            height: 40.0,
+            round,
+            magnification: 4.0,
+        }
+    }
+
+    #[test]
+    fn source_area_shrinks_by_the_magnification_around_the_centre() {
+        assert_eq!(lens(true).bounds(), (30.0, 30.0, 40.0, 40.0));
+        assert_eq!(lens(true).source_bounds(), (45.0, 45.0, 10.0, 10.0));
+    }
+
+    #[test]
+    fn lens_paints_an_enlarged_copy_of_the_pixels_under_it() {
+        let mut surface = cairo::ImageSurface::create(cairo::Format::ARgb32, 100, 100).unwrap();
+        {
+            let ctx = cairo::Context::new(&surface).unwrap();
+            ctx.set_source_rgb(1.0, 0.0, 0.0);
+            ctx.rectangle(48.0, 48.0, 4.0, 4.0);
+            let _ = ctx.fill();
+            render_magnifier_lens(&ctx, 1.0, &lens(false));
+        }
+        surface.flush();
+        let stride = surface.stride() as usize;
+        let data = surface.data().unwrap();
+        let alpha_at = |x: usize, y: usize| data[y * stride + x * 4 + 3];
+
+        assert_eq!(alpha_at(44, 50), 255, "the red square is drawn 4x larger");
+        assert_eq!(alpha_at(36, 50), 0, "outside the square stays clear");
+    }
+}
@@ -1,9 +1,14 @@
 //! Cairo-based rendering functions for shapes.
 
+mod appearance;
 mod background;
 mod blur;
+mod grid;
 mod highlight;
 mod image;
+mod laser;
+mod layers;
+mod magnifier;
 mod pressure_strokes;
 mod primitives;
 mod selection;
@@ -13,13 +18,22 @@ mod strokes;
 mod text;
 mod types;
 
-pub use background::{fill_transparent, render_board_background};
+pub(crate) use appearance::with_appearance;
+pub use background::{fill_transparent, render_board_background, render_page_background};
 pub use blur::{BlurRectParams, render_blur_rect};
+pub(crate) use blur::{PLACEHOLDER_FILL, PLACEHOLDER_STROKE, REDACTION_EDGE};
+pub use grid::{
+    AlignmentGuide, BoardGrid, GridPattern, render_alignment_guides, render_board_grid,
+    render_board_rulers,
+};
 pub use highlight::render_click_highlight;
+pub use laser::render_laser;
+pub use layers::render_frame_layers;
+pub use magnifier::{MagnifierLens, render_magnifier_lens};
 #[allow(unused_imports)]
 pub use pressure_strokes::render_freehand_pressure_borrowed;
 pub(crate) use pressure_strokes::render_freehand_pressure_preview_borrowed;
-pub(crate) use primitives::render_polygon_preview;
+pub(crate) use primitives::{render_polygon_preview, with_line_style};
 pub use selection::{render_selection_halo, render_selection_handles, selection_handle_rects};
 pub use shapes::render_shape;
 pub use spotlight::{
@@ -28,9 +42,9 @@ pub use spotlight::{
 };
 pub(crate) use strokes::render_eraser_stroke;
 pub use strokes::{render_freehand_borrowed, render_marker_stroke_borrowed};
-pub(crate) use text::render_sticky_note_preview;
 pub use text::{
     caret_line_width, caret_outline_width, render_sticky_note, render_text, sticky_note_foreground,
     text_outline_color,
 };
+pub(crate) use text::{render_sticky_note_preview, render_text_preview};
 pub use types::EraserReplayContext;
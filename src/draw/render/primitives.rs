@@ -1,6 +1,27 @@
-use crate::draw::Color;
+use crate::draw::{Color, LineStyle};
 use crate::util;
 
+/// Runs `draw` with `style`'s dash pattern applied to every stroke it makes.
+///
+/// Dashed strokes use round caps so dotted patterns render as dots; solid
+/// strokes leave the context alone, so each primitive keeps its own caps.
+pub(crate) fn with_line_style(
+    ctx: &cairo::Context,
+    style: LineStyle,
+    thick: f64,
+    draw: impl FnOnce(),
+) {
+    if style.is_solid() {
+        draw();
+        return;
+    }
+    let _ = ctx.save();
+    ctx.set_dash(&style.dash_pattern(thick), 0.0);
+    ctx.set_line_cap(cairo::LineCap::Round);
+    draw();
+    let _ = ctx.restore();
+}
+
 /// Render a straight line
 pub(super) fn render_line(
     ctx: &cairo::Context,
@@ -133,6 +154,7 @@ pub(crate) fn render_polygon_preview(
     fill: bool,
     color: Color,
     thick: f64,
+    line_style: LineStyle,
 ) {
     if points.len() < 2 {
         return;
@@ -142,7 +164,13 @@ pub(crate) fn render_polygon_preview(
     ctx.new_path();
     ctx.set_source_rgba(color.r, color.g, color.b, color.a);
     ctx.set_line_width(thick);
-    ctx.set_line_cap(cairo::LineCap::Butt);
+    if line_style.is_solid() {
+        ctx.set_line_cap(cairo::LineCap::Butt);
+    } else {
+        // Dots are zero-length dashes and only show up with round caps.
+        ctx.set_dash(&line_style.dash_pattern(thick), 0.0);
+        ctx.set_line_cap(cairo::LineCap::Round);
+    }
     ctx.set_line_join(cairo::LineJoin::Round);
     ctx.move_to(points[0].0 as f64, points[0].1 as f64);
     for &(x, y) in &points[1..] {
@@ -208,6 +236,64 @@ pub(super) fn render_arrow(
     ctx.restore().ok();
 }
 
+/// Render an arrow with a dashed shaft.
+///
+/// The filled outline of [`render_arrow`] cannot carry a dash pattern, so the
+/// shaft is stroked at full thickness up to the arrowhead and the head is filled
+/// on its own.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn render_dashed_arrow(
+    ctx: &cairo::Context,
+    x1: i32,
+    y1: i32,
+    x2: i32,
+    y2: i32,
+    color: Color,
+    thick: f64,
+    arrow_length: f64,
+    arrow_angle: f64,
+    head_at_end: bool,
+    style: LineStyle,
+) {
+    let (tip_x, tip_y, tail_x, tail_y) = if head_at_end {
+        (x2, y2, x1, y1)
+    } else {
+        (x1, y1, x2, y2)
+    };
+    let Some(arrow) = util::calculate_dashed_arrow(
+        tip_x,
+        tip_y,
+        tail_x,
+        tail_y,
+        thick,
+        arrow_length,
+        arrow_angle,
+    ) else {
+        return;
+    };
+
+    ctx.save().ok();
+    ctx.new_path();
+    ctx.set_source_rgba(color.r, color.g, color.b, color.a);
+    if let Some(shaft_end) = arrow.shaft_end {
+        ctx.set_line_width(thick);
+        ctx.set_line_cap(cairo::LineCap::Round);
+        ctx.set_dash(&style.dash_pattern(thick), 0.0);
+        // Dashes start at the tail so every arrow of a style begins alike.
+        ctx.move_to(arrow.tail.0, arrow.tail.1);
+        ctx.line_to(shaft_end.0, shaft_end.1);
+        let _ = ctx.stroke();
+        ctx.set_dash(&[], 0.0);
+    }
+    let head = arrow.head;
+    ctx.move_to(head.tip.0, head.tip.1);
+    ctx.line_to(head.left.0, head.left.1);
+    ctx.line_to(head.right.0, head.right.1);
+    ctx.close_path();
+    let _ = ctx.fill();
+    ctx.restore().ok();
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,7 +321,14 @@ mod tests {
             a: 1.0,
         };
 
-        render_polygon_preview(&ctx, &[(20, 35), (70, 35)], false, red, 10.0);
+        render_polygon_preview(
+            &ctx,
+            &[(20, 35), (70, 35)],
+            false,
+            red,
+            10.0,
+            LineStyle::Solid,
+        );
 
         drop(ctx);
         assert_eq!(
@@ -11,6 +11,8 @@ use crate::util::Rect;
 const HANDLE_SIZE: f64 = 8.0;
 /// Selection handle border width
 const HANDLE_BORDER: f64 = 1.5;
+/// Distance of the rotation knob above the top edge
+const ROTATE_HANDLE_OFFSET: f64 = 24.0;
 
 /// Selection accent color (blue)
 const SELECTION_COLOR: Color = Color {
@@ -35,6 +37,11 @@ pub fn render_selection_halo(ctx: &cairo::Context, drawn: &DrawnShape) {
 
     // Ensure halo does not modify primary drawing state.
     let _ = ctx.save();
+    if let Some((cx, cy)) = drawn.shape.rotation_pivot() {
+        ctx.translate(cx, cy);
+        ctx.rotate(drawn.shape.rotation().to_radians());
+        ctx.translate(-cx, -cy);
+    }
     match &drawn.shape {
         Shape::Freehand { points, thick, .. } => {
             render_freehand_borrowed(ctx, points, glow, thick + outline_width);
@@ -126,7 +133,7 @@ pub fn render_selection_halo(ctx: &cairo::Context, drawn: &DrawnShape) {
             );
         }
         Shape::BlurRect { .. } => {
-            if let Some(bounds) = drawn.bounding_box() {
+            if let Some(bounds) = drawn.shape.local_bounding_box() {
                 let padding = 3.0;
                 let x = bounds.x as f64 - padding;
                 let y = bounds.y as f64 - padding;
@@ -168,7 +175,7 @@ pub fn render_selection_halo(ctx: &cairo::Context, drawn: &DrawnShape) {
             render_freehand_borrowed(ctx, points, glow, outline);
         }
         Shape::Text { .. } | Shape::Image { .. } => {
-            if let Some(bounds) = drawn.bounding_box() {
+            if let Some(bounds) = drawn.shape.local_bounding_box() {
                 let padding = 4.0;
                 let x = bounds.x as f64 - padding;
                 let y = bounds.y as f64 - padding;
@@ -184,7 +191,7 @@ pub fn render_selection_halo(ctx: &cairo::Context, drawn: &DrawnShape) {
             }
         }
         Shape::StickyNote { .. } => {
-            if let Some(bounds) = drawn.bounding_box() {
+            if let Some(bounds) = drawn.shape.local_bounding_box() {
                 let padding = 4.0;
                 let x = bounds.x as f64 - padding;
                 let y = bounds.y as f64 - padding;
@@ -203,7 +210,8 @@ pub fn render_selection_halo(ctx: &cairo::Context, drawn: &DrawnShape) {
     let _ = ctx.restore();
 }
 
-/// Renders selection handles (corner resize handles) for a bounding box.
+/// Renders selection handles (corner and edge resize handles plus the rotation
+/// knob) for a bounding box.
 pub fn render_selection_handles(ctx: &cairo::Context, bounds: &Rect) {
     let _ = ctx.save();
 
@@ -281,6 +289,28 @@ pub fn render_selection_handles(ctx: &cairo::Context, bounds: &Rect) {
         let _ = ctx.stroke();
     }
 
+    // Draw rotation knob on a stem above the top edge
+    let knob_x = x + w / 2.0;
+    let knob_y = y - ROTATE_HANDLE_OFFSET;
+    ctx.set_source_rgba(SELECTION_COLOR.r, SELECTION_COLOR.g, SELECTION_COLOR.b, 0.6);
+    ctx.set_line_width(1.0);
+    ctx.move_to(knob_x, y);
+    ctx.line_to(knob_x, knob_y + half);
+    let _ = ctx.stroke();
+
+    ctx.set_source_rgba(1.0, 1.0, 1.0, 0.95);
+    ctx.arc(knob_x, knob_y, half, 0.0, std::f64::consts::TAU);
+    let _ = ctx.fill();
+    ctx.set_source_rgba(
+        SELECTION_COLOR.r,
+        SELECTION_COLOR.g,
+        SELECTION_COLOR.b,
+        SELECTION_COLOR.a,
+    );
+    ctx.set_line_width(HANDLE_BORDER);
+    ctx.arc(knob_x, knob_y, half, 0.0, std::f64::consts::TAU);
+    let _ = ctx.stroke();
+
     let _ = ctx.restore();
 }
 
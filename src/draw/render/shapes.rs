@@ -2,14 +2,16 @@ use super::blur::{render_black_out_rect, render_blur_placeholder};
 use super::highlight::render_click_highlight;
 use super::image::render_image_shape;
 use super::pressure_strokes::render_freehand_pressure_borrowed;
-use super::primitives::{render_arrow, render_ellipse, render_line, render_polygon, render_rect};
+use super::primitives::{
+    render_arrow, render_dashed_arrow, render_ellipse, render_line, render_polygon, render_rect,
+    with_line_style,
+};
 use super::strokes::{render_freehand_borrowed, render_marker_stroke_borrowed};
 use super::text::{render_sticky_note, render_text};
-use crate::draw::Color;
 use crate::draw::shape::Shape;
 use crate::draw::shape::{
-    ARROW_LABEL_BACKGROUND, arrow_label_layout, measure_text_with_context,
-    step_marker_outline_thickness, step_marker_radius,
+    ARROW_LABEL_BACKGROUND, TextMarkup, arrow_label_layout, measure_text_with_context,
+    step_marker_colors, step_marker_outline_thickness, step_marker_radius,
 };
 
 /// Renders a single shape to a Cairo context.
@@ -21,13 +23,29 @@ use crate::draw::shape::{
 /// * `ctx` - Cairo drawing context to render to
 /// * `shape` - The shape to render
 pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
+    let Some((cx, cy)) = shape.rotation_pivot() else {
+        render_unrotated_shape(ctx, shape);
+        return;
+    };
+    let _ = ctx.save();
+    ctx.translate(cx, cy);
+    ctx.rotate(shape.rotation().to_radians());
+    ctx.translate(-cx, -cy);
+    render_unrotated_shape(ctx, shape);
+    let _ = ctx.restore();
+}
+
+fn render_unrotated_shape(ctx: &cairo::Context, shape: &Shape) {
     match shape {
         Shape::Freehand {
             points,
             color,
             thick,
+            line_style,
         } => {
-            render_freehand_borrowed(ctx, points, *color, *thick);
+            with_line_style(ctx, *line_style, *thick, || {
+                render_freehand_borrowed(ctx, points, *color, *thick);
+            });
         }
         Shape::FreehandPressure { points, color } => {
             let coords: Vec<(i32, i32)> = points.iter().map(|&(x, y, _)| (x, y)).collect();
@@ -41,8 +59,11 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
             y2,
             color,
             thick,
+            line_style,
         } => {
-            render_line(ctx, *x1, *y1, *x2, *y2, *color, *thick);
+            with_line_style(ctx, *line_style, *thick, || {
+                render_line(ctx, *x1, *y1, *x2, *y2, *color, *thick);
+            });
         }
         Shape::Rect {
             x,
@@ -52,8 +73,12 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
             fill,
             color,
             thick,
+            rotation: _,
+            line_style,
         } => {
-            render_rect(ctx, *x, *y, *w, *h, *fill, *color, *thick);
+            with_line_style(ctx, *line_style, *thick, || {
+                render_rect(ctx, *x, *y, *w, *h, *fill, *color, *thick);
+            });
         }
         Shape::Ellipse {
             cx,
@@ -63,17 +88,24 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
             fill,
             color,
             thick,
+            rotation: _,
+            line_style,
         } => {
-            render_ellipse(ctx, *cx, *cy, *rx, *ry, *fill, *color, *thick);
+            with_line_style(ctx, *line_style, *thick, || {
+                render_ellipse(ctx, *cx, *cy, *rx, *ry, *fill, *color, *thick);
+            });
         }
         Shape::Polygon {
             points,
             fill,
             color,
             thick,
+            line_style,
             ..
         } => {
-            render_polygon(ctx, points, *fill, *color, *thick);
+            with_line_style(ctx, *line_style, *thick, || {
+                render_polygon(ctx, points, *fill, *color, *thick);
+            });
         }
         Shape::Arrow {
             x1,
@@ -86,24 +118,41 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
             arrow_angle,
             head_at_end,
             label,
+            line_style,
         } => {
             let (tip_x, tip_y, tail_x, tail_y) = if *head_at_end {
                 (*x2, *y2, *x1, *y1)
             } else {
                 (*x1, *y1, *x2, *y2)
             };
-            render_arrow(
-                ctx,
-                *x1,
-                *y1,
-                *x2,
-                *y2,
-                *color,
-                *thick,
-                *arrow_length,
-                *arrow_angle,
-                *head_at_end,
-            );
+            if line_style.is_solid() {
+                render_arrow(
+                    ctx,
+                    *x1,
+                    *y1,
+                    *x2,
+                    *y2,
+                    *color,
+                    *thick,
+                    *arrow_length,
+                    *arrow_angle,
+                    *head_at_end,
+                );
+            } else {
+                render_dashed_arrow(
+                    ctx,
+                    *x1,
+                    *y1,
+                    *x2,
+                    *y2,
+                    *color,
+                    *thick,
+                    *arrow_length,
+                    *arrow_angle,
+                    *head_at_end,
+                    *line_style,
+                );
+            }
             if let Some(label) = label {
                 let label_text = label.value.to_string();
                 if let Some(layout) = arrow_label_layout(
@@ -137,6 +186,7 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
             h,
             strength: _,
             style,
+            rotation: _,
         } => {
             // Without a captured backdrop the sampling styles can only stand in
             // with a placeholder, but a black out is already its final form.
@@ -159,6 +209,7 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
             font_descriptor,
             background_enabled,
             wrap_width,
+            rotation: _,
         } => {
             render_text(
                 ctx,
@@ -176,57 +227,26 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
             let label_text = label.value.to_string();
             let radius = step_marker_radius(label.value, label.size, &label.font_descriptor);
             let outline_thickness = step_marker_outline_thickness(label.size);
-            let alpha = color.a.clamp(0.0, 1.0);
-            let fill_color = Color {
-                a: (alpha * 0.9).clamp(0.0, 1.0),
-                ..*color
-            };
-            let brightness = color.r * 0.299 + color.g * 0.587 + color.b * 0.114;
-            let (outline_color, text_color) = if brightness > 0.6 {
-                (
-                    Color {
-                        r: 0.05,
-                        g: 0.05,
-                        b: 0.05,
-                        a: 0.85 * alpha,
-                    },
-                    Color {
-                        r: 0.12,
-                        g: 0.12,
-                        b: 0.12,
-                        a: alpha,
-                    },
-                )
-            } else {
-                (
-                    Color {
-                        r: 0.98,
-                        g: 0.98,
-                        b: 0.98,
-                        a: 0.9 * alpha,
-                    },
-                    Color {
-                        r: 0.98,
-                        g: 0.98,
-                        b: 0.98,
-                        a: alpha,
-                    },
-                )
-            };
+            let colors = step_marker_colors(*color);
             render_click_highlight(
                 ctx,
                 *x as f64,
                 *y as f64,
                 radius,
                 outline_thickness,
-                fill_color,
-                outline_color,
+                colors.fill,
+                colors.outline,
                 1.0,
             );
             let font_desc = label.font_descriptor.to_pango_string(label.size);
-            if let Some(metrics) =
-                measure_text_with_context(ctx, &label_text, &font_desc, label.size, None)
-            {
+            if let Some(metrics) = measure_text_with_context(
+                ctx,
+                &label_text,
+                &font_desc,
+                label.size,
+                None,
+                TextMarkup::Plain,
+            ) {
                 let center_offset_x = metrics.ink_x + metrics.ink_width / 2.0;
                 let center_offset_y = metrics.ink_y + metrics.ink_height / 2.0;
                 let baseline_x = (*x as f64 - center_offset_x).round() as i32;
@@ -236,7 +256,7 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
                     baseline_x,
                     baseline_y,
                     &label_text,
-                    text_color,
+                    colors.text,
                     label.size,
                     &label.font_descriptor,
                     false,
@@ -274,7 +294,9 @@ pub fn render_shape(ctx: &cairo::Context, shape: &Shape) {
         Shape::EraserStroke { .. } => {
             // Eraser strokes require an eraser replay context; ignore in generic rendering.
         }
-        Shape::Image { x, y, w, h, data } => {
+        Shape::Image {
+            x, y, w, h, data, ..
+        } => {
             render_image_shape(ctx, *x, *y, *w, *h, data);
         }
     }
@@ -36,6 +36,7 @@ pub fn spotlight_regions_for_frame(frame: &crate::draw::Frame) -> Vec<SpotlightR
     frame
         .shapes
         .iter()
+        .filter(|drawn| frame.is_shape_visible(drawn))
         .filter_map(|drawn| match &drawn.shape {
             crate::draw::Shape::Spotlight { cx, cy, rx, ry } => Some(SpotlightRegion {
                 cx: f64::from(*cx),
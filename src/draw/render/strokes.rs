@@ -90,6 +90,39 @@ pub(crate) fn render_eraser_stroke(
         let _ = ctx.stroke();
     }
 
+    // The grid sits on the board background, so erasing redraws it under the cut.
+    if let Some(grid) = eraser_ctx.grid {
+        build_path(ctx);
+        let extents = ctx.stroke_extents();
+        ctx.new_path();
+        if let Ok((x1, y1, x2, y2)) = extents {
+            let _ = ctx.save();
+            ctx.rectangle(x1, y1, x2 - x1, y2 - y1);
+            ctx.clip();
+            ctx.push_group();
+            super::grid::render_board_grid(ctx, &grid);
+            if let Ok(pattern) = ctx.pop_group() {
+                build_path(ctx);
+                ctx.set_operator(cairo::Operator::Over);
+                let _ = ctx.set_source(&pattern);
+                let _ = ctx.stroke();
+            }
+            let _ = ctx.restore();
+        }
+    }
+
+    // An imported page sits above the board background, so erasing restores it too.
+    if let Some(background) = eraser_ctx.page_background {
+        ctx.push_group();
+        super::background::render_page_background(ctx, background);
+        if let Ok(pattern) = ctx.pop_group() {
+            build_path(ctx);
+            ctx.set_operator(cairo::Operator::Over);
+            let _ = ctx.set_source(&pattern);
+            let _ = ctx.stroke();
+        }
+    }
+
     let _ = ctx.restore();
 }
 
@@ -1,6 +1,6 @@
 use crate::draw::shape::{
-    TextMeasurement, measure_text_with_context, sticky_note_layout, sticky_note_layout_text,
-    sticky_note_text_layout,
+    TextMarkup, TextMeasurement, configured_layout, measure_text_with_context, sticky_note_layout,
+    sticky_note_layout_text, sticky_note_text_layout,
 };
 use crate::draw::{Color, FontDescriptor};
 use std::f64::consts::{FRAC_PI_2, PI};
@@ -13,7 +13,8 @@ use std::f64::consts::{FRAC_PI_2, PI};
 /// with proper line spacing determined by the font metrics.
 ///
 /// Text is rendered with a contrasting stroke outline for better visibility
-/// against any background color.
+/// against any background color. Inline markup (`**bold**`, `*italic*`,
+/// `` `code` ``, bullets, headings) is drawn styled with its markers hidden.
 ///
 /// # Arguments
 /// * `ctx` - Cairo drawing context to render to
@@ -35,6 +36,62 @@ pub fn render_text(
     font_descriptor: &FontDescriptor,
     background_enabled: bool,
     wrap_width: Option<i32>,
+) {
+    render_text_layout(
+        ctx,
+        x,
+        y,
+        text,
+        color,
+        size,
+        font_descriptor,
+        background_enabled,
+        wrap_width,
+        TextMarkup::Rendered,
+    );
+}
+
+/// Render the live text editor. Markup is styled but its markers stay
+/// visible, so the laid-out text matches the buffer byte for byte and the
+/// caret, selection, and IME decorations index it directly.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn render_text_preview(
+    ctx: &cairo::Context,
+    x: i32,
+    y: i32,
+    text: &str,
+    color: Color,
+    size: f64,
+    font_descriptor: &FontDescriptor,
+    background_enabled: bool,
+    wrap_width: Option<i32>,
+) {
+    render_text_layout(
+        ctx,
+        x,
+        y,
+        text,
+        color,
+        size,
+        font_descriptor,
+        background_enabled,
+        wrap_width,
+        TextMarkup::Source,
+    );
+}
+
+#[allow(clippy::too_many_arguments)]
+fn render_text_layout(
+    ctx: &cairo::Context,
+    x: i32,
+    y: i32,
+    text: &str,
+    color: Color,
+    size: f64,
+    font_descriptor: &FontDescriptor,
+    background_enabled: bool,
+    wrap_width: Option<i32>,
+    markup: TextMarkup,
 ) {
     // Save context state to prevent settings from leaking to other drawing operations
     ctx.save().ok();
@@ -43,41 +100,29 @@ pub fn render_text(
     // Subpixel can cause color fringing on transparent/composited surfaces
     ctx.set_antialias(cairo::Antialias::Best);
 
-    // Create Pango layout for text rendering
-    let layout = pangocairo::functions::create_layout(ctx);
-
-    // Set font description from config
+    // Create Pango layout for text rendering (Pango handles newlines automatically)
     let font_desc_str = font_descriptor.to_pango_string(size);
-    let font_desc = pango::FontDescription::from_string(&font_desc_str);
-    layout.set_font_description(Some(&font_desc));
-
-    // Set the text (Pango handles newlines automatically)
-    layout.set_text(text);
-    if let Some(width) = wrap_width {
-        let width = width.max(1);
-        let width_pango = (width as i64 * pango::SCALE as i64).min(i32::MAX as i64) as i32;
-        layout.set_width(width_pango);
-        layout.set_wrap(pango::WrapMode::WordChar);
-    }
+    let layout = configured_layout(ctx, text, &font_desc_str, wrap_width, markup);
 
     // Use cached measurements for ink rect (avoids repeated Pango measurement)
-    let measurement = measure_text_with_context(ctx, text, &font_desc_str, size, wrap_width)
-        .unwrap_or_else(|| {
-            // Fallback: measure directly.
-            let (ink_rect, logical_rect) = layout.extents();
-            let scale = pango::SCALE as f64;
-            TextMeasurement {
-                ink_x: ink_rect.x() as f64 / scale,
-                ink_y: ink_rect.y() as f64 / scale,
-                ink_width: ink_rect.width() as f64 / scale,
-                ink_height: ink_rect.height() as f64 / scale,
-                logical_x: logical_rect.x() as f64 / scale,
-                logical_y: logical_rect.y() as f64 / scale,
-                logical_width: logical_rect.width() as f64 / scale,
-                logical_height: logical_rect.height() as f64 / scale,
-                baseline: layout.baseline() as f64 / scale,
-            }
-        });
+    let measurement =
+        measure_text_with_context(ctx, text, &font_desc_str, size, wrap_width, markup)
+            .unwrap_or_else(|| {
+                // Fallback: measure directly.
+                let (ink_rect, logical_rect) = layout.extents();
+                let scale = pango::SCALE as f64;
+                TextMeasurement {
+                    ink_x: ink_rect.x() as f64 / scale,
+                    ink_y: ink_rect.y() as f64 / scale,
+                    ink_width: ink_rect.width() as f64 / scale,
+                    ink_height: ink_rect.height() as f64 / scale,
+                    logical_x: logical_rect.x() as f64 / scale,
+                    logical_y: logical_rect.y() as f64 / scale,
+                    logical_width: logical_rect.width() as f64 / scale,
+                    logical_height: logical_rect.height() as f64 / scale,
+                    baseline: layout.baseline() as f64 / scale,
+                }
+            });
     let content = measurement.content_extents(wrap_width);
 
     // Calculate brightness to determine background/stroke color
@@ -117,11 +162,14 @@ pub fn render_text(
     ctx.set_source_rgba(outline.r, outline.g, outline.b, outline.a);
     ctx.set_line_width(size * 0.06);
     ctx.set_line_join(cairo::LineJoin::Round);
-    let _ = ctx.stroke_preserve();
+    let _ = ctx.stroke();
 
-    // Fill with bright, full-intensity color
+    // Fill with bright, full-intensity color. Showing the layout rather than
+    // filling the glyph path keeps real glyphs on vector surfaces, so text in
+    // exported PDFs stays selectable and searchable.
+    ctx.move_to(x as f64, adjusted_y);
     ctx.set_source_rgba(color.r, color.g, color.b, color.a);
-    let _ = ctx.fill();
+    pangocairo::functions::show_layout(ctx, &layout);
 
     // Restore context state
     ctx.restore().ok();
@@ -176,11 +224,13 @@ pub fn render_sticky_note(
         size,
         font_descriptor,
         wrap_width,
+        TextMarkup::Rendered,
     );
 }
 
 /// Render the live sticky-note editor, using a measurement-only placeholder
 /// when its buffer is empty so the background remains visible behind the caret.
+/// Like [`render_text_preview`], markup markers stay visible while editing.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn render_sticky_note_preview(
     ctx: &cairo::Context,
@@ -202,6 +252,7 @@ pub(crate) fn render_sticky_note_preview(
         size,
         font_descriptor,
         wrap_width,
+        TextMarkup::Source,
     );
 }
 
@@ -216,11 +267,13 @@ fn render_sticky_note_layout(
     size: f64,
     font_descriptor: &FontDescriptor,
     wrap_width: Option<i32>,
+    markup: TextMarkup,
 ) {
     ctx.save().ok();
     ctx.set_antialias(cairo::Antialias::Best);
 
-    let text_layout = sticky_note_text_layout(ctx, layout_text, size, font_descriptor, wrap_width);
+    let text_layout =
+        sticky_note_text_layout(ctx, layout_text, size, font_descriptor, wrap_width, markup);
     let base_x = x as f64;
     let base_y = y as f64 - text_layout.baseline;
     let note_layout = sticky_note_layout(
@@ -488,6 +541,7 @@ mod tests {
             ("gjpqy", 24.0, false),
             ("kako", 32.0, true),
             ("Multi\nline", 28.0, false),
+            ("# Heading\n**bold** *it* `code`\n- item", 28.0, true),
         ] {
             let origin = (150, 220);
             let mut surface = cairo::ImageSurface::create(cairo::Format::ARgb32, 600, 420).unwrap();
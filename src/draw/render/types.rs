@@ -1,4 +1,7 @@
 use crate::draw::Color;
+use crate::draw::frame::PageBackground;
+
+use super::grid::BoardGrid;
 
 /// Background replay context for tools that need access to the captured backdrop.
 pub struct EraserReplayContext<'a> {
@@ -14,4 +17,8 @@ pub struct EraserReplayContext<'a> {
     pub logical_to_image_scale_x: f64,
     /// Vertical scale from logical canvas coordinates to captured image pixels.
     pub logical_to_image_scale_y: f64,
+    /// Imported page background, repainted under erased strokes.
+    pub page_background: Option<&'a PageBackground>,
+    /// Board grid, repainted over the solid background under erased strokes.
+    pub grid: Option<BoardGrid>,
 }
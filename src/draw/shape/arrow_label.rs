@@ -1,6 +1,7 @@
 use crate::draw::FontDescriptor;
 use crate::util::Rect;
 
+use super::markup::TextMarkup;
 use super::text::{text_bounds_from_metrics, text_layout_metrics};
 
 pub(crate) const ARROW_LABEL_BACKGROUND: bool = true;
@@ -50,7 +51,13 @@ pub(crate) fn arrow_label_layout(
     let anchor_x = tail_x as f64 + ux * along + nx * offset;
     let anchor_y = tail_y as f64 + uy * along + ny * offset;
 
-    let metrics = text_layout_metrics(label_text, label_size, font_descriptor, None)?;
+    let metrics = text_layout_metrics(
+        label_text,
+        label_size,
+        font_descriptor,
+        None,
+        TextMarkup::Rendered,
+    )?;
     let center_offset_x = metrics.ink_x + metrics.ink_width / 2.0;
     let center_offset_y = metrics.ink_y + metrics.ink_height / 2.0;
 
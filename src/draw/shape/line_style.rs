@@ -0,0 +1,96 @@
+use super::types::Shape;
+use serde::{Deserialize, Serialize};
+
+/// Dash pattern applied to a stroke or outline.
+///
+/// Dash and gap lengths scale with the stroke thickness, so a dashed hairline
+/// and a dashed marker-width line read as the same style. Every non-solid
+/// style is stroked with round caps, which is also what turns the zero-length
+/// dashes of `Dotted` and `DashDot` into dots.
+#[cfg_attr(feature = "config-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "kebab-case")]
+pub enum LineStyle {
+    #[default]
+    Solid,
+    Dashed,
+    Dotted,
+    DashDot,
+}
+
+impl LineStyle {
+    /// Every style, in the order the toolbar and cycling action step through them.
+    pub const ALL: [Self; 4] = [Self::Solid, Self::Dashed, Self::Dotted, Self::DashDot];
+
+    /// Short human-readable name for toolbars, menus, and toasts.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Solid => "Solid",
+            Self::Dashed => "Dashed",
+            Self::Dotted => "Dotted",
+            Self::DashDot => "Dash-dot",
+        }
+    }
+
+    /// Next style in [`Self::ALL`] order, wrapping at the end.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Solid => Self::Dashed,
+            Self::Dashed => Self::Dotted,
+            Self::Dotted => Self::DashDot,
+            Self::DashDot => Self::Solid,
+        }
+    }
+
+    /// Serde skip predicate that keeps solid shapes in the pre-style format.
+    pub(crate) fn is_solid(&self) -> bool {
+        *self == Self::Solid
+    }
+
+    /// Alternating on/off lengths for a stroke `thick` pixels wide, in the form
+    /// Cairo's `set_dash` and SVG's `stroke-dasharray` take. Empty for solid.
+    ///
+    /// The round caps add half the thickness to each end of every dash, so the
+    /// "on" lengths here are a thickness shorter than what ends up visible and
+    /// the gaps a thickness longer.
+    pub fn dash_pattern(self, thick: f64) -> Vec<f64> {
+        let unit = thick.max(1.0);
+        match self {
+            Self::Solid => Vec::new(),
+            Self::Dashed => vec![3.0 * unit, 4.0 * unit],
+            Self::Dotted => vec![0.0, 2.5 * unit],
+            Self::DashDot => vec![3.0 * unit, 2.5 * unit, 0.0, 2.5 * unit],
+        }
+    }
+}
+
+impl Shape {
+    /// Dash style of the stroke, for shapes whose outline can be styled.
+    ///
+    /// Pressure strokes, markers, text, and the other fill-only shapes have no
+    /// style and report `None`.
+    pub fn line_style(&self) -> Option<LineStyle> {
+        match self {
+            Shape::Freehand { line_style, .. }
+            | Shape::Line { line_style, .. }
+            | Shape::Rect { line_style, .. }
+            | Shape::Ellipse { line_style, .. }
+            | Shape::Polygon { line_style, .. }
+            | Shape::Arrow { line_style, .. } => Some(*line_style),
+            _ => None,
+        }
+    }
+
+    /// The line style field, for shapes that have one.
+    pub(crate) fn line_style_mut(&mut self) -> Option<&mut LineStyle> {
+        match self {
+            Shape::Freehand { line_style, .. }
+            | Shape::Line { line_style, .. }
+            | Shape::Rect { line_style, .. }
+            | Shape::Ellipse { line_style, .. }
+            | Shape::Polygon { line_style, .. }
+            | Shape::Arrow { line_style, .. } => Some(line_style),
+            _ => None,
+        }
+    }
+}
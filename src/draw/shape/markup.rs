@@ -0,0 +1,401 @@
+//! Lightweight inline markup for text annotations and sticky notes.
+//!
+//! Text shapes store their Markdown-subset source verbatim: `**bold**`,
+//! `*italic*`, `` `code` ``, `- ` / `* ` bullet lines and `#`–`###` headings,
+//! with a backslash escaping any marker character. The parser turns that
+//! source into the string Pango lays out plus the styled byte ranges that
+//! become layout attributes.
+//!
+//! The editor lays out the source itself ([`TextMarkup::Source`]): every byte
+//! stays in the layout and only attributes are added, so caret, selection and
+//! IME offsets index the buffer directly with no mapping. Committed shapes use
+//! [`TextMarkup::Rendered`], which hides the markers.
+
+use std::ops::Range;
+
+/// Font family Pango substitutes for `` `code` `` spans.
+pub(crate) const CODE_FONT_FAMILY: &str = "Monospace";
+
+/// Deepest heading level recognised at the start of a line.
+const MAX_HEADING_LEVEL: usize = 3;
+
+/// How a text run's markup is interpreted when it is laid out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub(crate) enum TextMarkup {
+    /// Laid out verbatim, for strings that are never markup (step labels).
+    Plain,
+    /// The editor view: styles applied, markers kept, text byte-identical.
+    Source,
+    /// The committed view: markers hidden and bullets drawn as `•`.
+    Rendered,
+}
+
+/// Inline styles active over one span of laid-out text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct SpanStyle {
+    pub bold: bool,
+    pub italic: bool,
+    pub code: bool,
+    /// Heading level 1–3, or 0 outside headings.
+    pub heading: u8,
+}
+
+impl SpanStyle {
+    pub(crate) fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// A byte range of [`RichText::text`] and the style it is drawn with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct StyledSpan {
+    pub range: Range<usize>,
+    pub style: SpanStyle,
+}
+
+/// Markup source resolved into the string to lay out and its styled spans.
+/// Unstyled text has no span.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct RichText {
+    pub text: String,
+    pub spans: Vec<StyledSpan>,
+}
+
+impl RichText {
+    /// Style at every byte of `range`, as consecutive `(range, style)` runs
+    /// that together cover it.
+    pub(crate) fn runs_in(&self, range: Range<usize>) -> Vec<(Range<usize>, SpanStyle)> {
+        let mut runs = Vec::new();
+        let mut cursor = range.start;
+        for span in &self.spans {
+            let start = span.range.start.max(range.start);
+            let end = span.range.end.min(range.end);
+            if start >= end {
+                continue;
+            }
+            if cursor < start {
+                runs.push((cursor..start, SpanStyle::default()));
+            }
+            runs.push((start..end, span.style));
+            cursor = end;
+        }
+        if cursor < range.end {
+            runs.push((cursor..range.end, SpanStyle::default()));
+        }
+        runs
+    }
+}
+
+/// Relative font size of a heading line.
+pub(crate) fn heading_scale(level: u8) -> f64 {
+    match level {
+        1 => 1.5,
+        2 => 1.3,
+        3 => 1.15,
+        _ => 1.0,
+    }
+}
+
+/// Resolve `source` for layout under `markup`.
+pub(crate) fn parse_markup(source: &str, markup: TextMarkup) -> RichText {
+    let mut out = RichText::default();
+    if markup == TextMarkup::Plain {
+        out.text.push_str(source);
+        return out;
+    }
+    let keep_markers = markup == TextMarkup::Source;
+    for line in source.split_inclusive('\n') {
+        let (body, newline) = match line.strip_suffix('\n') {
+            Some(body) => (body, "\n"),
+            None => (line, ""),
+        };
+        let mut base = SpanStyle::default();
+        let mut rest = body;
+        if let Some(level) = heading_level(body) {
+            base.heading = level as u8;
+            base.bold = true;
+            if keep_markers {
+                push(&mut out, &body[..=level], base);
+            }
+            rest = &body[level + 1..];
+        } else if let Some(indent) = bullet_indent(body) {
+            push(&mut out, &body[..indent], base);
+            if keep_markers {
+                push(&mut out, &body[indent..indent + 2], base);
+            } else {
+                push(&mut out, "• ", base);
+            }
+            rest = &body[indent + 2..];
+        }
+        parse_inline(rest, base, keep_markers, &mut out);
+        push(&mut out, newline, SpanStyle::default());
+    }
+    out
+}
+
+/// Build the Pango attributes that draw `spans` over the layout's base font.
+pub(crate) fn pango_attributes(spans: &[StyledSpan]) -> pango::AttrList {
+    let attrs = pango::AttrList::new();
+    for span in spans {
+        let (Ok(start), Ok(end)) = (
+            u32::try_from(span.range.start),
+            u32::try_from(span.range.end),
+        ) else {
+            continue;
+        };
+        let style = span.style;
+        if style.bold {
+            let mut attr = pango::AttrInt::new_weight(pango::Weight::Bold);
+            attr.set_start_index(start);
+            attr.set_end_index(end);
+            attrs.insert(attr);
+        }
+        if style.italic {
+            let mut attr = pango::AttrInt::new_style(pango::Style::Italic);
+            attr.set_start_index(start);
+            attr.set_end_index(end);
+            attrs.insert(attr);
+        }
+        if style.code {
+            let mut attr = pango::AttrString::new_family(CODE_FONT_FAMILY);
+            attr.set_start_index(start);
+            attr.set_end_index(end);
+            attrs.insert(attr);
+        }
+        if style.heading > 0 {
+            let mut attr = pango::AttrFloat::new_scale(heading_scale(style.heading));
+            attr.set_start_index(start);
+            attr.set_end_index(end);
+            attrs.insert(attr);
+        }
+    }
+    attrs
+}
+
+fn push(out: &mut RichText, text: &str, style: SpanStyle) {
+    if text.is_empty() {
+        return;
+    }
+    let start = out.text.len();
+    out.text.push_str(text);
+    let end = out.text.len();
+    if style.is_plain() {
+        return;
+    }
+    if let Some(last) = out.spans.last_mut()
+        && last.range.end == start
+        && last.style == style
+    {
+        last.range.end = end;
+        return;
+    }
+    out.spans.push(StyledSpan {
+        range: start..end,
+        style,
+    });
+}
+
+/// `#`, `##` or `###` followed by a space opens a heading line.
+fn heading_level(line: &str) -> Option<usize> {
+    let hashes = line.bytes().take_while(|byte| *byte == b'#').count();
+    ((1..=MAX_HEADING_LEVEL).contains(&hashes) && line.as_bytes().get(hashes) == Some(&b' '))
+        .then_some(hashes)
+}
+
+/// Indentation before a `- `, `* ` or `+ ` bullet marker, if the line is one.
+fn bullet_indent(line: &str) -> Option<usize> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    let marker = line.as_bytes().get(indent..indent + 2)?;
+    matches!(marker, [b'-' | b'*' | b'+', b' ']).then_some(indent)
+}
+
+fn is_escapable(ch: char) -> bool {
+    matches!(ch, '\\' | '*' | '`' | '#' | '-' | '+')
+}
+
+/// Whether `after` (the text following an opening `delimiter`) starts with
+/// content and later holds a closing `delimiter` right after non-whitespace.
+fn has_closing(after: &str, delimiter: &str) -> bool {
+    if after.starts_with(char::is_whitespace) || after.is_empty() {
+        return false;
+    }
+    after
+        .match_indices(delimiter)
+        .any(|(index, _)| index > 0 && !after[..index].ends_with(char::is_whitespace))
+}
+
+fn parse_inline(line: &str, base: SpanStyle, keep_markers: bool, out: &mut RichText) {
+    let mut strong = false;
+    let mut emphasis = false;
+    let style = |strong: bool, emphasis: bool| SpanStyle {
+        bold: base.bold || strong,
+        italic: base.italic || emphasis,
+        ..base
+    };
+    let mut index = 0;
+    while index < line.len() {
+        let rest = &line[index..];
+        let previous = line[..index].chars().next_back();
+        let current = style(strong, emphasis);
+
+        if let Some(escaped) = rest.strip_prefix('\\')
+            && let Some(ch) = escaped.chars().next()
+            && is_escapable(ch)
+        {
+            if keep_markers {
+                push(out, "\\", current);
+            }
+            push(out, &escaped[..ch.len_utf8()], current);
+            index += 1 + ch.len_utf8();
+            continue;
+        }
+
+        if let Some(after) = rest.strip_prefix('`')
+            && let Some(close) = after.find('`')
+            && close > 0
+        {
+            let code = SpanStyle {
+                code: true,
+                ..current
+            };
+            if keep_markers {
+                push(out, &rest[..close + 2], code);
+            } else {
+                push(out, &after[..close], code);
+            }
+            index += close + 2;
+            continue;
+        }
+
+        if let Some(after) = rest.strip_prefix("**") {
+            let toggles = if strong {
+                previous.is_some_and(|ch| !ch.is_whitespace())
+            } else {
+                has_closing(after, "**")
+            };
+            if toggles {
+                strong = !strong;
+                if keep_markers {
+                    // Markers take the style of the span they delimit.
+                    push(out, "**", style(true, emphasis));
+                }
+                index += 2;
+                continue;
+            }
+        } else if let Some(after) = rest.strip_prefix('*') {
+            let toggles = if emphasis {
+                previous.is_some_and(|ch| !ch.is_whitespace())
+                    && !after.starts_with(char::is_alphanumeric)
+            } else {
+                !previous.is_some_and(char::is_alphanumeric) && has_closing(after, "*")
+            };
+            if toggles {
+                emphasis = !emphasis;
+                if keep_markers {
+                    push(out, "*", style(strong, true));
+                }
+                index += 1;
+                continue;
+            }
+        }
+
+        let ch = rest.chars().next().unwrap_or_default();
+        push(out, &rest[..ch.len_utf8()], current);
+        index += ch.len_utf8();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bold() -> SpanStyle {
+        SpanStyle {
+            bold: true,
+            ..SpanStyle::default()
+        }
+    }
+
+    fn italic() -> SpanStyle {
+        SpanStyle {
+            italic: true,
+            ..SpanStyle::default()
+        }
+    }
+
+    #[test]
+    fn rendered_view_hides_markers_and_styles_their_content() {
+        let rich = parse_markup("a **bold** and *it* `x`", TextMarkup::Rendered);
+
+        assert_eq!(rich.text, "a bold and it x");
+        assert_eq!(rich.spans[0].range, 2..6);
+        assert_eq!(rich.spans[0].style, bold());
+        assert_eq!(rich.spans[1].range, 11..13);
+        assert_eq!(rich.spans[1].style, italic());
+        assert_eq!(rich.spans[2].range, 14..15);
+        assert!(rich.spans[2].style.code);
+    }
+
+    #[test]
+    fn source_view_keeps_every_byte_so_offsets_map_to_the_buffer() {
+        for source in [
+            "a **bold** and *it* `x`",
+            "# Title\n- one\n  * two\n\\*literal\\*",
+            "unclosed **bold and *it",
+            "你好 **世界**",
+        ] {
+            let rich = parse_markup(source, TextMarkup::Source);
+            assert_eq!(rich.text, source);
+        }
+
+        let rich = parse_markup("a **b**", TextMarkup::Source);
+        assert_eq!(rich.spans[0].range, 2..7, "markers share the bold span");
+    }
+
+    #[test]
+    fn line_prefixes_become_headings_and_bullets() {
+        let rich = parse_markup("## Plan\n- ship\n  * test", TextMarkup::Rendered);
+
+        assert_eq!(rich.text, "Plan\n• ship\n  • test");
+        assert_eq!(rich.spans[0].range, 0..4);
+        assert_eq!(rich.spans[0].style.heading, 2);
+        assert!(rich.spans[0].style.bold);
+    }
+
+    #[test]
+    fn arithmetic_and_escapes_stay_literal() {
+        for (source, expected) in [
+            ("2 * 3 * 4", "2 * 3 * 4"),
+            ("2*3*4", "2*3*4"),
+            ("snake*case*name", "snake*case*name"),
+            ("\\*not italic\\*", "*not italic*"),
+            ("#hashtag", "#hashtag"),
+            ("-5 degrees", "-5 degrees"),
+        ] {
+            let rich = parse_markup(source, TextMarkup::Rendered);
+            assert_eq!(rich.text, expected, "{source:?}");
+            assert!(rich.spans.is_empty(), "{source:?} must not be styled");
+        }
+    }
+
+    #[test]
+    fn plain_markup_is_verbatim() {
+        let rich = parse_markup("**12**", TextMarkup::Plain);
+        assert_eq!(rich.text, "**12**");
+        assert!(rich.spans.is_empty());
+    }
+
+    #[test]
+    fn runs_cover_a_range_with_styled_and_plain_pieces() {
+        let rich = parse_markup("a **b** c", TextMarkup::Rendered);
+
+        assert_eq!(
+            rich.runs_in(0..rich.text.len()),
+            vec![
+                (0..2, SpanStyle::default()),
+                (2..3, bold()),
+                (3..5, SpanStyle::default()),
+            ]
+        );
+    }
+}
@@ -2,33 +2,49 @@
 
 mod arrow_label;
 mod bounds;
+mod line_style;
+mod markup;
 mod polygon;
+mod recognition;
+mod rotation;
+mod smoothing;
 mod step_marker;
 mod text;
 mod text_cache;
 mod types;
 
+pub use line_style::LineStyle;
 pub use polygon::{
     PolygonKind, REGULAR_POLYGON_DEFAULT_SIDES, REGULAR_POLYGON_MAX_SIDES,
     REGULAR_POLYGON_MIN_SIDES, clamp_regular_sides,
 };
+pub use recognition::{RecognizedShape, recognize_stroke};
+pub use rotation::rotate_point;
 pub use types::{
     ArrowLabel, BlurStyle, EmbeddedImage, EraserBrush, EraserKind, Shape, StepMarkerLabel,
 };
 
 pub(crate) use arrow_label::{ARROW_LABEL_BACKGROUND, arrow_label_layout};
 pub(crate) use bounds::{bounding_box_for_blur, bounding_box_for_eraser, bounding_box_for_points};
+pub(crate) use markup::{CODE_FONT_FAMILY, SpanStyle, TextMarkup, heading_scale, parse_markup};
 pub(crate) use polygon::{PolygonTemplate, generated_points, has_minimum_distinct_points};
-pub(crate) use step_marker::{step_marker_outline_thickness, step_marker_radius};
+pub(crate) use rotation::rotated_rect_bounds;
+pub(crate) use smoothing::{smooth_pressure_stroke, smooth_stroke};
+pub(crate) use step_marker::{
+    step_marker_colors, step_marker_outline_thickness, step_marker_radius,
+};
+#[cfg(test)]
+pub(crate) use text::bounding_box_for_text;
 pub(crate) use text::{
-    bounding_box_for_sticky_note_preview, bounding_box_for_text, sticky_note_layout,
+    bounding_box_for_sticky_note_preview, bounding_box_for_text_preview, sticky_note_layout,
     sticky_note_layout_text, sticky_note_text_layout,
 };
 pub(crate) use text_cache::{
     CaretGeometry, LogicalBounds, TextMeasurement, VisualCaretDirection, VisualLineDirection,
     VisualLineEdge, caret_at_visual_selection_edge, caret_geometry_text,
     caret_on_adjacent_visual_line, caret_on_adjacent_visual_position, caret_on_visual_line_edge,
-    configured_layout, hit_test_text, measure_text_with_context, text_preview_geometry,
+    configured_layout, font_pixel_size, hit_test_text, measure_text_cached,
+    measure_text_with_context, text_preview_geometry, visual_lines,
 };
 
 #[cfg(test)]
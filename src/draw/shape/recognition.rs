@@ -0,0 +1,392 @@
+//! Smart-ink recognition: finds the clean shape a rough freehand stroke depicts.
+//!
+//! Everything here is plain geometry on the stroke's points, so it is cheap
+//! enough to run on every pen release. Recognition is conservative: a stroke
+//! that fits no shape well stays freehand.
+
+use crate::draw::Color;
+
+use super::{LineStyle, PolygonKind, Shape};
+
+/// Strokes with a smaller bounding-box diagonal stay freehand; at that size a
+/// scribble is more likely a tick or a dot than a shape.
+const MIN_SHAPE_SIZE: f64 = 24.0;
+/// An open stroke is a line when its path is at most this much longer than the
+/// distance between its ends...
+const LINE_MAX_PATH_RATIO: f64 = 1.08;
+/// ...and no point strays further than this fraction of that distance.
+const LINE_MAX_DEVIATION: f64 = 0.06;
+/// A stroke is closed when its ends are this close, relative to its length.
+const CLOSED_MAX_GAP: f64 = 0.2;
+/// Corner-finding tolerance, relative to the bounding-box diagonal.
+const CORNER_TOLERANCE: f64 = 0.09;
+/// Points must lie within this fraction of the short side from the bounding
+/// box edges...
+const RECT_EDGE_BAND: f64 = 0.1;
+/// ...for at least this share of a stroke to read as a rectangle.
+const RECT_MIN_EDGE_SHARE: f64 = 0.88;
+/// Mean radial deviation allowed for an ellipse, relative to its radii.
+const ELLIPSE_MAX_ERROR: f64 = 0.1;
+/// The shaft of an arrow is at least this share of the stroke length.
+const ARROW_MIN_SHAFT_SHARE: f64 = 0.4;
+/// Arrowhead points stay within this fraction of the shaft from the tip.
+const ARROW_MAX_HEAD: f64 = 0.5;
+/// Barbs leave the tip between these angles (degrees) from the shaft.
+const ARROW_BARB_ANGLES: (f64, f64) = (10.0, 80.0);
+
+type Point = (f64, f64);
+
+/// A shape recognized from a freehand stroke, in canvas coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecognizedShape {
+    Line { start: (i32, i32), end: (i32, i32) },
+    Arrow { tail: (i32, i32), tip: (i32, i32) },
+    Rect { x: i32, y: i32, w: i32, h: i32 },
+    Ellipse { cx: i32, cy: i32, rx: i32, ry: i32 },
+    Triangle([(i32, i32); 3]),
+}
+
+impl RecognizedShape {
+    /// Builds the outline shape drawn with the stroke's color, thickness, and
+    /// line style.
+    pub fn into_shape(
+        self,
+        color: Color,
+        thick: f64,
+        arrow_length: f64,
+        arrow_angle: f64,
+        line_style: LineStyle,
+    ) -> Shape {
+        match self {
+            Self::Line { start, end } => Shape::Line {
+                x1: start.0,
+                y1: start.1,
+                x2: end.0,
+                y2: end.1,
+                color,
+                thick,
+                line_style,
+            },
+            Self::Arrow { tail, tip } => Shape::Arrow {
+                x1: tail.0,
+                y1: tail.1,
+                x2: tip.0,
+                y2: tip.1,
+                color,
+                thick,
+                arrow_length,
+                arrow_angle,
+                head_at_end: true,
+                label: None,
+                line_style,
+            },
+            Self::Rect { x, y, w, h } => Shape::Rect {
+                x,
+                y,
+                w,
+                h,
+                fill: false,
+                color,
+                thick,
+                rotation: 0.0,
+                line_style,
+            },
+            Self::Ellipse { cx, cy, rx, ry } => Shape::Ellipse {
+                cx,
+                cy,
+                rx,
+                ry,
+                fill: false,
+                color,
+                thick,
+                rotation: 0.0,
+                line_style,
+            },
+            Self::Triangle(points) => Shape::Polygon {
+                kind: PolygonKind::Triangle,
+                points: points.to_vec(),
+                fill: false,
+                color,
+                thick,
+                line_style,
+            },
+        }
+    }
+}
+
+/// Recognizes a line, arrow, rectangle, ellipse, or triangle in a freehand
+/// stroke, or returns `None` when the stroke fits none of them well.
+pub fn recognize_stroke(points: &[(i32, i32)]) -> Option<RecognizedShape> {
+    let mut stroke: Vec<Point> = Vec::with_capacity(points.len());
+    for &(x, y) in points {
+        let point = (f64::from(x), f64::from(y));
+        if stroke.last() != Some(&point) {
+            stroke.push(point);
+        }
+    }
+    if stroke.len() < 2 {
+        return None;
+    }
+    let bounds = Bounds::of(&stroke);
+    if bounds.diagonal() < MIN_SHAPE_SIZE {
+        return None;
+    }
+    let length: f64 = stroke
+        .windows(2)
+        .map(|pair| distance(pair[0], pair[1]))
+        .sum();
+    let gap = distance(stroke[0], stroke[stroke.len() - 1]);
+    if gap <= CLOSED_MAX_GAP * length {
+        recognize_closed(&stroke)
+    } else {
+        recognize_open(&stroke, &bounds, length, gap)
+    }
+}
+
+fn recognize_open(
+    stroke: &[Point],
+    bounds: &Bounds,
+    length: f64,
+    gap: f64,
+) -> Option<RecognizedShape> {
+    let start = stroke[0];
+    let end = stroke[stroke.len() - 1];
+    let deviation = stroke
+        .iter()
+        .map(|&point| segment_distance(point, start, end))
+        .fold(0.0, f64::max);
+    if length <= gap * LINE_MAX_PATH_RATIO && deviation <= gap * LINE_MAX_DEVIATION {
+        return Some(RecognizedShape::Line {
+            start: rounded(start),
+            end: rounded(end),
+        });
+    }
+
+    let vertices = simplify(stroke, CORNER_TOLERANCE * bounds.diagonal());
+    let [tail, tip, head @ ..] = vertices.as_slice() else {
+        return None;
+    };
+    let shaft = distance(*tail, *tip);
+    if head.is_empty() || shaft < ARROW_MIN_SHAFT_SHARE * length {
+        return None;
+    }
+    let back = ((tail.0 - tip.0) / shaft, (tail.1 - tip.1) / shaft);
+    let (min_angle, max_angle) = ARROW_BARB_ANGLES;
+    let mut barbs = 0;
+    for &point in head {
+        let reach = distance(point, *tip);
+        if reach > ARROW_MAX_HEAD * shaft {
+            return None;
+        }
+        if reach < CORNER_TOLERANCE * shaft {
+            continue;
+        }
+        let cosine = ((point.0 - tip.0) * back.0 + (point.1 - tip.1) * back.1) / reach;
+        let angle = cosine.clamp(-1.0, 1.0).acos().to_degrees();
+        if !(min_angle..=max_angle).contains(&angle) {
+            return None;
+        }
+        barbs += 1;
+    }
+    (barbs > 0).then(|| RecognizedShape::Arrow {
+        tail: rounded(*tail),
+        tip: rounded(*tip),
+    })
+}
+
+fn recognize_closed(stroke: &[Point]) -> Option<RecognizedShape> {
+    let stroke = trim_overshoot(stroke);
+    let bounds = &Bounds::of(stroke);
+    let (width, height) = (bounds.width(), bounds.height());
+    // A back-and-forth scribble also ends where it started.
+    if width.min(height) < MIN_SHAPE_SIZE / 3.0 {
+        return None;
+    }
+    let corners = closed_corners(stroke, CORNER_TOLERANCE * bounds.diagonal());
+
+    if let [a, b, c] = corners.as_slice() {
+        let area = ((b.0 - a.0) * (c.1 - a.1) - (c.0 - a.0) * (b.1 - a.1)).abs() / 2.0;
+        // Any triangle fills at most half its bounding box; a sliver is not
+        // what the user meant.
+        if area >= 0.25 * width * height {
+            return Some(RecognizedShape::Triangle([
+                rounded(*a),
+                rounded(*b),
+                rounded(*c),
+            ]));
+        }
+    }
+
+    if (4..=5).contains(&corners.len()) {
+        let band = RECT_EDGE_BAND * width.min(height);
+        let on_edges = stroke
+            .iter()
+            .filter(|&&(x, y)| {
+                (x - bounds.min.0)
+                    .min(bounds.max.0 - x)
+                    .min(y - bounds.min.1)
+                    .min(bounds.max.1 - y)
+                    <= band
+            })
+            .count();
+        if on_edges as f64 >= RECT_MIN_EDGE_SHARE * stroke.len() as f64 {
+            let (x, y) = rounded(bounds.min);
+            let (right, bottom) = rounded(bounds.max);
+            return Some(RecognizedShape::Rect {
+                x,
+                y,
+                w: right - x,
+                h: bottom - y,
+            });
+        }
+    }
+
+    let center = bounds.center();
+    let (rx, ry) = (width / 2.0, height / 2.0);
+    let error = stroke
+        .iter()
+        .map(|&(x, y)| (((x - center.0) / rx).hypot((y - center.1) / ry) - 1.0).abs())
+        .sum::<f64>()
+        / stroke.len() as f64;
+    if error <= ELLIPSE_MAX_ERROR {
+        let (cx, cy) = rounded(center);
+        return Some(RecognizedShape::Ellipse {
+            cx,
+            cy,
+            rx: rx.round() as i32,
+            ry: ry.round() as i32,
+        });
+    }
+    None
+}
+
+struct Bounds {
+    min: Point,
+    max: Point,
+}
+
+impl Bounds {
+    fn of(points: &[Point]) -> Self {
+        let mut bounds = Self {
+            min: (f64::INFINITY, f64::INFINITY),
+            max: (f64::NEG_INFINITY, f64::NEG_INFINITY),
+        };
+        for &(x, y) in points {
+            bounds.min = (bounds.min.0.min(x), bounds.min.1.min(y));
+            bounds.max = (bounds.max.0.max(x), bounds.max.1.max(y));
+        }
+        bounds
+    }
+
+    fn width(&self) -> f64 {
+        self.max.0 - self.min.0
+    }
+
+    fn height(&self) -> f64 {
+        self.max.1 - self.min.1
+    }
+
+    fn diagonal(&self) -> f64 {
+        self.width().hypot(self.height())
+    }
+
+    fn center(&self) -> Point {
+        (
+            (self.min.0 + self.max.0) / 2.0,
+            (self.min.1 + self.max.1) / 2.0,
+        )
+    }
+}
+
+/// Cuts a closed stroke where it comes back nearest to its start, dropping
+/// the overshoot that retraces the first edge.
+fn trim_overshoot(stroke: &[Point]) -> &[Point] {
+    let start = stroke[0];
+    let closest = (stroke.len() / 2..stroke.len())
+        .min_by(|&a, &b| distance(stroke[a], start).total_cmp(&distance(stroke[b], start)))
+        .unwrap_or(stroke.len() - 1);
+    &stroke[..=closest]
+}
+
+/// Corners of a closed stroke: the stroke is split at the point farthest from
+/// its start so neither half is degenerate, each half is simplified, and the
+/// start is dropped again if it only sat on an edge.
+fn closed_corners(stroke: &[Point], tolerance: f64) -> Vec<Point> {
+    let start = stroke[0];
+    let far = (0..stroke.len())
+        .max_by(|&a, &b| distance(stroke[a], start).total_cmp(&distance(stroke[b], start)))
+        .unwrap_or(0);
+    let mut ring = stroke.to_vec();
+    ring.push(start);
+    let mut corners = simplify(&ring[..=far], tolerance);
+    corners.pop();
+    corners.extend(simplify(&ring[far..], tolerance));
+    corners.pop();
+
+    // The stroke rarely starts on a corner, and its closing overshoot can
+    // leave a spurious vertex next to the start; drop vertices that sit on
+    // the edge between their neighbours.
+    let mut index = 0;
+    while corners.len() > 3 && index < corners.len() {
+        let previous = corners[(index + corners.len() - 1) % corners.len()];
+        let next = corners[(index + 1) % corners.len()];
+        if segment_distance(corners[index], previous, next) <= tolerance {
+            corners.remove(index);
+        } else {
+            index += 1;
+        }
+    }
+    corners
+}
+
+/// Douglas–Peucker simplification keeping both endpoints.
+fn simplify(points: &[Point], tolerance: f64) -> Vec<Point> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut spans = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = spans.pop() {
+        let farthest = (first + 1..last)
+            .map(|index| {
+                (
+                    index,
+                    segment_distance(points[index], points[first], points[last]),
+                )
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+        if let Some((index, offset)) = farthest
+            && offset > tolerance
+        {
+            keep[index] = true;
+            spans.push((first, index));
+            spans.push((index, last));
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(&point, kept)| kept.then_some(point))
+        .collect()
+}
+
+fn distance(a: Point, b: Point) -> f64 {
+    (a.0 - b.0).hypot(a.1 - b.1)
+}
+
+fn segment_distance(point: Point, start: Point, end: Point) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length_squared = dx * dx + dy * dy;
+    if length_squared == 0.0 {
+        return distance(point, start);
+    }
+    let t =
+        (((point.0 - start.0) * dx + (point.1 - start.1) * dy) / length_squared).clamp(0.0, 1.0);
+    distance(point, (start.0 + t * dx, start.1 + t * dy))
+}
+
+fn rounded(point: Point) -> (i32, i32) {
+    (point.0.round() as i32, point.1.round() as i32)
+}
@@ -0,0 +1,130 @@
+use super::bounds::{bounding_box_for_ellipse, ensure_positive_rect_f64};
+use super::types::{Shape, is_unrotated};
+use crate::util::Rect;
+
+impl Shape {
+    /// Clockwise rotation in degrees.
+    ///
+    /// Only rectangles, ellipses, text, images, and blur regions carry a
+    /// rotation; point-based shapes bake it into their points instead, and
+    /// markers and notes always stay upright.
+    pub fn rotation(&self) -> f64 {
+        match self {
+            Shape::Rect { rotation, .. }
+            | Shape::Ellipse { rotation, .. }
+            | Shape::Text { rotation, .. }
+            | Shape::Image { rotation, .. }
+            | Shape::BlurRect { rotation, .. } => *rotation,
+            _ => 0.0,
+        }
+    }
+
+    /// The rotation field, for shapes that have one.
+    pub(crate) fn rotation_mut(&mut self) -> Option<&mut f64> {
+        match self {
+            Shape::Rect { rotation, .. }
+            | Shape::Ellipse { rotation, .. }
+            | Shape::Text { rotation, .. }
+            | Shape::Image { rotation, .. }
+            | Shape::BlurRect { rotation, .. } => Some(rotation),
+            _ => None,
+        }
+    }
+
+    /// Point the shape is rotated about, or `None` while it is unrotated.
+    pub(crate) fn rotation_pivot(&self) -> Option<(f64, f64)> {
+        if is_unrotated(&self.rotation()) {
+            return None;
+        }
+        match self {
+            Shape::Rect { x, y, w, h, .. }
+            | Shape::Image { x, y, w, h, .. }
+            | Shape::BlurRect { x, y, w, h, .. } => Some((
+                f64::from(*x) + f64::from(*w) / 2.0,
+                f64::from(*y) + f64::from(*h) / 2.0,
+            )),
+            Shape::Ellipse { cx, cy, .. } => Some((f64::from(*cx), f64::from(*cy))),
+            // Text is anchored at its baseline, so it turns about the middle of
+            // the block it lays out to.
+            Shape::Text { .. } => self.local_bounding_box().map(rect_center),
+            _ => None,
+        }
+    }
+
+    /// Maps a canvas point into the shape's unrotated frame, where the
+    /// hit-testing geometry lives.
+    pub(crate) fn unrotate_point(&self, point: (i32, i32)) -> (i32, i32) {
+        let Some((cx, cy)) = self.rotation_pivot() else {
+            return point;
+        };
+        let (x, y) = rotate_point(
+            f64::from(point.0),
+            f64::from(point.1),
+            cx,
+            cy,
+            -self.rotation(),
+        );
+        (x.round() as i32, y.round() as i32)
+    }
+
+    pub(super) fn rotated_bounding_box(&self) -> Option<Rect> {
+        let degrees = self.rotation();
+        if let Shape::Ellipse {
+            cx,
+            cy,
+            rx,
+            ry,
+            thick,
+            ..
+        } = self
+        {
+            // Extremes of a rotated ellipse, which hug it far tighter than a
+            // rotated copy of its unrotated box would.
+            let (sin, cos) = degrees.to_radians().sin_cos();
+            let rx = f64::from(rx.saturating_abs());
+            let ry = f64::from(ry.saturating_abs());
+            let half_w = (rx * cos).hypot(ry * sin).ceil() as i32;
+            let half_h = (rx * sin).hypot(ry * cos).ceil() as i32;
+            return bounding_box_for_ellipse(*cx, *cy, half_w, half_h, *thick);
+        }
+        let local = self.local_bounding_box()?;
+        let (cx, cy) = self.rotation_pivot()?;
+        rotated_rect_bounds(local, cx, cy, degrees)
+    }
+}
+
+/// Rotates `(x, y)` clockwise by `degrees` about `(cx, cy)` in canvas
+/// coordinates (y pointing down), matching `cairo::Context::rotate`.
+pub fn rotate_point(x: f64, y: f64, cx: f64, cy: f64, degrees: f64) -> (f64, f64) {
+    let (sin, cos) = degrees.to_radians().sin_cos();
+    let dx = x - cx;
+    let dy = y - cy;
+    (cx + dx * cos - dy * sin, cy + dx * sin + dy * cos)
+}
+
+/// Axis-aligned box around `rect` once rotated by `degrees` about `(cx, cy)`.
+pub(crate) fn rotated_rect_bounds(rect: Rect, cx: f64, cy: f64, degrees: f64) -> Option<Rect> {
+    let left = f64::from(rect.x);
+    let top = f64::from(rect.y);
+    let right = left + f64::from(rect.width);
+    let bottom = top + f64::from(rect.height);
+    let corners = [(left, top), (right, top), (left, bottom), (right, bottom)]
+        .map(|(x, y)| rotate_point(x, y, cx, cy, degrees));
+
+    let (mut min_x, mut min_y) = corners[0];
+    let (mut max_x, mut max_y) = corners[0];
+    for (x, y) in &corners[1..] {
+        min_x = min_x.min(*x);
+        min_y = min_y.min(*y);
+        max_x = max_x.max(*x);
+        max_y = max_y.max(*y);
+    }
+    ensure_positive_rect_f64(min_x, min_y, max_x, max_y)
+}
+
+fn rect_center(rect: Rect) -> (f64, f64) {
+    (
+        f64::from(rect.x) + f64::from(rect.width) / 2.0,
+        f64::from(rect.y) + f64::from(rect.height) / 2.0,
+    )
+}
@@ -0,0 +1,222 @@
+//! Curve fitting for finished freehand strokes.
+//!
+//! A stroke arrives as the integer pointer samples it was drawn with, joined
+//! by straight segments. Fitting first drops the samples that only carry
+//! pointer jitter (Ramer-Douglas-Peucker), then runs a centripetal Catmull-Rom
+//! spline through the points that remain and samples it at the spacing the
+//! stroke was drawn with. The samples replace the stroke's points, so
+//! rendering, hit-testing and export all see the same curve.
+//!
+//! Points are stored as whole pixels, so every sample is rounded. Sampling
+//! much closer than the input did would turn that rounding into visible
+//! wobble between neighbouring samples; matching the input's spacing keeps the
+//! error no larger than the drawn stroke already had.
+
+/// Samples within this many pixels of the simplified path are treated as
+/// jitter and dropped before fitting.
+const SIMPLIFY_TOLERANCE: f64 = 1.0;
+/// Bounds, in pixels, on the spacing between points sampled along the fitted
+/// curve. Within them the curve is sampled as densely as the input was.
+const MIN_SAMPLE_SPACING: f64 = 2.0;
+const MAX_SAMPLE_SPACING: f64 = 3.0;
+/// Longest gap between anchors along a span the simplifier found straight.
+/// Extra anchors keep the spline on such spans instead of letting it bow out
+/// past the corners at their ends.
+const STRAIGHT_ANCHOR_SPACING: f64 = 12.0;
+/// Smallest knot interval, so repeated points never divide by zero.
+const MIN_KNOT_INTERVAL: f64 = 1e-3;
+
+/// A stroke sample: position and pen thickness.
+type Sample = (f64, f64, f64);
+
+/// Fits a smooth curve through a freehand or marker stroke.
+pub(crate) fn smooth_stroke(points: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let samples: Vec<Sample> = points
+        .iter()
+        .map(|&(x, y)| (f64::from(x), f64::from(y), 0.0))
+        .collect();
+    let mut smoothed: Vec<(i32, i32)> = Vec::with_capacity(samples.len());
+    for (x, y, _) in fit(&samples) {
+        let point = (x.round() as i32, y.round() as i32);
+        if smoothed.last() != Some(&point) {
+            smoothed.push(point);
+        }
+    }
+    smoothed
+}
+
+/// Fits a smooth curve through a pressure stroke. Thickness is interpolated
+/// linearly between the fitted points, so it never overshoots.
+pub(crate) fn smooth_pressure_stroke(points: &[(i32, i32, f32)]) -> Vec<(i32, i32, f32)> {
+    let samples: Vec<Sample> = points
+        .iter()
+        .map(|&(x, y, thickness)| (f64::from(x), f64::from(y), f64::from(thickness)))
+        .collect();
+    let mut smoothed: Vec<(i32, i32, f32)> = Vec::with_capacity(samples.len());
+    for (x, y, thickness) in fit(&samples) {
+        let (x, y) = (x.round() as i32, y.round() as i32);
+        match smoothed.last_mut() {
+            Some(last) if (last.0, last.1) == (x, y) => last.2 = last.2.max(thickness as f32),
+            _ => smoothed.push((x, y, thickness as f32)),
+        }
+    }
+    smoothed
+}
+
+fn fit(samples: &[Sample]) -> Vec<Sample> {
+    let mut distinct: Vec<Sample> = Vec::with_capacity(samples.len());
+    for &sample in samples {
+        match distinct.last_mut() {
+            Some(last) if (last.0, last.1) == (sample.0, sample.1) => last.2 = last.2.max(sample.2),
+            _ => distinct.push(sample),
+        }
+    }
+    if distinct.len() < 3 {
+        return distinct;
+    }
+
+    let anchors = anchors(&distinct);
+    if anchors.len() < 3 {
+        return anchors;
+    }
+    let spacing = input_spacing(&distinct).clamp(MIN_SAMPLE_SPACING, MAX_SAMPLE_SPACING);
+
+    let mut curve = Vec::new();
+    for index in 0..anchors.len() - 1 {
+        let p1 = anchors[index];
+        let p2 = anchors[index + 1];
+        // Reflect the neighbours past each end so the curve leaves and meets
+        // the end points along the stroke's own direction.
+        let p0 = if index > 0 {
+            anchors[index - 1]
+        } else {
+            reflect(p2, p1)
+        };
+        let p3 = anchors
+            .get(index + 2)
+            .copied()
+            .unwrap_or_else(|| reflect(p1, p2));
+        let steps = (distance(p1, p2) / spacing).ceil().max(1.0) as usize;
+        for step in 0..steps {
+            let u = step as f64 / steps as f64;
+            let (x, y) = catmull_rom(p0, p1, p2, p3, u);
+            curve.push((x, y, p1.2 + (p2.2 - p1.2) * u));
+        }
+    }
+    curve.push(*anchors.last().expect("at least three anchors"));
+    curve
+}
+
+/// The points the spline passes through: the simplified stroke, with extra
+/// points along the straight spans it smoothed over.
+fn anchors(samples: &[Sample]) -> Vec<Sample> {
+    let kept = simplify(samples);
+    let mut anchors = vec![samples[0]];
+    for pair in kept.windows(2) {
+        let (start, end) = (samples[pair[0]], samples[pair[1]]);
+        let straight = pair[1] - pair[0] > 1;
+        let pieces = if straight {
+            (distance(start, end) / STRAIGHT_ANCHOR_SPACING)
+                .ceil()
+                .max(1.0) as usize
+        } else {
+            1
+        };
+        for piece in 1..=pieces {
+            let t = piece as f64 / pieces as f64;
+            anchors.push((
+                start.0 + (end.0 - start.0) * t,
+                start.1 + (end.1 - start.1) * t,
+                start.2 + (end.2 - start.2) * t,
+            ));
+        }
+    }
+    anchors
+}
+
+/// Ramer-Douglas-Peucker over position and thickness: returns the indices of
+/// the samples that stray from the chord between the kept samples around
+/// them, plus both ends.
+fn simplify(samples: &[Sample]) -> Vec<usize> {
+    let last = samples.len() - 1;
+    let mut keep = vec![false; samples.len()];
+    keep[0] = true;
+    keep[last] = true;
+    let mut spans = vec![(0, last)];
+    while let Some((start, end)) = spans.pop() {
+        let mut worst = (start, 0.0);
+        for index in start + 1..end {
+            let error = chord_error(samples[index], samples[start], samples[end]);
+            if error > worst.1 {
+                worst = (index, error);
+            }
+        }
+        if worst.1 > SIMPLIFY_TOLERANCE {
+            keep[worst.0] = true;
+            spans.push((start, worst.0));
+            spans.push((worst.0, end));
+        }
+    }
+    keep.iter()
+        .enumerate()
+        .filter_map(|(index, &kept)| kept.then_some(index))
+        .collect()
+}
+
+fn chord_error(sample: Sample, start: Sample, end: Sample) -> f64 {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    let length_sq = dx * dx + dy * dy;
+    let t = if length_sq > 0.0 {
+        (((sample.0 - start.0) * dx + (sample.1 - start.1) * dy) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let along = (
+        start.0 + dx * t,
+        start.1 + dy * t,
+        start.2 + (end.2 - start.2) * t,
+    );
+    let offset = (sample.0 - along.0).hypot(sample.1 - along.1);
+    offset.max((sample.2 - along.2).abs())
+}
+
+/// Mean distance between consecutive samples of the drawn stroke.
+fn input_spacing(samples: &[Sample]) -> f64 {
+    let length: f64 = samples
+        .windows(2)
+        .map(|pair| distance(pair[0], pair[1]))
+        .sum();
+    length / (samples.len() - 1) as f64
+}
+
+fn distance(a: Sample, b: Sample) -> f64 {
+    (b.0 - a.0).hypot(b.1 - a.1)
+}
+
+fn reflect(point: Sample, center: Sample) -> Sample {
+    (2.0 * center.0 - point.0, 2.0 * center.1 - point.1, center.2)
+}
+
+/// Point `u` (0..1) of the way from `p1` to `p2` on the centripetal
+/// Catmull-Rom segment, which avoids the cusps and loops the uniform spline
+/// forms where anchor spacing changes sharply.
+fn catmull_rom(p0: Sample, p1: Sample, p2: Sample, p3: Sample, u: f64) -> (f64, f64) {
+    let knot = |a: Sample, b: Sample| distance(a, b).sqrt().max(MIN_KNOT_INTERVAL);
+    let t0 = 0.0;
+    let t1 = t0 + knot(p0, p1);
+    let t2 = t1 + knot(p1, p2);
+    let t3 = t2 + knot(p2, p3);
+    let t = t1 + (t2 - t1) * u;
+
+    let lerp = |a: (f64, f64), b: (f64, f64), ta: f64, tb: f64| {
+        let w = (t - ta) / (tb - ta);
+        (a.0 + (b.0 - a.0) * w, a.1 + (b.1 - a.1) * w)
+    };
+    let (q0, q1, q2, q3) = ((p0.0, p0.1), (p1.0, p1.1), (p2.0, p2.1), (p3.0, p3.1));
+    let a1 = lerp(q0, q1, t0, t1);
+    let a2 = lerp(q1, q2, t1, t2);
+    let a3 = lerp(q2, q3, t2, t3);
+    let b1 = lerp(a1, a2, t0, t2);
+    let b2 = lerp(a2, a3, t1, t3);
+    lerp(b1, b2, t1, t2)
+}
@@ -1,6 +1,7 @@
-use crate::draw::FontDescriptor;
+use crate::draw::{Color, FontDescriptor};
 use crate::util::Rect;
 
+use super::markup::TextMarkup;
 use super::text_cache::measure_text_cached;
 
 const STEP_MARKER_PADDING_RATIO: f64 = 0.45;
@@ -10,7 +11,7 @@ const STEP_MARKER_MIN_RADIUS: f64 = 10.0;
 pub(crate) fn step_marker_radius(value: u32, size: f64, font_descriptor: &FontDescriptor) -> f64 {
     let text = value.to_string();
     let font_desc_str = font_descriptor.to_pango_string(size);
-    let max_dim = measure_text_cached(&text, &font_desc_str, size, None)
+    let max_dim = measure_text_cached(&text, &font_desc_str, size, None, TextMarkup::Plain)
         .map(|m| m.ink_width.max(m.ink_height))
         .unwrap_or(size * 0.6);
     let padding = (size * STEP_MARKER_PADDING_RATIO).max(STEP_MARKER_PADDING_MIN);
@@ -21,6 +22,60 @@ pub(crate) fn step_marker_outline_thickness(size: f64) -> f64 {
     (size * 0.12).max(1.5)
 }
 
+/// Paint colors for a step marker bubble of the given base color.
+pub(crate) struct StepMarkerColors {
+    pub fill: Color,
+    pub outline: Color,
+    pub text: Color,
+}
+
+/// Bubble fill plus an outline and label that contrast with it: dark on light
+/// bubbles, light on dark ones.
+pub(crate) fn step_marker_colors(color: Color) -> StepMarkerColors {
+    let alpha = color.a.clamp(0.0, 1.0);
+    let fill = Color {
+        a: (alpha * 0.9).clamp(0.0, 1.0),
+        ..color
+    };
+    let brightness = color.r * 0.299 + color.g * 0.587 + color.b * 0.114;
+    let (outline, text) = if brightness > 0.6 {
+        (
+            Color {
+                r: 0.05,
+                g: 0.05,
+                b: 0.05,
+                a: 0.85 * alpha,
+            },
+            Color {
+                r: 0.12,
+                g: 0.12,
+                b: 0.12,
+                a: alpha,
+            },
+        )
+    } else {
+        (
+            Color {
+                r: 0.98,
+                g: 0.98,
+                b: 0.98,
+                a: 0.9 * alpha,
+            },
+            Color {
+                r: 0.98,
+                g: 0.98,
+                b: 0.98,
+                a: alpha,
+            },
+        )
+    };
+    StepMarkerColors {
+        fill,
+        outline,
+        text,
+    }
+}
+
 pub(crate) fn step_marker_bounds(
     x: i32,
     y: i32,
@@ -1,5 +1,7 @@
+use super::recognition::{RecognizedShape, recognize_stroke};
+use super::smoothing::{smooth_pressure_stroke, smooth_stroke};
 use super::types::Shape;
-use super::{EmbeddedImage, EraserBrush};
+use super::{EmbeddedImage, EraserBrush, LineStyle};
 use crate::draw::{EraserKind, FontDescriptor, PolygonKind, StepMarkerLabel, color::WHITE};
 use crate::util;
 
@@ -9,6 +11,7 @@ fn freehand_bounding_box_expands_with_thickness() {
         points: vec![(10, 20), (30, 40)],
         color: WHITE,
         thick: 6.0,
+        line_style: LineStyle::Solid,
     };
 
     let rect = shape.bounding_box().expect("freehand should have bounds");
@@ -27,6 +30,7 @@ fn line_bounding_box_covers_stroke() {
         y2: 90,
         color: WHITE,
         thick: 4.0,
+        line_style: LineStyle::Solid,
     };
 
     let rect = shape.bounding_box().expect("line should have bounds");
@@ -49,6 +53,7 @@ fn arrow_bounding_box_includes_head() {
         arrow_angle: 30.0,
         head_at_end: false,
         label: None,
+        line_style: LineStyle::Solid,
     };
 
     let rect = shape.bounding_box().expect("arrow should have bounds");
@@ -98,6 +103,8 @@ fn ellipse_bounding_box_handles_radii_and_stroke() {
         fill: false,
         color: WHITE,
         thick: 2.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     };
 
     let rect = shape.bounding_box().expect("ellipse should have bounds");
@@ -107,6 +114,77 @@ fn ellipse_bounding_box_handles_radii_and_stroke() {
     assert_eq!(rect.height, 42);
 }
 
+#[test]
+fn rotated_rect_bounding_box_covers_turned_corners() {
+    let shape = Shape::Rect {
+        x: 0,
+        y: 0,
+        w: 100,
+        h: 20,
+        fill: true,
+        color: WHITE,
+        thick: 2.0,
+        rotation: 90.0,
+        line_style: LineStyle::Solid,
+    };
+
+    let rect = shape
+        .bounding_box()
+        .expect("rotated rect should have bounds");
+    assert!(rect.width < rect.height);
+    assert!(rect.contains(50, -40));
+    assert!(rect.contains(50, 59));
+}
+
+#[test]
+fn rotated_ellipse_bounding_box_swaps_axes_at_quarter_turn() {
+    let shape = Shape::Ellipse {
+        cx: 200,
+        cy: 150,
+        rx: 40,
+        ry: 20,
+        fill: false,
+        color: WHITE,
+        thick: 2.0,
+        rotation: 90.0,
+        line_style: LineStyle::Solid,
+    };
+
+    let rect = shape.bounding_box().expect("ellipse should have bounds");
+    assert_eq!(rect.x, 179);
+    assert_eq!(rect.y, 109);
+    assert_eq!(rect.width, 42);
+    assert_eq!(rect.height, 82);
+}
+
+#[test]
+fn unrotated_shapes_omit_rotation_when_serialized() {
+    let shape = Shape::Rect {
+        x: 1,
+        y: 2,
+        w: 3,
+        h: 4,
+        fill: false,
+        color: WHITE,
+        thick: 1.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
+    };
+    let json = serde_json::to_string(&shape).expect("serialize rect");
+    assert!(!json.contains("rotation"));
+
+    let legacy: Shape = serde_json::from_str(&json).expect("deserialize rect");
+    assert_eq!(legacy.rotation(), 0.0);
+
+    let mut turned = legacy;
+    if let Some(rotation) = turned.rotation_mut() {
+        *rotation = 30.0;
+    }
+    let json = serde_json::to_string(&turned).expect("serialize rotated rect");
+    let restored: Shape = serde_json::from_str(&json).expect("deserialize rotated rect");
+    assert_eq!(restored, turned);
+}
+
 #[test]
 fn polygon_bounding_box_covers_vertices_and_stroke() {
     let shape = Shape::Polygon {
@@ -115,6 +193,7 @@ fn polygon_bounding_box_covers_vertices_and_stroke() {
         fill: false,
         color: WHITE,
         thick: 6.0,
+        line_style: LineStyle::Solid,
     };
 
     let rect = shape.bounding_box().expect("polygon should have bounds");
@@ -132,6 +211,7 @@ fn polygon_shape_serializes_and_deserializes_with_points() {
         fill: true,
         color: WHITE,
         thick: 4.0,
+        line_style: LineStyle::Solid,
     };
 
     let json = serde_json::to_string(&shape).expect("serialize polygon shape");
@@ -144,8 +224,10 @@ fn polygon_shape_serializes_and_deserializes_with_points() {
             fill,
             color,
             thick,
+            line_style,
         } => {
             assert_eq!(kind, PolygonKind::Regular { sides: 6 });
+            assert_eq!(line_style, LineStyle::Solid);
             assert_eq!(
                 points,
                 vec![(10, 20), (30, 20), (40, 35), (30, 50), (10, 50), (0, 35)]
@@ -166,6 +248,7 @@ fn invalid_polygon_has_no_bounds() {
         fill: false,
         color: WHITE,
         thick: 6.0,
+        line_style: LineStyle::Solid,
     };
 
     assert!(shape.bounding_box().is_none());
@@ -182,6 +265,7 @@ fn text_bounding_box_is_non_zero() {
         font_descriptor: FontDescriptor::default(),
         background_enabled: true,
         wrap_width: None,
+        rotation: 0.0,
     };
 
     let rect = shape.bounding_box().expect("text should have bounds");
@@ -282,6 +366,7 @@ fn image_bounding_box_and_kind_name_use_display_bounds() {
             height: 1,
             bytes: vec![1, 2, 3],
         },
+        rotation: 0.0,
     };
 
     let rect = shape.bounding_box().expect("image should have bounds");
@@ -302,6 +387,7 @@ fn image_serialization_uses_base64_bytes() {
             height: 4,
             bytes: vec![1, 2, 3, 4],
         },
+        rotation: 0.0,
     };
 
     let json = serde_json::to_string(&shape).expect("serialize image shape");
@@ -316,3 +402,239 @@ fn image_serialization_uses_base64_bytes() {
         other => panic!("expected image shape, got {:?}", other),
     }
 }
+
+/// Samples a polyline through `vertices` every few pixels, the way pointer
+/// motion arrives while drawing.
+fn traced(vertices: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut points = vec![vertices[0]];
+    for pair in vertices.windows(2) {
+        let (dx, dy) = (pair[1].0 - pair[0].0, pair[1].1 - pair[0].1);
+        let steps = (dx.abs().max(dy.abs()) / 4).max(1);
+        for step in 1..=steps {
+            points.push((pair[0].0 + dx * step / steps, pair[0].1 + dy * step / steps));
+        }
+    }
+    points
+}
+
+#[test]
+fn smart_ink_straightens_lines_and_arrows() {
+    let wobbly = traced(&[(0, 0), (60, 3), (120, -2), (200, 4)]);
+    assert_eq!(
+        recognize_stroke(&wobbly),
+        Some(RecognizedShape::Line {
+            start: (0, 0),
+            end: (200, 4)
+        })
+    );
+
+    let arrow = traced(&[(0, 100), (200, 100), (170, 80), (200, 100), (170, 122)]);
+    assert_eq!(
+        recognize_stroke(&arrow),
+        Some(RecognizedShape::Arrow {
+            tail: (0, 100),
+            tip: (200, 100)
+        })
+    );
+}
+
+#[test]
+fn smart_ink_recognizes_closed_shapes() {
+    // Starts mid-edge and overshoots the close, like a hand-drawn box.
+    let rect = traced(&[
+        (100, 12),
+        (210, 10),
+        (212, 130),
+        (10, 128),
+        (8, 11),
+        (120, 10),
+    ]);
+    assert_eq!(
+        recognize_stroke(&rect),
+        Some(RecognizedShape::Rect {
+            x: 8,
+            y: 10,
+            w: 204,
+            h: 120
+        })
+    );
+
+    let circle: Vec<(i32, i32)> = (0..=68)
+        .map(|step| {
+            let angle = f64::from(step) * std::f64::consts::TAU / 64.0;
+            (
+                (100.0 + 60.0 * angle.cos()).round() as i32,
+                (100.0 + 60.0 * angle.sin()).round() as i32,
+            )
+        })
+        .collect();
+    assert_eq!(
+        recognize_stroke(&circle),
+        Some(RecognizedShape::Ellipse {
+            cx: 100,
+            cy: 100,
+            rx: 60,
+            ry: 60
+        })
+    );
+
+    let triangle = traced(&[(30, 50), (60, 0), (120, 100), (0, 100), (30, 50)]);
+    match recognize_stroke(&triangle) {
+        Some(RecognizedShape::Triangle(corners)) => {
+            for corner in [(60, 0), (120, 100), (0, 100)] {
+                assert!(corners.contains(&corner), "{corners:?} misses {corner:?}");
+            }
+        }
+        other => panic!("expected triangle, got {other:?}"),
+    }
+}
+
+#[test]
+fn smart_ink_leaves_scribbles_and_ticks_alone() {
+    let zigzag = traced(&[(0, 0), (40, 80), (80, 0), (120, 80), (160, 0)]);
+    assert_eq!(recognize_stroke(&zigzag), None);
+    let tick = traced(&[(0, 0), (6, 8), (16, -6)]);
+    assert_eq!(recognize_stroke(&tick), None);
+    let check = traced(&[(0, 40), (30, 80), (120, 0)]);
+    assert_eq!(recognize_stroke(&check), None);
+
+    let arrow = RecognizedShape::Arrow {
+        tail: (0, 0),
+        tip: (50, 0),
+    }
+    .into_shape(WHITE, 3.0, 20.0, 30.0, LineStyle::Dashed);
+    assert!(matches!(
+        arrow,
+        Shape::Arrow {
+            head_at_end: true,
+            label: None,
+            line_style: LineStyle::Dashed,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn line_style_cycles_and_scales_dashes_with_thickness() {
+    let mut style = LineStyle::Solid;
+    for expected in [
+        LineStyle::Dashed,
+        LineStyle::Dotted,
+        LineStyle::DashDot,
+        LineStyle::Solid,
+    ] {
+        style = style.next();
+        assert_eq!(style, expected);
+    }
+
+    assert!(LineStyle::Solid.dash_pattern(4.0).is_empty());
+    assert_eq!(LineStyle::Dashed.dash_pattern(2.0), vec![6.0, 8.0]);
+    assert_eq!(LineStyle::Dashed.dash_pattern(4.0), vec![12.0, 16.0]);
+    assert_eq!(LineStyle::Dotted.dash_pattern(0.5), vec![0.0, 2.5]);
+}
+
+#[test]
+fn line_style_is_omitted_for_solid_shapes_and_defaults_when_missing() {
+    let solid = Shape::Line {
+        x1: 0,
+        y1: 0,
+        x2: 10,
+        y2: 10,
+        color: WHITE,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    };
+    let json = serde_json::to_string(&solid).expect("serialize solid line");
+    assert!(!json.contains("line_style"));
+    let restored: Shape = serde_json::from_str(&json).expect("deserialize solid line");
+    assert_eq!(restored.line_style(), Some(LineStyle::Solid));
+
+    let dash_dot = Shape::Rect {
+        x: 0,
+        y: 0,
+        w: 10,
+        h: 10,
+        fill: false,
+        color: WHITE,
+        thick: 2.0,
+        rotation: 0.0,
+        line_style: LineStyle::DashDot,
+    };
+    let json = serde_json::to_string(&dash_dot).expect("serialize dash-dot rect");
+    assert!(json.contains("\"line_style\":\"dash-dot\""));
+    let restored: Shape = serde_json::from_str(&json).expect("deserialize dash-dot rect");
+    assert_eq!(restored.line_style(), Some(LineStyle::DashDot));
+}
+
+#[test]
+fn smoothing_keeps_stroke_ends_and_straightens_jitter() {
+    // A horizontal stroke with one-pixel pointer jitter.
+    let jittery: Vec<(i32, i32)> = (0..=40).map(|step| (step * 5, 100 + (step % 2))).collect();
+    let smoothed = smooth_stroke(&jittery);
+
+    assert_eq!(smoothed.first(), Some(&(0, 100)));
+    assert_eq!(smoothed.last(), jittery.last());
+    assert!(smoothed.iter().all(|&(_, y)| (100..=101).contains(&y)));
+    assert!(
+        smoothed
+            .windows(2)
+            .all(|pair| (pair[1].0 - pair[0].0).abs() <= 3 && pair[0] != pair[1])
+    );
+}
+
+#[test]
+fn smoothing_keeps_straight_legs_tight_at_a_corner() {
+    let mut corner: Vec<(i32, i32)> = (0..=20).map(|step| (step * 5, 0)).collect();
+    corner.extend((1..=20).map(|step| (100, step * 5)));
+    let smoothed = smooth_stroke(&corner);
+
+    assert_eq!(smoothed.first(), Some(&(0, 0)));
+    assert_eq!(smoothed.last(), Some(&(100, 100)));
+    assert!(smoothed.contains(&(100, 0)));
+    assert!(
+        smoothed
+            .iter()
+            .all(|&(x, y)| (0..=101).contains(&x) && (-1..=100).contains(&y))
+    );
+}
+
+#[test]
+fn smoothing_samples_about_as_densely_as_the_stroke_was_drawn() {
+    // A gentle arc drawn with a sample every 3 px.
+    let arc: Vec<(i32, i32)> = (0..=40)
+        .map(|step| {
+            let x = step * 3;
+            (x, ((x - 60) * (x - 60)) / 600)
+        })
+        .collect();
+    let smoothed = smooth_stroke(&arc);
+
+    assert_eq!(smoothed.first(), arc.first());
+    assert_eq!(smoothed.last(), arc.last());
+    // About one sample per drawn point, rather than one every 2 px.
+    assert!(
+        smoothed.len() <= arc.len() * 5 / 4,
+        "{} samples for {} drawn",
+        smoothed.len(),
+        arc.len()
+    );
+}
+
+#[test]
+fn smoothing_leaves_short_strokes_alone_and_interpolates_pressure() {
+    assert_eq!(smooth_stroke(&[(5, 5)]), vec![(5, 5)]);
+    assert_eq!(
+        smooth_stroke(&[(5, 5), (5, 5), (9, 9)]),
+        vec![(5, 5), (9, 9)]
+    );
+
+    let pressure = [(0, 0, 2.0), (50, 30, 8.0), (100, 0, 4.0)];
+    let smoothed = smooth_pressure_stroke(&pressure);
+    assert_eq!(smoothed.first(), Some(&(0, 0, 2.0)));
+    assert_eq!(smoothed.last(), Some(&(100, 0, 4.0)));
+    assert!(
+        smoothed
+            .iter()
+            .all(|&(_, _, thickness)| (2.0..=8.0).contains(&thickness))
+    );
+}
@@ -2,13 +2,17 @@ use crate::draw::font::FontDescriptor;
 use crate::util::Rect;
 
 use super::bounds::ensure_positive_rect_f64;
-use super::text_cache::{TextContentExtents, TextMeasurement, measure_text_cached};
+use super::markup::TextMarkup;
+use super::text_cache::{
+    TextContentExtents, TextMeasurement, configured_layout, measure_text_cached,
+};
 
 pub(super) fn text_layout_metrics(
     text: &str,
     size: f64,
     font_descriptor: &FontDescriptor,
     wrap_width: Option<i32>,
+    markup: TextMarkup,
 ) -> Option<TextMeasurement> {
     if text.is_empty() {
         return None;
@@ -16,7 +20,7 @@ pub(super) fn text_layout_metrics(
 
     // Use cached text measurement instead of creating a new surface each time
     let font_desc_str = font_descriptor.to_pango_string(size);
-    let measurement = measure_text_cached(text, &font_desc_str, size, wrap_width)?;
+    let measurement = measure_text_cached(text, &font_desc_str, size, wrap_width, markup)?;
 
     Some(measurement)
 }
@@ -82,7 +86,52 @@ pub(crate) fn bounding_box_for_text(
     background_enabled: bool,
     wrap_width: Option<i32>,
 ) -> Option<Rect> {
-    let metrics = text_layout_metrics(text, size, font_descriptor, wrap_width)?;
+    bounding_box_for_text_layout(
+        x,
+        y,
+        text,
+        size,
+        font_descriptor,
+        background_enabled,
+        wrap_width,
+        TextMarkup::Rendered,
+    )
+}
+
+/// Bounds of the live editor text, which lays its markup out as source.
+pub(crate) fn bounding_box_for_text_preview(
+    x: i32,
+    y: i32,
+    text: &str,
+    size: f64,
+    font_descriptor: &FontDescriptor,
+    background_enabled: bool,
+    wrap_width: Option<i32>,
+) -> Option<Rect> {
+    bounding_box_for_text_layout(
+        x,
+        y,
+        text,
+        size,
+        font_descriptor,
+        background_enabled,
+        wrap_width,
+        TextMarkup::Source,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn bounding_box_for_text_layout(
+    x: i32,
+    y: i32,
+    text: &str,
+    size: f64,
+    font_descriptor: &FontDescriptor,
+    background_enabled: bool,
+    wrap_width: Option<i32>,
+    markup: TextMarkup,
+) -> Option<Rect> {
+    let metrics = text_layout_metrics(text, size, font_descriptor, wrap_width, markup)?;
     text_bounds_from_metrics(
         x as f64,
         y as f64,
@@ -157,25 +206,22 @@ pub(crate) fn sticky_note_text_layout(
     size: f64,
     font_descriptor: &FontDescriptor,
     wrap_width: Option<i32>,
+    markup: TextMarkup,
 ) -> StickyNoteTextLayout {
     let font_desc_str = font_descriptor.to_pango_string(size);
 
     // Create layout for rendering (required for draw operations)
-    let layout = pangocairo::functions::create_layout(ctx);
-    let font_desc = pango::FontDescription::from_string(&font_desc_str);
-    layout.set_font_description(Some(&font_desc));
-    layout.set_text(text);
-    if let Some(width) = wrap_width {
-        let width = width.max(1);
-        let width_pango = (width as i64 * pango::SCALE as i64).min(i32::MAX as i64) as i32;
-        layout.set_width(width_pango);
-        layout.set_wrap(pango::WrapMode::WordChar);
-    }
+    let layout = configured_layout(ctx, text, &font_desc_str, wrap_width, markup);
 
     // Use cached measurements if available, otherwise measure and cache
-    if let Some(measurement) =
-        super::text_cache::measure_text_with_context(ctx, text, &font_desc_str, size, wrap_width)
-    {
+    if let Some(measurement) = super::text_cache::measure_text_with_context(
+        ctx,
+        text,
+        &font_desc_str,
+        size,
+        wrap_width,
+        markup,
+    ) {
         StickyNoteTextLayout {
             layout,
             content: measurement.content_extents(wrap_width),
@@ -217,7 +263,15 @@ pub(crate) fn bounding_box_for_sticky_note(
     if text.is_empty() {
         return None;
     }
-    bounding_box_for_sticky_note_layout(x, y, text, size, font_descriptor, wrap_width)
+    bounding_box_for_sticky_note_layout(
+        x,
+        y,
+        text,
+        size,
+        font_descriptor,
+        wrap_width,
+        TextMarkup::Rendered,
+    )
 }
 
 pub(crate) fn bounding_box_for_sticky_note_preview(
@@ -235,6 +289,7 @@ pub(crate) fn bounding_box_for_sticky_note_preview(
         size,
         font_descriptor,
         wrap_width,
+        TextMarkup::Source,
     )
 }
 
@@ -245,10 +300,11 @@ fn bounding_box_for_sticky_note_layout(
     size: f64,
     font_descriptor: &FontDescriptor,
     wrap_width: Option<i32>,
+    markup: TextMarkup,
 ) -> Option<Rect> {
     // Use cached text measurement instead of creating a new surface each time
     let font_desc_str = font_descriptor.to_pango_string(size);
-    let measurement = measure_text_cached(text, &font_desc_str, size, wrap_width)?;
+    let measurement = measure_text_cached(text, &font_desc_str, size, wrap_width, markup)?;
 
     let base_x = x as f64;
     let base_y = y as f64 - measurement.baseline;
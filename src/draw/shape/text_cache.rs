@@ -1,6 +1,8 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 
+use super::markup::{RichText, TextMarkup, pango_attributes, parse_markup};
+
 /// Cached text measurement results from Pango layout.
 #[derive(Clone, Debug)]
 pub(crate) struct TextMeasurement {
@@ -52,15 +54,23 @@ struct TextCacheKey {
     size_hundredths: i32,
     /// Wrap width in pixels, or -1 for no wrap
     wrap_width: i32,
+    markup: TextMarkup,
 }
 
 impl TextCacheKey {
-    fn new(text: &str, font_desc_str: &str, size: f64, wrap_width: Option<i32>) -> Self {
+    fn new(
+        text: &str,
+        font_desc_str: &str,
+        size: f64,
+        wrap_width: Option<i32>,
+        markup: TextMarkup,
+    ) -> Self {
         Self {
             text: text.to_string(),
             font_desc_str: font_desc_str.to_string(),
             size_hundredths: (size * 100.0).round() as i32,
             wrap_width: wrap_width.unwrap_or(-1),
+            markup,
         }
     }
 }
@@ -147,19 +157,34 @@ where
 }
 
 /// Build a Pango layout configured exactly like the measurement and render
-/// paths: same font description, same text, same wrap mode and width clamp.
-/// Every caret, hit-test, and decoration helper goes through this, so their
-/// geometry cannot drift from what `measure_text_cached` and the renderer see.
+/// paths: same font description, same text and markup attributes, same wrap
+/// mode and width clamp. Every caret, hit-test, and decoration helper goes
+/// through this, so their geometry cannot drift from what
+/// `measure_text_cached` and the renderer see.
 pub(crate) fn configured_layout(
     ctx: &cairo::Context,
     text: &str,
     font_desc_str: &str,
     wrap_width: Option<i32>,
+    markup: TextMarkup,
+) -> pango::Layout {
+    rich_text_layout(ctx, &parse_markup(text, markup), font_desc_str, wrap_width)
+}
+
+/// [`configured_layout`] for markup that has already been resolved.
+pub(crate) fn rich_text_layout(
+    ctx: &cairo::Context,
+    rich: &RichText,
+    font_desc_str: &str,
+    wrap_width: Option<i32>,
 ) -> pango::Layout {
     let layout = pangocairo::functions::create_layout(ctx);
     let font_desc = pango::FontDescription::from_string(font_desc_str);
     layout.set_font_description(Some(&font_desc));
-    layout.set_text(text);
+    layout.set_text(&rich.text);
+    if !rich.spans.is_empty() {
+        layout.set_attributes(Some(&pango_attributes(&rich.spans)));
+    }
     if let Some(width) = wrap_width {
         let width = width.max(1);
         let width_pango =
@@ -188,12 +213,13 @@ pub(crate) fn measure_text_cached(
     font_desc_str: &str,
     size: f64,
     wrap_width: Option<i32>,
+    markup: TextMarkup,
 ) -> Option<TextMeasurement> {
     if text.is_empty() {
         return None;
     }
 
-    let key = TextCacheKey::new(text, font_desc_str, size, wrap_width);
+    let key = TextCacheKey::new(text, font_desc_str, size, wrap_width, markup);
 
     // Check cache first
     let cached = TEXT_CACHE.with(|cache| cache.borrow_mut().get(&key));
@@ -203,7 +229,7 @@ pub(crate) fn measure_text_cached(
 
     // Measure using shared context
     let measurement = with_measurement_context(|ctx| {
-        let layout = configured_layout(ctx, text, font_desc_str, wrap_width);
+        let layout = configured_layout(ctx, text, font_desc_str, wrap_width, markup);
 
         let (ink_rect, logical_rect) = layout.extents();
         let scale = pango::SCALE as f64;
@@ -240,11 +266,12 @@ pub(crate) fn measure_text_with_context(
     font_desc_str: &str,
     size: f64,
     wrap_width: Option<i32>,
+    markup: TextMarkup,
 ) -> Option<TextMeasurement> {
     // Delegate to measure_text_cached for consistent measurements.
     // Pango units are resolution-independent, so the measurement context
     // settings (scale, font options) don't affect the results.
-    measure_text_cached(text, font_desc_str, size, wrap_width)
+    measure_text_cached(text, font_desc_str, size, wrap_width, markup)
 }
 
 /// Hit-test a point against a rendered text run, returning the caret byte
@@ -254,6 +281,9 @@ pub(crate) fn measure_text_with_context(
 /// it is correct for wrapped and multiline text. The caret snaps to the
 /// trailing edge of a glyph when the point is on its right half. Returns `None`
 /// only when no measurement context is available.
+///
+/// This and the caret helpers below serve the editor, so they lay `text` out
+/// as markup source: styled, but byte-for-byte the buffer being edited.
 pub(crate) fn hit_test_text(
     text: &str,
     font_desc_str: &str,
@@ -265,7 +295,7 @@ pub(crate) fn hit_test_text(
         return Some(0);
     }
     with_measurement_context(|ctx| {
-        let layout = configured_layout(ctx, text, font_desc_str, wrap_width);
+        let layout = configured_layout(ctx, text, font_desc_str, wrap_width, TextMarkup::Source);
 
         let scale = pango::SCALE as f64;
         // Convert the baseline-relative y into the layout's top-left frame.
@@ -312,7 +342,7 @@ pub(crate) fn caret_on_adjacent_visual_position(
     direction: VisualCaretDirection,
 ) -> Option<usize> {
     with_measurement_context(|ctx| {
-        let layout = configured_layout(ctx, text, font_desc_str, wrap_width);
+        let layout = configured_layout(ctx, text, font_desc_str, wrap_width, TextMarkup::Source);
 
         let index = snap_char_boundary(text, byte_index);
         let old_index = i32::try_from(index).unwrap_or(i32::MAX);
@@ -343,7 +373,7 @@ pub(crate) fn caret_at_visual_selection_edge(
     direction: VisualCaretDirection,
 ) -> Option<usize> {
     with_measurement_context(|ctx| {
-        let layout = configured_layout(ctx, text, font_desc_str, wrap_width);
+        let layout = configured_layout(ctx, text, font_desc_str, wrap_width, TextMarkup::Source);
 
         let start = start.min(text.len());
         let end = end.min(text.len());
@@ -376,7 +406,7 @@ pub(crate) fn caret_on_visual_line_edge(
     edge: VisualLineEdge,
 ) -> Option<usize> {
     with_measurement_context(|ctx| {
-        let layout = configured_layout(ctx, text, font_desc_str, wrap_width);
+        let layout = configured_layout(ctx, text, font_desc_str, wrap_width, TextMarkup::Source);
 
         let index = snap_char_boundary(text, byte_index);
         let (line_index, _) =
@@ -410,7 +440,7 @@ pub(crate) fn caret_on_adjacent_visual_line(
     direction: VisualLineDirection,
 ) -> Option<usize> {
     with_measurement_context(|ctx| {
-        let layout = configured_layout(ctx, text, font_desc_str, wrap_width);
+        let layout = configured_layout(ctx, text, font_desc_str, wrap_width, TextMarkup::Source);
 
         let index = snap_char_boundary(text, byte_index);
         let index_i32 = i32::try_from(index).unwrap_or(i32::MAX);
@@ -469,7 +499,7 @@ pub(crate) fn caret_geometry_text(
     byte_index: usize,
 ) -> Option<CaretGeometry> {
     with_measurement_context(|ctx| {
-        let layout = configured_layout(ctx, text, font_desc_str, wrap_width);
+        let layout = configured_layout(ctx, text, font_desc_str, wrap_width, TextMarkup::Source);
         caret_geometry_in(&layout, text, byte_index)
     })
 }
@@ -508,7 +538,7 @@ pub(crate) fn text_preview_geometry(
     byte_index: Option<usize>,
 ) -> Option<TextPreviewGeometry> {
     with_measurement_context(|ctx| {
-        let layout = configured_layout(ctx, text, font_desc_str, wrap_width);
+        let layout = configured_layout(ctx, text, font_desc_str, wrap_width, TextMarkup::Source);
         TextPreviewGeometry {
             caret: byte_index.map(|byte_index| caret_geometry_in(&layout, text, byte_index)),
             logical: logical_bounds_in(&layout),
@@ -541,6 +571,66 @@ fn logical_bounds_in(layout: &pango::Layout) -> LogicalBounds {
     }
 }
 
+/// One visual line of a laid-out text run: the byte range it covers in the
+/// source text, its horizontal offset, and its baseline measured from the
+/// first-line baseline. Vector exports emit one text run per visual line so
+/// wrapping matches what the canvas painted.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct VisualLine {
+    pub range: std::ops::Range<usize>,
+    pub x: f64,
+    pub baseline_from_first: f64,
+}
+
+/// Split resolved `rich` text into the visual lines Pango lays it out as,
+/// using the same configured layout as measurement and rendering. Ranges index
+/// `rich.text`. Returns `None` only when no measurement context is available.
+pub(crate) fn visual_lines(
+    rich: &RichText,
+    font_desc_str: &str,
+    wrap_width: Option<i32>,
+) -> Option<Vec<VisualLine>> {
+    with_measurement_context(|ctx| {
+        let layout = rich_text_layout(ctx, rich, font_desc_str, wrap_width);
+        let text = rich.text.as_str();
+        let scale = pango::SCALE as f64;
+        let mut iter = layout.iter();
+        let first_baseline = iter.baseline() as f64 / scale;
+        let mut lines = Vec::new();
+        loop {
+            if let Some(line) = iter.line_readonly() {
+                let (_ink, logical) = iter.line_extents();
+                let start = snap_char_boundary(text, line.start_index().max(0) as usize);
+                let end = snap_char_boundary(text, start + line.length().max(0) as usize);
+                lines.push(VisualLine {
+                    range: start..end,
+                    x: logical.x() as f64 / scale,
+                    baseline_from_first: iter.baseline() as f64 / scale - first_baseline,
+                });
+            }
+            if !iter.next_line() {
+                break;
+            }
+        }
+        lines
+    })
+}
+
+/// Pixel size Pango renders a font of `size` points at, so formats that size
+/// text in user units (SVG) match the Cairo canvas.
+pub(crate) fn font_pixel_size(size: f64) -> f64 {
+    with_measurement_context(|ctx| {
+        let layout = pangocairo::functions::create_layout(ctx);
+        let resolution = pangocairo::functions::context_get_resolution(&layout.context());
+        if resolution > 0.0 {
+            size * resolution / 72.0
+        } else {
+            size * 96.0 / 72.0
+        }
+    })
+    .unwrap_or(size * 96.0 / 72.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -621,8 +711,8 @@ mod tests {
         let text = "Hello World";
         let font = "Sans 12";
 
-        let m1 = measure_text_cached(text, font, 12.0, None);
-        let m2 = measure_text_cached(text, font, 12.0, None);
+        let m1 = measure_text_cached(text, font, 12.0, None, TextMarkup::Plain);
+        let m2 = measure_text_cached(text, font, 12.0, None, TextMarkup::Plain);
 
         assert!(m1.is_some());
         assert!(m2.is_some());
@@ -642,15 +732,15 @@ mod tests {
         let text = "Test";
         let font = "Sans";
 
-        let m1 = measure_text_cached(text, font, 12.0, None);
-        let m2 = measure_text_cached(text, font, 24.0, None);
+        let m1 = measure_text_cached(text, font, 12.0, None, TextMarkup::Plain);
+        let m2 = measure_text_cached(text, font, 24.0, None, TextMarkup::Plain);
 
         assert!(m1.is_some(), "12pt measurement should succeed");
         assert!(m2.is_some(), "24pt measurement should succeed");
 
         // Request them again - should hit cache for both
-        let m1_cached = measure_text_cached(text, font, 12.0, None);
-        let m2_cached = measure_text_cached(text, font, 24.0, None);
+        let m1_cached = measure_text_cached(text, font, 12.0, None, TextMarkup::Plain);
+        let m2_cached = measure_text_cached(text, font, 24.0, None, TextMarkup::Plain);
 
         let m1 = m1.unwrap();
         let m1_cached = m1_cached.unwrap();
@@ -669,9 +759,9 @@ mod tests {
     #[test]
     fn test_cache_evicts_oldest_entry_at_capacity() {
         let mut cache = TextMeasurementCache::new(2);
-        let key_a = TextCacheKey::new("A", "Sans", 12.0, None);
-        let key_b = TextCacheKey::new("B", "Sans", 12.0, None);
-        let key_c = TextCacheKey::new("C", "Sans", 12.0, None);
+        let key_a = TextCacheKey::new("A", "Sans", 12.0, None, TextMarkup::Plain);
+        let key_b = TextCacheKey::new("B", "Sans", 12.0, None, TextMarkup::Plain);
+        let key_c = TextCacheKey::new("C", "Sans", 12.0, None, TextMarkup::Plain);
 
         cache.insert(key_a.clone(), measurement(10.0));
         cache.insert(key_b.clone(), measurement(20.0));
@@ -685,9 +775,9 @@ mod tests {
     #[test]
     fn test_get_refreshes_lru_order_before_eviction() {
         let mut cache = TextMeasurementCache::new(2);
-        let key_a = TextCacheKey::new("A", "Sans", 12.0, None);
-        let key_b = TextCacheKey::new("B", "Sans", 12.0, None);
-        let key_c = TextCacheKey::new("C", "Sans", 12.0, None);
+        let key_a = TextCacheKey::new("A", "Sans", 12.0, None, TextMarkup::Plain);
+        let key_b = TextCacheKey::new("B", "Sans", 12.0, None, TextMarkup::Plain);
+        let key_c = TextCacheKey::new("C", "Sans", 12.0, None, TextMarkup::Plain);
 
         cache.insert(key_a.clone(), measurement(10.0));
         cache.insert(key_b.clone(), measurement(20.0));
@@ -702,7 +792,7 @@ mod tests {
     #[test]
     fn test_insert_existing_key_updates_cached_measurement() {
         let mut cache = TextMeasurementCache::new(2);
-        let key = TextCacheKey::new("A", "Sans", 12.0, None);
+        let key = TextCacheKey::new("A", "Sans", 12.0, None, TextMarkup::Plain);
 
         cache.insert(key.clone(), measurement(10.0));
         cache.insert(key.clone(), measurement(42.0));
@@ -713,17 +803,28 @@ mod tests {
 
     #[test]
     fn test_empty_text_returns_none() {
-        let result = measure_text_cached("", "Sans 12", 12.0, None);
+        let result = measure_text_cached("", "Sans 12", 12.0, None, TextMarkup::Plain);
         assert!(result.is_none());
     }
 
+    #[test]
+    fn rendered_markup_hides_its_markers_from_the_measurement() {
+        let source = "**bold** text";
+        let plain = measure_text_cached(source, "Sans 12", 12.0, None, TextMarkup::Plain)
+            .expect("plain measurement");
+        let rendered = measure_text_cached(source, "Sans 12", 12.0, None, TextMarkup::Rendered)
+            .expect("rendered measurement");
+
+        assert!(rendered.logical_width < plain.logical_width);
+    }
+
     #[test]
     fn test_wrap_width_affects_cache_key() {
         let text = "A very long text that would wrap";
         let font = "Sans 12";
 
-        let m1 = measure_text_cached(text, font, 12.0, None);
-        let m2 = measure_text_cached(text, font, 12.0, Some(50));
+        let m1 = measure_text_cached(text, font, 12.0, None, TextMarkup::Plain);
+        let m2 = measure_text_cached(text, font, 12.0, Some(50), TextMarkup::Plain);
 
         assert!(m1.is_some());
         assert!(m2.is_some());
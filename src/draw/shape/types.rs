@@ -2,6 +2,7 @@ use super::bounds::{
     bounding_box_for_arrow, bounding_box_for_blur, bounding_box_for_ellipse,
     bounding_box_for_eraser, bounding_box_for_line, bounding_box_for_points, bounding_box_for_rect,
 };
+use super::line_style::LineStyle;
 use super::polygon::{PolygonKind, bounding_box_for_polygon};
 use super::step_marker::step_marker_bounds;
 use super::text::{bounding_box_for_sticky_note, bounding_box_for_text};
@@ -21,7 +22,7 @@ pub struct EmbeddedImage {
 }
 
 /// Brush options for eraser strokes.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EraserBrush {
     /// Brush diameter in pixels (logical coordinates)
     pub size: f64,
@@ -89,7 +90,7 @@ impl BlurStyle {
 }
 
 /// Label metadata for numbered arrows.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ArrowLabel {
     /// Numeric label value.
     pub value: u32,
@@ -100,7 +101,7 @@ pub struct ArrowLabel {
 }
 
 /// Label metadata for numbered step markers.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct StepMarkerLabel {
     /// Numeric label value.
     pub value: u32,
@@ -114,7 +115,7 @@ pub struct StepMarkerLabel {
 ///
 /// Each variant represents a different drawing tool/primitive with its specific parameters.
 /// All shapes store their own color and size information for independent rendering.
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Shape {
     /// Freehand drawing - polyline connecting mouse drag points
     Freehand {
@@ -124,6 +125,9 @@ pub enum Shape {
         color: Color,
         /// Line thickness in pixels
         thick: f64,
+        /// Dash style of the stroke
+        #[serde(default, skip_serializing_if = "LineStyle::is_solid")]
+        line_style: LineStyle,
     },
     /// Freehand drawing with variable thickness (pressure sensitivity)
     FreehandPressure {
@@ -146,6 +150,9 @@ pub enum Shape {
         color: Color,
         /// Line thickness in pixels
         thick: f64,
+        /// Dash style of the stroke
+        #[serde(default, skip_serializing_if = "LineStyle::is_solid")]
+        line_style: LineStyle,
     },
     /// Rectangle outline (drawn with Ctrl modifier)
     Rect {
@@ -163,6 +170,12 @@ pub enum Shape {
         color: Color,
         /// Border thickness in pixels
         thick: f64,
+        /// Clockwise rotation in degrees about the rectangle's center
+        #[serde(default, skip_serializing_if = "is_unrotated")]
+        rotation: f64,
+        /// Dash style of the border
+        #[serde(default, skip_serializing_if = "LineStyle::is_solid")]
+        line_style: LineStyle,
     },
     /// Ellipse/circle outline (drawn with Tab modifier)
     Ellipse {
@@ -180,6 +193,12 @@ pub enum Shape {
         color: Color,
         /// Border thickness in pixels
         thick: f64,
+        /// Clockwise rotation in degrees about the center
+        #[serde(default, skip_serializing_if = "is_unrotated")]
+        rotation: f64,
+        /// Dash style of the border
+        #[serde(default, skip_serializing_if = "LineStyle::is_solid")]
+        line_style: LineStyle,
     },
     /// Generic closed polygon, including named generated polygons and freeform polygons.
     Polygon {
@@ -193,6 +212,9 @@ pub enum Shape {
         color: Color,
         /// Border thickness in pixels.
         thick: f64,
+        /// Dash style of the border.
+        #[serde(default, skip_serializing_if = "LineStyle::is_solid")]
+        line_style: LineStyle,
     },
     /// Arrow with directional head (drawn with Ctrl+Shift modifiers)
     Arrow {
@@ -218,6 +240,9 @@ pub enum Shape {
         /// Optional label rendered near the arrow.
         #[serde(default, skip_serializing_if = "Option::is_none")]
         label: Option<ArrowLabel>,
+        /// Dash style of the shaft
+        #[serde(default, skip_serializing_if = "LineStyle::is_solid")]
+        line_style: LineStyle,
     },
     /// Rectangular blur region over the captured background.
     BlurRect {
@@ -235,6 +260,9 @@ pub enum Shape {
         /// existed, which deserialize as the historical Gaussian blur.
         #[serde(default)]
         style: BlurStyle,
+        /// Clockwise rotation in degrees about the region's center
+        #[serde(default, skip_serializing_if = "is_unrotated")]
+        rotation: f64,
     },
     /// Region that stays bright while the spotlight pass dims everything else.
     ///
@@ -280,6 +308,9 @@ pub enum Shape {
         /// Optional wrap width in pixels (None = auto)
         #[serde(default)]
         wrap_width: Option<i32>,
+        /// Clockwise rotation in degrees about the center of the text block
+        #[serde(default, skip_serializing_if = "is_unrotated")]
+        rotation: f64,
     },
     /// Sticky note with filled background and drop shadow
     StickyNote {
@@ -327,6 +358,9 @@ pub enum Shape {
         h: i32,
         /// Original encoded image payload and natural dimensions
         data: EmbeddedImage,
+        /// Clockwise rotation in degrees about the image's center
+        #[serde(default, skip_serializing_if = "is_unrotated")]
+        rotation: f64,
     },
 }
 
@@ -336,6 +370,17 @@ impl Shape {
     /// The returned rectangle is suitable for dirty region tracking and damage hints.
     /// Returns `None` only when the shape has no drawable area (e.g., degenerate data).
     pub fn bounding_box(&self) -> Option<Rect> {
+        if !is_unrotated(&self.rotation()) {
+            return self.rotated_bounding_box();
+        }
+        self.local_bounding_box()
+    }
+
+    /// Bounding box of the shape as if its rotation were zero.
+    ///
+    /// Hit-testing and rendering work in this unrotated frame and map points
+    /// through [`Shape::rotation_pivot`] instead.
+    pub(crate) fn local_bounding_box(&self) -> Option<Rect> {
         match self {
             Shape::Freehand { points, thick, .. } => bounding_box_for_points(points, *thick),
             Shape::FreehandPressure { points, .. } => {
@@ -398,6 +443,7 @@ impl Shape {
                 head_at_end,
                 label,
                 color: _,
+                line_style: _,
             } => bounding_box_for_arrow(
                 *x1,
                 *y1,
@@ -474,6 +520,11 @@ const fn default_arrow_head_at_end() -> bool {
     true
 }
 
+/// Serde skip predicate that keeps unrotated shapes in the pre-rotation format.
+pub(super) fn is_unrotated(degrees: &f64) -> bool {
+    *degrees == 0.0
+}
+
 fn normalized_rect(x: i32, y: i32, w: i32, h: i32) -> Option<Rect> {
     let min_x = if w < 0 { x.saturating_add(w) } else { x };
     let min_y = if h < 0 { y.saturating_add(h) } else { y };
@@ -0,0 +1,411 @@
+//! `wayscriber render`: draws a saved session's board to a PNG or PDF file.
+//!
+//! Runs entirely offscreen, so CI and documentation builds can regenerate
+//! images from committed `.wayscriber` session files without a compositor.
+//! Board backgrounds come from `config.toml` the same way the overlay applies
+//! them, and the images go through the same export snapshots and Cairo
+//! renderers as the overlay's own PNG and PDF exports.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::backend::wayland::{
+    BoardPdfExportBuildContext, PdfExportScope, backdrop_from_background,
+    build_board_pdf_export_snapshot,
+};
+use crate::canvas_export::{
+    BoardExportSnapshot, BoardPdfExportSnapshot, CanvasExportSnapshot, CanvasExportViewport,
+    PdfPageMetadata, SpotlightPassSnapshot, render_board_pdf, render_canvas_png,
+};
+use crate::capture::encode::ImageEncoding;
+use crate::config::Config;
+use crate::draw::BoardPages;
+use crate::input::boards::BoardState;
+use crate::input::{BoardBackground, BoardSpec};
+use crate::render_profiles::RenderProfileSet;
+use crate::session::{BoardSnapshot, SessionSnapshot};
+
+/// Logical canvas size used when `--size` is not given.
+pub(crate) const DEFAULT_RENDER_SIZE: (u32, u32) = (1920, 1080);
+
+/// Largest accepted `--size` side; cairo surfaces cannot be wider or taller.
+pub(crate) const MAX_RENDER_SIDE: u32 = 32767;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RenderFormat {
+    Png,
+    Pdf,
+}
+
+impl RenderFormat {
+    pub(crate) fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "png" => Some(Self::Png),
+            "pdf" => Some(Self::Pdf),
+            _ => None,
+        }
+    }
+}
+
+/// One `wayscriber render` invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RenderRequest {
+    pub(crate) session_file: PathBuf,
+    /// Board id; the session's active board when unset.
+    pub(crate) board: Option<String>,
+    /// 1-based page number. PNG renders the board's active page when unset;
+    /// PDF renders every page of the board.
+    pub(crate) page: Option<usize>,
+    pub(crate) format: RenderFormat,
+    pub(crate) out: PathBuf,
+    /// Logical canvas size, the overlay surface size the drawings assume.
+    pub(crate) width: u32,
+    pub(crate) height: u32,
+    /// Output pixels per logical pixel (PNG only).
+    pub(crate) scale: i32,
+}
+
+pub(crate) fn run_render(request: RenderRequest) -> ExitCode {
+    match render(&request) {
+        Ok(()) => {
+            println!("Wrote {}", request.out.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("wayscriber render: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn render(request: &RenderRequest) -> Result<()> {
+    let config = Config::load()?.config;
+    let snapshot = load_session(&config, &request.session_file)?;
+    let bytes = render_snapshot(&config, &snapshot, request)?;
+    std::fs::write(&request.out, bytes)
+        .with_context(|| format!("failed to write {}", request.out.display()))
+}
+
+fn load_session(config: &Config, session_file: &Path) -> Result<SessionSnapshot> {
+    let raw = session_file
+        .to_str()
+        .ok_or_else(|| anyhow!("--session-file path must be valid UTF-8"))?;
+    let path = crate::session::normalize_named_session_file_arg(raw);
+    crate::session::validate_named_session_file_for_info(&path)?;
+    if !path.is_file() {
+        bail!("session file not found: {}", path.display());
+    }
+    let mut options =
+        crate::session::options_from_config_for_named_file(&config.session, path.clone(), None);
+    options.force_resume_persistence();
+    crate::session::load_snapshot(&options)?
+        .ok_or_else(|| anyhow!("session file {} holds no boards", path.display()))
+}
+
+fn render_snapshot(
+    config: &Config,
+    snapshot: &SessionSnapshot,
+    request: &RenderRequest,
+) -> Result<Vec<u8>> {
+    let boards = board_states(config, &snapshot.boards);
+    let board_id = request
+        .board
+        .as_deref()
+        .unwrap_or(&snapshot.active_board_id);
+    let board_index = boards
+        .iter()
+        .position(|board| board.spec.id == board_id)
+        .ok_or_else(|| {
+            let available: Vec<&str> = boards.iter().map(|board| board.spec.id.as_str()).collect();
+            anyhow!(
+                "board '{board_id}' is not in the session (available: {})",
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            )
+        })?;
+    let board = &boards[board_index];
+    let page_count = board.pages.pages().len();
+    let page_index = match request.page {
+        Some(page) if page == 0 || page > page_count => bail!(
+            "board '{board_id}' has {page_count} page{}; page {page} does not exist",
+            if page_count == 1 { "" } else { "s" }
+        ),
+        Some(page) => Some(page - 1),
+        None => None,
+    };
+    let pan_enabled = config.resolved_boards().pan_enabled;
+    let spotlight = SpotlightPassSnapshot {
+        dim_opacity: config.spotlight.dim_opacity,
+        feather: config.spotlight.feather,
+    };
+
+    match request.format {
+        RenderFormat::Png => {
+            let page_index = page_index.unwrap_or_else(|| board.pages.active_index());
+            let frame = &board.pages.pages()[page_index];
+            let (origin_x, origin_y) = if pan_enabled && !board.spec.background.is_transparent() {
+                frame.view_offset()
+            } else {
+                (0, 0)
+            };
+            let image = render_canvas_png(&CanvasExportSnapshot {
+                viewport: CanvasExportViewport {
+                    logical_width: request.width,
+                    logical_height: request.height,
+                    scale: request.scale,
+                    origin_x,
+                    origin_y,
+                },
+                backdrop: backdrop_from_background(&board.spec.background, None),
+                board: BoardExportSnapshot {
+                    frame: frame.clone_without_history(),
+                },
+                render_profile: RenderProfileSet::from_config(&config.render_profiles)
+                    .export_profile(),
+                spotlight,
+            })?;
+            Ok(ImageEncoding::for_format("png", &config.capture)
+                .encode(&image.bytes)?
+                .into_owned())
+        }
+        RenderFormat::Pdf => {
+            let mut document = build_board_pdf_export_snapshot(BoardPdfExportBuildContext {
+                logical_width: request.width,
+                logical_height: request.height,
+                boards: &boards,
+                active_board_index: board_index,
+                pan_enabled,
+                scope: PdfExportScope::ActiveBoard,
+                config: &config.export.pdf,
+                desktop_backdrop: None,
+                spotlight,
+            })?;
+            if let Some(page_index) = page_index {
+                keep_only_page(&mut document, page_index);
+            }
+            Ok(render_board_pdf(&document)?)
+        }
+    }
+}
+
+/// Narrows a board PDF to one page. The page keeps its place on the board,
+/// but it is now the document's only page, so the "page N of M" labels say 1/1.
+fn keep_only_page(document: &mut BoardPdfExportSnapshot, page_index: usize) {
+    let mut page = document.pages.swap_remove(page_index);
+    let metadata = page.metadata;
+    page.metadata = PdfPageMetadata::new(
+        metadata.app_board_index,
+        metadata.app_board_count,
+        metadata.export_board_index,
+        metadata.export_board_count,
+        metadata.board_page_index,
+        metadata.board_page_count,
+        0,
+        1,
+        metadata.board_name,
+        metadata.page_name,
+    );
+    document.pages = vec![page];
+}
+
+/// The session's boards, styled by their `config.toml` entries. Boards the
+/// config no longer lists render on a transparent background.
+fn board_states(config: &Config, boards: &[BoardSnapshot]) -> Vec<BoardState> {
+    let configured = config.resolved_boards();
+    boards
+        .iter()
+        .map(|board| {
+            let spec = configured
+                .items
+                .iter()
+                .find(|item| item.id == board.id)
+                .map(BoardSpec::from_config)
+                .unwrap_or_else(|| BoardSpec {
+                    id: board.id.clone(),
+                    name: board.id.clone(),
+                    background: BoardBackground::Transparent,
+                    default_pen_color: None,
+                    auto_adjust_pen: false,
+                    persist: true,
+                    pinned: false,
+                });
+            BoardState {
+                spec,
+                pages: BoardPages::from_pages(board.pages.pages.clone(), board.pages.active),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draw::{BLACK, Frame, LineStyle, Shape};
+    use crate::session::BoardPagesSnapshot;
+
+    fn page_with_line() -> Frame {
+        let mut frame = Frame::new();
+        frame.add_shape(Shape::Line {
+            x1: 2,
+            y1: 2,
+            x2: 30,
+            y2: 20,
+            color: BLACK,
+            thick: 3.0,
+            line_style: LineStyle::Solid,
+        });
+        frame
+    }
+
+    fn session() -> SessionSnapshot {
+        SessionSnapshot {
+            active_board_id: "whiteboard".to_string(),
+            boards: vec![BoardSnapshot {
+                id: "whiteboard".to_string(),
+                pages: BoardPagesSnapshot {
+                    pages: vec![Frame::new(), page_with_line()],
+                    active: 1,
+                },
+            }],
+            tool_state: None,
+        }
+    }
+
+    fn request(format: RenderFormat) -> RenderRequest {
+        RenderRequest {
+            session_file: PathBuf::from("board.wayscriber"),
+            board: None,
+            page: None,
+            format,
+            out: PathBuf::from("out"),
+            width: 40,
+            height: 30,
+            scale: 2,
+        }
+    }
+
+    #[test]
+    fn png_renders_the_active_page_at_the_requested_size() {
+        let bytes = render_snapshot(&Config::default(), &session(), &request(RenderFormat::Png))
+            .expect("render png");
+        let image =
+            crate::image_decode::decode_rgba(crate::image_decode::EncodedImageFormat::Png, &bytes)
+                .expect("decode png");
+        assert_eq!((image.width, image.height), (80, 60));
+        // The default whiteboard is opaque.
+        assert!(image.rgba.chunks_exact(4).all(|pixel| pixel[3] == 255));
+    }
+
+    #[test]
+    fn pdf_renders_the_whole_board_or_one_page() {
+        let whole = render_snapshot(&Config::default(), &session(), &request(RenderFormat::Pdf))
+            .expect("render pdf");
+        assert!(whole.starts_with(b"%PDF"));
+
+        let single = render_snapshot(
+            &Config::default(),
+            &session(),
+            &RenderRequest {
+                page: Some(2),
+                ..request(RenderFormat::Pdf)
+            },
+        )
+        .expect("render pdf page");
+        assert!(single.starts_with(b"%PDF"));
+        assert!(single.len() < whole.len());
+    }
+
+    #[test]
+    fn one_page_of_a_multi_page_pdf_is_numbered_as_its_own_document() {
+        let mut snapshot = session();
+        snapshot.boards[0].pages.pages.push(page_with_line());
+        let config = Config::default();
+        let boards = board_states(&config, &snapshot.boards);
+        let mut document = build_board_pdf_export_snapshot(BoardPdfExportBuildContext {
+            logical_width: 40,
+            logical_height: 30,
+            boards: &boards,
+            active_board_index: 0,
+            pan_enabled: false,
+            scope: PdfExportScope::ActiveBoard,
+            config: &config.export.pdf,
+            desktop_backdrop: None,
+            spotlight: SpotlightPassSnapshot {
+                dim_opacity: config.spotlight.dim_opacity,
+                feather: config.spotlight.feather,
+            },
+        })
+        .expect("build pdf document");
+        assert_eq!(document.pages.len(), 3);
+
+        keep_only_page(&mut document, 1);
+
+        assert_eq!(document.pages.len(), 1);
+        let metadata = &document.pages[0].metadata;
+        assert_eq!(
+            (
+                metadata.document_page_label.as_str(),
+                metadata.document_page_count_label.as_str()
+            ),
+            ("1", "1")
+        );
+        assert_eq!(
+            (
+                metadata.board_page_label.as_str(),
+                metadata.board_page_count_label.as_str(),
+                metadata.page_name_label.as_str()
+            ),
+            ("2", "3", "Page 2")
+        );
+
+        let mut labelled = Config::default();
+        labelled.export.pdf.labels.enabled = true;
+        let bytes = render_snapshot(
+            &labelled,
+            &snapshot,
+            &RenderRequest {
+                page: Some(2),
+                ..request(RenderFormat::Pdf)
+            },
+        )
+        .expect("render labelled pdf page");
+        assert!(bytes.starts_with(b"%PDF"));
+        assert!(bytes.windows(b"/Count 1".len()).any(|w| w == b"/Count 1"));
+    }
+
+    #[test]
+    fn missing_boards_and_pages_are_reported() {
+        let err = render_snapshot(
+            &Config::default(),
+            &session(),
+            &RenderRequest {
+                board: Some("blackboard".to_string()),
+                ..request(RenderFormat::Png)
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "board 'blackboard' is not in the session (available: whiteboard)"
+        );
+
+        let err = render_snapshot(
+            &Config::default(),
+            &session(),
+            &RenderRequest {
+                page: Some(3),
+                ..request(RenderFormat::Pdf)
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "board 'whiteboard' has 2 pages; page 3 does not exist"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Message catalogs for overlay text.
+//!
+//! Overlay strings — action labels, help sections, the guided tour, toasts,
+//! the command palette, and the GTK toolbar — are written in English in the
+//! source and double as gettext-style message ids. `locales/wayscriber.pot`
+//! is the English catalog listing every id; each bundled `locales/<lang>.po`
+//! translates some or all of them and anything missing falls back to English.
+//! Catalogs are compiled into the binary, so no runtime data files are needed.
+//!
+//! The language comes from `[ui] language`, where `auto` follows the
+//! `LANGUAGE`/`LC_ALL`/`LC_MESSAGES`/`LANG` environment like gettext does.
+
+mod catalog;
+
+#[cfg(test)]
+mod tests;
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+pub(crate) use catalog::Catalog;
+
+/// `[ui] language` value that follows the environment.
+pub(crate) const AUTO_LANGUAGE: &str = "auto";
+
+const ENGLISH: &str = "en";
+/// Bundled translations, by language code.
+const BUNDLED_LOCALES: &[(&str, &str)] = &[("de", include_str!("../locales/de.po"))];
+
+/// Index into [`BUNDLED_LOCALES`] of the active translation; English when
+/// out of range.
+static ACTIVE_LOCALE: AtomicUsize = AtomicUsize::new(usize::MAX);
+static CATALOGS: OnceLock<Vec<Catalog>> = OnceLock::new();
+
+/// Returns the active language's text for `msgid`, or `msgid` itself when it
+/// has no translation.
+pub(crate) fn tr(msgid: &str) -> &str {
+    active_catalog()
+        .and_then(|catalog| catalog.get(msgid))
+        .unwrap_or(msgid)
+}
+
+/// [`tr`] for text that is already owned, such as a toast message built at
+/// runtime. Untranslated text is returned as is, without copying.
+pub(crate) fn tr_string(text: String) -> String {
+    match active_catalog().and_then(|catalog| catalog.get(&text)) {
+        Some(translated) => translated.to_string(),
+        None => text,
+    }
+}
+
+/// Like [`tr`], then replaces each `{name}` placeholder with its value.
+pub(crate) fn tr_args(msgid: &str, args: &[(&str, &str)]) -> String {
+    args.iter()
+        .fold(tr(msgid).to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+/// Selects the catalog for a `[ui] language` value.
+pub(crate) fn set_language(setting: &str) {
+    let candidates = match setting.trim() {
+        "" | AUTO_LANGUAGE => environment_languages(|name| std::env::var(name).ok()),
+        language => vec![language.to_string()],
+    };
+    let index = candidates
+        .iter()
+        .map_while(|language| {
+            // An explicit English preference ends the search.
+            (language_code(language) != ENGLISH).then(|| bundled_index(language))
+        })
+        .flatten()
+        .next();
+    let previous = ACTIVE_LOCALE.swap(index.unwrap_or(usize::MAX), Ordering::Relaxed);
+    if previous != index.unwrap_or(usize::MAX) {
+        log::info!(
+            "UI language: {}",
+            index.map_or(ENGLISH, |index| BUNDLED_LOCALES[index].0)
+        );
+    }
+}
+
+/// Language codes `[ui] language` accepts besides `auto`.
+pub(crate) fn supported_languages() -> impl Iterator<Item = &'static str> {
+    std::iter::once(ENGLISH).chain(BUNDLED_LOCALES.iter().map(|(code, _)| *code))
+}
+
+pub(crate) fn is_supported_language(language: &str) -> bool {
+    let code = language_code(language);
+    supported_languages().any(|supported| supported == code)
+}
+
+fn active_catalog() -> Option<&'static Catalog> {
+    let index = ACTIVE_LOCALE.load(Ordering::Relaxed);
+    (index < BUNDLED_LOCALES.len()).then(|| &catalogs()[index])
+}
+
+fn catalogs() -> &'static [Catalog] {
+    CATALOGS.get_or_init(|| {
+        BUNDLED_LOCALES
+            .iter()
+            .map(|(code, source)| {
+                Catalog::parse(source).unwrap_or_else(|err| {
+                    log::warn!("Ignoring bundled '{code}' translations: {err}");
+                    Catalog::default()
+                })
+            })
+            .collect()
+    })
+}
+
+fn bundled_index(language: &str) -> Option<usize> {
+    let code = language_code(language);
+    BUNDLED_LOCALES
+        .iter()
+        .position(|(bundled, _)| *bundled == code)
+}
+
+/// `de_AT.UTF-8@euro` and `de-AT` both become `de`.
+fn language_code(language: &str) -> String {
+    language
+        .split(['_', '-', '.', '@'])
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// The languages the environment asks for, most preferred first, following
+/// gettext: the first set of `LC_ALL`, `LC_MESSAGES`, and `LANG` is the
+/// locale, and a `LANGUAGE` priority list overrides it unless the locale is
+/// `C`/`POSIX`.
+fn environment_languages(var: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(&var)
+        .find(|value| !value.is_empty());
+    let Some(locale) = locale else {
+        return Vec::new();
+    };
+    if matches!(locale.as_str(), "C" | "POSIX") || locale.starts_with("C.") {
+        return Vec::new();
+    }
+    let mut languages: Vec<String> = var("LANGUAGE")
+        .unwrap_or_default()
+        .split(':')
+        .filter(|language| !language.is_empty())
+        .map(str::to_string)
+        .collect();
+    languages.push(locale);
+    languages
+}
+
+/// The English catalog, which lists every message id.
+#[cfg(test)]
+pub(crate) fn english_catalog() -> Catalog {
+    Catalog::parse(include_str!("../locales/wayscriber.pot")).expect("English catalog parses")
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+/// One parsed `.po`/`.pot` file: English message ids mapped to their
+/// translations.
+///
+/// Only the subset of the gettext format wayscriber ships is understood:
+/// `#` comments, `msgid`/`msgstr` pairs, and the `"..."` continuation lines
+/// that follow either keyword. Entries with an empty `msgstr` are untranslated
+/// and fall back to English.
+#[derive(Debug, Default)]
+pub(crate) struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub(crate) fn parse(source: &str) -> Result<Self, String> {
+        let mut messages = HashMap::new();
+        let mut msgid: Option<String> = None;
+        let mut msgstr: Option<String> = None;
+        let mut finish = |msgid: &mut Option<String>, msgstr: &mut Option<String>| {
+            if let (Some(id), Some(text)) = (msgid.take(), msgstr.take())
+                && !id.is_empty()
+            {
+                messages.insert(id, text);
+            }
+        };
+
+        for (index, line) in source.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(rest) = line.strip_prefix("msgid ") {
+                if msgid.is_some() && msgstr.is_none() {
+                    return Err(format!("line {line_number}: msgid without msgstr"));
+                }
+                finish(&mut msgid, &mut msgstr);
+                msgid = Some(unquote(rest, line_number)?);
+            } else if let Some(rest) = line.strip_prefix("msgstr ") {
+                if msgid.is_none() || msgstr.is_some() {
+                    return Err(format!("line {line_number}: msgstr without msgid"));
+                }
+                msgstr = Some(unquote(rest, line_number)?);
+            } else if line.starts_with('"') {
+                let text = unquote(line, line_number)?;
+                match (msgid.as_mut(), msgstr.as_mut()) {
+                    (_, Some(msgstr)) => msgstr.push_str(&text),
+                    (Some(msgid), None) => msgid.push_str(&text),
+                    (None, None) => {
+                        return Err(format!("line {line_number}: string outside an entry"));
+                    }
+                }
+            } else {
+                return Err(format!("line {line_number}: unsupported line '{line}'"));
+            }
+        }
+        if msgid.is_some() && msgstr.is_none() {
+            return Err("msgid without msgstr at end of file".to_string());
+        }
+        finish(&mut msgid, &mut msgstr);
+        Ok(Self { messages })
+    }
+
+    /// The translation of `msgid`, if this catalog has a non-empty one.
+    pub(crate) fn get(&self, msgid: &str) -> Option<&str> {
+        self.messages
+            .get(msgid)
+            .map(String::as_str)
+            .filter(|text| !text.is_empty())
+    }
+
+    #[cfg(test)]
+    pub(crate) fn contains(&self, msgid: &str) -> bool {
+        self.messages.contains_key(msgid)
+    }
+
+    #[cfg(test)]
+    pub(crate) fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.messages
+            .iter()
+            .map(|(id, text)| (id.as_str(), text.as_str()))
+    }
+}
+
+fn unquote(raw: &str, line_number: usize) -> Result<String, String> {
+    let inner = raw
+        .trim()
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+        .ok_or_else(|| format!("line {line_number}: expected a quoted string"))?;
+    let mut text = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            text.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('t') => text.push('\t'),
+            Some('"') => text.push('"'),
+            Some('\\') => text.push('\\'),
+            other => {
+                return Err(format!(
+                    "line {line_number}: unsupported escape '\\{}'",
+                    other.map(String::from).unwrap_or_default()
+                ));
+            }
+        }
+    }
+    Ok(text)
+}
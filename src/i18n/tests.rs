@@ -0,0 +1,248 @@
+use super::*;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::config::action_meta::{action_meta_iter, english_display_label};
+use crate::input::state::TourStep;
+
+fn placeholders(text: &str) -> BTreeSet<&str> {
+    text.match_indices('{')
+        .filter_map(|(start, _)| {
+            let end = start + text[start..].find('}')?;
+            Some(&text[start..=end])
+        })
+        .collect()
+}
+
+#[test]
+fn catalog_parses_escapes_and_continuation_lines() {
+    let catalog = Catalog::parse(
+        r#"# comment
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+
+msgid "Line one\nLine \"two\""
+msgstr "Zeile eins\n"
+"Zeile \"zwei\""
+
+msgid "Untranslated"
+msgstr ""
+"#,
+    )
+    .expect("catalog parses");
+    assert_eq!(
+        catalog.get("Line one\nLine \"two\""),
+        Some("Zeile eins\nZeile \"zwei\"")
+    );
+    assert_eq!(catalog.get("Untranslated"), None);
+    assert!(catalog.contains("Untranslated"));
+    assert_eq!(catalog.get(""), None);
+}
+
+#[test]
+fn catalog_rejects_malformed_entries() {
+    assert!(Catalog::parse("msgstr \"orphan\"").is_err());
+    assert!(Catalog::parse("msgid \"a\"\nmsgid \"b\"\nmsgstr \"\"").is_err());
+    assert!(Catalog::parse("msgid \"a\"").is_err());
+    assert!(Catalog::parse("msgid unquoted\nmsgstr \"\"").is_err());
+    assert!(Catalog::parse("msgid \"\\x\"\nmsgstr \"\"").is_err());
+    assert!(Catalog::parse("msgctxt \"menu\"").is_err());
+}
+
+#[test]
+fn bundled_translations_only_use_english_ids_and_keep_placeholders() {
+    let english = english_catalog();
+    for (code, source) in BUNDLED_LOCALES {
+        let catalog = Catalog::parse(source)
+            .unwrap_or_else(|err| panic!("'{code}' catalog does not parse: {err}"));
+        for (msgid, msgstr) in catalog.entries() {
+            assert!(
+                english.contains(msgid),
+                "'{code}' translates '{msgid}', which is not in the English catalog"
+            );
+            if !msgstr.is_empty() {
+                assert_eq!(
+                    placeholders(msgid),
+                    placeholders(msgstr),
+                    "'{code}' translation of '{msgid}' changes its placeholders"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn action_labels_are_in_the_english_catalog() {
+    let english = english_catalog();
+    for meta in action_meta_iter() {
+        for msgid in [
+            meta.label,
+            meta.short_label(),
+            meta.description,
+            english_display_label(meta.action),
+        ] {
+            assert!(
+                english.contains(msgid),
+                "{:?}: '{msgid}' missing from the English catalog",
+                meta.action
+            );
+        }
+    }
+}
+
+#[test]
+fn tour_titles_and_hints_are_in_the_english_catalog() {
+    let english = english_catalog();
+    let steps = (0..TourStep::COUNT).map(|index| TourStep::from_index(index).expect("tour step"));
+    for step in steps {
+        assert!(english.contains(step.title()), "{step:?} title");
+        assert!(english.contains(step.nav_hint()), "{step:?} nav hint");
+    }
+}
+
+/// Non-test Rust sources under `src/`, without a trailing inline test module.
+fn translatable_sources() -> Vec<(std::path::PathBuf, String)> {
+    let mut sources = Vec::new();
+    let mut stack = vec![Path::new(env!("CARGO_MANIFEST_DIR")).join("src")];
+    while let Some(dir) = stack.pop() {
+        for entry in std::fs::read_dir(&dir).expect("read src dir") {
+            let path = entry.expect("dir entry").path();
+            if path.is_dir() {
+                stack.push(path);
+                continue;
+            }
+            if path.extension().and_then(|e| e.to_str()) != Some("rs")
+                || path.file_name().and_then(|name| name.to_str()) == Some("tests.rs")
+                || path.components().any(|part| part.as_os_str() == "tests")
+            {
+                continue;
+            }
+            let contents = std::fs::read_to_string(&path).expect("read source file");
+            let contents = contents
+                .split("#[cfg(test)]\nmod tests {")
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            sources.push((path, contents));
+        }
+    }
+    sources
+}
+
+/// Every literal handed to `tr`/`tr_args` or to a toast constructor must have
+/// an English catalog entry; run `tools/update-pot.py` after adding text.
+#[test]
+fn translated_source_literals_are_in_the_english_catalog() {
+    let english = english_catalog();
+    let calls = [
+        "tr(\"",
+        "tr_args(\"",
+        "Toast::info(\"",
+        "Toast::warning(\"",
+        "Toast::error(\"",
+    ];
+
+    let mut missing = Vec::new();
+    for (path, contents) in translatable_sources() {
+        for call in calls {
+            for (start, _) in contents.match_indices(call) {
+                let preceding = contents[..start].chars().next_back();
+                if preceding.is_some_and(|ch| ch.is_alphanumeric() || ch == '_') {
+                    continue;
+                }
+                let literal = &contents[start + call.len()..];
+                let Some(end) = literal.find('"') else {
+                    continue;
+                };
+                let literal = &literal[..end];
+                // Escaped literals are left to the extractor.
+                if literal.contains('\\') || literal.is_empty() {
+                    continue;
+                }
+                if !english.contains(literal) {
+                    missing.push(format!("{}: {literal}", path.display()));
+                }
+            }
+        }
+    }
+    assert!(
+        missing.is_empty(),
+        "messages missing from locales/wayscriber.pot (run tools/update-pot.py):\n{}",
+        missing.join("\n")
+    );
+}
+
+/// A toast translates its message by looking the whole text up, so text that
+/// was formatted first never matches a catalog id. Parameterized toasts pass a
+/// `{placeholder}` id to `tr_args` instead.
+#[test]
+fn toast_messages_are_not_formatted_before_translation() {
+    let mut formatted = Vec::new();
+    for (path, contents) in translatable_sources() {
+        for call in ["Toast::info(", "Toast::warning(", "Toast::error("] {
+            for (start, _) in contents.match_indices(call) {
+                let argument = contents[start + call.len()..].trim_start();
+                if argument.starts_with("format!") {
+                    let line = contents[..start].lines().count();
+                    formatted.push(format!("{}:{line}", path.display()));
+                }
+            }
+        }
+    }
+    assert!(
+        formatted.is_empty(),
+        "toasts built from format! cannot be translated; use tr_args:\n{}",
+        formatted.join("\n")
+    );
+}
+
+#[test]
+fn english_text_is_returned_unchanged_without_a_translation() {
+    assert_eq!(tr("not a catalog message"), "not a catalog message");
+    assert_eq!(
+        tr_args("{count} of {total}", &[("count", "2"), ("total", "5")]),
+        "2 of 5"
+    );
+    assert_eq!(tr_string("owned text".to_string()), "owned text");
+}
+
+#[test]
+fn environment_languages_follow_gettext_precedence() {
+    let env = |pairs: &'static [(&'static str, &'static str)]| {
+        move |name: &str| {
+            pairs
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    };
+
+    assert_eq!(
+        environment_languages(env(&[("LANG", "de_DE.UTF-8")])),
+        vec!["de_DE.UTF-8"]
+    );
+    assert_eq!(
+        environment_languages(env(&[("LC_ALL", "fr_FR.UTF-8"), ("LANG", "de_DE.UTF-8")])),
+        vec!["fr_FR.UTF-8"]
+    );
+    assert_eq!(
+        environment_languages(env(&[
+            ("LANGUAGE", "pt_BR:de"),
+            ("LC_MESSAGES", "en_US.UTF-8")
+        ])),
+        vec!["pt_BR", "de", "en_US.UTF-8"]
+    );
+    assert!(environment_languages(env(&[("LANGUAGE", "de"), ("LANG", "C.UTF-8")])).is_empty());
+    assert!(environment_languages(env(&[("LANGUAGE", "de")])).is_empty());
+}
+
+#[test]
+fn language_codes_ignore_region_encoding_and_modifier() {
+    assert_eq!(language_code("de_AT.UTF-8@euro"), "de");
+    assert_eq!(language_code("DE-at"), "de");
+    assert!(is_supported_language("de_CH"));
+    assert!(is_supported_language("en_GB"));
+    assert!(!is_supported_language("xx"));
+    assert_eq!(bundled_index("de_DE.UTF-8"), Some(0));
+}
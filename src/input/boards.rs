@@ -7,6 +7,7 @@ mod operations;
 #[cfg(test)]
 mod tests;
 
+use crate::config::BoardGridConfig;
 use crate::draw::BoardPages;
 use std::collections::BTreeMap;
 
@@ -61,6 +62,9 @@ pub struct BoardManager {
     show_pan_badge: bool,
     persist_customizations: bool,
     default_board_id: String,
+    grid: BoardGridConfig,
+    /// Runtime show/hide state of the grid; starts from `grid.style`.
+    grid_visible: bool,
     template: BoardSpec,
     identity_generation: BoardIdentityGeneration,
 }
@@ -78,6 +82,8 @@ impl Clone for BoardManager {
             show_pan_badge: self.show_pan_badge,
             persist_customizations: self.persist_customizations,
             default_board_id: self.default_board_id.clone(),
+            grid: self.grid.clone(),
+            grid_visible: self.grid_visible,
             template: self.template.clone(),
             identity_generation: BoardIdentityGeneration::fresh(),
         }
@@ -97,6 +103,8 @@ impl BoardManager {
             show_pan_badge: self.show_pan_badge,
             persist_customizations: self.persist_customizations,
             default_board_id: self.default_board_id.clone(),
+            grid: self.grid.clone(),
+            grid_visible: self.grid_visible,
             template: self.template.clone(),
             identity_generation: self.identity_generation,
         }
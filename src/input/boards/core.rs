@@ -2,6 +2,7 @@ use super::{
     BOARD_ID_TRANSPARENT, BoardBackground, BoardIdentityGeneration, BoardManager, BoardSpec,
     BoardState,
 };
+use crate::config::{BoardGridConfig, BoardGridStyle};
 use crate::draw::{BoardPages, Frame, PageDeleteOutcome as CanvasPageDeleteOutcome};
 use std::collections::HashSet;
 
@@ -102,6 +103,36 @@ impl BoardManager {
         self.show_pan_badge
     }
 
+    pub fn grid_config(&self) -> &BoardGridConfig {
+        &self.grid
+    }
+
+    /// Style the grid is drawn with right now; `Off` while it is hidden. A
+    /// grid configured `off` and then shown draws as lines.
+    pub fn grid_style(&self) -> BoardGridStyle {
+        match (self.grid_visible, self.grid.style) {
+            (false, _) => BoardGridStyle::Off,
+            (true, BoardGridStyle::Off) => BoardGridStyle::Lines,
+            (true, style) => style,
+        }
+    }
+
+    pub fn grid_snap(&self) -> bool {
+        self.grid.snap
+    }
+
+    /// Shows or hides the grid; returns whether it is now shown.
+    pub fn toggle_grid(&mut self) -> bool {
+        self.grid_visible = !self.grid_visible;
+        self.grid_visible
+    }
+
+    /// Turns grid snapping on or off; returns whether it is now on.
+    pub fn toggle_grid_snap(&mut self) -> bool {
+        self.grid.snap = !self.grid.snap;
+        self.grid.snap
+    }
+
     pub fn max_count(&self) -> usize {
         self.max_count
     }
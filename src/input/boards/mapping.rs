@@ -3,7 +3,9 @@ use super::{
     BoardManager, BoardSpec, BoardState, board_color_from_config, board_color_to_config,
     runtime_contrast_pen_color,
 };
-use crate::config::{BoardBackgroundConfig, BoardItemConfig, BoardsConfig};
+use crate::config::{
+    BoardBackgroundConfig, BoardGridConfig, BoardGridStyle, BoardItemConfig, BoardsConfig,
+};
 use crate::domain::Color;
 use std::collections::BTreeSet;
 
@@ -97,6 +99,8 @@ impl BoardManager {
             show_pan_badge: config.show_pan_badge,
             persist_customizations: config.persist_customizations,
             default_board_id: config.default_board,
+            grid_visible: config.grid.style != BoardGridStyle::Off,
+            grid: config.grid,
             template,
             identity_generation: BoardIdentityGeneration::fresh(),
         }
@@ -111,6 +115,11 @@ impl BoardManager {
             show_pan_badge: self.show_pan_badge,
             persist_customizations: self.persist_customizations,
             default_board: self.default_board_id.clone(),
+            grid: BoardGridConfig {
+                style: self.grid_style(),
+                snap: self.grid_snap(),
+                ..self.grid.clone()
+            },
             items: self
                 .boards
                 .iter()
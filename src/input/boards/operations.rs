@@ -183,6 +183,10 @@ pub enum PageRestorePlacement {
 
 #[derive(Clone, Debug)]
 #[allow(dead_code)]
+#[allow(
+    clippy::large_enum_variant,
+    reason = "a rejected restore hands the page back without extra indirection"
+)]
 pub enum PageRestoreOutcome {
     Restored {
         board_id: String,
@@ -25,6 +25,8 @@ pub fn compute_hit_bounds(shape: &DrawnShape, tolerance: f64) -> Option<Rect> {
 
 /// Returns `true` if the point intersects the provided shape within tolerance.
 pub fn hit_test(shape: &DrawnShape, point: (i32, i32), tolerance: f64) -> bool {
+    // Rotated shapes are tested in their own upright frame.
+    let point = shape.shape.unrotate_point(point);
     match &shape.shape {
         Shape::Freehand { points, thick, .. } => {
             shapes::freehand_hit(points, point, *thick, tolerance)
@@ -109,7 +111,7 @@ pub fn hit_test(shape: &DrawnShape, point: (i32, i32), tolerance: f64) -> bool {
         }
         Shape::BlurRect { .. } => {
             let inflate = tolerance.ceil() as i32;
-            if let Some(bounds) = shape.bounding_box() {
+            if let Some(bounds) = shape.shape.local_bounding_box() {
                 bounds
                     .inflated(inflate)
                     .unwrap_or(bounds)
@@ -119,7 +121,7 @@ pub fn hit_test(shape: &DrawnShape, point: (i32, i32), tolerance: f64) -> bool {
             }
         }
         Shape::Text { .. } | Shape::StickyNote { .. } | Shape::Image { .. } => {
-            if let Some(bounds) = shape.bounding_box() {
+            if let Some(bounds) = shape.shape.local_bounding_box() {
                 let inflate = tolerance.ceil() as i32;
                 bounds
                     .inflated(inflate)
@@ -151,6 +153,7 @@ pub fn hit_test_for_point_targeting(shape: &DrawnShape, point: (i32, i32), toler
         return true;
     }
 
+    let point = shape.shape.unrotate_point(point);
     match &shape.shape {
         Shape::Rect {
             x,
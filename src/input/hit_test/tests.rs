@@ -1,7 +1,7 @@
 use super::*;
 use crate::draw::{
     ArrowLabel, BLACK, DrawnShape, EmbeddedImage, EraserBrush, EraserKind, FontDescriptor,
-    PolygonKind, Shape, StepMarkerLabel,
+    LineStyle, PolygonKind, Shape, StepMarkerLabel,
 };
 
 #[test]
@@ -16,6 +16,8 @@ fn compute_hit_bounds_inflates_bounds_for_tolerance() {
             fill: false,
             color: BLACK,
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         },
         0,
         false,
@@ -67,6 +69,8 @@ fn rect_hit_handles_degenerate_dimensions() {
             fill: false,
             color: BLACK,
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         },
         0,
         false,
@@ -88,6 +92,8 @@ fn ellipse_hit_handles_zero_radius() {
             fill: false,
             color: BLACK,
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         },
         0,
         false,
@@ -107,6 +113,7 @@ fn polygon_hit_tests_closed_outline_only() {
             fill: true,
             color: BLACK,
             thick: 2.0,
+            line_style: LineStyle::Solid,
         },
         0,
         false,
@@ -119,6 +126,31 @@ fn polygon_hit_tests_closed_outline_only() {
     );
 }
 
+#[test]
+fn rotated_rect_hit_follows_turned_outline() {
+    let rect = DrawnShape::with_metadata(
+        5,
+        Shape::Rect {
+            x: 0,
+            y: 0,
+            w: 100,
+            h: 20,
+            fill: false,
+            color: BLACK,
+            thick: 2.0,
+            rotation: 90.0,
+            line_style: LineStyle::Solid,
+        },
+        0,
+        false,
+    );
+
+    // The top edge turns onto the vertical line x = 60 about the center (50, 10).
+    assert!(hit_test(&rect, (60, 10), 1.0));
+    assert!(hit_test(&rect, (60, -30), 1.0));
+    assert!(!hit_test(&rect, (95, 0), 1.0));
+}
+
 #[test]
 fn point_targeting_hits_filled_rect_and_ellipse_interiors() {
     let rect = DrawnShape::with_metadata(
@@ -131,6 +163,8 @@ fn point_targeting_hits_filled_rect_and_ellipse_interiors() {
             fill: true,
             color: BLACK,
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         },
         0,
         false,
@@ -145,6 +179,8 @@ fn point_targeting_hits_filled_rect_and_ellipse_interiors() {
             fill: true,
             color: BLACK,
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         },
         0,
         false,
@@ -166,6 +202,7 @@ fn point_targeting_hits_filled_polygon_interior() {
             fill: true,
             color: BLACK,
             thick: 2.0,
+            line_style: LineStyle::Solid,
         },
         0,
         false,
@@ -187,6 +224,7 @@ fn invalid_polygon_hit_test_is_false() {
             fill: false,
             color: BLACK,
             thick: 2.0,
+            line_style: LineStyle::Solid,
         },
         0,
         false,
@@ -233,6 +271,7 @@ fn arrow_label_hit_detects_label_bounds() {
             arrow_angle: 30.0,
             head_at_end: true,
             label: Some(label),
+            line_style: LineStyle::Solid,
         },
         0,
         false,
@@ -309,6 +348,7 @@ fn image_hit_test_uses_display_rectangle() {
                 height: 3,
                 bytes: vec![1, 2, 3],
             },
+            rotation: 0.0,
         },
         0,
         false,
@@ -25,8 +25,9 @@ pub use state::{
     BoardPickerCursorHint, ClickHighlightSettings, ColorPickerCursorHint, CommandPaletteCursorHint,
     ContextMenuCursorHint, DrawingState, EyedropperUiState, HelpOverlayClick,
     HelpOverlayCursorHint, HelpOverlayReleaseOutcome, InputHudActiveSource, InputHudEntry,
-    InputHudEntryKind, InputHudSettings, InputState, OcrInputSource, OcrUiState, OutputFocusAction,
-    SelectionHandle, SelectionPropertyEntry, SelectionPropertyKind, TextInputMode, ZoomAction,
+    InputHudEntryKind, InputHudSettings, InputState, LaserSettings, MagnifierSettings,
+    OcrInputSource, OcrUiState, OutputFocusAction, SelectionHandle, SelectionPropertyEntry,
+    SelectionPropertyKind, TextInputMode, TimerKind, TimerSettings, TimerView, ZoomAction,
 };
 #[cfg(feature = "tablet-input")]
 #[allow(unused_imports)]
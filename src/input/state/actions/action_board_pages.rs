@@ -75,6 +75,14 @@ impl InputState {
                 self.restore_deleted_page();
                 true
             }
+            Action::ToggleGrid => {
+                self.toggle_board_grid();
+                true
+            }
+            Action::ToggleSnapToGrid => {
+                self.toggle_grid_snap();
+                true
+            }
             Action::Board1 => {
                 self.switch_board_slot(0);
                 true
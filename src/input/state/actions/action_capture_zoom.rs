@@ -46,6 +46,27 @@ impl InputState {
                 self.reset_modifiers();
                 true
             }
+            Action::ExportCanvasSvgFile | Action::ExportBoardSvgFile => {
+                log::debug!("SVG export action {:?} pending for backend", action);
+                self.set_pending_backend_action(PendingBackendAction::SvgExport(action));
+
+                // Clear modifiers to prevent them from being "stuck" after capture
+                // (portal dialog causes key releases to be missed or focus to flicker)
+                self.reset_modifiers();
+                true
+            }
+            Action::ExportReplayFrames => {
+                log::debug!("Replay frame export pending for backend");
+                self.set_pending_backend_action(PendingBackendAction::ReplayFrameExport);
+                self.reset_modifiers();
+                true
+            }
+            Action::ToggleRecording => {
+                log::debug!("Recording toggle pending for backend");
+                self.set_pending_backend_action(PendingBackendAction::ToggleRecording);
+                self.reset_modifiers();
+                true
+            }
             Action::CopyTextFromScreen => {
                 // The backend owns capture ownership and the region selector,
                 // so this only records the intent. It selects no tool and
@@ -82,6 +103,12 @@ impl InputState {
                 self.request_zoom_action(ZoomAction::RefreshCapture);
                 true
             }
+            Action::ToggleMagnifier => {
+                self.toggle_magnifier();
+                true
+            }
+            Action::MagnifierZoomIn => self.step_magnifier_zoom(true),
+            Action::MagnifierZoomOut => self.step_magnifier_zoom(false),
             Action::FocusNextOutput => {
                 self.request_output_focus_action(OutputFocusAction::Next);
                 self.reset_modifiers();
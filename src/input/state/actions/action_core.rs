@@ -1,5 +1,6 @@
 use super::super::{DrawingState, InputState, TextInputMode};
 use crate::domain::Action;
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastPriority};
 use log::info;
 
@@ -55,7 +56,7 @@ impl InputState {
                     let mut has_locked = false;
                     let mut has_unlocked = false;
                     for shape in &frame.shapes {
-                        if shape.locked {
+                        if shape.locked || !frame.is_shape_editable(shape) {
                             has_locked = true;
                         } else {
                             has_unlocked = true;
@@ -87,6 +88,25 @@ impl InputState {
                 }
                 true
             }
+            Action::ClearActiveLayer => {
+                if self.clear_active_layer() {
+                    info!("Cleared the active layer");
+                } else {
+                    let frame = self.boards.active_frame();
+                    let name = frame.active_layer().name.clone();
+                    if !frame.shapes_on_layer(frame.active_layer_id()).is_empty() {
+                        self.push_toast(
+                            ToastPriority::Info,
+                            "core",
+                            Toast::warning(tr_args(
+                                "Every shape on {name} is locked.",
+                                &[("name", &name)],
+                            )),
+                        );
+                    }
+                }
+                true
+            }
             _ => false,
         }
     }
@@ -2,7 +2,7 @@ use crate::domain::Action;
 use crate::input::state::{Toast, ToastPriority};
 use log::info;
 
-use super::super::{InputState, SelectionAxis};
+use super::super::{InputState, SelectionAlignment, SelectionAxis};
 
 const KEYBOARD_NUDGE_SMALL: i32 = 8;
 const KEYBOARD_NUDGE_LARGE: i32 = 32;
@@ -36,11 +36,11 @@ impl InputState {
             }
             Action::SelectAll => {
                 let previous_bounds = self.selection_bounding_box(self.selected_shape_ids());
-                let ids: Vec<_> = self
-                    .boards
-                    .active_frame()
+                let frame = self.boards.active_frame();
+                let ids: Vec<_> = frame
                     .shapes
                     .iter()
+                    .filter(|shape| frame.is_shape_editable(shape))
                     .map(|shape| shape.id)
                     .collect();
                 if ids.is_empty() {
@@ -76,6 +76,36 @@ impl InputState {
                 }
                 true
             }
+            Action::MoveSelectionToLayerAbove => {
+                if self.move_selection_to_layer(true) {
+                    info!("Moved selection to the layer above");
+                }
+                true
+            }
+            Action::MoveSelectionToLayerBelow => {
+                if self.move_selection_to_layer(false) {
+                    info!("Moved selection to the layer below");
+                }
+                true
+            }
+            Action::GroupSelection => {
+                if self.group_selection() {
+                    info!("Grouped {} shape(s)", self.selected_shape_ids().len());
+                } else if self.has_selection() {
+                    self.push_toast(
+                        ToastPriority::Info,
+                        "selection",
+                        Toast::warning("Select at least two shapes to group."),
+                    );
+                }
+                true
+            }
+            Action::UngroupSelection => {
+                if self.ungroup_selection() {
+                    info!("Ungrouped selection");
+                }
+                true
+            }
             Action::NudgeSelectionUp => {
                 let step = if self.modifiers.shift {
                     KEYBOARD_NUDGE_LARGE
@@ -174,6 +204,54 @@ impl InputState {
                 }
                 true
             }
+            Action::AlignSelectionLeft => {
+                if self.align_selection(SelectionAlignment::Left) {
+                    info!("Aligned selection left");
+                }
+                true
+            }
+            Action::AlignSelectionCenter => {
+                if self.align_selection(SelectionAlignment::Center) {
+                    info!("Aligned selection centers");
+                }
+                true
+            }
+            Action::AlignSelectionRight => {
+                if self.align_selection(SelectionAlignment::Right) {
+                    info!("Aligned selection right");
+                }
+                true
+            }
+            Action::AlignSelectionTop => {
+                if self.align_selection(SelectionAlignment::Top) {
+                    info!("Aligned selection top");
+                }
+                true
+            }
+            Action::AlignSelectionMiddle => {
+                if self.align_selection(SelectionAlignment::Middle) {
+                    info!("Aligned selection middles");
+                }
+                true
+            }
+            Action::AlignSelectionBottom => {
+                if self.align_selection(SelectionAlignment::Bottom) {
+                    info!("Aligned selection bottom");
+                }
+                true
+            }
+            Action::DistributeSelectionHorizontally => {
+                if self.distribute_selection(true) {
+                    info!("Distributed selection horizontally");
+                }
+                true
+            }
+            Action::DistributeSelectionVertically => {
+                if self.distribute_selection(false) {
+                    info!("Distributed selection vertically");
+                }
+                true
+            }
             Action::DeleteSelection => {
                 if self.delete_selection() {
                     info!("Deleted selection");
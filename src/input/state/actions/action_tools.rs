@@ -1,4 +1,5 @@
 use crate::domain::Action;
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastPriority};
 use crate::input::tool::Tool;
 use log::info;
@@ -52,7 +53,18 @@ impl InputState {
                     self.push_toast(
                         ToastPriority::Info,
                         "blur-style",
-                        Toast::info(format!("Blur style: {label}")),
+                        Toast::info(tr_args("Blur style: {label}", &[("label", label)])),
+                    );
+                }
+            }
+            Action::CycleLineStyle => {
+                if self.cycle_line_style() {
+                    let label = self.line_style.label();
+                    info!("Line style set to {label}");
+                    self.push_toast(
+                        ToastPriority::Info,
+                        "line-style",
+                        Toast::info(tr_args("Line style: {label}", &[("label", label)])),
                     );
                 }
             }
@@ -78,6 +90,18 @@ impl InputState {
                     info!("Fill {}", if enable { "enabled" } else { "disabled" });
                 }
             }
+            Action::ToggleConnectorMode => {
+                let enable = !self.connector_mode;
+                if self.set_connector_mode(enable) {
+                    let message = if enable {
+                        "Connector mode on: lines and arrows attach to shapes"
+                    } else {
+                        "Connector mode off"
+                    };
+                    info!("{message}");
+                    self.push_toast(ToastPriority::Info, "connector-mode", Toast::info(message));
+                }
+            }
             Action::ToggleHighlightTool => {
                 let previous_enabled = self.click_highlight_enabled();
                 let previous_tool_ring = self.highlight_tool_ring_enabled();
@@ -93,6 +117,10 @@ impl InputState {
                 };
                 info!("{}", message);
             }
+            Action::ToggleFadeInk => {
+                let enable = self.toggle_fade_ink();
+                info!("Fade ink {}", if enable { "enabled" } else { "disabled" });
+            }
             _ => return false,
         }
 
@@ -3,7 +3,8 @@ use crate::configurator_destination::{
     quick_colors_destination,
 };
 use crate::domain::Action;
-use crate::input::state::{Toast, ToastPriority};
+use crate::i18n::tr_args;
+use crate::input::state::{TimerKind, Toast, ToastPriority};
 use log::info;
 
 use super::super::{DrawingState, InputState, PendingBackendAction, PendingToolbarPersistence};
@@ -190,11 +191,12 @@ impl InputState {
                     crate::config::TopDisplayMode::Hidden => {
                         // The hidden rung teaches its own way back: another
                         // cycle press always lands on Full.
-                        let label =
-                            match self.action_binding_primary_label(Action::CycleToolbarDisplay) {
-                                Some(binding) => format!("Show ({binding})"),
-                                None => "Show".to_string(),
-                            };
+                        let label = match self
+                            .action_binding_primary_label(Action::CycleToolbarDisplay)
+                        {
+                            Some(binding) => tr_args("Show ({binding})", &[("binding", &binding)]),
+                            None => "Show".to_string(),
+                        };
                         Toast::info("Toolbar: hidden").action(label, Action::CycleToolbarDisplay)
                     }
                 };
@@ -218,6 +220,38 @@ impl InputState {
                 );
                 true
             }
+            Action::ToggleReplay => {
+                self.toggle_replay();
+                true
+            }
+            Action::StartBreakTimer => {
+                self.start_timer(TimerKind::Break);
+                true
+            }
+            Action::StartTalkTimer => {
+                self.start_timer(TimerKind::Talk);
+                true
+            }
+            Action::StartStopwatch => {
+                self.start_timer(TimerKind::Stopwatch);
+                true
+            }
+            Action::ToggleTimerPause => {
+                self.toggle_timer_pause();
+                true
+            }
+            Action::ResetTimer => {
+                self.reset_timer();
+                true
+            }
+            Action::TimerAddMinute => {
+                self.add_timer_minute();
+                true
+            }
+            Action::ToggleTimerDisplay => {
+                self.toggle_timer_display();
+                true
+            }
             Action::ToggleLightMode => {
                 let enabled = self.toggle_light_mode();
                 info!(
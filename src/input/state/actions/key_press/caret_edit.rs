@@ -474,6 +474,65 @@ pub(in crate::input::state) fn select_all(
     true
 }
 
+/// Toggle an inline markup marker (`**` for Ctrl+B, `*` for Ctrl+I) around
+/// the selection. Markers already hugging the selection are removed; otherwise
+/// the selection is wrapped and stays selected inside them. With no selection
+/// an empty pair is inserted with the caret between, or removed again when the
+/// caret already sits in one. Returns whether the buffer changed.
+pub(in crate::input::state) fn toggle_inline_marker(
+    buffer: &mut String,
+    caret: &mut usize,
+    anchor: &mut Option<usize>,
+    marker: &str,
+    max_len: usize,
+) -> bool {
+    let range = selection_range(*caret, *anchor).unwrap_or(*caret..*caret);
+    let caret_at_end = *caret == range.end;
+    let width = marker.len();
+    let (start, end) = if is_wrapped_in(&buffer[..range.start], &buffer[range.end..], marker) {
+        buffer.replace_range(range.end..range.end + width, "");
+        buffer.replace_range(range.start - width..range.start, "");
+        (range.start - width, range.end - width)
+    } else {
+        if buffer.len() + width * 2 > max_len {
+            return false;
+        }
+        buffer.insert_str(range.end, marker);
+        buffer.insert_str(range.start, marker);
+        (range.start + width, range.end + width)
+    };
+    if start == end {
+        *caret = start;
+        *anchor = None;
+    } else if caret_at_end {
+        *anchor = Some(start);
+        *caret = end;
+    } else {
+        *anchor = Some(end);
+        *caret = start;
+    }
+    true
+}
+
+/// Whether `marker` closes around the text between `before` and `after`.
+/// Star runs are counted so italic and bold toggle independently inside
+/// `***both***`: an odd run carries an italic star, a run of two or more a
+/// bold pair.
+fn is_wrapped_in(before: &str, after: &str, marker: &str) -> bool {
+    let leading = before
+        .bytes()
+        .rev()
+        .take_while(|byte| *byte == b'*')
+        .count();
+    let trailing = after.bytes().take_while(|byte| *byte == b'*').count();
+    let run = leading.min(trailing);
+    match marker {
+        "*" => run % 2 == 1,
+        "**" => run >= 2,
+        _ => before.ends_with(marker) && after.starts_with(marker),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -689,4 +748,85 @@ mod tests {
         clamp(buf, &mut caret, &mut anchor);
         assert_eq!((caret, anchor), (1, Some(1)));
     }
+
+    #[test]
+    fn toggle_marker_wraps_and_keeps_the_selection_inside() {
+        let mut e = Ed::sel("make this bold", 5, 9); // "this"
+        assert!(toggle_inline_marker(
+            &mut e.buf,
+            &mut e.caret,
+            &mut e.anchor,
+            "**",
+            100
+        ));
+        assert_eq!(e.buf, "make **this** bold");
+        assert_eq!((e.anchor, e.caret), (Some(7), 11));
+
+        assert!(toggle_inline_marker(
+            &mut e.buf,
+            &mut e.caret,
+            &mut e.anchor,
+            "**",
+            100
+        ));
+        assert_eq!(e.buf, "make this bold");
+        assert_eq!((e.anchor, e.caret), (Some(5), 9));
+    }
+
+    #[test]
+    fn toggle_marker_without_selection_inserts_and_removes_an_empty_pair() {
+        let mut e = Ed::at("ab", 1);
+        assert!(toggle_inline_marker(
+            &mut e.buf,
+            &mut e.caret,
+            &mut e.anchor,
+            "*",
+            100
+        ));
+        assert_eq!((e.buf.as_str(), e.caret), ("a**b", 2));
+
+        assert!(toggle_inline_marker(
+            &mut e.buf,
+            &mut e.caret,
+            &mut e.anchor,
+            "*",
+            100
+        ));
+        assert_eq!((e.buf.as_str(), e.caret), ("ab", 1));
+    }
+
+    #[test]
+    fn italic_toggle_inside_bold_adds_its_own_star() {
+        let mut e = Ed::sel("**x**", 2, 3);
+        assert!(toggle_inline_marker(
+            &mut e.buf,
+            &mut e.caret,
+            &mut e.anchor,
+            "*",
+            100
+        ));
+        assert_eq!(e.buf, "***x***");
+
+        assert!(toggle_inline_marker(
+            &mut e.buf,
+            &mut e.caret,
+            &mut e.anchor,
+            "**",
+            100
+        ));
+        assert_eq!(e.buf, "*x*");
+    }
+
+    #[test]
+    fn toggle_marker_respects_max_len() {
+        let mut e = Ed::sel("abc", 0, 3);
+        assert!(!toggle_inline_marker(
+            &mut e.buf,
+            &mut e.caret,
+            &mut e.anchor,
+            "**",
+            5
+        ));
+        assert_eq!(e.buf, "abc");
+    }
 }
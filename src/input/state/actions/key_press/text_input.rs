@@ -106,6 +106,7 @@ impl InputState {
                     font_descriptor: self.font_descriptor.clone(),
                     background_enabled: self.text_background_enabled,
                     wrap_width: self.text_wrap_width,
+                    rotation: 0.0,
                 },
                 TextInputMode::StickyNote => Shape::StickyNote {
                     x,
@@ -190,6 +191,7 @@ impl InputState {
         }
 
         let mutates_buffer = match key {
+            Key::Char('b' | 'B' | 'i' | 'I') if ctrl => !shift,
             Key::Char(_) | Key::Space => !ctrl,
             Key::Return => shift,
             Key::Backspace | Key::Delete => true,
@@ -234,6 +236,15 @@ impl InputState {
                 )
             }
             Key::Char('a' | 'A') if ctrl && !alt => caret_edit::select_all(buffer, caret, anchor),
+            // Inline markup: Ctrl+B / Ctrl+I toggle `**bold**` / `*italic*`
+            // around the selection. Text mode takes these keys ahead of any
+            // bound action (Ctrl+B toggles the blackboard elsewhere).
+            Key::Char('b' | 'B') if ctrl && !shift => {
+                caret_edit::toggle_inline_marker(buffer, caret, anchor, "**", MAX_TEXT_LENGTH)
+            }
+            Key::Char('i' | 'I') if ctrl && !shift => {
+                caret_edit::toggle_inline_marker(buffer, caret, anchor, "*", MAX_TEXT_LENGTH)
+            }
             Key::Space if !ctrl && !alt => {
                 caret_edit::insert_str(buffer, caret, anchor, " ", MAX_TEXT_LENGTH)
             }
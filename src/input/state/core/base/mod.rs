@@ -16,8 +16,8 @@ pub use types::{
 };
 pub(crate) use types::{
     BlockedActionFeedback, BoardPickerClickState, ClipboardFingerprint, ClipboardPasteRequest,
-    DelayedHistory, HistoryMode, PasteAnchor, PendingBackendAction, PendingBoardDelete,
-    PendingClipboardFallback, PendingOnboardingUsage, PendingPageDelete,
+    ClipboardShape, DelayedHistory, HistoryMode, PasteAnchor, PendingBackendAction,
+    PendingBoardDelete, PendingClipboardFallback, PendingOnboardingUsage, PendingPageDelete,
     PendingSelectionClipboardPublish, PendingToolbarPersistence, PolygonClickState,
     PresetFeedbackState, SelectionPublishState, TextBlockDrag, TextClickState,
     TextClipboardRequest, TextCutTarget, TextEditEntryFeedback, TextPasteEdit, TextPasteTarget,
@@ -11,10 +11,13 @@ use crate::config::{
     Action, BoardsConfig, PRESET_SLOTS_MAX, QuickColorPalette, RadialMenuMouseBinding, Shortcut,
 };
 use crate::draw::{
-    BlurStyle, DirtyTracker, EraserKind, FontDescriptor, REGULAR_POLYGON_DEFAULT_SIDES,
+    BlurStyle, DirtyTracker, EraserKind, FontDescriptor, LineStyle, REGULAR_POLYGON_DEFAULT_SIDES,
 };
 use crate::input::state::highlight::{ClickHighlightSettings, ClickHighlightState};
 use crate::input::state::input_hud::{InputHudSettings, InputHudState};
+use crate::input::state::laser::{LaserSettings, LaserState};
+use crate::input::state::magnifier::{MagnifierSettings, MagnifierState};
+use crate::input::state::timer::{TimerSettings, TimerState};
 use crate::input::{
     BoardManager,
     modifiers::{DragToolBindings, Modifiers},
@@ -96,6 +99,13 @@ impl InputState {
             eraser_mode,
             marker_opacity,
             blur_style: BlurStyle::default(),
+            line_style: LineStyle::default(),
+            smart_ink_enabled: false,
+            smart_ink_hold_ms: 0,
+            smart_ink_rest: None,
+            stabilizer_strength: 0.0,
+            stabilizer_brush: None,
+            smooth_strokes: false,
             spotlight_dim_opacity: 0.6,
             spotlight_feather: 0.35,
             current_font_size: font_size,
@@ -168,6 +178,9 @@ impl InputState {
             toolbar_visible: true,
             toolbar_top_visible: true,
             fill_enabled,
+            connector_mode: false,
+            replay: None,
+            recording_started_at: None,
             polygon_sides: REGULAR_POLYGON_DEFAULT_SIDES,
             toolbar_top_pinned: true,
             toolbar_use_icons: true, // Default to icon mode
@@ -202,6 +215,8 @@ impl InputState {
             deleted_pages: Vec::new(),
             dirty_tracker: DirtyTracker::new(),
             last_provisional_bounds: None,
+            selection_drag_residual: (0, 0),
+            alignment_guides: Vec::new(),
             last_text_preview_bounds: None,
             text_input_cursor_rect_dirty: false,
             text_input_external_change_dirty: false,
@@ -224,6 +239,10 @@ impl InputState {
             pending_text_paste: Default::default(),
             max_shapes_per_frame,
             click_highlight: ClickHighlightState::new(click_highlight_settings),
+            laser: LaserState::new(LaserSettings::default()),
+            magnifier: MagnifierState::new(MagnifierSettings::default()),
+            timer: TimerState::new(TimerSettings::default()),
+            drop_target: None,
             input_hud: InputHudState::new(InputHudSettings::default()),
             tool_override: None,
             selection_state: SelectionState::None,
@@ -300,6 +319,7 @@ impl InputState {
             pending_frozen_toggle: false,
             eyedropper_ui_state: crate::input::state::core::EyedropperUiState::Inactive,
             pending_eyedropper_toggle: false,
+            pending_magnifier_source: false,
             ocr_ui_state: crate::input::state::core::OcrUiState::Inactive,
             pending_ocr_request: false,
             ocr_cancelled_by_toolbar: false,
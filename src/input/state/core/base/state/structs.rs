@@ -6,34 +6,41 @@ use super::super::super::{
     color_picker_popup::{
         ColorPickerPopupAction, ColorPickerPopupLayout, ColorPickerPopupState, HexPasteTarget,
     },
+    drop_target::DropTarget,
     index::SpatialGrid,
     menus::{ContextMenuLayout, ContextMenuState},
     properties::{PropertiesPanelLayout, ShapePropertiesPanel},
     radial_menu::{RadialMenuLayout, RadialMenuState},
+    replay::ReplayPlayback,
     selection::SelectionState,
     status_hud::StatusHudRebuildInputs,
 };
 use super::super::toast_queue::ToastQueue;
 use super::super::types::{
-    BlockedActionFeedback, BoardPickerClickState, ClipboardPasteRequest, CompositorCapabilities,
-    DelayedHistory, DrawingState, KeybindingEditRequest, OutputFocusAction, PendingBackendAction,
-    PendingBoardDelete, PendingClipboardFallback, PendingOnboardingUsage, PendingPageDelete,
-    PendingSelectionClipboardPublish, PendingToolbarPersistence, PolygonClickState, PresetAction,
-    PresetFeedbackState, PressureThicknessEditMode, PressureThicknessEntryMode, QuickColorEdit,
-    SelectionAxis, SelectionPublishState, StatusChangeHighlight, TextBlockDrag, TextClickState,
-    TextClipboardRequest, TextEditEntryFeedback, TextInputMode, TextPasteTarget, UiToastState,
-    ZoomAction,
+    BlockedActionFeedback, BoardPickerClickState, ClipboardPasteRequest, ClipboardShape,
+    CompositorCapabilities, DelayedHistory, DrawingState, KeybindingEditRequest, OutputFocusAction,
+    PendingBackendAction, PendingBoardDelete, PendingClipboardFallback, PendingOnboardingUsage,
+    PendingPageDelete, PendingSelectionClipboardPublish, PendingToolbarPersistence,
+    PolygonClickState, PresetAction, PresetFeedbackState, PressureThicknessEditMode,
+    PressureThicknessEntryMode, QuickColorEdit, SelectionAxis, SelectionPublishState,
+    StatusChangeHighlight, TextBlockDrag, TextClickState, TextClipboardRequest,
+    TextEditEntryFeedback, TextInputMode, TextPasteTarget, UiToastState, ZoomAction,
 };
 use crate::config::{
     Action, PresenterModeConfig, QuickColorPalette, RadialMenuMouseBinding, ResolvedToolbarItems,
     Shortcut, ToolPresetConfig, ToolbarItemId, ToolbarItemOrderGroup, ToolbarItemsConfig,
 };
 use crate::draw::frame::ShapeSnapshot;
-use crate::draw::{BlurStyle, Color, DirtyTracker, EraserKind, FontDescriptor, Shape, ShapeId};
+use crate::draw::{
+    AlignmentGuide, BlurStyle, Color, DirtyTracker, EraserKind, FontDescriptor, LineStyle, ShapeId,
+};
 use crate::input::BoardManager;
 use crate::input::boards::{BoardRestoreRequest, PageRestoreRequest, PendingBoardRuntimeUiAction};
 use crate::input::state::highlight::ClickHighlightState;
 use crate::input::state::input_hud::InputHudState;
+use crate::input::state::laser::LaserState;
+use crate::input::state::magnifier::MagnifierState;
+use crate::input::state::timer::TimerState;
 use crate::input::{
     Key, MouseButton,
     modifiers::{DragToolBindings, Modifiers},
@@ -116,6 +123,20 @@ pub struct InputState {
     pub marker_opacity: f64,
     /// How the blur tool obscures the region it covers
     pub blur_style: BlurStyle,
+    /// Dash style applied to new lines, outlines, arrows, and pen strokes
+    pub line_style: LineStyle,
+    /// Whether finished pen strokes are replaced by recognized shapes
+    pub smart_ink_enabled: bool,
+    /// Rest time required at the end of a pen stroke before recognizing it (0 = always)
+    pub smart_ink_hold_ms: u64,
+    /// Where and since when the pointer has rested during the current pen stroke
+    pub(crate) smart_ink_rest: Option<((i32, i32), Instant)>,
+    /// Lazy-brush stabilizer strength for pen and marker strokes (0.0 = off)
+    pub stabilizer_strength: f64,
+    /// Where the stabilized ink of the current stroke has reached
+    pub(crate) stabilizer_brush: Option<(f64, f64)>,
+    /// Whether finished pen and marker strokes are curve-fitted
+    pub smooth_strokes: bool,
     /// Alpha of the dim layer outside every spotlight
     pub spotlight_dim_opacity: f64,
     /// Fraction of each spotlight radius spent fading out at the edge
@@ -278,6 +299,13 @@ pub struct InputState {
     pub toolbar_top_visible: bool,
     /// Whether fill is enabled for fill-capable shapes (rect, ellipse)
     pub fill_enabled: bool,
+    /// Whether new lines and arrows attach their ends to the shapes they start
+    /// or end on
+    pub connector_mode: bool,
+    /// Replay of the active page, while replay mode runs
+    pub(crate) replay: Option<ReplayPlayback>,
+    /// When the current overlay recording started, while one runs
+    pub(crate) recording_started_at: Option<Instant>,
     /// Current side count for regular polygon drawing.
     pub polygon_sides: u8,
     /// Whether the top toolbar is pinned (saved to config, opens at startup)
@@ -355,6 +383,11 @@ pub struct InputState {
     pub(crate) dirty_tracker: DirtyTracker,
     /// Cached bounds for the current provisional shape (if any)
     pub(crate) last_provisional_bounds: Option<Rect>,
+    /// Pointer travel a snapped selection drag has not applied yet, so the
+    /// selection catches up with the pointer once it leaves a snap position
+    pub(crate) selection_drag_residual: (i32, i32),
+    /// Alignment guides shown while dragging a selection
+    pub(crate) alignment_guides: Vec<AlignmentGuide>,
     /// Cached bounds for live text preview/caret (if any)
     pub(crate) last_text_preview_bounds: Option<Rect>,
     /// Coalesced request for the Wayland backend to publish the current text
@@ -430,6 +463,14 @@ pub struct InputState {
     pub max_shapes_per_frame: usize,
     /// Click highlight animation state
     pub(crate) click_highlight: ClickHighlightState,
+    /// Laser pointer trail and fade-ink bookkeeping
+    pub(crate) laser: LaserState,
+    /// Magnifier lens that follows the pointer
+    pub(crate) magnifier: MagnifierState,
+    /// Break timer, talk timer, and stopwatch
+    pub(crate) timer: TimerState,
+    /// Accepted drag hovering over the overlay, while one does
+    pub(crate) drop_target: Option<DropTarget>,
     /// On-screen input HUD (keystroke/click chips) state
     pub(crate) input_hud: InputHudState,
     /// Optional tool override independent of modifier keys
@@ -511,7 +552,7 @@ pub struct InputState {
     /// Cached bounds of up to two rendered toast action chips.
     pub(crate) ui_toast_action_bounds: [Option<(f64, f64, f64, f64)>; 2],
     /// Copied selection shapes for paste operations
-    pub(in crate::input::state::core) selection_clipboard: Option<Vec<Shape>>,
+    pub(in crate::input::state::core) selection_clipboard: Option<Vec<ClipboardShape>>,
     /// Local clipboard generation for the copied shape selection.
     pub(in crate::input::state::core) selection_clipboard_generation: u64,
     /// System clipboard publication state for the current local selection.
@@ -601,6 +642,9 @@ pub struct InputState {
         crate::input::state::core::EyedropperUiState,
     /// Pending eyedropper activation request for the Wayland backend.
     pub(in crate::input::state::core) pending_eyedropper_toggle: bool,
+    /// The magnifier opened or closed and the backend has to provide (or
+    /// release) the screen image under it.
+    pub(in crate::input::state::core) pending_magnifier_source: bool,
     /// Screen text recognition (OCR) region-selector lifecycle.
     pub(in crate::input::state::core) ocr_ui_state: crate::input::state::core::OcrUiState,
     /// Pending `Copy text from screen` request for the Wayland backend.
@@ -41,7 +41,8 @@ pub enum ToastPriority {
     Critical,
 }
 
-/// Content of a toast push: everything except priority and key.
+/// Content of a toast push: everything except priority and key. The message
+/// and chip labels are looked up in the UI language's catalog as they are set.
 #[derive(Debug, Clone)]
 pub struct Toast {
     pub kind: UiToastKind,
@@ -56,7 +57,7 @@ impl Toast {
     pub fn new(kind: UiToastKind, message: impl Into<String>) -> Self {
         Self {
             kind,
-            message: message.into(),
+            message: crate::i18n::tr_string(message.into()),
             duration_ms: UI_TOAST_DURATION_MS,
             action: None,
             secondary_action: None,
@@ -83,7 +84,7 @@ impl Toast {
 
     pub fn action(mut self, label: impl Into<String>, action: Action) -> Self {
         self.action = Some(ToastAction {
-            label: label.into(),
+            label: crate::i18n::tr_string(label.into()),
             command: ToastCommand::Dispatch(action),
         });
         self
@@ -91,7 +92,7 @@ impl Toast {
 
     pub(crate) fn command(mut self, label: impl Into<String>, command: ToastCommand) -> Self {
         self.action = Some(ToastAction {
-            label: label.into(),
+            label: crate::i18n::tr_string(label.into()),
             command,
         });
         self
@@ -101,7 +102,7 @@ impl Toast {
     /// their chips; the remaining toast body becomes dismiss-only.
     pub fn secondary_action(mut self, label: impl Into<String>, action: Action) -> Self {
         self.secondary_action = Some(ToastAction {
-            label: label.into(),
+            label: crate::i18n::tr_string(label.into()),
             command: ToastCommand::Dispatch(action),
         });
         self
@@ -113,7 +114,7 @@ impl Toast {
         command: ToastCommand,
     ) -> Self {
         self.secondary_action = Some(ToastAction {
-            label: label.into(),
+            label: crate::i18n::tr_string(label.into()),
             command,
         });
         self
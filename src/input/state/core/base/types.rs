@@ -16,8 +16,8 @@ pub const STATUS_CHANGE_HIGHLIGHT_MS: u64 = 300;
 use crate::capture::{ImageOperationKind, file::FileSaveConfig};
 use crate::config::ToolPresetConfig;
 use crate::domain::{Action, OnboardingTip};
-use crate::draw::frame::ShapeSnapshot;
-use crate::draw::{Color, Shape, ShapeId};
+use crate::draw::frame::{GroupId, ShapeAppearance, ShapeSnapshot};
+use crate::draw::{Color, Frame, Shape, ShapeId};
 use crate::input::tool::Tool;
 use crate::util::Rect;
 use serde::{Deserialize, Serialize};
@@ -158,6 +158,8 @@ pub enum SelectionHandle {
     Bottom,
     Left,
     Right,
+    /// Knob above the top edge that turns the selection about its center
+    Rotate,
 }
 
 /// Describes which kind of text input is active.
@@ -286,6 +288,8 @@ pub(crate) enum ToastCommand {
         tip: OnboardingTip,
         then: Option<Action>,
     },
+    /// Opens the session file most recently dropped onto the overlay.
+    OpenDroppedSession,
 }
 
 /// Labeled command rendered as a toast action chip.
@@ -299,7 +303,7 @@ impl ToastAction {
     pub(crate) fn dispatch_action(&self) -> Option<Action> {
         match self.command {
             ToastCommand::Dispatch(action) => Some(action),
-            ToastCommand::AcknowledgeTip { .. } => None,
+            ToastCommand::AcknowledgeTip { .. } | ToastCommand::OpenDroppedSession => None,
         }
     }
 }
@@ -502,7 +506,11 @@ pub enum PendingBackendAction {
     Screenshot(Action),
     CanvasExport(Action),
     BoardPdfExport(Action),
+    SvgExport(Action),
+    ReplayFrameExport,
+    ToggleRecording,
     DesktopOpen(crate::desktop_open::DesktopOpenRequest),
+    PageImport(std::path::PathBuf),
     ClearSavedToolState,
 }
 
@@ -596,7 +604,61 @@ pub(crate) struct WayscriberClipboardSelection {
     pub app_version: String,
     pub app_instance_id: String,
     pub copy_generation: u64,
-    pub shapes: Vec<Shape>,
+    pub shapes: Vec<ClipboardShape>,
+}
+
+/// One copied shape plus the drawn-shape state that travels with it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "ClipboardShapeRepr")]
+pub(crate) struct ClipboardShape {
+    pub shape: Shape,
+    #[serde(default, skip_serializing_if = "ShapeAppearance::is_default")]
+    pub appearance: ShapeAppearance,
+    /// The group the shape belonged to when copied. Entries sharing it paste
+    /// back as one group under a fresh id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<GroupId>,
+}
+
+impl From<Shape> for ClipboardShape {
+    fn from(shape: Shape) -> Self {
+        Self {
+            shape,
+            appearance: ShapeAppearance::default(),
+            group: None,
+        }
+    }
+}
+
+/// Schema-1 payloads list bare shapes; later ones wrap each in an entry.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ClipboardShapeRepr {
+    Entry {
+        shape: Shape,
+        #[serde(default)]
+        appearance: ShapeAppearance,
+        #[serde(default)]
+        group: Option<GroupId>,
+    },
+    Bare(Shape),
+}
+
+impl From<ClipboardShapeRepr> for ClipboardShape {
+    fn from(repr: ClipboardShapeRepr) -> Self {
+        match repr {
+            ClipboardShapeRepr::Entry {
+                shape,
+                appearance,
+                group,
+            } => Self {
+                shape,
+                appearance,
+                group,
+            },
+            ClipboardShapeRepr::Bare(shape) => shape.into(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -630,6 +692,12 @@ pub(crate) enum SelectionPublishState {
 pub(crate) struct PendingSelectionClipboardPublish {
     pub generation: u64,
     pub payload_json: String,
+    /// The copied shapes in selection order, for the text form offered to
+    /// other apps.
+    pub shapes: Vec<Shape>,
+    /// The copied shapes on their layers, for the image forms, so opacity and
+    /// blend modes render as on the canvas.
+    pub frame: Frame,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -1,3 +1,4 @@
+mod backgrounds;
 mod delete_restore;
 mod lifecycle;
 mod pages;
@@ -0,0 +1,97 @@
+use std::path::PathBuf;
+
+use super::super::base::{InputState, PendingBackendAction};
+use crate::draw::{Frame, PageBackground};
+use crate::i18n::tr_args;
+use crate::input::state::{Toast, ToastPriority};
+
+impl InputState {
+    /// Asks the backend to rasterize `path` into page backgrounds; the pages
+    /// arrive later through [`Self::apply_imported_pages`].
+    pub fn request_page_import(&mut self, path: PathBuf) {
+        self.set_pending_backend_action(PendingBackendAction::PageImport(path));
+    }
+
+    /// Adds imported document pages to the active board, one board page per
+    /// document page. An empty active page takes the first document page;
+    /// the rest are inserted after it so the deck keeps its order. Each page's
+    /// background is an undo step on that page. Returns the number of pages
+    /// added.
+    pub fn apply_imported_pages(&mut self, backgrounds: Vec<PageBackground>) -> usize {
+        let Some(label) = backgrounds.first().map(|background| {
+            background
+                .source
+                .path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| background.label())
+        }) else {
+            return 0;
+        };
+        let count = backgrounds.len();
+        let undo_limit = self.undo_stack_limit;
+        self.prepare_active_page_content_change();
+        let pages = self.boards.active_pages_mut();
+        let reuse_active =
+            pages.active_frame().is_empty() && pages.active_frame().background().is_none();
+        let first_index = if reuse_active {
+            pages.active_index()
+        } else {
+            pages.active_index() + 1
+        };
+        let mut backgrounds = backgrounds.into_iter();
+        if reuse_active {
+            pages
+                .active_frame_mut()
+                .replace_background(backgrounds.next(), undo_limit);
+        }
+        let mut insert_at = first_index + usize::from(reuse_active);
+        for background in backgrounds {
+            let mut frame = Frame::new();
+            frame.replace_background(Some(background), undo_limit);
+            insert_at = pages.insert_page_at(insert_at, frame) + 1;
+        }
+        pages.switch_to_page(first_index);
+        self.finish_active_page_content_change();
+        let page_num = self.boards.active_page_index() + 1;
+        let page_count = self.boards.page_count();
+        self.push_toast(
+            ToastPriority::Info,
+            "page.nav",
+            Toast::info(tr_args(
+                if count == 1 {
+                    "Imported 1 page from {label} ({page_num}/{page_count})"
+                } else {
+                    "Imported {count} pages from {label} ({page_num}/{page_count})"
+                },
+                &[
+                    ("count", &count.to_string()),
+                    ("label", &label),
+                    ("page_num", &page_num.to_string()),
+                    ("page_count", &page_count.to_string()),
+                ],
+            )),
+        );
+        count
+    }
+
+    /// Removes the imported background from the active page, keeping its
+    /// annotations; undo brings it back. Returns false if the page had none.
+    pub fn clear_page_background(&mut self) -> bool {
+        if self.boards.active_frame().background().is_none() {
+            return false;
+        }
+        self.prepare_active_page_content_change();
+        let undo_limit = self.undo_stack_limit;
+        self.boards
+            .active_frame_mut()
+            .replace_background(None, undo_limit);
+        self.finish_active_page_content_change();
+        self.push_toast(
+            ToastPriority::Info,
+            "page.nav",
+            Toast::info("Page background removed"),
+        );
+        true
+    }
+}
@@ -2,6 +2,7 @@ use super::super::base::{
     BOARD_DELETE_CONFIRM_MS, BOARD_UNDO_EXPIRE_MS, InputState, PendingBoardDelete,
 };
 use crate::domain::Action;
+use crate::i18n::tr_args;
 use crate::input::boards::{
     BoardDeleteOutcome, BoardDeleteRejection, BoardDeleteRequest, BoardDeleteTarget,
     BoardIdentityGeneration, BoardRestoreOutcome, BoardRestoreRejection, BoardRestoreRequest,
@@ -151,9 +152,12 @@ impl InputState {
                 self.push_toast(
                     ToastPriority::Action,
                     "board.delete",
-                    Toast::warning(format!("Delete board '{name}'? Click to confirm."))
-                        .action("Delete", Action::BoardDelete)
-                        .duration_ms(BOARD_DELETE_CONFIRM_MS),
+                    Toast::warning(tr_args(
+                        "Delete board '{name}'? Click to confirm.",
+                        &[("name", &name)],
+                    ))
+                    .action("Delete", Action::BoardDelete)
+                    .duration_ms(BOARD_DELETE_CONFIRM_MS),
                 );
             }
             BoardDeleteOutcome::Deleted {
@@ -180,7 +184,7 @@ impl InputState {
                 self.push_toast(
                     ToastPriority::Action,
                     "board.delete",
-                    Toast::info(format!("Board deleted: {deleted_name}"))
+                    Toast::info(tr_args("Board deleted: {name}", &[("name", &deleted_name)]))
                         .action("Undo", Action::BoardRestoreDeleted),
                 );
 
@@ -266,7 +270,10 @@ impl InputState {
                 self.push_toast(
                     ToastPriority::Info,
                     "board.delete",
-                    Toast::info(format!("Board restored: {restored_name}")),
+                    Toast::info(tr_args(
+                        "Board restored: {name}",
+                        &[("name", &restored_name)],
+                    )),
                 );
             }
             BoardRestoreOutcome::Rejected(BoardRestoreRejection::MaxCountReached { request }) => {
@@ -3,6 +3,7 @@ use super::super::super::base::{
 };
 use crate::domain::Action;
 use crate::draw::PageDeleteOutcome as CanvasPageDeleteOutcome;
+use crate::i18n::tr_args;
 use crate::input::boards::{
     PageDeleteBoardTarget, PageDeleteOutcome, PageDeleteRequest, PageDeleteTarget,
     PageOperationRejection, PageRestoreOutcome, PageRestorePlacement, PageRestoreRejection,
@@ -73,10 +74,14 @@ impl InputState {
                     confirmation,
                     expires_at: now + Duration::from_millis(PAGE_DELETE_CONFIRM_MS),
                 });
-                self.push_toast(ToastPriority::Info, "page.delete", Toast::warning(format!(
-                        "Delete page {}/{} on '{board_name}' ({board_id})? Click delete again to confirm.",
-                        page_index + 1,
-                        page_count
+                self.push_toast(ToastPriority::Info, "page.delete", Toast::warning(tr_args(
+                        "Delete page {page_num}/{page_count} on '{board_name}' ({board_id})? Click delete again to confirm.",
+                        &[
+                            ("page_num", &(page_index + 1).to_string()),
+                            ("page_count", &page_count.to_string()),
+                            ("board_name", &board_name),
+                            ("board_id", &board_id),
+                        ],
                     )).duration_ms(PAGE_DELETE_CONFIRM_MS));
                 CanvasPageDeleteOutcome::Pending
             }
@@ -86,7 +91,10 @@ impl InputState {
                 self.push_toast(
                     ToastPriority::Info,
                     "page.delete",
-                    Toast::info(format!("Page cleared on '{board_name}' ({board_id})")),
+                    Toast::info(tr_args(
+                        "Page cleared on '{board_name}' ({board_id})",
+                        &[("board_name", &board_name), ("board_id", &board_id)],
+                    )),
                 );
                 CanvasPageDeleteOutcome::Cleared
             }
@@ -100,10 +108,14 @@ impl InputState {
                 self.push_toast(
                     ToastPriority::Info,
                     "page.delete",
-                    Toast::info(format!(
-                        "Page deleted on '{board_name}' ({board_id}) ({}/{})",
-                        new_page_index + 1,
-                        new_page_count
+                    Toast::info(tr_args(
+                        "Page deleted on '{board_name}' ({board_id}) ({page_num}/{page_count})",
+                        &[
+                            ("board_name", &board_name),
+                            ("board_id", &board_id),
+                            ("page_num", &(new_page_index + 1).to_string()),
+                            ("page_count", &new_page_count.to_string()),
+                        ],
                     )),
                 );
                 CanvasPageDeleteOutcome::Removed
@@ -165,10 +177,12 @@ impl InputState {
                 self.push_toast(
                     ToastPriority::Action,
                     "page.delete",
-                    Toast::warning(format!(
-                        "Delete page {}/{}? Click to confirm.",
-                        page_index + 1,
-                        page_count
+                    Toast::warning(tr_args(
+                        "Delete page {page_num}/{page_count}? Click to confirm.",
+                        &[
+                            ("page_num", &(page_index + 1).to_string()),
+                            ("page_count", &page_count.to_string()),
+                        ],
                     ))
                     .action("Delete", Action::PageDelete)
                     .duration_ms(PAGE_DELETE_CONFIRM_MS),
@@ -205,9 +219,12 @@ impl InputState {
                 self.push_toast(
                     ToastPriority::Action,
                     "page.delete",
-                    Toast::info(format!(
-                        "Page deleted ({}/{new_page_count})",
-                        new_page_index + 1
+                    Toast::info(tr_args(
+                        "Page deleted ({page_num}/{page_count})",
+                        &[
+                            ("page_num", &(new_page_index + 1).to_string()),
+                            ("page_count", &new_page_count.to_string()),
+                        ],
                     ))
                     .action("Undo", Action::PageRestoreDeleted),
                 );
@@ -291,7 +308,13 @@ impl InputState {
                     self.push_toast(
                         ToastPriority::Info,
                         "page.delete",
-                        Toast::info(format!("Page restored ({}/{page_count})", page_index + 1)),
+                        Toast::info(tr_args(
+                            "Page restored ({page_num}/{page_count})",
+                            &[
+                                ("page_num", &(page_index + 1).to_string()),
+                                ("page_count", &page_count.to_string()),
+                            ],
+                        )),
                     );
                 }
                 PageRestoreOutcome::Rejected(PageRestoreRejection::MissingBoard { request }) => {
@@ -1,5 +1,6 @@
 use super::super::base::InputState;
 use crate::draw::Color;
+use crate::i18n::tr_args;
 use crate::input::boards::PendingBoardRuntimeUiAction;
 use crate::input::state::{Toast, ToastPriority};
 use crate::input::{BoardBackground, runtime_contrast_pen_color};
@@ -165,8 +166,14 @@ impl InputState {
         self.push_toast(
             ToastPriority::Info,
             "page.nav",
-            Toast::info(format!(
-                "Page added on '{board_name}' ({board_id}) ({page_num}/{page_count})"
+            Toast::info(tr_args(
+                "Page added on '{board_name}' ({board_id}) ({page_num}/{page_count})",
+                &[
+                    ("board_name", &board_name),
+                    ("board_id", &board_id),
+                    ("page_num", &page_num.to_string()),
+                    ("page_count", &page_count.to_string()),
+                ],
             )),
         );
         true
@@ -204,8 +211,14 @@ impl InputState {
         self.push_toast(
             ToastPriority::Info,
             "page.nav",
-            Toast::info(format!(
-                "Page duplicated on '{board_name}' ({board_id}) ({page_num}/{page_count})"
+            Toast::info(tr_args(
+                "Page duplicated on '{board_name}' ({board_id}) ({page_num}/{page_count})",
+                &[
+                    ("board_name", &board_name),
+                    ("board_id", &board_id),
+                    ("page_num", &page_num.to_string()),
+                    ("page_count", &page_count.to_string()),
+                ],
             )),
         );
         true
@@ -307,14 +320,21 @@ impl InputState {
             self.mark_board_surface_changed();
         }
 
-        let action = if copy { "copied" } else { "moved" };
         self.push_toast(
             ToastPriority::Info,
             "page.nav",
-            Toast::info(format!(
-                "Page {action} to '{target_name}' ({target_id}) ({}/{})",
-                new_index + 1,
-                target_count
+            Toast::info(tr_args(
+                if copy {
+                    "Page copied to '{board_name}' ({board_id}) ({page_num}/{page_count})"
+                } else {
+                    "Page moved to '{board_name}' ({board_id}) ({page_num}/{page_count})"
+                },
+                &[
+                    ("board_name", &target_name),
+                    ("board_id", &target_id),
+                    ("page_num", &(new_index + 1).to_string()),
+                    ("page_count", &target_count.to_string()),
+                ],
             )),
         );
         self.mark_session_dirty();
@@ -369,7 +389,13 @@ impl InputState {
         self.push_toast(
             ToastPriority::Info,
             "page.nav",
-            Toast::info(format!("Page created ({page_num}/{page_count})")),
+            Toast::info(tr_args(
+                "Page created ({page_num}/{page_count})",
+                &[
+                    ("page_num", &page_num.to_string()),
+                    ("page_count", &page_count.to_string()),
+                ],
+            )),
         );
     }
 
@@ -386,7 +412,13 @@ impl InputState {
         self.push_toast(
             ToastPriority::Info,
             "page.nav",
-            Toast::info(format!("Page duplicated ({page_num}/{page_count})")),
+            Toast::info(tr_args(
+                "Page duplicated ({page_num}/{page_count})",
+                &[
+                    ("page_num", &page_num.to_string()),
+                    ("page_count", &page_count.to_string()),
+                ],
+            )),
         );
     }
 }
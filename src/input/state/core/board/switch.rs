@@ -1,4 +1,5 @@
 use super::super::base::InputState;
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastPriority};
 use crate::input::{BOARD_ID_TRANSPARENT, BoardSpec};
 
@@ -67,7 +68,7 @@ impl InputState {
             self.push_toast(
                 ToastPriority::Info,
                 "board.switch",
-                Toast::info(format!("Board created: {name}")),
+                Toast::info(tr_args("Board created: {name}", &[("name", &name)])),
             );
         }
         created
@@ -134,7 +135,7 @@ impl InputState {
             self.push_toast(
                 ToastPriority::Info,
                 "board.switch",
-                Toast::info(format!("Board duplicated: {name}")),
+                Toast::info(tr_args("Board duplicated: {name}", &[("name", name)])),
             );
 
             // Handle color auto-adjustment for the duplicated board
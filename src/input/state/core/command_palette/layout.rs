@@ -74,11 +74,11 @@ pub(crate) enum CommandPaletteRowAction {
 
 impl CommandPaletteRowAction {
     pub(crate) fn tooltip(self) -> &'static str {
-        match self {
+        crate::i18n::tr(match self {
             Self::Edit => "Edit shortcut",
             Self::Delete => "Unbind shortcut",
             Self::Reset => "Reset shortcut to default",
-        }
+        })
     }
 }
 
@@ -980,6 +980,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn return_key_sets_pending_board_svg_export_backend_action() {
+        let mut state = make_state();
+        state.toggle_command_palette();
+        state.command_palette_query = "board svg".to_string();
+        let selected = state.selected_command().expect("selected command");
+        assert_eq!(
+            selected.action,
+            crate::config::keybindings::Action::ExportBoardSvgFile
+        );
+
+        assert!(state.handle_command_palette_key(crate::input::Key::Return));
+
+        assert_eq!(
+            state.take_pending_backend_action(),
+            Some(crate::input::state::PendingBackendAction::SvgExport(
+                crate::config::keybindings::Action::ExportBoardSvgFile
+            ))
+        );
+    }
+
     #[test]
     fn return_key_sets_pending_clear_saved_tool_state_backend_action() {
         let mut state = make_state();
@@ -242,7 +242,7 @@ fn build_command_palette_rows(
     let mut last_header: Option<&'static str> = None;
     for (command_index, command) in filtered.iter().enumerate() {
         let header = if command_index < recent_len {
-            COMMAND_PALETTE_RECENT_HEADER
+            crate::i18n::tr(COMMAND_PALETTE_RECENT_HEADER)
         } else {
             action_category_display_name(command.category)
         };
@@ -336,11 +336,14 @@ pub(crate) fn fuzzy_score(query: &str, text: &str) -> i32 {
 /// is the command palette's per-token index minus the per-user shortcut labels
 /// (callers that have shortcut strings fold those in with their own
 /// [`fuzzy_score`]). Shared with the help overlay so alias queries like
-/// "pie menu" rank the same on both surfaces.
+/// "pie menu" rank the same on both surfaces. Labels and descriptions match
+/// in English as well as in the UI language.
 pub(crate) fn action_meta_token_score(meta: &ActionMeta, token: &str) -> i32 {
     let aliases = meta.search_aliases.join(" ");
     fuzzy_score(token, meta.label)
+        .max(fuzzy_score(token, crate::i18n::tr(meta.label)))
         .max(fuzzy_score(token, meta.description))
+        .max(fuzzy_score(token, crate::i18n::tr(meta.description)))
         .max(fuzzy_score(token, action_category_name(meta.category)))
         .max(fuzzy_score(token, &aliases))
         .max(
@@ -354,8 +357,10 @@ pub(crate) fn action_meta_token_score(meta: &ActionMeta, token: &str) -> i32 {
 /// [`action_meta_token_score`] so the two surfaces share one ranking model.
 fn action_meta_query_bonus(meta: &ActionMeta, query: &str) -> i32 {
     let aliases = meta.search_aliases.join(" ");
-    let mut bonus = fuzzy_score(query, meta.label) * 2;
-    bonus += fuzzy_score(query, meta.description);
+    let mut bonus =
+        fuzzy_score(query, meta.label).max(fuzzy_score(query, crate::i18n::tr(meta.label))) * 2;
+    bonus += fuzzy_score(query, meta.description)
+        .max(fuzzy_score(query, crate::i18n::tr(meta.description)));
     bonus += fuzzy_score(query, &aliases) * 2;
     bonus += fuzzy_score(query, action_category_name(meta.category));
     if let Some(short_label) = meta.short_label {
@@ -391,9 +396,9 @@ fn action_category_name(category: ActionCategory) -> &'static str {
     }
 }
 
-/// Human-cased category names for palette group headers.
+/// Human-cased category names for palette group headers, in the UI language.
 fn action_category_display_name(category: ActionCategory) -> &'static str {
-    match category {
+    crate::i18n::tr(match category {
         ActionCategory::Core => "Core",
         ActionCategory::Drawing => "Drawing",
         ActionCategory::Tools => "Tools",
@@ -405,7 +410,7 @@ fn action_category_display_name(category: ActionCategory) -> &'static str {
         ActionCategory::Selection => "Selection",
         ActionCategory::History => "History",
         ActionCategory::Presets => "Presets",
-    }
+    })
 }
 
 #[cfg(test)]
@@ -2,7 +2,7 @@ use super::base::{DrawingState, InputState, TextInputMode};
 use crate::draw::Shape;
 use crate::draw::shape::{
     CaretGeometry, LogicalBounds, bounding_box_for_points, bounding_box_for_sticky_note_preview,
-    bounding_box_for_text,
+    bounding_box_for_text_preview,
 };
 use crate::input::tool::{
     PROVISIONAL_POLYGON_DAMAGE_PADDING, ToolMotionBehavior, ToolMotionSizeSource,
@@ -203,7 +203,7 @@ impl InputState {
         };
         let preview = self.text_input_preview(cursor_glyph)?;
         let text_bounds = match self.text_input_mode {
-            TextInputMode::Plain => bounding_box_for_text(
+            TextInputMode::Plain => bounding_box_for_text_preview(
                 *x,
                 *y,
                 &preview.text,
@@ -469,6 +469,7 @@ mod tests {
             font_descriptor: FontDescriptor::default(),
             background_enabled: false,
             wrap_width: None,
+            rotation: 0.0,
         }
     }
 
@@ -543,7 +544,7 @@ mod tests {
         let bounds = state
             .compute_text_preview_bounds()
             .expect("a composing block has preview bounds");
-        let ink = bounding_box_for_text(
+        let ink = bounding_box_for_text_preview(
             100,
             100,
             "kako",
@@ -573,7 +574,7 @@ mod tests {
         let bounds = state
             .compute_text_preview_bounds()
             .expect("active text input has preview bounds");
-        let ink = bounding_box_for_text(
+        let ink = bounding_box_for_text_preview(
             100,
             100,
             "kako",
@@ -0,0 +1,71 @@
+//! Drag-and-drop onto the overlay.
+//!
+//! The backend reports where an accepted drag hovers so the UI can highlight
+//! the drop target. A drop becomes a paste request anchored at the drop point;
+//! the dropped content is read off-thread and applied like a clipboard paste.
+
+use super::base::{ClipboardPasteRequest, InputState, PasteAnchor};
+use crate::draw::Shape;
+
+/// What an accepted drag adds to the canvas when dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DropKind {
+    Image,
+    Text,
+    /// A file list; the file type is only known once the drop is read.
+    File,
+}
+
+/// An accepted drag hovering over the overlay, in surface coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DropTarget {
+    pub x: f64,
+    pub y: f64,
+    pub kind: DropKind,
+}
+
+impl InputState {
+    pub(crate) fn drop_target(&self) -> Option<DropTarget> {
+        self.drop_target
+    }
+
+    pub(crate) fn set_drop_target(&mut self, target: DropTarget) {
+        if self.drop_target == Some(target) {
+            return;
+        }
+        self.drop_target = Some(target);
+        self.mark_drop_target_changed();
+    }
+
+    pub(crate) fn clear_drop_target(&mut self) {
+        if self.drop_target.take().is_some() {
+            self.mark_drop_target_changed();
+        }
+    }
+
+    /// Starts a paste request anchored at a drop point in canvas coordinates.
+    pub(crate) fn request_drop_paste(&mut self, x: i32, y: i32) -> ClipboardPasteRequest {
+        self.begin_paste_request(PasteAnchor::Pointer { x, y })
+    }
+
+    /// Builds a text annotation in the current text style; the paste moves it
+    /// onto the drop point.
+    pub(crate) fn dropped_text_shape(&self, text: String) -> Shape {
+        Shape::Text {
+            x: 0,
+            y: 0,
+            text,
+            color: self.current_color,
+            size: self.current_font_size,
+            font_descriptor: self.font_descriptor.clone(),
+            background_enabled: self.text_background_enabled,
+            wrap_width: self.text_wrap_width,
+            rotation: 0.0,
+        }
+    }
+
+    fn mark_drop_target_changed(&mut self) {
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+}
@@ -0,0 +1,112 @@
+use super::base::{InputState, SelectionHandle, Toast, ToastPriority};
+use crate::config::BoardGridStyle;
+use crate::draw::{BoardGrid, GridPattern};
+use crate::input::boards::runtime_contrast_pen_color;
+use crate::input::{BoardBackground, Tool};
+use crate::util::Rect;
+
+impl InputState {
+    /// The grid drawn behind the active board's content. Only solid boards
+    /// have one; the transparent overlay never does.
+    pub fn board_grid(&self) -> Option<BoardGrid> {
+        let BoardBackground::Solid(background) = self.boards.active_background() else {
+            return None;
+        };
+        let pattern = match self.boards.grid_style() {
+            BoardGridStyle::Off => return None,
+            BoardGridStyle::Lines => GridPattern::Lines,
+            BoardGridStyle::Dots => GridPattern::Dots,
+        };
+        Some(BoardGrid {
+            pattern,
+            spacing: self.boards.grid_config().spacing,
+            color: runtime_contrast_pen_color(*background),
+        })
+    }
+
+    /// Whether rulers are drawn along the top and left edges of the view.
+    pub fn board_rulers_visible(&self) -> bool {
+        self.boards.grid_config().rulers && self.board_grid().is_some()
+    }
+
+    /// Whether a selection drag shows guides against other shapes.
+    pub(crate) fn alignment_guides_enabled(&self) -> bool {
+        self.boards.grid_config().guides && !self.board_is_transparent()
+    }
+
+    /// The grid that points snap to: the visible grid, while snapping is on.
+    pub(crate) fn snap_grid(&self) -> Option<BoardGrid> {
+        self.board_grid().filter(|_| self.boards.grid_snap())
+    }
+
+    /// Snaps a drag point of `tool` to the nearest grid intersection, for the
+    /// tools that place a shape between two points.
+    pub(crate) fn snap_tool_point(&self, tool: Tool, point: (i32, i32)) -> (i32, i32) {
+        match self.snap_grid() {
+            Some(grid) if tool.snaps_to_grid() => (
+                grid.snap(point.0 as f64) as i32,
+                grid.snap(point.1 as f64) as i32,
+            ),
+            _ => point,
+        }
+    }
+
+    /// Adjusts a resize drag so the edges the handle moves land on the grid.
+    pub(crate) fn snap_resize_delta(
+        &self,
+        handle: SelectionHandle,
+        bounds: &Rect,
+        dx: i32,
+        dy: i32,
+    ) -> (i32, i32) {
+        let Some(grid) = self.snap_grid() else {
+            return (dx, dy);
+        };
+        let snap_edge = |edge: i32, delta: i32| grid.snap((edge + delta) as f64) as i32 - edge;
+        let dx = match handle {
+            SelectionHandle::Left | SelectionHandle::TopLeft | SelectionHandle::BottomLeft => {
+                snap_edge(bounds.x, dx)
+            }
+            SelectionHandle::Right | SelectionHandle::TopRight | SelectionHandle::BottomRight => {
+                snap_edge(bounds.x + bounds.width, dx)
+            }
+            _ => dx,
+        };
+        let dy = match handle {
+            SelectionHandle::Top | SelectionHandle::TopLeft | SelectionHandle::TopRight => {
+                snap_edge(bounds.y, dy)
+            }
+            SelectionHandle::Bottom
+            | SelectionHandle::BottomLeft
+            | SelectionHandle::BottomRight => snap_edge(bounds.y + bounds.height, dy),
+            _ => dy,
+        };
+        (dx, dy)
+    }
+
+    /// Shows or hides the board grid.
+    pub(crate) fn toggle_board_grid(&mut self) -> bool {
+        let shown = self.boards.toggle_grid();
+        let message = match (shown, self.board_is_transparent()) {
+            (true, true) => "Grid on: shows on solid-color boards",
+            (true, false) => "Grid on",
+            (false, _) => "Grid off",
+        };
+        self.push_toast(ToastPriority::Info, "board-grid", Toast::info(message));
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+        true
+    }
+
+    /// Turns snapping to the board grid on or off.
+    pub(crate) fn toggle_grid_snap(&mut self) -> bool {
+        let snap = self.boards.toggle_grid_snap();
+        let message = match (snap, self.board_grid().is_some()) {
+            (true, true) => "Snap to grid on",
+            (true, false) => "Snap to grid on: applies while the grid is shown",
+            (false, _) => "Snap to grid off",
+        };
+        self.push_toast(ToastPriority::Info, "board-grid", Toast::info(message));
+        true
+    }
+}
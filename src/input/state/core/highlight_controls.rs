@@ -110,7 +110,7 @@ impl InputState {
             .current_tool_with_bindings(self.drag_tool_bindings);
 
         if let Some(override_tool) = self.tool_override {
-            if matches!(override_tool, Tool::Highlight | Tool::Eraser) {
+            if matches!(override_tool, Tool::Highlight | Tool::Laser | Tool::Eraser) {
                 return override_tool;
             }
 
@@ -7,6 +7,7 @@ impl InputState {
         self.invalidate_hit_cache_from_action(action);
         self.mark_dirty_from_action(action);
         self.clear_selection();
+        self.reroute_connectors();
         self.needs_redraw = true;
         self.mark_session_dirty();
     }
@@ -40,12 +41,19 @@ impl InputState {
                 self.dirty_tracker.mark_optional_rect(after.bounding_box());
                 self.invalidate_hit_cache_for(*shape_id);
             }
-            UndoAction::Reorder { shape_id, .. } => {
+            UndoAction::Reorder { shape_id, .. }
+            | UndoAction::Regroup { shape_id, .. }
+            | UndoAction::Reconnect { shape_id, .. }
+            | UndoAction::Restyle { shape_id, .. }
+            | UndoAction::Relayer { shape_id, .. } => {
                 if let Some(shape) = self.boards.active_frame().shape(*shape_id) {
                     self.dirty_tracker.mark_shape(&shape.shape);
                     self.invalidate_hit_cache_for(*shape_id);
                 }
             }
+            UndoAction::LayerChange { .. } | UndoAction::Background { .. } => {
+                self.dirty_tracker.mark_full()
+            }
             UndoAction::Compound { actions } => {
                 for action in actions {
                     self.mark_dirty_from_action(action);
@@ -65,9 +73,15 @@ impl InputState {
             | UndoAction::ModifyImageBounds { shape_id, .. } => {
                 self.invalidate_hit_cache_for(*shape_id);
             }
-            UndoAction::Reorder { shape_id, .. } => {
+            UndoAction::Reorder { shape_id, .. }
+            | UndoAction::Regroup { shape_id, .. }
+            | UndoAction::Reconnect { shape_id, .. }
+            | UndoAction::Restyle { shape_id, .. }
+            | UndoAction::Relayer { shape_id, .. } => {
                 self.invalidate_hit_cache_for(*shape_id);
             }
+            UndoAction::LayerChange { .. } => self.invalidate_hit_cache(),
+            UndoAction::Background { .. } => {}
             UndoAction::Compound { actions } => {
                 for action in actions {
                     self.invalidate_hit_cache_from_action(action);
@@ -81,7 +95,7 @@ impl InputState {
 mod tests {
     use super::*;
     use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
-    use crate::draw::{Color, FontDescriptor, Shape, frame::ShapeSnapshot};
+    use crate::draw::{Color, FontDescriptor, LineStyle, Shape, frame::ShapeSnapshot};
     use crate::input::{ClickHighlightSettings, EraserMode};
 
     fn make_state() -> InputState {
@@ -141,6 +155,8 @@ mod tests {
                 a: 1.0,
             },
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         }
     }
 
@@ -72,6 +72,9 @@ impl InputState {
             let Some(drawn) = shape_map.get(&id) else {
                 continue;
             };
+            if !frame.is_shape_editable(drawn) {
+                continue;
+            }
             let bounds = hit_test::compute_hit_bounds(drawn, tolerance);
             let hit = bounds
                 .as_ref()
@@ -173,6 +176,10 @@ impl InputState {
             return None;
         }
 
+        if !frame.is_shape_editable(&frame.shapes[index]) {
+            return None;
+        }
+
         let (shape_id, bounds, hit) = {
             let drawn = &frame.shapes[index];
             let cached = self.hit_test_cache.get(&drawn.id).copied();
@@ -201,6 +208,9 @@ impl InputState {
         let Some(drawn) = frame.shape(id) else {
             return false;
         };
+        if !frame.is_shape_editable(drawn) {
+            return false;
+        }
 
         let cached = self.hit_test_cache.get(&id).copied();
         let bounds = cached.or_else(|| hit_test::compute_hit_bounds(drawn, tolerance));
@@ -232,7 +242,8 @@ impl InputState {
         None
     }
 
-    /// Performs hit-testing against the active frame and returns the top-most shape id.
+    /// Performs hit-testing against the active frame and returns the top-most
+    /// shape id. Shapes on hidden or locked layers are skipped.
     pub fn hit_test_at(&mut self, x: i32, y: i32) -> Option<ShapeId> {
         let tolerance = self.hit_test_tolerance;
         let len = self.boards.active_frame().shapes.len();
@@ -0,0 +1,187 @@
+use super::base::{InputState, Toast, ToastPriority};
+use crate::draw::ShapeId;
+use crate::i18n::tr_args;
+use crate::input::state::laser::{FadingInk, LaserSettings, LaserState};
+use crate::input::tool::Tool;
+use cairo::Context as CairoContext;
+use std::time::Instant;
+
+/// Seconds at the end of a fade-ink stroke's life spent fading it out.
+const FADE_INK_TAIL_SECS: f64 = 0.6;
+
+impl InputState {
+    /// Applies laser pointer settings loaded from config.
+    pub fn init_laser_from_config(&mut self, settings: LaserSettings) {
+        self.laser = LaserState::new(settings);
+    }
+
+    /// Returns whether the laser pointer is the active tool.
+    pub fn laser_tool_active(&self) -> bool {
+        self.active_tool() == Tool::Laser
+    }
+
+    /// Returns whether new pen strokes fade away on their own.
+    pub fn fade_ink_enabled(&self) -> bool {
+        self.laser.fade_ink_enabled()
+    }
+
+    /// Turns fade ink on or off and returns the new state. Strokes already
+    /// fading keep fading.
+    pub(crate) fn toggle_fade_ink(&mut self) -> bool {
+        let enabled = self.laser.toggle_fade_ink();
+        let message = if enabled {
+            tr_args(
+                "Fade ink on: pen strokes vanish after {seconds}s",
+                &[(
+                    "seconds",
+                    &format!(
+                        "{:.1}",
+                        self.laser.settings().fade_ink_duration.as_secs_f64()
+                    ),
+                )],
+            )
+        } else {
+            "Fade ink off".to_string()
+        };
+        self.push_toast(ToastPriority::Info, "fade-ink", Toast::info(message));
+        self.needs_redraw = true;
+        enabled
+    }
+
+    /// Removes the laser dot and trail at once, e.g. on a tool change.
+    pub fn clear_laser_trail(&mut self) {
+        if self.laser.clear(&mut self.dirty_tracker) {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Starts the laser afresh after a tool change: any old trail goes, and
+    /// the dot appears under the pointer straight away if the laser is on.
+    pub(crate) fn reset_laser_pointer(&mut self) {
+        self.clear_laser_trail();
+        if self.pointer_seen {
+            let (x, y) = self.last_canvas_pointer_position;
+            self.update_laser_pointer(x, y);
+        }
+    }
+
+    /// Follows the pointer with the laser dot while the laser is active.
+    pub(crate) fn update_laser_pointer(&mut self, x: i32, y: i32) {
+        if !self.laser_tool_active() {
+            self.clear_laser_trail();
+            return;
+        }
+        if self.laser.push_point(
+            f64::from(x),
+            f64::from(y),
+            Instant::now(),
+            &mut self.dirty_tracker,
+        ) {
+            self.needs_redraw = true;
+        }
+    }
+
+    /// Advances the laser trail and fade-ink strokes; returns true while
+    /// either still needs animation frames.
+    pub fn advance_laser(&mut self, now: Instant) -> bool {
+        let trail_active = self.laser.advance(now, &mut self.dirty_tracker);
+        let ink_active = self.advance_fade_ink(now);
+        trail_active || ink_active
+    }
+
+    /// Render the laser trail and dot to the cairo context.
+    pub fn render_laser(&self, ctx: &CairoContext, now: Instant) {
+        self.laser.render(ctx, now);
+    }
+
+    /// Schedules a just-drawn stroke on the active page to fade away.
+    pub(crate) fn track_fade_ink(&mut self, id: ShapeId) {
+        let Some(drawn) = self.boards.active_frame().shape(id) else {
+            return;
+        };
+        let ink = FadingInk {
+            board_id: self.boards.active_board_id().to_string(),
+            page: self.boards.active_page_index(),
+            shape: id,
+            created_at: drawn.created_at,
+            base_opacity: drawn.appearance.opacity,
+            drawn_at: Instant::now(),
+        };
+        self.laser.push_fading_ink(ink);
+        self.needs_redraw = true;
+    }
+
+    fn advance_fade_ink(&mut self, now: Instant) -> bool {
+        if !self.laser.has_fading_ink() {
+            return false;
+        }
+        let lifetime = self.laser.settings().fade_ink_duration.as_secs_f64();
+        let mut pending = self.laser.take_fading_ink();
+        pending.retain(|ink| {
+            let elapsed = now.saturating_duration_since(ink.drawn_at).as_secs_f64();
+            let alpha = crate::ui::anim::end_fade(elapsed, lifetime, FADE_INK_TAIL_SECS);
+            self.apply_fade_ink(ink, alpha)
+        });
+        let active = !pending.is_empty();
+        self.laser.restore_fading_ink(pending);
+        active
+    }
+
+    /// Dims a fade-ink stroke to `alpha` of its opacity, or removes it at zero.
+    /// Returns whether the stroke is still on its page.
+    fn apply_fade_ink(&mut self, ink: &FadingInk, alpha: f64) -> bool {
+        let on_active_page = self.boards.active_board_id() == ink.board_id
+            && self.boards.active_page_index() == ink.page;
+        let Some(frame) = self
+            .boards
+            .board_state_by_id_mut(&ink.board_id)
+            .and_then(|board| board.pages.frame_mut(ink.page))
+        else {
+            return false;
+        };
+        if frame
+            .shape(ink.shape)
+            .is_none_or(|drawn| drawn.created_at != ink.created_at)
+        {
+            return false;
+        }
+
+        let bounds = if alpha <= 0.0 {
+            let Some((_, removed)) = frame.remove_shape_by_id(ink.shape) else {
+                return false;
+            };
+            self.mark_session_dirty();
+            removed.bounding_box()
+        } else {
+            let Some(drawn) = frame.shape_mut(ink.shape) else {
+                return false;
+            };
+            let opacity = ink.base_opacity * alpha;
+            if (drawn.appearance.opacity - opacity).abs() <= f64::EPSILON {
+                return true;
+            }
+            drawn.appearance.opacity = opacity;
+            drawn.bounding_box()
+        };
+
+        if on_active_page {
+            if alpha <= 0.0 && self.selected_shape_ids().contains(&ink.shape) {
+                let remaining: Vec<_> = self
+                    .selected_shape_ids()
+                    .iter()
+                    .copied()
+                    .filter(|id| *id != ink.shape)
+                    .collect();
+                if remaining.is_empty() {
+                    self.clear_selection();
+                } else {
+                    self.set_selection(remaining);
+                }
+            }
+            self.dirty_tracker.mark_optional_rect(bounds);
+            self.invalidate_hit_cache_for(ink.shape);
+            self.needs_redraw = true;
+        }
+        alpha > 0.0
+    }
+}
@@ -0,0 +1,272 @@
+use super::base::{InputState, Toast, ToastPriority};
+use crate::draw::frame::{LayerTable, UndoAction};
+use crate::draw::{LayerId, MAX_LAYERS};
+use crate::i18n::tr_args;
+
+/// Opacity steps the layer menu cycles through, from opaque down.
+const LAYER_OPACITY_STEPS: [f64; 4] = [1.0, 0.75, 0.5, 0.25];
+
+impl InputState {
+    /// Adds a layer on top of the active page and starts drawing on it.
+    pub(crate) fn layer_new(&mut self) -> bool {
+        let frame = self.boards.active_frame_mut();
+        let before = frame.layer_table();
+        let Some(id) = frame.add_layer() else {
+            self.push_toast(
+                ToastPriority::Info,
+                "layers",
+                Toast::warning(tr_args(
+                    "A page holds at most {max} layers.",
+                    &[("max", &MAX_LAYERS.to_string())],
+                )),
+            );
+            return false;
+        };
+        self.finish_layer_change(before);
+        let name = self.layer_name(id);
+        self.push_toast(
+            ToastPriority::Info,
+            "layers",
+            Toast::info(tr_args("{name} created", &[("name", &name)])),
+        );
+        true
+    }
+
+    /// Makes `id` the layer new shapes go to, showing and unlocking it first
+    /// so the choice always leaves something to draw on.
+    pub(crate) fn layer_activate(&mut self, id: LayerId) -> bool {
+        let frame = self.boards.active_frame_mut();
+        if frame.layer(id).is_none() {
+            return false;
+        }
+        let before = frame.layer_table();
+        let revealed = frame.set_layer_visible(id, true) | frame.set_layer_locked(id, false);
+        if !frame.set_active_layer(id) && !revealed {
+            return false;
+        }
+        self.finish_layer_change(before);
+        let name = self.layer_name(id);
+        self.push_toast(
+            ToastPriority::Info,
+            "layers",
+            Toast::info(tr_args("Drawing on {name}", &[("name", &name)])),
+        );
+        true
+    }
+
+    /// Hides the active layer and moves drawing to the nearest editable one.
+    pub(crate) fn layer_hide_active(&mut self) -> bool {
+        self.retire_active_layer(true)
+    }
+
+    /// Locks the active layer and moves drawing to the nearest editable one.
+    pub(crate) fn layer_lock_active(&mut self) -> bool {
+        self.retire_active_layer(false)
+    }
+
+    /// Steps the active layer's opacity down through the menu presets,
+    /// wrapping back to opaque.
+    pub(crate) fn layer_cycle_active_opacity(&mut self) -> bool {
+        let frame = self.boards.active_frame_mut();
+        let before = frame.layer_table();
+        let layer = frame.active_layer();
+        let (id, current) = (layer.id, layer.opacity);
+        let next = LAYER_OPACITY_STEPS
+            .iter()
+            .copied()
+            .find(|step| *step < current - f64::EPSILON)
+            .unwrap_or(LAYER_OPACITY_STEPS[0]);
+        if !frame.set_layer_opacity(id, next) {
+            return false;
+        }
+        self.finish_layer_change(before);
+        true
+    }
+
+    /// Deletes the active layer when it is empty and not the page's last.
+    pub(crate) fn layer_delete_active(&mut self) -> bool {
+        let frame = self.boards.active_frame_mut();
+        let before = frame.layer_table();
+        let id = frame.active_layer_id();
+        if frame.layers().len() <= 1 {
+            self.push_toast(
+                ToastPriority::Info,
+                "layers",
+                Toast::warning("A page needs at least one layer."),
+            );
+            return false;
+        }
+        if !frame.remove_layer(id) {
+            let name = self.layer_name(id);
+            self.push_toast(
+                ToastPriority::Info,
+                "layers",
+                Toast::warning(tr_args(
+                    "Clear {name} before deleting it.",
+                    &[("name", &name)],
+                )),
+            );
+            return false;
+        }
+        self.finish_layer_change(before);
+        true
+    }
+
+    /// Renames `id`; blank names and unchanged names are ignored.
+    #[allow(dead_code)]
+    pub(crate) fn layer_rename(&mut self, id: LayerId, name: &str) -> bool {
+        let frame = self.boards.active_frame_mut();
+        let before = frame.layer_table();
+        if !frame.rename_layer(id, name) {
+            return false;
+        }
+        self.finish_layer_change(before);
+        true
+    }
+
+    /// Moves the selection onto the nearest editable layer above (`up`) or
+    /// below the first selected shape's layer, as one undo step, and makes
+    /// that layer active.
+    pub(crate) fn move_selection_to_layer(&mut self, up: bool) -> bool {
+        let frame = self.boards.active_frame();
+        let before = frame.layer_table();
+        let ids: Vec<_> = frame
+            .shapes
+            .iter()
+            .filter(|drawn| self.selected_shape_ids().contains(&drawn.id))
+            .map(|drawn| drawn.id)
+            .collect();
+        let Some(from) = ids
+            .first()
+            .and_then(|id| frame.shape(*id))
+            .map(|drawn| drawn.layer)
+        else {
+            return false;
+        };
+        let Some(target) = frame.neighbor_editable_layer(from, up) else {
+            self.push_toast(
+                ToastPriority::Info,
+                "layers",
+                Toast::warning(if up {
+                    "No editable layer above."
+                } else {
+                    "No editable layer below."
+                }),
+            );
+            return false;
+        };
+
+        let frame = self.boards.active_frame_mut();
+        let actions: Vec<UndoAction> = ids
+            .iter()
+            .filter_map(|id| frame.move_shape_to_layer(*id, target))
+            .collect();
+        if actions.is_empty() {
+            return false;
+        }
+        frame.set_active_layer(target);
+        let count = actions.len();
+        let action = if count == 1 {
+            actions.into_iter().next().expect("one relayer step")
+        } else {
+            UndoAction::Compound { actions }
+        };
+        frame.push_undo_action(action, self.undo_stack_limit);
+
+        let name = self.layer_name(target);
+        self.finish_layer_change(before);
+        self.push_toast(
+            ToastPriority::Info,
+            "layers",
+            Toast::info(tr_args(
+                "Moved {count} shape(s) to {name}",
+                &[("count", &count.to_string()), ("name", &name)],
+            )),
+        );
+        true
+    }
+
+    fn retire_active_layer(&mut self, hide: bool) -> bool {
+        let frame = self.boards.active_frame_mut();
+        let before = frame.layer_table();
+        let id = frame.active_layer_id();
+        let has_other = frame
+            .layers()
+            .iter()
+            .any(|layer| layer.id != id && layer.editable());
+        if !has_other {
+            self.push_toast(
+                ToastPriority::Info,
+                "layers",
+                Toast::warning("Add or show another layer first."),
+            );
+            return false;
+        }
+        let changed = if hide {
+            frame.set_layer_visible(id, false)
+        } else {
+            frame.set_layer_locked(id, true)
+        };
+        if !changed {
+            return false;
+        }
+        let next = frame.ensure_active_layer_editable();
+        self.finish_layer_change(before);
+        if let Some(next) = next {
+            let name = self.layer_name(next);
+            self.push_toast(
+                ToastPriority::Info,
+                "layers",
+                Toast::info(tr_args("Drawing on {name}", &[("name", &name)])),
+            );
+        }
+        true
+    }
+
+    fn layer_name(&self, id: LayerId) -> String {
+        self.boards
+            .active_frame()
+            .layer(id)
+            .map(|layer| layer.name.clone())
+            .unwrap_or_default()
+    }
+
+    /// Records the change from `before` as one undo step when the layer
+    /// stack itself changed; switching the active layer alone is not undone.
+    ///
+    /// Layer settings change what renders and what hit-testing reaches
+    /// across the whole page, so everything cached about the page goes.
+    fn finish_layer_change(&mut self, before: LayerTable) {
+        let undo_limit = self.undo_stack_limit;
+        let frame = self.boards.active_frame_mut();
+        let after = frame.layer_table();
+        if after.layers != before.layers {
+            frame.push_undo_action(UndoAction::LayerChange { before, after }, undo_limit);
+        }
+        let frame = self.boards.active_frame();
+        let editable: Vec<_> = self
+            .selected_shape_ids()
+            .iter()
+            .copied()
+            .filter(|id| {
+                frame
+                    .shape(*id)
+                    .is_some_and(|drawn| frame.is_shape_editable(drawn))
+            })
+            .collect();
+        if editable.len() != self.selected_shape_ids().len() {
+            if editable.is_empty() {
+                self.clear_selection();
+            } else {
+                self.set_selection(editable);
+            }
+        }
+        self.invalidate_hit_cache();
+        if self.is_properties_panel_open() {
+            self.properties_panel_needs_refresh = true;
+        }
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+        self.mark_session_dirty();
+    }
+}
@@ -0,0 +1,91 @@
+use super::base::{InputState, Toast, ToastPriority};
+use crate::draw::MagnifierLens;
+use crate::i18n::tr_args;
+use crate::input::state::magnifier::{MagnifierSettings, MagnifierState};
+
+impl InputState {
+    /// Applies magnifier settings loaded from config.
+    pub fn init_magnifier_from_config(&mut self, settings: MagnifierSettings) {
+        self.magnifier = MagnifierState::new(settings);
+    }
+
+    /// Returns whether the magnifier lens is showing.
+    pub fn magnifier_active(&self) -> bool {
+        self.magnifier.active()
+    }
+
+    /// Current magnification of the lens.
+    pub fn magnifier_magnification(&self) -> f64 {
+        self.magnifier.magnification()
+    }
+
+    /// Shows or hides the lens. Drawing carries on underneath either way.
+    pub(crate) fn toggle_magnifier(&mut self) {
+        if self.magnifier.active() {
+            self.close_magnifier();
+            return;
+        }
+        self.set_magnifier_active(true);
+        self.push_toast(
+            ToastPriority::Info,
+            "magnifier",
+            Toast::info(tr_args(
+                "Magnifier on at {zoom}x: scroll to change, Esc to close",
+                &[("zoom", &format!("{:.1}", self.magnifier_magnification()))],
+            )),
+        );
+    }
+
+    /// Hides the lens if it is showing.
+    pub(crate) fn close_magnifier(&mut self) {
+        if self.magnifier.active() {
+            self.set_magnifier_active(false);
+        }
+    }
+
+    /// Zooms the lens in or out by one step. Returns false when the lens is
+    /// closed or already at the end of its range.
+    pub(crate) fn step_magnifier_zoom(&mut self, zoom_in: bool) -> bool {
+        if !self.magnifier.active() || !self.magnifier.step(zoom_in) {
+            return false;
+        }
+        self.push_toast(
+            ToastPriority::Info,
+            "magnifier",
+            Toast::info(tr_args(
+                "Magnifier {zoom}x",
+                &[("zoom", &format!("{:.1}", self.magnifier_magnification()))],
+            )),
+        );
+        self.needs_redraw = true;
+        true
+    }
+
+    /// Returns true once after the lens opened or closed, for the backend to
+    /// capture the screen under it or let that capture go.
+    pub(crate) fn take_pending_magnifier_source(&mut self) -> bool {
+        std::mem::take(&mut self.pending_magnifier_source)
+    }
+
+    /// The lens to draw around `center` (screen coordinates), if it is open.
+    pub(crate) fn magnifier_lens(&self, center: (i32, i32)) -> Option<MagnifierLens> {
+        if !self.magnifier.active() {
+            return None;
+        }
+        let settings = self.magnifier.settings();
+        Some(MagnifierLens {
+            center: (f64::from(center.0), f64::from(center.1)),
+            width: settings.width,
+            height: settings.height,
+            round: settings.round,
+            magnification: self.magnifier.magnification(),
+        })
+    }
+
+    fn set_magnifier_active(&mut self, active: bool) {
+        self.magnifier.set_active(active);
+        self.pending_magnifier_source = true;
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+}
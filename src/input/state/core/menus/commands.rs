@@ -99,6 +99,14 @@ impl InputState {
                 self.set_selection_locked(false);
                 self.close_context_menu();
             }
+            MenuCommand::Group => {
+                self.group_selection();
+                self.close_context_menu();
+            }
+            MenuCommand::Ungroup => {
+                self.ungroup_selection();
+                self.close_context_menu();
+            }
             MenuCommand::Properties => {
                 if self.show_properties_panel() {
                     self.close_context_menu();
@@ -298,6 +306,60 @@ impl InputState {
                 self.switch_to_page(index);
                 self.close_context_menu();
             }
+            MenuCommand::OpenLayersMenu => {
+                let anchor = if let Some(layout) = self.context_menu_layout {
+                    (
+                        (layout.origin_x + layout.width + 8.0).round() as i32,
+                        layout.origin_y.round() as i32,
+                    )
+                } else if let ContextMenuState::Open { anchor, .. } = &self.context_menu_state {
+                    *anchor
+                } else {
+                    self.last_pointer_position
+                };
+                self.open_context_menu(anchor, Vec::new(), ContextMenuKind::Layers, None);
+                self.pending_menu_hover_recalc = false;
+                self.set_context_menu_focus(None);
+                self.focus_first_context_menu_entry();
+                self.dirty_tracker.mark_full();
+                self.needs_redraw = true;
+            }
+            MenuCommand::SwitchToLayer(id) => {
+                self.layer_activate(id);
+                self.close_context_menu();
+            }
+            MenuCommand::LayerNew => {
+                self.layer_new();
+                self.close_context_menu();
+            }
+            MenuCommand::LayerHide => {
+                self.layer_hide_active();
+                self.close_context_menu();
+            }
+            MenuCommand::LayerLock => {
+                self.layer_lock_active();
+                self.close_context_menu();
+            }
+            MenuCommand::LayerOpacity => {
+                self.layer_cycle_active_opacity();
+                self.close_context_menu();
+            }
+            MenuCommand::LayerMoveSelectionUp => {
+                self.handle_action(Action::MoveSelectionToLayerAbove);
+                self.close_context_menu();
+            }
+            MenuCommand::LayerMoveSelectionDown => {
+                self.handle_action(Action::MoveSelectionToLayerBelow);
+                self.close_context_menu();
+            }
+            MenuCommand::LayerClear => {
+                self.handle_action(Action::ClearActiveLayer);
+                self.close_context_menu();
+            }
+            MenuCommand::LayerDelete => {
+                self.layer_delete_active();
+                self.close_context_menu();
+            }
             MenuCommand::OpenBoardPicker => {
                 self.close_context_menu();
                 self.toggle_board_picker();
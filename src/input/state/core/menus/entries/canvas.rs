@@ -10,7 +10,7 @@ impl InputState {
         let mut has_locked = false;
         let mut has_unlocked = false;
         for shape in &frame.shapes {
-            if shape.locked {
+            if shape.locked || !frame.is_shape_editable(shape) {
                 has_locked = true;
             } else {
                 has_unlocked = true;
@@ -77,6 +77,13 @@ impl InputState {
             false,
             Some(MenuCommand::OpenPagesMenu),
         ));
+        entries.push(ContextMenuEntry::new(
+            "Layers",
+            None::<String>,
+            true,
+            false,
+            Some(MenuCommand::OpenLayersMenu),
+        ));
 
         // Quick board switching options
         let current_id = self.board_id();
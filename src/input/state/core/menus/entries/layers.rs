@@ -0,0 +1,118 @@
+use super::super::super::base::InputState;
+use super::super::types::{ContextMenuEntry, MenuCommand};
+use crate::domain::Action;
+use crate::draw::MAX_LAYERS;
+
+impl InputState {
+    pub(super) fn layers_menu_entries(&self) -> Vec<ContextMenuEntry> {
+        let frame = self.boards.active_frame();
+        let active = frame.active_layer();
+        let layer_count = frame.layers().len();
+        let selection_layer = self
+            .selected_shape_ids()
+            .first()
+            .and_then(|id| frame.shape(*id))
+            .map(|drawn| drawn.layer);
+        let can_move = |up: bool| {
+            selection_layer.is_some_and(|layer| frame.neighbor_editable_layer(layer, up).is_some())
+        };
+
+        let mut entries = Vec::new();
+
+        // Current layer indicator
+        entries.push(ContextMenuEntry::new(
+            format!("Drawing on {}", active.name),
+            None::<String>,
+            false,
+            true,
+            None,
+        ));
+
+        // List layers top-first, the way they stack on screen
+        for layer in frame.layers().iter().rev() {
+            let mut label = format!("  {}", layer.name);
+            if layer.id == active.id {
+                label.push_str(" (active)");
+            }
+            if !layer.visible {
+                label.push_str(" (hidden)");
+            } else if layer.locked {
+                label.push_str(" (locked)");
+            }
+            if layer.opacity < 1.0 {
+                label.push_str(&format!(" {:.0}%", layer.opacity * 100.0));
+            }
+            entries.push(ContextMenuEntry::new(
+                label,
+                None::<String>,
+                false,
+                layer.id == active.id,
+                Some(MenuCommand::SwitchToLayer(layer.id)),
+            ));
+        }
+
+        // Active layer settings
+        entries.push(ContextMenuEntry::new(
+            "New Layer",
+            None::<String>,
+            false,
+            layer_count >= MAX_LAYERS,
+            Some(MenuCommand::LayerNew),
+        ));
+        entries.push(ContextMenuEntry::new(
+            "Hide Layer",
+            None::<String>,
+            false,
+            layer_count < 2,
+            Some(MenuCommand::LayerHide),
+        ));
+        entries.push(ContextMenuEntry::new(
+            "Lock Layer",
+            None::<String>,
+            false,
+            layer_count < 2,
+            Some(MenuCommand::LayerLock),
+        ));
+        entries.push(ContextMenuEntry::new(
+            format!("Layer Opacity: {:.0}%", active.opacity * 100.0),
+            None::<String>,
+            false,
+            false,
+            Some(MenuCommand::LayerOpacity),
+        ));
+
+        // Selection
+        entries.push(ContextMenuEntry::new(
+            "Move Selection Up a Layer",
+            self.shortcut_for_action(Action::MoveSelectionToLayerAbove),
+            false,
+            !can_move(true),
+            Some(MenuCommand::LayerMoveSelectionUp),
+        ));
+        entries.push(ContextMenuEntry::new(
+            "Move Selection Down a Layer",
+            self.shortcut_for_action(Action::MoveSelectionToLayerBelow),
+            false,
+            !can_move(false),
+            Some(MenuCommand::LayerMoveSelectionDown),
+        ));
+
+        // Management
+        entries.push(ContextMenuEntry::new(
+            "Clear Layer",
+            self.shortcut_for_action(Action::ClearActiveLayer),
+            false,
+            frame.shapes_on_layer(active.id).is_empty(),
+            Some(MenuCommand::LayerClear),
+        ));
+        entries.push(ContextMenuEntry::new(
+            "Delete Layer",
+            None::<String>,
+            false,
+            layer_count < 2 || !frame.shapes_on_layer(active.id).is_empty(),
+            Some(MenuCommand::LayerDelete),
+        ));
+
+        entries
+    }
+}
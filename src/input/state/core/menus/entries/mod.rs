@@ -1,5 +1,6 @@
 mod boards;
 mod canvas;
+mod layers;
 mod page;
 mod pages;
 mod shape;
@@ -55,6 +56,7 @@ impl InputState {
                 ContextMenuKind::Shape => self.shape_menu_entries(shape_ids, *hovered_shape_id),
                 ContextMenuKind::Zoom => self.zoom_menu_entries(),
                 ContextMenuKind::Pages => self.pages_menu_entries(),
+                ContextMenuKind::Layers => self.layers_menu_entries(),
                 ContextMenuKind::Boards => self.boards_menu_entries(),
                 ContextMenuKind::Page => self.page_context_menu_entries(),
                 ContextMenuKind::PageMove => self.page_move_menu_entries(),
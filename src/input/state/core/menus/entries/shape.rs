@@ -14,6 +14,9 @@ impl InputState {
         let locked = ids
             .iter()
             .any(|id| frame.shape(*id).map(|shape| shape.locked).unwrap_or(false));
+        let grouped = ids
+            .iter()
+            .any(|id| frame.shape(*id).is_some_and(|shape| shape.group.is_some()));
         let all_locked = !ids.is_empty()
             && ids
                 .iter()
@@ -82,6 +85,23 @@ impl InputState {
                 MenuCommand::Lock
             }),
         ));
+        if grouped {
+            entries.push(ContextMenuEntry::new(
+                "Ungroup",
+                self.shortcut_for_action(Action::UngroupSelection),
+                false,
+                false,
+                Some(MenuCommand::Ungroup),
+            ));
+        } else {
+            entries.push(ContextMenuEntry::new(
+                "Group",
+                self.shortcut_for_action(Action::GroupSelection),
+                false,
+                ids.len() < 2,
+                Some(MenuCommand::Group),
+            ));
+        }
         entries.push(ContextMenuEntry::new(
             "Properties",
             self.shortcut_for_action(Action::ToggleSelectionProperties),
@@ -89,6 +109,13 @@ impl InputState {
             false,
             Some(MenuCommand::Properties),
         ));
+        entries.push(ContextMenuEntry::new(
+            "Layers",
+            None::<String>,
+            true,
+            false,
+            Some(MenuCommand::OpenLayersMenu),
+        ));
         if self.boards.pan_enabled() && !self.board_is_transparent() {
             let reset_disabled = self.boards.active_frame().view_offset() == (0, 0);
             entries.push(ContextMenuEntry::new(
@@ -1,4 +1,4 @@
-use crate::draw::ShapeId;
+use crate::draw::{LayerId, ShapeId};
 
 /// Distinguishes between canvas-level and shape-level context menus.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -7,6 +7,7 @@ pub enum ContextMenuKind {
     Canvas,
     Zoom,
     Pages,
+    Layers,
     Boards,
     Page,
     PageMove,
@@ -38,6 +39,8 @@ pub enum MenuCommand {
     MoveToBack,
     Lock,
     Unlock,
+    Group,
+    Ungroup,
     Properties,
     EditText,
     ClearAll,
@@ -59,6 +62,16 @@ pub enum MenuCommand {
     PageDeleteFromContext,
     PageMoveToBoard { id: String },
     SwitchToPage(usize),
+    OpenLayersMenu,
+    SwitchToLayer(LayerId),
+    LayerNew,
+    LayerHide,
+    LayerLock,
+    LayerOpacity,
+    LayerMoveSelectionUp,
+    LayerMoveSelectionDown,
+    LayerClear,
+    LayerDelete,
     OpenBoardsMenu,
     OpenBoardPicker,
     BoardPrev,
@@ -4,23 +4,33 @@ pub(crate) mod board_picker;
 pub(crate) mod color_picker_popup;
 mod command_palette;
 mod dirty;
+mod drop_target;
 mod eyedropper;
+mod grid;
 mod highlight_controls;
 mod history;
 mod ime;
 mod index;
 mod input_hud_controls;
+mod laser_controls;
+mod layers;
+mod magnifier_controls;
 mod menus;
 pub(crate) mod modal;
 mod ocr;
 mod properties;
 pub(crate) mod radial_menu;
+mod recording;
+mod replay;
 mod selection;
 mod selection_actions;
 mod session;
 mod session_preflight;
 mod session_preflight_exact;
+mod smart_ink;
+mod stabilizer;
 mod status_hud;
+mod timer_controls;
 mod tool_controls;
 mod tour;
 mod utility;
@@ -39,7 +49,7 @@ pub(crate) use base::{
     TextCutTarget, TextPasteEdit, TextPasteTarget, ToastCommand, ToastPress,
 };
 pub(crate) use base::{
-    ClipboardFingerprint, ClipboardPasteRequest, PasteAnchor, PendingBackendAction,
+    ClipboardFingerprint, ClipboardPasteRequest, ClipboardShape, PasteAnchor, PendingBackendAction,
     PendingOnboardingUsage, PendingSelectionClipboardPublish, PendingToolbarPersistence,
     SelectionPublishState, WayscriberClipboardSelection,
 };
@@ -64,6 +74,7 @@ pub(crate) use command_palette::{
 pub use command_palette::{
     COMMAND_PALETTE_MAX_VISIBLE, CommandPaletteCursorHint, CommandPaletteListRow,
 };
+pub use drop_target::{DropKind, DropTarget};
 pub use eyedropper::{EyedropperCaptureSource, EyedropperUiState};
 #[cfg(test)]
 pub(crate) use ime::build_text_input_preview;
@@ -81,7 +92,9 @@ pub use radial_menu::{
     compass_slice, size_ring_angle_for_value, size_ring_value_for_angle, slice_parent,
     sub_ring_child_count, sub_ring_children,
 };
+pub use replay::ReplayPlayback;
 pub use selection::SelectionState;
+pub(crate) use selection_actions::SelectionAlignment;
 pub use tool_controls::PrecisionEntryState;
 pub use tour::TourStep;
 pub(crate) use utility::HelpOverlayPressSource;
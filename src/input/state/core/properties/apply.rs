@@ -74,6 +74,7 @@ impl InputState {
             SelectionPropertyKind::Thickness => {
                 self.apply_selection_thickness(direction_or_default(direction))
             }
+            SelectionPropertyKind::LineStyle => self.apply_selection_line_style(direction),
             SelectionPropertyKind::Fill => self.apply_selection_fill(direction),
             SelectionPropertyKind::FontSize => {
                 self.apply_selection_font_size(direction_or_default(direction))
@@ -88,6 +89,11 @@ impl InputState {
             SelectionPropertyKind::TextBackground => {
                 self.apply_selection_text_background(direction)
             }
+            SelectionPropertyKind::Opacity => {
+                self.apply_selection_opacity(direction_or_default(direction))
+            }
+            SelectionPropertyKind::BlendMode => self.apply_selection_blend_mode(direction),
+            SelectionPropertyKind::Layer => self.move_selection_to_layer(direction >= 0),
         }
     }
 }
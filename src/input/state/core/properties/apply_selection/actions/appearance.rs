@@ -0,0 +1,57 @@
+use crate::draw::{BlendMode, ShapeAppearance};
+use crate::input::state::core::base::InputState;
+use crate::input::state::core::properties::apply_selection::constants::SELECTION_OPACITY_STEP;
+use crate::input::state::core::properties::utils::cycle_index;
+
+impl InputState {
+    pub(in crate::input::state::core::properties) fn apply_selection_opacity(
+        &mut self,
+        direction: i32,
+    ) -> bool {
+        let delta = SELECTION_OPACITY_STEP * direction as f64;
+        let result = self.apply_selection_appearance_change(|appearance| {
+            // Snap to whole steps so repeated nudges never drift off the grid.
+            let steps = ((appearance.clamped_opacity() + delta) / SELECTION_OPACITY_STEP).round();
+            let next = (steps * SELECTION_OPACITY_STEP).clamp(ShapeAppearance::MIN_OPACITY, 1.0);
+            if (next - appearance.opacity).abs() > f64::EPSILON {
+                appearance.opacity = next;
+                true
+            } else {
+                false
+            }
+        });
+
+        self.report_selection_apply_result(result, "opacity")
+    }
+
+    pub(in crate::input::state::core::properties) fn apply_selection_blend_mode(
+        &mut self,
+        direction: i32,
+    ) -> bool {
+        let frame = self.boards.active_frame();
+        let base_mode = self
+            .selected_shape_ids()
+            .iter()
+            .filter_map(|id| frame.shape(*id))
+            .filter(|drawn| !drawn.locked && ShapeAppearance::applies_to(&drawn.shape))
+            .map(|drawn| drawn.appearance.blend)
+            .next()
+            .unwrap_or_default();
+        let index = BlendMode::ALL
+            .iter()
+            .position(|mode| *mode == base_mode)
+            .unwrap_or(0);
+        let offset = if direction == 0 { 1 } else { direction };
+        let target = BlendMode::ALL[cycle_index(index, BlendMode::ALL.len(), offset)];
+
+        let result = self.apply_selection_appearance_change(|appearance| {
+            if appearance.blend == target {
+                return false;
+            }
+            appearance.blend = target;
+            true
+        });
+
+        self.report_selection_apply_result(result, "blend mode")
+    }
+}
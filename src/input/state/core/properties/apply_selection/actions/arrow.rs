@@ -96,7 +96,7 @@ impl InputState {
 mod tests {
     use super::*;
     use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
-    use crate::draw::{Color, FontDescriptor};
+    use crate::draw::{Color, FontDescriptor, LineStyle};
     use crate::input::{ClickHighlightSettings, EraserMode};
 
     fn make_state() -> InputState {
@@ -155,6 +155,7 @@ mod tests {
             arrow_angle,
             head_at_end,
             label: None,
+            line_style: LineStyle::Solid,
         })
     }
 
@@ -131,7 +131,7 @@ impl InputState {
 mod tests {
     use super::*;
     use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
-    use crate::draw::FontDescriptor;
+    use crate::draw::{FontDescriptor, LineStyle};
     use crate::input::{ClickHighlightSettings, EraserMode};
 
     fn make_state() -> InputState {
@@ -225,6 +225,8 @@ mod tests {
             fill: false,
             color: crate::draw::BLACK,
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         state.set_selection(vec![rect_id]);
 
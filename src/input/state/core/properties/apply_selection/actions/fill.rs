@@ -55,7 +55,7 @@ impl InputState {
 mod tests {
     use super::*;
     use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
-    use crate::draw::{Color, FontDescriptor};
+    use crate::draw::{Color, FontDescriptor, LineStyle};
     use crate::input::{ClickHighlightSettings, EraserMode};
 
     fn make_state() -> InputState {
@@ -109,6 +109,8 @@ mod tests {
             fill: false,
             color: state.current_color,
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         let ellipse_id = state.boards.active_frame_mut().add_shape(Shape::Ellipse {
             cx: 26,
@@ -118,6 +120,8 @@ mod tests {
             fill: true,
             color: state.current_color,
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         state.set_selection(vec![rect_id, ellipse_id]);
 
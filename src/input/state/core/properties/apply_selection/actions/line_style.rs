@@ -0,0 +1,38 @@
+use crate::draw::LineStyle;
+use crate::input::state::core::base::InputState;
+use crate::input::state::core::properties::utils::cycle_index;
+
+impl InputState {
+    pub(in crate::input::state::core::properties) fn apply_selection_line_style(
+        &mut self,
+        direction: i32,
+    ) -> bool {
+        let frame = self.boards.active_frame();
+        let base_style = self
+            .selected_shape_ids()
+            .iter()
+            .filter_map(|id| frame.shape(*id))
+            .filter(|drawn| !drawn.locked)
+            .find_map(|drawn| drawn.shape.line_style())
+            .unwrap_or_default();
+        let index = LineStyle::ALL
+            .iter()
+            .position(|style| *style == base_style)
+            .unwrap_or(0);
+        let offset = if direction == 0 { 1 } else { direction };
+        let target = LineStyle::ALL[cycle_index(index, LineStyle::ALL.len(), offset)];
+
+        let result = self.apply_selection_change(
+            |shape| shape.line_style().is_some(),
+            |shape| match shape.line_style_mut() {
+                Some(line_style) if *line_style != target => {
+                    *line_style = target;
+                    true
+                }
+                _ => false,
+            },
+        );
+
+        self.report_selection_apply_result(result, "line style")
+    }
+}
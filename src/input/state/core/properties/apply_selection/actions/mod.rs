@@ -1,5 +1,7 @@
+mod appearance;
 mod arrow;
 mod color;
 mod fill;
+mod line_style;
 mod stroke;
 mod text;
@@ -75,7 +75,7 @@ impl InputState {
 mod tests {
     use super::*;
     use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
-    use crate::draw::{Color, FontDescriptor};
+    use crate::draw::{Color, FontDescriptor, LineStyle};
     use crate::input::{ClickHighlightSettings, EraserMode};
 
     fn make_state() -> InputState {
@@ -129,6 +129,8 @@ mod tests {
             fill: false,
             color: state.current_color,
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         state.set_selection(vec![rect_id]);
 
@@ -151,6 +153,7 @@ mod tests {
             font_descriptor: state.font_descriptor.clone(),
             background_enabled: false,
             wrap_width: None,
+            rotation: 0.0,
         });
         state.set_selection(vec![text_id]);
 
@@ -2,6 +2,7 @@ pub(super) const SELECTION_THICKNESS_STEP: f64 = 1.0;
 pub(super) const SELECTION_FONT_SIZE_STEP: f64 = 2.0;
 pub(super) const SELECTION_ARROW_LENGTH_STEP: f64 = 2.0;
 pub(super) const SELECTION_ARROW_ANGLE_STEP: f64 = 2.0;
+pub(super) const SELECTION_OPACITY_STEP: f64 = 0.1;
 pub(super) const MIN_FONT_SIZE: f64 = 8.0;
 pub(super) const MAX_FONT_SIZE: f64 = 72.0;
 pub(super) const MIN_ARROW_LENGTH: f64 = 5.0;
@@ -1,6 +1,7 @@
 use super::super::super::base::InputState;
 use super::super::summary::shape_color;
-use crate::draw::{Color, Shape};
+use crate::draw::{Color, Shape, ShapeAppearance};
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastPriority};
 
 #[derive(Default)]
@@ -143,6 +144,75 @@ impl InputState {
         result
     }
 
+    /// Counterpart of [`Self::apply_selection_change`] for the drawn shape's
+    /// appearance, recorded as restyle steps rather than shape snapshots.
+    pub(super) fn apply_selection_appearance_change<F>(
+        &mut self,
+        mut apply: F,
+    ) -> SelectionApplyResult
+    where
+        F: FnMut(&mut ShapeAppearance) -> bool,
+    {
+        let ids = self.selected_shape_ids().to_vec();
+        let mut result = SelectionApplyResult::default();
+        let mut actions = Vec::new();
+        let mut dirty_regions = Vec::new();
+
+        for id in ids {
+            let frame = self.boards.active_frame_mut();
+            let Some(drawn) = frame.shape_mut(id) else {
+                continue;
+            };
+            if !ShapeAppearance::applies_to(&drawn.shape) {
+                continue;
+            }
+            result.applicable += 1;
+            if drawn.locked {
+                result.locked += 1;
+                continue;
+            }
+
+            let from = drawn.appearance;
+            if !apply(&mut drawn.appearance) || drawn.appearance == from {
+                drawn.appearance = from;
+                continue;
+            }
+            actions.push(crate::draw::frame::UndoAction::Restyle {
+                shape_id: id,
+                from,
+                to: drawn.appearance,
+            });
+            dirty_regions.push((id, drawn.bounding_box()));
+            result.changed += 1;
+        }
+
+        if actions.is_empty() {
+            return result;
+        }
+
+        let undo_action = if actions.len() == 1 {
+            let Some(action) = actions.pop() else {
+                return result;
+            };
+            action
+        } else {
+            crate::draw::frame::UndoAction::Compound { actions }
+        };
+
+        self.boards
+            .active_frame_mut()
+            .push_undo_action(undo_action, self.undo_stack_limit);
+        self.mark_session_dirty();
+
+        for (shape_id, bounds) in dirty_regions {
+            self.mark_selection_dirty_region(bounds);
+            self.invalidate_hit_cache_for(shape_id);
+        }
+        self.needs_redraw = true;
+
+        result
+    }
+
     pub(super) fn report_selection_apply_result(
         &mut self,
         result: SelectionApplyResult,
@@ -152,7 +222,10 @@ impl InputState {
             self.push_toast(
                 ToastPriority::Info,
                 "selection.apply",
-                Toast::warning(format!("No {label} to edit in selection.")),
+                Toast::warning(tr_args(
+                    "No {label} to edit in selection.",
+                    &[("label", label)],
+                )),
             );
             return false;
         }
@@ -162,7 +235,10 @@ impl InputState {
                 self.push_toast(
                     ToastPriority::Info,
                     "selection.apply",
-                    Toast::warning(format!("All {label} shapes are locked.")),
+                    Toast::warning(tr_args(
+                        "All {label} shapes are locked.",
+                        &[("label", label)],
+                    )),
                 );
             } else {
                 self.push_toast(
@@ -178,7 +254,10 @@ impl InputState {
             self.push_toast(
                 ToastPriority::Info,
                 "selection.apply",
-                Toast::warning(format!("{} locked shape(s) unchanged.", result.locked)),
+                Toast::warning(tr_args(
+                    "{count} locked shape(s) unchanged.",
+                    &[("count", &result.locked.to_string())],
+                )),
             );
         }
         true
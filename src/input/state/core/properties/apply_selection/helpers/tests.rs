@@ -1,6 +1,6 @@
 use super::*;
 use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
-use crate::draw::{Color, FontDescriptor};
+use crate::draw::{Color, FontDescriptor, LineStyle};
 use crate::input::{ClickHighlightSettings, EraserMode};
 
 fn make_state() -> InputState {
@@ -60,6 +60,8 @@ fn add_rect(
         fill,
         color,
         thick: 2.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     if locked {
         let index = state
@@ -1,7 +1,8 @@
 use super::super::base::InputState;
 use super::summary::{
-    shape_arrow_angle, shape_arrow_head, shape_arrow_length, shape_color, shape_fill,
-    shape_font_size, shape_text_background, shape_thickness, summarize_property,
+    drawn_blend_mode, drawn_opacity, shape_arrow_angle, shape_arrow_head, shape_arrow_length,
+    shape_color, shape_fill, shape_font_size, shape_line_style, shape_text_background,
+    shape_thickness, summarize_drawn_property, summarize_property,
 };
 use super::types::{SelectionPropertyEntry, SelectionPropertyKind};
 use super::utils::{approx_eq, color_eq, color_label};
@@ -95,6 +96,26 @@ impl InputState {
             }
         }
 
+        let line_style_summary = summarize_property(frame, ids, shape_line_style, |a, b| a == b);
+        if line_style_summary.applicable {
+            let value = if !line_style_summary.editable {
+                "Locked".to_string()
+            } else if line_style_summary.mixed {
+                "Mixed".to_string()
+            } else {
+                line_style_summary
+                    .value
+                    .map(|v| v.label().to_string())
+                    .unwrap_or_else(|| "Mixed".to_string())
+            };
+            entries.push(SelectionPropertyEntry {
+                label: "Line style".to_string(),
+                value,
+                kind: SelectionPropertyKind::LineStyle,
+                disabled: !line_style_summary.editable,
+            });
+        }
+
         let fill_summary = summarize_property(frame, ids, shape_fill, |a, b| a == b);
         if fill_summary.applicable {
             let value = if !fill_summary.editable {
@@ -215,6 +236,70 @@ impl InputState {
             });
         }
 
+        let opacity_summary = summarize_drawn_property(frame, ids, drawn_opacity, approx_eq);
+        if opacity_summary.applicable {
+            let value = if !opacity_summary.editable {
+                "Locked".to_string()
+            } else if opacity_summary.mixed {
+                "Mixed".to_string()
+            } else {
+                opacity_summary
+                    .value
+                    .map(|v| format!("{:.0}%", v * 100.0))
+                    .unwrap_or_else(|| "Mixed".to_string())
+            };
+            entries.push(SelectionPropertyEntry {
+                label: "Opacity".to_string(),
+                value,
+                kind: SelectionPropertyKind::Opacity,
+                disabled: !opacity_summary.editable,
+            });
+        }
+
+        let blend_summary = summarize_drawn_property(frame, ids, drawn_blend_mode, |a, b| a == b);
+        if blend_summary.applicable {
+            let value = if !blend_summary.editable {
+                "Locked".to_string()
+            } else if blend_summary.mixed {
+                "Mixed".to_string()
+            } else {
+                blend_summary
+                    .value
+                    .map(|v| v.label().to_string())
+                    .unwrap_or_else(|| "Mixed".to_string())
+            };
+            entries.push(SelectionPropertyEntry {
+                label: "Blend".to_string(),
+                value,
+                kind: SelectionPropertyKind::BlendMode,
+                disabled: !blend_summary.editable,
+            });
+        }
+
+        // Only worth a row once the page has somewhere else to move shapes.
+        if frame.layers().len() > 1 {
+            let mut layers = ids
+                .iter()
+                .filter_map(|id| frame.shape(*id))
+                .map(|drawn| drawn.layer);
+            if let Some(first) = layers.next() {
+                let value = if layers.all(|layer| layer == first) {
+                    frame
+                        .layer(first)
+                        .map(|layer| layer.name.clone())
+                        .unwrap_or_else(|| "Mixed".to_string())
+                } else {
+                    "Mixed".to_string()
+                };
+                entries.push(SelectionPropertyEntry {
+                    label: "Layer".to_string(),
+                    value,
+                    kind: SelectionPropertyKind::Layer,
+                    disabled: false,
+                });
+            }
+        }
+
         entries
     }
 }
@@ -223,7 +308,7 @@ impl InputState {
 mod tests {
     use super::*;
     use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
-    use crate::draw::{Color, FontDescriptor};
+    use crate::draw::{Color, FontDescriptor, LineStyle};
     use crate::input::{ClickHighlightSettings, EraserMode};
 
     fn make_state() -> InputState {
@@ -289,6 +374,8 @@ mod tests {
                 a: 1.0,
             },
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         let second = state.boards.active_frame_mut().add_shape(Shape::Rect {
             x: 20,
@@ -303,6 +390,8 @@ mod tests {
                 a: 1.0,
             },
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
 
         let entries = state.build_selection_property_entries(&[first, second]);
@@ -324,6 +413,7 @@ mod tests {
             font_descriptor: state.font_descriptor.clone(),
             background_enabled: true,
             wrap_width: None,
+            rotation: 0.0,
         });
         let index = state
             .boards
@@ -356,6 +446,7 @@ mod tests {
             arrow_angle: 35.0,
             head_at_end: true,
             label: None,
+            line_style: LineStyle::Solid,
         });
 
         let entries = state.build_selection_property_entries(&[arrow_id]);
@@ -379,6 +470,7 @@ mod tests {
             arrow_angle: 35.0,
             head_at_end: true,
             label: None,
+            line_style: LineStyle::Solid,
         });
         let second = state.boards.active_frame_mut().add_shape(Shape::Arrow {
             x1: 10,
@@ -391,6 +483,7 @@ mod tests {
             arrow_angle: 35.0,
             head_at_end: false,
             label: None,
+            line_style: LineStyle::Solid,
         });
 
         let entries = state.build_selection_property_entries(&[first, second]);
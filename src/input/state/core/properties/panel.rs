@@ -99,7 +99,7 @@ impl InputState {
             let mut lines = Vec::new();
             lines.push(format!("Shape ID: {shape_id}"));
             lines.push(format!("Type: {}", drawn.shape.kind_name()));
-            lines.push(format!("Layer: {} of {}", index + 1, frame.shapes.len()));
+            lines.push(format!("Stack: {} of {}", index + 1, frame.shapes.len()));
             lines.push(format!(
                 "Locked: {}",
                 if drawn.locked { "Yes" } else { "No" }
@@ -170,7 +170,7 @@ impl InputState {
                 let mut lines = Vec::new();
                 lines.push(format!("Shape ID: {shape_id}"));
                 lines.push(format!("Type: {}", drawn.shape.kind_name()));
-                lines.push(format!("Layer: {} of {}", index + 1, frame.shapes.len()));
+                lines.push(format!("Stack: {} of {}", index + 1, frame.shapes.len()));
                 lines.push(format!(
                     "Locked: {}",
                     if drawn.locked { "Yes" } else { "No" }
@@ -232,7 +232,7 @@ impl InputState {
 mod tests {
     use super::*;
     use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
-    use crate::draw::{Color, FontDescriptor, Shape, ShapeId};
+    use crate::draw::{Color, FontDescriptor, LineStyle, Shape, ShapeId};
     use crate::input::state::SelectionState;
     use crate::input::{ClickHighlightSettings, EraserMode};
     use std::collections::HashSet;
@@ -286,6 +286,8 @@ mod tests {
             fill: false,
             color: state.current_color,
             thick: state.current_thickness,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         })
     }
 
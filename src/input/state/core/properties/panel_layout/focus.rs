@@ -116,7 +116,7 @@ impl InputState {
 mod tests {
     use super::*;
     use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
-    use crate::draw::{Color, FontDescriptor, Shape};
+    use crate::draw::{Color, FontDescriptor, LineStyle, Shape};
     use crate::input::{ClickHighlightSettings, EraserMode};
 
     fn make_state() -> InputState {
@@ -168,6 +168,8 @@ mod tests {
             fill: false,
             color: state.current_color,
             thick: state.current_thickness,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
         state.set_selection(vec![shape_id]);
         assert!(state.show_properties_panel());
@@ -1,4 +1,6 @@
-use crate::draw::{Color, Frame, Shape, ShapeId};
+use crate::draw::{
+    BlendMode, Color, DrawnShape, Frame, LineStyle, Shape, ShapeAppearance, ShapeId,
+};
 
 #[derive(Debug)]
 pub(super) struct PropertySummary<T> {
@@ -12,12 +14,28 @@ pub(super) fn summarize_property<T, F, Eq>(
     frame: &Frame,
     ids: &[ShapeId],
     mut extract: F,
-    mut eq: Eq,
+    eq: Eq,
 ) -> PropertySummary<T>
 where
     T: Clone,
     F: FnMut(&Shape) -> Option<T>,
     Eq: FnMut(&T, &T) -> bool,
+{
+    summarize_drawn_property(frame, ids, |drawn| extract(&drawn.shape), eq)
+}
+
+/// Like [`summarize_property`], for properties stored on the drawn shape
+/// rather than inside the shape itself.
+pub(super) fn summarize_drawn_property<T, F, Eq>(
+    frame: &Frame,
+    ids: &[ShapeId],
+    mut extract: F,
+    mut eq: Eq,
+) -> PropertySummary<T>
+where
+    T: Clone,
+    F: FnMut(&DrawnShape) -> Option<T>,
+    Eq: FnMut(&T, &T) -> bool,
 {
     let mut values = Vec::new();
     let mut applicable = 0;
@@ -25,7 +43,7 @@ where
         let Some(drawn) = frame.shape(*id) else {
             continue;
         };
-        let Some(value) = extract(&drawn.shape) else {
+        let Some(value) = extract(drawn) else {
             continue;
         };
         applicable += 1;
@@ -97,6 +115,10 @@ pub(super) fn shape_thickness(shape: &Shape) -> Option<f64> {
     }
 }
 
+pub(super) fn shape_line_style(shape: &Shape) -> Option<LineStyle> {
+    shape.line_style()
+}
+
 pub(super) fn shape_fill(shape: &Shape) -> Option<bool> {
     match shape {
         Shape::Rect { fill, .. } | Shape::Ellipse { fill, .. } | Shape::Polygon { fill, .. } => {
@@ -143,10 +165,18 @@ pub(super) fn shape_text_background(shape: &Shape) -> Option<bool> {
     }
 }
 
+pub(super) fn drawn_opacity(drawn: &DrawnShape) -> Option<f64> {
+    ShapeAppearance::applies_to(&drawn.shape).then(|| drawn.appearance.clamped_opacity())
+}
+
+pub(super) fn drawn_blend_mode(drawn: &DrawnShape) -> Option<BlendMode> {
+    ShapeAppearance::applies_to(&drawn.shape).then_some(drawn.appearance.blend)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::draw::FontDescriptor;
+    use crate::draw::{FontDescriptor, LineStyle};
     use crate::input::state::core::properties::utils::color_eq;
 
     fn rect(color: Color, fill: bool, thick: f64) -> Shape {
@@ -158,6 +188,8 @@ mod tests {
             fill,
             color,
             thick,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         }
     }
 
@@ -178,6 +210,7 @@ mod tests {
             font_descriptor: FontDescriptor::default(),
             background_enabled: false,
             wrap_width: None,
+            rotation: 0.0,
         });
 
         let summary = summarize_property(&frame, &[text_id], shape_fill, |a, b| a == b);
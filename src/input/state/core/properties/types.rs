@@ -4,12 +4,16 @@ use crate::util::Rect;
 pub enum SelectionPropertyKind {
     Color,
     Thickness,
+    LineStyle,
     Fill,
     FontSize,
     ArrowHead,
     ArrowLength,
     ArrowAngle,
     TextBackground,
+    Opacity,
+    BlendMode,
+    Layer,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -50,7 +50,8 @@ impl CompassDir {
     }
 }
 
-/// A sub-ring-owning parent slice on the compass.
+/// A sub-ring owner on the compass: a parent slice, or the companion ring
+/// of an action slice.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RadialParent {
     /// E: the shape family (children derived from the toolbar's
@@ -58,6 +59,8 @@ pub enum RadialParent {
     Shapes,
     /// SW: numbered step markers and sticky notes.
     Notes,
+    /// NE companions: presenting aids that sit with the marker.
+    Present,
 }
 
 impl RadialParent {
@@ -67,6 +70,7 @@ impl RadialParent {
         match self {
             RadialParent::Shapes => "Shapes",
             RadialParent::Notes => "Notes",
+            RadialParent::Present => "Present",
         }
     }
 
@@ -76,6 +80,7 @@ impl RadialParent {
         match self {
             RadialParent::Shapes => shapes_children(),
             RadialParent::Notes => &NOTES_CHILDREN,
+            RadialParent::Present => &PRESENT_CHILDREN,
         }
     }
 }
@@ -83,6 +88,10 @@ impl RadialParent {
 /// SW sub-ring: step markers first, then sticky notes.
 const NOTES_CHILDREN: [Action; 2] = [Action::SelectStepMarkerTool, Action::EnterStickyNoteMode];
 
+/// NE companion ring, revealed by hovering the marker: the laser pointer,
+/// then the magnifier lens.
+const PRESENT_CHILDREN: [Action; 2] = [Action::SelectLaserTool, Action::ToggleMagnifier];
+
 /// E sub-ring, derived from the toolbar's `shape_tools()` order (the shapes
 /// source of truth) filtered to the radial-eligible members: Arrow already
 /// owns the SE compass slice, and the exotic polygon variants stay behind
@@ -121,43 +130,55 @@ pub struct RadialSlice {
     pub dir: CompassDir,
     /// What selecting the slice does.
     pub kind: RadialSliceKind,
+    /// Companion sub-ring of an action slice: hovering the wedge reveals it,
+    /// while selecting or flicking the wedge still dispatches the action.
+    pub companions: Option<RadialParent>,
 }
 
-/// The fixed compass: N Pen, NE Marker, E Shapes, SE Arrow, S Select,
-/// SW Notes (step marker + sticky note), W Text, NW Eraser. Standalone Line
-/// lives in the Shapes sub-ring; history/clear actions are not on the ring.
+/// The fixed compass: N Pen, NE Marker (with the laser pointer and magnifier
+/// as companions), E Shapes, SE Arrow, S Select, SW Notes (step marker +
+/// sticky note), W Text, NW Eraser. Standalone Line lives in the Shapes
+/// sub-ring; history/clear actions are not on the ring.
 pub const COMPASS_SLICES: [RadialSlice; TOOL_SEGMENT_COUNT] = [
     RadialSlice {
         dir: CompassDir::N,
         kind: RadialSliceKind::Action(Action::SelectPenTool),
+        companions: None,
     },
     RadialSlice {
         dir: CompassDir::NE,
         kind: RadialSliceKind::Action(Action::SelectMarkerTool),
+        companions: Some(RadialParent::Present),
     },
     RadialSlice {
         dir: CompassDir::E,
         kind: RadialSliceKind::Parent(RadialParent::Shapes),
+        companions: None,
     },
     RadialSlice {
         dir: CompassDir::SE,
         kind: RadialSliceKind::Action(Action::SelectArrowTool),
+        companions: None,
     },
     RadialSlice {
         dir: CompassDir::S,
         kind: RadialSliceKind::Action(Action::SelectSelectionTool),
+        companions: None,
     },
     RadialSlice {
         dir: CompassDir::SW,
         kind: RadialSliceKind::Parent(RadialParent::Notes),
+        companions: None,
     },
     RadialSlice {
         dir: CompassDir::W,
         kind: RadialSliceKind::Action(Action::EnterTextMode),
+        companions: None,
     },
     RadialSlice {
         dir: CompassDir::NW,
         kind: RadialSliceKind::Action(Action::SelectEraserTool),
+        companions: None,
     },
 ];
 
@@ -166,14 +187,22 @@ pub fn compass_slice(idx: u8) -> Option<&'static RadialSlice> {
     COMPASS_SLICES.get(idx as usize)
 }
 
-/// Parent at a segment index, if that slice expands a sub-ring.
+/// Sub-ring owner at a segment index, if hovering that slice expands one
+/// (a parent slice or an action slice's companions).
 pub fn slice_parent(idx: u8) -> Option<RadialParent> {
-    match compass_slice(idx)?.kind {
+    let slice = compass_slice(idx)?;
+    match slice.kind {
         RadialSliceKind::Parent(parent) => Some(parent),
-        RadialSliceKind::Action(_) => None,
+        RadialSliceKind::Action(_) => slice.companions,
     }
 }
 
+/// Whether the slice at a segment index is a parent, which expands its
+/// sub-ring on selection instead of dispatching an action.
+pub fn is_parent_slice(idx: u8) -> bool {
+    compass_slice(idx).is_some_and(|slice| matches!(slice.kind, RadialSliceKind::Parent(_)))
+}
+
 /// Sub-ring children for a segment index (empty for non-parent slices).
 pub fn sub_ring_children(parent_idx: u8) -> &'static [Action] {
     slice_parent(parent_idx)
@@ -8,7 +8,7 @@ use std::time::{Duration, Instant};
 
 pub use compass::{
     COMPASS_SLICES, CompassDir, RadialParent, RadialSlice, RadialSliceKind, TOOL_SEGMENT_COUNT,
-    compass_slice, slice_parent, sub_ring_child_count, sub_ring_children,
+    compass_slice, is_parent_slice, slice_parent, sub_ring_child_count, sub_ring_children,
 };
 pub use size_ring::{
     SIZE_RING_ARC_SPAN, SIZE_RING_ARC_START, size_ring_angle_for_value, size_ring_value_for_angle,
@@ -35,7 +35,7 @@ pub enum RadialMenuState {
         /// Currently hovered segment (if any).
         hover: Option<RadialSegmentId>,
         /// Expanded sub-ring parent segment index (compass index:
-        /// Shapes = 2 / E, Notes = 5 / SW).
+        /// Shapes = 2 / E, Notes = 5 / SW, Present = 1 / NE).
         expanded_sub_ring: Option<u8>,
         /// When the menu opened; painting starts at
         /// `opened_at + RADIAL_PAINT_DELAY`.
@@ -3,9 +3,7 @@ use std::time::{Duration, Instant};
 use super::layout::CENTER_RADIUS;
 use super::size_ring::size_ring_value_for_angle;
 use super::{RADIAL_PAINT_DELAY, RadialMenuState, RadialRingSwatch, RadialSegmentId};
-use super::{
-    RadialSliceKind, compass_slice, slice_parent, sub_ring_child_count, sub_ring_children,
-};
+use super::{RadialSliceKind, compass_slice, is_parent_slice, slice_parent, sub_ring_children};
 use crate::input::events::MouseButton;
 use crate::input::state::InputState;
 
@@ -150,7 +148,8 @@ impl InputState {
 
             // Expand/collapse sub-ring based on hovered segment
             match segment {
-                // Hovering a parent slice expands its sub-ring
+                // Hovering a parent slice (or one with companions) expands
+                // its sub-ring
                 Some(RadialSegmentId::Tool(idx)) if slice_parent(idx).is_some() => {
                     *expanded_sub_ring = Some(idx);
                 }
@@ -192,7 +191,7 @@ impl InputState {
         };
 
         match hover {
-            Some(RadialSegmentId::Tool(idx)) if sub_ring_child_count(idx) > 0 => {
+            Some(RadialSegmentId::Tool(idx)) if is_parent_slice(idx) => {
                 // Parent with children — expand sub-ring, don't close
                 self.radial_menu_expand_sub_ring(idx);
                 return;
@@ -333,7 +332,7 @@ impl InputState {
         // Direction-only commit (the blind-flick path, also the fallback for
         // sighted releases outside the sub-ring/color bands).
         let idx = super::hit_test::primary_segment_for_point(cx, cy, x, y);
-        if slice_parent(idx).is_some() {
+        if is_parent_slice(idx) {
             self.radial_menu_expand_sub_ring(idx);
             return true;
         }
@@ -0,0 +1,28 @@
+//! Overlay recording indicator.
+//!
+//! The backend owns the recording worker and its frame clock; input state only
+//! mirrors when the current recording started so the status HUD can show the
+//! REC badge with its elapsed time.
+
+use std::time::{Duration, Instant};
+
+use super::base::InputState;
+
+impl InputState {
+    pub fn recording_active(&self) -> bool {
+        self.recording_started_at.is_some()
+    }
+
+    /// Time since the current recording started, `None` when not recording.
+    pub fn recording_elapsed(&self) -> Option<Duration> {
+        self.recording_started_at.map(|started| started.elapsed())
+    }
+
+    pub(crate) fn set_recording_started_at(&mut self, started_at: Option<Instant>) {
+        if self.recording_started_at == started_at {
+            return;
+        }
+        self.recording_started_at = started_at;
+        self.needs_redraw = true;
+    }
+}
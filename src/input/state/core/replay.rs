@@ -0,0 +1,295 @@
+//! Replay mode: re-draws the active page shape by shape in creation order.
+//!
+//! The replay works on a snapshot of the page taken when it starts, so the
+//! canvas renders [`InputState::canvas_frame`] instead of the live page while
+//! it runs. Any edit would land on a page the user cannot see, so replay
+//! consumes canvas keys and presses and other actions end it first.
+
+use std::time::Instant;
+
+use crate::draw::frame::{Frame, ReplayTimeline};
+use crate::input::events::Key;
+use crate::ui::replay_bar_layout;
+
+use super::base::{InputState, Toast, ToastPriority};
+use super::modal::ModalSurface;
+
+/// Shapes drawn per second at 1× speed.
+const REPLAY_SHAPES_PER_SECOND: f64 = 2.0;
+/// Playback speeds, slowest first.
+const REPLAY_SPEEDS: [f64; 7] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0, 16.0];
+const DEFAULT_SPEED_INDEX: usize = 2;
+
+/// Playback state of a running replay.
+#[derive(Debug, Clone)]
+pub struct ReplayPlayback {
+    timeline: ReplayTimeline,
+    frame: Frame,
+    position: f64,
+    speed_index: usize,
+    playing: bool,
+    last_tick: Option<Instant>,
+    scrubbing: bool,
+}
+
+impl ReplayPlayback {
+    fn new(timeline: ReplayTimeline) -> Self {
+        let frame = timeline.frame_at(0.0);
+        Self {
+            timeline,
+            frame,
+            position: 0.0,
+            speed_index: DEFAULT_SPEED_INDEX,
+            playing: true,
+            last_tick: None,
+            scrubbing: false,
+        }
+    }
+
+    /// Number of shapes on the replayed page.
+    pub fn len(&self) -> usize {
+        self.timeline.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.timeline.is_empty()
+    }
+
+    pub fn playing(&self) -> bool {
+        self.playing
+    }
+
+    /// Position in shapes, between 0 and [`Self::len`].
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    /// Number of shapes fully drawn at the current position.
+    pub fn shapes_shown(&self) -> usize {
+        self.position.floor() as usize
+    }
+
+    /// Fraction of the replay already played, from 0 to 1.
+    pub fn progress(&self) -> f64 {
+        if self.is_empty() {
+            return 1.0;
+        }
+        self.position / self.len() as f64
+    }
+
+    /// Playback speed multiplier.
+    pub fn speed(&self) -> f64 {
+        REPLAY_SPEEDS[self.speed_index]
+    }
+
+    fn at_end(&self) -> bool {
+        self.position >= self.len() as f64
+    }
+
+    /// Moves to `position`, returning whether the visible page changed.
+    fn seek(&mut self, position: f64) -> bool {
+        let position = position.clamp(0.0, self.len() as f64);
+        if position == self.position {
+            return false;
+        }
+        self.position = position;
+        self.frame = self.timeline.frame_at(position);
+        true
+    }
+
+    fn set_playing(&mut self, playing: bool) {
+        self.playing = playing;
+        self.last_tick = None;
+    }
+}
+
+impl InputState {
+    pub fn replay(&self) -> Option<&ReplayPlayback> {
+        self.replay.as_ref()
+    }
+
+    pub fn replay_active(&self) -> bool {
+        self.replay.is_some()
+    }
+
+    /// The page the canvas shows: the replayed page while a replay runs,
+    /// otherwise the active page.
+    pub fn canvas_frame(&self) -> &Frame {
+        match &self.replay {
+            Some(replay) => &replay.frame,
+            None => self.boards.active_frame(),
+        }
+    }
+
+    /// Starts replaying the active page from an empty canvas.
+    pub fn start_replay(&mut self) {
+        if self.replay.is_some() {
+            return;
+        }
+        let timeline = ReplayTimeline::new(self.boards.active_frame());
+        if timeline.is_empty() {
+            self.push_toast(
+                ToastPriority::Info,
+                "replay",
+                Toast::info("Nothing to replay on this page"),
+            );
+            return;
+        }
+        self.cancel_active_interaction();
+        self.clear_selection();
+        for surface in ModalSurface::ALL {
+            if self.modal_is_open(surface) {
+                self.close_modal(surface);
+            }
+        }
+        self.replay = Some(ReplayPlayback::new(timeline));
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    /// Leaves replay mode and shows the live page again.
+    pub fn stop_replay(&mut self) {
+        if self.replay.take().is_some() {
+            self.dirty_tracker.mark_full();
+            self.needs_redraw = true;
+        }
+    }
+
+    pub fn toggle_replay(&mut self) {
+        if self.replay.is_some() {
+            self.stop_replay();
+        } else {
+            self.start_replay();
+        }
+    }
+
+    /// Advances a playing replay to `now`; returns true while it keeps playing.
+    pub fn advance_replay(&mut self, now: Instant) -> bool {
+        let Some(replay) = self.replay.as_mut() else {
+            return false;
+        };
+        if !replay.playing {
+            return false;
+        }
+        let elapsed = replay
+            .last_tick
+            .map(|last| now.saturating_duration_since(last).as_secs_f64())
+            .unwrap_or(0.0);
+        replay.last_tick = Some(now);
+        let changed =
+            replay.seek(replay.position + elapsed * REPLAY_SHAPES_PER_SECOND * replay.speed());
+        if replay.at_end() {
+            replay.set_playing(false);
+        }
+        let playing = replay.playing;
+        if changed || !playing {
+            self.dirty_tracker.mark_full();
+            self.needs_redraw = true;
+        }
+        playing
+    }
+
+    /// Jumps the replay to `position` shapes in and pauses it.
+    pub fn seek_replay(&mut self, position: f64) {
+        let Some(replay) = self.replay.as_mut() else {
+            return;
+        };
+        replay.set_playing(false);
+        replay.seek(position);
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    fn toggle_replay_playing(&mut self) {
+        let Some(replay) = self.replay.as_mut() else {
+            return;
+        };
+        if replay.playing {
+            replay.set_playing(false);
+        } else {
+            if replay.at_end() {
+                replay.seek(0.0);
+            }
+            replay.set_playing(true);
+        }
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    fn change_replay_speed(&mut self, faster: bool) {
+        let Some(replay) = self.replay.as_mut() else {
+            return;
+        };
+        replay.speed_index = if faster {
+            (replay.speed_index + 1).min(REPLAY_SPEEDS.len() - 1)
+        } else {
+            replay.speed_index.saturating_sub(1)
+        };
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+
+    /// Handles a key press while a replay runs. Returns true if the key was
+    /// handled; every key is consumed so nothing edits the hidden live page.
+    pub(crate) fn handle_replay_key(&mut self, key: Key) -> bool {
+        let Some(replay) = self.replay.as_ref() else {
+            return false;
+        };
+        let position = replay.position;
+        let len = replay.len() as f64;
+        match key {
+            Key::Escape => self.stop_replay(),
+            Key::Space | Key::Return => self.toggle_replay_playing(),
+            Key::Left => self.seek_replay((position.ceil() - 1.0).max(0.0)),
+            Key::Right => self.seek_replay(position.floor() + 1.0),
+            Key::Home => self.seek_replay(0.0),
+            Key::End => self.seek_replay(len),
+            Key::Up => self.change_replay_speed(true),
+            Key::Down => self.change_replay_speed(false),
+            _ => {}
+        }
+        true
+    }
+
+    /// Handles a pointer press while a replay runs: the button toggles
+    /// playback and the track seeks. Every press is consumed.
+    pub(crate) fn handle_replay_press(&mut self, x: f64, y: f64) -> bool {
+        let Some(replay) = self.replay.as_ref() else {
+            return false;
+        };
+        let len = replay.len() as f64;
+        let layout = replay_bar_layout(self.screen_width, self.screen_height);
+        if layout.button_contains(x, y) {
+            self.toggle_replay_playing();
+        } else if let Some(fraction) = layout.track_fraction_at(x, y) {
+            self.seek_replay(fraction * len);
+            if let Some(replay) = self.replay.as_mut() {
+                replay.scrubbing = true;
+            }
+        }
+        true
+    }
+
+    /// Follows a scrub drag started on the replay track. Returns true while
+    /// the pointer belongs to the replay.
+    pub(crate) fn handle_replay_motion(&mut self, x: f64) -> bool {
+        let Some(replay) = self.replay.as_ref() else {
+            return false;
+        };
+        if replay.scrubbing {
+            let len = replay.len() as f64;
+            let layout = replay_bar_layout(self.screen_width, self.screen_height);
+            self.seek_replay(layout.track_fraction_for_x(x) * len);
+        }
+        true
+    }
+
+    /// Ends a scrub drag. Returns true while the pointer belongs to the replay.
+    pub(crate) fn handle_replay_release(&mut self) -> bool {
+        let Some(replay) = self.replay.as_mut() else {
+            return false;
+        };
+        replay.scrubbing = false;
+        true
+    }
+}
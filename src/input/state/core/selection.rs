@@ -1,7 +1,8 @@
 use super::base::InputState;
 use crate::draw::ShapeId;
+use crate::draw::frame::GroupId;
 use crate::util::Rect;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Debug, Clone)]
 pub enum SelectionState {
@@ -41,6 +42,7 @@ impl InputState {
     }
 
     pub fn set_selection(&mut self, ids: Vec<ShapeId>) {
+        let ids = self.with_group_members(ids);
         if ids.is_empty() {
             self.selection_state = SelectionState::None;
             self.last_selection_axis = None;
@@ -67,6 +69,7 @@ impl InputState {
     where
         I: IntoIterator<Item = ShapeId>,
     {
+        let iter = self.with_group_members(iter.into_iter().collect());
         match &mut self.selection_state {
             SelectionState::Active {
                 shape_ids,
@@ -87,6 +90,40 @@ impl InputState {
         }
     }
 
+    /// Adds the rest of each shape's group right after it.
+    ///
+    /// Every way of selecting — click, shift-click, marquee, select-all, undo
+    /// restoring a selection — funnels through here, so a group can only ever
+    /// be selected whole.
+    fn with_group_members(&self, ids: Vec<ShapeId>) -> Vec<ShapeId> {
+        let frame = self.boards.active_frame();
+        if !frame.shapes.iter().any(|shape| shape.group.is_some()) {
+            return ids;
+        }
+        let mut members: HashMap<GroupId, Vec<ShapeId>> = HashMap::new();
+        for shape in &frame.shapes {
+            if let Some(group) = shape.group {
+                members.entry(group).or_default().push(shape.id);
+            }
+        }
+        let mut seen = HashSet::with_capacity(ids.len());
+        let mut expanded = Vec::with_capacity(ids.len());
+        for id in ids {
+            if !seen.insert(id) {
+                continue;
+            }
+            expanded.push(id);
+            if let Some(group) = frame.shape(id).and_then(|shape| shape.group) {
+                for member in members.get(&group).into_iter().flatten() {
+                    if seen.insert(*member) {
+                        expanded.push(*member);
+                    }
+                }
+            }
+        }
+        expanded
+    }
+
     pub(crate) fn selection_bounding_box(&self, ids: &[ShapeId]) -> Option<Rect> {
         let frame = self.boards.active_frame();
         let mut min_x = i32::MAX;
@@ -0,0 +1,312 @@
+//! Snapped selection drags with alignment guides, and the align/distribute
+//! actions for multi-selections.
+
+use crate::draw::frame::GroupId;
+use crate::draw::{AlignmentGuide, BoardGrid, ShapeId};
+use crate::input::InputState;
+use crate::input::state::{Toast, ToastPriority};
+use crate::util::Rect;
+
+/// How close, in canvas pixels, a dragged edge or centre has to come to
+/// another shape's before the selection snaps onto it.
+const GUIDE_SNAP_DISTANCE: i32 = 6;
+
+/// Line the selected shapes are lined up on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SelectionAlignment {
+    Left,
+    Center,
+    Right,
+    Top,
+    Middle,
+    Bottom,
+}
+
+impl SelectionAlignment {
+    fn horizontal(self) -> bool {
+        matches!(self, Self::Left | Self::Center | Self::Right)
+    }
+
+    /// Which of [`axis_lines`] the alignment lines up.
+    fn line_index(self) -> usize {
+        match self {
+            Self::Left | Self::Top => 0,
+            Self::Center | Self::Middle => 1,
+            Self::Right | Self::Bottom => 2,
+        }
+    }
+}
+
+/// A shape, or the selected members of a group, moved as one piece by the
+/// align and distribute actions.
+struct AlignUnit {
+    ids: Vec<ShapeId>,
+    bounds: Rect,
+}
+
+/// Start edge, centre, and end edge of `rect` along one axis.
+fn axis_lines(rect: &Rect, horizontal: bool) -> [i32; 3] {
+    if horizontal {
+        [rect.x, rect.x + rect.width / 2, rect.x + rect.width]
+    } else {
+        [rect.y, rect.y + rect.height / 2, rect.y + rect.height]
+    }
+}
+
+/// Offset that snaps `target` along one axis: onto the nearest edge or
+/// centre of another shape within reach, else onto the grid. Returns the
+/// offset and, for a shape match, the line and the shape's bounds.
+fn snap_axis(
+    target: &Rect,
+    others: &[Rect],
+    grid: Option<BoardGrid>,
+    horizontal: bool,
+) -> (i32, Option<(i32, Rect)>) {
+    let lines = axis_lines(target, horizontal);
+    let mut best: Option<(i32, i32, Rect)> = None;
+    for other in others {
+        for other_line in axis_lines(other, horizontal) {
+            for line in lines {
+                let offset = other_line - line;
+                if offset.abs() <= GUIDE_SNAP_DISTANCE
+                    && best.is_none_or(|(best_offset, _, _)| offset.abs() < best_offset.abs())
+                {
+                    best = Some((offset, other_line, *other));
+                }
+            }
+        }
+    }
+    match (best, grid) {
+        (Some((offset, line, other)), _) => (offset, Some((line, other))),
+        (None, Some(grid)) => (grid.snap(lines[0] as f64) as i32 - lines[0], None),
+        (None, None) => (0, None),
+    }
+}
+
+fn guide_rect(guide: &AlignmentGuide) -> Option<Rect> {
+    match *guide {
+        AlignmentGuide::Vertical { x, y0, y1 } => Rect::from_min_max(x - 1, y0, x + 2, y1 + 1),
+        AlignmentGuide::Horizontal { y, x0, x1 } => Rect::from_min_max(x0, y - 1, x1 + 1, y + 2),
+    }
+}
+
+impl InputState {
+    /// Moves the selection by one pointer step of a drag, snapping it to the
+    /// grid and to other shapes' edges and centres and recording the guides
+    /// to show.
+    ///
+    /// Returns `None` when the step was not used, so the caller keeps
+    /// accumulating it, and otherwise whether any shape moved.
+    pub(crate) fn drag_selection_by(&mut self, dx: i32, dy: i32) -> Option<bool> {
+        let grid = self.snap_grid();
+        let guides_enabled = self.alignment_guides_enabled();
+        if grid.is_none() && !guides_enabled {
+            return self.apply_translation_to_selection(dx, dy).then_some(true);
+        }
+        let bounds = self.movable_selection_bounds()?;
+
+        let (residual_x, residual_y) = self.selection_drag_residual;
+        let (want_x, want_y) = (dx + residual_x, dy + residual_y);
+        let target = Rect {
+            x: bounds.x + want_x,
+            y: bounds.y + want_y,
+            ..bounds
+        };
+        let others = if guides_enabled {
+            self.alignment_targets()
+        } else {
+            Vec::new()
+        };
+        let (snap_x, match_x) = snap_axis(&target, &others, grid, true);
+        let (snap_y, match_y) = snap_axis(&target, &others, grid, false);
+
+        let moved = self.apply_translation_to_selection(want_x + snap_x, want_y + snap_y);
+        let after = self.movable_selection_bounds().unwrap_or(bounds);
+        self.selection_drag_residual =
+            (want_x - (after.x - bounds.x), want_y - (after.y - bounds.y));
+
+        let mut guides = Vec::new();
+        if let Some((x, other)) = match_x
+            && axis_lines(&after, true).contains(&x)
+        {
+            guides.push(AlignmentGuide::Vertical {
+                x,
+                y0: after.y.min(other.y),
+                y1: (after.y + after.height).max(other.y + other.height),
+            });
+        }
+        if let Some((y, other)) = match_y
+            && axis_lines(&after, false).contains(&y)
+        {
+            guides.push(AlignmentGuide::Horizontal {
+                y,
+                x0: after.x.min(other.x),
+                x1: (after.x + after.width).max(other.x + other.width),
+            });
+        }
+        self.set_alignment_guides(guides);
+        Some(moved)
+    }
+
+    /// Forgets the guides and leftover travel of a finished selection drag.
+    pub(crate) fn end_selection_drag_snapping(&mut self) {
+        self.selection_drag_residual = (0, 0);
+        self.set_alignment_guides(Vec::new());
+    }
+
+    /// Lines up the selected shapes on the left, centre, or right (or top,
+    /// middle, bottom) line of the selection's bounds, as one undo step.
+    /// Grouped shapes move together.
+    pub(crate) fn align_selection(&mut self, alignment: SelectionAlignment) -> bool {
+        let units = self.alignment_units();
+        let Some(bounds) = self.movable_selection_bounds().filter(|_| units.len() >= 2) else {
+            self.push_toast(
+                ToastPriority::Info,
+                "selection-align",
+                Toast::info("Select at least two shapes to align."),
+            );
+            return false;
+        };
+        let horizontal = alignment.horizontal();
+        let target = axis_lines(&bounds, horizontal)[alignment.line_index()];
+        let moves: Vec<_> = units
+            .into_iter()
+            .map(|unit| {
+                let offset = target - axis_lines(&unit.bounds, horizontal)[alignment.line_index()];
+                (unit.ids, offset)
+            })
+            .collect();
+        self.move_alignment_units(moves, horizontal)
+    }
+
+    /// Spaces the selected shapes evenly between the two outermost ones,
+    /// left to right or top to bottom, as one undo step.
+    pub(crate) fn distribute_selection(&mut self, horizontal: bool) -> bool {
+        let mut units = self.alignment_units();
+        if units.len() < 3 {
+            self.push_toast(
+                ToastPriority::Info,
+                "selection-align",
+                Toast::info("Select at least three shapes to distribute."),
+            );
+            return false;
+        }
+        units.sort_by_key(|unit| axis_lines(&unit.bounds, horizontal)[0]);
+
+        let size = |unit: &AlignUnit| {
+            let [start, _, end] = axis_lines(&unit.bounds, horizontal);
+            i64::from(end - start)
+        };
+        let first = i64::from(axis_lines(&units[0].bounds, horizontal)[0]);
+        let last = &units[units.len() - 1];
+        let span = i64::from(axis_lines(&last.bounds, horizontal)[0]) + size(last) - first;
+        let gaps = (units.len() - 1) as i64;
+        let free = span - units.iter().map(size).sum::<i64>();
+
+        let mut before = 0i64;
+        let mut moves = Vec::with_capacity(units.len());
+        for (index, unit) in units.into_iter().enumerate() {
+            let start = first + before + free * index as i64 / gaps;
+            before += size(&unit);
+            let current = i64::from(axis_lines(&unit.bounds, horizontal)[0]);
+            let offset = i32::try_from(start - current).unwrap_or(0);
+            moves.push((unit.ids, offset));
+        }
+        self.move_alignment_units(moves, horizontal)
+    }
+
+    /// Bounds of the editable shapes outside the selection that a dragged
+    /// selection can line up with.
+    fn alignment_targets(&self) -> Vec<Rect> {
+        let frame = self.boards.active_frame();
+        let selected = self.selected_shape_ids();
+        frame
+            .shapes
+            .iter()
+            .filter(|drawn| frame.is_shape_editable(drawn) && !selected.contains(&drawn.id))
+            .filter_map(|drawn| drawn.bounding_box())
+            .collect()
+    }
+
+    /// The unlocked selected shapes, grouped shapes merged into one unit.
+    fn alignment_units(&self) -> Vec<AlignUnit> {
+        let frame = self.boards.active_frame();
+        let mut units: Vec<(Option<GroupId>, AlignUnit)> = Vec::new();
+        for id in self.selected_shape_ids() {
+            let Some(drawn) = frame.shape(*id).filter(|drawn| !drawn.locked) else {
+                continue;
+            };
+            let Some(bounds) = drawn.bounding_box() else {
+                continue;
+            };
+            let existing = drawn.group.and_then(|group| {
+                units
+                    .iter_mut()
+                    .find(|(unit_group, _)| *unit_group == Some(group))
+            });
+            match existing {
+                Some((_, unit)) => {
+                    unit.ids.push(*id);
+                    unit.bounds = Rect::from_min_max(
+                        unit.bounds.x.min(bounds.x),
+                        unit.bounds.y.min(bounds.y),
+                        (unit.bounds.x + unit.bounds.width).max(bounds.x + bounds.width),
+                        (unit.bounds.y + unit.bounds.height).max(bounds.y + bounds.height),
+                    )
+                    .unwrap_or(unit.bounds);
+                }
+                None => units.push((
+                    drawn.group,
+                    AlignUnit {
+                        ids: vec![*id],
+                        bounds,
+                    },
+                )),
+            }
+        }
+        units.into_iter().map(|(_, unit)| unit).collect()
+    }
+
+    fn move_alignment_units(&mut self, moves: Vec<(Vec<ShapeId>, i32)>, horizontal: bool) -> bool {
+        let before = self.capture_movable_selection_snapshots();
+        let mut moved_any = false;
+        for (ids, offset) in moves {
+            if offset == 0 {
+                continue;
+            }
+            let (dx, dy) = if horizontal { (offset, 0) } else { (0, offset) };
+            for id in ids {
+                let bounds = {
+                    let Some(drawn) = self.boards.active_frame_mut().shape_mut(id) else {
+                        continue;
+                    };
+                    let before = drawn.bounding_box();
+                    Self::translate_shape(&mut drawn.shape, dx, dy);
+                    drawn.invalidate_bounds();
+                    (before, drawn.bounding_box())
+                };
+                self.mark_selection_dirty_region(bounds.0);
+                self.mark_selection_dirty_region(bounds.1);
+                self.invalidate_hit_cache_for(id);
+                moved_any = true;
+            }
+        }
+        if !moved_any {
+            return false;
+        }
+        self.reroute_connectors();
+        self.needs_redraw = true;
+        self.push_translation_undo(before)
+    }
+
+    fn set_alignment_guides(&mut self, guides: Vec<AlignmentGuide>) {
+        if guides == self.alignment_guides {
+            return;
+        }
+        for guide in self.alignment_guides.iter().chain(&guides) {
+            self.dirty_tracker.mark_optional_rect(guide_rect(guide));
+        }
+        self.alignment_guides = guides;
+        self.needs_redraw = true;
+    }
+}
@@ -1,13 +1,15 @@
 use super::super::base::{
-    ClipboardFingerprint, ClipboardPasteRequest, InputState, PasteAnchor,
+    ClipboardFingerprint, ClipboardPasteRequest, ClipboardShape, InputState, PasteAnchor,
     PendingSelectionClipboardPublish, SelectionPublishState, WayscriberClipboardSelection,
 };
-use crate::draw::Shape;
-use crate::draw::frame::UndoAction;
+use crate::draw::frame::{GroupId, ShapeAppearance, UndoAction};
+use crate::draw::{Frame, ShapeId};
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastPriority};
 use crate::util::Rect;
+use std::collections::HashMap;
 
-const PRIVATE_CLIPBOARD_SCHEMA_VERSION: u32 = 1;
+const PRIVATE_CLIPBOARD_SCHEMA_VERSION: u32 = 2;
 
 mod duplicate;
 mod image_paste;
@@ -15,7 +17,7 @@ mod image_paste;
 #[allow(dead_code)]
 impl InputState {
     pub(crate) fn copy_selection(&mut self) -> usize {
-        let copied = {
+        let (copied, excerpt) = {
             let ids = self.selected_shape_ids();
             if ids.is_empty() {
                 return 0;
@@ -23,15 +25,21 @@ impl InputState {
 
             let frame = self.boards.active_frame();
             let mut copied = Vec::new();
+            let mut copied_ids = Vec::new();
             for id in ids {
                 if let Some(shape) = frame.shape(*id) {
                     if shape.locked {
                         continue;
                     }
-                    copied.push(shape.shape.clone());
+                    copied.push(ClipboardShape {
+                        shape: shape.shape.clone(),
+                        appearance: shape.appearance,
+                        group: shape.group,
+                    });
+                    copied_ids.push(*id);
                 }
             }
-            copied
+            (copied, frame.excerpt(&copied_ids))
         };
 
         if copied.is_empty() {
@@ -49,6 +57,12 @@ impl InputState {
                     PendingSelectionClipboardPublish {
                         generation: payload.copy_generation,
                         payload_json,
+                        shapes: payload
+                            .shapes
+                            .into_iter()
+                            .map(|entry| entry.shape)
+                            .collect(),
+                        frame: excerpt,
                     }
                 })
             });
@@ -57,7 +71,7 @@ impl InputState {
 
     fn selection_clipboard_payload(
         &self,
-        shapes: Vec<Shape>,
+        shapes: Vec<ClipboardShape>,
     ) -> Option<WayscriberClipboardSelection> {
         if shapes.is_empty() {
             return None;
@@ -90,13 +104,24 @@ impl InputState {
         let mut created = Vec::new();
         let mut new_ids = Vec::new();
         let mut limit_hit = false;
+        let mut pasted_groups = HashMap::new();
 
-        for shape in shapes {
-            let mut cloned_shape = shape;
+        for entry in shapes {
+            let mut cloned_shape = entry.shape;
             Self::translate_shape(&mut cloned_shape, dx, dy);
             let new_id = {
                 let frame = self.boards.active_frame_mut();
-                frame.try_add_shape_with_id(cloned_shape, self.max_shapes_per_frame)
+                let new_id = frame.try_add_shape_with_id(cloned_shape, self.max_shapes_per_frame);
+                if let Some(new_id) = new_id {
+                    restore_pasted_shape(
+                        frame,
+                        new_id,
+                        entry.appearance,
+                        entry.group,
+                        &mut pasted_groups,
+                    );
+                }
+                new_id
             };
 
             let Some(new_id) = new_id else {
@@ -140,8 +165,12 @@ impl InputState {
             self.push_toast(
                 ToastPriority::Info,
                 "selection.clipboard",
-                Toast::warning(format!(
-                    "Shape limit reached; pasted {created_len} of {total}."
+                Toast::warning(tr_args(
+                    "Shape limit reached; pasted {count} of {total}.",
+                    &[
+                        ("count", &created_len.to_string()),
+                        ("total", &total.to_string()),
+                    ],
                 )),
             );
         }
@@ -156,6 +185,14 @@ impl InputState {
         &mut self,
         anchor: PasteAnchor,
     ) -> ClipboardPasteRequest {
+        let request = self.begin_paste_request(anchor);
+        self.pending_clipboard_paste_request = Some(request.clone());
+        request
+    }
+
+    /// Starts a paste request without queueing a clipboard read; the caller
+    /// supplies the content itself (for example a drag-and-drop transfer).
+    pub(crate) fn begin_paste_request(&mut self, anchor: PasteAnchor) -> ClipboardPasteRequest {
         self.clipboard_paste_request_counter = self.clipboard_paste_request_counter.wrapping_add(1);
         let id = self.clipboard_paste_request_counter;
         let request = ClipboardPasteRequest {
@@ -170,7 +207,6 @@ impl InputState {
             local_selection_fallback_generation: self.local_selection_fallback_generation(),
         };
         self.active_clipboard_paste_request_id = Some(id);
-        self.pending_clipboard_paste_request = Some(request.clone());
         request
     }
 
@@ -198,7 +234,7 @@ impl InputState {
     pub(crate) fn local_selection_shapes_for_fallback(
         &self,
         generation: u64,
-    ) -> Option<Vec<Shape>> {
+    ) -> Option<Vec<ClipboardShape>> {
         (generation == self.selection_clipboard_generation
             && self.local_selection_fallback_allowed())
         .then(|| self.selection_clipboard.clone())
@@ -209,7 +245,7 @@ impl InputState {
     pub(crate) fn local_selection_shapes_for_pending_publish(
         &self,
         generation: Option<u64>,
-    ) -> Option<Vec<Shape>> {
+    ) -> Option<Vec<ClipboardShape>> {
         let generation = generation?;
         (generation == self.selection_clipboard_generation
             && matches!(
@@ -261,7 +297,7 @@ impl InputState {
         &mut self,
         request_generation: Option<u64>,
         current: Option<ClipboardFingerprint>,
-    ) -> Option<Vec<Shape>> {
+    ) -> Option<Vec<ClipboardShape>> {
         let request_generation = request_generation?;
         let SelectionPublishState::Failed {
             generation,
@@ -327,7 +363,7 @@ impl InputState {
         &self,
         request: &ClipboardPasteRequest,
         payload: WayscriberClipboardSelection,
-    ) -> Option<Vec<Shape>> {
+    ) -> Option<Vec<ClipboardShape>> {
         if payload.app_instance_id == self.clipboard_app_instance_id {
             if request.local_selection_fallback_generation == Some(payload.copy_generation) {
                 if self.selection_clipboard_generation == payload.copy_generation
@@ -354,7 +390,7 @@ impl InputState {
     pub(crate) fn paste_clipboard_shapes_from_request(
         &mut self,
         request: &ClipboardPasteRequest,
-        shapes: Vec<Shape>,
+        shapes: Vec<ClipboardShape>,
     ) -> usize {
         if shapes.is_empty() {
             return 0;
@@ -370,6 +406,7 @@ impl InputState {
         let mut dirty_bounds = Vec::new();
         let mut hit_ids = Vec::new();
         let mut limit_hit = false;
+        let mut pasted_groups = HashMap::new();
         let total = shapes.len();
         let max_shapes = self.max_shapes_per_frame;
         let undo_limit = self.undo_stack_limit;
@@ -390,13 +427,20 @@ impl InputState {
             return 0;
         };
 
-        for shape in shapes {
-            let mut cloned_shape = shape;
+        for entry in shapes {
+            let mut cloned_shape = entry.shape;
             Self::translate_shape(&mut cloned_shape, dx, dy);
             let Some(new_id) = frame.try_add_shape_with_id(cloned_shape, max_shapes) else {
                 limit_hit = true;
                 break;
             };
+            restore_pasted_shape(
+                frame,
+                new_id,
+                entry.appearance,
+                entry.group,
+                &mut pasted_groups,
+            );
 
             if let Some(index) = frame.find_index(new_id)
                 && let Some(stored) = frame.shape(new_id).cloned()
@@ -436,8 +480,12 @@ impl InputState {
             self.push_toast(
                 ToastPriority::Info,
                 "selection.clipboard",
-                Toast::warning(format!(
-                    "Shape limit reached; pasted {created_len} of {total}."
+                Toast::warning(tr_args(
+                    "Shape limit reached; pasted {count} of {total}.",
+                    &[
+                        ("count", &created_len.to_string()),
+                        ("total", &total.to_string()),
+                    ],
                 )),
             );
         }
@@ -445,7 +493,28 @@ impl InputState {
     }
 }
 
-fn non_empty_shapes(shapes: Vec<Shape>) -> Option<Vec<Shape>> {
+/// Gives a pasted shape its copied appearance and puts it into a fresh group
+/// standing in for the one it was copied from, so a paste never joins the
+/// original group.
+fn restore_pasted_shape(
+    frame: &mut Frame,
+    id: ShapeId,
+    appearance: ShapeAppearance,
+    copied_group: Option<GroupId>,
+    pasted_groups: &mut HashMap<GroupId, GroupId>,
+) {
+    let group = copied_group.map(|copied_group| {
+        *pasted_groups
+            .entry(copied_group)
+            .or_insert_with(|| frame.next_group_id())
+    });
+    if let Some(shape) = frame.shape_mut(id) {
+        shape.appearance = appearance;
+        shape.group = group;
+    }
+}
+
+fn non_empty_shapes(shapes: Vec<ClipboardShape>) -> Option<Vec<ClipboardShape>> {
     if shapes.is_empty() {
         None
     } else {
@@ -453,7 +522,7 @@ fn non_empty_shapes(shapes: Vec<Shape>) -> Option<Vec<Shape>> {
     }
 }
 
-fn shape_paste_translation(shapes: &[Shape], anchor: PasteAnchor) -> (i32, i32) {
+fn shape_paste_translation(shapes: &[ClipboardShape], anchor: PasteAnchor) -> (i32, i32) {
     let Some(bounds) = shapes_bounding_box(shapes) else {
         return (0, 0);
     };
@@ -466,15 +535,15 @@ fn shape_paste_translation(shapes: &[Shape], anchor: PasteAnchor) -> (i32, i32)
     )
 }
 
-fn shapes_bounding_box(shapes: &[Shape]) -> Option<Rect> {
+fn shapes_bounding_box(shapes: &[ClipboardShape]) -> Option<Rect> {
     let mut min_x = i32::MAX;
     let mut min_y = i32::MAX;
     let mut max_x = i32::MIN;
     let mut max_y = i32::MIN;
     let mut found = false;
 
-    for shape in shapes {
-        if let Some(bounds) = shape.bounding_box() {
+    for entry in shapes {
+        if let Some(bounds) = entry.shape.bounding_box() {
             min_x = min_x.min(bounds.x);
             min_y = min_y.min(bounds.y);
             max_x = max_x.max(bounds.x + bounds.width);
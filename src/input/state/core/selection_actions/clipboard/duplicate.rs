@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use super::super::super::base::InputState;
 use crate::draw::frame::UndoAction;
 
@@ -13,6 +15,8 @@ impl InputState {
 
         let mut created = Vec::new();
         let mut new_ids = Vec::new();
+        // Copies of a group form a new group of their own.
+        let mut copied_groups = HashMap::new();
         for idx in 0..ids_len {
             let id = self.selected_shape_ids()[idx];
             let original = {
@@ -30,7 +34,19 @@ impl InputState {
             Self::translate_shape(&mut cloned_shape, DUPLICATE_OFFSET, DUPLICATE_OFFSET);
             let new_id = {
                 let frame = self.boards.active_frame_mut();
-                frame.add_shape(cloned_shape)
+                let new_id = frame.add_shape(cloned_shape);
+                if let Some(copy) = frame.shape_mut(new_id) {
+                    copy.appearance = shape.appearance;
+                }
+                if let Some(group) = shape.group {
+                    let copy_group = *copied_groups
+                        .entry(group)
+                        .or_insert_with(|| frame.next_group_id());
+                    if let Some(copy) = frame.shape_mut(new_id) {
+                        copy.group = Some(copy_group);
+                    }
+                }
+                new_id
             };
 
             if let Some((index, stored)) = {
@@ -55,6 +55,7 @@ impl InputState {
             w,
             h,
             data: image,
+            rotation: 0.0,
         };
         let Some(new_id) = frame.try_add_shape_with_id(shape, max_shapes) else {
             log::warn!(
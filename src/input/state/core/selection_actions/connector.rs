@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use super::super::base::InputState;
+use crate::draw::frame::{Connector, ConnectorAnchor, ConnectorEnd, UndoAction};
+use crate::draw::{Shape, ShapeId};
+
+impl InputState {
+    pub(crate) fn set_connector_mode(&mut self, enabled: bool) -> bool {
+        if self.connector_mode == enabled {
+            return false;
+        }
+        self.connector_mode = enabled;
+        self.needs_redraw = true;
+        true
+    }
+
+    /// Moves connector ends back onto the shapes they are attached to.
+    ///
+    /// Selected connectors are skipped: while they move with the selection,
+    /// their ends follow the drag rather than their targets.
+    pub(crate) fn reroute_connectors(&mut self) {
+        let skip: HashSet<ShapeId> = self.selected_shape_ids().iter().copied().collect();
+        let rerouted = self.boards.active_frame_mut().reroute_connectors(&skip);
+        if rerouted.is_empty() {
+            return;
+        }
+        for connector in rerouted {
+            self.dirty_tracker.mark_optional_rect(connector.before);
+            self.dirty_tracker.mark_optional_rect(connector.after);
+            self.invalidate_hit_cache_for(connector.shape_id);
+        }
+        self.needs_redraw = true;
+    }
+
+    /// Detaches the ends of moved connectors whose targets did not move with
+    /// them, returning the undo records for the detachments.
+    pub(crate) fn detach_connectors_left_behind(&mut self, moved: &[ShapeId]) -> Vec<UndoAction> {
+        let moved_set: HashSet<ShapeId> = moved.iter().copied().collect();
+        let frame = self.boards.active_frame_mut();
+        let mut actions = Vec::new();
+        for id in moved {
+            let Some(shape) = frame.shape_mut(*id) else {
+                continue;
+            };
+            let Some(connector) = shape.connector else {
+                continue;
+            };
+            let kept = connector.retain_targets(|target| moved_set.contains(&target));
+            if kept == shape.connector {
+                continue;
+            }
+            let from = std::mem::replace(&mut shape.connector, kept);
+            actions.push(UndoAction::Reconnect {
+                shape_id: *id,
+                from,
+                to: kept,
+            });
+        }
+        actions
+    }
+
+    /// Snaps the ends of a new line or arrow onto the shapes under them.
+    ///
+    /// Returns the attachments to record once the shape is added; ends that
+    /// land on empty canvas, or on the same shape as the other end, stay free.
+    pub(crate) fn attach_connector_ends(&mut self, shape: &mut Shape) -> Option<Connector> {
+        let (x1, y1, x2, y2) = match shape {
+            Shape::Line { x1, y1, x2, y2, .. } | Shape::Arrow { x1, y1, x2, y2, .. } => {
+                (x1, y1, x2, y2)
+            }
+            _ => return None,
+        };
+        let start = self.connector_end_at((*x1, *y1));
+        let end = self.connector_end_at((*x2, *y2));
+        if let (Some((start, _)), Some((end, _))) = (start, end)
+            && start.shape_id == end.shape_id
+        {
+            return None;
+        }
+        if let Some((_, (x, y))) = start {
+            (*x1, *y1) = (x, y);
+        }
+        if let Some((_, (x, y))) = end {
+            (*x2, *y2) = (x, y);
+        }
+        Connector::from_ends(
+            start.map(|(attached, _)| attached),
+            end.map(|(attached, _)| attached),
+        )
+    }
+
+    /// Top-most shape at `point` and the anchor on it nearest to `point`.
+    ///
+    /// Outline-only shapes are matched by their bounds too, so an end dropped
+    /// inside an unfilled box still attaches to it.
+    fn connector_end_at(&mut self, point: (i32, i32)) -> Option<(ConnectorEnd, (i32, i32))> {
+        let shape_id = self.hit_test_at(point.0, point.1).or_else(|| {
+            let frame = self.boards.active_frame();
+            frame
+                .shapes
+                .iter()
+                .rev()
+                .find(|drawn| {
+                    frame.is_shape_editable(drawn)
+                        && drawn
+                            .bounding_box()
+                            .is_some_and(|bounds| bounds.contains(point.0, point.1))
+                })
+                .map(|drawn| drawn.id)
+        })?;
+        let bounds = self.boards.active_frame().shape(shape_id)?.bounding_box()?;
+        let anchor = ConnectorAnchor::nearest(bounds, point);
+        Some((ConnectorEnd { shape_id, anchor }, anchor.point_on(bounds)))
+    }
+}
@@ -29,6 +29,7 @@ impl InputState {
         frame
             .shapes
             .iter()
+            .filter(|shape| frame.is_shape_editable(shape))
             .filter_map(|shape| {
                 shape
                     .shape
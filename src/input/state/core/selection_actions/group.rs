@@ -0,0 +1,53 @@
+use super::super::base::InputState;
+use crate::draw::frame::{GroupId, UndoAction};
+
+impl InputState {
+    /// Puts every selected shape into one new group. Groups inside the
+    /// selection are merged rather than nested.
+    pub(crate) fn group_selection(&mut self) -> bool {
+        if self.selected_shape_ids().len() < 2 {
+            return false;
+        }
+        let group = self.boards.active_frame().next_group_id();
+        self.regroup_selection(Some(group))
+    }
+
+    /// Dissolves every group the selection touches. The former members stay
+    /// selected, now as individual shapes.
+    pub(crate) fn ungroup_selection(&mut self) -> bool {
+        self.regroup_selection(None)
+    }
+
+    fn regroup_selection(&mut self, group: Option<GroupId>) -> bool {
+        let ids = self.selected_shape_ids().to_vec();
+        let mut actions = Vec::new();
+        for id in ids {
+            let from = {
+                let frame = self.boards.active_frame_mut();
+                let Some(shape) = frame.shape_mut(id) else {
+                    continue;
+                };
+                if shape.group == group {
+                    continue;
+                }
+                std::mem::replace(&mut shape.group, group)
+            };
+            actions.push(UndoAction::Regroup {
+                shape_id: id,
+                from,
+                to: group,
+            });
+        }
+
+        if actions.is_empty() {
+            return false;
+        }
+
+        self.boards
+            .active_frame_mut()
+            .push_undo_action(UndoAction::Compound { actions }, self.undo_stack_limit);
+        self.mark_session_dirty();
+        self.needs_redraw = true;
+        true
+    }
+}
@@ -1,8 +1,14 @@
+mod align;
 mod clipboard;
+mod connector;
 mod delete;
 mod geometry;
+mod group;
 mod reorder;
 mod resize;
+mod rotate;
 mod state;
 mod text;
 mod translation;
+
+pub(crate) use align::SelectionAlignment;
@@ -17,13 +17,18 @@ impl InputState {
         }
 
         let mut actions = Vec::new();
-        let len = self.boards.active_frame().shapes.len();
         for idx in 0..ids_len {
             let id = self.selected_shape_ids()[idx];
             let movement = {
                 let frame = self.boards.active_frame_mut();
                 if let Some(from) = frame.find_index(id) {
-                    let target = if to_front { len.saturating_sub(1) } else { 0 };
+                    // Front and back are the top and bottom of the shape's layer.
+                    let span = frame.layer_span(frame.shapes[from].layer);
+                    let target = if to_front {
+                        span.end.saturating_sub(1)
+                    } else {
+                        span.start
+                    };
                     if from == target {
                         None
                     } else if frame.move_shape(from, target).is_some() {
@@ -1,7 +1,7 @@
 //! Selection resize functionality.
 
 use crate::draw::frame::ShapeSnapshot;
-use crate::draw::{Shape, ShapeId};
+use crate::draw::{ArrowLabel, Shape, ShapeId, StepMarkerLabel};
 use crate::input::InputState;
 use crate::input::state::core::base::SelectionHandle;
 use crate::util::Rect;
@@ -10,6 +10,8 @@ mod resize_helpers;
 // Handle size for hit testing (matches render constants)
 const HANDLE_SIZE: i32 = 8;
 const HANDLE_TOLERANCE: i32 = 4;
+/// Distance of the rotation knob above the selection's top edge
+const ROTATE_HANDLE_OFFSET: i32 = 24;
 
 impl InputState {
     /// Hit test for selection handles. Returns the handle if mouse is over one.
@@ -90,6 +92,7 @@ impl InputState {
         for shape_id in ids_to_invalidate {
             self.invalidate_hit_cache_for(shape_id);
         }
+        self.reroute_connectors();
         self.mark_selection_dirty_region(self.selection_bounds());
     }
 
@@ -110,18 +113,27 @@ impl InputState {
                 fill,
                 color,
                 thick,
+                rotation,
+                line_style,
             } => {
-                let (nx, ny) = Self::scale_point_i32(*x, *y, anchor_x, anchor_y, scale_x, scale_y);
-                let nw = Self::scale_size(*w, scale_x);
-                let nh = Self::scale_size(*h, scale_y);
+                let (x, y, w, h) = Self::scale_box(
+                    (*x, *y, *w, *h),
+                    *rotation,
+                    anchor_x,
+                    anchor_y,
+                    scale_x,
+                    scale_y,
+                );
                 Shape::Rect {
-                    x: nx,
-                    y: ny,
-                    w: nw.max(1),
-                    h: nh.max(1),
+                    x,
+                    y,
+                    w,
+                    h,
                     fill: *fill,
                     color: *color,
                     thick: *thick,
+                    rotation: *rotation,
+                    line_style: *line_style,
                 }
             }
             Shape::Ellipse {
@@ -132,11 +144,14 @@ impl InputState {
                 fill,
                 color,
                 thick,
+                rotation,
+                line_style,
             } => {
                 let (ncx, ncy) =
                     Self::scale_point_i32(*cx, *cy, anchor_x, anchor_y, scale_x, scale_y);
-                let nrx = Self::scale_size(*rx, scale_x);
-                let nry = Self::scale_size(*ry, scale_y);
+                let (local_x, local_y) = Self::rotated_scale_factors(*rotation, scale_x, scale_y);
+                let nrx = Self::scale_size(*rx, local_x);
+                let nry = Self::scale_size(*ry, local_y);
                 Shape::Ellipse {
                     cx: ncx,
                     cy: ncy,
@@ -145,6 +160,8 @@ impl InputState {
                     fill: *fill,
                     color: *color,
                     thick: *thick,
+                    rotation: *rotation,
+                    line_style: *line_style,
                 }
             }
             Shape::Spotlight { cx, cy, rx, ry } => {
@@ -164,6 +181,7 @@ impl InputState {
                 y2,
                 color,
                 thick,
+                line_style,
             } => {
                 let (nx1, ny1) =
                     Self::scale_point_i32(*x1, *y1, anchor_x, anchor_y, scale_x, scale_y);
@@ -176,6 +194,7 @@ impl InputState {
                     y2: ny2,
                     color: *color,
                     thick: *thick,
+                    line_style: *line_style,
                 }
             }
             Shape::Arrow {
@@ -189,6 +208,7 @@ impl InputState {
                 arrow_angle,
                 head_at_end,
                 label,
+                line_style,
             } => {
                 let (nx1, ny1) =
                     Self::scale_point_i32(*x1, *y1, anchor_x, anchor_y, scale_x, scale_y);
@@ -204,7 +224,11 @@ impl InputState {
                     arrow_length: *arrow_length,
                     arrow_angle: *arrow_angle,
                     head_at_end: *head_at_end,
-                    label: label.clone(),
+                    label: label.as_ref().map(|label| ArrowLabel {
+                        size: Self::scale_font_size(label.size, scale_x, scale_y),
+                        ..label.clone()
+                    }),
+                    line_style: *line_style,
                 }
             }
             Shape::Polygon {
@@ -213,6 +237,7 @@ impl InputState {
                 fill,
                 color,
                 thick,
+                line_style,
             } => {
                 let scaled_points =
                     Self::scale_points(points, anchor_x, anchor_y, scale_x, scale_y);
@@ -222,6 +247,7 @@ impl InputState {
                     fill: *fill,
                     color: *color,
                     thick: *thick,
+                    line_style: *line_style,
                 }
             }
             Shape::BlurRect {
@@ -231,35 +257,56 @@ impl InputState {
                 h,
                 strength,
                 style,
+                rotation,
             } => {
-                let (nx, ny) = Self::scale_point_i32(*x, *y, anchor_x, anchor_y, scale_x, scale_y);
-                let nw = Self::scale_size(*w, scale_x);
-                let nh = Self::scale_size(*h, scale_y);
+                let (x, y, w, h) = Self::scale_box(
+                    (*x, *y, *w, *h),
+                    *rotation,
+                    anchor_x,
+                    anchor_y,
+                    scale_x,
+                    scale_y,
+                );
                 Shape::BlurRect {
-                    x: nx,
-                    y: ny,
-                    w: nw.max(1),
-                    h: nh.max(1),
+                    x,
+                    y,
+                    w,
+                    h,
                     strength: *strength,
                     style: *style,
+                    rotation: *rotation,
                 }
             }
-            Shape::Image { x, y, w, h, data } => {
-                let (nx, ny) = Self::scale_point_i32(*x, *y, anchor_x, anchor_y, scale_x, scale_y);
-                let nw = Self::scale_size(*w, scale_x);
-                let nh = Self::scale_size(*h, scale_y);
+            Shape::Image {
+                x,
+                y,
+                w,
+                h,
+                data,
+                rotation,
+            } => {
+                let (x, y, w, h) = Self::scale_box(
+                    (*x, *y, *w, *h),
+                    *rotation,
+                    anchor_x,
+                    anchor_y,
+                    scale_x,
+                    scale_y,
+                );
                 Shape::Image {
-                    x: nx,
-                    y: ny,
-                    w: nw.max(1),
-                    h: nh.max(1),
+                    x,
+                    y,
+                    w,
+                    h,
                     data: data.clone(),
+                    rotation: *rotation,
                 }
             }
             Shape::Freehand {
                 points,
                 color,
                 thick,
+                line_style,
             } => {
                 let scaled_points =
                     Self::scale_points(points, anchor_x, anchor_y, scale_x, scale_y);
@@ -267,6 +314,7 @@ impl InputState {
                     points: scaled_points,
                     color: *color,
                     thick: *thick,
+                    line_style: *line_style,
                 }
             }
             Shape::FreehandPressure { points, color } => {
@@ -296,7 +344,10 @@ impl InputState {
                     x: nx,
                     y: ny,
                     color: *color,
-                    label: label.clone(),
+                    label: StepMarkerLabel {
+                        size: Self::scale_font_size(label.size, scale_x, scale_y),
+                        ..label.clone()
+                    },
                 }
             }
             Shape::EraserStroke { points, brush } => {
@@ -307,7 +358,8 @@ impl InputState {
                     brush: brush.clone(),
                 }
             }
-            // Text and StickyNote: just move position, don't scale content
+            // Text scales its font by the geometric mean of the two factors,
+            // so a one-sided stretch still grows it, and its wrap width with it.
             Shape::Text {
                 x,
                 y,
@@ -317,17 +369,20 @@ impl InputState {
                 font_descriptor,
                 background_enabled,
                 wrap_width,
+                rotation,
             } => {
                 let (nx, ny) = Self::scale_point_i32(*x, *y, anchor_x, anchor_y, scale_x, scale_y);
+                let (local_x, _) = Self::rotated_scale_factors(*rotation, scale_x, scale_y);
                 Shape::Text {
                     x: nx,
                     y: ny,
                     text: text.clone(),
                     color: *color,
-                    size: *size,
+                    size: Self::scale_font_size(*size, scale_x, scale_y),
                     font_descriptor: font_descriptor.clone(),
                     background_enabled: *background_enabled,
-                    wrap_width: *wrap_width,
+                    wrap_width: wrap_width.map(|width| Self::scale_size(width, local_x).max(1)),
+                    rotation: *rotation,
                 }
             }
             Shape::StickyNote {
@@ -345,9 +400,9 @@ impl InputState {
                     y: ny,
                     text: text.clone(),
                     background: *background,
-                    size: *size,
+                    size: Self::scale_font_size(*size, scale_x, scale_y),
                     font_descriptor: font_descriptor.clone(),
-                    wrap_width: *wrap_width,
+                    wrap_width: wrap_width.map(|width| Self::scale_size(width, scale_x).max(1)),
                 }
             }
         }
@@ -1,7 +1,9 @@
+use crate::ui::toolbar::model::ToolbarSliderSpec;
 use crate::util::Rect;
 
 use super::super::super::base::InputState;
 use super::super::super::base::SelectionHandle;
+use super::ROTATE_HANDLE_OFFSET;
 
 #[derive(Debug, Clone, Copy)]
 pub(super) struct ResizeHandleProbe {
@@ -12,6 +14,9 @@ pub(super) struct ResizeHandleProbe {
 }
 
 const MIN_RESIZE_SIZE: f64 = 10.0;
+/// Smallest font size a scale drag can shrink text to; below this glyphs stop
+/// being legible and the text becomes hard to grab again.
+const MIN_SCALED_FONT_SIZE: f64 = 4.0;
 
 impl InputState {
     pub(super) fn point_near(&self, x: i32, y: i32, cx: i32, cy: i32, radius: i32) -> bool {
@@ -22,7 +27,7 @@ impl InputState {
         bounds: &Rect,
         corner_radius: i32,
         edge_radius: i32,
-    ) -> [ResizeHandleProbe; 8] {
+    ) -> [ResizeHandleProbe; 9] {
         let right = bounds.x + bounds.width;
         let bottom = bounds.y + bounds.height;
         let mid_x = bounds.x + bounds.width / 2;
@@ -36,6 +41,12 @@ impl InputState {
             Self::build_handle_probe(SelectionHandle::Bottom, mid_x, bottom, edge_radius),
             Self::build_handle_probe(SelectionHandle::Left, bounds.x, mid_y, edge_radius),
             Self::build_handle_probe(SelectionHandle::Right, right, mid_y, edge_radius),
+            Self::build_handle_probe(
+                SelectionHandle::Rotate,
+                mid_x,
+                bounds.y - ROTATE_HANDLE_OFFSET,
+                corner_radius,
+            ),
         ]
     }
 
@@ -93,6 +104,7 @@ impl InputState {
             SelectionHandle::Top | SelectionHandle::Bottom => width,
             SelectionHandle::Left => Self::clamp_resize_size(width - dx),
             SelectionHandle::Right => Self::clamp_resize_size(width + dx),
+            SelectionHandle::Rotate => width,
         }
     }
 
@@ -107,6 +119,7 @@ impl InputState {
             SelectionHandle::Left | SelectionHandle::Right => height,
             SelectionHandle::Top => Self::clamp_resize_size(height - dy),
             SelectionHandle::Bottom => Self::clamp_resize_size(height + dy),
+            SelectionHandle::Rotate => height,
         }
     }
 
@@ -124,6 +137,7 @@ impl InputState {
             SelectionHandle::Bottom => (x + w / 2.0, y),
             SelectionHandle::Left => (x + w, y + h / 2.0),
             SelectionHandle::Right => (x, y + h / 2.0),
+            SelectionHandle::Rotate => (x + w / 2.0, y + h / 2.0),
         }
     }
 
@@ -157,6 +171,63 @@ impl InputState {
         (size as f64 * factor).round() as i32
     }
 
+    /// How much a selection scale stretches a shape turned by `rotation`
+    /// along its own width and height axes.
+    ///
+    /// A turned box under a non-uniform scale would shear; keeping it a box
+    /// and stretching each side by the length its axis gains is the closest
+    /// fit, and exact whenever the turn is a multiple of 90 degrees.
+    pub(super) fn rotated_scale_factors(rotation: f64, scale_x: f64, scale_y: f64) -> (f64, f64) {
+        if rotation == 0.0 {
+            return (scale_x, scale_y);
+        }
+        let (sin, cos) = rotation.to_radians().sin_cos();
+        (
+            (scale_x * cos).hypot(scale_y * sin),
+            (scale_x * sin).hypot(scale_y * cos),
+        )
+    }
+
+    /// Scales an `(x, y, w, h)` box that turns about its center.
+    pub(super) fn scale_box(
+        (x, y, w, h): (i32, i32, i32, i32),
+        rotation: f64,
+        anchor_x: f64,
+        anchor_y: f64,
+        scale_x: f64,
+        scale_y: f64,
+    ) -> (i32, i32, i32, i32) {
+        let (local_x, local_y) = Self::rotated_scale_factors(rotation, scale_x, scale_y);
+        let nw = Self::scale_size(w, local_x).max(1);
+        let nh = Self::scale_size(h, local_y).max(1);
+        if rotation == 0.0 {
+            let (nx, ny) = Self::scale_point_i32(x, y, anchor_x, anchor_y, scale_x, scale_y);
+            return (nx, ny, nw, nh);
+        }
+        let (cx, cy) = Self::scale_point(
+            x as f64 + w as f64 / 2.0,
+            y as f64 + h as f64 / 2.0,
+            anchor_x,
+            anchor_y,
+            scale_x,
+            scale_y,
+        );
+        (
+            (cx - nw as f64 / 2.0).round() as i32,
+            (cy - nh as f64 / 2.0).round() as i32,
+            nw,
+            nh,
+        )
+    }
+
+    /// Font sizes follow the geometric mean of the two factors, so a corner
+    /// drag scales text like everything else and an edge drag still grows it.
+    /// Growth stops at the text tool's largest font size.
+    pub(super) fn scale_font_size(size: f64, scale_x: f64, scale_y: f64) -> f64 {
+        (size * (scale_x * scale_y).sqrt())
+            .clamp(MIN_SCALED_FONT_SIZE, ToolbarSliderSpec::FONT_SIZE.max)
+    }
+
     pub(super) fn scale_points(
         points: &[(i32, i32)],
         anchor_x: f64,
@@ -0,0 +1,170 @@
+//! Selection rotation functionality.
+
+use crate::draw::frame::ShapeSnapshot;
+use crate::draw::shape::rotate_point;
+use crate::draw::{Shape, ShapeId};
+use crate::input::InputState;
+use crate::util::Rect;
+
+/// Angle step the rotation handle snaps to while Shift is held.
+const ROTATION_SNAP_DEGREES: f64 = 15.0;
+
+impl InputState {
+    /// Turns the selected shapes about the center of `original_bounds` by the
+    /// angle the pointer swept around it from `start` to `current`.
+    ///
+    /// With `snap` the result lands on 15 degree steps. A lone shape that keeps
+    /// its own rotation snaps its final angle rather than the sweep, so it can
+    /// always be turned back to exactly upright.
+    pub(crate) fn apply_selection_rotation(
+        &mut self,
+        original_bounds: &Rect,
+        start: (i32, i32),
+        current: (i32, i32),
+        snap: bool,
+        snapshots: &[(ShapeId, ShapeSnapshot)],
+    ) {
+        if snapshots.is_empty() {
+            return;
+        }
+
+        let cx = original_bounds.x as f64 + original_bounds.width as f64 / 2.0;
+        let cy = original_bounds.y as f64 + original_bounds.height as f64 / 2.0;
+        let angle_to = |(x, y): (i32, i32)| (y as f64 - cy).atan2(x as f64 - cx).to_degrees();
+        let swept = angle_to(current) - angle_to(start);
+        let degrees = if snap {
+            let base = match snapshots {
+                [(_, only)] => only.shape.rotation(),
+                _ => 0.0,
+            };
+            (((base + swept) / ROTATION_SNAP_DEGREES).round() * ROTATION_SNAP_DEGREES) - base
+        } else {
+            swept
+        };
+
+        let previous_bounds = self.selection_bounds();
+        self.mark_selection_dirty_region(previous_bounds);
+
+        let mut ids_to_invalidate = Vec::with_capacity(snapshots.len());
+        {
+            let frame = self.boards.active_frame_mut();
+            for (shape_id, snapshot) in snapshots {
+                if let Some(drawn) = frame.shape_mut(*shape_id) {
+                    drawn.set_shape(rotate_shape(&snapshot.shape, cx, cy, degrees));
+                    ids_to_invalidate.push(*shape_id);
+                }
+            }
+        }
+
+        for shape_id in ids_to_invalidate {
+            self.invalidate_hit_cache_for(shape_id);
+        }
+        self.reroute_connectors();
+        self.mark_selection_dirty_region(self.selection_bounds());
+    }
+}
+
+/// Turns one shape by `degrees` about `(cx, cy)`.
+///
+/// Shapes with a rotation field move their center and add the angle; point
+/// based shapes turn every point. Step markers, sticky notes, and spotlights
+/// have no orientation to keep, so they only follow the turn with their center.
+fn rotate_shape(original: &Shape, cx: f64, cy: f64, degrees: f64) -> Shape {
+    let turn = |x: f64, y: f64| rotate_point(x, y, cx, cy, degrees);
+    let turn_i32 = |x: &mut i32, y: &mut i32| {
+        let (nx, ny) = turn(*x as f64, *y as f64);
+        *x = nx.round() as i32;
+        *y = ny.round() as i32;
+    };
+    // Moves a shape's reference point by however far the turn carries its center.
+    let follow_center = |x: &mut i32, y: &mut i32, center: Option<Rect>| {
+        let Some(center) = center else {
+            return;
+        };
+        let old_x = center.x as f64 + center.width as f64 / 2.0;
+        let old_y = center.y as f64 + center.height as f64 / 2.0;
+        let (new_x, new_y) = turn(old_x, old_y);
+        *x += (new_x - old_x).round() as i32;
+        *y += (new_y - old_y).round() as i32;
+    };
+
+    let mut shape = original.clone();
+    let local_bounds = original.local_bounding_box();
+    match &mut shape {
+        Shape::Freehand { points, .. }
+        | Shape::MarkerStroke { points, .. }
+        | Shape::EraserStroke { points, .. }
+        | Shape::Polygon { points, .. } => {
+            for (x, y) in points.iter_mut() {
+                turn_i32(x, y);
+            }
+        }
+        Shape::FreehandPressure { points, .. } => {
+            for (x, y, _) in points.iter_mut() {
+                turn_i32(x, y);
+            }
+        }
+        Shape::Line { x1, y1, x2, y2, .. } | Shape::Arrow { x1, y1, x2, y2, .. } => {
+            turn_i32(x1, y1);
+            turn_i32(x2, y2);
+        }
+        Shape::Rect {
+            x,
+            y,
+            w,
+            h,
+            rotation,
+            ..
+        }
+        | Shape::Image {
+            x,
+            y,
+            w,
+            h,
+            rotation,
+            ..
+        }
+        | Shape::BlurRect {
+            x,
+            y,
+            w,
+            h,
+            rotation,
+            ..
+        } => {
+            let half_w = *w as f64 / 2.0;
+            let half_h = *h as f64 / 2.0;
+            let (ncx, ncy) = turn(*x as f64 + half_w, *y as f64 + half_h);
+            *x = (ncx - half_w).round() as i32;
+            *y = (ncy - half_h).round() as i32;
+            *rotation = normalize_degrees(*rotation + degrees);
+        }
+        Shape::Ellipse {
+            cx: ex,
+            cy: ey,
+            rotation,
+            ..
+        } => {
+            turn_i32(ex, ey);
+            *rotation = normalize_degrees(*rotation + degrees);
+        }
+        Shape::Text { x, y, rotation, .. } => {
+            follow_center(x, y, local_bounds);
+            *rotation = normalize_degrees(*rotation + degrees);
+        }
+        Shape::StickyNote { x, y, .. } => follow_center(x, y, local_bounds),
+        Shape::StepMarker { x, y, .. } => turn_i32(x, y),
+        Shape::Spotlight { cx: sx, cy: sy, .. } => turn_i32(sx, sy),
+    }
+    shape
+}
+
+/// Wraps an angle into `(-180, 180]`.
+fn normalize_degrees(degrees: f64) -> f64 {
+    let wrapped = degrees.rem_euclid(360.0);
+    if wrapped > 180.0 {
+        wrapped - 360.0
+    } else {
+        wrapped
+    }
+}
@@ -1,9 +1,11 @@
 use super::super::base::InputState;
-use crate::draw::DirtyFullReason;
 use crate::draw::frame::{ShapeSnapshot, UndoAction};
+use crate::draw::{DirtyFullReason, DrawnShape, Frame};
 use crate::util::Rect;
 
-const SELECTION_DAMAGE_PADDING: i32 = 8;
+/// Reaches past the selection handles, including the rotation knob that sits
+/// 24px above the top edge.
+const SELECTION_DAMAGE_PADDING: i32 = 32;
 
 impl InputState {
     pub(crate) fn set_selection_locked(&mut self, locked: bool) -> bool {
@@ -55,7 +57,23 @@ impl InputState {
         true
     }
 
+    /// Removes every unlocked shape on a visible, unlocked layer.
     pub(crate) fn clear_all(&mut self) -> bool {
+        self.clear_shapes_where(|frame, shape| frame.is_shape_editable(shape))
+    }
+
+    /// Removes every unlocked shape on the active layer, leaving the other
+    /// layers untouched.
+    pub(crate) fn clear_active_layer(&mut self) -> bool {
+        let frame = self.boards.active_frame();
+        let layer = frame.active_layer_id();
+        if !frame.is_layer_editable(layer) {
+            return false;
+        }
+        self.clear_shapes_where(|_, shape| shape.layer == layer)
+    }
+
+    fn clear_shapes_where(&mut self, clearable: impl Fn(&Frame, &DrawnShape) -> bool) -> bool {
         let removed = {
             let frame = self.boards.active_frame();
             if frame.shapes.is_empty() {
@@ -66,7 +84,7 @@ impl InputState {
                 .iter()
                 .cloned()
                 .enumerate()
-                .filter(|(_, shape)| !shape.locked)
+                .filter(|(_, shape)| !shape.locked && clearable(frame, shape))
                 .collect::<Vec<_>>()
         };
         if removed.is_empty() {
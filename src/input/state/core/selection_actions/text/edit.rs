@@ -47,6 +47,7 @@ impl InputState {
                     font_descriptor,
                     background_enabled,
                     wrap_width,
+                    ..
                 } => (
                     TextInputMode::Plain,
                     *x,
@@ -183,6 +184,13 @@ impl InputState {
             return false;
         };
 
+        // The editor lays text out upright; the committed edit turns back to
+        // wherever the original was rotated.
+        let mut new_shape = new_shape;
+        if let Some(rotation) = new_shape.rotation_mut() {
+            *rotation = before_snapshot.shape.rotation();
+        }
+
         let updated = {
             let frame = self.boards.active_frame_mut();
             if let Some(shape) = frame.shape_mut(shape_id) {
@@ -27,6 +27,11 @@ impl InputState {
         if !matches!(shape.shape, Shape::Text { .. } | Shape::StickyNote { .. }) {
             return None;
         }
+        // The wrap handle drags along the text's own baseline; once the text
+        // is turned, the selection's scale handles take over.
+        if shape.shape.rotation() != 0.0 {
+            return None;
+        }
         let bounds = shape.bounding_box()?;
         let handle = Self::text_resize_handle_rect(bounds)?;
         Some((shape_id, handle))
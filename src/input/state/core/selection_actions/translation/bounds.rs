@@ -35,7 +35,9 @@ impl InputState {
         }
     }
 
-    pub(super) fn movable_selection_bounds(&self) -> Option<Rect> {
+    pub(in crate::input::state::core::selection_actions) fn movable_selection_bounds(
+        &self,
+    ) -> Option<Rect> {
         let ids = self.selected_shape_ids();
         if ids.is_empty() {
             return None;
@@ -74,6 +74,7 @@ impl InputState {
         }
 
         if moved_any {
+            self.reroute_connectors();
             self.needs_redraw = true;
         }
         moved_any
@@ -30,6 +30,7 @@ impl InputState {
                 self.invalidate_hit_cache_for(shape_id);
             }
         }
+        self.reroute_connectors();
         self.needs_redraw = true;
     }
 
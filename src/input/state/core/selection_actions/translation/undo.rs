@@ -26,6 +26,9 @@ impl InputState {
             }
         }
 
+        let moved: Vec<ShapeId> = before.iter().map(|(shape_id, _)| *shape_id).collect();
+        actions.extend(self.detach_connectors_left_behind(&moved));
+
         if actions.is_empty() {
             return false;
         }
@@ -220,8 +220,8 @@ impl InputState {
 
 #[cfg(test)]
 mod tests {
-    use crate::draw::frame::ShapeSnapshot;
     use crate::draw::{BLACK, Shape};
+    use crate::draw::{LineStyle, frame::ShapeSnapshot};
     use crate::input::state::core::TextBlockDrag;
     use crate::input::state::core::board_picker::{BoardPickerDrag, BoardPickerPageDrag};
     use crate::input::state::test_support::make_test_input_state;
@@ -384,6 +384,7 @@ mod tests {
             font_descriptor: state.font_descriptor.clone(),
             background_enabled: state.text_background_enabled,
             wrap_width: Some(180),
+            rotation: 0.0,
         });
         state.set_selection(vec![shape_id]);
         assert!(state.edit_selected_text());
@@ -435,6 +436,7 @@ mod tests {
                 points: vec![(0, 0), (1, 1)],
                 color: BLACK,
                 thick: 1.0,
+                line_style: LineStyle::Solid,
             },
             locked: false,
         }
@@ -3,6 +3,7 @@ use super::session_preflight_exact::{
     ClonePreflightAction, duplicate_board_id_for_preflight, exact_visible_save_allows,
 };
 use crate::draw::{BoardPages, Frame, Shape};
+use crate::i18n::{tr, tr_args};
 use crate::input::boards::BoardState;
 use crate::input::state::{Toast, ToastPriority};
 use crate::session::{CompressionMode, DEFAULT_MAX_EXPANDED_SESSION_BYTES, SessionOptions};
@@ -251,17 +252,15 @@ impl InputState {
                 Some(false) => {
                     return self.block_clone_heavy_action(
                         toast_key,
-                        action_label,
-                        action_name,
-                        "session would exceed save limits.".to_string(),
+                        action,
+                        tr("session would exceed save limits.").to_string(),
                     );
                 }
                 None => {
                     return self.block_clone_heavy_action(
                         toast_key,
-                        action_label,
-                        action_name,
-                        "session size check failed.".to_string(),
+                        action,
+                        tr("session size check failed.").to_string(),
                     );
                 }
             }
@@ -283,10 +282,10 @@ impl InputState {
             );
             return self.block_clone_heavy_action(
                 toast_key,
-                action_label,
-                action_name,
-                format!(
-                    "session would exceed {limit}. Remove images or raise session.max_file_size_mb."
+                action,
+                tr_args(
+                    "session would exceed {limit}. Remove images or raise session.max_file_size_mb.",
+                    &[("limit", &limit)],
                 ),
             );
         }
@@ -308,9 +307,11 @@ impl InputState {
             );
             return self.block_clone_heavy_action(
                 toast_key,
-                action_label,
-                action_name,
-                format!("session would exceed the {limit} safety limit. Remove images or reduce duplicated content."),
+                action,
+                tr_args(
+                    "session would exceed the {limit} safety limit. Remove images or reduce duplicated content.",
+                    &[("limit", &limit)],
+                ),
             );
         }
         true
@@ -319,15 +320,20 @@ impl InputState {
     fn block_clone_heavy_action(
         &mut self,
         toast_key: &'static str,
-        action_label: &str,
-        action_name: &str,
+        action: ClonePreflightAction,
         reason: String,
     ) -> bool {
-        self.push_toast(
-            ToastPriority::Info,
-            toast_key,
-            Toast::warning(format!("{action_label} {action_name} blocked; {reason}")),
-        );
+        let args = [("reason", reason.as_str())];
+        let message = match action {
+            ClonePreflightAction::BoardDuplicate => {
+                tr_args("Board duplicate blocked; {reason}", &args)
+            }
+            ClonePreflightAction::PageDuplicate { .. } => {
+                tr_args("Page duplicate blocked; {reason}", &args)
+            }
+            ClonePreflightAction::PageCopy { .. } => tr_args("Page copy blocked; {reason}", &args),
+        };
+        self.push_toast(ToastPriority::Info, toast_key, Toast::warning(message));
         self.trigger_blocked_feedback();
         false
     }
@@ -420,6 +426,11 @@ fn estimate_frame_page_storage(page: &Frame) -> CloneStorageEstimate {
     if offset != (0, 0) {
         estimate.add_raw(32);
     }
+    if let Some(background) = page.background() {
+        let source = background.source.path.to_string_lossy();
+        estimate.add_raw(escaped_json_string_len(&source));
+        estimate.add_image(background.image.bytes.len(), &background.image.mime_type);
+    }
     for shape in page.shapes.iter().map(|drawn| &drawn.shape) {
         estimate = estimate.saturating_add(estimate_shape_storage(shape));
     }
@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+use super::base::{DrawingState, InputState};
+use crate::draw::shape::{RecognizedShape, recognize_stroke};
+use crate::input::Tool;
+
+/// Pointer jitter (in pixels) still counted as resting at the end of a stroke.
+const SMART_INK_REST_RADIUS: i32 = 4;
+
+impl InputState {
+    /// Starts rest tracking for a new stroke at its press position.
+    pub(crate) fn begin_smart_ink_rest(&mut self, point: (i32, i32)) {
+        self.smart_ink_rest = Some((point, Instant::now()));
+    }
+
+    /// Moves the rest anchor once the pen leaves its jitter radius.
+    pub(crate) fn track_smart_ink_rest(&mut self, point: (i32, i32)) {
+        if !self.smart_ink_enabled
+            || self.smart_ink_hold_ms == 0
+            || !matches!(
+                self.state,
+                DrawingState::Drawing {
+                    tool: Tool::Pen,
+                    ..
+                }
+            )
+        {
+            return;
+        }
+        let moved = self
+            .smart_ink_rest
+            .is_none_or(|(anchor, _)| !within_rest_radius(anchor, point));
+        if moved {
+            self.smart_ink_rest = Some((point, Instant::now()));
+        }
+    }
+
+    /// Returns the shape a finished pen stroke should become, if smart ink is
+    /// on, the hold requirement is met, and the stroke looks like one.
+    pub(crate) fn recognize_smart_ink(
+        &mut self,
+        tool: Tool,
+        points: &[(i32, i32)],
+        end: (i32, i32),
+    ) -> Option<RecognizedShape> {
+        let rest = self.smart_ink_rest.take();
+        if !self.smart_ink_enabled || tool != Tool::Pen {
+            return None;
+        }
+        if self.smart_ink_hold_ms > 0 {
+            let (anchor, since) = rest?;
+            if !within_rest_radius(anchor, end)
+                || since.elapsed() < Duration::from_millis(self.smart_ink_hold_ms)
+            {
+                return None;
+            }
+        }
+        if points.last() == Some(&end) {
+            recognize_stroke(points)
+        } else {
+            let mut points = points.to_vec();
+            points.push(end);
+            recognize_stroke(&points)
+        }
+    }
+}
+
+fn within_rest_radius(anchor: (i32, i32), point: (i32, i32)) -> bool {
+    (point.0 - anchor.0).abs() <= SMART_INK_REST_RADIUS
+        && (point.1 - anchor.1).abs() <= SMART_INK_REST_RADIUS
+}
@@ -0,0 +1,75 @@
+use super::base::InputState;
+use crate::input::Tool;
+use crate::input::tool::ToolDrawingBehavior;
+
+/// Length, in pixels, of the lazy-brush string at full stabilizer strength.
+const MAX_STABILIZER_RADIUS: f64 = 32.0;
+
+impl InputState {
+    /// Starts the lazy brush for a new stroke at its press position.
+    pub(crate) fn begin_stroke_stabilizer(&mut self, point: (i32, i32)) {
+        self.stabilizer_brush = Some((f64::from(point.0), f64::from(point.1)));
+    }
+
+    /// Returns the point a pen or marker stroke records for a pointer sample.
+    ///
+    /// With the stabilizer on, the ink is a brush pulled along on a string:
+    /// it stays put while the pointer moves within the string's length and
+    /// otherwise follows the pointer at that distance, which irons out hand
+    /// tremor. Returns `None` while the brush has not reached a new pixel.
+    /// Other tools, and strokes with the stabilizer off, record the pointer.
+    pub(crate) fn stabilize_stroke_point(
+        &mut self,
+        tool: Tool,
+        pointer: (i32, i32),
+    ) -> Option<(i32, i32)> {
+        let Some(radius) = self.stabilizer_radius(tool) else {
+            return Some(pointer);
+        };
+        let (brush_x, brush_y) = self
+            .stabilizer_brush
+            .unwrap_or((f64::from(pointer.0), f64::from(pointer.1)));
+        let dx = f64::from(pointer.0) - brush_x;
+        let dy = f64::from(pointer.1) - brush_y;
+        let distance = dx.hypot(dy);
+        if distance <= radius {
+            self.stabilizer_brush = Some((brush_x, brush_y));
+            return None;
+        }
+
+        let previous = (brush_x.round() as i32, brush_y.round() as i32);
+        let pull = (distance - radius) / distance;
+        let brush = (brush_x + dx * pull, brush_y + dy * pull);
+        self.stabilizer_brush = Some(brush);
+        let point = (brush.0.round() as i32, brush.1.round() as i32);
+        (point != previous).then_some(point)
+    }
+
+    /// Ends the lazy brush for a finished stroke. The brush trails the
+    /// pointer by up to the string length, so a stabilized stroke is
+    /// extended to the release point instead of stopping short of it.
+    pub(crate) fn finish_stroke_stabilizer(
+        &mut self,
+        tool: Tool,
+        release: (i32, i32),
+        points: &mut Vec<(i32, i32)>,
+        point_thicknesses: &mut Vec<f32>,
+    ) {
+        if self.stabilizer_brush.take().is_none() || self.stabilizer_radius(tool).is_none() {
+            return;
+        }
+        if points.last() == Some(&release) {
+            return;
+        }
+        points.push(release);
+        if let Some(&thickness) = point_thicknesses.last() {
+            point_thicknesses.push(thickness);
+        }
+    }
+
+    fn stabilizer_radius(&self, tool: Tool) -> Option<f64> {
+        let radius = self.stabilizer_strength.clamp(0.0, 1.0) * MAX_STABILIZER_RADIUS;
+        (radius > 0.0 && matches!(tool.drawing_behavior(), ToolDrawingBehavior::Path { .. }))
+            .then_some(radius)
+    }
+}
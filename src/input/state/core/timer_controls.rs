@@ -0,0 +1,178 @@
+use std::time::{Duration, Instant};
+
+use super::base::{InputState, Toast, ToastPriority};
+use crate::i18n::tr_args;
+use crate::input::state::timer::{TimerKind, TimerSettings, TimerState, TimerView, format_clock};
+
+impl InputState {
+    /// Applies timer settings loaded from config.
+    pub fn init_timer_from_config(&mut self, settings: TimerSettings) {
+        self.timer = TimerState::new(settings);
+    }
+
+    /// Returns whether a timer is showing (running, paused, or finished).
+    pub fn timer_active(&self) -> bool {
+        self.timer.run().is_some()
+    }
+
+    /// Starts a fresh break timer, talk timer, or stopwatch, replacing any
+    /// timer already showing. The break timer also switches to the configured
+    /// break board.
+    pub(crate) fn start_timer(&mut self, kind: TimerKind) {
+        self.timer.start(kind, Instant::now());
+        if kind == TimerKind::Break {
+            self.enter_break_board();
+        } else {
+            self.leave_break_board();
+        }
+        let message = match self
+            .timer
+            .run()
+            .and_then(|run| run.remaining(Instant::now()))
+        {
+            Some(remaining) => {
+                let time = format_clock(remaining.as_secs());
+                let args = [("time", time.as_str())];
+                match kind {
+                    TimerKind::Break => tr_args("Break timer started: {time}", &args),
+                    TimerKind::Talk => tr_args("Talk timer started: {time}", &args),
+                    TimerKind::Stopwatch => tr_args("Stopwatch timer started: {time}", &args),
+                }
+            }
+            None => "Stopwatch started".to_string(),
+        };
+        self.push_toast(ToastPriority::Info, "timer", Toast::info(message));
+        self.mark_timer_changed();
+    }
+
+    /// Pauses the running timer or resumes a paused one.
+    pub(crate) fn toggle_timer_pause(&mut self) -> bool {
+        let Some(paused) = self.timer.toggle_pause(Instant::now()) else {
+            let message = if self.timer_active() {
+                "Timer has finished: reset it or add a minute"
+            } else {
+                "No timer running"
+            };
+            self.push_toast(ToastPriority::Info, "timer", Toast::info(message));
+            return false;
+        };
+        let message = if paused {
+            "Timer paused"
+        } else {
+            "Timer resumed"
+        };
+        self.push_toast(ToastPriority::Info, "timer", Toast::info(message));
+        self.mark_timer_changed();
+        true
+    }
+
+    /// Stops and hides the timer, returning from the break board if the break
+    /// timer switched to it.
+    pub(crate) fn reset_timer(&mut self) -> bool {
+        if !self.timer.reset() {
+            return false;
+        }
+        self.leave_break_board();
+        self.mark_timer_changed();
+        true
+    }
+
+    /// Adds a minute to the running countdown.
+    pub(crate) fn add_timer_minute(&mut self) -> bool {
+        if !self.timer.add_minute(Instant::now()) {
+            let message = if self.timer_active() {
+                "Only a countdown can be extended"
+            } else {
+                "No timer running"
+            };
+            self.push_toast(ToastPriority::Info, "timer", Toast::info(message));
+            return false;
+        }
+        self.mark_timer_changed();
+        true
+    }
+
+    /// Switches between the large overlay and the compact status item.
+    pub(crate) fn toggle_timer_display(&mut self) {
+        let compact = self.timer.toggle_compact();
+        let message = if compact {
+            "Timer shown in the status bar"
+        } else {
+            "Timer shown as an overlay"
+        };
+        self.push_toast(ToastPriority::Info, "timer", Toast::info(message));
+        self.mark_timer_changed();
+    }
+
+    /// Advances the timer to `now`. Returns true when it needs a redraw.
+    pub fn tick_timer(&mut self, now: Instant) -> bool {
+        let tick = self.timer.tick(now);
+        if let Some(kind) = tick.finished {
+            let message = match kind {
+                TimerKind::Break => "Break is over",
+                _ => "Time is up",
+            };
+            self.push_toast(ToastPriority::Info, "timer", Toast::info(message));
+        }
+        if tick.changed {
+            self.dirty_tracker.mark_full();
+        }
+        tick.changed
+    }
+
+    /// How long the event loop may sleep before the timer needs a tick.
+    pub fn timer_timeout(&self, now: Instant) -> Option<Duration> {
+        self.timer.timeout(now)
+    }
+
+    /// Returns the countdown that reached zero since the last call, for the
+    /// backend to notify and play the end-of-time sound.
+    pub(crate) fn take_pending_timer_finished(&mut self) -> Option<TimerKind> {
+        self.timer.take_pending_finished()
+    }
+
+    pub(crate) fn timer_settings(&self) -> &TimerSettings {
+        self.timer.settings()
+    }
+
+    /// What to draw for the timer this frame, if one is showing.
+    pub(crate) fn timer_view(&self, now: Instant) -> Option<TimerView> {
+        self.timer.view(now)
+    }
+
+    fn enter_break_board(&mut self) {
+        let Some(target) = self.timer.settings().break_board.clone() else {
+            return;
+        };
+        if !self.boards.has_board(&target) || self.board_id() == target {
+            return;
+        }
+        // A restart keeps the board the first break timer left.
+        if self.timer.restore_board().is_none() {
+            self.timer
+                .set_restore_board(Some(self.board_id().to_string()));
+        }
+        self.switch_board_force(&target);
+    }
+
+    fn leave_break_board(&mut self) {
+        let Some(previous) = self.timer.take_restore_board() else {
+            return;
+        };
+        // Only undo our own switch: a board the user picked meanwhile stays.
+        let on_break_board = self
+            .timer
+            .settings()
+            .break_board
+            .as_deref()
+            .is_some_and(|board| board == self.board_id());
+        if on_break_board && self.boards.has_board(&previous) {
+            self.switch_board_force(&previous);
+        }
+    }
+
+    fn mark_timer_changed(&mut self) {
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+    }
+}
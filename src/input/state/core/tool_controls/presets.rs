@@ -114,6 +114,9 @@ impl InputState {
         if let Some(polygon_sides) = preset.polygon_sides {
             let _ = self.set_polygon_sides(polygon_sides);
         }
+        if let Some(line_style) = preset.line_style {
+            let _ = self.set_line_style(line_style);
+        }
         // Redraw only: the bar's visibility is a this-run preference the
         // session snapshot does not carry, unlike every tool value above it.
         if let Some(show_status_bar) = preset.show_status_bar
@@ -298,6 +301,7 @@ impl InputState {
             arrow_angle: Some(self.arrow_angle),
             arrow_head_at_end: Some(self.arrow_head_at_end),
             polygon_sides: Some(self.polygon_sides),
+            line_style: Some(self.line_style),
             show_status_bar: Some(self.show_status_bar),
             drag_tools: Some(self.drag_tool_bindings.to_config()),
         }
@@ -1,5 +1,5 @@
 use super::super::base::{DrawingState, InputState, MAX_STROKE_THICKNESS, MIN_STROKE_THICKNESS};
-use crate::draw::{BlurStyle, Color, FontDescriptor, clamp_regular_sides};
+use crate::draw::{BlurStyle, Color, FontDescriptor, LineStyle, clamp_regular_sides};
 use crate::input::state::{Toast, ToastPriority};
 use crate::input::{
     DragBinding, MouseButton,
@@ -165,7 +165,7 @@ impl InputState {
                 self.presenter_mode_config.tool_behavior,
                 crate::config::PresenterToolBehavior::ForceHighlightLocked
             )
-            && tool != Some(Tool::Highlight)
+            && !matches!(tool, Some(Tool::Highlight | Tool::Laser))
         {
             return false;
         }
@@ -175,6 +175,7 @@ impl InputState {
 
         self.tool_override = tool;
         self.active_preset_slot = None;
+        self.reset_laser_pointer();
 
         if tool == Some(Tool::Blur)
             && self.blur_style.needs_backdrop()
@@ -406,6 +407,23 @@ impl InputState {
         true
     }
 
+    /// Sets the dash style for new strokes and outlines. Returns true if changed.
+    pub fn set_line_style(&mut self, style: LineStyle) -> bool {
+        if self.line_style == style {
+            return false;
+        }
+        self.line_style = style;
+        self.dirty_tracker.mark_full();
+        self.needs_redraw = true;
+        self.mark_session_dirty();
+        true
+    }
+
+    /// Steps to the next line style, wrapping around.
+    pub fn cycle_line_style(&mut self) -> bool {
+        self.set_line_style(self.line_style.next())
+    }
+
     /// Sets the font descriptor used for text rendering. Returns true if changed.
     #[allow(dead_code)]
     pub fn set_font_descriptor(&mut self, descriptor: FontDescriptor) -> bool {
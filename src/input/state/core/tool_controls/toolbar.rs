@@ -4,6 +4,7 @@ use crate::config::{
     TopDisplayMode, factory_individual_toolbar_item_visibility_settings,
 };
 use crate::domain::Action;
+use crate::i18n::tr_args;
 use crate::input::state::{Toast, ToastPriority};
 
 /// How long the "Cleared — Undo?" toast stays up after a mouse-path clear.
@@ -98,17 +99,20 @@ impl InputState {
         }
         let mut parts = Vec::new();
         if let Some(binding) = self.action_binding_primary_label(Action::ToggleToolbar) {
-            parts.push(format!("{binding} toolbar"));
+            parts.push(tr_args("{binding} toolbar", &[("binding", &binding)]));
         }
         if let Some(binding) = self.action_binding_primary_label(Action::ToggleStatusBar) {
-            parts.push(format!("{binding} status bar"));
+            parts.push(tr_args("{binding} status bar", &[("binding", &binding)]));
         }
         let message = if parts.is_empty() && self.right_click_chrome_recovery_available() {
             "All UI hidden — right-click to restore".to_string()
         } else if parts.is_empty() {
             "All UI hidden — select the recovery action".to_string()
         } else {
-            format!("All UI hidden — {}", parts.join(" · "))
+            tr_args(
+                "All UI hidden — {restore}",
+                &[("restore", &parts.join(" · "))],
+            )
         };
         let (action_label, recovery_action) =
             if self.presenter_mode && self.presenter_mode_config.hide_toolbars {
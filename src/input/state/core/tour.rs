@@ -39,9 +39,9 @@ impl TourStep {
         }
     }
 
-    /// Get step title.
+    /// Get step title, in the UI language.
     pub fn title(&self) -> &'static str {
-        match self {
+        crate::i18n::tr(match self {
             Self::Welcome => "Welcome to Wayscriber",
             Self::DrawingBasics => "Drawing Basics",
             Self::ToolbarIntro => "Toolbar Access",
@@ -51,15 +51,15 @@ impl TourStep {
             Self::HelpOverlay => "Help & Shortcuts",
             Self::Presets => "Quick Presets",
             Self::Complete => "Tour Complete",
-        }
+        })
     }
 
     /// Get navigation hint for the step.
     pub fn nav_hint(&self) -> &'static str {
-        match self {
+        crate::i18n::tr(match self {
             Self::Complete => "Press Enter or Escape to finish",
             _ => "Space/Enter: Next  |  Backspace: Back  |  Escape: Skip",
-        }
+        })
     }
 }
 
@@ -69,154 +69,176 @@ impl InputState {
     /// the toolbar rebind-modifier helper), so no key string is ever hardcoded
     /// and the copy tracks the user's actual configuration.
     pub fn tour_step_description(&self, step: TourStep) -> String {
+        use crate::i18n::{tr, tr_args};
+
+        let mut lines: Vec<String> = Vec::new();
+        let mut push = |text: &str| lines.push(text.to_string());
         match step {
-            TourStep::Welcome => "Wayscriber is a screen annotation tool.\n\
-                 Draw anywhere on your screen to highlight, explain, or present."
-                .to_string(),
+            TourStep::Welcome => {
+                push(tr("Wayscriber is a screen annotation tool."));
+                push(tr(
+                    "Draw anywhere on your screen to highlight, explain, or present.",
+                ));
+            }
             TourStep::DrawingBasics => {
-                let mut lines = vec!["Click and drag to draw with the pen tool.".to_string()];
+                push(tr("Click and drag to draw with the pen tool."));
                 match self.tour_join_shortcuts(&[
                     Action::SetColorRed,
                     Action::SetColorGreen,
                     Action::SetColorBlue,
                     Action::SetColorYellow,
                 ]) {
-                    Some(colors) => lines.push(format!("Press {colors} to change colors.")),
-                    None => lines.push("Use the color keys to change colors.".to_string()),
+                    Some(colors) => push(&tr_args(
+                        "Press {colors} to change colors.",
+                        &[("colors", &colors)],
+                    )),
+                    None => push(tr("Use the color keys to change colors.")),
                 }
                 match self
                     .tour_join_shortcuts(&[Action::IncreaseThickness, Action::DecreaseThickness])
                 {
-                    Some(thick) => {
-                        lines.push(format!("Scroll wheel or {thick} adjusts thickness."))
-                    }
-                    None => lines.push("The scroll wheel adjusts thickness.".to_string()),
+                    Some(thick) => push(&tr_args(
+                        "Scroll wheel or {keys} adjusts thickness.",
+                        &[("keys", &thick)],
+                    )),
+                    None => push(tr("The scroll wheel adjusts thickness.")),
                 }
                 match self.shortcut_for_action(Action::ToggleRadialMenu) {
-                    Some(radial) => lines.push(format!(
-                        "{radial} opens the radial menu for quick tool/color changes."
+                    Some(radial) => push(&tr_args(
+                        "{key} opens the radial menu for quick tool/color changes.",
+                        &[("key", &radial)],
                     )),
-                    None => {
-                        lines.push("The radial menu offers quick tool/color changes.".to_string())
-                    }
+                    None => push(tr("The radial menu offers quick tool/color changes.")),
                 }
-                lines.join("\n")
             }
             TourStep::ToolbarIntro => {
-                let mut lines = Vec::new();
                 let toggle = self.shortcut_for_action(Action::ToggleToolbar);
                 let cycle = self.shortcut_for_action(Action::CycleToolbarDisplay);
                 match (toggle, cycle) {
-                    (Some(toggle), Some(cycle)) => lines.push(format!(
+                    (Some(toggle), Some(cycle)) => push(&tr_args(
                         "Press {toggle} to toggle the toolbar; {cycle} cycles it \
-                         full \u{2192} micro \u{2192} hidden."
+                         full \u{2192} micro \u{2192} hidden.",
+                        &[("toggle", &toggle), ("cycle", &cycle)],
                     )),
-                    (Some(toggle), None) => {
-                        lines.push(format!("Press {toggle} to toggle the toolbar."))
-                    }
-                    (None, Some(cycle)) => lines.push(format!(
-                        "Press {cycle} to cycle the toolbar full \u{2192} micro \u{2192} hidden."
+                    (Some(toggle), None) => push(&tr_args(
+                        "Press {toggle} to toggle the toolbar.",
+                        &[("toggle", &toggle)],
                     )),
-                    (None, None) => lines.push("Toggle the toolbar from its actions.".to_string()),
+                    (None, Some(cycle)) => push(&tr_args(
+                        "Press {cycle} to cycle the toolbar full \u{2192} micro \u{2192} hidden.",
+                        &[("cycle", &cycle)],
+                    )),
+                    (None, None) => push(tr("Toggle the toolbar from its actions.")),
                 }
-                lines.push(
-                    "The toolbar provides quick access to all tools and settings.".to_string(),
-                );
+                push(tr(
+                    "The toolbar provides quick access to all tools and settings.",
+                ));
                 if let Some(click) = self.toolbar_rebind_modifier.click_label() {
-                    lines.push(format!(
-                        "By default, {click} a bindable control to change its shortcut."
+                    push(&tr_args(
+                        "By default, {click} a bindable control to change its shortcut.",
+                        &[("click", click)],
                     ));
                 }
-                lines.join("\n")
             }
             TourStep::CommandPalette => {
-                let mut lines = Vec::new();
                 match self.shortcut_for_action(Action::ToggleCommandPalette) {
-                    Some(key) => lines.push(format!("Press {key} to open the command palette.")),
-                    None => lines.push("Open the command palette to run any action.".to_string()),
+                    Some(key) => push(&tr_args(
+                        "Press {key} to open the command palette.",
+                        &[("key", &key)],
+                    )),
+                    None => push(tr("Open the command palette to run any action.")),
                 }
-                lines.push("Quickly search and run any action by typing.".to_string());
-                lines.push("Use the row controls to edit, unbind, or reset shortcuts.".to_string());
-                lines.push(
-                    "Press Ctrl+Shift+E on a row to change its shortcut in the configurator."
-                        .to_string(),
-                );
-                lines.join("\n")
+                push(tr("Quickly search and run any action by typing."));
+                push(tr(
+                    "Use the row controls to edit, unbind, or reset shortcuts.",
+                ));
+                push(tr(
+                    "Press Ctrl+Shift+E on a row to change its shortcut in the configurator.",
+                ));
+            }
+            TourStep::ContextMenu => {
+                push(tr("Right-click anywhere for quick actions."));
+                push(tr("Access boards, pages, and common commands."));
+                push(tr("Shape-specific options when clicking on shapes."));
             }
-            TourStep::ContextMenu => "Right-click anywhere for quick actions.\n\
-                 Access boards, pages, and common commands.\n\
-                 Shape-specific options when clicking on shapes."
-                .to_string(),
             TourStep::StatusBar => {
                 let board = self.show_status_board_badge && self.boards.show_badge();
                 let page = self.show_status_page_badge;
                 let entry = match (board, page) {
-                    (true, true) => Some("Board or Page"),
-                    (true, false) => Some("Board"),
-                    (false, true) => Some("Page"),
+                    (true, true) => Some(tr("Board or Page")),
+                    (true, false) => Some(tr("Board")),
+                    (false, true) => Some(tr("Page")),
                     (false, false) => None,
                 };
-                let mut lines = match entry {
-                    Some(entry) => vec![format!(
-                        "Click the {entry} segment in the status bar to open the board picker."
-                    )],
-                    None => vec![
-                        "Board/Page status-bar segments are hidden in your configuration."
-                            .to_string(),
-                    ],
-                };
+                match entry {
+                    Some(entry) => push(&tr_args(
+                        "Click the {segment} segment in the status bar to open the board picker.",
+                        &[("segment", entry)],
+                    )),
+                    None => push(tr(
+                        "Board/Page status-bar segments are hidden in your configuration.",
+                    )),
+                }
                 match (entry, self.shortcut_for_action(Action::BoardPicker)) {
-                    (Some(_), Some(key)) => lines.push(format!(
-                        "Switch between boards and pages there, or press {key}."
+                    (Some(_), Some(key)) => push(&tr_args(
+                        "Switch between boards and pages there, or press {key}.",
+                        &[("key", &key)],
+                    )),
+                    (Some(_), None) => push(tr("Switch between boards and pages there.")),
+                    (None, Some(key)) => push(&tr_args(
+                        "Press {key} to open the board picker.",
+                        &[("key", &key)],
+                    )),
+                    (None, None) => push(tr("Open the board picker from an action menu.")),
+                }
+            }
+            TourStep::HelpOverlay => {
+                match self.shortcut_for_action(Action::ToggleHelp) {
+                    Some(key) => push(&tr_args(
+                        "Press {key} to see all keyboard shortcuts.",
+                        &[("key", &key)],
                     )),
-                    (Some(_), None) => {
-                        lines.push("Switch between boards and pages there.".to_string())
-                    }
-                    (None, Some(key)) => {
-                        lines.push(format!("Press {key} to open the board picker."))
-                    }
-                    (None, None) => {
-                        lines.push("Open the board picker from an action menu.".to_string())
-                    }
+                    None => push(tr("Open the help overlay to see all keyboard shortcuts.")),
                 }
-                lines.join("\n")
+                push(tr("Type to search for specific commands."));
             }
-            TourStep::HelpOverlay => match self.shortcut_for_action(Action::ToggleHelp) {
-                Some(key) => format!(
-                    "Press {key} to see all keyboard shortcuts.\n\
-                     Type to search for specific commands."
-                ),
-                None => "Open the help overlay to see all keyboard shortcuts.\n\
-                     Type to search for specific commands."
-                    .to_string(),
-            },
             TourStep::Presets => {
-                let mut lines = Vec::new();
                 match self.tour_shortcut_range(Action::ApplyPreset1, Action::ApplyPreset5) {
-                    Some(apply) => lines.push(format!("{apply} apply saved tool presets.")),
-                    None => lines.push("Preset keys apply saved tool presets.".to_string()),
+                    Some(apply) => push(&tr_args(
+                        "{keys} apply saved tool presets.",
+                        &[("keys", &apply)],
+                    )),
+                    None => push(tr("Preset keys apply saved tool presets.")),
                 }
                 if let Some(save) =
                     self.tour_shortcut_range(Action::SavePreset1, Action::SavePreset5)
                 {
-                    lines.push(format!("{save} saves current tool settings."));
+                    push(&tr_args(
+                        "{keys} saves current tool settings.",
+                        &[("keys", &save)],
+                    ));
                 }
                 if let Some(clear) =
                     self.tour_shortcut_range(Action::ClearPreset1, Action::ClearPreset5)
                 {
-                    lines.push(format!("{clear} clears a preset slot."));
+                    push(&tr_args(
+                        "{keys} clears a preset slot.",
+                        &[("keys", &clear)],
+                    ));
+                }
+            }
+            TourStep::Complete => {
+                push(tr("You're ready to annotate!"));
+                if let Some(key) = self.shortcut_for_action(Action::ToggleHelp) {
+                    push(&tr_args(
+                        "Press {key} anytime to review shortcuts.",
+                        &[("key", &key)],
+                    ));
                 }
-                lines.join("\n")
+                push(tr("Enjoy using Wayscriber!"));
             }
-            TourStep::Complete => match self.shortcut_for_action(Action::ToggleHelp) {
-                Some(key) => format!(
-                    "You're ready to annotate!\n\
-                     Press {key} anytime to review shortcuts.\n\
-                     Enjoy using Wayscriber!"
-                ),
-                None => "You're ready to annotate!\nEnjoy using Wayscriber!".to_string(),
-            },
         }
+        lines.join("\n")
     }
 
     /// Resolve each action's shortcut and join the bound ones with `"/"`.
@@ -138,6 +138,10 @@ impl InputState {
         ) {
             self.needs_redraw = true;
         }
+        self.update_laser_pointer(canvas_x, canvas_y);
+        if self.magnifier.active() {
+            self.needs_redraw = true;
+        }
     }
 
     /// Updates the cached pointer location without triggering pointer-driven visuals.
@@ -224,6 +228,7 @@ impl InputState {
             }
             DrawingState::MovingSelection { snapshots, .. } => {
                 self.restore_selection_from_snapshots(snapshots.clone());
+                self.end_selection_drag_snapping();
                 self.state = DrawingState::Idle;
             }
             DrawingState::Selecting { .. } => {
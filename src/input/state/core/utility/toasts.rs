@@ -7,6 +7,7 @@ use crate::capture::{
     file::{FileSaveConfig, save_screenshot},
 };
 use crate::domain::Action;
+use crate::i18n::tr_args;
 use std::path::Path;
 use std::time::{Duration, Instant};
 
@@ -48,22 +49,27 @@ impl InputState {
         let mut parts = Vec::new();
         self.last_capture_path = saved_path.map(|path| path.to_path_buf());
         if let Some(path) = saved_path {
-            let mut saved = format!("Saved to {}", path.display());
+            let mut saved =
+                crate::i18n::tr_args("Saved to {path}", &[("path", &path.display().to_string())]);
             if let Some(binding) = open_folder_binding {
-                saved.push_str(&format!(" ({binding} opens folder)"));
+                saved.push(' ');
+                saved.push_str(&crate::i18n::tr_args(
+                    "({shortcut} opens folder)",
+                    &[("shortcut", binding)],
+                ));
             }
             parts.push(saved);
         }
 
         if copied_to_clipboard {
             if saved_path.is_none() {
-                parts.push("Clipboard only (no file saved)".to_string());
+                parts.push(crate::i18n::tr("Clipboard only (no file saved)").to_string());
             }
-            parts.push("Copied to clipboard".to_string());
+            parts.push(crate::i18n::tr("Copied to clipboard").to_string());
         }
 
         if parts.is_empty() {
-            parts.push("Screenshot captured".to_string());
+            parts.push(crate::i18n::tr("Screenshot captured").to_string());
         }
 
         self.push_toast(
@@ -247,7 +253,10 @@ impl InputState {
                     self.push_toast(
                         ToastPriority::Info,
                         "capture.save",
-                        Toast::info(format!("Saved to {}", filename.to_string_lossy())),
+                        Toast::info(tr_args(
+                            "Saved to {file}",
+                            &[("file", &filename.to_string_lossy())],
+                        )),
                     );
                 } else {
                     self.push_toast(
@@ -258,6 +267,9 @@ impl InputState {
                             ImageOperationKind::CanvasExport => "Canvas exported",
                             ImageOperationKind::BoardPdfExport => "Board exported",
                             ImageOperationKind::AllBoardsPdfExport => "Boards exported",
+                            ImageOperationKind::CanvasSvgExport => "Canvas exported",
+                            ImageOperationKind::BoardSvgExport => "Board exported",
+                            ImageOperationKind::ReplayFrameExport => "Replay exported",
                         }),
                     );
                 }
@@ -278,7 +290,7 @@ impl InputState {
                 self.push_toast(
                     ToastPriority::Critical,
                     "capture.save",
-                    Toast::error(format!("Save failed: {message}"))
+                    Toast::error(tr_args("Save failed: {message}", &[("message", &message)]))
                         .action("Retry", Action::SavePendingToFile),
                 );
                 self.trigger_blocked_feedback();
@@ -313,9 +325,10 @@ impl InputState {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::capture::encode::ImageEncoding;
     use crate::config::{BoardsConfig, KeybindingsConfig, PresenterModeConfig};
     use crate::domain::OnboardingTip;
-    use crate::draw::{Color, FontDescriptor, Shape};
+    use crate::draw::{Color, FontDescriptor, LineStyle, Shape};
     use crate::input::state::core::base::{TextEditEntryFeedback, UiToastKind};
     use crate::input::{ClickHighlightSettings, EraserMode};
     use crate::ui::toolbar::ToolbarEvent;
@@ -567,6 +580,8 @@ mod tests {
                 a: 1.0,
             },
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
     }
 
@@ -799,6 +814,7 @@ mod tests {
                 save_directory: temp.path().to_path_buf(),
                 filename_template: "fallback".to_string(),
                 format: "png".to_string(),
+                encoding: ImageEncoding::default(),
             },
             ImageOperationKind::Screenshot,
             true,
@@ -824,6 +840,7 @@ mod tests {
                 save_directory: not_a_directory,
                 filename_template: "canvas_fallback".to_string(),
                 format: "png".to_string(),
+                encoding: ImageEncoding::default(),
             },
             ImageOperationKind::CanvasExport,
             false,
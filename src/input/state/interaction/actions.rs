@@ -8,7 +8,8 @@ pub(crate) fn classify_action(action: Action) -> ActionRoute {
         Action::Exit
         | Action::EnterTextMode
         | Action::EnterStickyNoteMode
-        | Action::ClearCanvas => ActionRoute::Core,
+        | Action::ClearCanvas
+        | Action::ClearActiveLayer => ActionRoute::Core,
         Action::Undo
         | Action::Redo
         | Action::UndoAll
@@ -21,6 +22,10 @@ pub(crate) fn classify_action(action: Action) -> ActionRoute {
         | Action::SelectAll
         | Action::MoveSelectionToFront
         | Action::MoveSelectionToBack
+        | Action::MoveSelectionToLayerAbove
+        | Action::MoveSelectionToLayerBelow
+        | Action::GroupSelection
+        | Action::UngroupSelection
         | Action::NudgeSelectionUp
         | Action::NudgeSelectionDown
         | Action::NudgeSelectionLeft
@@ -31,6 +36,14 @@ pub(crate) fn classify_action(action: Action) -> ActionRoute {
         | Action::MoveSelectionToEnd
         | Action::MoveSelectionToTop
         | Action::MoveSelectionToBottom
+        | Action::AlignSelectionLeft
+        | Action::AlignSelectionCenter
+        | Action::AlignSelectionRight
+        | Action::AlignSelectionTop
+        | Action::AlignSelectionMiddle
+        | Action::AlignSelectionBottom
+        | Action::DistributeSelectionHorizontally
+        | Action::DistributeSelectionVertically
         | Action::DeleteSelection => ActionRoute::Selection,
         Action::IncreaseThickness
         | Action::DecreaseThickness
@@ -42,6 +55,7 @@ pub(crate) fn classify_action(action: Action) -> ActionRoute {
         | Action::SelectEraserTool
         | Action::ToggleEraserMode
         | Action::CycleBlurStyle
+        | Action::CycleLineStyle
         | Action::SelectPenTool
         | Action::SelectLineTool
         | Action::SelectRectTool
@@ -55,12 +69,15 @@ pub(crate) fn classify_action(action: Action) -> ActionRoute {
         | Action::SelectBlurTool
         | Action::SelectSpotlightTool
         | Action::SelectHighlightTool
+        | Action::SelectLaserTool
         | Action::IncreaseFontSize
         | Action::DecreaseFontSize
         | Action::ResetArrowLabelCounter
         | Action::ResetStepMarkerCounter
         | Action::ToggleHighlightTool
-        | Action::ToggleFill => ActionRoute::Tool,
+        | Action::ToggleFadeInk
+        | Action::ToggleFill
+        | Action::ToggleConnectorMode => ActionRoute::Tool,
         Action::ToggleWhiteboard
         | Action::ToggleBlackboard
         | Action::ReturnToTransparent
@@ -86,7 +103,9 @@ pub(crate) fn classify_action(action: Action) -> ActionRoute {
         | Action::PageNew
         | Action::PageDuplicate
         | Action::PageDelete
-        | Action::PageRestoreDeleted => ActionRoute::BoardPages,
+        | Action::PageRestoreDeleted
+        | Action::ToggleGrid
+        | Action::ToggleSnapToGrid => ActionRoute::BoardPages,
         Action::ToggleHelp
         | Action::ToggleQuickHelp
         | Action::ToggleStatusBar
@@ -116,7 +135,15 @@ pub(crate) fn classify_action(action: Action) -> ActionRoute {
         | Action::ClearSavedToolState
         | Action::OpenCaptureFolder
         | Action::ToggleCommandPalette
-        | Action::ReplayTour => ActionRoute::Ui,
+        | Action::ReplayTour
+        | Action::ToggleReplay
+        | Action::StartBreakTimer
+        | Action::StartTalkTimer
+        | Action::StartStopwatch
+        | Action::ToggleTimerPause
+        | Action::ResetTimer
+        | Action::TimerAddMinute
+        | Action::ToggleTimerDisplay => ActionRoute::Ui,
         Action::SetColorRed
         | Action::SetColorGreen
         | Action::SetColorBlue
@@ -140,6 +167,10 @@ pub(crate) fn classify_action(action: Action) -> ActionRoute {
         | Action::ExportCanvasClipboardAndFile
         | Action::ExportBoardPdfFile
         | Action::ExportAllBoardsPdfFile
+        | Action::ExportCanvasSvgFile
+        | Action::ExportBoardSvgFile
+        | Action::ExportReplayFrames
+        | Action::ToggleRecording
         | Action::CopyTextFromScreen
         | Action::ToggleFrozenMode
         | Action::ZoomIn
@@ -147,6 +178,9 @@ pub(crate) fn classify_action(action: Action) -> ActionRoute {
         | Action::ResetZoom
         | Action::ToggleZoomLock
         | Action::RefreshZoomCapture
+        | Action::ToggleMagnifier
+        | Action::MagnifierZoomIn
+        | Action::MagnifierZoomOut
         | Action::FocusNextOutput
         | Action::FocusPrevOutput
         | Action::SavePendingToFile => ActionRoute::CaptureZoom,
@@ -175,6 +209,11 @@ pub(crate) fn route_action(state: &mut InputState, action: Action) -> RoutingOut
     ) {
         adapters::close_properties_panel_before_action(state);
     }
+    // The live page is hidden behind a replay, so any command that could
+    // change or move away from it ends the replay first.
+    if !matches!(action, Action::ToggleReplay | Action::ExportReplayFrames) {
+        state.stop_replay();
+    }
 
     let route = classify_action(action);
     adapters::dispatch_action(state, action, route);
@@ -3,7 +3,7 @@ use super::super::outcome::{
     ActiveInteractionKind, InteractionSideEffect, NoRouteReason, PointerSideEffect, RoutingOutcome,
 };
 use crate::input::state::mouse::TEXT_CLICK_DRAG_THRESHOLD;
-use crate::input::state::{DrawingState, InputState};
+use crate::input::state::{DrawingState, InputState, SelectionHandle};
 use crate::input::tool::{ToolMotionBehavior, ToolMotionSizeSource, ToolPressBehavior};
 use crate::input::{EraserMode, MouseButton, Tool};
 use std::sync::Arc;
@@ -49,8 +49,9 @@ pub(crate) fn handle_active_motion(
                 tool.press_behavior(),
                 ToolPressBehavior::StartDrawing { .. }
             ) {
+                let (start_x, start_y) = state.snap_tool_point(tool, (*start_x, *start_y));
                 let drawing_thickness = state.thickness_for_tool(tool);
-                let mut points = vec![(*start_x, *start_y)];
+                let mut points = vec![(start_x, start_y)];
                 let mut point_thicknesses = vec![drawing_thickness as f32];
                 if let Some(sample_size) = motion_sample_size(state, tool) {
                     points.push((canvas.x(), canvas.y()));
@@ -58,8 +59,8 @@ pub(crate) fn handle_active_motion(
                 }
                 state.state = DrawingState::Drawing {
                     tool,
-                    start_x: *start_x,
-                    start_y: *start_y,
+                    start_x,
+                    start_y,
                     points,
                     point_thicknesses,
                 };
@@ -78,7 +79,7 @@ pub(crate) fn handle_active_motion(
         let dx = canvas.x() - *last_x;
         let dy = canvas.y() - *last_y;
         if (dx != 0 || dy != 0)
-            && state.apply_translation_to_selection(dx, dy)
+            && let Some(moved_now) = state.drag_selection_by(dx, dy)
             && let DrawingState::MovingSelection {
                 last_x,
                 last_y,
@@ -88,7 +89,7 @@ pub(crate) fn handle_active_motion(
         {
             *last_x = canvas.x();
             *last_y = canvas.y();
-            *moved = true;
+            *moved |= moved_now;
         }
         return Some(RoutingOutcome::Continued(
             ActiveInteractionKind::MovingSelection,
@@ -108,7 +109,20 @@ pub(crate) fn handle_active_motion(
         let handle = *handle;
         let original_bounds = *original_bounds;
         let snapshots = Arc::clone(snapshots);
-        state.apply_selection_resize(handle, &original_bounds, dx, dy, snapshots.as_ref());
+        if handle == SelectionHandle::Rotate {
+            let start = (*start_x, *start_y);
+            let snap = state.modifiers.shift;
+            state.apply_selection_rotation(
+                &original_bounds,
+                start,
+                (canvas.x(), canvas.y()),
+                snap,
+                snapshots.as_ref(),
+            );
+        } else {
+            let (dx, dy) = state.snap_resize_delta(handle, &original_bounds, dx, dy);
+            state.apply_selection_resize(handle, &original_bounds, dx, dy, snapshots.as_ref());
+        }
         state.needs_redraw = true;
         return Some(RoutingOutcome::Continued(
             ActiveInteractionKind::ResizingSelection,
@@ -141,25 +155,30 @@ pub(crate) fn handle_drawing_or_idle_motion(
 ) -> RoutingOutcome {
     let canvas = points.canvas();
     let mut drawing = false;
-    let sample_size = if let DrawingState::Drawing { tool, .. } = &state.state {
-        motion_sample_size(state, *tool)
-    } else {
-        None
+    let drawing_tool = match &state.state {
+        DrawingState::Drawing { tool, .. } => Some(*tool),
+        _ => None,
     };
+    let sample = drawing_tool.and_then(|tool| {
+        let size = motion_sample_size(state, tool)?;
+        let point = state.stabilize_stroke_point(tool, (canvas.x(), canvas.y()))?;
+        Some((point, size))
+    });
     if let DrawingState::Drawing {
         points,
         point_thicknesses,
         ..
     } = &mut state.state
     {
-        if let Some(thickness) = sample_size {
-            points.push((canvas.x(), canvas.y()));
+        if let Some((point, thickness)) = sample {
+            points.push(point);
             point_thicknesses.push(thickness as f32);
         }
         drawing = true;
     }
 
     if drawing {
+        state.track_smart_ink_rest((canvas.x(), canvas.y()));
         state.update_provisional_dirty(canvas.x(), canvas.y());
         state.needs_redraw = true;
         RoutingOutcome::Continued(ActiveInteractionKind::Drawing)
@@ -90,6 +90,11 @@ pub(crate) fn handle_global_modifier_key(
         .then_some(modifier_key_side_effect())
 }
 
+pub(crate) fn handle_replay_key(state: &mut InputState, key: Key) -> Option<RoutingOutcome> {
+    (state.replay_active() && state.handle_replay_key(key))
+        .then_some(RoutingOutcome::Consumed(ConsumedBy::Replay))
+}
+
 pub(crate) fn handle_properties_panel_key(
     state: &mut InputState,
     key: Key,
@@ -13,8 +13,9 @@ pub(crate) use keyboard::{
     handle_color_picker_key, handle_command_palette_key, handle_context_menu_key,
     handle_drawing_escape_cancel_key, handle_global_modifier_key, handle_help_overlay_key,
     handle_idle_selection_cancel_key, handle_pending_delete_cancel_key, handle_precision_entry_key,
-    handle_properties_panel_key, handle_radial_menu_key, handle_return_edit_selected_text_key,
-    handle_text_input_key, handle_top_popover_dismiss_key, handle_tour_key,
+    handle_properties_panel_key, handle_radial_menu_key, handle_replay_key,
+    handle_return_edit_selected_text_key, handle_text_input_key, handle_top_popover_dismiss_key,
+    handle_tour_key,
 };
 pub(crate) use pointer::{
     close_properties_panel_before_tool_routing, finish_pointer_interaction,
@@ -22,7 +23,7 @@ pub(crate) use pointer::{
     handle_color_picker_motion, handle_color_picker_press, handle_context_menu_motion,
     handle_left_context_menu_press, handle_middle_press, handle_properties_panel_motion,
     handle_properties_panel_press, handle_radial_menu_motion, handle_radial_menu_press,
-    handle_radial_menu_release, handle_release_overlays, handle_right_press,
-    handle_status_hud_press, handle_tool_button_press, handle_unbound_left_press,
-    handle_zoom_chip_press, update_pointer_positions,
+    handle_radial_menu_release, handle_release_overlays, handle_replay_motion, handle_replay_press,
+    handle_replay_release, handle_right_press, handle_status_hud_press, handle_tool_button_press,
+    handle_unbound_left_press, handle_zoom_chip_press, update_pointer_positions,
 };
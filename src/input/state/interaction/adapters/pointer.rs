@@ -148,6 +148,36 @@ pub(crate) fn handle_zoom_chip_press(
     Some(RoutingOutcome::Consumed(ConsumedBy::ZoomChip))
 }
 
+pub(crate) fn handle_replay_press(
+    state: &mut InputState,
+    button: MouseButton,
+    points: PointerPoints,
+) -> Option<RoutingOutcome> {
+    if !state.replay_active() {
+        return None;
+    }
+    let screen = points.screen();
+    if button == MouseButton::Left {
+        state.handle_replay_press(screen.x() as f64, screen.y() as f64);
+    }
+    Some(RoutingOutcome::Consumed(ConsumedBy::Replay))
+}
+
+pub(crate) fn handle_replay_motion(
+    state: &mut InputState,
+    points: PointerPoints,
+) -> Option<RoutingOutcome> {
+    state
+        .handle_replay_motion(points.screen().x() as f64)
+        .then_some(RoutingOutcome::Consumed(ConsumedBy::Replay))
+}
+
+pub(crate) fn handle_replay_release(state: &mut InputState) -> Option<RoutingOutcome> {
+    state
+        .handle_replay_release()
+        .then_some(RoutingOutcome::Consumed(ConsumedBy::Replay))
+}
+
 pub(crate) fn close_properties_panel_before_tool_routing(state: &mut InputState) {
     state.close_properties_panel();
 }
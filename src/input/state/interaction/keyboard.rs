@@ -53,6 +53,9 @@ fn route_key_event(state: &mut InputState, key: Key, is_repeat: bool) -> Routing
     if let Some(outcome) = adapters::handle_global_modifier_key(state, key) {
         return outcome;
     }
+    if let Some(outcome) = adapters::handle_replay_key(state, key) {
+        return outcome;
+    }
     if let Some(outcome) = adapters::handle_properties_panel_key(state, key) {
         return outcome;
     }
@@ -24,7 +24,7 @@ mod tests {
     };
     use super::*;
     use crate::config::Action;
-    use crate::draw::Shape;
+    use crate::draw::{LineStyle, Shape};
     use crate::input::state::test_support::make_test_input_state;
     use crate::input::{BOARD_ID_BLACKBOARD, EraserMode, Key, MouseButton, Tool};
 
@@ -41,6 +41,8 @@ mod tests {
             fill: false,
             color: state.current_color,
             thick: state.current_thickness,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         })
     }
 
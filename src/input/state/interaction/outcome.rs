@@ -27,6 +27,7 @@ pub(crate) enum ConsumedBy {
     RadialMenuToggle,
     StatusHud,
     ZoomChip,
+    Replay,
     SequencePrefix,
 }
 
@@ -12,6 +12,9 @@ pub(crate) fn route_pointer_press(state: &mut InputState, event: PointerPress) -
     // interaction.
     state.clear_status_hud_press_pending();
     state.clear_zoom_chip_press_pending();
+    if let Some(outcome) = adapters::handle_replay_press(state, event.button(), points) {
+        return outcome;
+    }
     if let Some(outcome) =
         adapters::handle_building_polygon_non_left_press(state, event.button(), points)
     {
@@ -71,6 +74,9 @@ pub(crate) fn route_pointer_motion(state: &mut InputState, event: PointerMotion)
         state.update_status_hud_hover_from_pointer(screen.x(), screen.y());
         state.update_zoom_chip_hover_from_pointer(screen.x(), screen.y());
     }
+    if let Some(outcome) = adapters::handle_replay_motion(state, points) {
+        return outcome;
+    }
     if let Some(outcome) = adapters::handle_radial_menu_motion(state, points) {
         return outcome;
     }
@@ -99,6 +105,10 @@ pub(crate) fn route_pointer_release(
     let points = event.points();
     adapters::update_pointer_positions(state, points);
 
+    if let Some(outcome) = adapters::handle_replay_release(state) {
+        return outcome;
+    }
+
     // Status HUD press→release contract for paths that route presses through
     // this chain (tablet, touch fallbacks): a HUD press consumed by
     // `handle_status_hud_press` activates its chip on release-inside. The
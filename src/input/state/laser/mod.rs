@@ -0,0 +1,6 @@
+mod settings;
+mod state;
+
+pub use settings::LaserSettings;
+pub(crate) use state::FadingInk;
+pub use state::LaserState;
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use crate::config::LaserConfig;
+use crate::draw::Color;
+
+/// Runtime settings for the laser pointer and fade ink.
+#[derive(Clone)]
+pub struct LaserSettings {
+    pub color: Color,
+    pub width: f64,
+    /// How long a trail point stays visible.
+    pub fade: Duration,
+    /// Whether fade ink starts enabled.
+    pub fade_ink: bool,
+    /// How long a fade-ink stroke stays on the page.
+    pub fade_ink_duration: Duration,
+}
+
+impl Default for LaserSettings {
+    fn default() -> Self {
+        Self::from(&LaserConfig::default())
+    }
+}
+
+impl From<&LaserConfig> for LaserSettings {
+    fn from(cfg: &LaserConfig) -> Self {
+        Self {
+            color: Color {
+                r: cfg.color[0],
+                g: cfg.color[1],
+                b: cfg.color[2],
+                a: cfg.color[3],
+            },
+            width: cfg.width,
+            fade: Duration::from_millis(cfg.fade_ms),
+            fade_ink: cfg.fade_ink,
+            fade_ink_duration: Duration::from_secs_f64(cfg.fade_ink_seconds),
+        }
+    }
+}
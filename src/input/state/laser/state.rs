@@ -0,0 +1,228 @@
+use std::collections::VecDeque;
+use std::time::Instant;
+
+use crate::draw::{DirtyTracker, ShapeId};
+use crate::util::Rect;
+
+use super::settings::LaserSettings;
+
+/// Upper bound on stored trail points; a fast sweep over a long fade would
+/// otherwise grow the trail without limit.
+const MAX_TRAIL_POINTS: usize = 256;
+/// Pointer moves shorter than this (in canvas pixels) do not add a point.
+const MIN_POINT_SPACING: f64 = 1.5;
+
+#[derive(Clone)]
+pub struct LaserState {
+    settings: LaserSettings,
+    fade_ink_enabled: bool,
+    trail: VecDeque<TrailPoint>,
+    dot: Option<(f64, f64)>,
+    last_bounds: Option<Rect>,
+    fading_ink: Vec<FadingInk>,
+}
+
+#[derive(Clone)]
+struct TrailPoint {
+    x: f64,
+    y: f64,
+    at: Instant,
+}
+
+/// A pen stroke drawn with fade ink on, waiting to disappear.
+///
+/// The stroke is found again by board, page index, id and creation stamp, so
+/// one whose page was deleted or reordered in the meantime is left alone.
+#[derive(Clone, Debug)]
+pub(crate) struct FadingInk {
+    pub(crate) board_id: String,
+    pub(crate) page: usize,
+    pub(crate) shape: ShapeId,
+    pub(crate) created_at: u64,
+    pub(crate) base_opacity: f64,
+    pub(crate) drawn_at: Instant,
+}
+
+impl LaserState {
+    pub fn new(settings: LaserSettings) -> Self {
+        let fade_ink_enabled = settings.fade_ink;
+        Self {
+            settings,
+            fade_ink_enabled,
+            trail: VecDeque::new(),
+            dot: None,
+            last_bounds: None,
+            fading_ink: Vec::new(),
+        }
+    }
+
+    pub fn settings(&self) -> &LaserSettings {
+        &self.settings
+    }
+
+    pub fn fade_ink_enabled(&self) -> bool {
+        self.fade_ink_enabled
+    }
+
+    pub fn toggle_fade_ink(&mut self) -> bool {
+        self.fade_ink_enabled = !self.fade_ink_enabled;
+        self.fade_ink_enabled
+    }
+
+    #[cfg(test)]
+    pub fn has_trail(&self) -> bool {
+        !self.trail.is_empty()
+    }
+
+    /// Moves the dot to `(x, y)` and extends the trail behind it.
+    pub fn push_point(&mut self, x: f64, y: f64, now: Instant, tracker: &mut DirtyTracker) -> bool {
+        if let Some(last) = self.trail.back()
+            && (last.x - x).hypot(last.y - y) < MIN_POINT_SPACING
+        {
+            return false;
+        }
+        if self.trail.len() >= MAX_TRAIL_POINTS {
+            self.trail.pop_front();
+        }
+        self.trail.push_back(TrailPoint { x, y, at: now });
+        self.dot = Some((x, y));
+        self.refresh_bounds(tracker);
+        true
+    }
+
+    /// Drops the dot and the whole trail at once.
+    pub fn clear(&mut self, tracker: &mut DirtyTracker) -> bool {
+        if self.trail.is_empty() && self.dot.is_none() {
+            return false;
+        }
+        self.trail.clear();
+        self.dot = None;
+        self.refresh_bounds(tracker);
+        true
+    }
+
+    /// Expires trail points older than the fade; returns true while any
+    /// remain and the trail still needs animation frames.
+    pub fn advance(&mut self, now: Instant, tracker: &mut DirtyTracker) -> bool {
+        if self.trail.is_empty() {
+            return false;
+        }
+        let fade = self.settings.fade;
+        while self
+            .trail
+            .front()
+            .is_some_and(|point| now.saturating_duration_since(point.at) >= fade)
+        {
+            self.trail.pop_front();
+        }
+        // Every remaining point changes opacity, so the whole trail repaints.
+        self.refresh_bounds(tracker);
+        !self.trail.is_empty()
+    }
+
+    pub fn render(&self, ctx: &cairo::Context, now: Instant) {
+        let lifetime = self.settings.fade.as_secs_f64();
+        let points: Vec<(f64, f64, f64)> = self
+            .trail
+            .iter()
+            .map(|point| {
+                let age = now.saturating_duration_since(point.at).as_secs_f64();
+                (point.x, point.y, crate::ui::anim::trail_fade(age, lifetime))
+            })
+            .collect();
+        crate::draw::render_laser(
+            ctx,
+            &points,
+            self.dot,
+            self.settings.color,
+            self.settings.width,
+        );
+    }
+
+    pub(crate) fn push_fading_ink(&mut self, ink: FadingInk) {
+        self.fading_ink.push(ink);
+    }
+
+    pub(crate) fn has_fading_ink(&self) -> bool {
+        !self.fading_ink.is_empty()
+    }
+
+    pub(crate) fn take_fading_ink(&mut self) -> Vec<FadingInk> {
+        std::mem::take(&mut self.fading_ink)
+    }
+
+    pub(crate) fn restore_fading_ink(&mut self, ink: Vec<FadingInk>) {
+        self.fading_ink = ink;
+    }
+
+    fn refresh_bounds(&mut self, tracker: &mut DirtyTracker) {
+        let bounds = self.current_bounds();
+        if bounds == self.last_bounds && self.trail.is_empty() {
+            return;
+        }
+        tracker.mark_optional_rect(self.last_bounds);
+        tracker.mark_optional_rect(bounds);
+        self.last_bounds = bounds;
+    }
+
+    fn current_bounds(&self) -> Option<Rect> {
+        let mut points = self
+            .trail
+            .iter()
+            .map(|point| (point.x, point.y))
+            .chain(self.dot);
+        let (first_x, first_y) = points.next()?;
+        let (min_x, min_y, max_x, max_y) = points.fold(
+            (first_x, first_y, first_x, first_y),
+            |(min_x, min_y, max_x, max_y), (x, y)| {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            },
+        );
+        // The dot's glow reaches past the trail width; pad for it plus
+        // anti-aliasing.
+        let pad = (self.settings.width * 1.5).ceil() + 2.0;
+        Rect::from_min_max(
+            (min_x - pad).floor() as i32,
+            (min_y - pad).floor() as i32,
+            (max_x + pad).ceil() as i32,
+            (max_y + pad).ceil() as i32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn advance_expires_points_older_than_the_fade() {
+        let mut state = LaserState::new(LaserSettings::default());
+        let mut tracker = DirtyTracker::new();
+        let start = Instant::now();
+        assert!(state.push_point(10.0, 10.0, start, &mut tracker));
+        assert!(state.push_point(40.0, 10.0, start + Duration::from_millis(300), &mut tracker));
+
+        let fade = state.settings().fade;
+        assert!(state.advance(start + fade, &mut tracker));
+        assert_eq!(state.trail.len(), 1);
+        assert!(!state.advance(start + fade * 2, &mut tracker));
+        assert!(!state.has_trail());
+        assert_eq!(
+            state.dot,
+            Some((40.0, 10.0)),
+            "the dot stays under the pointer after the trail fades"
+        );
+    }
+
+    #[test]
+    fn tiny_moves_do_not_add_points() {
+        let mut state = LaserState::new(LaserSettings::default());
+        let mut tracker = DirtyTracker::new();
+        let now = Instant::now();
+        assert!(state.push_point(10.0, 10.0, now, &mut tracker));
+        assert!(!state.push_point(10.5, 10.5, now, &mut tracker));
+        assert_eq!(state.trail.len(), 1);
+    }
+}
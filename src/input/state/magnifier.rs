@@ -0,0 +1,91 @@
+use crate::config::{MAGNIFIER_MIN_MAGNIFICATION, MagnifierConfig, MagnifierShape};
+
+/// Runtime settings for the magnifier lens.
+#[derive(Clone, Debug)]
+pub struct MagnifierSettings {
+    pub round: bool,
+    /// Lens width in logical pixels.
+    pub width: f64,
+    /// Lens height in logical pixels.
+    pub height: f64,
+    /// Magnification the lens opens with.
+    pub magnification: f64,
+    pub max_magnification: f64,
+    /// Factor one zoom step multiplies or divides the magnification by.
+    pub zoom_step: f64,
+}
+
+impl Default for MagnifierSettings {
+    fn default() -> Self {
+        Self::from(&MagnifierConfig::default())
+    }
+}
+
+impl From<&MagnifierConfig> for MagnifierSettings {
+    fn from(cfg: &MagnifierConfig) -> Self {
+        let round = cfg.shape == MagnifierShape::Circle;
+        Self {
+            round,
+            width: cfg.size,
+            height: if round {
+                cfg.size
+            } else {
+                cfg.size / cfg.aspect_ratio
+            },
+            magnification: cfg.magnification,
+            max_magnification: cfg.max_magnification,
+            zoom_step: cfg.zoom_step,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MagnifierState {
+    settings: MagnifierSettings,
+    active: bool,
+    magnification: f64,
+}
+
+impl MagnifierState {
+    pub fn new(settings: MagnifierSettings) -> Self {
+        let magnification = settings.magnification;
+        Self {
+            settings,
+            active: false,
+            magnification,
+        }
+    }
+
+    pub fn settings(&self) -> &MagnifierSettings {
+        &self.settings
+    }
+
+    pub fn active(&self) -> bool {
+        self.active
+    }
+
+    pub fn set_active(&mut self, active: bool) {
+        self.active = active;
+    }
+
+    pub fn magnification(&self) -> f64 {
+        self.magnification
+    }
+
+    /// Multiplies (or divides) the magnification by one zoom step, within the
+    /// configured range. Returns false when it was already at the limit.
+    pub fn step(&mut self, zoom_in: bool) -> bool {
+        let step = self.settings.zoom_step;
+        let next = if zoom_in {
+            self.magnification * step
+        } else {
+            self.magnification / step
+        }
+        .clamp(MAGNIFIER_MIN_MAGNIFICATION, self.settings.max_magnification);
+        if (next - self.magnification).abs() < f64::EPSILON {
+            return false;
+        }
+        self.magnification = next;
+        true
+    }
+}
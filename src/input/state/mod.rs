@@ -3,11 +3,14 @@ mod core;
 mod highlight;
 mod input_hud;
 pub(crate) mod interaction;
+mod laser;
+mod magnifier;
 mod mouse;
 mod render;
 mod spotlight;
 #[cfg(test)]
 mod tests;
+mod timer;
 
 #[cfg(test)]
 pub(crate) use actions::key_press::bindings::key_to_action_label_for_test;
@@ -27,19 +30,19 @@ pub use core::{
     ColorPickerCursorHint, ColorPickerPopupLayout, ColorPickerPopupState, CommandPaletteCursorHint,
     CommandPaletteListRow, CompassDir, CompositorCapabilities, ContextMenuCursorHint,
     ContextMenuEntry, ContextMenuKind, ContextMenuState, DesktopEnvironment, DrawingState,
-    EyedropperCaptureSource, EyedropperUiState, HelpOverlayClick, HelpOverlayCursorHint,
-    HelpOverlayReleaseOutcome, ImeCompositionState, ImePreedit, InputState, MAX_STROKE_THICKNESS,
-    MIN_STROKE_THICKNESS, OcrCaptureSource, OcrInputSource, OcrSelection, OcrUiState,
-    OutputFocusAction, PRESET_FEEDBACK_DURATION_MS, PRESET_TOAST_DURATION_MS, PickerDrag,
-    PrecisionEntryState, PresetAction, PresetFeedbackKind, PressureThicknessEditMode,
+    DropKind, DropTarget, EyedropperCaptureSource, EyedropperUiState, HelpOverlayClick,
+    HelpOverlayCursorHint, HelpOverlayReleaseOutcome, ImeCompositionState, ImePreedit, InputState,
+    MAX_STROKE_THICKNESS, MIN_STROKE_THICKNESS, OcrCaptureSource, OcrInputSource, OcrSelection,
+    OcrUiState, OutputFocusAction, PRESET_FEEDBACK_DURATION_MS, PRESET_TOAST_DURATION_MS,
+    PickerDrag, PrecisionEntryState, PresetAction, PresetFeedbackKind, PressureThicknessEditMode,
     PressureThicknessEntryMode, QuickColorEdit, RADIAL_COMPASS_SLICES, RADIAL_PAINT_DELAY,
     RADIAL_TOOL_SEGMENT_COUNT, RadialMenuLayout, RadialMenuState, RadialParent, RadialRingSwatch,
-    RadialSegmentId, RadialSlice, RadialSliceKind, SIZE_RING_ARC_SPAN, SIZE_RING_ARC_START,
-    SelectionAxis, SelectionHandle, SelectionPropertyEntry, SelectionPropertyKind, SelectionState,
-    ShellMode, TextInputMode, Toast, ToastPriority, ToastPushOutcome, ToastQueue, TourStep,
-    UI_TOAST_DURATION_MS, UiToastKind, ZoomAction, color_picker_rgb_to_hsv, compass_slice,
-    size_ring_angle_for_value, size_ring_value_for_angle, slice_parent, sub_ring_child_count,
-    sub_ring_children,
+    RadialSegmentId, RadialSlice, RadialSliceKind, ReplayPlayback, SIZE_RING_ARC_SPAN,
+    SIZE_RING_ARC_START, SelectionAxis, SelectionHandle, SelectionPropertyEntry,
+    SelectionPropertyKind, SelectionState, ShellMode, TextInputMode, Toast, ToastPriority,
+    ToastPushOutcome, ToastQueue, TourStep, UI_TOAST_DURATION_MS, UiToastKind, ZoomAction,
+    color_picker_rgb_to_hsv, compass_slice, size_ring_angle_for_value, size_ring_value_for_angle,
+    slice_parent, sub_ring_child_count, sub_ring_children,
 };
 pub(crate) use core::{
     COMMAND_PALETTE_INPUT_HEIGHT, COMMAND_PALETTE_ITEM_HEIGHT, COMMAND_PALETTE_LIST_GAP,
@@ -50,11 +53,12 @@ pub(crate) use core::{
 };
 #[allow(unused_imports)]
 pub(crate) use core::{
-    ClipboardFingerprint, ClipboardPasteRequest, HelpOverlayPressSource, HexPasteTarget,
-    KeybindingEditOperation, KeybindingEditRequest, PasteAnchor, PendingBackendAction,
-    PendingOnboardingUsage, PendingSelectionClipboardPublish, PendingToolbarPersistence,
-    SelectionPublishState, TextClipboardRequest, TextCutTarget, TextPasteEdit, TextPasteTarget,
-    ToastCommand, ToastPress, WayscriberClipboardSelection,
+    ClipboardFingerprint, ClipboardPasteRequest, ClipboardShape, HelpOverlayPressSource,
+    HexPasteTarget, KeybindingEditOperation, KeybindingEditRequest, PasteAnchor,
+    PendingBackendAction, PendingOnboardingUsage, PendingSelectionClipboardPublish,
+    PendingToolbarPersistence, SelectionAlignment, SelectionPublishState, TextClipboardRequest,
+    TextCutTarget, TextPasteEdit, TextPasteTarget, ToastCommand, ToastPress,
+    WayscriberClipboardSelection,
 };
 pub use highlight::ClickHighlightSettings;
 #[allow(unused_imports)]
@@ -62,6 +66,9 @@ pub use input_hud::{
     InputHudActiveSource, InputHudEntry, InputHudEntryKind, InputHudSettings, InputHudState,
     input_hud_key_label, input_hud_mouse_label, input_hud_scroll_label, is_bare_modifier,
 };
+pub use laser::LaserSettings;
+pub use magnifier::MagnifierSettings;
+pub use timer::{TimerKind, TimerSettings, TimerView};
 
 #[cfg(test)]
 pub(crate) mod test_support {
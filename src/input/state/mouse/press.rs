@@ -151,12 +151,16 @@ impl InputState {
                 crate::config::PresenterToolBehavior::ForceHighlightLocked
             )
         {
+            // The laser is the one other tool locked presenter mode allows.
+            if self.tool_override() == Some(Tool::Laser) {
+                return Some(Tool::Laser);
+            }
             return Some(Tool::Highlight);
         }
 
         if button == MouseButton::Left
             && let Some(override_tool) = self.tool_override()
-            && (matches!(override_tool, Tool::Highlight | Tool::Eraser)
+            && (matches!(override_tool, Tool::Highlight | Tool::Laser | Tool::Eraser)
                 || !self.modifiers.active_drag_modifier().is_active())
         {
             return Some(self.active_tool());
@@ -461,6 +465,7 @@ impl InputState {
                 let snapshots = self.capture_movable_selection_snapshots();
                 if !snapshots.is_empty() {
                     self.begin_pointer_drag(button, color);
+                    self.end_selection_drag_snapping();
                     self.state = DrawingState::MovingSelection {
                         last_x: x,
                         last_y: y,
@@ -484,7 +489,9 @@ impl InputState {
         }
 
         match tool.press_behavior() {
-            ToolPressBehavior::Selection | ToolPressBehavior::HighlightNoop => {}
+            ToolPressBehavior::Selection
+            | ToolPressBehavior::HighlightNoop
+            | ToolPressBehavior::LaserNoop => {}
             ToolPressBehavior::StartFreeformPolygon => {
                 self.mark_draw_activity();
                 self.start_building_polygon(x, y);
@@ -502,6 +509,7 @@ impl InputState {
                 }
                 self.sync_current_settings_for_tool(tool);
                 let drawing_thickness = self.thickness_for_tool(tool);
+                let (x, y) = self.snap_tool_point(tool, (x, y));
                 self.begin_pointer_drag(button, color);
                 self.state = DrawingState::Drawing {
                     tool,
@@ -511,6 +519,8 @@ impl InputState {
                     point_thicknesses: vec![drawing_thickness as f32],
                 };
                 self.last_provisional_bounds = None;
+                self.begin_smart_ink_rest((x, y));
+                self.begin_stroke_stabilizer((x, y));
                 self.update_provisional_dirty(x, y);
                 self.needs_redraw = true;
             }
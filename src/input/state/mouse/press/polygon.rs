@@ -125,6 +125,7 @@ impl InputState {
             fill,
             color,
             thick,
+            line_style: self.line_style,
         };
         let bounds = shape.bounding_box();
         let addition = {
@@ -3,7 +3,9 @@ use log::warn;
 use crate::draw::Shape;
 use crate::draw::frame::UndoAction;
 use crate::draw::shape::bounding_box_for_points;
-use crate::input::tool::{FinishedToolStroke, PolygonStrokeSnapshot, ToolStrokeSnapshot};
+use crate::input::tool::{
+    FinishedToolStroke, PolygonStrokeSnapshot, ToolStrokeSnapshot, ToolUsage,
+};
 use crate::input::{InputState, Tool};
 use crate::util::Rect;
 
@@ -25,7 +27,19 @@ pub(super) fn finish_drawing(state: &mut InputState, tool: Tool, release: Drawin
         &release.point_thicknesses,
         drawing_thickness,
     );
-    let finished = if tool.polygon_template().is_some() {
+    let recognized = state.recognize_smart_ink(tool, &release.points, release.end);
+    let finished = if let Some(recognized) = recognized {
+        FinishedToolStroke::Shape {
+            shape: recognized.into_shape(
+                drawing_color,
+                drawing_thickness,
+                state.arrow_length,
+                state.arrow_angle,
+                state.line_style,
+            ),
+            usage: ToolUsage::default(),
+        }
+    } else if tool.polygon_template().is_some() {
         let snapshot = PolygonStrokeSnapshot {
             tool,
             start: release.start,
@@ -33,6 +47,7 @@ pub(super) fn finish_drawing(state: &mut InputState, tool: Tool, release: Drawin
             color: drawing_color,
             size: drawing_thickness,
             fill_enabled: state.fill_enabled,
+            line_style: state.line_style,
             regular_sides: state.polygon_sides,
         };
         tool.finish_polygon_stroke(snapshot)
@@ -47,6 +62,7 @@ pub(super) fn finish_drawing(state: &mut InputState, tool: Tool, release: Drawin
             size: drawing_thickness,
             marker_opacity: state.marker_opacity,
             fill_enabled: state.fill_enabled,
+            line_style: state.line_style,
             blur_style: state.blur_style,
             arrow_length: state.arrow_length,
             arrow_angle: state.arrow_angle,
@@ -57,11 +73,12 @@ pub(super) fn finish_drawing(state: &mut InputState, tool: Tool, release: Drawin
             eraser_size: state.eraser_size,
             eraser_kind: state.eraser_kind,
             pressure_variation_threshold: state.pressure_variation_threshold,
+            smooth_strokes: state.smooth_strokes,
         };
         tool.finish_stroke(snapshot)
     };
 
-    let (shape, usage) = match finished {
+    let (mut shape, usage) = match finished {
         FinishedToolStroke::Shape { shape, usage } => (shape, usage),
         FinishedToolStroke::EraseStroke { path } => {
             state.clear_provisional_dirty();
@@ -76,25 +93,38 @@ pub(super) fn finish_drawing(state: &mut InputState, tool: Tool, release: Drawin
         }
     };
 
+    let connector = if state.connector_mode && matches!(tool, Tool::Line | Tool::Arrow) {
+        state.attach_connector_ends(&mut shape)
+    } else {
+        None
+    };
+
     let bounds = shape.bounding_box();
     let path_damage = finished_path_damage_regions(&shape, bounds);
     let preserve_provisional_cleanup =
         matches!(shape, Shape::Freehand { .. }) && pressure_preview_exceeds_final_width;
 
+    // Fade-ink strokes vanish on their own, so they never enter undo history.
+    let fade_ink = tool == Tool::Pen && state.fade_ink_enabled();
     let mut limit_reached = false;
     let addition = {
         let frame = state.boards.active_frame_mut();
         match frame.try_add_shape_with_id(shape.clone(), state.max_shapes_per_frame) {
             Some(new_id) => {
+                if let Some(added) = frame.shape_mut(new_id) {
+                    added.connector = connector;
+                }
                 if let Some(index) = frame.find_index(new_id) {
                     if let Some(new_shape) = frame.shape(new_id) {
                         let snapshot = new_shape.clone();
-                        frame.push_undo_action(
-                            UndoAction::Create {
-                                shapes: vec![(index, snapshot.clone())],
-                            },
-                            state.undo_stack_limit,
-                        );
+                        if !fade_ink {
+                            frame.push_undo_action(
+                                UndoAction::Create {
+                                    shapes: vec![(index, snapshot.clone())],
+                                },
+                                state.undo_stack_limit,
+                            );
+                        }
                         Some((new_id, snapshot))
                     } else {
                         None
@@ -112,6 +142,9 @@ pub(super) fn finish_drawing(state: &mut InputState, tool: Tool, release: Drawin
 
     if let Some((new_id, _snapshot)) = addition {
         state.invalidate_hit_cache_for(new_id);
+        if fade_ink {
+            state.track_fade_ink(new_id);
+        }
         if let Some(path_damage) = path_damage {
             let provisional_bounds = state.take_provisional_dirty_bounds();
             for region in path_damage {
@@ -86,6 +86,7 @@ impl InputState {
             DrawingState::MovingSelection {
                 snapshots, moved, ..
             } => {
+                self.end_selection_drag_snapping();
                 selection::finish_moving_selection(self, snapshots, moved);
             }
             DrawingState::Selecting {
@@ -109,15 +110,21 @@ impl InputState {
                 tool,
                 start_x,
                 start_y,
-                points,
-                point_thicknesses,
+                mut points,
+                mut point_thicknesses,
             } => {
+                self.finish_stroke_stabilizer(
+                    tool,
+                    (canvas_x, canvas_y),
+                    &mut points,
+                    &mut point_thicknesses,
+                );
                 drawing::finish_drawing(
                     self,
                     tool,
                     drawing::DrawingRelease {
                         start: (start_x, start_y),
-                        end: (canvas_x, canvas_y),
+                        end: self.snap_tool_point(tool, (canvas_x, canvas_y)),
                         points,
                         point_thicknesses,
                     },
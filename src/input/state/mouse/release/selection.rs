@@ -84,31 +84,39 @@ pub(super) fn finish_selection_resize(
     snapshots: &[(ShapeId, ShapeSnapshot)],
 ) {
     // Capture after-snapshots and push undo actions
-    let mut has_changes = false;
-    let frame = state.boards.active_frame_mut();
-    for (shape_id, before_snapshot) in snapshots {
-        if let Some(shape) = frame.shape(*shape_id) {
-            let after_snapshot = ShapeSnapshot {
-                shape: shape.shape.clone(),
-                locked: shape.locked,
-            };
-            // Check if shape bounds changed (simpler than full PartialEq on Shape)
-            let before_bounds = before_snapshot.shape.bounding_box();
-            let after_bounds = after_snapshot.shape.bounding_box();
-            if before_bounds != after_bounds {
-                frame.push_undo_action(
-                    UndoAction::modify_from_snapshots(
+    let mut actions = Vec::new();
+    {
+        let frame = state.boards.active_frame();
+        for (shape_id, before_snapshot) in snapshots {
+            if let Some(shape) = frame.shape(*shape_id) {
+                let after_snapshot = ShapeSnapshot {
+                    shape: shape.shape.clone(),
+                    locked: shape.locked,
+                };
+                // Bounds alone miss turns that land on the same box, such as a
+                // square rotated by 90 degrees or a line rotated by 180.
+                if before_snapshot.shape != after_snapshot.shape {
+                    actions.push(UndoAction::modify_from_snapshots(
                         *shape_id,
                         before_snapshot.clone(),
                         after_snapshot,
-                    ),
-                    state.undo_stack_limit,
-                );
-                has_changes = true;
+                    ));
+                }
             }
         }
     }
-    if has_changes {
+    if !actions.is_empty() {
+        let moved: Vec<ShapeId> = snapshots.iter().map(|(shape_id, _)| *shape_id).collect();
+        actions.extend(state.detach_connectors_left_behind(&moved));
+        let undo_action = if actions.len() == 1 {
+            actions.remove(0)
+        } else {
+            UndoAction::Compound { actions }
+        };
+        state
+            .boards
+            .active_frame_mut()
+            .push_undo_action(undo_action, state.undo_stack_limit);
         state.mark_session_dirty();
     }
     state.needs_redraw = true;
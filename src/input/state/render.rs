@@ -1,4 +1,6 @@
-use crate::draw::render::{render_freehand_pressure_preview_borrowed, render_polygon_preview};
+use crate::draw::render::{
+    render_freehand_pressure_preview_borrowed, render_polygon_preview, with_line_style,
+};
 use crate::draw::shape::bounding_box_for_points;
 use crate::draw::{
     Color, Shape, render_freehand_borrowed, render_marker_stroke_borrowed, render_shape,
@@ -28,6 +30,7 @@ impl InputState {
         else {
             return ProvisionalToolStroke::None;
         };
+        let (current_x, current_y) = self.snap_tool_point(*tool, (current_x, current_y));
 
         if tool.polygon_template().is_some() {
             let snapshot = PolygonProvisionalSnapshot {
@@ -37,6 +40,7 @@ impl InputState {
                 color: self.active_drag_color_or_current(),
                 size: self.thickness_for_tool(*tool),
                 fill_enabled: self.fill_enabled,
+                line_style: self.line_style,
                 regular_sides: self.polygon_sides,
             };
             return tool.provisional_polygon_stroke(snapshot);
@@ -53,6 +57,7 @@ impl InputState {
             eraser_size: self.eraser_size,
             marker_opacity: self.marker_opacity,
             fill_enabled: self.fill_enabled,
+            line_style: self.line_style,
             blur_style: self.blur_style,
             arrow_length: self.arrow_length,
             arrow_angle: self.arrow_angle,
@@ -77,8 +82,11 @@ impl InputState {
                 points,
                 color,
                 size,
+                line_style,
             } => {
-                render_freehand_borrowed(ctx, points, color, size);
+                with_line_style(ctx, line_style, size, || {
+                    render_freehand_borrowed(ctx, points, color, size);
+                });
                 true
             }
             ProvisionalToolStroke::BorrowedPressureFreehand {
@@ -121,6 +129,7 @@ impl InputState {
                         h: params.h,
                         strength: params.strength,
                         style: params.style,
+                        rotation: params.rotation,
                     },
                 );
                 true
@@ -140,11 +149,20 @@ impl InputState {
                 points,
                 color,
                 size,
+                line_style,
             } => {
                 let ranges = path_damage_ranges(points, damage_regions, size);
                 if ranges.is_empty() {
                     return false;
                 }
+                // Dashes restart at every sub-path, so a chunked redraw would
+                // misalign them against the rest of the stroke.
+                if !line_style.is_solid() {
+                    with_line_style(ctx, line_style, size, || {
+                        render_freehand_borrowed(ctx, points, color, size);
+                    });
+                    return true;
+                }
                 for range in ranges {
                     render_freehand_borrowed(ctx, &points[range], color, size);
                 }
@@ -287,7 +305,14 @@ impl InputState {
                 if let Some(point) = preview.or(Some((current_x, current_y))) {
                     preview_points.push(point);
                 }
-                render_polygon_preview(ctx, &preview_points, *fill, *color, *thick);
+                render_polygon_preview(
+                    ctx,
+                    &preview_points,
+                    *fill,
+                    *color,
+                    *thick,
+                    self.line_style,
+                );
                 true
             }
             _ => false,
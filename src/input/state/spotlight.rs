@@ -15,7 +15,7 @@ impl InputState {
     /// Including the in-progress drag is what makes the tool usable: the dimming
     /// follows the drag instead of appearing only once the button is released.
     pub(crate) fn spotlight_regions(&self, cursor: (i32, i32)) -> Vec<SpotlightRegion> {
-        let mut regions = spotlight_regions_for_frame(self.boards.active_frame());
+        let mut regions = spotlight_regions_for_frame(self.canvas_frame());
 
         regions.extend(self.provisional_spotlight_region(cursor));
         regions
@@ -53,17 +53,15 @@ impl InputState {
     /// Drives the full-damage decision: a spotlight changes every pixel outside
     /// itself, so partial damage cannot describe adding, moving, or removing one.
     pub(crate) fn has_spotlight(&self) -> bool {
-        self.boards
-            .active_frame()
-            .shapes
-            .iter()
-            .any(|drawn| matches!(drawn.shape, Shape::Spotlight { .. }))
-            || matches!(
-                &self.state,
-                DrawingState::Drawing {
-                    tool: Tool::Spotlight,
-                    ..
-                }
-            )
+        let frame = self.boards.active_frame();
+        frame.shapes.iter().any(|drawn| {
+            matches!(drawn.shape, Shape::Spotlight { .. }) && frame.is_shape_visible(drawn)
+        }) || matches!(
+            &self.state,
+            DrawingState::Drawing {
+                tool: Tool::Spotlight,
+                ..
+            }
+        )
     }
 }
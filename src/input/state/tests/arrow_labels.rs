@@ -24,6 +24,7 @@ fn arrow_with_label(value: u32, font_descriptor: &FontDescriptor) -> Shape {
             size: 12.0,
             font_descriptor: font_descriptor.clone(),
         }),
+        line_style: LineStyle::Solid,
     }
 }
 
@@ -150,6 +150,7 @@ fn switch_board_cancels_text_edit_on_source_board_before_switching() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
     state.set_selection(vec![shape_id]);
     assert!(state.edit_selected_text());
@@ -174,6 +175,8 @@ fn switch_board_cancels_selection_move_on_source_board_before_switching() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![shape_id]);
     let snapshots = state.capture_movable_selection_snapshots();
@@ -367,6 +370,7 @@ fn duplicate_board_cancels_text_edit_before_cloning_board() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
     state.set_selection(vec![shape_id]);
     assert!(state.edit_selected_text());
@@ -395,6 +399,7 @@ fn add_active_image_shape(state: &mut InputState, bytes: usize) -> ShapeId {
             height: 180,
             bytes: pseudo_random_bytes(bytes),
         },
+        rotation: 0.0,
     })
 }
 
@@ -424,6 +429,7 @@ fn make_active_page_history_only(state: &mut InputState) {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
     let index = frame.find_index(id).expect("shape index");
     let shape = frame.shape(id).expect("shape").clone();
@@ -31,6 +31,7 @@ fn add_text_shape(state: &mut InputState, text: &str) -> ShapeId {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     })
 }
 
@@ -272,6 +273,8 @@ fn page_delete_on_last_page_clears_shapes_without_removing_page() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     assert_eq!(state.page_delete(), PageDeleteOutcome::Cleared);
@@ -334,6 +337,8 @@ fn page_content_edit_does_not_stale_pending_page_delete() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     assert_eq!(
         state.delete_active_page_at(requested_at + Duration::from_millis(1)),
@@ -0,0 +1,72 @@
+//! Drag-and-drop target feedback and drop placement.
+
+use super::*;
+use crate::input::state::{DropKind, DropTarget};
+
+#[test]
+fn drop_target_changes_request_a_redraw_only_when_they_change() {
+    let mut state = create_test_input_state();
+    let target = DropTarget {
+        x: 40.0,
+        y: 60.0,
+        kind: DropKind::Image,
+    };
+
+    state.needs_redraw = false;
+    state.set_drop_target(target);
+    assert_eq!(state.drop_target(), Some(target));
+    assert!(state.needs_redraw);
+
+    state.needs_redraw = false;
+    state.set_drop_target(target);
+    assert!(!state.needs_redraw);
+
+    state.clear_drop_target();
+    assert_eq!(state.drop_target(), None);
+    assert!(state.needs_redraw);
+
+    state.needs_redraw = false;
+    state.clear_drop_target();
+    assert!(!state.needs_redraw);
+}
+
+#[test]
+fn dropped_text_lands_centered_on_the_drop_point_without_a_clipboard_read() {
+    let mut state = create_test_input_state();
+    state.current_font_size = 24.0;
+
+    let request = state.request_drop_paste(300, 200);
+    assert!(
+        state.take_pending_clipboard_paste_request().is_none(),
+        "a drop supplies its own content and must not queue a clipboard read"
+    );
+
+    let shape = state.dropped_text_shape("Dropped note".to_string());
+    assert_eq!(
+        state.paste_clipboard_shapes_from_request(&request, vec![shape.into()]),
+        1
+    );
+    state.finish_clipboard_paste_request(request.id);
+
+    let frame = state.boards.active_frame();
+    assert_eq!(frame.shapes.len(), 1);
+    let pasted = &frame.shapes[0].shape;
+    let Shape::Text { text, size, .. } = pasted else {
+        panic!("expected a text annotation, got {pasted:?}");
+    };
+    assert_eq!(text, "Dropped note");
+    assert_eq!(*size, 24.0);
+    let bounds = pasted.bounding_box().expect("text bounds");
+    let center = (bounds.x + bounds.width / 2, bounds.y + bounds.height / 2);
+    assert!((center.0 - 300).abs() <= 1 && (center.1 - 200).abs() <= 1);
+}
+
+#[test]
+fn a_drop_supersedes_a_pending_clipboard_paste() {
+    let mut state = create_test_input_state();
+    let clipboard = state.request_clipboard_paste();
+    let drop = state.request_drop_paste(10, 10);
+
+    assert_ne!(clipboard.id, drop.id);
+    assert_eq!(state.active_clipboard_paste_request_id(), Some(drop.id));
+}
@@ -131,6 +131,7 @@ fn alt_click_selects_filled_polygon_interior() {
         fill: true,
         color: state.current_color,
         thick: state.current_thickness,
+        line_style: LineStyle::Solid,
     });
 
     state.modifiers.alt = true;
@@ -394,6 +395,7 @@ fn append_path_limit_rejection_clears_provisional_damage() {
         y2: 2,
         color: state.current_color,
         thick: state.current_thickness,
+        line_style: LineStyle::Solid,
     });
     state.max_shapes_per_frame = 1;
     let _ = state.take_dirty_regions();
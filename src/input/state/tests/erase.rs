@@ -18,6 +18,7 @@ fn erase_stroke_samples_sparse_path() {
             a: 1.0,
         },
         thick: 1.0,
+        line_style: LineStyle::Solid,
     });
 
     let erased = state.erase_strokes_by_points(&[(0, -10), (100, 10)]);
@@ -44,6 +45,7 @@ fn erase_stroke_includes_release_segment() {
             a: 1.0,
         },
         thick: 1.0,
+        line_style: LineStyle::Solid,
     });
 
     state.on_mouse_press(MouseButton::Left, 0, -10);
@@ -70,6 +72,7 @@ fn erase_stroke_skips_locked_shapes() {
             a: 1.0,
         },
         thick: 1.0,
+        line_style: LineStyle::Solid,
     });
     let unlocked_id = state.boards.active_frame_mut().add_shape(Shape::Line {
         x1: 0,
@@ -83,6 +86,7 @@ fn erase_stroke_skips_locked_shapes() {
             a: 1.0,
         },
         thick: 1.0,
+        line_style: LineStyle::Solid,
     });
 
     if let Some(index) = state.boards.active_frame().find_index(locked_id) {
@@ -121,6 +125,7 @@ fn erase_stroke_samples_randomized_crossings() {
                 a: 1.0,
             },
             thick: 1.0,
+            line_style: LineStyle::Solid,
         });
 
         let unit = next_unit(&mut seed);
@@ -164,6 +169,8 @@ fn erase_stroke_hits_various_shapes() {
                     a: 1.0,
                 },
                 thick: 1.0,
+                line_style: LineStyle::Solid,
+                rotation: 0.0,
             },
             vec![(0, 10), (100, 10)],
         ),
@@ -181,6 +188,8 @@ fn erase_stroke_hits_various_shapes() {
                     a: 1.0,
                 },
                 thick: 1.0,
+                line_style: LineStyle::Solid,
+                rotation: 0.0,
             },
             vec![(0, 40), (100, 40)],
         ),
@@ -197,6 +206,7 @@ fn erase_stroke_hits_various_shapes() {
                     a: 1.0,
                 },
                 thick: 1.0,
+                line_style: LineStyle::Solid,
                 arrow_length: 20.0,
                 arrow_angle: 30.0,
                 head_at_end: true,
@@ -243,6 +253,7 @@ fn spatial_grid_eraser_hits_after_add_move_delete() {
                 a: 1.0,
             },
             thick: 2.0,
+            line_style: LineStyle::Solid,
         });
         shape_ids.push(id);
     }
@@ -325,6 +336,8 @@ fn spatial_grid_large_tolerance_finds_distant_shapes() {
                 a: 1.0,
             },
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
     }
 
@@ -166,6 +166,7 @@ fn preset_status_bar_update_stays_hidden_until_focus_mode_restores_it() {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: Some(false),
         drag_tools: None,
     });
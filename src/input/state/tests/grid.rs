@@ -0,0 +1,146 @@
+//! Board grid snapping and the align/distribute selection actions.
+
+use super::*;
+use crate::input::BOARD_ID_WHITEBOARD;
+
+fn add_rect(state: &mut InputState, x: i32, y: i32) -> crate::draw::ShapeId {
+    let color = state.current_color;
+    let thick = state.current_thickness;
+    state.boards.active_frame_mut().add_shape(Shape::Rect {
+        x,
+        y,
+        w: 40,
+        h: 40,
+        fill: true,
+        color,
+        thick,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
+    })
+}
+
+fn bounds(state: &InputState, id: crate::draw::ShapeId) -> crate::util::Rect {
+    state
+        .boards
+        .active_frame()
+        .shape(id)
+        .and_then(|drawn| drawn.bounding_box())
+        .expect("shape has bounds")
+}
+
+fn whiteboard_with_grid() -> InputState {
+    let mut state = create_test_input_state();
+    state.switch_board(BOARD_ID_WHITEBOARD);
+    state.handle_action(Action::ToggleGrid);
+    state
+}
+
+#[test]
+fn grid_only_shows_on_solid_boards() {
+    let mut state = create_test_input_state();
+    state.handle_action(Action::ToggleGrid);
+    assert!(
+        state.board_grid().is_none(),
+        "the transparent overlay never draws a grid"
+    );
+
+    state.switch_board(BOARD_ID_WHITEBOARD);
+    let grid = state.board_grid().expect("whiteboard shows the grid");
+    assert_eq!(grid.spacing, 32.0);
+
+    state.handle_action(Action::ToggleGrid);
+    assert!(state.board_grid().is_none());
+}
+
+#[test]
+fn shape_tools_snap_to_grid_but_freehand_does_not() {
+    let mut state = whiteboard_with_grid();
+    assert_eq!(state.snap_tool_point(Tool::Rect, (45, 70)), (32, 64));
+    assert_eq!(state.snap_tool_point(Tool::Pen, (45, 70)), (45, 70));
+
+    state.handle_action(Action::ToggleSnapToGrid);
+    assert_eq!(state.snap_tool_point(Tool::Rect, (45, 70)), (45, 70));
+}
+
+#[test]
+fn dragging_a_selection_lands_it_on_the_grid() {
+    let mut state = whiteboard_with_grid();
+    let id = add_rect(&mut state, 50, 50);
+    state.set_selection(vec![id]);
+
+    assert_eq!(state.drag_selection_by(20, 3), Some(true));
+    let moved = bounds(&state, id);
+    assert_eq!(moved.x % 32, 0);
+    assert_eq!(moved.y % 32, 0);
+    state.end_selection_drag_snapping();
+}
+
+#[test]
+fn dragging_near_another_shape_shows_an_alignment_guide() {
+    let mut state = create_test_input_state();
+    state.switch_board(BOARD_ID_WHITEBOARD);
+    let anchor = add_rect(&mut state, 200, 10);
+    let dragged = add_rect(&mut state, 10, 300);
+    state.set_selection(vec![dragged]);
+
+    let offset = bounds(&state, anchor).x - bounds(&state, dragged).x;
+    assert_eq!(state.drag_selection_by(offset - 4, 0), Some(true));
+    assert_eq!(bounds(&state, dragged).x, bounds(&state, anchor).x);
+    assert!(!state.alignment_guides.is_empty());
+
+    state.end_selection_drag_snapping();
+    assert!(state.alignment_guides.is_empty());
+}
+
+#[test]
+fn align_left_lines_up_shapes_and_undoes_in_one_step() {
+    let mut state = create_test_input_state();
+    let first = add_rect(&mut state, 10, 10);
+    let second = add_rect(&mut state, 90, 100);
+    let second_before = bounds(&state, second);
+    state.set_selection(vec![first, second]);
+
+    state.handle_action(Action::AlignSelectionLeft);
+    assert_eq!(bounds(&state, first).x, bounds(&state, second).x);
+    assert_eq!(
+        bounds(&state, second).y,
+        second_before.y,
+        "aligning left only moves shapes horizontally"
+    );
+
+    if let Some(action) = state.boards.active_frame_mut().undo_last() {
+        state.apply_action_side_effects(&action);
+    }
+    assert_eq!(bounds(&state, second), second_before);
+}
+
+#[test]
+fn distribute_spaces_shapes_evenly_between_the_outermost() {
+    let mut state = create_test_input_state();
+    let left = add_rect(&mut state, 0, 10);
+    let middle = add_rect(&mut state, 30, 10);
+    let right = add_rect(&mut state, 200, 10);
+    let (left_before, right_before) = (bounds(&state, left), bounds(&state, right));
+    state.set_selection(vec![right, left, middle]);
+
+    state.handle_action(Action::DistributeSelectionHorizontally);
+    let (a, b, c) = (
+        bounds(&state, left),
+        bounds(&state, middle),
+        bounds(&state, right),
+    );
+    assert_eq!((a, c), (left_before, right_before));
+    assert_eq!(b.x - (a.x + a.width), c.x - (b.x + b.width));
+}
+
+#[test]
+fn align_needs_two_shapes_and_distribute_three() {
+    let mut state = create_test_input_state();
+    let first = add_rect(&mut state, 10, 10);
+    let second = add_rect(&mut state, 90, 100);
+    state.set_selection(vec![first]);
+    assert!(!state.align_selection(crate::input::state::SelectionAlignment::Top));
+
+    state.set_selection(vec![first, second]);
+    assert!(!state.distribute_selection(false));
+}
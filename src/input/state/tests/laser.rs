@@ -0,0 +1,97 @@
+//! The laser pointer trail and fade ink.
+
+use super::*;
+use crate::config::PresenterToolBehavior;
+use std::time::{Duration, Instant};
+
+fn draw_pen_stroke(state: &mut InputState) {
+    state.on_mouse_press(MouseButton::Left, 10, 10);
+    state.on_mouse_motion(60, 40);
+    state.on_mouse_motion(120, 90);
+    state.on_mouse_release(MouseButton::Left, 120, 90);
+}
+
+#[test]
+fn laser_pointer_leaves_a_trail_but_never_a_shape() {
+    let mut state = create_test_input_state();
+    state.handle_action(Action::SelectLaserTool);
+    assert!(state.laser_tool_active());
+
+    state.on_mouse_motion(20, 20);
+    state.on_mouse_press(MouseButton::Left, 20, 20);
+    state.on_mouse_motion(80, 60);
+    state.on_mouse_release(MouseButton::Left, 80, 60);
+
+    assert!(state.laser.has_trail());
+    assert!(state.boards.active_frame().shapes.is_empty());
+    assert_eq!(state.boards.active_frame().undo_stack_len(), 0);
+}
+
+#[test]
+fn laser_trail_fades_out_and_clears_on_tool_change() {
+    let mut state = create_test_input_state();
+    state.handle_action(Action::SelectLaserTool);
+    state.on_mouse_motion(20, 20);
+    state.on_mouse_motion(80, 60);
+
+    let later = Instant::now() + Duration::from_secs(10);
+    assert!(
+        !state.advance_laser(later),
+        "the trail is gone after its fade"
+    );
+    assert!(!state.laser.has_trail());
+
+    state.on_mouse_motion(100, 60);
+    assert!(state.laser.has_trail());
+    state.handle_action(Action::SelectPenTool);
+    assert!(!state.laser.has_trail());
+}
+
+#[test]
+fn fade_ink_strokes_skip_undo_and_vanish() {
+    let mut state = create_test_input_state();
+    state.handle_action(Action::SelectPenTool);
+    state.handle_action(Action::ToggleFadeInk);
+    assert!(state.fade_ink_enabled());
+
+    draw_pen_stroke(&mut state);
+    assert_eq!(state.boards.active_frame().shapes.len(), 1);
+    assert_eq!(
+        state.boards.active_frame().undo_stack_len(),
+        0,
+        "fading strokes never enter undo history"
+    );
+
+    let soon = Instant::now() + Duration::from_millis(100);
+    assert!(state.advance_laser(soon));
+    assert_eq!(state.boards.active_frame().shapes.len(), 1);
+
+    let later = Instant::now() + Duration::from_secs(60);
+    assert!(!state.advance_laser(later));
+    assert!(state.boards.active_frame().shapes.is_empty());
+}
+
+#[test]
+fn pen_strokes_persist_with_fade_ink_off() {
+    let mut state = create_test_input_state();
+    state.handle_action(Action::SelectPenTool);
+
+    draw_pen_stroke(&mut state);
+    assert!(!state.advance_laser(Instant::now() + Duration::from_secs(60)));
+    assert_eq!(state.boards.active_frame().shapes.len(), 1);
+    assert_eq!(state.boards.active_frame().undo_stack_len(), 1);
+}
+
+#[test]
+fn presenter_locked_mode_still_allows_the_laser() {
+    let mut state = create_test_input_state();
+    state.presenter_mode_config.tool_behavior = PresenterToolBehavior::ForceHighlightLocked;
+    state.toggle_presenter_mode();
+    assert_eq!(state.tool_override(), Some(Tool::Highlight));
+
+    state.handle_action(Action::SelectLaserTool);
+    assert_eq!(state.tool_override(), Some(Tool::Laser));
+
+    state.handle_action(Action::SelectPenTool);
+    assert_eq!(state.tool_override(), Some(Tool::Laser));
+}
@@ -0,0 +1,154 @@
+//! Layers within a page: hit-testing, clearing, and moving shapes between them.
+
+use super::*;
+
+fn add_rect(state: &mut InputState, x: i32) -> crate::draw::ShapeId {
+    let color = state.current_color;
+    let thick = state.current_thickness;
+    state.boards.active_frame_mut().add_shape(Shape::Rect {
+        x,
+        y: 10,
+        w: 40,
+        h: 40,
+        fill: true,
+        color,
+        thick,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
+    })
+}
+
+#[test]
+fn locked_layers_are_skipped_by_hit_testing_and_clearing() {
+    let mut state = create_test_input_state();
+    let base = add_rect(&mut state, 10);
+    assert!(state.layer_new());
+    let upper = add_rect(&mut state, 10);
+
+    assert_eq!(state.hit_test_at(30, 30), Some(upper));
+    assert!(state.layer_lock_active());
+    assert_eq!(
+        state.boards.active_frame().active_layer_id(),
+        0,
+        "locking the active layer moves drawing to the one beneath"
+    );
+    assert_eq!(state.hit_test_at(30, 30), Some(base));
+
+    assert!(state.clear_all());
+    let frame = state.boards.active_frame();
+    assert!(frame.shape(base).is_none());
+    assert!(
+        frame.shape(upper).is_some(),
+        "locked layers survive Clear All"
+    );
+}
+
+#[test]
+fn clear_active_layer_leaves_other_layers_alone() {
+    let mut state = create_test_input_state();
+    let base = add_rect(&mut state, 10);
+    assert!(state.layer_new());
+    let upper = add_rect(&mut state, 100);
+
+    state.handle_action(Action::ClearActiveLayer);
+
+    let frame = state.boards.active_frame();
+    assert!(frame.shape(upper).is_none());
+    assert!(frame.shape(base).is_some());
+    assert!(
+        state.layer_delete_active(),
+        "an emptied layer can be deleted"
+    );
+    assert_eq!(state.boards.active_frame().layers().len(), 1);
+}
+
+#[test]
+fn moving_the_selection_between_layers_is_one_undo_step() {
+    let mut state = create_test_input_state();
+    let first = add_rect(&mut state, 10);
+    let second = add_rect(&mut state, 100);
+    assert!(state.layer_new());
+    let upper = state.boards.active_frame().active_layer_id();
+    assert!(state.layer_activate(0));
+
+    state.set_selection(vec![first, second]);
+    state.handle_action(Action::MoveSelectionToLayerAbove);
+
+    let frame = state.boards.active_frame();
+    assert_eq!(frame.shape(first).unwrap().layer, upper);
+    assert_eq!(frame.shape(second).unwrap().layer, upper);
+    assert_eq!(frame.active_layer_id(), upper);
+
+    state.handle_action(Action::Undo);
+    let frame = state.boards.active_frame();
+    assert_eq!(frame.shape(first).unwrap().layer, 0);
+    assert_eq!(frame.shape(second).unwrap().layer, 0);
+    assert_eq!(frame.find_index(first), Some(0));
+    assert_eq!(frame.find_index(second), Some(1));
+}
+
+/// Runs `change`, then checks Undo puts the layer stack back exactly and Redo
+/// reapplies it.
+fn assert_layer_change_round_trips(
+    state: &mut InputState,
+    change: impl FnOnce(&mut InputState) -> bool,
+) {
+    let before = state.boards.active_frame().layer_table();
+    assert!(change(state));
+    let after = state.boards.active_frame().layer_table();
+    assert_ne!(before, after);
+
+    state.handle_action(Action::Undo);
+    assert_eq!(state.boards.active_frame().layer_table(), before);
+    state.handle_action(Action::Redo);
+    assert_eq!(state.boards.active_frame().layer_table(), after);
+}
+
+#[test]
+fn creating_a_layer_is_undoable() {
+    let mut state = create_test_input_state();
+    assert_layer_change_round_trips(&mut state, InputState::layer_new);
+}
+
+#[test]
+fn deleting_a_layer_is_undoable() {
+    let mut state = create_test_input_state();
+    assert!(state.layer_new());
+    assert_layer_change_round_trips(&mut state, InputState::layer_delete_active);
+}
+
+#[test]
+fn renaming_a_layer_is_undoable() {
+    let mut state = create_test_input_state();
+    assert_layer_change_round_trips(&mut state, |state| state.layer_rename(0, "Sketch"));
+    assert_eq!(state.boards.active_frame().layers()[0].name, "Sketch");
+}
+
+#[test]
+fn hiding_a_layer_is_undoable() {
+    let mut state = create_test_input_state();
+    assert!(state.layer_new());
+    assert_layer_change_round_trips(&mut state, InputState::layer_hide_active);
+}
+
+#[test]
+fn locking_a_layer_is_undoable() {
+    let mut state = create_test_input_state();
+    assert!(state.layer_new());
+    assert_layer_change_round_trips(&mut state, InputState::layer_lock_active);
+}
+
+#[test]
+fn fading_a_layer_is_undoable() {
+    let mut state = create_test_input_state();
+    assert_layer_change_round_trips(&mut state, InputState::layer_cycle_active_opacity);
+}
+
+#[test]
+fn switching_layers_alone_adds_no_undo_step() {
+    let mut state = create_test_input_state();
+    assert!(state.layer_new());
+    let steps = state.boards.active_frame().undo_stack_len();
+    assert!(state.layer_activate(0));
+    assert_eq!(state.boards.active_frame().undo_stack_len(), steps);
+}
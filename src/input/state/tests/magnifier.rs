@@ -0,0 +1,63 @@
+//! The magnifier lens.
+
+use super::*;
+use crate::config::{MagnifierConfig, MagnifierShape};
+use crate::input::state::MagnifierSettings;
+
+#[test]
+fn toggle_magnifier_opens_and_closes_the_lens_and_asks_for_a_source() {
+    let mut state = create_test_input_state();
+    assert!(state.magnifier_lens((10, 10)).is_none());
+
+    state.handle_action(Action::ToggleMagnifier);
+    assert!(state.magnifier_active());
+    assert!(state.take_pending_magnifier_source());
+    assert!(
+        !state.take_pending_magnifier_source(),
+        "the request is taken once"
+    );
+    let lens = state.magnifier_lens((120, 80)).expect("lens while open");
+    assert_eq!(lens.center, (120.0, 80.0));
+    assert_eq!(lens.magnification, 2.0);
+
+    state.handle_action(Action::ToggleMagnifier);
+    assert!(!state.magnifier_active());
+    assert!(state.take_pending_magnifier_source());
+    assert!(state.magnifier_lens((120, 80)).is_none());
+}
+
+#[test]
+fn magnifier_zoom_steps_stay_within_range() {
+    let mut state = create_test_input_state();
+    state.handle_action(Action::MagnifierZoomIn);
+    assert_eq!(
+        state.magnifier_magnification(),
+        2.0,
+        "zoom steps need the lens open"
+    );
+
+    state.handle_action(Action::ToggleMagnifier);
+    state.handle_action(Action::MagnifierZoomIn);
+    assert!((state.magnifier_magnification() - 2.5).abs() < 1e-9);
+
+    while state.step_magnifier_zoom(true) {}
+    assert_eq!(state.magnifier_magnification(), 8.0);
+    while state.step_magnifier_zoom(false) {}
+    assert_eq!(state.magnifier_magnification(), 1.25);
+}
+
+#[test]
+fn rectangular_magnifier_uses_the_aspect_ratio() {
+    let mut state = create_test_input_state();
+    state.init_magnifier_from_config(MagnifierSettings::from(&MagnifierConfig {
+        shape: MagnifierShape::Rectangle,
+        size: 320.0,
+        aspect_ratio: 2.0,
+        ..MagnifierConfig::default()
+    }));
+    state.handle_action(Action::ToggleMagnifier);
+
+    let lens = state.magnifier_lens((0, 0)).expect("lens while open");
+    assert!(!lens.round);
+    assert_eq!((lens.width, lens.height), (320.0, 160.0));
+}
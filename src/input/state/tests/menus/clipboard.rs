@@ -9,6 +9,8 @@ fn add_rect(state: &mut InputState) -> crate::draw::ShapeId {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     })
 }
 
@@ -104,7 +106,7 @@ fn copy_menu_command_publishes_selection_and_closes_menu() {
         .take_pending_selection_clipboard_publish()
         .expect("pending clipboard publish");
     assert_eq!(publish.generation, 1);
-    assert!(publish.payload_json.contains("\"schema_version\":1"));
+    assert!(publish.payload_json.contains("\"schema_version\":2"));
     assert!(matches!(state.context_menu_state, ContextMenuState::Hidden));
 }
 
@@ -54,6 +54,8 @@ fn shape_menu_includes_select_this_entry_whenever_hovered() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let second = state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 40,
@@ -63,6 +65,8 @@ fn shape_menu_includes_select_this_entry_whenever_hovered() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![first, second]);
@@ -105,6 +109,8 @@ fn shape_menu_includes_reset_canvas_position_on_solid_boards() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -135,6 +141,8 @@ fn select_this_shape_command_focuses_single_shape() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let second = state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 40,
@@ -144,6 +152,8 @@ fn select_this_shape_command_focuses_single_shape() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![first, second]);
@@ -181,6 +191,8 @@ fn properties_command_opens_panel() {
                 a: 1.0,
             },
             thick: 2.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         })
     };
 
@@ -214,6 +226,7 @@ fn keyboard_context_menu_focuses_edit_for_selected_text() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     state.set_selection(vec![shape_id]);
@@ -406,6 +419,8 @@ fn canvas_menu_uses_clear_unlocked_label_when_canvas_has_locked_shapes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 20,
@@ -415,6 +430,8 @@ fn canvas_menu_uses_clear_unlocked_label_when_canvas_has_locked_shapes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let locked_index = state
         .boards
@@ -570,6 +587,8 @@ fn keyboard_shape_menu_anchor_tracks_panned_board_view_offset() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![shape_id]);
 
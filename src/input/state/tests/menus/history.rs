@@ -13,6 +13,8 @@ fn push_rect_create(state: &mut InputState, x: i32) {
         fill: false,
         color,
         thick,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let index = frame.find_index(id).expect("shape index");
     let snapshot = frame.shape(id).expect("shape snapshot").clone();
@@ -38,6 +40,8 @@ fn undo_all_and_redo_all_process_entire_stack() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let first_index = frame.find_index(first).unwrap();
     let first_snapshot = frame.shape(first).unwrap().clone();
@@ -56,6 +60,8 @@ fn undo_all_and_redo_all_process_entire_stack() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let second_index = frame.find_index(second).unwrap();
     let second_snapshot = frame.shape(second).unwrap().clone();
@@ -89,6 +95,7 @@ fn undo_all_with_delay_respects_history() {
         y2: 10,
         color: state.current_color,
         thick: state.current_thickness,
+        line_style: LineStyle::Solid,
     });
     let idx = frame.find_index(id).unwrap();
     let snap = frame.shape(id).unwrap().clone();
@@ -117,6 +124,7 @@ fn redo_all_with_delay_replays_history() {
         y2: 10,
         color: state.current_color,
         thick: state.current_thickness,
+        line_style: LineStyle::Solid,
     });
     let idx = frame.find_index(id).unwrap();
     let snap = frame.shape(id).unwrap().clone();
@@ -12,6 +12,7 @@ fn shape_menu_disables_edit_for_locked_text() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     if let Some(index) = state.boards.active_frame().find_index(shape_id) {
@@ -45,6 +46,8 @@ fn shape_menu_disables_delete_when_all_locked() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let second = state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 25,
@@ -54,6 +57,8 @@ fn shape_menu_disables_delete_when_all_locked() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     if let Some(index) = state.boards.active_frame().find_index(first) {
@@ -90,6 +95,8 @@ fn shape_menu_allows_delete_when_mixed_lock_state() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let unlocked_id = state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 30,
@@ -99,6 +106,8 @@ fn shape_menu_allows_delete_when_mixed_lock_state() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     if let Some(index) = state.boards.active_frame().find_index(locked_id) {
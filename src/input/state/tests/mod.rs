@@ -1,7 +1,7 @@
 use super::core::{ContextMenuKind, ContextMenuState, MenuCommand};
 use super::*;
 use crate::config::{Action, BoardsConfig, ColorSpec, ToolPresetConfig};
-use crate::draw::{Color, EraserKind, FontDescriptor, Shape, frame::UndoAction};
+use crate::draw::{Color, EraserKind, FontDescriptor, LineStyle, Shape, frame::UndoAction};
 use crate::input::{ClickHighlightSettings, EraserMode, Key, MouseButton, Tool};
 
 mod helpers;
@@ -16,11 +16,16 @@ mod basics;
 mod board_picker;
 mod boards;
 mod delete_restore;
+mod drag_drop;
 mod drawing;
 mod erase;
 mod focus_mode;
+mod grid;
 mod input_hud;
+mod laser;
+mod layers;
 mod light_mode;
+mod magnifier;
 mod menus;
 mod modal;
 mod pages;
@@ -28,13 +33,17 @@ mod presenter_mode;
 mod pressure_modes;
 mod properties_panel;
 mod radial_menu;
+mod replay;
 mod selection;
 mod session_preflight;
+mod smart_ink;
 mod spotlight;
+mod stabilizer;
 mod status_hud;
 mod step_markers;
 mod text_edit;
 mod text_input;
+mod timer;
 mod tool_controls;
 mod toolbar_display;
 mod transform;
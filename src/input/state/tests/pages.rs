@@ -1,5 +1,7 @@
 use super::*;
-use crate::draw::{BoardPages, EmbeddedImage, Frame, ShapeId};
+use crate::draw::{
+    BoardPages, EmbeddedImage, Frame, PageBackground, PageBackgroundSource, ShapeId,
+};
 use crate::input::{
     BOARD_ID_BLACKBOARD, BOARD_ID_TRANSPARENT, BOARD_ID_WHITEBOARD, BoardBackground,
 };
@@ -36,6 +38,7 @@ fn add_active_text_shape(state: &mut InputState, text: &str) -> ShapeId {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     })
 }
 
@@ -486,6 +489,7 @@ fn add_active_image_shape(state: &mut InputState, bytes: usize) -> ShapeId {
             height: 180,
             bytes: pseudo_random_bytes(bytes),
         },
+        rotation: 0.0,
     })
 }
 
@@ -591,3 +595,89 @@ fn projected_mixed_page_duplicate_written_size(
         .visible_without_history
         .written_size
 }
+
+fn imported_page(page: u32) -> PageBackground {
+    PageBackground::fitted(
+        PageBackgroundSource {
+            path: PathBuf::from("/tmp/deck.pdf"),
+            page: Some(page),
+        },
+        EmbeddedImage {
+            mime_type: "image/png".to_string(),
+            width: 4,
+            height: 3,
+            bytes: vec![1, 2, 3],
+        },
+        800,
+        600,
+    )
+}
+
+#[test]
+fn imported_pages_fill_an_empty_page_then_follow_it_in_order() {
+    let mut state = create_test_input_state();
+    let board = state.boards.active_index();
+    set_named_pages(&mut state, board, &["intro", "outro"], 0);
+
+    let added = state.apply_imported_pages(vec![imported_page(1), imported_page(2)]);
+
+    assert_eq!(added, 2);
+    let pages = state.boards.active_pages().pages();
+    let imported: Vec<_> = pages
+        .iter()
+        .map(|page| {
+            page.background()
+                .and_then(|background| background.source.page)
+        })
+        .collect();
+    assert_eq!(imported, [Some(1), Some(2), None]);
+    assert_eq!(pages[0].page_name(), Some("intro"));
+    assert_eq!(state.boards.active_page_index(), 0);
+    assert!(state.take_session_dirty());
+}
+
+#[test]
+fn imported_pages_never_cover_existing_annotations() {
+    let mut state = create_test_input_state();
+    add_active_text_shape(&mut state, "keep me");
+
+    state.apply_imported_pages(vec![imported_page(1)]);
+
+    assert_eq!(state.boards.page_count(), 2);
+    assert!(
+        state.boards.active_pages().pages()[0]
+            .background()
+            .is_none()
+    );
+    assert_eq!(state.boards.active_page_index(), 1);
+    assert!(state.boards.active_frame().background().is_some());
+
+    assert!(state.clear_page_background());
+    assert!(state.boards.active_frame().background().is_none());
+    assert!(!state.clear_page_background());
+}
+
+#[test]
+fn importing_and_clearing_a_page_background_are_undoable() {
+    let mut state = create_test_input_state();
+
+    state.apply_imported_pages(vec![imported_page(1)]);
+    assert!(state.boards.active_frame().background().is_some());
+
+    assert!(state.clear_page_background());
+    state.handle_action(Action::Undo);
+    assert_eq!(
+        state
+            .boards
+            .active_frame()
+            .background()
+            .and_then(|background| background.source.page),
+        Some(1)
+    );
+
+    state.handle_action(Action::Undo);
+    assert!(state.boards.active_frame().background().is_none());
+
+    state.handle_action(Action::Redo);
+    assert!(state.boards.active_frame().background().is_some());
+}
@@ -67,6 +67,7 @@ fn presenter_mode_blocks_preset_status_bar_toggle() {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: Some(true),
         drag_tools: None,
     };
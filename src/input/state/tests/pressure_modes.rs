@@ -28,6 +28,7 @@ fn add_text_shape(state: &mut InputState) -> ShapeId {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     })
 }
 
@@ -76,6 +77,7 @@ fn text_snapshot(
             font_descriptor,
             background_enabled,
             wrap_width,
+            ..
         }) => (
             *x,
             *y,
@@ -11,6 +11,8 @@ fn add_rect(state: &mut InputState, x: i32, y: i32, w: i32, h: i32) -> crate::dr
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     })
 }
 
@@ -42,7 +44,7 @@ fn show_properties_panel_for_single_shape_reports_type_layer_and_lock_state() {
             .any(|line| line == &format!("Shape ID: {shape_id}"))
     );
     assert!(panel.lines.iter().any(|line| line == "Type: Rectangle"));
-    assert!(panel.lines.iter().any(|line| line == "Layer: 1 of 1"));
+    assert!(panel.lines.iter().any(|line| line == "Stack: 1 of 1"));
     assert!(panel.lines.iter().any(|line| line == "Locked: No"));
     assert!(panel.lines.iter().any(|line| line.starts_with("Bounds: ")));
 }
@@ -201,6 +203,85 @@ fn activate_fill_entry_toggles_rectangle_fill_and_refreshes_panel_value() {
     );
 }
 
+#[test]
+fn line_style_entry_cycles_rectangle_style_in_both_directions() {
+    let mut state = create_test_input_state();
+    let shape_id = add_rect(&mut state, 5, 5, 20, 20);
+    state.set_selection(vec![shape_id]);
+    assert!(state.show_properties_panel());
+    let style_index = entry_index(&state, "Line style");
+    assert_eq!(
+        state.properties_panel().expect("panel").entries[style_index].value,
+        "Solid"
+    );
+    state.set_properties_panel_focus(Some(style_index));
+
+    assert!(state.activate_properties_panel_entry());
+    assert_eq!(
+        state
+            .boards
+            .active_frame()
+            .shape(shape_id)
+            .expect("shape")
+            .shape
+            .line_style(),
+        Some(LineStyle::Dashed)
+    );
+    assert_eq!(
+        state.properties_panel().expect("panel").entries[style_index].value,
+        "Dashed"
+    );
+
+    assert!(state.adjust_properties_panel_entry(-1));
+    assert!(state.adjust_properties_panel_entry(-1));
+    assert_eq!(
+        state.properties_panel().expect("panel").entries[style_index].value,
+        "Dash-dot"
+    );
+}
+
+#[test]
+fn opacity_and_blend_entries_restyle_the_shape_and_undo_as_one_step_each() {
+    let mut state = create_test_input_state();
+    let shape_id = add_rect(&mut state, 5, 5, 20, 20);
+    state.set_selection(vec![shape_id]);
+    assert!(state.show_properties_panel());
+    let opacity_index = entry_index(&state, "Opacity");
+    let blend_index = entry_index(&state, "Blend");
+    assert_eq!(
+        state.properties_panel().expect("panel").entries[opacity_index].value,
+        "100%"
+    );
+
+    state.set_properties_panel_focus(Some(opacity_index));
+    assert!(state.adjust_properties_panel_entry(-1));
+    assert!(state.adjust_properties_panel_entry(-1));
+    state.set_properties_panel_focus(Some(blend_index));
+    assert!(state.activate_properties_panel_entry());
+
+    let appearance = state
+        .boards
+        .active_frame()
+        .shape(shape_id)
+        .expect("shape")
+        .appearance;
+    assert!((appearance.opacity - 0.8).abs() < 1e-9);
+    assert_eq!(appearance.blend, crate::draw::BlendMode::Multiply);
+    let panel = state.properties_panel().expect("panel");
+    assert_eq!(panel.entries[opacity_index].value, "80%");
+    assert_eq!(panel.entries[blend_index].value, "Multiply");
+
+    state.handle_action(Action::Undo);
+    let appearance = state
+        .boards
+        .active_frame()
+        .shape(shape_id)
+        .expect("shape")
+        .appearance;
+    assert_eq!(appearance.blend, crate::draw::BlendMode::Normal);
+    assert!((appearance.opacity - 0.8).abs() < 1e-9);
+}
+
 #[test]
 fn adjust_font_size_entry_increases_text_size_and_refreshes_panel_value() {
     let mut state = create_test_input_state();
@@ -213,6 +294,7 @@ fn adjust_font_size_entry_increases_text_size_and_refreshes_panel_value() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: false,
         wrap_width: None,
+        rotation: 0.0,
     });
     state.set_selection(vec![shape_id]);
     assert!(state.show_properties_panel());
@@ -249,6 +331,7 @@ fn activate_text_background_entry_on_mixed_selection_turns_all_backgrounds_on()
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: false,
         wrap_width: None,
+        rotation: 0.0,
     });
     let second = state.boards.active_frame_mut().add_shape(Shape::Text {
         x: 40,
@@ -259,6 +342,7 @@ fn activate_text_background_entry_on_mixed_selection_turns_all_backgrounds_on()
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: true,
         wrap_width: None,
+        rotation: 0.0,
     });
     state.set_selection(vec![first, second]);
     assert!(state.show_properties_panel());
@@ -301,6 +385,7 @@ fn adjust_arrow_length_entry_clamps_to_max_and_refreshes_panel_value() {
         arrow_angle: 30.0,
         head_at_end: true,
         label: None,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![shape_id]);
     assert!(state.show_properties_panel());
@@ -268,10 +268,28 @@ fn notes_sub_ring_is_step_marker_then_sticky_note() {
     );
 }
 
+#[test]
+fn marker_wedge_carries_the_laser_pointer_as_a_companion() {
+    assert_eq!(
+        RADIAL_COMPASS_SLICES[CompassDir::NE.index() as usize].companions,
+        Some(RadialParent::Present)
+    );
+    assert!(sub_ring_children(CompassDir::NE.index()).contains(&Action::SelectLaserTool));
+}
+
+#[test]
+fn marker_companion_ring_is_laser_then_magnifier() {
+    assert_eq!(
+        sub_ring_children(CompassDir::NE.index()),
+        &[Action::SelectLaserTool, Action::ToggleMagnifier]
+    );
+}
+
 #[test]
 fn non_parent_wedges_have_no_sub_ring_children() {
     for slice in RADIAL_COMPASS_SLICES.iter() {
-        let expected = matches!(slice.kind, RadialSliceKind::Parent(_));
+        let expected =
+            matches!(slice.kind, RadialSliceKind::Parent(_)) || slice.companions.is_some();
         assert_eq!(
             sub_ring_child_count(slice.dir.index()) > 0,
             expected,
@@ -326,6 +344,69 @@ fn notes_sub_ring_child_dispatches_sticky_note_mode() {
     assert!(matches!(state.text_input_mode, TextInputMode::StickyNote));
 }
 
+#[test]
+fn hovering_marker_wedge_reveals_companions_but_selecting_it_picks_marker() {
+    let mut state = create_test_input_state();
+    let layout = open_with_layout(&mut state);
+
+    let (x, y) = point_in_compass_wedge(&layout, CompassDir::NE);
+    state.update_radial_menu_hover(x, y);
+    assert_eq!(expanded_sub_ring_of(&state), Some(CompassDir::NE.index()));
+
+    state.radial_menu_select_hovered();
+
+    assert!(!state.is_radial_menu_open());
+    assert_eq!(state.active_tool(), Tool::Marker);
+}
+
+#[test]
+fn marker_companion_ring_child_selects_laser_pointer() {
+    let mut state = create_test_input_state();
+    let layout = open_with_layout(&mut state);
+
+    let (parent_x, parent_y) = point_in_compass_wedge(&layout, CompassDir::NE);
+    state.update_radial_menu_hover(parent_x, parent_y);
+
+    let children = sub_ring_children(CompassDir::NE.index());
+    let laser_idx = children
+        .iter()
+        .position(|action| *action == Action::SelectLaserTool)
+        .expect("laser child") as u8;
+    let (x, y) =
+        point_in_sub_tool_segment(&layout, CompassDir::NE.index(), laser_idx, children.len());
+    state.update_radial_menu_hover(x, y);
+    state.radial_menu_select_hovered();
+
+    assert!(!state.is_radial_menu_open());
+    assert_eq!(state.active_tool(), Tool::Laser);
+}
+
+#[test]
+fn marker_companion_ring_child_toggles_magnifier() {
+    let mut state = create_test_input_state();
+    let layout = open_with_layout(&mut state);
+
+    let (parent_x, parent_y) = point_in_compass_wedge(&layout, CompassDir::NE);
+    state.update_radial_menu_hover(parent_x, parent_y);
+
+    let children = sub_ring_children(CompassDir::NE.index());
+    let magnifier_idx = children
+        .iter()
+        .position(|action| *action == Action::ToggleMagnifier)
+        .expect("magnifier child") as u8;
+    let (x, y) = point_in_sub_tool_segment(
+        &layout,
+        CompassDir::NE.index(),
+        magnifier_idx,
+        children.len(),
+    );
+    state.update_radial_menu_hover(x, y);
+    state.radial_menu_select_hovered();
+
+    assert!(!state.is_radial_menu_open());
+    assert!(state.magnifier_active());
+}
+
 #[test]
 fn compass_ring_offers_no_history_or_clear_actions() {
     let mut ring_actions: Vec<Action> = Vec::new();
@@ -472,6 +553,7 @@ fn right_click_when_radial_open_on_panned_board_uses_canvas_hit_testing() {
         y2: 90,
         color: state.current_color,
         thick: state.current_thickness,
+        line_style: LineStyle::Solid,
     });
     state.open_radial_menu(50.0, 40.0);
     assert!(state.is_radial_menu_open());
@@ -0,0 +1,66 @@
+use super::create_test_input_state;
+use crate::config::Action;
+use crate::draw::{LineStyle, Shape, color::BLACK};
+use crate::input::Key;
+use std::time::{Duration, Instant};
+
+fn add_line(state: &mut crate::input::InputState, x: i32) {
+    state.boards.active_frame_mut().add_shape(Shape::Line {
+        x1: x,
+        y1: 0,
+        x2: x + 40,
+        y2: 20,
+        color: BLACK,
+        thick: 2.0,
+        line_style: LineStyle::Solid,
+    });
+}
+
+#[test]
+fn replay_steps_through_the_page_without_touching_it() {
+    let mut state = create_test_input_state();
+    add_line(&mut state, 0);
+    add_line(&mut state, 100);
+
+    state.handle_action(Action::ToggleReplay);
+    assert!(state.replay_active());
+    assert!(state.canvas_frame().shapes.is_empty());
+
+    state.on_key_press(Key::Space);
+    assert!(!state.replay().unwrap().playing());
+    state.on_key_press(Key::Right);
+    assert_eq!(state.canvas_frame().shapes.len(), 1);
+    state.on_key_press(Key::End);
+    assert_eq!(state.canvas_frame().shapes.len(), 2);
+
+    // Keys that would edit the page are swallowed while replaying.
+    state.on_key_press(Key::Delete);
+    assert_eq!(state.boards.active_frame().shapes.len(), 2);
+
+    state.on_key_press(Key::Escape);
+    assert!(!state.replay_active());
+    assert_eq!(state.canvas_frame().shapes.len(), 2);
+}
+
+#[test]
+fn replay_plays_over_time_and_stops_at_the_end() {
+    let mut state = create_test_input_state();
+    add_line(&mut state, 0);
+    add_line(&mut state, 100);
+    state.start_replay();
+
+    let start = Instant::now();
+    assert!(state.advance_replay(start));
+    assert!(state.advance_replay(start + Duration::from_millis(500)));
+    assert_eq!(state.replay().unwrap().shapes_shown(), 1);
+    assert!(!state.advance_replay(start + Duration::from_secs(5)));
+    assert_eq!(state.replay().unwrap().shapes_shown(), 2);
+    assert!(!state.replay().unwrap().playing());
+}
+
+#[test]
+fn replay_of_an_empty_page_does_not_start() {
+    let mut state = create_test_input_state();
+    state.start_replay();
+    assert!(!state.replay_active());
+}
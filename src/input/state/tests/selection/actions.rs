@@ -11,6 +11,8 @@ fn select_all_action_selects_shapes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let second = state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 30,
@@ -20,6 +22,8 @@ fn select_all_action_selects_shapes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.handle_action(Action::SelectAll);
@@ -40,6 +44,8 @@ fn escape_clears_selection_before_exit() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -59,6 +65,8 @@ fn select_tool_drag_selects_shapes_in_rect() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let outside = state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 80,
@@ -68,6 +76,8 @@ fn select_tool_drag_selects_shapes_in_rect() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_tool_override(Some(Tool::Select));
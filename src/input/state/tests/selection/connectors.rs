@@ -0,0 +1,149 @@
+use super::*;
+use crate::draw::frame::{Connector, ConnectorAnchor, ShapeId};
+
+fn add_rect(state: &mut InputState, x: i32) -> ShapeId {
+    state.boards.active_frame_mut().add_shape(Shape::Rect {
+        x,
+        y: 20,
+        w: 40,
+        h: 30,
+        fill: false,
+        color: state.current_color,
+        thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
+    })
+}
+
+fn connector_of(state: &InputState, id: ShapeId) -> Option<Connector> {
+    state.boards.active_frame().shape(id).unwrap().connector
+}
+
+fn arrow_ends(state: &InputState, id: ShapeId) -> ((i32, i32), (i32, i32)) {
+    match &state.boards.active_frame().shape(id).unwrap().shape {
+        Shape::Arrow { x1, y1, x2, y2, .. } => ((*x1, *y1), (*x2, *y2)),
+        other => panic!("expected arrow, got {other:?}"),
+    }
+}
+
+fn anchor_point(state: &InputState, id: ShapeId, anchor: ConnectorAnchor) -> (i32, i32) {
+    let bounds = state
+        .boards
+        .active_frame()
+        .shape(id)
+        .unwrap()
+        .bounding_box()
+        .unwrap();
+    anchor.point_on(bounds)
+}
+
+/// Draws an arrow from the right edge of `from` to the left edge of `to`.
+fn draw_connector(state: &mut InputState) -> (ShapeId, ShapeId, ShapeId) {
+    let from = add_rect(state, 10);
+    let to = add_rect(state, 160);
+    assert!(state.set_tool_override(Some(Tool::Arrow)));
+    state.handle_action(Action::ToggleConnectorMode);
+    assert!(state.connector_mode);
+
+    state.on_mouse_press(MouseButton::Left, 48, 35);
+    state.on_mouse_motion(162, 35);
+    state.on_mouse_release(MouseButton::Left, 162, 35);
+    let arrow = state.boards.active_frame().shapes.last().unwrap().id;
+    (from, to, arrow)
+}
+
+#[test]
+fn connector_mode_snaps_arrow_ends_to_shape_anchors() {
+    let mut state = create_test_input_state();
+    let (from, to, arrow) = draw_connector(&mut state);
+
+    let connector = connector_of(&state, arrow).expect("arrow should be attached");
+    let start = connector.start.expect("start should be attached");
+    let end = connector.end.expect("end should be attached");
+    assert_eq!(
+        (start.shape_id, start.anchor),
+        (from, ConnectorAnchor::Right)
+    );
+    assert_eq!((end.shape_id, end.anchor), (to, ConnectorAnchor::Left));
+    assert_eq!(
+        arrow_ends(&state, arrow),
+        (
+            anchor_point(&state, from, ConnectorAnchor::Right),
+            anchor_point(&state, to, ConnectorAnchor::Left)
+        )
+    );
+}
+
+#[test]
+fn moving_a_target_reroutes_the_connector_until_undone() {
+    let mut state = create_test_input_state();
+    let (_, to, arrow) = draw_connector(&mut state);
+    let original = arrow_ends(&state, arrow);
+
+    state.set_selection(vec![to]);
+    assert!(state.translate_selection_with_undo(0, 40));
+    assert_eq!(
+        arrow_ends(&state, arrow).1,
+        anchor_point(&state, to, ConnectorAnchor::Left)
+    );
+    assert_eq!(arrow_ends(&state, arrow).0, original.0);
+
+    let action = state.boards.active_frame_mut().undo_last().unwrap();
+    state.apply_action_side_effects(&action);
+    assert_eq!(arrow_ends(&state, arrow), original);
+
+    let action = state.boards.active_frame_mut().redo_last().unwrap();
+    state.apply_action_side_effects(&action);
+    assert_eq!(
+        arrow_ends(&state, arrow).1,
+        anchor_point(&state, to, ConnectorAnchor::Left)
+    );
+}
+
+#[test]
+fn moving_a_connector_on_its_own_detaches_it() {
+    let mut state = create_test_input_state();
+    let (from, to, arrow) = draw_connector(&mut state);
+    let attached = connector_of(&state, arrow);
+
+    state.set_selection(vec![arrow]);
+    assert!(state.translate_selection_with_undo(0, 60));
+    assert_eq!(connector_of(&state, arrow), None);
+
+    // With the arrow free, moving its former target leaves it in place.
+    let detached = arrow_ends(&state, arrow);
+    state.set_selection(vec![to]);
+    assert!(state.translate_selection_with_undo(20, 0));
+    assert_eq!(arrow_ends(&state, arrow), detached);
+
+    for _ in 0..2 {
+        let action = state.boards.active_frame_mut().undo_last().unwrap();
+        state.apply_action_side_effects(&action);
+    }
+    assert_eq!(connector_of(&state, arrow), attached);
+    assert_eq!(
+        arrow_ends(&state, arrow),
+        (
+            anchor_point(&state, from, ConnectorAnchor::Right),
+            anchor_point(&state, to, ConnectorAnchor::Left)
+        )
+    );
+}
+
+#[test]
+fn connector_moves_with_its_targets_and_stays_attached() {
+    let mut state = create_test_input_state();
+    let (from, to, arrow) = draw_connector(&mut state);
+    let attached = connector_of(&state, arrow);
+
+    state.set_selection(vec![from, to, arrow]);
+    assert!(state.translate_selection_with_undo(15, 15));
+    assert_eq!(connector_of(&state, arrow), attached);
+    assert_eq!(
+        arrow_ends(&state, arrow),
+        (
+            anchor_point(&state, from, ConnectorAnchor::Right),
+            anchor_point(&state, to, ConnectorAnchor::Left)
+        )
+    );
+}
@@ -10,6 +10,7 @@ fn delete_shapes_by_ids_ignores_missing_ids() {
         y2: 5,
         color: state.current_color,
         thick: state.current_thickness,
+        line_style: LineStyle::Solid,
     });
 
     let removed = state.delete_shapes_by_ids(&[9999]);
@@ -29,6 +30,7 @@ fn locked_shape_blocks_edit_and_delete() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     if let Some(index) = state.boards.active_frame().find_index(shape_id) {
@@ -54,6 +56,7 @@ fn clear_all_removes_shapes_even_when_marked_frozen() {
         y2: 10,
         color: state.current_color,
         thick: state.current_thickness,
+        line_style: LineStyle::Solid,
     });
 
     // Simulate frozen flag being on
@@ -76,6 +79,8 @@ fn clear_all_skips_locked_shapes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let unlocked_id = state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 40,
@@ -85,6 +90,8 @@ fn clear_all_skips_locked_shapes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     if let Some(index) = state.boards.active_frame().find_index(locked_id) {
@@ -114,6 +121,8 @@ fn clear_all_returns_false_when_all_locked() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     if let Some(index) = state.boards.active_frame().find_index(locked_id) {
@@ -16,6 +16,8 @@ fn duplicate_selection_via_action_creates_offset_shape() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![original_id]);
@@ -53,6 +55,8 @@ fn copy_paste_selection_centers_shape_at_pointer() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![original_id]);
@@ -100,6 +104,8 @@ fn immediate_paste_after_copy_uses_pending_local_publish_shapes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![original_id]);
     state.handle_action(Action::CopySelection);
@@ -120,6 +126,54 @@ fn immediate_paste_after_copy_uses_pending_local_publish_shapes() {
     assert_eq!(state.boards.active_frame().shapes.len(), 2);
 }
 
+#[test]
+fn copy_paste_keeps_shape_opacity_and_blend_mode() {
+    let mut state = create_test_input_state();
+    let original_id = state.boards.active_frame_mut().add_shape(Shape::Rect {
+        x: 10,
+        y: 20,
+        w: 100,
+        h: 80,
+        fill: true,
+        color: state.current_color,
+        thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
+    });
+    let appearance = crate::draw::ShapeAppearance {
+        opacity: 0.4,
+        blend: crate::draw::BlendMode::Multiply,
+    };
+    state
+        .boards
+        .active_frame_mut()
+        .shape_mut(original_id)
+        .unwrap()
+        .appearance = appearance;
+    state.set_selection(vec![original_id]);
+    state.handle_action(Action::CopySelection);
+
+    let publish = state
+        .take_pending_selection_clipboard_publish()
+        .expect("pending publish");
+    let payload: WayscriberClipboardSelection =
+        serde_json::from_str(&publish.payload_json).expect("private payload");
+    state.handle_action(Action::PasteSelection);
+    let request = state
+        .take_pending_clipboard_paste_request()
+        .expect("pending paste request");
+    assert_eq!(
+        state.paste_clipboard_shapes_from_request(&request, payload.shapes),
+        1
+    );
+    state.finish_clipboard_paste_request(request.id);
+
+    let pasted = state.selected_shape_ids()[0];
+    assert_ne!(pasted, original_id);
+    let frame = state.boards.active_frame();
+    assert_eq!(frame.shape(pasted).unwrap().appearance, appearance);
+}
+
 #[test]
 fn stale_publish_completion_is_ignored_for_newer_copy() {
     let mut state = create_test_input_state();
@@ -131,6 +185,8 @@ fn stale_publish_completion_is_ignored_for_newer_copy() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![first_id]);
     state.handle_action(Action::CopySelection);
@@ -146,6 +202,8 @@ fn stale_publish_completion_is_ignored_for_newer_copy() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![second_id]);
     state.handle_action(Action::CopySelection);
@@ -176,6 +234,8 @@ fn failed_local_clipboard_precedence_clears_when_fingerprint_changes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![original_id]);
     state.handle_action(Action::CopySelection);
@@ -234,6 +294,8 @@ fn failed_local_clipboard_without_failure_fingerprint_supersedes_when_current_is
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![original_id]);
     state.handle_action(Action::CopySelection);
@@ -271,6 +333,8 @@ fn failed_local_clipboard_without_current_fingerprint_does_not_fast_path() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![original_id]);
     state.handle_action(Action::CopySelection);
@@ -301,6 +365,8 @@ fn published_selection_allows_local_fallback_until_superseded() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![original_id]);
     state.handle_action(Action::CopySelection);
@@ -339,6 +405,8 @@ fn fallback_generation_rejects_newer_local_copy() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![original_id]);
     state.handle_action(Action::CopySelection);
@@ -374,6 +442,8 @@ fn private_payload_for_request_rejects_newer_same_instance_generation() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![first_id]);
     state.handle_action(Action::CopySelection);
@@ -393,6 +463,8 @@ fn private_payload_for_request_rejects_newer_same_instance_generation() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![second_id]);
     state.handle_action(Action::CopySelection);
@@ -420,6 +492,8 @@ fn private_payload_for_request_uses_payload_when_current_generation_changed() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![first_id]);
     state.handle_action(Action::CopySelection);
@@ -441,6 +515,8 @@ fn private_payload_for_request_uses_payload_when_current_generation_changed() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![second_id]);
     state.handle_action(Action::CopySelection);
@@ -449,7 +525,7 @@ fn private_payload_for_request_uses_payload_when_current_generation_changed() {
         .private_payload_shapes_for_request(&request, first_payload)
         .expect("request-owned private payload shapes");
     assert_eq!(shapes.len(), 1);
-    match &shapes[0] {
+    match &shapes[0].shape {
         Shape::Rect { x, y, .. } => {
             assert_eq!((*x, *y), (10, 20));
         }
@@ -468,6 +544,8 @@ fn same_instance_private_payload_with_no_fallback_generation_uses_payload() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![original_id]);
     state.handle_action(Action::CopySelection);
@@ -506,6 +584,8 @@ fn request_generation_supersede_ignores_newer_local_copy() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![first_id]);
     state.handle_action(Action::CopySelection);
@@ -525,6 +605,8 @@ fn request_generation_supersede_ignores_newer_local_copy() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![second_id]);
     state.handle_action(Action::CopySelection);
@@ -553,6 +635,8 @@ fn failed_local_fast_path_rejects_newer_generation() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![first_id]);
     state.handle_action(Action::CopySelection);
@@ -585,6 +669,8 @@ fn failed_local_fast_path_rejects_newer_generation() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![second_id]);
     state.handle_action(Action::CopySelection);
@@ -620,6 +706,8 @@ fn duplicate_selection_skips_locked_shapes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let locked_id = state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 20,
@@ -629,6 +717,8 @@ fn duplicate_selection_skips_locked_shapes() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     if let Some(index) = state.boards.active_frame().find_index(locked_id) {
@@ -657,6 +747,8 @@ fn copy_selection_of_only_locked_shapes_leaves_clipboard_empty() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let locked_index = state
         .boards
@@ -681,6 +773,8 @@ fn repeated_paste_selection_uses_current_pointer_anchor() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![original_id]);
     assert_eq!(state.copy_selection(), 1);
@@ -711,6 +805,8 @@ fn paste_selection_warns_when_shape_limit_prevents_any_paste() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![original_id]);
     assert_eq!(state.copy_selection(), 1);
@@ -734,6 +830,8 @@ fn paste_selection_warns_when_shape_limit_allows_only_partial_paste() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     let second = state.boards.active_frame_mut().add_shape(Shape::Rect {
         x: 20,
@@ -743,6 +841,8 @@ fn paste_selection_warns_when_shape_limit_allows_only_partial_paste() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![first, second]);
     assert_eq!(state.copy_selection(), 2);
@@ -0,0 +1,142 @@
+use super::*;
+use crate::draw::frame::{GroupId, ShapeId};
+
+fn add_rect(state: &mut InputState, x: i32) -> ShapeId {
+    state.boards.active_frame_mut().add_shape(Shape::Rect {
+        x,
+        y: 20,
+        w: 40,
+        h: 30,
+        fill: false,
+        color: state.current_color,
+        thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
+    })
+}
+
+fn group_of(state: &InputState, id: ShapeId) -> Option<GroupId> {
+    state.boards.active_frame().shape(id).unwrap().group
+}
+
+#[test]
+fn grouped_shapes_select_as_one_until_ungrouped() {
+    let mut state = create_test_input_state();
+    let arrow = add_rect(&mut state, 10);
+    let label = add_rect(&mut state, 80);
+    let other = add_rect(&mut state, 160);
+
+    state.set_selection(vec![arrow, label]);
+    state.handle_action(Action::GroupSelection);
+    assert!(group_of(&state, arrow).is_some());
+    assert_eq!(group_of(&state, arrow), group_of(&state, label));
+    assert_eq!(group_of(&state, other), None);
+
+    state.set_selection(vec![label]);
+    assert_eq!(state.selected_shape_ids(), &[label, arrow]);
+    state.clear_selection();
+    state.extend_selection([other]);
+    state.extend_selection([arrow]);
+    assert_eq!(state.selected_shape_ids(), &[other, arrow, label]);
+
+    state.set_selection(vec![arrow]);
+    state.handle_action(Action::UngroupSelection);
+    assert_eq!(group_of(&state, arrow), None);
+    assert_eq!(state.selected_shape_ids(), &[arrow, label]);
+    state.set_selection(vec![label]);
+    assert_eq!(state.selected_shape_ids(), &[label]);
+}
+
+#[test]
+fn grouping_needs_two_shapes() {
+    let mut state = create_test_input_state();
+    let only = add_rect(&mut state, 10);
+    state.set_selection(vec![only]);
+    state.handle_action(Action::GroupSelection);
+    assert_eq!(group_of(&state, only), None);
+    assert_eq!(state.boards.active_frame().undo_stack_len(), 0);
+}
+
+#[test]
+fn group_and_ungroup_are_undoable() {
+    let mut state = create_test_input_state();
+    let first = add_rect(&mut state, 10);
+    let second = add_rect(&mut state, 80);
+
+    state.set_selection(vec![first, second]);
+    state.handle_action(Action::GroupSelection);
+    let group = group_of(&state, first);
+    state.set_selection(vec![first]);
+    state.handle_action(Action::UngroupSelection);
+
+    state.handle_action(Action::Undo);
+    assert_eq!(group_of(&state, first), group);
+    assert_eq!(group_of(&state, second), group);
+    state.handle_action(Action::Undo);
+    assert_eq!(group_of(&state, first), None);
+    state.handle_action(Action::Redo);
+    assert_eq!(group_of(&state, second), group);
+}
+
+#[test]
+fn duplicating_a_group_makes_a_separate_group() {
+    let mut state = create_test_input_state();
+    let first = add_rect(&mut state, 10);
+    let second = add_rect(&mut state, 80);
+    state.set_selection(vec![first, second]);
+    state.handle_action(Action::GroupSelection);
+
+    state.handle_action(Action::DuplicateSelection);
+    let copies = state.selected_shape_ids().to_vec();
+    assert_eq!(copies.len(), 2);
+    let copy_group = group_of(&state, copies[0]);
+    assert!(copy_group.is_some());
+    assert_eq!(group_of(&state, copies[1]), copy_group);
+    assert_ne!(copy_group, group_of(&state, first));
+}
+
+#[test]
+fn pasting_a_copied_group_makes_a_separate_group() {
+    let mut state = create_test_input_state();
+    let first = add_rect(&mut state, 10);
+    let second = add_rect(&mut state, 80);
+    let loose = add_rect(&mut state, 160);
+    state.set_selection(vec![first, second]);
+    state.handle_action(Action::GroupSelection);
+    let original_group = group_of(&state, first);
+
+    state.extend_selection([loose]);
+    state.handle_action(Action::CopySelection);
+    state.handle_action(Action::PasteSelection);
+    let request = state
+        .take_pending_clipboard_paste_request()
+        .expect("pending paste request");
+    let shapes = state
+        .local_selection_shapes_for_pending_publish(request.local_selection_fallback_generation)
+        .expect("pending local publish selection");
+    assert_eq!(
+        state.paste_clipboard_shapes_from_request(&request, shapes),
+        3
+    );
+    state.finish_clipboard_paste_request(request.id);
+
+    let pasted = state.selected_shape_ids().to_vec();
+    let pasted_group = group_of(&state, pasted[0]);
+    assert!(pasted_group.is_some());
+    assert_ne!(pasted_group, original_group);
+    assert_eq!(group_of(&state, pasted[1]), pasted_group);
+    assert_eq!(group_of(&state, pasted[2]), None);
+    assert_eq!(group_of(&state, first), original_group);
+}
+
+#[test]
+fn selecting_every_member_of_a_group_lists_each_once() {
+    let mut state = create_test_input_state();
+    let first = add_rect(&mut state, 10);
+    let second = add_rect(&mut state, 80);
+    state.set_selection(vec![first, second]);
+    state.handle_action(Action::GroupSelection);
+
+    state.set_selection(vec![second, first, second]);
+    assert_eq!(state.selected_shape_ids(), &[second, first]);
+}
@@ -2,5 +2,7 @@
 use super::*;
 
 mod actions;
+mod connectors;
 mod deletion;
 mod duplicate;
+mod groups;
@@ -71,6 +71,7 @@ fn add_active_image_shape(state: &mut InputState) -> ShapeId {
             height: 180,
             bytes: Vec::new(),
         },
+        rotation: 0.0,
     })
 }
 
@@ -82,6 +83,7 @@ fn add_active_point_shape(state: &mut InputState, kind: PointShapeKind, points:
             points: point_path(points),
             color,
             thick,
+            line_style: LineStyle::Solid,
         }),
         PointShapeKind::Marker => state
             .boards
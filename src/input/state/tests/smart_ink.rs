@@ -0,0 +1,144 @@
+use super::*;
+use std::time::{Duration, Instant};
+
+fn draw_pen_stroke(state: &mut InputState, corners: &[(i32, i32)]) {
+    let (start, rest) = corners.split_first().expect("stroke start");
+    state.on_mouse_press(MouseButton::Left, start.0, start.1);
+    let mut previous = *start;
+    for &corner in rest {
+        for step in 1..=10 {
+            let x = previous.0 + (corner.0 - previous.0) * step / 10;
+            let y = previous.1 + (corner.1 - previous.1) * step / 10;
+            state.on_mouse_motion(x, y);
+        }
+        previous = corner;
+    }
+    state.on_mouse_release(MouseButton::Left, previous.0, previous.1);
+}
+
+const RECT_STROKE: [(i32, i32); 6] = [
+    (100, 100),
+    (300, 100),
+    (300, 220),
+    (100, 220),
+    (100, 100),
+    (140, 102),
+];
+
+#[test]
+fn smart_ink_is_off_by_default() {
+    let mut state = create_test_input_state();
+    draw_pen_stroke(&mut state, &RECT_STROKE);
+
+    let shapes = &state.boards.active_frame().shapes;
+    assert_eq!(shapes.len(), 1);
+    assert!(matches!(shapes[0].shape, Shape::Freehand { .. }));
+}
+
+#[test]
+fn smart_ink_replaces_pen_stroke_with_single_undoable_shape() {
+    let mut state = create_test_input_state();
+    state.smart_ink_enabled = true;
+    draw_pen_stroke(&mut state, &RECT_STROKE);
+
+    let shapes = &state.boards.active_frame().shapes;
+    assert_eq!(shapes.len(), 1);
+    match &shapes[0].shape {
+        Shape::Rect {
+            x,
+            y,
+            w,
+            h,
+            color,
+            thick,
+            ..
+        } => {
+            assert_eq!((*x, *y, *w, *h), (100, 100, 200, 120));
+            assert_eq!(*color, state.current_color);
+            assert_eq!(*thick, state.current_thickness);
+        }
+        other => panic!("expected a rectangle, got {other:?}"),
+    }
+
+    state.handle_action(Action::Undo);
+    assert!(state.boards.active_frame().shapes.is_empty());
+}
+
+#[test]
+fn smart_ink_recognizes_arrows_and_keeps_scribbles() {
+    let mut state = create_test_input_state();
+    state.smart_ink_enabled = true;
+    draw_pen_stroke(
+        &mut state,
+        &[(0, 0), (200, 0), (180, -15), (200, 0), (180, 15)],
+    );
+    draw_pen_stroke(
+        &mut state,
+        &[(0, 100), (40, 160), (80, 100), (120, 160), (160, 100)],
+    );
+
+    let shapes = &state.boards.active_frame().shapes;
+    assert_eq!(shapes.len(), 2);
+    assert!(matches!(
+        shapes[0].shape,
+        Shape::Arrow {
+            x1: 0,
+            y1: 0,
+            x2: 200,
+            y2: 0,
+            ..
+        }
+    ));
+    assert!(matches!(shapes[1].shape, Shape::Freehand { .. }));
+}
+
+#[test]
+fn smart_ink_hold_requires_resting_before_release() {
+    let mut state = create_test_input_state();
+    state.smart_ink_enabled = true;
+    state.smart_ink_hold_ms = 400;
+
+    draw_pen_stroke(&mut state, &[(0, 0), (200, 0)]);
+    assert!(matches!(
+        state.boards.active_frame().shapes[0].shape,
+        Shape::Freehand { .. }
+    ));
+
+    state.on_mouse_press(MouseButton::Left, 0, 50);
+    for step in 1..=10 {
+        state.on_mouse_motion(step * 20, 50);
+    }
+    // Jitter inside the rest radius keeps the anchor.
+    state.on_mouse_motion(201, 51);
+    let (anchor, _) = state.smart_ink_rest.expect("rest anchor");
+    assert_eq!(anchor, (200, 50));
+    state.smart_ink_rest = Some((anchor, Instant::now() - Duration::from_millis(500)));
+    state.on_mouse_release(MouseButton::Left, 200, 50);
+
+    assert!(matches!(
+        state.boards.active_frame().shapes[1].shape,
+        Shape::Line {
+            x1: 0,
+            y1: 50,
+            x2: 200,
+            y2: 50,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn smart_ink_ignores_other_tools() {
+    let mut state = create_test_input_state();
+    state.smart_ink_enabled = true;
+    state.modifiers.ctrl = true;
+    state.modifiers.shift = true;
+    state.on_mouse_press(MouseButton::Left, 0, 0);
+    state.on_mouse_release(MouseButton::Left, 20, 20);
+
+    assert!(matches!(
+        state.boards.active_frame().shapes[0].shape,
+        Shape::Arrow { .. }
+    ));
+    assert!(state.smart_ink_rest.is_none());
+}
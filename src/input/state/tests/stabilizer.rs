@@ -0,0 +1,85 @@
+use super::*;
+
+fn freehand_points(state: &InputState) -> Vec<(i32, i32)> {
+    match &state.boards.active_frame().shapes[0].shape {
+        Shape::Freehand { points, .. } | Shape::MarkerStroke { points, .. } => points.clone(),
+        Shape::FreehandPressure { points, .. } => points.iter().map(|&(x, y, _)| (x, y)).collect(),
+        other => panic!("expected a freehand stroke, got {other:?}"),
+    }
+}
+
+/// The points of the stroke still being drawn.
+fn stroke_points(state: &InputState) -> Vec<(i32, i32)> {
+    match &state.state {
+        DrawingState::Drawing { points, .. } => points.clone(),
+        other => panic!("expected a stroke in progress, got {other:?}"),
+    }
+}
+
+#[test]
+fn stabilizer_is_off_by_default_and_records_every_sample() {
+    let mut state = create_test_input_state();
+    state.on_mouse_press(MouseButton::Left, 10, 10);
+    state.on_mouse_motion(12, 11);
+    state.on_mouse_motion(14, 10);
+    state.on_mouse_release(MouseButton::Left, 14, 10);
+
+    assert_eq!(freehand_points(&state), vec![(10, 10), (12, 11), (14, 10)]);
+}
+
+#[test]
+fn stabilizer_ignores_tremor_and_trails_the_pointer() {
+    let mut state = create_test_input_state();
+    state.stabilizer_strength = 0.5;
+    state.on_mouse_press(MouseButton::Left, 100, 100);
+    // Tremor inside the 16 px string leaves the ink where it is.
+    for &(x, y) in &[(104, 98), (97, 103), (110, 100), (100, 112)] {
+        state.on_mouse_motion(x, y);
+    }
+    // A deliberate move pulls the ink along, one string length behind.
+    state.on_mouse_motion(150, 100);
+
+    let points = stroke_points(&state);
+    assert_eq!(points.first(), Some(&(100, 100)));
+    assert_eq!(points.len(), 2);
+    let (x, y) = points[1];
+    assert!((133..=135).contains(&x), "brush at {x}");
+    assert!((99..=101).contains(&y));
+}
+
+#[test]
+fn stabilized_stroke_ends_at_the_release_point() {
+    let mut state = create_test_input_state();
+    state.stabilizer_strength = 1.0;
+    state.on_mouse_press(MouseButton::Left, 0, 0);
+    for x in (10..=200).step_by(10) {
+        state.on_mouse_motion(x, 0);
+    }
+    state.on_mouse_release(MouseButton::Left, 200, 0);
+
+    let points = freehand_points(&state);
+    assert_eq!(points.last(), Some(&(200, 0)));
+    assert!(points.len() > 2, "the brush still trails during the stroke");
+    assert!(state.stabilizer_brush.is_none());
+}
+
+#[test]
+fn smooth_strokes_store_the_fitted_curve() {
+    let mut state = create_test_input_state();
+    state.smooth_strokes = true;
+    state.on_mouse_press(MouseButton::Left, 0, 0);
+    for (x, y) in [(40, 0), (80, 40), (80, 80)] {
+        state.on_mouse_motion(x, y);
+    }
+    state.on_mouse_release(MouseButton::Left, 80, 80);
+
+    let points = freehand_points(&state);
+    assert_eq!(points.first(), Some(&(0, 0)));
+    assert_eq!(points.last(), Some(&(80, 80)));
+    assert!(points.len() > 20);
+    assert!(
+        points
+            .windows(2)
+            .all(|pair| (pair[1].0 - pair[0].0).abs() <= 4 && (pair[1].1 - pair[0].1).abs() <= 4)
+    );
+}
@@ -12,6 +12,7 @@ fn edit_selected_text_commit_updates_and_undo() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     state.set_selection(vec![shape_id]);
@@ -59,6 +60,7 @@ fn edit_selected_text_cancel_restores_original() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     state.set_selection(vec![shape_id]);
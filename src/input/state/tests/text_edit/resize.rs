@@ -13,6 +13,7 @@ fn dragging_text_resize_handle_updates_wrap_width_within_screen() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     state.set_selection(vec![shape_id]);
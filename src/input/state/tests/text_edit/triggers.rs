@@ -12,6 +12,7 @@ fn double_click_edit_enters_text_input() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     let bounds = state
@@ -59,6 +60,7 @@ fn right_click_clears_double_click_tracking() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     let bounds = state
@@ -131,6 +133,7 @@ fn enter_key_starts_edit_for_selected_text() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     state.set_selection(vec![shape_id]);
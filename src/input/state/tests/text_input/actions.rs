@@ -13,6 +13,7 @@ fn test_redo_restores_shape_after_undo() {
             y2: 10,
             color: state.current_color,
             thick: state.current_thickness,
+            line_style: LineStyle::Solid,
         });
 
         let index = frame.find_index(shape_id).unwrap();
@@ -194,6 +194,24 @@ fn select_all_then_type_replaces_the_whole_buffer() {
     assert_eq!(buffer(&state), "X");
 }
 
+#[test]
+fn ctrl_b_and_ctrl_i_wrap_the_selection_in_markup_instead_of_running_actions() {
+    let mut state = text_state("note");
+    let board_before = state.board_id().to_string();
+    state.modifiers.ctrl = true;
+    state.on_key_press(Key::Char('a'));
+    state.on_key_press(Key::Char('b'));
+    state.on_key_press(Key::Char('i'));
+    state.modifiers.ctrl = false;
+
+    assert_eq!(buffer(&state), "***note***");
+    assert_eq!(
+        state.board_id(),
+        board_before,
+        "Ctrl+B formats while editing"
+    );
+}
+
 #[test]
 fn shift_arrow_selects_and_backspace_deletes_the_selection() {
     let mut state = text_state("abcd");
@@ -633,6 +651,7 @@ fn edit_ghost_is_hidden_in_place_and_shown_after_moving() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
     state.set_selection(vec![shape_id]);
     assert!(
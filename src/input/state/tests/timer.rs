@@ -0,0 +1,118 @@
+//! Break timer, talk timer, and stopwatch actions.
+
+use std::time::{Duration, Instant};
+
+use super::*;
+use crate::config::{TimerConfig, TimerDisplay};
+use crate::input::state::{TimerKind, TimerSettings};
+use crate::input::{BOARD_ID_BLACKBOARD, BOARD_ID_TRANSPARENT, BOARD_ID_WHITEBOARD};
+
+fn toast_message(state: &InputState) -> Option<&str> {
+    state.ui_toast.as_ref().map(|toast| toast.message.as_str())
+}
+
+fn with_break_board(state: &mut InputState, board: &str) {
+    state.init_timer_from_config(TimerSettings::from(&TimerConfig {
+        break_board: board.to_string(),
+        ..TimerConfig::default()
+    }));
+}
+
+#[test]
+fn timer_actions_start_pause_and_reset_the_timer() {
+    let mut state = create_test_input_state();
+    assert!(state.timer_view(Instant::now()).is_none());
+
+    state.handle_action(Action::StartTalkTimer);
+    let view = state.timer_view(Instant::now()).expect("timer after start");
+    assert_eq!(view.kind, TimerKind::Talk);
+    assert_eq!(view.clock, "20:00");
+    assert!(!view.paused);
+    assert!(state.needs_redraw);
+
+    state.handle_action(Action::ToggleTimerPause);
+    assert!(state.timer_view(Instant::now()).unwrap().paused);
+    assert_eq!(toast_message(&state), Some("Timer paused"));
+    assert_eq!(state.timer_timeout(Instant::now()), None);
+
+    state.handle_action(Action::TimerAddMinute);
+    assert_eq!(state.timer_view(Instant::now()).unwrap().clock, "21:00");
+
+    state.handle_action(Action::ResetTimer);
+    assert!(!state.timer_active());
+}
+
+#[test]
+fn finished_countdown_is_reported_once_to_the_backend() {
+    let mut state = create_test_input_state();
+    state.handle_action(Action::StartBreakTimer);
+
+    let later = Instant::now() + Duration::from_secs(11 * 60);
+    assert!(state.tick_timer(later));
+    assert_eq!(toast_message(&state), Some("Break is over"));
+    assert_eq!(state.take_pending_timer_finished(), Some(TimerKind::Break));
+    assert_eq!(state.take_pending_timer_finished(), None);
+    assert!(state.timer_view(later).unwrap().finished);
+}
+
+#[test]
+fn stopwatch_cannot_be_extended() {
+    let mut state = create_test_input_state();
+    state.handle_action(Action::StartStopwatch);
+    state.handle_action(Action::TimerAddMinute);
+    assert_eq!(
+        toast_message(&state),
+        Some("Only a countdown can be extended")
+    );
+    assert_eq!(state.timer_view(Instant::now()).unwrap().clock, "0:00");
+}
+
+#[test]
+fn toggle_timer_display_switches_to_the_compact_badge() {
+    let mut state = create_test_input_state();
+    state.init_timer_from_config(TimerSettings::from(&TimerConfig {
+        display: TimerDisplay::Compact,
+        ..TimerConfig::default()
+    }));
+    state.handle_action(Action::StartStopwatch);
+    assert!(state.timer_view(Instant::now()).unwrap().compact);
+
+    state.handle_action(Action::ToggleTimerDisplay);
+    assert!(!state.timer_view(Instant::now()).unwrap().compact);
+}
+
+#[test]
+fn break_timer_shows_the_break_board_and_reset_returns() {
+    let mut state = create_test_input_state();
+    with_break_board(&mut state, BOARD_ID_BLACKBOARD);
+    assert_eq!(state.board_id(), BOARD_ID_TRANSPARENT);
+
+    state.handle_action(Action::StartBreakTimer);
+    assert_eq!(state.board_id(), BOARD_ID_BLACKBOARD);
+
+    state.handle_action(Action::ResetTimer);
+    assert_eq!(state.board_id(), BOARD_ID_TRANSPARENT);
+}
+
+#[test]
+fn reset_keeps_a_board_the_user_picked_during_the_break() {
+    let mut state = create_test_input_state();
+    with_break_board(&mut state, BOARD_ID_BLACKBOARD);
+
+    state.handle_action(Action::StartBreakTimer);
+    state.switch_board_force(BOARD_ID_WHITEBOARD);
+    state.handle_action(Action::ResetTimer);
+    assert_eq!(state.board_id(), BOARD_ID_WHITEBOARD);
+}
+
+#[test]
+fn switching_to_the_talk_timer_leaves_the_break_board() {
+    let mut state = create_test_input_state();
+    with_break_board(&mut state, BOARD_ID_BLACKBOARD);
+
+    state.handle_action(Action::StartBreakTimer);
+    state.handle_action(Action::StartBreakTimer);
+    assert_eq!(state.board_id(), BOARD_ID_BLACKBOARD);
+    state.handle_action(Action::StartTalkTimer);
+    assert_eq!(state.board_id(), BOARD_ID_TRANSPARENT);
+}
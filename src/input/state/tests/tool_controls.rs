@@ -1,6 +1,6 @@
 use super::*;
 use crate::config::{PresenterToolBehavior, PresetToolStatesConfig, ToolPresetConfig};
-use crate::draw::BlurStyle;
+use crate::draw::{BlurStyle, LineStyle};
 use crate::input::{DragBinding, DragToolBindings, PerToolDrawingSettings};
 use crate::ui::toolbar::{ToolContext, ToolOptionsKind, ToolbarEvent, ToolbarSnapshot};
 
@@ -72,6 +72,21 @@ fn cycling_from_black_out_to_sampling_blur_requests_frozen_capture() {
     assert!(state.take_pending_frozen_toggle());
 }
 
+#[test]
+fn cycle_line_style_wraps_and_marks_session_dirty() {
+    let mut state = create_test_input_state();
+    state.session_dirty = false;
+
+    for expected in [LineStyle::Dashed, LineStyle::Dotted, LineStyle::DashDot] {
+        assert!(state.cycle_line_style());
+        assert_eq!(state.line_style, expected);
+    }
+    assert!(state.cycle_line_style());
+    assert_eq!(state.line_style, LineStyle::Solid);
+    assert!(state.session_dirty);
+    assert!(!state.set_line_style(LineStyle::Solid));
+}
+
 #[test]
 fn pick_screen_color_requests_backend_eyedropper_activation() {
     let mut state = create_test_input_state();
@@ -1919,6 +1934,7 @@ fn apply_full_preset_restores_all_tool_settings() {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: None,
         drag_tools: None,
     });
@@ -2002,6 +2018,7 @@ fn toolbar_preset_preview_uses_nested_profile_for_active_preset_tool() {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: None,
         drag_tools: None,
     });
@@ -2021,6 +2038,7 @@ fn toolbar_preset_preview_uses_nested_profile_for_active_preset_tool() {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: None,
         drag_tools: None,
     });
@@ -2068,6 +2086,7 @@ fn legacy_preset_changes_only_selected_tool_settings() {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: None,
         drag_tools: None,
     });
@@ -2106,6 +2125,7 @@ fn legacy_step_marker_preset_uses_font_derived_size() {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: None,
         drag_tools: None,
     });
@@ -2143,6 +2163,7 @@ fn full_step_marker_preset_uses_captured_profile_size() {
         arrow_angle: None,
         arrow_head_at_end: None,
         polygon_sides: None,
+        line_style: None,
         show_status_bar: None,
         drag_tools: None,
     });
@@ -1,4 +1,5 @@
 use super::*;
+use crate::ui::toolbar::model::ToolbarSliderSpec;
 
 #[test]
 fn translate_selection_with_undo_moves_shape() {
@@ -10,6 +11,7 @@ fn translate_selection_with_undo_moves_shape() {
         y2: 50,
         color: state.current_color,
         thick: state.current_thickness,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -54,6 +56,8 @@ fn resizing_selection_marks_previous_live_bounds_dirty() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -109,6 +113,8 @@ fn resizing_selection_back_to_start_restores_original_geometry() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -158,6 +164,184 @@ fn resizing_selection_back_to_start_restores_original_geometry() {
     );
 }
 
+#[test]
+fn rotating_selection_with_snap_lands_on_fifteen_degree_steps() {
+    let mut state = create_test_input_state();
+    let shape_id = state.boards.active_frame_mut().add_shape(Shape::Rect {
+        x: 10,
+        y: 10,
+        w: 40,
+        h: 20,
+        fill: false,
+        color: state.current_color,
+        thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
+    });
+
+    state.set_selection(vec![shape_id]);
+    let original_bounds = state
+        .selection_bounds()
+        .expect("selection should have bounds");
+    let cx = original_bounds.x + original_bounds.width / 2;
+    let cy = original_bounds.y + original_bounds.height / 2;
+    let snapshots = state.capture_resize_selection_snapshots();
+
+    // A quarter turn that drifts a few degrees past the axis.
+    state.apply_selection_rotation(
+        &original_bounds,
+        (cx, cy - 40),
+        (cx + 40, cy + 4),
+        true,
+        &snapshots,
+    );
+
+    {
+        let frame = state.boards.active_frame();
+        let shape = frame.shape(shape_id).expect("shape should exist");
+        match &shape.shape {
+            Shape::Rect { w, h, rotation, .. } => {
+                assert_eq!((*w, *h), (40, 20));
+                assert_eq!(*rotation, 90.0);
+            }
+            _ => panic!("Expected rect shape"),
+        }
+    }
+
+    state.apply_selection_rotation(
+        &original_bounds,
+        (cx, cy - 40),
+        (cx + 40, cy + 4),
+        false,
+        &snapshots,
+    );
+    let frame = state.boards.active_frame();
+    let rotation = frame
+        .shape(shape_id)
+        .expect("shape should exist")
+        .shape
+        .rotation();
+    assert!(rotation > 90.0 && rotation < 100.0, "rotation={rotation}");
+}
+
+#[test]
+fn rotating_selection_turns_point_based_shapes() {
+    let mut state = create_test_input_state();
+    let shape_id = state.boards.active_frame_mut().add_shape(Shape::Line {
+        x1: 0,
+        y1: 50,
+        x2: 100,
+        y2: 50,
+        color: state.current_color,
+        thick: state.current_thickness,
+        line_style: LineStyle::Solid,
+    });
+
+    state.set_selection(vec![shape_id]);
+    let original_bounds = state
+        .selection_bounds()
+        .expect("selection should have bounds");
+    let cx = original_bounds.x + original_bounds.width / 2;
+    let cy = original_bounds.y + original_bounds.height / 2;
+    let snapshots = state.capture_resize_selection_snapshots();
+
+    state.apply_selection_rotation(
+        &original_bounds,
+        (cx + 40, cy),
+        (cx, cy + 40),
+        false,
+        &snapshots,
+    );
+
+    let frame = state.boards.active_frame();
+    match &frame.shape(shape_id).expect("shape should exist").shape {
+        Shape::Line { x1, y1, x2, y2, .. } => {
+            assert!((*x1 - 50).abs() <= 1 && (*x2 - 50).abs() <= 1);
+            assert!((*y1).abs() <= 1 && (*y2 - 100).abs() <= 1);
+        }
+        _ => panic!("Expected line shape"),
+    }
+}
+
+#[test]
+fn scaling_selection_grows_text_font_size() {
+    let mut state = create_test_input_state();
+    let shape_id = state.boards.active_frame_mut().add_shape(Shape::Text {
+        x: 20,
+        y: 40,
+        text: "Scale me".to_string(),
+        color: state.current_color,
+        size: 20.0,
+        font_descriptor: state.font_descriptor.clone(),
+        background_enabled: false,
+        wrap_width: Some(100),
+        rotation: 0.0,
+    });
+
+    state.set_selection(vec![shape_id]);
+    let original_bounds = state
+        .selection_bounds()
+        .expect("selection should have bounds");
+    let snapshots = state.capture_resize_selection_snapshots();
+
+    state.apply_selection_resize(
+        SelectionHandle::BottomRight,
+        &original_bounds,
+        original_bounds.width,
+        original_bounds.height,
+        &snapshots,
+    );
+
+    let frame = state.boards.active_frame();
+    match &frame.shape(shape_id).expect("shape should exist").shape {
+        Shape::Text {
+            size, wrap_width, ..
+        } => {
+            assert!((*size - 40.0).abs() < 1.0, "size={size}");
+            assert_eq!(*wrap_width, Some(200));
+        }
+        _ => panic!("Expected text shape"),
+    }
+}
+
+#[test]
+fn scaling_selection_stops_text_at_the_largest_font_size() {
+    let mut state = create_test_input_state();
+    let shape_id = state.boards.active_frame_mut().add_shape(Shape::Text {
+        x: 20,
+        y: 40,
+        text: "Scale me".to_string(),
+        color: state.current_color,
+        size: 60.0,
+        font_descriptor: state.font_descriptor.clone(),
+        background_enabled: false,
+        wrap_width: Some(100),
+        rotation: 0.0,
+    });
+
+    state.set_selection(vec![shape_id]);
+    let original_bounds = state
+        .selection_bounds()
+        .expect("selection should have bounds");
+    let snapshots = state.capture_resize_selection_snapshots();
+
+    state.apply_selection_resize(
+        SelectionHandle::BottomRight,
+        &original_bounds,
+        original_bounds.width,
+        original_bounds.height,
+        &snapshots,
+    );
+
+    let frame = state.boards.active_frame();
+    match &frame.shape(shape_id).expect("shape should exist").shape {
+        Shape::Text { size, .. } => {
+            assert_eq!(*size, ToolbarSliderSpec::FONT_SIZE.max);
+        }
+        _ => panic!("Expected text shape"),
+    }
+}
+
 #[test]
 fn move_selection_to_horizontal_edges_uses_screen_bounds() {
     let mut state = create_test_input_state();
@@ -170,6 +354,8 @@ fn move_selection_to_horizontal_edges_uses_screen_bounds() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -204,6 +390,8 @@ fn move_selection_to_horizontal_edges_ignores_last_axis() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -239,6 +427,8 @@ fn move_selection_to_vertical_edges_explicit_actions() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -272,6 +462,8 @@ fn nudge_selection_large_uses_large_step() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -297,6 +489,8 @@ fn nudge_selection_clamps_left_and_top_edges() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -321,6 +515,8 @@ fn nudge_selection_clamps_right_and_bottom_edges() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     state.set_selection(vec![shape_id]);
@@ -348,6 +544,7 @@ fn restore_selection_snapshots_reverts_translation() {
         font_descriptor: state.font_descriptor.clone(),
         background_enabled: state.text_background_enabled,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     state.set_selection(vec![shape_id]);
@@ -0,0 +1,466 @@
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::config::{TimerConfig, TimerDisplay};
+
+/// How long a finished countdown flashes.
+const FLASH_DURATION: Duration = Duration::from_secs(4);
+/// Half a flash cycle: the finished timer alternates lit and plain this often.
+const FLASH_PHASE: Duration = Duration::from_millis(250);
+const MINUTE: Duration = Duration::from_secs(60);
+
+/// Runtime settings for the break timer, talk timer, and stopwatch.
+#[derive(Clone, Debug)]
+pub struct TimerSettings {
+    pub break_duration: Duration,
+    pub talk_duration: Duration,
+    /// Show the compact pill instead of the large overlay.
+    pub compact: bool,
+    pub flash: bool,
+    pub notify: bool,
+    /// Sound played when a countdown reaches zero, tilde already expanded.
+    pub sound_file: Option<PathBuf>,
+    /// Board shown while the break timer runs.
+    pub break_board: Option<String>,
+}
+
+impl Default for TimerSettings {
+    fn default() -> Self {
+        Self::from(&TimerConfig::default())
+    }
+}
+
+impl From<&TimerConfig> for TimerSettings {
+    fn from(cfg: &TimerConfig) -> Self {
+        Self {
+            break_duration: MINUTE * cfg.break_minutes,
+            talk_duration: MINUTE * cfg.talk_minutes,
+            compact: cfg.display == TimerDisplay::Compact,
+            flash: cfg.flash,
+            notify: cfg.notify,
+            sound_file: cfg.sound_file.as_deref().map(crate::paths::expand_tilde),
+            break_board: (!cfg.break_board.is_empty()).then(|| cfg.break_board.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimerKind {
+    Break,
+    Talk,
+    Stopwatch,
+}
+
+impl TimerKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Break => "Break",
+            Self::Talk => "Talk",
+            Self::Stopwatch => "Stopwatch",
+        }
+    }
+}
+
+/// One timer from start to reset.
+#[derive(Clone, Debug)]
+pub struct TimerRun {
+    kind: TimerKind,
+    /// Countdown length; `None` for the stopwatch.
+    duration: Option<Duration>,
+    /// Time counted before the current running stretch.
+    banked: Duration,
+    /// When the current running stretch began; `None` while paused or
+    /// finished.
+    running_since: Option<Instant>,
+    /// When the countdown reached zero.
+    finished_at: Option<Instant>,
+}
+
+impl TimerRun {
+    fn new(kind: TimerKind, duration: Option<Duration>, now: Instant) -> Self {
+        Self {
+            kind,
+            duration,
+            banked: Duration::ZERO,
+            running_since: Some(now),
+            finished_at: None,
+        }
+    }
+
+    pub fn paused(&self) -> bool {
+        self.running_since.is_none() && self.finished_at.is_none()
+    }
+
+    pub fn finished(&self) -> bool {
+        self.finished_at.is_some()
+    }
+
+    /// Time counted so far, never past the end of a countdown.
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        let running = self
+            .running_since
+            .map_or(Duration::ZERO, |since| now.saturating_duration_since(since));
+        let elapsed = self.banked + running;
+        self.duration
+            .map_or(elapsed, |duration| elapsed.min(duration))
+    }
+
+    /// Time left on a countdown.
+    pub fn remaining(&self, now: Instant) -> Option<Duration> {
+        self.duration
+            .map(|duration| duration.saturating_sub(self.elapsed(now)))
+    }
+
+    /// Fraction of a countdown already used, 0.0 - 1.0.
+    pub fn progress(&self, now: Instant) -> Option<f64> {
+        self.duration.map(|duration| {
+            if duration.is_zero() {
+                1.0
+            } else {
+                self.elapsed(now).as_secs_f64() / duration.as_secs_f64()
+            }
+        })
+    }
+
+    /// Whole seconds on the clock: the time left rounded up for a countdown,
+    /// so it reads 0:00 only once it is over, and the time elapsed for the
+    /// stopwatch.
+    pub fn display_secs(&self, now: Instant) -> u64 {
+        match self.remaining(now) {
+            Some(remaining) => remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0),
+            None => self.elapsed(now).as_secs(),
+        }
+    }
+
+    /// Pauses or resumes. Returns false for a finished countdown, which has
+    /// nothing left to pause.
+    fn set_paused(&mut self, paused: bool, now: Instant) -> bool {
+        if self.finished() || paused == self.paused() {
+            return false;
+        }
+        if paused {
+            self.banked = self.elapsed(now);
+            self.running_since = None;
+        } else {
+            self.running_since = Some(now);
+        }
+        true
+    }
+
+    /// Adds a minute to a countdown, restarting it if it had already run out.
+    fn add_minute(&mut self, now: Instant) -> bool {
+        let Some(duration) = self.duration.as_mut() else {
+            return false;
+        };
+        *duration += MINUTE;
+        if self.finished_at.take().is_some() {
+            self.running_since = Some(now);
+        }
+        true
+    }
+
+    /// Marks a countdown finished once its time is up. Returns true on the
+    /// call that finishes it.
+    fn finish_if_due(&mut self, now: Instant) -> bool {
+        if self.finished() || self.running_since.is_none() {
+            return false;
+        }
+        let Some(duration) = self.duration else {
+            return false;
+        };
+        if self.elapsed(now) < duration {
+            return false;
+        }
+        self.banked = duration;
+        self.running_since = None;
+        self.finished_at = Some(now);
+        true
+    }
+
+    /// Time until the clock next shows a different second.
+    fn next_second(&self, now: Instant) -> Option<Duration> {
+        self.running_since?;
+        // A countdown shows the time left rounded up, so it ticks when the
+        // fraction runs out; the stopwatch ticks when the fraction fills up.
+        let nanos = match self.remaining(now) {
+            Some(remaining) => remaining.subsec_nanos(),
+            None => 1_000_000_000 - self.elapsed(now).subsec_nanos(),
+        };
+        Some(if nanos == 0 {
+            Duration::from_secs(1)
+        } else {
+            Duration::from_nanos(u64::from(nanos))
+        })
+    }
+}
+
+/// What happened on a [`TimerState::tick`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TimerTick {
+    /// The timer looks different from the last frame.
+    pub changed: bool,
+    /// A countdown reached zero on this tick.
+    pub finished: Option<TimerKind>,
+}
+
+/// Everything the renderer needs to draw the timer for one frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimerView {
+    pub kind: TimerKind,
+    /// Clock text, e.g. "9:59" or "1:02:03".
+    pub clock: String,
+    pub paused: bool,
+    pub finished: bool,
+    /// The finished timer is in the lit half of a flash.
+    pub flash_lit: bool,
+    pub compact: bool,
+    /// Fraction of a countdown already used.
+    pub progress: Option<f64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TimerState {
+    settings: TimerSettings,
+    run: Option<TimerRun>,
+    compact: bool,
+    /// Seconds and flash phase the last frame showed, so the clock is only
+    /// redrawn when it changes.
+    shown: Option<(u64, bool)>,
+    /// Board that was active before the break timer switched away from it.
+    restore_board: Option<String>,
+    /// A countdown finished and the backend has yet to notify and play the
+    /// sound.
+    pending_finished: Option<TimerKind>,
+}
+
+impl TimerState {
+    pub fn new(settings: TimerSettings) -> Self {
+        let compact = settings.compact;
+        Self {
+            settings,
+            run: None,
+            compact,
+            shown: None,
+            restore_board: None,
+            pending_finished: None,
+        }
+    }
+
+    pub fn settings(&self) -> &TimerSettings {
+        &self.settings
+    }
+
+    pub fn run(&self) -> Option<&TimerRun> {
+        self.run.as_ref()
+    }
+
+    pub fn toggle_compact(&mut self) -> bool {
+        self.compact = !self.compact;
+        self.compact
+    }
+
+    /// Starts a fresh timer of `kind`, replacing any running one.
+    pub fn start(&mut self, kind: TimerKind, now: Instant) {
+        let duration = match kind {
+            TimerKind::Break => Some(self.settings.break_duration),
+            TimerKind::Talk => Some(self.settings.talk_duration),
+            TimerKind::Stopwatch => None,
+        };
+        self.run = Some(TimerRun::new(kind, duration, now));
+        self.shown = None;
+        self.pending_finished = None;
+    }
+
+    /// Stops and hides the timer. Returns false when none was running.
+    pub fn reset(&mut self) -> bool {
+        self.shown = None;
+        self.pending_finished = None;
+        self.run.take().is_some()
+    }
+
+    /// Pauses a running timer or resumes a paused one. Returns the new paused
+    /// state, or `None` when there is nothing to pause.
+    pub fn toggle_pause(&mut self, now: Instant) -> Option<bool> {
+        let run = self.run.as_mut()?;
+        let paused = !run.paused();
+        run.set_paused(paused, now).then_some(paused)
+    }
+
+    pub fn add_minute(&mut self, now: Instant) -> bool {
+        self.run.as_mut().is_some_and(|run| run.add_minute(now))
+    }
+
+    pub fn set_restore_board(&mut self, board: Option<String>) {
+        self.restore_board = board;
+    }
+
+    pub fn restore_board(&self) -> Option<&str> {
+        self.restore_board.as_deref()
+    }
+
+    pub fn take_restore_board(&mut self) -> Option<String> {
+        self.restore_board.take()
+    }
+
+    pub fn take_pending_finished(&mut self) -> Option<TimerKind> {
+        self.pending_finished.take()
+    }
+
+    /// Whether the finished timer is in the lit half of its flash.
+    pub fn flash_lit(&self, now: Instant) -> bool {
+        if !self.settings.flash {
+            return false;
+        }
+        let Some(finished_at) = self.run.as_ref().and_then(|run| run.finished_at) else {
+            return false;
+        };
+        let since = now.saturating_duration_since(finished_at);
+        since < FLASH_DURATION && (since.as_millis() / FLASH_PHASE.as_millis()).is_multiple_of(2)
+    }
+
+    /// Advances the timer to `now`, finishing a countdown whose time is up.
+    pub fn tick(&mut self, now: Instant) -> TimerTick {
+        let Some(run) = self.run.as_mut() else {
+            return TimerTick {
+                changed: self.shown.take().is_some(),
+                finished: None,
+            };
+        };
+        let finished = run.finish_if_due(now).then_some(run.kind);
+        let shown = (run.display_secs(now), self.flash_lit(now));
+        let changed = self.shown != Some(shown);
+        self.shown = Some(shown);
+        if finished.is_some() {
+            self.pending_finished = finished;
+        }
+        TimerTick { changed, finished }
+    }
+
+    /// Time until the timer next needs a tick: the next second on a running
+    /// clock, or the next flash phase of a finished countdown.
+    pub fn timeout(&self, now: Instant) -> Option<Duration> {
+        let run = self.run.as_ref()?;
+        if let Some(next) = run.next_second(now) {
+            return Some(next);
+        }
+        let finished_at = run.finished_at.filter(|_| self.settings.flash)?;
+        let since = now.saturating_duration_since(finished_at);
+        if since > FLASH_DURATION {
+            return None;
+        }
+        let phase = FLASH_PHASE.as_nanos();
+        let into_phase = since.as_nanos() % phase;
+        Some(Duration::from_nanos((phase - into_phase) as u64))
+    }
+
+    pub fn view(&self, now: Instant) -> Option<TimerView> {
+        let run = self.run.as_ref()?;
+        Some(TimerView {
+            kind: run.kind,
+            clock: format_clock(run.display_secs(now)),
+            paused: run.paused(),
+            finished: run.finished(),
+            flash_lit: self.flash_lit(now),
+            compact: self.compact,
+            progress: run.progress(now),
+        })
+    }
+}
+
+/// "M:SS" below an hour, "H:MM:SS" from there on.
+pub fn format_clock(secs: u64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}")
+    } else {
+        format!("{minutes}:{seconds:02}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> TimerSettings {
+        TimerSettings {
+            break_duration: Duration::from_secs(90),
+            ..TimerSettings::default()
+        }
+    }
+
+    #[test]
+    fn countdown_rounds_up_and_finishes_once() {
+        let start = Instant::now();
+        let mut timer = TimerState::new(settings());
+        timer.start(TimerKind::Break, start);
+
+        let view = timer.view(start + Duration::from_millis(500)).unwrap();
+        assert_eq!(view.clock, "1:30");
+        assert!(timer.tick(start + Duration::from_millis(500)).changed);
+        assert!(!timer.tick(start + Duration::from_millis(900)).changed);
+        assert_eq!(
+            timer.timeout(start + Duration::from_millis(900)),
+            Some(Duration::from_millis(100))
+        );
+
+        let end = start + Duration::from_secs(90);
+        let tick = timer.tick(end);
+        assert_eq!(tick.finished, Some(TimerKind::Break));
+        assert_eq!(timer.tick(end).finished, None, "a countdown finishes once");
+        assert_eq!(timer.view(end).unwrap().clock, "0:00");
+        assert_eq!(timer.take_pending_finished(), Some(TimerKind::Break));
+    }
+
+    #[test]
+    fn pause_freezes_the_clock_and_add_minute_revives_a_finished_countdown() {
+        let start = Instant::now();
+        let mut timer = TimerState::new(settings());
+        timer.start(TimerKind::Break, start);
+        assert_eq!(
+            timer.toggle_pause(start + Duration::from_secs(30)),
+            Some(true)
+        );
+        let paused = timer.view(start + Duration::from_secs(300)).unwrap();
+        assert_eq!(paused.clock, "1:00");
+        assert_eq!(timer.timeout(start + Duration::from_secs(300)), None);
+        assert_eq!(
+            timer.toggle_pause(start + Duration::from_secs(300)),
+            Some(false)
+        );
+
+        let end = start + Duration::from_secs(360);
+        timer.tick(end);
+        assert!(timer.run().unwrap().finished());
+        assert_eq!(timer.toggle_pause(end), None);
+        assert!(timer.add_minute(end));
+        assert!(!timer.run().unwrap().finished());
+        assert_eq!(timer.view(end).unwrap().clock, "1:00");
+    }
+
+    #[test]
+    fn finished_countdown_flashes_then_settles() {
+        let start = Instant::now();
+        let mut timer = TimerState::new(settings());
+        timer.start(TimerKind::Break, start);
+        let end = start + Duration::from_secs(90);
+        timer.tick(end);
+
+        assert!(timer.flash_lit(end));
+        assert!(!timer.flash_lit(end + FLASH_PHASE));
+        assert_eq!(timer.timeout(end), Some(FLASH_PHASE));
+        assert!(!timer.flash_lit(end + FLASH_DURATION));
+        assert_eq!(timer.timeout(end + FLASH_DURATION * 2), None);
+    }
+
+    #[test]
+    fn stopwatch_counts_up_and_cannot_be_extended() {
+        let start = Instant::now();
+        let mut timer = TimerState::new(settings());
+        timer.start(TimerKind::Stopwatch, start);
+        let later = start + Duration::from_millis(3_723_250);
+        assert_eq!(timer.view(later).unwrap().clock, "1:02:03");
+        assert_eq!(timer.timeout(later), Some(Duration::from_millis(750)));
+        assert!(!timer.add_minute(later));
+        assert_eq!(timer.tick(later).finished, None);
+    }
+}
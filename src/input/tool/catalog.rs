@@ -20,6 +20,7 @@ pub(crate) struct ToolDescriptor {
 pub(crate) enum ToolPressBehavior {
     Selection,
     HighlightNoop,
+    LaserNoop,
     StartFreeformPolygon,
     StartDrawing { request_blur_capture: bool },
 }
@@ -82,7 +83,7 @@ const fn profile(
     }
 }
 
-const DESCRIPTORS: [ToolDescriptor; 17] = [
+const DESCRIPTORS: [ToolDescriptor; 18] = [
     ToolDescriptor {
         tool: Tool::Select,
         short_label: "Select",
@@ -360,6 +361,22 @@ const DESCRIPTORS: [ToolDescriptor; 17] = [
         motion: ToolMotionBehavior::NoPathAccumulation,
         drawing: ToolDrawingBehavior::None,
     },
+    ToolDescriptor {
+        tool: Tool::Laser,
+        short_label: "Laser",
+        display_label: "Laser Pointer",
+        action: Some(Action::SelectLaserTool),
+        profile: profile(
+            ToolSettingsSlot::Pen,
+            ToolSizeSource::DrawingThickness,
+            ToolControlGroup::None,
+            false,
+            "",
+        ),
+        press: ToolPressBehavior::LaserNoop,
+        motion: ToolMotionBehavior::NoPathAccumulation,
+        drawing: ToolDrawingBehavior::None,
+    },
     ToolDescriptor {
         tool: Tool::StepMarker,
         short_label: "Steps",
@@ -401,7 +418,7 @@ const DESCRIPTORS: [ToolDescriptor; 17] = [
 ];
 
 impl Tool {
-    pub(crate) const ALL: [Self; 17] = [
+    pub(crate) const ALL: [Self; 18] = [
         Self::Select,
         Self::Pen,
         Self::Line,
@@ -417,6 +434,7 @@ impl Tool {
         Self::Spotlight,
         Self::Marker,
         Self::Highlight,
+        Self::Laser,
         Self::StepMarker,
         Self::Eraser,
     ];
@@ -438,8 +456,9 @@ impl Tool {
             Self::Spotlight => &DESCRIPTORS[12],
             Self::Marker => &DESCRIPTORS[13],
             Self::Highlight => &DESCRIPTORS[14],
-            Self::StepMarker => &DESCRIPTORS[15],
-            Self::Eraser => &DESCRIPTORS[16],
+            Self::Laser => &DESCRIPTORS[15],
+            Self::StepMarker => &DESCRIPTORS[16],
+            Self::Eraser => &DESCRIPTORS[17],
         }
     }
 
@@ -498,6 +517,22 @@ impl Tool {
         self.profile().show_marker_opacity()
     }
 
+    /// Whether the tool's drag points snap to the board grid: the tools that
+    /// place a shape from a start and end point, but not freehand paths.
+    pub(crate) fn snaps_to_grid(self) -> bool {
+        matches!(
+            self.drawing_behavior(),
+            ToolDrawingBehavior::Line
+                | ToolDrawingBehavior::Rect
+                | ToolDrawingBehavior::Ellipse
+                | ToolDrawingBehavior::Polygon(_)
+                | ToolDrawingBehavior::Arrow
+                | ToolDrawingBehavior::BlurRect
+                | ToolDrawingBehavior::Spotlight
+                | ToolDrawingBehavior::StepMarker
+        )
+    }
+
     #[cfg_attr(not(feature = "tablet-input"), allow(dead_code))]
     pub(crate) fn supports_pressure_thickness(self) -> bool {
         matches!(
@@ -1,5 +1,10 @@
-use crate::draw::shape::{bounding_box_for_blur, bounding_box_for_eraser, bounding_box_for_points};
-use crate::draw::{ArrowLabel, BlurRectParams, BlurStyle, Color, EraserBrush, EraserKind, Shape};
+use crate::draw::shape::{
+    bounding_box_for_blur, bounding_box_for_eraser, bounding_box_for_points,
+    smooth_pressure_stroke, smooth_stroke,
+};
+use crate::draw::{
+    ArrowLabel, BlurRectParams, BlurStyle, Color, EraserBrush, EraserKind, LineStyle, Shape,
+};
 use crate::input::tool::{
     EraserMode, Tool, ToolDrawingBehavior, ToolPathKind, ToolPressureBehavior,
 };
@@ -20,6 +25,7 @@ pub(crate) struct ToolStrokeSnapshot {
     pub(crate) size: f64,
     pub(crate) marker_opacity: f64,
     pub(crate) fill_enabled: bool,
+    pub(crate) line_style: LineStyle,
     pub(crate) blur_style: BlurStyle,
     pub(crate) arrow_length: f64,
     pub(crate) arrow_angle: f64,
@@ -30,6 +36,8 @@ pub(crate) struct ToolStrokeSnapshot {
     pub(crate) eraser_size: f64,
     pub(crate) eraser_kind: EraserKind,
     pub(crate) pressure_variation_threshold: f64,
+    /// Curve-fit freehand and marker paths before they become shapes.
+    pub(crate) smooth_strokes: bool,
 }
 
 /// Immutable inputs needed to turn one completed polygon drag into a shape.
@@ -40,6 +48,7 @@ pub(crate) struct PolygonStrokeSnapshot {
     pub(crate) color: Color,
     pub(crate) size: f64,
     pub(crate) fill_enabled: bool,
+    pub(crate) line_style: LineStyle,
     pub(crate) regular_sides: u8,
 }
 
@@ -67,6 +76,7 @@ pub(crate) struct ProvisionalToolSnapshot<'a> {
     pub(crate) eraser_size: f64,
     pub(crate) marker_opacity: f64,
     pub(crate) fill_enabled: bool,
+    pub(crate) line_style: LineStyle,
     pub(crate) blur_style: BlurStyle,
     pub(crate) arrow_length: f64,
     pub(crate) arrow_angle: f64,
@@ -83,6 +93,7 @@ pub(crate) struct PolygonProvisionalSnapshot {
     pub(crate) color: Color,
     pub(crate) size: f64,
     pub(crate) fill_enabled: bool,
+    pub(crate) line_style: LineStyle,
     pub(crate) regular_sides: u8,
 }
 
@@ -91,6 +102,7 @@ pub(crate) enum ProvisionalToolStroke<'a> {
         points: &'a [(i32, i32)],
         color: Color,
         size: f64,
+        line_style: LineStyle,
     },
     BorrowedPressureFreehand {
         points: &'a [(i32, i32)],
@@ -127,6 +139,7 @@ impl Tool {
                 y2: snapshot.end.1,
                 color: snapshot.color,
                 thick: snapshot.size,
+                line_style: snapshot.line_style,
             }),
             ToolDrawingBehavior::Rect => finish_shape(snapshot, usage, |snapshot| {
                 let (x, w) = normalized_axis(snapshot.start.0, snapshot.end.0);
@@ -139,6 +152,8 @@ impl Tool {
                     fill: snapshot.fill_enabled,
                     color: snapshot.color,
                     thick: snapshot.size,
+                    rotation: 0.0,
+                    line_style: snapshot.line_style,
                 }
             }),
             ToolDrawingBehavior::Ellipse => finish_shape(snapshot, usage, |snapshot| {
@@ -156,6 +171,8 @@ impl Tool {
                     fill: snapshot.fill_enabled,
                     color: snapshot.color,
                     thick: snapshot.size,
+                    rotation: 0.0,
+                    line_style: snapshot.line_style,
                 }
             }),
             ToolDrawingBehavior::Polygon(_) => {
@@ -178,6 +195,7 @@ impl Tool {
                     arrow_angle: snapshot.arrow_angle,
                     head_at_end: snapshot.arrow_head_at_end,
                     label: snapshot.arrow_label,
+                    line_style: snapshot.line_style,
                 })
             }
             ToolDrawingBehavior::BlurRect => finish_shape(snapshot, usage, |snapshot| {
@@ -190,6 +208,7 @@ impl Tool {
                     h,
                     strength: snapshot.size,
                     style: snapshot.blur_style,
+                    rotation: 0.0,
                 }
             }),
             ToolDrawingBehavior::Spotlight => finish_shape(snapshot, usage, |snapshot| {
@@ -228,7 +247,10 @@ impl Tool {
                 kind: ToolPathKind::Freehand,
                 pressure: ToolPressureBehavior::OptionalPressureStroke,
             } => {
-                if !snapshot.point_thicknesses.is_empty()
+                // Pressure strokes have no dash pattern, so a styled pen
+                // previews (and finishes) as a plain freehand stroke.
+                if snapshot.line_style.is_solid()
+                    && !snapshot.point_thicknesses.is_empty()
                     && snapshot.point_thicknesses.len() == snapshot.points.len()
                 {
                     ProvisionalToolStroke::BorrowedPressureFreehand {
@@ -241,6 +263,7 @@ impl Tool {
                         points: snapshot.points,
                         color: snapshot.color,
                         size: snapshot.size,
+                        line_style: snapshot.line_style,
                     }
                 }
             }
@@ -251,6 +274,7 @@ impl Tool {
                 points: snapshot.points,
                 color: snapshot.color,
                 size: snapshot.size,
+                line_style: snapshot.line_style,
             },
             ToolDrawingBehavior::Path {
                 kind: ToolPathKind::Marker,
@@ -267,6 +291,7 @@ impl Tool {
                 y2: snapshot.current.1,
                 color: snapshot.color,
                 thick: snapshot.size,
+                line_style: snapshot.line_style,
             }),
             ToolDrawingBehavior::Rect => {
                 let (x, w) = normalized_axis(snapshot.start.0, snapshot.current.0);
@@ -279,6 +304,8 @@ impl Tool {
                     fill: snapshot.fill_enabled,
                     color: snapshot.color,
                     thick: snapshot.size,
+                    rotation: 0.0,
+                    line_style: snapshot.line_style,
                 })
             }
             ToolDrawingBehavior::Ellipse => {
@@ -296,6 +323,8 @@ impl Tool {
                     fill: snapshot.fill_enabled,
                     color: snapshot.color,
                     thick: snapshot.size,
+                    rotation: 0.0,
+                    line_style: snapshot.line_style,
                 })
             }
             ToolDrawingBehavior::Polygon(_) => {
@@ -313,6 +342,7 @@ impl Tool {
                 arrow_angle: snapshot.arrow_angle,
                 head_at_end: snapshot.arrow_head_at_end,
                 label: snapshot.arrow_label,
+                line_style: snapshot.line_style,
             }),
             ToolDrawingBehavior::BlurRect => {
                 let (x, w) = normalized_axis(snapshot.start.0, snapshot.current.0);
@@ -324,6 +354,7 @@ impl Tool {
                     h,
                     strength: snapshot.size,
                     style: snapshot.blur_style,
+                    rotation: 0.0,
                     cacheable: false,
                 })
             }
@@ -384,6 +415,47 @@ impl<'a> ProvisionalToolStroke<'a> {
             Self::None => None,
         }
     }
+
+    /// The stroke as the shape it previews, for snapshots rendered away from
+    /// the live canvas. Eraser and blur previews have no standalone shape.
+    pub(crate) fn to_shape(&self) -> Option<Shape> {
+        match self {
+            Self::BorrowedFreehand {
+                points,
+                color,
+                size,
+                line_style,
+            } => Some(Shape::Freehand {
+                points: points.to_vec(),
+                color: *color,
+                thick: *size,
+                line_style: *line_style,
+            }),
+            Self::BorrowedPressureFreehand {
+                points,
+                point_thicknesses,
+                color,
+            } => Some(Shape::FreehandPressure {
+                points: points
+                    .iter()
+                    .zip(point_thicknesses.iter())
+                    .map(|(&(x, y), &thickness)| (x, y, thickness))
+                    .collect(),
+                color: *color,
+            }),
+            Self::BorrowedMarker {
+                points,
+                color,
+                size,
+            } => Some(Shape::MarkerStroke {
+                points: points.to_vec(),
+                color: *color,
+                thick: *size,
+            }),
+            Self::Shape(shape) => Some(shape.clone()),
+            Self::EraserPreview { .. } | Self::BlurReplayPreview(_) | Self::None => None,
+        }
+    }
 }
 
 pub(crate) fn marker_color_with_opacity(color: Color, marker_opacity: f64) -> Color {
@@ -400,18 +472,22 @@ fn finish_path_stroke(
     match kind {
         ToolPathKind::Freehand => {
             if matches!(pressure, ToolPressureBehavior::OptionalPressureStroke)
+                && snapshot.line_style.is_solid()
                 && pressure_data_varies(
                     &snapshot.point_thicknesses,
                     snapshot.points.len(),
                     snapshot.pressure_variation_threshold,
                 )
             {
-                let points = snapshot
+                let mut points: Vec<_> = snapshot
                     .points
                     .into_iter()
                     .zip(snapshot.point_thicknesses)
                     .map(|((x, y), t)| (x, y, t))
                     .collect();
+                if snapshot.smooth_strokes {
+                    points = smooth_pressure_stroke(&points);
+                }
                 return FinishedToolStroke::Shape {
                     shape: Shape::FreehandPressure {
                         points,
@@ -423,16 +499,17 @@ fn finish_path_stroke(
 
             FinishedToolStroke::Shape {
                 shape: Shape::Freehand {
-                    points: snapshot.points,
+                    points: smoothed_path(snapshot.points, snapshot.smooth_strokes),
                     color: snapshot.color,
                     thick: snapshot.size,
+                    line_style: snapshot.line_style,
                 },
                 usage,
             }
         }
         ToolPathKind::Marker => FinishedToolStroke::Shape {
             shape: Shape::MarkerStroke {
-                points: snapshot.points,
+                points: smoothed_path(snapshot.points, snapshot.smooth_strokes),
                 color: marker_color_with_opacity(snapshot.color, snapshot.marker_opacity),
                 thick: snapshot.size,
             },
@@ -441,6 +518,14 @@ fn finish_path_stroke(
     }
 }
 
+fn smoothed_path(points: Vec<(i32, i32)>, smooth: bool) -> Vec<(i32, i32)> {
+    if smooth {
+        smooth_stroke(&points)
+    } else {
+        points
+    }
+}
+
 fn finish_eraser(snapshot: ToolStrokeSnapshot) -> FinishedToolStroke {
     if snapshot.eraser_mode == EraserMode::Stroke {
         let mut path = snapshot.points;
@@ -62,6 +62,7 @@ fn finish_polygon(
             fill: snapshot.fill_enabled,
             color: snapshot.color,
             thick: snapshot.size,
+            line_style: snapshot.line_style,
         },
         usage,
     }
@@ -83,5 +84,6 @@ fn provisional_polygon(
         fill: snapshot.fill_enabled,
         color: snapshot.color,
         thick: snapshot.size,
+        line_style: snapshot.line_style,
     })
 }
@@ -1,7 +1,7 @@
 use super::drawing::marker_color_with_opacity;
 use super::*;
 use crate::config::Action;
-use crate::draw::{Color, Shape};
+use crate::draw::{Color, LineStyle, Shape};
 use std::collections::HashSet;
 
 fn color(r: f64) -> Color {
@@ -100,9 +100,10 @@ fn drag_tools_round_trip_through_descriptor_table() {
             assert_eq!(drag_tool.as_tool(), Some(tool));
         } else {
             // Tools with no modifier-drag binding: freeform polygon needs
-            // click-to-add vertices, and the spotlight is selected explicitly.
+            // click-to-add vertices, and the spotlight and laser pointer are
+            // selected explicitly.
             assert!(
-                matches!(tool, Tool::FreeformPolygon | Tool::Spotlight),
+                matches!(tool, Tool::FreeformPolygon | Tool::Spotlight | Tool::Laser),
                 "{tool:?} unexpectedly has no drag binding"
             );
         }
@@ -131,6 +132,7 @@ fn descriptor_exposes_press_motion_and_drawing_behavior() {
         Tool::Highlight.press_behavior(),
         ToolPressBehavior::HighlightNoop
     );
+    assert_eq!(Tool::Laser.press_behavior(), ToolPressBehavior::LaserNoop);
     assert_eq!(
         Tool::Blur.press_behavior(),
         ToolPressBehavior::StartDrawing {
@@ -188,6 +190,7 @@ fn provisional_polygon_bounds_include_extra_preview_padding() {
         color: color(1.0),
         size: 4.0,
         fill_enabled: false,
+        line_style: LineStyle::Solid,
         regular_sides: 5,
     });
 
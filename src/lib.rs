@@ -15,6 +15,7 @@ mod cli;
 pub(crate) mod clipboard_text;
 pub mod config;
 pub mod configurator_destination;
+pub(crate) mod control_socket;
 mod daemon;
 mod desktop_open;
 pub mod domain;
@@ -22,6 +23,8 @@ pub mod draw;
 pub mod durable_io;
 pub mod env_vars;
 pub(crate) mod file_uri;
+mod headless_render;
+pub(crate) mod i18n;
 pub(crate) mod image_decode;
 pub mod input;
 mod label_format;
@@ -29,6 +32,7 @@ mod logger;
 mod notification;
 mod ocr;
 mod onboarding;
+mod page_import;
 pub mod palette_recents;
 pub mod paths;
 mod process_broker;
@@ -47,6 +51,7 @@ pub(crate) mod test_env;
 #[cfg(test)]
 pub(crate) mod test_temp;
 pub mod time_utils;
+mod timer_sound;
 mod toolbar_gtk;
 pub mod toolbar_icons;
 mod tray_action;
@@ -85,6 +90,8 @@ pub fn run_from_env() -> ExitCode {
             logger::init(cli.daemon || cli.active);
             exit_code_for_app_result(app::run(cli))
         }
+        Ok(CliOutcome::Ctl(request)) => control_socket::run_ctl(request),
+        Ok(CliOutcome::Render(request)) => headless_render::run_render(request),
         Ok(CliOutcome::Help) => {
             cli::print_help();
             ExitCode::SUCCESS
@@ -12,7 +12,7 @@ mod controller;
 mod tesseract;
 
 pub(crate) use controller::{OcrController, OcrPoll, OcrSubmitError};
-pub(crate) use tesseract::{TesseractRecognizer, WlCopyPublisher};
+pub(crate) use tesseract::{TesseractRecognizer, WlCopyPublisher, program_on_path};
 
 /// Turns encoded image bytes into text. The production implementation shells
 /// out to Tesseract; tests substitute deterministic fakes.
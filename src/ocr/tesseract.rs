@@ -163,7 +163,7 @@ fn classify_stderr(stderr: &str, languages: &OcrLanguages) -> OcrFailure {
 ///
 /// Checked before invoking so "not installed" is its own actionable message
 /// rather than a generic spawn failure.
-fn program_on_path(program: &str) -> bool {
+pub(crate) fn program_on_path(program: &str) -> bool {
     std::env::var_os("PATH").is_some_and(|path| program_in_search_path(program, &path))
 }
 
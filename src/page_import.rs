@@ -0,0 +1,419 @@
+//! Rasterizes document pages into board page backgrounds.
+//!
+//! PDFs and SVGs are rendered by the Cairo-based command-line converters
+//! (`pdftocairo` from poppler-utils, `rsvg-convert` from librsvg) through the
+//! process broker, the same way OCR reaches Tesseract. They are optional
+//! runtime dependencies, recommended by the packages like Tesseract; without
+//! them the import reports which package to install. PNG and JPEG files are
+//! embedded as they are. Every page comes back as an embedded PNG so a session
+//! keeps rendering after the source document moves or changes.
+
+use std::borrow::Cow;
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::draw::{EmbeddedImage, PageBackground, PageBackgroundSource};
+use crate::image_decode::{EncodedImageFormat, format_from_mime_or_bytes, image_dimensions};
+use crate::process_broker::HelperKind;
+
+/// Pages past this are dropped rather than turned into hundreds of board pages.
+pub(crate) const MAX_IMPORTED_PAGES: usize = 200;
+/// Largest source or rasterized file read into memory.
+const MAX_PAGE_FILE_BYTES: u64 = 64 * 1024 * 1024;
+const PDFTOCAIRO_PROGRAM: &str = "pdftocairo";
+const RSVG_CONVERT_PROGRAM: &str = "rsvg-convert";
+const CONVERTER_TIMEOUT: Duration = Duration::from_secs(60);
+/// Both converters write to files; stdout only ever carries diagnostics.
+const CONVERTER_STDOUT_CAP: usize = 64 * 1024;
+const RASTER_PAGE_PREFIX: &str = "page";
+
+/// One import: the document and the viewport its pages are fitted into.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PageImportRequest {
+    pub(crate) path: PathBuf,
+    /// Logical viewport size in canvas pixels.
+    pub(crate) viewport_width: u32,
+    pub(crate) viewport_height: u32,
+    /// Output scale; pages are rasterized at device resolution so they stay
+    /// sharp on HiDPI outputs.
+    pub(crate) scale: f64,
+}
+
+impl PageImportRequest {
+    fn raster_width(&self) -> u32 {
+        scaled(self.viewport_width, self.scale)
+    }
+
+    fn raster_height(&self) -> u32 {
+        scaled(self.viewport_height, self.scale)
+    }
+}
+
+fn scaled(logical: u32, scale: f64) -> u32 {
+    let scale = if scale.is_finite() && scale > 0.0 {
+        scale
+    } else {
+        1.0
+    };
+    (f64::from(logical.max(1)) * scale).round().max(1.0) as u32
+}
+
+/// Why an import produced no pages. Each variant is a distinct user-facing
+/// message; converter output stays in the log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum PageImportError {
+    /// The file is not a PDF, SVG, PNG, or JPEG.
+    UnsupportedFormat,
+    /// The converter for this format is not on `PATH`.
+    ConverterMissing {
+        program: &'static str,
+        package: &'static str,
+    },
+    /// The converter exited unsuccessfully or was unavailable.
+    ConverterFailed { program: &'static str },
+    /// The converter did not finish inside the timeout.
+    TimedOut { program: &'static str },
+    /// The document rendered to no pages.
+    NoPages,
+    /// The file or a rendered page could not be read.
+    Unreadable,
+    /// The file or a rendered page is larger than the import limit.
+    TooLarge,
+}
+
+impl PageImportError {
+    /// Stable user-facing message.
+    pub(crate) fn message(&self) -> String {
+        match self {
+            Self::UnsupportedFormat => {
+                "Only PDF, SVG, PNG, and JPEG files can be imported as pages.".to_string()
+            }
+            Self::ConverterMissing { program, package } => {
+                format!("Install {package} ({program}) to import this file.")
+            }
+            Self::ConverterFailed { program } => format!("{program} could not render the file."),
+            Self::TimedOut { program } => format!("{program} timed out rendering the file."),
+            Self::NoPages => "The file has no pages to import.".to_string(),
+            Self::Unreadable => "Could not read the file to import.".to_string(),
+            Self::TooLarge => "The file is too large to import.".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SourceFormat {
+    Pdf,
+    Svg,
+    Raster,
+}
+
+fn source_format(path: &Path) -> Option<SourceFormat> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    match extension.as_str() {
+        "pdf" => Some(SourceFormat::Pdf),
+        "svg" | "svgz" => Some(SourceFormat::Svg),
+        "png" | "jpg" | "jpeg" => Some(SourceFormat::Raster),
+        _ => None,
+    }
+}
+
+/// Whether `path` has an extension the importer understands.
+pub(crate) fn is_supported_path(path: &Path) -> bool {
+    source_format(path).is_some()
+}
+
+/// Renders every page of `request.path`, in document order, fitted to the
+/// request viewport. Blocks on the converter, so callers run it on a worker.
+pub(crate) fn import_pages(
+    request: &PageImportRequest,
+) -> Result<Vec<PageBackground>, PageImportError> {
+    let format = source_format(&request.path).ok_or(PageImportError::UnsupportedFormat)?;
+    let pages = match format {
+        SourceFormat::Raster => {
+            let image = read_embedded_image(&request.path)?;
+            vec![(None, image)]
+        }
+        SourceFormat::Pdf | SourceFormat::Svg => {
+            let directory = tempfile::Builder::new()
+                .prefix("wayscriber-page-import-")
+                .tempdir()
+                .map_err(|err| {
+                    log::warn!("Page import temporary directory creation failed: {err}");
+                    PageImportError::Unreadable
+                })?;
+            let rendered = if format == SourceFormat::Pdf {
+                render_pdf(request, directory.path())?
+            } else {
+                render_svg(request, directory.path())?
+            };
+            let pages = rendered
+                .into_iter()
+                .map(|(page, path)| read_embedded_image(&path).map(|image| (page, image)))
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Err(err) = directory.close() {
+                log::warn!("Page import temporary directory cleanup failed: {err}");
+            }
+            pages
+        }
+    };
+    if pages.is_empty() {
+        return Err(PageImportError::NoPages);
+    }
+    Ok(pages
+        .into_iter()
+        .map(|(page, image)| {
+            PageBackground::fitted(
+                PageBackgroundSource {
+                    path: request.path.clone(),
+                    page,
+                },
+                image,
+                request.viewport_width,
+                request.viewport_height,
+            )
+        })
+        .collect())
+}
+
+fn render_pdf(
+    request: &PageImportRequest,
+    directory: &Path,
+) -> Result<Vec<(Option<u32>, PathBuf)>, PageImportError> {
+    // `-scale-to` fits each page inside a square, so portrait and landscape
+    // pages both get the viewport's long edge.
+    let size = request
+        .raster_width()
+        .max(request.raster_height())
+        .to_string();
+    let last_page = MAX_IMPORTED_PAGES.to_string();
+    let prefix = directory.join(RASTER_PAGE_PREFIX);
+    let source = source_argument(&request.path);
+    run_converter(
+        HelperKind::PdfToCairo,
+        PDFTOCAIRO_PROGRAM,
+        "poppler-utils",
+        [
+            OsStr::new("-png"),
+            OsStr::new("-scale-to"),
+            OsStr::new(&size),
+            OsStr::new("-l"),
+            OsStr::new(&last_page),
+            source.as_os_str(),
+            prefix.as_os_str(),
+        ],
+    )?;
+    numbered_pages(directory)
+}
+
+fn render_svg(
+    request: &PageImportRequest,
+    directory: &Path,
+) -> Result<Vec<(Option<u32>, PathBuf)>, PageImportError> {
+    let width = request.raster_width().to_string();
+    let height = request.raster_height().to_string();
+    let output = directory.join(format!("{RASTER_PAGE_PREFIX}.png"));
+    let source = source_argument(&request.path);
+    run_converter(
+        HelperKind::RsvgConvert,
+        RSVG_CONVERT_PROGRAM,
+        "librsvg",
+        [
+            OsStr::new("--width"),
+            OsStr::new(&width),
+            OsStr::new("--height"),
+            OsStr::new(&height),
+            OsStr::new("--keep-aspect-ratio"),
+            OsStr::new("--format"),
+            OsStr::new("png"),
+            OsStr::new("--output"),
+            output.as_os_str(),
+            source.as_os_str(),
+        ],
+    )?;
+    Ok(vec![(None, output)])
+}
+
+/// The document path as a converter argument. Neither converter takes `--`,
+/// so a relative path beginning with `-` is anchored to `./` rather than read
+/// as an option; the broker rejects option-like paths either way.
+fn source_argument(path: &Path) -> Cow<'_, Path> {
+    if path.as_os_str().as_encoded_bytes().starts_with(b"-") {
+        Cow::Owned(Path::new(".").join(path))
+    } else {
+        Cow::Borrowed(path)
+    }
+}
+
+fn run_converter<'a>(
+    kind: HelperKind,
+    program: &'static str,
+    package: &'static str,
+    arguments: impl IntoIterator<Item = &'a OsStr>,
+) -> Result<(), PageImportError> {
+    if !crate::ocr::program_on_path(program) {
+        return Err(PageImportError::ConverterMissing { program, package });
+    }
+    let output = crate::process_broker::current()
+        .and_then(|broker| {
+            broker.run(
+                kind,
+                OsStr::new(program),
+                arguments,
+                Vec::new(),
+                CONVERTER_TIMEOUT,
+                CONVERTER_STDOUT_CAP,
+            )
+        })
+        .map_err(|err| {
+            log::warn!("Failed to run {program}: {err:#}");
+            PageImportError::ConverterFailed { program }
+        })?;
+    if output.timed_out {
+        return Err(PageImportError::TimedOut { program });
+    }
+    if output.status != 0 {
+        log::warn!(
+            "{program} exited with status {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+        return Err(PageImportError::ConverterFailed { program });
+    }
+    Ok(())
+}
+
+/// The pages `pdftocairo` wrote as `page-N.png`, zero-padded to the width of
+/// the last page number, sorted by page number.
+fn numbered_pages(directory: &Path) -> Result<Vec<(Option<u32>, PathBuf)>, PageImportError> {
+    let entries = std::fs::read_dir(directory).map_err(|err| {
+        log::warn!("Failed to list rendered pages: {err}");
+        PageImportError::Unreadable
+    })?;
+    let mut pages: Vec<(u32, PathBuf)> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter_map(|path| {
+            let number = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix(RASTER_PAGE_PREFIX)?
+                .strip_prefix('-')?
+                .strip_suffix(".png")?
+                .parse()
+                .ok()?;
+            Some((number, path))
+        })
+        .collect();
+    pages.sort_by_key(|(number, _)| *number);
+    pages.truncate(MAX_IMPORTED_PAGES);
+    Ok(pages
+        .into_iter()
+        .map(|(number, path)| (Some(number), path))
+        .collect())
+}
+
+fn read_embedded_image(path: &Path) -> Result<EmbeddedImage, PageImportError> {
+    let metadata = std::fs::metadata(path).map_err(|err| {
+        log::warn!("Failed to stat {}: {err}", path.display());
+        PageImportError::Unreadable
+    })?;
+    if metadata.len() > MAX_PAGE_FILE_BYTES {
+        return Err(PageImportError::TooLarge);
+    }
+    let bytes = std::fs::read(path).map_err(|err| {
+        log::warn!("Failed to read {}: {err}", path.display());
+        PageImportError::Unreadable
+    })?;
+    let format = format_from_mime_or_bytes("", &bytes).ok_or(PageImportError::UnsupportedFormat)?;
+    let (width, height) = image_dimensions(format, &bytes).map_err(|err| {
+        log::warn!("Failed to decode {}: {err}", path.display());
+        PageImportError::Unreadable
+    })?;
+    let mime_type = match format {
+        EncodedImageFormat::Png => "image/png",
+        EncodedImageFormat::Jpeg => "image/jpeg",
+    };
+    Ok(EmbeddedImage {
+        mime_type: mime_type.to_string(),
+        width,
+        height,
+        bytes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(path: PathBuf) -> PageImportRequest {
+        PageImportRequest {
+            path,
+            viewport_width: 200,
+            viewport_height: 100,
+            scale: 2.0,
+        }
+    }
+
+    #[test]
+    fn unknown_extensions_are_rejected_before_any_converter_runs() {
+        let result = import_pages(&request(PathBuf::from("/tmp/slides.key")));
+        assert_eq!(result, Err(PageImportError::UnsupportedFormat));
+        assert_eq!(
+            source_format(Path::new("deck.PDF")),
+            Some(SourceFormat::Pdf)
+        );
+        assert_eq!(
+            source_format(Path::new("logo.svgz")),
+            Some(SourceFormat::Svg)
+        );
+    }
+
+    #[test]
+    fn png_files_are_embedded_and_fitted_to_the_viewport() {
+        let directory = crate::test_temp::tempdir().unwrap();
+        let path = directory.path().join("slide.png");
+        let surface = cairo::ImageSurface::create(cairo::Format::ARgb32, 40, 40).unwrap();
+        let mut file = std::fs::File::create(&path).unwrap();
+        surface.write_to_png(&mut file).unwrap();
+
+        let pages = import_pages(&request(path.clone())).unwrap();
+        assert_eq!(pages.len(), 1);
+        let page = &pages[0];
+        assert_eq!(page.source.path, path);
+        assert_eq!(page.source.page, None);
+        assert_eq!(page.image.mime_type, "image/png");
+        assert_eq!((page.image.width, page.image.height), (40, 40));
+        assert_eq!((page.x, page.y, page.width, page.height), (50, 0, 100, 100));
+    }
+
+    #[test]
+    fn rendered_pdf_pages_sort_by_page_number() {
+        let directory = crate::test_temp::tempdir().unwrap();
+        for name in ["page-10.png", "page-02.png", "page-01.png", "notes.txt"] {
+            std::fs::write(directory.path().join(name), b"").unwrap();
+        }
+
+        let pages: Vec<_> = numbered_pages(directory.path())
+            .unwrap()
+            .into_iter()
+            .map(|(page, _)| page)
+            .collect();
+        assert_eq!(pages, [Some(1), Some(2), Some(10)]);
+    }
+
+    #[test]
+    fn option_like_source_paths_are_anchored_to_the_current_directory() {
+        assert_eq!(
+            source_argument(Path::new("-opw.pdf")),
+            Path::new("./-opw.pdf")
+        );
+        assert_eq!(
+            source_argument(Path::new("/home/user/-slides.pdf")),
+            Path::new("/home/user/-slides.pdf")
+        );
+        assert_eq!(
+            source_argument(Path::new("slides.pdf")),
+            Path::new("slides.pdf")
+        );
+    }
+}
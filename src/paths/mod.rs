@@ -154,6 +154,12 @@ pub fn daemon_pid_file() -> PathBuf {
     runtime_root().join("wayscriber.pid")
 }
 
+/// Location for the overlay's scripting control socket (`wayscriber ctl`).
+/// Uses [`XDG_RUNTIME_DIR_ENV`] when available; falls back to data/home/temp.
+pub fn control_socket_path() -> PathBuf {
+    runtime_root().join("control.sock")
+}
+
 /// Location for persistent logs.
 pub fn log_dir() -> PathBuf {
     data_dir()
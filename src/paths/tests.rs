@@ -22,6 +22,10 @@ fn tray_action_prefers_runtime_dir_when_set() {
     assert!(daemon_command_file().starts_with(tmp.path()));
     assert!(daemon_command_dir().starts_with(tmp.path()));
     assert!(daemon_pid_file().starts_with(tmp.path()));
+    assert_eq!(
+        control_socket_path(),
+        tmp.path().join("wayscriber").join("control.sock")
+    );
 
     if let Some(prev) = prev {
         unsafe {
@@ -58,6 +58,8 @@ pub(super) fn validate(
         HelperKind::Hyprctl => basename == "hyprctl",
         HelperKind::Slurp => basename == "slurp",
         HelperKind::Tesseract => basename == "tesseract",
+        HelperKind::PdfToCairo => basename == "pdftocairo",
+        HelperKind::RsvgConvert => basename == "rsvg-convert",
         HelperKind::WlPaste => basename == "wl-paste",
         HelperKind::WlCopy => basename == "wl-copy",
         HelperKind::SessionZenity => basename == "zenity",
@@ -71,6 +73,7 @@ pub(super) fn validate(
             ),
         HelperKind::DesktopOpen => matches!(basename.as_str(), "xdg-open" | "open"),
         HelperKind::UpdateFetcher => matches!(basename.as_str(), "curl" | "wget"),
+        HelperKind::SoundPlayer => matches!(basename.as_str(), "pw-play" | "paplay"),
         #[cfg(test)]
         HelperKind::TestSleep => basename == "sleep",
         #[cfg(test)]
@@ -152,6 +155,65 @@ fn validate_arguments(kind: HelperKind, basename: &str, arguments: &[OsWire]) ->
                 _ => {}
             }
         }
+        HelperKind::SoundPlayer => {
+            let [file] = arguments else {
+                bail!("sound player requires exactly one file argument");
+            };
+            if file.0.starts_with(b"-") {
+                bail!("sound player file must not be an option");
+            }
+        }
+        HelperKind::PdfToCairo => {
+            let [png, scale_to, size, last, last_page, source, prefix] = arguments else {
+                bail!("pdftocairo requires -png -scale-to SIZE -l PAGE SOURCE PREFIX");
+            };
+            if png.0 != b"-png"
+                || scale_to.0 != b"-scale-to"
+                || !is_decimal(&size.0)
+                || last.0 != b"-l"
+                || !is_decimal(&last_page.0)
+            {
+                bail!("pdftocairo options must be -png -scale-to SIZE -l PAGE");
+            }
+            if source.0.starts_with(b"-") || prefix.0.starts_with(b"-") {
+                bail!("pdftocairo source and output prefix must not be options");
+            }
+        }
+        HelperKind::RsvgConvert => {
+            let [
+                width_flag,
+                width,
+                height_flag,
+                height,
+                keep_aspect,
+                format_flag,
+                format,
+                output_flag,
+                output,
+                source,
+            ] = arguments
+            else {
+                bail!(
+                    "rsvg-convert requires --width W --height H --keep-aspect-ratio --format png --output OUTPUT SOURCE"
+                );
+            };
+            if width_flag.0 != b"--width"
+                || !is_decimal(&width.0)
+                || height_flag.0 != b"--height"
+                || !is_decimal(&height.0)
+                || keep_aspect.0 != b"--keep-aspect-ratio"
+                || format_flag.0 != b"--format"
+                || format.0 != b"png"
+                || output_flag.0 != b"--output"
+            {
+                bail!(
+                    "rsvg-convert options must be --width W --height H --keep-aspect-ratio --format png --output"
+                );
+            }
+            if output.0.starts_with(b"-") || source.0.starts_with(b"-") {
+                bail!("rsvg-convert output and source must not be options");
+            }
+        }
         HelperKind::Systemctl => {
             if arguments.first().map(|argument| argument.0.as_slice()) != Some(b"--user") {
                 bail!("systemctl helper is restricted to the user service manager");
@@ -171,6 +233,10 @@ fn validate_arguments(kind: HelperKind, basename: &str, arguments: &[OsWire]) ->
     Ok(())
 }
 
+fn is_decimal(argument: &[u8]) -> bool {
+    !argument.is_empty() && argument.iter().all(u8::is_ascii_digit)
+}
+
 fn is_curl_config_argument(argument: &[u8]) -> bool {
     if long_option_matches(argument, b"config") {
         return true;
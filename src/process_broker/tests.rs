@@ -283,6 +283,151 @@ fn tesseract_manifest_allows_only_the_tesseract_basename() {
     }
 }
 
+const PDFTOCAIRO_ARGUMENTS: &[&str] = &[
+    "-png",
+    "-scale-to",
+    "1920",
+    "-l",
+    "200",
+    "/home/user/slides.pdf",
+    "/tmp/import/page",
+];
+
+const RSVG_CONVERT_ARGUMENTS: &[&str] = &[
+    "--width",
+    "1920",
+    "--height",
+    "1080",
+    "--keep-aspect-ratio",
+    "--format",
+    "png",
+    "--output",
+    "/tmp/import/page.png",
+    "/home/user/diagram.svg",
+];
+
+#[test]
+fn page_import_rasterizers_allow_only_their_own_basenames() {
+    for (kind, allowed, rejected, arguments) in [
+        (
+            HelperKind::PdfToCairo,
+            "/usr/bin/pdftocairo",
+            "/usr/bin/rsvg-convert",
+            PDFTOCAIRO_ARGUMENTS,
+        ),
+        (
+            HelperKind::RsvgConvert,
+            "/usr/bin/rsvg-convert",
+            "/usr/bin/pdftocairo",
+            RSVG_CONVERT_ARGUMENTS,
+        ),
+    ] {
+        let arguments = wire_arguments(arguments);
+        let program = super::wire::OsWire::from_os(OsStr::new(allowed)).unwrap();
+        super::manifest::validate(kind, &program, &arguments, &[], &[]).unwrap();
+        for program in [rejected, "/usr/bin/sh"] {
+            let program = super::wire::OsWire::from_os(OsStr::new(program)).unwrap();
+            assert!(
+                super::manifest::validate(kind, &program, &arguments, &[], &[]).is_err(),
+                "{program:?} must not be allowed for {kind:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn page_import_rasterizers_accept_only_their_fixed_argument_shape() {
+    let replace = |arguments: &[&'static str], index: usize, value: &'static str| {
+        let mut arguments = arguments.to_vec();
+        arguments[index] = value;
+        arguments
+    };
+    for (kind, program, arguments, source, output) in [
+        (
+            HelperKind::PdfToCairo,
+            "pdftocairo",
+            PDFTOCAIRO_ARGUMENTS,
+            5,
+            6,
+        ),
+        (
+            HelperKind::RsvgConvert,
+            "rsvg-convert",
+            RSVG_CONVERT_ARGUMENTS,
+            9,
+            8,
+        ),
+    ] {
+        let program = super::wire::OsWire::from_os(OsStr::new(program)).unwrap();
+        let mut rejected = vec![
+            Vec::new(),
+            arguments[..arguments.len() - 1].to_vec(),
+            [arguments, &["--extra"]].concat(),
+            replace(arguments, 0, "-ps"),
+            replace(arguments, 2, "1920x"),
+            replace(arguments, source, "-opw"),
+            replace(arguments, source, "--help"),
+            replace(arguments, output, "-"),
+        ];
+        if kind == HelperKind::RsvgConvert {
+            rejected.push(replace(arguments, 6, "pdf"));
+        }
+        for arguments in rejected {
+            assert!(
+                super::manifest::validate(kind, &program, &wire_arguments(&arguments), &[], &[])
+                    .is_err(),
+                "{kind:?} accepted {arguments:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn sound_player_manifest_plays_one_file_with_pw_play_or_paplay() {
+    for program in ["/usr/bin/pw-play", "/usr/bin/paplay"] {
+        let program = super::wire::OsWire::from_os(OsStr::new(program)).unwrap();
+        super::manifest::validate(
+            HelperKind::SoundPlayer,
+            &program,
+            &wire_arguments(&["/home/user/chime.oga"]),
+            &[],
+            &[],
+        )
+        .unwrap();
+    }
+
+    let player = super::wire::OsWire::from_os(OsStr::new("pw-play")).unwrap();
+    for arguments in [
+        &[][..],
+        &["--target", "/home/user/chime.oga"][..],
+        &["-v"][..],
+    ] {
+        assert!(
+            super::manifest::validate(
+                HelperKind::SoundPlayer,
+                &player,
+                &wire_arguments(arguments),
+                &[],
+                &[],
+            )
+            .is_err(),
+            "sound player accepted {arguments:?}"
+        );
+    }
+
+    let shell = super::wire::OsWire::from_os(OsStr::new("sh")).unwrap();
+    assert!(
+        super::manifest::validate(
+            HelperKind::SoundPlayer,
+            &shell,
+            &wire_arguments(&["/home/user/chime.oga"]),
+            &[],
+            &[],
+        )
+        .is_err()
+    );
+}
+
 #[test]
 fn tesseract_reads_the_complete_recognized_output_rather_than_a_prefix() {
     // Prefix mode stops the helper once the cap fills; OCR must instead see the
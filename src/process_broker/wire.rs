@@ -37,6 +37,8 @@ pub(crate) enum HelperKind {
     Hyprctl,
     Slurp,
     Tesseract,
+    PdfToCairo,
+    RsvgConvert,
     WlPaste,
     WlCopy,
     SessionZenity,
@@ -47,6 +49,7 @@ pub(crate) enum HelperKind {
     About,
     DesktopOpen,
     UpdateFetcher,
+    SoundPlayer,
     #[cfg(test)]
     TestSleep,
     #[cfg(test)]
@@ -91,6 +91,28 @@ impl RenderColorProfile {
             | u32::from(premul_blue)
     }
 
+    /// Remap a single annotation color, for vector exports that write colors
+    /// instead of pixels. Alpha is preserved, like the pixel path.
+    pub(crate) fn remap_color(&self, color: crate::draw::Color) -> crate::draw::Color {
+        let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let Some(target) = self.mappings.get(
+            &Rgb8 {
+                r: channel(color.r),
+                g: channel(color.g),
+                b: channel(color.b),
+            }
+            .key(),
+        ) else {
+            return color;
+        };
+        crate::draw::Color {
+            r: f64::from(target.r) / 255.0,
+            g: f64::from(target.g) / 255.0,
+            b: f64::from(target.b) / 255.0,
+            a: color.a,
+        }
+    }
+
     pub fn remap_argb8888_regions(
         &self,
         data: &mut [u8],
@@ -1,5 +1,5 @@
 use super::*;
-use crate::draw::{Color, Frame, Shape};
+use crate::draw::{Color, Frame, LineStyle, Shape};
 use crate::env_vars::{CATALOG_HOOKS_TEST_ENV, XDG_DATA_HOME_ENV};
 use crate::session::{BoardPagesSnapshot, BoardSnapshot, SessionOptions, SessionSnapshot};
 use std::fs;
@@ -56,6 +56,7 @@ fn sample_snapshot() -> SessionSnapshot {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
 
     SessionSnapshot {
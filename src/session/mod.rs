@@ -12,6 +12,9 @@ mod primary;
 mod snapshot;
 mod storage;
 
+/// File extension used for session files picked through the open/save dialogs.
+pub(crate) const SESSION_FILE_EXTENSION: &str = "wayscriber-session";
+
 #[allow(unused_imports)]
 pub use artifacts::{
     NamedSessionClearOutcome, NamedSessionDuplicateOutcome, NamedSessionMoveOutcome,
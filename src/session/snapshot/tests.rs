@@ -13,7 +13,7 @@ use super::types::{
 };
 use super::{load_snapshot, save_snapshot};
 use crate::draw::frame::{ShapeSnapshot, UndoAction};
-use crate::draw::{Color, FontDescriptor, Frame, Shape};
+use crate::draw::{Color, FontDescriptor, Frame, LineStyle, Shape};
 use crate::input::EraserMode;
 use crate::session::options::{CompressionMode, SessionOptions};
 use crate::test_temp::tempdir;
@@ -40,6 +40,7 @@ fn sample_frame() -> Frame {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
     frame
 }
@@ -71,6 +72,7 @@ fn sample_tool_state() -> ToolStateSnapshot {
         eraser_kind: crate::draw::EraserKind::Circle,
         eraser_mode: EraserMode::Brush,
         blur_style: Default::default(),
+        line_style: Default::default(),
         recent_colors: Vec::new(),
         marker_opacity: Some(0.32),
         fill_enabled: Some(false),
@@ -1816,6 +1818,7 @@ fn save_snapshot_refuses_compressed_payload_over_expanded_limit() {
         font_descriptor: Default::default(),
         background_enabled: false,
         wrap_width: None,
+        rotation: 0.0,
     });
     let snapshot = SessionSnapshot {
         active_board_id: "transparent".to_string(),
@@ -2143,6 +2146,7 @@ fn save_snapshot_preserves_multiple_pages() {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
 
     let mut second = Frame::new();
@@ -2159,6 +2163,8 @@ fn save_snapshot_preserves_multiple_pages() {
             a: 1.0,
         },
         thick: 1.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     let snapshot = SessionSnapshot {
@@ -2241,6 +2247,7 @@ fn save_snapshot_serializes_compound_undo_history() {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
     let second_id = frame.add_shape(Shape::Line {
         x1: 20,
@@ -2254,6 +2261,7 @@ fn save_snapshot_serializes_compound_undo_history() {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
 
     let before_first = snapshot_for_shape(&frame, first_id);
@@ -2342,6 +2350,7 @@ fn load_snapshot_inner_migrates_legacy_frame_to_pages() {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
 
     let file = SessionFile {
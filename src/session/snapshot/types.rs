@@ -1,6 +1,6 @@
 use crate::config::Config;
 use crate::draw::{
-    BlurStyle, Color, EraserKind, FontDescriptor, Frame, REGULAR_POLYGON_DEFAULT_SIDES,
+    BlurStyle, Color, EraserKind, FontDescriptor, Frame, LineStyle, REGULAR_POLYGON_DEFAULT_SIDES,
     clamp_regular_sides,
 };
 use crate::input::{EraserMode, InputState, PerToolDrawingSettings, Tool};
@@ -71,6 +71,8 @@ pub struct ToolStateSnapshot {
     pub eraser_mode: EraserMode,
     #[serde(default)]
     pub blur_style: BlurStyle,
+    #[serde(default)]
+    pub line_style: LineStyle,
     /// Recently applied colours, most-recent-first. Absent in sessions written
     /// before recents were persisted, which restore an empty list.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
@@ -108,6 +110,7 @@ impl ToolStateSnapshot {
             eraser_kind: input.eraser_kind,
             eraser_mode: input.eraser_mode,
             blur_style: input.blur_style,
+            line_style: input.line_style,
             recent_colors: input.recent_colors().to_vec(),
             marker_opacity: Some(input.marker_opacity),
             fill_enabled: Some(input.fill_enabled),
@@ -145,6 +148,7 @@ impl ToolStateSnapshot {
             eraser_kind: EraserKind::Circle,
             eraser_mode: config.drawing.default_eraser_mode,
             blur_style: config.drawing.default_blur_style,
+            line_style: config.drawing.default_line_style,
             recent_colors: Vec::new(),
             marker_opacity: Some(config.drawing.marker_opacity),
             fill_enabled: Some(config.drawing.default_fill_enabled),
@@ -1,5 +1,5 @@
 use super::{ClearToolStateOutcome, clear_session, clear_tool_state, inspect_session};
-use crate::draw::{Color, FontDescriptor, Frame, Shape};
+use crate::draw::{Color, FontDescriptor, Frame, LineStyle, Shape};
 use crate::session::snapshot::{BoardPagesSnapshot, BoardSnapshot};
 use crate::session::{
     CompressionMode, SessionOptions, SessionSnapshot, ToolStateSnapshot, save_snapshot,
@@ -24,6 +24,7 @@ fn transparent_line_snapshot() -> SessionSnapshot {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
 
     SessionSnapshot {
@@ -52,6 +53,7 @@ fn sample_tool_state() -> ToolStateSnapshot {
         eraser_kind: crate::draw::EraserKind::Circle,
         eraser_mode: crate::input::EraserMode::Brush,
         blur_style: Default::default(),
+        line_style: Default::default(),
         recent_colors: Vec::new(),
         marker_opacity: Some(0.32),
         fill_enabled: Some(false),
@@ -284,6 +286,8 @@ fn inspect_session_reports_counts_and_flags() {
             a: 1.0,
         },
         thick: 1.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     let snapshot = SessionSnapshot {
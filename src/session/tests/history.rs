@@ -1,7 +1,7 @@
 use super::super::*;
 use super::helpers::dummy_input_state;
 use crate::draw::frame::{ShapeSnapshot, UndoAction};
-use crate::draw::{Color, Shape};
+use crate::draw::{Color, LineStyle, Shape};
 use crate::input::BOARD_ID_TRANSPARENT;
 use std::fs;
 
@@ -26,6 +26,7 @@ fn snapshot_preserves_history_only_frames() {
             a: 1.0,
         },
         thick: 3.0,
+        line_style: LineStyle::Solid,
     });
     let index = frame.find_index(id).unwrap();
     frame.push_undo_action(
@@ -78,6 +79,7 @@ fn modify_delete_cycle_survives_restore() {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
     let index = frame.find_index(id).unwrap();
     frame.push_undo_action(
@@ -108,6 +110,7 @@ fn modify_delete_cycle_survives_restore() {
                         a: 1.0,
                     },
                     thick: 1.0,
+                    line_style: LineStyle::Solid,
                 },
                 locked: false,
             },
@@ -173,6 +176,8 @@ fn clear_all_can_be_undone_after_restore() {
                 a: 1.0,
             },
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
     }
 
@@ -215,6 +220,7 @@ fn moved_multi_selection_with_history_survives_save_restore() {
             a: 1.0,
         },
         thick: 3.0,
+        line_style: LineStyle::Solid,
     });
     let second = input.boards.active_frame_mut().add_shape(Shape::Freehand {
         points: vec![(40, 40), (50, 50)],
@@ -225,6 +231,7 @@ fn moved_multi_selection_with_history_survives_save_restore() {
             a: 1.0,
         },
         thick: 3.0,
+        line_style: LineStyle::Solid,
     });
     input.set_selection(vec![first, second]);
 
@@ -291,6 +298,7 @@ fn corrupted_history_is_dropped_but_shapes_load() {
             a: 1.0,
         },
         thick: 1.5,
+        line_style: LineStyle::Solid,
     });
     let index = frame.find_index(id).unwrap();
     frame.push_undo_action(
@@ -1,7 +1,7 @@
 use super::super::*;
 use super::helpers::dummy_input_state;
 use crate::draw::frame::{ShapeSnapshot, UndoAction};
-use crate::draw::{Color, EmbeddedImage, FontDescriptor, Shape};
+use crate::draw::{Color, EmbeddedImage, FontDescriptor, LineStyle, Shape};
 use crate::input::{BOARD_ID_WHITEBOARD, EraserMode};
 use std::fs;
 use std::path::Path;
@@ -33,6 +33,7 @@ fn save_snapshot_errors_when_payload_exceeds_max_file_size() {
             eraser_kind: crate::draw::EraserKind::Circle,
             eraser_mode: EraserMode::Brush,
             blur_style: Default::default(),
+            line_style: Default::default(),
             recent_colors: Vec::new(),
             marker_opacity: Some(0.32),
             fill_enabled: Some(false),
@@ -147,6 +148,8 @@ fn load_snapshot_truncates_shapes_when_exceeding_max_shapes_per_frame() {
                     a: 1.0,
                 },
                 thick: 1.0,
+                rotation: 0.0,
+                line_style: LineStyle::Solid,
             });
         }
     }
@@ -194,6 +197,7 @@ fn image_frame(bytes: usize) -> crate::draw::Frame {
             height: 360,
             bytes: pseudo_random_bytes(bytes),
         },
+        rotation: 0.0,
     });
     frame
 }
@@ -618,6 +622,7 @@ fn save_snapshot_keeps_depth_one_when_visible_payload_is_near_limit() {
                 height: 360,
                 bytes: vec![0x35; 96 * 1024],
             },
+            rotation: 0.0,
         });
         let id = frame.add_shape(large_freehand(40, 0));
         for offset in 1..=2 {
@@ -745,6 +750,7 @@ fn add_image_and_annotations(frame: &mut crate::draw::Frame, page_index: usize,
             height: 360,
             bytes: vec![0x5a; bytes],
         },
+        rotation: 0.0,
     });
     let (image_index, image_shape) = frame
         .find_index(image_id)
@@ -771,6 +777,7 @@ fn add_image_and_annotations(frame: &mut crate::draw::Frame, page_index: usize,
             a: 1.0,
         },
         thick: 3.0,
+        line_style: LineStyle::Solid,
     });
     frame.add_shape(Shape::Text {
         x: 24,
@@ -786,6 +793,7 @@ fn add_image_and_annotations(frame: &mut crate::draw::Frame, page_index: usize,
         font_descriptor: FontDescriptor::default(),
         background_enabled: true,
         wrap_width: None,
+        rotation: 0.0,
     });
 }
 
@@ -816,5 +824,6 @@ fn large_freehand(point_count: usize, offset: i32) -> Shape {
             a: 1.0,
         },
         thick: 3.0,
+        line_style: LineStyle::Solid,
     }
 }
@@ -1,6 +1,6 @@
 use super::super::*;
 use super::helpers::dummy_input_state;
-use crate::draw::{Color, FontDescriptor, Frame, Shape};
+use crate::draw::{Color, FontDescriptor, Frame, LineStyle, Shape};
 use crate::input::{BOARD_ID_BLACKBOARD, BOARD_ID_TRANSPARENT, BOARD_ID_WHITEBOARD};
 use crate::session::snapshot::BoardSnapshot;
 use std::fs;
@@ -27,6 +27,7 @@ fn session_roundtrip_preserves_shapes_across_frames() {
             a: 1.0,
         },
         thick: 3.0,
+        line_style: LineStyle::Solid,
     });
 
     input.switch_board(BOARD_ID_WHITEBOARD);
@@ -44,6 +45,7 @@ fn session_roundtrip_preserves_shapes_across_frames() {
         font_descriptor: FontDescriptor::default(),
         background_enabled: false,
         wrap_width: None,
+        rotation: 0.0,
     });
 
     input.switch_board(BOARD_ID_BLACKBOARD);
@@ -60,6 +62,8 @@ fn session_roundtrip_preserves_shapes_across_frames() {
             a: 1.0,
         },
         thick: 1.5,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     let snapshot = snapshot_from_input(&input, &options).expect("snapshot produced");
@@ -111,6 +115,7 @@ fn session_roundtrip_preserves_pages_beyond_visible_shortcuts() {
             font_descriptor: FontDescriptor::default(),
             background_enabled: false,
             wrap_width: None,
+            rotation: 0.0,
         });
     }
     assert!(input.switch_to_page(ACTIVE_PAGE));
@@ -161,6 +166,7 @@ fn save_snapshot_rotates_backup_when_enabled() {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
 
     let snapshot = SessionSnapshot {
@@ -208,6 +214,8 @@ fn save_snapshot_skips_backup_when_disabled() {
             a: 1.0,
         },
         thick: 1.0,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
 
     let snapshot = SessionSnapshot {
@@ -1,7 +1,7 @@
 use super::super::*;
 use super::helpers::dummy_input_state;
 use crate::config::{Action, KeybindingsConfig};
-use crate::draw::{Color, FontDescriptor, Frame, PageDeleteOutcome, Shape};
+use crate::draw::{Color, FontDescriptor, Frame, LineStyle, PageDeleteOutcome, Shape};
 use crate::input::BOARD_ID_BLACKBOARD;
 use crate::input::state::{MAX_STROKE_THICKNESS, MIN_STROKE_THICKNESS};
 use crate::input::{EraserMode, PerToolDrawingSettings, Tool};
@@ -39,6 +39,7 @@ fn snapshot_includes_frames_and_tool_state() {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: LineStyle::Solid,
     });
 
     let snapshot = snapshot_from_input(&input, &options).expect("snapshot present");
@@ -317,6 +318,7 @@ fn apply_legacy_snapshot_preserves_config_initialized_font_descriptor() {
             eraser_kind: crate::draw::EraserKind::Circle,
             eraser_mode: EraserMode::Brush,
             blur_style: Default::default(),
+            line_style: Default::default(),
             recent_colors: Vec::new(),
             marker_opacity: Some(0.32),
             fill_enabled: Some(false),
@@ -368,6 +370,7 @@ fn apply_snapshot_clamps_restored_per_tool_thicknesses() {
             eraser_kind: crate::draw::EraserKind::Circle,
             eraser_mode: EraserMode::Brush,
             blur_style: Default::default(),
+            line_style: Default::default(),
             recent_colors: Vec::new(),
             marker_opacity: Some(0.32),
             fill_enabled: Some(false),
@@ -416,6 +419,7 @@ fn apply_legacy_snapshot_uses_font_derived_step_marker_size() {
             eraser_kind: crate::draw::EraserKind::Circle,
             eraser_mode: EraserMode::Brush,
             blur_style: Default::default(),
+            line_style: Default::default(),
             recent_colors: Vec::new(),
             marker_opacity: Some(0.32),
             fill_enabled: Some(false),
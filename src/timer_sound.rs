@@ -0,0 +1,68 @@
+//! Sound played when a countdown timer reaches zero.
+//!
+//! The file is handed to PipeWire's `pw-play`, or to PulseAudio's `paplay`
+//! where PipeWire is missing, through the process broker; nothing is decoded
+//! in-process. Playback runs on its own thread and is bounded, so a long or
+//! stuck file cannot hold anything up.
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::process_broker::{HelperKind, ProcessBroker};
+
+/// Players tried in order; the first one that starts wins.
+const PLAYERS: &[&str] = &["pw-play", "paplay"];
+/// Longest a chime may play before it is cut off.
+const PLAYBACK_LIMIT: Duration = Duration::from_secs(30);
+/// Players print at most a line or two of diagnostics.
+const OUTPUT_CAP: usize = 64 * 1024;
+
+/// Play `path` without blocking the caller. A sound that cannot be played is
+/// logged and otherwise ignored: the timer has already flashed and notified.
+pub(crate) fn play_in_background(path: PathBuf) -> Result<()> {
+    let broker = crate::process_broker::current()?;
+    std::thread::Builder::new()
+        .name("wayscriber-timer-sound".to_string())
+        .spawn(move || {
+            if let Err(err) = play_with_broker(&broker, &path) {
+                log::warn!("Timer sound {} failed: {err:#}", path.display());
+            }
+        })
+        .context("failed to start timer sound worker")?;
+    Ok(())
+}
+
+fn play_with_broker(broker: &ProcessBroker, path: &Path) -> Result<()> {
+    if !path.is_file() {
+        bail!("not a file");
+    }
+    let mut last_error = anyhow!("no sound player configured");
+    for player in PLAYERS {
+        match broker.run(
+            HelperKind::SoundPlayer,
+            OsStr::new(player),
+            [path.as_os_str()],
+            Vec::new(),
+            PLAYBACK_LIMIT,
+            OUTPUT_CAP,
+        ) {
+            Ok(output) => {
+                if output.status != 0 && !output.timed_out {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    bail!(
+                        "{player} exited with status {}: {}",
+                        output.status,
+                        stderr.trim()
+                    );
+                }
+                return Ok(());
+            }
+            // Not installed (or not startable): try the next player.
+            Err(err) => last_error = err,
+        }
+    }
+    Err(last_error.context("no sound player could be started (install pw-play or paplay)"))
+}
@@ -57,7 +57,7 @@ pub(in crate::toolbar_gtk) fn build_popover_content(ctx: &mut SectionCtx) -> gtk
 
 /// A bold section header, styled like the collapsible cards' titles.
 fn section_title(ctx: &SectionCtx, title: &str) -> gtk4::Label {
-    let label = gtk4::Label::new(Some(title));
+    let label = gtk4::Label::new(Some(crate::i18n::tr(title)));
     label.add_css_class("section-title");
     label.set_xalign(0.0);
     label.set_margin_top(ctx.px(2.0));
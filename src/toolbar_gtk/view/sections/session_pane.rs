@@ -103,7 +103,10 @@ fn overwrite_confirmation_rows(
     confirmation: &model::session::ToolbarSessionOverwriteConfirmation,
 ) -> gtk4::Box {
     let rows = gtk4::Box::new(gtk4::Orientation::Vertical, ctx.px(5.0));
-    let message = gtk4::Label::new(Some(&format!("Replace {}?", confirmation.label)));
+    let message = gtk4::Label::new(Some(&crate::i18n::tr_args(
+        "Replace {name}?",
+        &[("name", &confirmation.label)],
+    )));
     message.set_xalign(0.0);
     message.set_ellipsize(gtk4::pango::EllipsizeMode::Middle);
     rows.append(&message);
@@ -111,8 +114,16 @@ fn overwrite_confirmation_rows(
     let buttons = gtk4::Box::new(gtk4::Orientation::Horizontal, ctx.px(5.0));
     buttons.set_homogeneous(true);
     let actions = [
-        ("Replace", confirmation.confirm_event(), true),
-        ("Cancel", confirmation.cancel_event(), false),
+        (
+            crate::i18n::tr("Replace"),
+            confirmation.confirm_event(),
+            true,
+        ),
+        (
+            crate::i18n::tr("Cancel"),
+            confirmation.cancel_event(),
+            false,
+        ),
     ];
     for (label, event, destructive) in actions {
         let button = gtk4::Button::with_label(label);
@@ -148,7 +159,7 @@ fn recent_row(ctx: &SectionCtx, recent: &model::ToolbarSessionRecent) -> gtk4::B
     row.append(&label);
     button.set_child(Some(&row));
     button.set_tooltip_text(Some(&format_binding_label(
-        &format!("Open {}", recent.label),
+        &crate::i18n::tr_args("Open {name}", &[("name", &recent.label)]),
         Some(&recent.path.display().to_string()),
     )));
     let sender = ctx.feedback.clone();
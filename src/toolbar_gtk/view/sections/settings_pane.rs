@@ -333,7 +333,7 @@ fn item_override_rows(
 }
 
 fn sub_header(text: &str) -> gtk4::Label {
-    let label = gtk4::Label::new(Some(text));
+    let label = gtk4::Label::new(Some(crate::i18n::tr(text)));
     label.add_css_class("section-title");
     label.set_xalign(0.0);
     label
@@ -7,6 +7,7 @@ use std::rc::Rc;
 
 use gtk4::prelude::*;
 
+use crate::i18n::{tr, tr_args};
 use crate::toolbar_icons;
 use crate::ui::toolbar::{ToolbarEvent, ToolbarSnapshot, model};
 
@@ -59,9 +60,9 @@ fn toggle_checkbox(
     active: bool,
     event: fn(bool) -> ToolbarEvent,
 ) -> gtk4::CheckButton {
-    let check = gtk4::CheckButton::with_label(label);
+    let check = gtk4::CheckButton::with_label(tr(label));
     check.set_active(active);
-    check.set_tooltip_text(Some(tooltip));
+    check.set_tooltip_text(Some(tr(tooltip)));
     let sender = ctx.feedback.clone();
     check.connect_toggled(move |check| {
         send_event(&sender, event(check.is_active()));
@@ -76,7 +77,7 @@ fn custom_row(ctx: &mut SectionCtx, body: &gtk4::Box, is_undo: bool) {
     let row_h = ctx.sz(26.0);
     let row = gtk4::Box::new(gtk4::Orientation::Horizontal, ctx.px(6.0));
 
-    let tooltip = if is_undo { "Step undo" } else { "Step redo" };
+    let tooltip = tr(if is_undo { "Step undo" } else { "Step redo" });
     let step_button = if ctx.use_icons {
         let button = sized_button(ctx.sz(42.0), row_h);
         let icon = IconWidget::new(
@@ -92,7 +93,7 @@ fn custom_row(ctx: &mut SectionCtx, body: &gtk4::Box, is_undo: bool) {
     } else {
         // The built-in row draws the label left-aligned with a 10px inset.
         let button = sized_button(ctx.sz(90.0), row_h);
-        let text = gtk4::Label::new(Some(if is_undo { "Step Undo" } else { "Step Redo" }));
+        let text = gtk4::Label::new(Some(tr(if is_undo { "Step Undo" } else { "Step Redo" })));
         text.set_xalign(0.0);
         text.set_margin_start(ctx.px(10.0));
         button.set_child(Some(&text));
@@ -284,7 +285,7 @@ fn format_secs(value: f64) -> String {
 }
 
 fn steps_text(steps: usize) -> String {
-    format!("{steps} steps")
+    tr_args("{count} steps", &[("count", &steps.to_string())])
 }
 
 fn row_delay_secs(snapshot: &ToolbarSnapshot, is_undo: bool) -> f64 {
@@ -297,27 +298,30 @@ fn row_delay_secs(snapshot: &ToolbarSnapshot, is_undo: bool) -> f64 {
 }
 
 fn row_delay_tooltip(is_undo: bool, secs: f64) -> String {
-    if is_undo {
-        format!("Undo step delay: {secs:.1}s (drag)")
+    let msgid = if is_undo {
+        "Undo step delay: {seconds}s (drag)"
     } else {
-        format!("Redo step delay: {secs:.1}s (drag)")
-    }
+        "Redo step delay: {seconds}s (drag)"
+    };
+    tr_args(msgid, &[("seconds", &format!("{secs:.1}"))])
 }
 
 fn global_delay_label(is_undo: bool, delay_ms: u64) -> String {
     let secs = delay_ms as f64 / 1000.0;
-    if is_undo {
-        format!("Undo delay: {secs:.1}s")
+    let msgid = if is_undo {
+        "Undo delay: {seconds}s"
     } else {
-        format!("Redo delay: {secs:.1}s")
-    }
+        "Redo delay: {seconds}s"
+    };
+    tr_args(msgid, &[("seconds", &format!("{secs:.1}"))])
 }
 
 fn global_delay_tooltip(is_undo: bool, delay_ms: u64) -> String {
     let secs = delay_ms as f64 / 1000.0;
-    if is_undo {
-        format!("Undo-all delay: {secs:.1}s (drag)")
+    let msgid = if is_undo {
+        "Undo-all delay: {seconds}s (drag)"
     } else {
-        format!("Redo-all delay: {secs:.1}s (drag)")
-    }
+        "Redo-all delay: {seconds}s (drag)"
+    };
+    tr_args(msgid, &[("seconds", &format!("{secs:.1}"))])
 }
@@ -31,6 +31,7 @@ use gtk4_layer_shell::{Edge, KeyboardMode, Layer, LayerShell};
 
 use crate::backend::wayland::{plan_top_strip, top_toolbar_size};
 use crate::config::{Action, ToolbarLayoutMode, action_short_label};
+use crate::draw::LineStyle;
 use crate::input::Tool;
 use crate::toolbar_icons::top_toolbar_icon_painter;
 use crate::ui::toolbar::{ToolbarEvent, ToolbarSnapshot, model};
@@ -116,8 +117,8 @@ fn control_face_button(
 }
 
 /// Snapshot inputs the shapes-popover grid renders from: active tool,
-/// override, fill flag, polygon sides.
-type ShapesContentKey = (Tool, Option<Tool>, bool, u8);
+/// override, fill flag, polygon sides, line style.
+type ShapesContentKey = (Tool, Option<Tool>, bool, u8, LineStyle);
 
 /// Snapshot inputs the overflow grid renders from: active tool, override,
 /// text/note/highlight active flags, and the popover open flags (entry
@@ -185,6 +185,7 @@ impl TopBar {
                     snapshot.tool_override,
                     snapshot.fill_enabled,
                     snapshot.polygon_sides,
+                    snapshot.line_style,
                 );
                 if self.shapes_content_key.get() != Some(content_key) {
                     self.shapes_capture_surface
@@ -478,7 +479,7 @@ impl TopBar {
             content.append(&row_box);
         }
 
-        // Option rows: Fill and polygon sides live inside the popover, so
+        // Option rows: Fill, line style, and polygon sides live inside the popover, so
         // using them must not close it (GTK popovers keep inside clicks).
         if model::top_fill_visible(snapshot) {
             let fill = gtk4::CheckButton::with_label(action_short_label(Action::ToggleFill));
@@ -496,6 +497,21 @@ impl TopBar {
             });
             content.append(&fill);
         }
+        let line_style = gtk4::Button::with_label(&crate::i18n::tr_args(
+            "Line: {style}",
+            &[("style", snapshot.line_style.label())],
+        ));
+        set_semantic_widget_id(&line_style, "top.options.line-style");
+        line_style.set_tooltip_text(Some(&model::action_tooltip(
+            snapshot,
+            Action::CycleLineStyle,
+        )));
+        let sender = self.feedback.clone();
+        let next_style = snapshot.line_style.next();
+        line_style.connect_clicked(move |_| {
+            send_event(&sender, ToolbarEvent::SetLineStyle(next_style));
+        });
+        content.append(&line_style);
         if snapshot.active_tool == Tool::RegularPolygon
             || snapshot.tool_override == Some(Tool::RegularPolygon)
         {
@@ -507,7 +523,10 @@ impl TopBar {
             minus.connect_clicked(move |_| {
                 send_event(&sender, ToolbarEvent::NudgePolygonSides(-1));
             });
-            let label = gtk4::Label::new(Some(&format!("{} sides", snapshot.polygon_sides)));
+            let label = gtk4::Label::new(Some(&crate::i18n::tr_args(
+                "{count} sides",
+                &[("count", &snapshot.polygon_sides.to_string())],
+            )));
             label.set_hexpand(true);
             let plus = text_button("+", side_button, "More sides");
             set_semantic_widget_id(&plus, "top.options.sides-plus");
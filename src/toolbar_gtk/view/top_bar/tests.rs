@@ -1758,6 +1758,7 @@ fn actual_gtk_widgets_match_the_shared_contract_without_presenting_a_window() {
         );
     }
     expected_shape_ids.extend([
+        "top.options.line-style".to_string(),
         "top.options.sides-minus".to_string(),
         "top.options.sides-plus".to_string(),
     ]);
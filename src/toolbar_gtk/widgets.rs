@@ -357,14 +357,14 @@ pub(super) fn icon_button(
     let button = sized_button(button_size.0, button_size.1);
     let icon = IconWidget::new(painter, icon_size);
     button.set_child(Some(&icon.area));
-    button.set_tooltip_text(Some(tooltip));
+    button.set_tooltip_text(Some(crate::i18n::tr(tooltip)));
     IconButton { button }
 }
 
 pub(super) fn text_button(label: &str, button_size: (f64, f64), tooltip: &str) -> gtk4::Button {
     let button = sized_button(button_size.0, button_size.1);
-    button.set_label(label);
-    button.set_tooltip_text(Some(tooltip));
+    button.set_label(crate::i18n::tr(label));
+    button.set_tooltip_text(Some(crate::i18n::tr(tooltip)));
     button
 }
 
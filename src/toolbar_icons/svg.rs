@@ -473,6 +473,21 @@ fn draw_spotlight(ctx: &Context) {
     fill(ctx);
 }
 
+fn draw_laser(ctx: &Context) {
+    // A bright dot trailing a streak that thins out behind it.
+    circle(ctx, 16.5, 7.5, 4.0);
+    stroke(ctx);
+    dot(ctx, 16.5, 7.5, 1.8);
+    ctx.move_to(12.8, 11.2);
+    ctx.line_to(9.0, 15.0);
+    stroke(ctx);
+    ctx.set_line_width(1.4);
+    ctx.move_to(7.4, 16.6);
+    ctx.line_to(5.6, 18.4);
+    stroke(ctx);
+    dot(ctx, 4.0, 20.0, 0.8);
+}
+
 fn draw_triangle(ctx: &Context) {
     ctx.move_to(12.0, 4.0);
     ctx.line_to(20.0, 19.0);
@@ -608,6 +623,7 @@ renderers!(
     (render_circle, draw_ellipse),
     (render_blur, draw_blur),
     (render_spotlight, draw_spotlight),
+    (render_laser, draw_laser),
     (render_triangle, draw_triangle),
     (render_parallelogram, draw_parallelogram),
     (render_rhombus, draw_rhombus),
@@ -625,7 +641,7 @@ mod tests {
     type IconRender = fn(&Context, f64, f64, f64);
 
     const SIZES: [i32; 5] = [18, 20, 22, 24, 28];
-    const ICONS: [(&str, IconRender); 34] = [
+    const ICONS: [(&str, IconRender); 35] = [
         ("drag", render_drag),
         ("select", render_select),
         ("pen", render_pen),
@@ -653,6 +669,7 @@ mod tests {
         ("ellipse", render_circle),
         ("blur", render_blur),
         ("spotlight", render_spotlight),
+        ("laser", render_laser),
         ("triangle", render_triangle),
         ("parallelogram", render_parallelogram),
         ("rhombus", render_rhombus),
@@ -75,3 +75,27 @@ pub fn draw_icon_zoom_reset(ctx: &Context, x: f64, y: f64, size: f64) {
     ctx.arc(cx, cy, dot_r, 0.0, PI * 2.0);
     let _ = ctx.fill();
 }
+
+/// Draw a magnifier lens icon (large lens with a glint and a short handle).
+pub fn draw_icon_magnifier(ctx: &Context, x: f64, y: f64, size: f64) {
+    let s = size;
+    let stroke = (s * 0.1).max(1.5);
+    ctx.set_line_width(stroke);
+    ctx.set_line_cap(cairo::LineCap::Round);
+    ctx.set_line_join(cairo::LineJoin::Round);
+
+    let cx = x + s * 0.42;
+    let cy = y + s * 0.42;
+    let r = s * 0.3;
+
+    ctx.arc(cx, cy, r, 0.0, PI * 2.0);
+    let _ = ctx.stroke();
+
+    ctx.move_to(cx + r * 0.72, cy + r * 0.72);
+    ctx.line_to(x + s * 0.88, y + s * 0.88);
+    let _ = ctx.stroke();
+
+    ctx.set_line_width((stroke * 0.7).max(1.0));
+    ctx.arc(cx, cy, r * 0.55, PI * 1.1, PI * 1.45);
+    let _ = ctx.stroke();
+}
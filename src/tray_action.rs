@@ -23,6 +23,11 @@ pub(crate) enum TrayAction {
     LightDrawToggle,
     LightDrawOn,
     LightDrawOff,
+    StartBreakTimer,
+    StartTalkTimer,
+    StartStopwatch,
+    ToggleTimerPause,
+    ResetTimer,
 }
 
 impl TrayAction {
@@ -39,6 +44,11 @@ impl TrayAction {
             TrayAction::LightDrawToggle => "light_draw_toggle",
             TrayAction::LightDrawOn => "light_draw_on",
             TrayAction::LightDrawOff => "light_draw_off",
+            TrayAction::StartBreakTimer => "start_break_timer",
+            TrayAction::StartTalkTimer => "start_talk_timer",
+            TrayAction::StartStopwatch => "start_stopwatch",
+            TrayAction::ToggleTimerPause => "toggle_timer_pause",
+            TrayAction::ResetTimer => "reset_timer",
         }
     }
 
@@ -54,6 +64,11 @@ impl TrayAction {
             "light_draw_toggle" => Some(TrayAction::LightDrawToggle),
             "light_draw_on" => Some(TrayAction::LightDrawOn),
             "light_draw_off" => Some(TrayAction::LightDrawOff),
+            "start_break_timer" => Some(TrayAction::StartBreakTimer),
+            "start_talk_timer" => Some(TrayAction::StartTalkTimer),
+            "start_stopwatch" => Some(TrayAction::StartStopwatch),
+            "toggle_timer_pause" => Some(TrayAction::ToggleTimerPause),
+            "reset_timer" => Some(TrayAction::ResetTimer),
             _ => None,
         }
     }
@@ -198,6 +213,11 @@ mod tests {
             TrayAction::LightDrawToggle,
             TrayAction::LightDrawOn,
             TrayAction::LightDrawOff,
+            TrayAction::StartBreakTimer,
+            TrayAction::StartTalkTimer,
+            TrayAction::StartStopwatch,
+            TrayAction::ToggleTimerPause,
+            TrayAction::ResetTimer,
         ];
 
         for action in actions {
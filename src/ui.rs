@@ -6,6 +6,7 @@ mod color_picker_popup;
 mod command_palette;
 pub mod constants;
 mod context_menu;
+mod drop_target;
 mod help_overlay;
 mod input_hud;
 mod onboarding_card;
@@ -13,9 +14,11 @@ mod precision_entry;
 mod primitives;
 mod properties_panel;
 mod radial_menu;
+mod replay_bar;
 mod status;
 mod text_highlight;
 pub mod theme;
+mod timer;
 mod toasts;
 mod tour;
 
@@ -23,6 +26,7 @@ pub use board_picker::render_board_picker;
 pub use color_picker_popup::{color_picker_popup_visual_geometry, render_color_picker_popup};
 pub use command_palette::{command_palette_visual_geometry, render_command_palette};
 pub use context_menu::render_context_menu;
+pub use drop_target::render_drop_target;
 #[allow(unused_imports)]
 pub use help_overlay::HelpOverlayBindings;
 #[cfg(test)]
@@ -35,16 +39,21 @@ pub use input_hud::{input_hud_geometry, render_input_hud};
 pub use onboarding_card::{OnboardingCard, OnboardingChecklistItem, render_onboarding_card};
 pub use precision_entry::render_precision_entry_popup;
 pub(crate) use primitives::checkerboard_behind;
+/// Also traces the rounded frames of post-processed captures.
+pub(crate) use primitives::draw_rounded_rect;
 /// Shared measured-text trimming, also used by the standalone about dialog.
 pub(crate) use primitives::ellipsize_to_fit;
 pub use properties_panel::render_properties_panel;
 pub use radial_menu::render_radial_menu;
+pub use replay_bar::{ReplayBarLayout, render_replay_bar, replay_bar_layout};
 pub use status::{
-    StatusHudLayout, StatusHudSegmentKind, ZoomChipButtonKind, ZoomChipLayout, ZoomChipPress,
-    compute_status_hud_layout, compute_zoom_chip_layout, render_editing_badge, render_frozen_badge,
-    render_page_badge, render_pan_badge, render_status_bar, render_zoom_badge, render_zoom_chip,
-    status_hud_geometry, zoom_chip_geometry,
+    StatusChange, StatusHudLayout, StatusHudSegmentKind, StatusModel, ZoomChipButtonKind,
+    ZoomChipLayout, ZoomChipPress, compute_status_hud_layout, compute_zoom_chip_layout,
+    render_editing_badge, render_frozen_badge, render_page_badge, render_pan_badge,
+    render_recording_badge, render_status_bar, render_timer_badge, render_zoom_badge,
+    render_zoom_chip, status_hud_geometry, zoom_chip_geometry,
 };
+pub use timer::render_timer_overlay;
 pub use toasts::{
     blocked_feedback_rects, preset_toast_geometry, render_blocked_feedback, render_preset_toast,
     render_ui_toast, ui_toast_geometry,
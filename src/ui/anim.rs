@@ -145,6 +145,20 @@ pub fn end_fade(elapsed_secs: f64, duration_secs: f64, fade_secs: f64) -> f64 {
     }
 }
 
+/// Opacity of a trail sample `age_secs` old that is gone after
+/// `lifetime_secs`. Eases in so the fresh end of the trail stays bright and
+/// the tail drops away quickly. Used by the laser pointer.
+pub fn trail_fade(age_secs: f64, lifetime_secs: f64) -> f64 {
+    if lifetime_secs <= 0.0 || age_secs >= lifetime_secs {
+        return 0.0;
+    }
+    if !motion_enabled() {
+        return 1.0;
+    }
+    let progress = (age_secs / lifetime_secs).clamp(0.0, 1.0);
+    1.0 - progress.powi(3)
+}
+
 /// Attack/hold/release flash: ramp to `peak` by `attack_end`, hold until
 /// `hold_end`, then release to zero at `progress == 1.0`. Used by the
 /// blocked-action edge flash.
@@ -207,6 +221,19 @@ mod tests {
         assert!(flash(1.0, 0.15, 0.4, 0.22).abs() < 1e-9);
     }
 
+    #[test]
+    fn trail_fade_eases_out_and_snaps_under_reduced_motion() {
+        {
+            let _motion = override_motion_for_test(true);
+            assert_eq!(trail_fade(0.0, 0.7), 1.0);
+            assert!((trail_fade(0.35, 0.7) - 0.875).abs() < 1e-9);
+            assert_eq!(trail_fade(0.7, 0.7), 0.0);
+        }
+        let _motion = override_motion_for_test(false);
+        assert_eq!(trail_fade(0.69, 0.7), 1.0);
+        assert_eq!(trail_fade(0.7, 0.7), 0.0);
+    }
+
     #[test]
     fn envelope_eases_toward_the_target_and_settles() {
         let _motion = override_motion_for_test(true);
@@ -1,6 +1,7 @@
 use crate::draw::{
-    EraserReplayContext, SpotlightPass, render_eraser_stroke, render_shape, render_spotlight_pass,
-    spotlight_regions_for_frame,
+    EraserReplayContext, SpotlightPass, render_eraser_stroke, render_frame_layers,
+    render_page_background, render_shape, render_spotlight_pass, spotlight_regions_for_frame,
+    with_appearance,
 };
 use crate::input::BoardBackground;
 use crate::input::state::{PAGE_NAME_HEIGHT, PAGE_NAME_PADDING};
@@ -88,18 +89,23 @@ fn render_frame_shapes(
         },
         logical_to_image_scale_x: 1.0,
         logical_to_image_scale_y: 1.0,
+        page_background: frame.background(),
+        grid: None,
     };
+    if let Some(page_background) = frame.background() {
+        render_page_background(ctx, page_background);
+    }
 
-    for drawn in &frame.shapes {
+    render_frame_layers(ctx, frame, &eraser_ctx, |drawn, eraser_ctx| {
         match &drawn.shape {
             crate::draw::Shape::EraserStroke { points, brush } => {
-                render_eraser_stroke(ctx, points, brush, &eraser_ctx);
+                render_eraser_stroke(ctx, points, brush, eraser_ctx);
             }
             _ => {
-                render_shape(ctx, &drawn.shape);
+                with_appearance(ctx, drawn.appearance, || render_shape(ctx, &drawn.shape));
             }
         }
-    }
+    });
 
     // Spotlights paint nothing per-shape, so without this pass a page holding
     // only spotlights would thumbnail as an empty page. Runs after the shapes to
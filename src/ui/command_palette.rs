@@ -295,7 +295,7 @@ fn render_keybinding_capture(
     draw_text_baseline(
         ctx,
         title_style,
-        &format!("Rebind {}", action_label(action)),
+        &crate::i18n::tr_args("Rebind {action}", &[("action", action_label(action))]),
         x + 22.0,
         y + 38.0,
         None,
@@ -305,13 +305,16 @@ fn render_keybinding_capture(
     draw_text_baseline(
         ctx,
         body_style,
-        &format!(
-            "Current: {}",
-            if current.is_empty() {
-                "Not bound".to_string()
-            } else {
-                current.join(", ")
-            }
+        &crate::i18n::tr_args(
+            "Current: {shortcuts}",
+            &[(
+                "shortcuts",
+                &if current.is_empty() {
+                    crate::i18n::tr("Not bound").to_string()
+                } else {
+                    current.join(", ")
+                },
+            )],
         ),
         x + 22.0,
         y + 70.0,
@@ -321,7 +324,7 @@ fn render_keybinding_capture(
     draw_text_baseline(
         ctx,
         body_style,
-        "Press the new shortcut now",
+        crate::i18n::tr("Press the new shortcut now"),
         x + 22.0,
         y + 108.0,
         None,
@@ -330,7 +333,7 @@ fn render_keybinding_capture(
     draw_text_baseline(
         ctx,
         body_style,
-        KEYBINDING_CAPTURE_SCOPE_NOTE,
+        crate::i18n::tr(KEYBINDING_CAPTURE_SCOPE_NOTE),
         x + 22.0,
         y + 140.0,
         None,
@@ -436,7 +439,7 @@ fn draw_command_palette_input(
         draw_text_baseline(
             ctx,
             input_style,
-            COMMAND_PALETTE_QUERY_PLACEHOLDER,
+            crate::i18n::tr(COMMAND_PALETTE_QUERY_PLACEHOLDER),
             inner_x + 10.0,
             text_y,
             None,
@@ -529,6 +532,8 @@ fn draw_command_palette_empty_state(
     empty_y: f64,
 ) {
     let center_x = inner_x + inner_width / 2.0;
+    let message = crate::i18n::tr(EMPTY_COMMAND_PALETTE);
+    let suggestions = crate::i18n::tr(EMPTY_COMMAND_SUGGESTIONS);
 
     let empty_style = command_palette_text_style(
         COMMAND_PALETTE_LABEL_TEXT_SIZE,
@@ -542,12 +547,12 @@ fn draw_command_palette_empty_state(
         cairo::FontSlant::Normal,
         cairo::FontWeight::Bold,
         empty_style.size,
-        EMPTY_COMMAND_PALETTE,
+        message,
     );
     draw_text_baseline(
         ctx,
         empty_style,
-        EMPTY_COMMAND_PALETTE,
+        message,
         center_x - msg_extents.width() / 2.0,
         empty_y,
         None,
@@ -565,12 +570,12 @@ fn draw_command_palette_empty_state(
         cairo::FontSlant::Italic,
         cairo::FontWeight::Normal,
         suggest_style.size,
-        EMPTY_COMMAND_SUGGESTIONS,
+        suggestions,
     );
     draw_text_baseline(
         ctx,
         suggest_style,
-        EMPTY_COMMAND_SUGGESTIONS,
+        suggestions,
         center_x - suggest_extents.width() / 2.0,
         empty_y + 20.0,
         None,
@@ -633,6 +638,7 @@ fn draw_command_palette_escape_hint(
         cairo::FontSlant::Normal,
     );
     constants::set_color(ctx, constants::with_alpha(TEXT_DESCRIPTION, 0.6));
+    let hint = crate::i18n::tr(COMMAND_PALETTE_INPUT_HINT);
     let hint_y = y + height - HINT_BASELINE_BOTTOM_OFFSET;
     let hint_extents = text_extents_for(
         ctx,
@@ -640,12 +646,12 @@ fn draw_command_palette_escape_hint(
         cairo::FontSlant::Normal,
         cairo::FontWeight::Normal,
         COMMAND_PALETTE_HINT_TEXT_SIZE,
-        COMMAND_PALETTE_INPUT_HINT,
+        hint,
     );
     draw_text_baseline(
         ctx,
         hint_style,
-        COMMAND_PALETTE_INPUT_HINT,
+        hint,
         x + (palette_width - hint_extents.width()) / 2.0,
         hint_y,
         None,
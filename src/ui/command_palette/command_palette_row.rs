@@ -56,6 +56,8 @@ pub(super) fn render_command_row(
     item_y: f64,
     is_selected: bool,
 ) {
+    let label = crate::i18n::tr(cmd.label);
+    let description = crate::i18n::tr(cmd.description);
     if is_selected {
         draw_rounded_rect(
             ctx,
@@ -92,14 +94,14 @@ pub(super) fn render_command_row(
     draw_label_match_highlights(
         ctx,
         &input_state.command_palette_query,
-        cmd.label,
+        label,
         label_x,
         label_y,
         styles.label.size,
     );
 
     constants::set_color(ctx, constants::with_alpha(TEXT_WHITE, text_alpha));
-    render_command_row_label(ctx, cmd.label, label_x, label_y, styles);
+    render_command_row_label(ctx, label, label_x, label_y, styles);
 
     let label_extents = text_extents_for(
         ctx,
@@ -107,7 +109,7 @@ pub(super) fn render_command_row(
         cairo::FontSlant::Normal,
         cairo::FontWeight::Normal,
         styles.label.size,
-        cmd.label,
+        label,
     );
     let desc_x = label_x + label_extents.width() + 12.0;
     let configurable = KeybindingsConfig::default()
@@ -137,7 +139,7 @@ pub(super) fn render_command_row(
     render_command_row_description(
         ctx,
         &styles.desc,
-        cmd.description,
+        description,
         desc_x,
         label_y,
         max_desc_width,
@@ -0,0 +1,90 @@
+//! Drop-target highlight shown while an accepted drag hovers the overlay.
+
+use crate::i18n::tr;
+use crate::input::state::{DropKind, InputState};
+use crate::ui_text::{UiTextStyle, draw_text_baseline, text_layout};
+
+use super::constants::{self, ACCENT_BRIGHT, RADIUS_PANEL, RADIUS_XL, TEXT_PRIMARY};
+use super::primitives::draw_rounded_rect;
+
+const FRAME_INSET: f64 = 12.0;
+const FRAME_WIDTH: f64 = 3.0;
+const FRAME_DASH: [f64; 2] = [14.0, 8.0];
+const WASH_ALPHA: f64 = 0.08;
+const LABEL_FONT_SIZE: f64 = 14.0;
+const LABEL_PADDING_X: f64 = 14.0;
+const LABEL_PADDING_Y: f64 = 9.0;
+/// Gap between the drag pointer and the label pill below it.
+const LABEL_OFFSET: f64 = 24.0;
+
+/// Render the drop-target frame and a label next to the drag pointer.
+pub fn render_drop_target(ctx: &cairo::Context, input_state: &InputState, width: u32, height: u32) {
+    let Some(target) = input_state.drop_target() else {
+        return;
+    };
+    let (screen_width, screen_height) = (width as f64, height as f64);
+    let frame_width = screen_width - FRAME_INSET * 2.0;
+    let frame_height = screen_height - FRAME_INSET * 2.0;
+    if frame_width <= 0.0 || frame_height <= 0.0 {
+        return;
+    }
+
+    let _ = ctx.save();
+    draw_rounded_rect(
+        ctx,
+        FRAME_INSET,
+        FRAME_INSET,
+        frame_width,
+        frame_height,
+        RADIUS_XL,
+    );
+    constants::set_color(ctx, constants::with_alpha(ACCENT_BRIGHT, WASH_ALPHA));
+    let _ = ctx.fill_preserve();
+    constants::set_color(ctx, ACCENT_BRIGHT);
+    ctx.set_line_width(FRAME_WIDTH);
+    ctx.set_dash(&FRAME_DASH, 0.0);
+    let _ = ctx.stroke();
+    let _ = ctx.restore();
+
+    let label = match target.kind {
+        DropKind::Image => tr("Drop to add image"),
+        DropKind::Text => tr("Drop to add text"),
+        DropKind::File => tr("Drop to add file"),
+    };
+    let style = UiTextStyle {
+        family: "Sans",
+        slant: cairo::FontSlant::Normal,
+        weight: cairo::FontWeight::Bold,
+        size: LABEL_FONT_SIZE,
+    };
+    let extents = text_layout(ctx, style, label, None).ink_extents();
+    let pill_width = extents.width() + LABEL_PADDING_X * 2.0;
+    let pill_height = extents.height() + LABEL_PADDING_Y * 2.0;
+    let max_x = (screen_width - FRAME_INSET - pill_width).max(FRAME_INSET);
+    let max_y = (screen_height - FRAME_INSET - pill_height).max(FRAME_INSET);
+    let pill_x = (target.x - pill_width / 2.0).clamp(FRAME_INSET, max_x);
+    let below = target.y + LABEL_OFFSET;
+    let pill_y = if below <= max_y {
+        below
+    } else {
+        target.y - LABEL_OFFSET - pill_height
+    }
+    .clamp(FRAME_INSET, max_y);
+
+    draw_rounded_rect(ctx, pill_x, pill_y, pill_width, pill_height, RADIUS_PANEL);
+    constants::set_color(ctx, crate::ui::theme::popup::bg_modal());
+    let _ = ctx.fill_preserve();
+    constants::set_color(ctx, ACCENT_BRIGHT);
+    ctx.set_line_width(1.0);
+    let _ = ctx.stroke();
+
+    constants::set_color(ctx, TEXT_PRIMARY);
+    draw_text_baseline(
+        ctx,
+        style,
+        label,
+        pill_x + LABEL_PADDING_X - extents.x_bearing(),
+        pill_y + LABEL_PADDING_Y - extents.y_bearing(),
+        None,
+    );
+}
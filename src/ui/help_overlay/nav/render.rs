@@ -150,7 +150,7 @@ pub(crate) fn draw_nav(
         draw_text_baseline(
             ctx,
             nav_style,
-            "Type to search... (Esc clears)",
+            crate::i18n::tr("Type to search... (Esc clears)"),
             inner_x + search_padding_x,
             extra_line_baseline,
             None,
@@ -36,11 +36,16 @@ pub(crate) fn build_nav_state(
 ) -> NavState {
     let nav_text_primary = if !search_active && page_count > 1 {
         format!(
-            "{}  {}  Page {}/{}",
+            "{}  {}  {}",
             nav_title,
             BULLET,
-            page_index + 1,
-            page_count
+            crate::i18n::tr_args(
+                "Page {page}/{count}",
+                &[
+                    ("page", &(page_index + 1).to_string()),
+                    ("count", &page_count.to_string()),
+                ],
+            )
         )
     } else {
         nav_title.to_string()
@@ -49,14 +54,14 @@ pub(crate) fn build_nav_state(
     let nav_secondary_segments: Vec<(String, [f64; 4])> = if search_active {
         vec![
             ("Esc".to_string(), nav_key_color),
-            (": Close".to_string(), subtitle_color),
+            (crate::i18n::tr(": Close").to_string(), subtitle_color),
             (nav_separator.clone(), subtitle_color),
             ("Backspace".to_string(), nav_key_color),
-            (": Remove".to_string(), subtitle_color),
+            (crate::i18n::tr(": Remove").to_string(), subtitle_color),
         ]
     } else if page_count > 1 {
         vec![
-            ("Switch pages:".to_string(), subtitle_color),
+            (crate::i18n::tr("Switch pages:").to_string(), subtitle_color),
             (
                 "  Left/Right, PageUp/PageDown, Home/End".to_string(),
                 nav_key_color,
@@ -65,7 +70,7 @@ pub(crate) fn build_nav_state(
     } else {
         vec![
             ("Esc".to_string(), nav_key_color),
-            (": Close".to_string(), subtitle_color),
+            (crate::i18n::tr(": Close").to_string(), subtitle_color),
         ]
     };
     let nav_text_secondary: String = nav_secondary_segments
@@ -93,7 +98,7 @@ pub(crate) fn build_nav_state(
     .width();
 
     let search_text = if search_active {
-        let prefix = "Search: ";
+        let prefix = crate::i18n::tr("Search: ");
         let prefix_extents = text_extents_for(
             ctx,
             help_font_family,
@@ -115,7 +120,7 @@ pub(crate) fn build_nav_state(
     } else {
         None
     };
-    let search_hint_text = (!search_active).then(|| "Type to search".to_string());
+    let search_hint_text = (!search_active).then(|| crate::i18n::tr("Type to search").to_string());
     let extra_line_text = search_text.or(search_hint_text);
     let extra_line_width = extra_line_text.as_ref().map(|text| {
         text_extents_for(
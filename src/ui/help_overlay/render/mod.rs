@@ -51,11 +51,11 @@ pub fn render_help_overlay(
     scroll_offset: f64,
     quick_mode: bool,
 ) -> f64 {
-    let title_text = if quick_mode {
+    let title_text = crate::i18n::tr(if quick_mode {
         "Quick Reference"
     } else {
         "Wayscriber Controls"
-    };
+    });
     let palette_binding = bindings
         .labels_for(Action::ToggleCommandPalette)
         .and_then(|labels| labels.first())
@@ -70,13 +70,13 @@ pub fn render_help_overlay(
     let header_hints = if quick_mode {
         vec![HeaderHint {
             keys: palette_binding,
-            label: "Command Palette (search all)",
+            label: crate::i18n::tr("Command Palette (search all)"),
         }]
     } else {
         vec![
             HeaderHint {
                 keys: palette_binding,
-                label: "Command Palette",
+                label: crate::i18n::tr("Command Palette"),
             },
             HeaderHint {
                 keys: config_binding,
@@ -87,7 +87,7 @@ pub fn render_help_overlay(
     let header = HeaderContent {
         version: &version_text,
         intro: if quick_mode {
-            Some("Essential shortcuts")
+            Some(crate::i18n::tr("Essential shortcuts"))
         } else {
             None
         },
@@ -105,16 +105,23 @@ pub fn render_help_overlay(
         .unwrap_or("Shift+F1");
     let note_text_base_owned;
     let note_text_base: &str = if quick_mode {
-        note_text_base_owned = format!("{} for full help", help_binding);
+        note_text_base_owned =
+            crate::i18n::tr_args("{shortcut} for full help", &[("shortcut", help_binding)]);
         &note_text_base_owned
     } else {
-        "Note: Each board has independent pages"
-    };
-    let close_hint_owned = if quick_mode {
-        format!("{} / Esc to close", quick_help_binding)
-    } else {
-        format!("{} / Esc to close", help_binding)
+        crate::i18n::tr("Note: Each board has independent pages")
     };
+    let close_hint_owned = crate::i18n::tr_args(
+        "{shortcut} / Esc to close",
+        &[(
+            "shortcut",
+            if quick_mode {
+                quick_help_binding
+            } else {
+                help_binding
+            },
+        )],
+    );
     let close_hint_text: &str = &close_hint_owned;
 
     let layout = get_or_build_overlay_layout(
@@ -67,7 +67,7 @@ pub(super) fn build_overlay_layout(
         2
     };
     let page_index = page_index.min(page_count - 1);
-    let nav_title = if quick_mode { "Quick Ref" } else { "Controls" };
+    let nav_title = crate::i18n::tr(if quick_mode { "Quick Ref" } else { "Controls" });
 
     let sections = if quick_mode {
         section_sets.quick
@@ -174,11 +174,21 @@ pub(super) fn build_overlay_layout(
     let grid_view_height = (inner_height - header_height - footer_height).max(0.0);
     let scroll_max = (grid.grid_height - grid_view_height).max(0.0);
     let scroll_offset = scroll_offset.clamp(0.0, scroll_max);
-    let page_label = format!("Page {}/{}", page_index + 1, page_count.max(1));
+    let page_label = crate::i18n::tr_args(
+        "Page {page}/{count}",
+        &[
+            ("page", &(page_index + 1).to_string()),
+            ("count", &page_count.max(1).to_string()),
+        ],
+    );
     let note_text = if scroll_max > 0.0 {
         format!(
-            "{}  {}  {}  {}  Scroll: Mouse wheel",
-            note_text_base, BULLET, page_label, BULLET
+            "{}  {}  {}  {}  {}",
+            note_text_base,
+            BULLET,
+            page_label,
+            BULLET,
+            crate::i18n::tr("Scroll: Mouse wheel")
         )
     } else {
         format!("{}  {}  {}", note_text_base, BULLET, page_label)
@@ -145,11 +145,12 @@ pub(super) fn action_row(
     action: Action,
     fallback: &str,
 ) -> super::super::types::Row {
-    super::super::types::row(
-        binding_or_fallback(bindings, action, fallback),
-        crate::config::action_label(action),
-    )
-    .with_action(action)
+    let key = binding_or_fallback(bindings, action, fallback);
+    super::super::types::Row {
+        key: crate::i18n::tr(&key).to_string(),
+        action: crate::config::action_label(action),
+        action_id: Some(action),
+    }
 }
 
 pub(super) fn primary_or_fallback(
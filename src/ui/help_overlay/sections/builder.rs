@@ -89,7 +89,7 @@ pub(crate) fn filter_sections_for_search(
 
     if filtered.is_empty() {
         filtered.push(Section {
-            title: "No results",
+            title: crate::i18n::tr("No results"),
             rows: vec![
                 row("", "Try: zoom, page, selection, capture"),
                 row("", "Tip: search by key or action name"),
@@ -9,7 +9,7 @@ use super::super::bindings::{
 
 pub(super) fn build_quick_sections(bindings: &HelpOverlayBindings) -> Vec<Section> {
     let quick_drawing = Section {
-        title: "Drawing",
+        title: crate::i18n::tr("Drawing"),
         rows: vec![
             action_row(bindings, Action::SelectPenTool, NOT_BOUND_LABEL),
             action_row(bindings, Action::SelectEraserTool, NOT_BOUND_LABEL),
@@ -20,7 +20,7 @@ pub(super) fn build_quick_sections(bindings: &HelpOverlayBindings) -> Vec<Sectio
         icon: Some(toolbar_icons::draw_icon_pen),
     };
     let quick_actions = Section {
-        title: "Actions",
+        title: crate::i18n::tr("Actions"),
         rows: vec![
             action_row(bindings, Action::Undo, NOT_BOUND_LABEL),
             action_row(bindings, Action::ClearCanvas, NOT_BOUND_LABEL),
@@ -31,7 +31,7 @@ pub(super) fn build_quick_sections(bindings: &HelpOverlayBindings) -> Vec<Sectio
         icon: Some(toolbar_icons::draw_icon_undo),
     };
     let quick_navigation = Section {
-        title: "Navigation",
+        title: crate::i18n::tr("Navigation"),
         rows: vec![
             row(
                 bindings_or_fallback(
@@ -36,7 +36,7 @@ pub(super) fn build_main_sections(
     capture_enabled: bool,
 ) -> MainSections {
     let board_modes = (!context_filter || board_enabled).then(|| Section {
-        title: "Boards",
+        title: crate::i18n::tr("Boards"),
         rows: vec![
             action_row(bindings, Action::ToggleWhiteboard, NOT_BOUND_LABEL),
             action_row(bindings, Action::ToggleBlackboard, NOT_BOUND_LABEL),
@@ -84,7 +84,7 @@ pub(super) fn build_main_sections(
     });
 
     let pages = Section {
-        title: "Pages",
+        title: crate::i18n::tr("Pages"),
         rows: vec![
             action_row(bindings, Action::PagePrev, NOT_BOUND_LABEL),
             action_row(bindings, Action::PageNext, NOT_BOUND_LABEL),
@@ -101,7 +101,7 @@ pub(super) fn build_main_sections(
         .collect();
 
     let drawing = Section {
-        title: "Drawing",
+        title: crate::i18n::tr("Drawing"),
         rows: vec![
             action_row(bindings, Action::SelectPenTool, NOT_BOUND_LABEL),
             action_row(bindings, Action::SelectLineTool, "Shift+Drag"),
@@ -112,6 +112,7 @@ pub(super) fn build_main_sections(
             action_row(bindings, Action::ToggleHighlightTool, NOT_BOUND_LABEL),
             action_row(bindings, Action::SelectMarkerTool, NOT_BOUND_LABEL),
             action_row(bindings, Action::SelectStepMarkerTool, NOT_BOUND_LABEL),
+            action_row(bindings, Action::SelectLaserTool, NOT_BOUND_LABEL),
             action_row(bindings, Action::SelectEraserTool, NOT_BOUND_LABEL),
             row(
                 bindings_or_fallback(
@@ -127,7 +128,7 @@ pub(super) fn build_main_sections(
     };
 
     let selection = Section {
-        title: "Selection",
+        title: crate::i18n::tr("Selection"),
         rows: vec![
             action_row(bindings, Action::SelectSelectionTool, NOT_BOUND_LABEL),
             row("Drag", "Selection tool"),
@@ -136,6 +137,8 @@ pub(super) fn build_main_sections(
             action_row(bindings, Action::CopySelection, NOT_BOUND_LABEL),
             action_row(bindings, Action::PasteSelection, NOT_BOUND_LABEL),
             action_row(bindings, Action::DeleteSelection, NOT_BOUND_LABEL),
+            action_row(bindings, Action::GroupSelection, NOT_BOUND_LABEL),
+            action_row(bindings, Action::UngroupSelection, NOT_BOUND_LABEL),
             action_row(bindings, Action::ToggleSelectionProperties, NOT_BOUND_LABEL),
             action_row(bindings, Action::IncreaseFontSize, NOT_BOUND_LABEL),
             action_row(bindings, Action::DecreaseFontSize, NOT_BOUND_LABEL),
@@ -145,13 +148,14 @@ pub(super) fn build_main_sections(
     };
 
     let pen_text = Section {
-        title: "Pen & Text",
+        title: crate::i18n::tr("Pen & Text"),
         rows: vec![
             action_row(bindings, Action::EnterTextMode, NOT_BOUND_LABEL),
             action_row(bindings, Action::EnterStickyNoteMode, NOT_BOUND_LABEL),
             action_row(bindings, Action::IncreaseFontSize, NOT_BOUND_LABEL),
             action_row(bindings, Action::DecreaseFontSize, NOT_BOUND_LABEL),
             action_row(bindings, Action::ToggleFill, NOT_BOUND_LABEL),
+            action_row(bindings, Action::ToggleConnectorMode, NOT_BOUND_LABEL),
             row("Selection properties panel", "Text background"),
         ],
         badges: Vec::new(),
@@ -162,7 +166,7 @@ pub(super) fn build_main_sections(
     // the editor, ahead of the action layer, so they are fixed rather than
     // configurable bindings.
     let text_editing = Section {
-        title: "Text editing",
+        title: crate::i18n::tr("Text editing"),
         rows: vec![
             row("Click", "Place caret"),
             row("Shift+Click / Shift+Arrows", "Select text"),
@@ -171,6 +175,7 @@ pub(super) fn build_main_sections(
             row("Home/End, Ctrl+Home/End", "Line start/end, text start/end"),
             row("Ctrl+A", "Select all text"),
             row("Ctrl+C / Ctrl+X / Ctrl+V", "Copy / cut / paste selection"),
+            row("Ctrl+B / Ctrl+I", "Bold / italic markup"),
             row("Backspace/Delete, +Ctrl", "Delete character/word"),
             row("Shift+Enter", "New line"),
             row("Enter", "Finish editing"),
@@ -181,7 +186,7 @@ pub(super) fn build_main_sections(
     };
 
     let zoom = Section {
-        title: "Zoom",
+        title: crate::i18n::tr("Zoom"),
         rows: vec![
             action_row(bindings, Action::ZoomIn, NOT_BOUND_LABEL),
             action_row(bindings, Action::ZoomOut, NOT_BOUND_LABEL),
@@ -189,6 +194,8 @@ pub(super) fn build_main_sections(
             action_row(bindings, Action::ToggleZoomLock, NOT_BOUND_LABEL),
             row("Middle drag / arrow keys", "Pan view"),
             action_row(bindings, Action::RefreshZoomCapture, NOT_BOUND_LABEL),
+            action_row(bindings, Action::ToggleMagnifier, NOT_BOUND_LABEL),
+            row("Scroll over the lens", "Magnifier zoom"),
         ],
         badges: Vec::new(),
         icon: Some(toolbar_icons::draw_icon_zoom_in),
@@ -226,6 +233,12 @@ pub(super) fn build_main_sections(
         action_row(bindings, Action::ToggleToolbar, NOT_BOUND_LABEL),
         action_row(bindings, Action::CycleToolbarDisplay, NOT_BOUND_LABEL),
         action_row(bindings, Action::TogglePresenterMode, NOT_BOUND_LABEL),
+        action_row(bindings, Action::ToggleReplay, NOT_BOUND_LABEL),
+        action_row(bindings, Action::StartBreakTimer, NOT_BOUND_LABEL),
+        action_row(bindings, Action::StartTalkTimer, NOT_BOUND_LABEL),
+        action_row(bindings, Action::StartStopwatch, NOT_BOUND_LABEL),
+        action_row(bindings, Action::ToggleTimerPause, NOT_BOUND_LABEL),
+        action_row(bindings, Action::ResetTimer, NOT_BOUND_LABEL),
         action_row(bindings, Action::ToggleLightMode, NOT_BOUND_LABEL),
         action_row(bindings, Action::ToggleLightModeDrawing, NOT_BOUND_LABEL),
         action_row(bindings, Action::OpenConfigurator, NOT_BOUND_LABEL),
@@ -240,7 +253,7 @@ pub(super) fn build_main_sections(
         ));
     }
     let actions = Section {
-        title: "Actions",
+        title: crate::i18n::tr("Actions"),
         rows: action_rows,
         badges: Vec::new(),
         icon: Some(toolbar_icons::draw_icon_undo),
@@ -291,10 +304,14 @@ pub(super) fn build_main_sections(
         ),
         action_row(bindings, Action::ExportBoardPdfFile, NOT_BOUND_LABEL),
         action_row(bindings, Action::ExportAllBoardsPdfFile, NOT_BOUND_LABEL),
+        action_row(bindings, Action::ExportCanvasSvgFile, NOT_BOUND_LABEL),
+        action_row(bindings, Action::ExportBoardSvgFile, NOT_BOUND_LABEL),
+        action_row(bindings, Action::ExportReplayFrames, NOT_BOUND_LABEL),
+        action_row(bindings, Action::ToggleRecording, NOT_BOUND_LABEL),
         action_row(bindings, Action::OpenCaptureFolder, NOT_BOUND_LABEL),
     ]);
     let screenshots = Some(Section {
-        title: "Screenshots & Export",
+        title: crate::i18n::tr("Screenshots & Export"),
         rows: screenshot_rows,
         badges: Vec::new(),
         icon: Some(toolbar_icons::draw_icon_save),
@@ -48,6 +48,10 @@ fn canvas_export_rows_remain_visible_when_capture_context_is_disabled() {
         Action::ExportCanvasClipboardAndFile,
         Action::ExportBoardPdfFile,
         Action::ExportAllBoardsPdfFile,
+        Action::ExportCanvasSvgFile,
+        Action::ExportBoardSvgFile,
+        Action::ExportReplayFrames,
+        Action::ToggleRecording,
     ] {
         assert!(
             rows.contains(&action_label(action)),
@@ -60,3 +64,24 @@ fn canvas_export_rows_remain_visible_when_capture_context_is_disabled() {
         "Screenshot rows should stay hidden when capture context is disabled"
     );
 }
+
+#[test]
+fn section_titles_and_row_actions_are_in_the_english_catalog() {
+    let catalog = crate::i18n::english_catalog();
+    let bindings = HelpOverlayBindings::default();
+    let sets = build_section_sets(&bindings, true, false, true, true);
+    for section in sets.all.iter().chain(sets.quick.iter()) {
+        assert!(
+            catalog.contains(section.title),
+            "help section '{}' missing from the English catalog",
+            section.title
+        );
+        for row in &section.rows {
+            assert!(
+                catalog.contains(row.action),
+                "help row '{}' missing from the English catalog",
+                row.action
+            );
+        }
+    }
+}
@@ -46,10 +46,13 @@ pub(crate) struct BadgeTextMetrics {
     pub(crate) y_bearing: f64,
 }
 
+/// A help row written in English; both columns are shown in the UI language.
+/// Key names such as `Ctrl+Z` have no catalog entry and stay as they are.
 pub(crate) fn row<T: Into<String>>(key: T, action: &'static str) -> Row {
+    let key = key.into();
     Row {
-        key: key.into(),
-        action,
+        key: crate::i18n::tr(&key).to_string(),
+        action: crate::i18n::tr(action),
         action_id: None,
     }
 }
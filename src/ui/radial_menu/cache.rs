@@ -152,10 +152,11 @@ pub(super) fn base_cache_key(
         .join("|");
 
     let slices = format!(
-        "{:?};shapes={:?};notes={:?}",
+        "{:?};shapes={:?};notes={:?};present={:?}",
         RADIAL_COMPASS_SLICES,
         sub_ring_children(2),
         sub_ring_children(5),
+        sub_ring_children(1),
     );
 
     BaseKey {
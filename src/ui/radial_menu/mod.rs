@@ -14,8 +14,8 @@ use crate::input::state::{
 };
 use crate::input::{DrawingState, InputState, Tool};
 use crate::toolbar_icons::{
-    MicroChipStyle, ToolbarIconPainter, draw_icon_note, draw_icon_shape_picker, draw_micro_chip,
-    top_toolbar_icon_painter,
+    MicroChipStyle, ToolbarIconPainter, draw_icon_laser, draw_icon_note, draw_icon_shape_picker,
+    draw_micro_chip, top_toolbar_icon_painter,
 };
 use crate::ui::primitives::{draw_keycap, keycap_size};
 use crate::ui::theme::{self, Rgba, overlay, toolbar};
@@ -699,6 +699,7 @@ fn slice_icon(slice: &RadialSlice) -> Option<ToolbarIconPainter> {
         RadialSliceKind::Action(action) => action_meta(action).and_then(|meta| meta.icon),
         RadialSliceKind::Parent(RadialParent::Shapes) => Some(draw_icon_shape_picker),
         RadialSliceKind::Parent(RadialParent::Notes) => Some(draw_icon_note),
+        RadialSliceKind::Parent(RadialParent::Present) => Some(draw_icon_laser),
     }
 }
 
@@ -734,6 +735,7 @@ fn action_is_active(action: Action, active_tool: Tool, input_state: &InputState)
             matches!(input_state.state, DrawingState::TextInput { .. })
                 && matches!(input_state.text_input_mode, TextInputMode::StickyNote)
         }
+        Action::ToggleMagnifier => input_state.magnifier_active(),
         _ => Tool::from_select_action(action) == Some(active_tool),
     }
 }
@@ -0,0 +1,196 @@
+//! Replay transport bar shown along the bottom edge while a page replays.
+
+use crate::input::state::InputState;
+use crate::ui_text::{UiTextStyle, draw_text_baseline};
+
+use super::constants::{
+    self, ACCENT_BRIGHT, PROGRESS_FILL, PROGRESS_TRACK, RADIUS_PANEL, TEXT_HINT, TEXT_PRIMARY,
+};
+use super::primitives::{draw_rounded_rect, ellipsize_to_fit};
+
+const BAR_MAX_WIDTH: f64 = 560.0;
+const BAR_HEIGHT: f64 = 64.0;
+const BAR_MARGIN: f64 = 16.0;
+const BAR_BOTTOM_OFFSET: f64 = 24.0;
+const BUTTON_RADIUS: f64 = 14.0;
+const TRACK_LEFT: f64 = 56.0;
+const TRACK_TOP: f64 = 20.0;
+const TRACK_HEIGHT: f64 = 6.0;
+/// Extra height around the track that still counts as grabbing it.
+const TRACK_GRAB_SLOP: f64 = 10.0;
+const HINT: &str = "Space play/pause \u{00b7} \u{2190}/\u{2192} step \u{00b7} \u{2191}/\u{2193} speed \u{00b7} Esc exit";
+
+/// Geometry of the replay bar, shared by rendering and pointer hit-testing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayBarLayout {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+    pub button_center: (f64, f64),
+    pub track_x: f64,
+    pub track_y: f64,
+    pub track_width: f64,
+}
+
+impl ReplayBarLayout {
+    pub fn contains(&self, x: f64, y: f64) -> bool {
+        x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height
+    }
+
+    pub fn button_contains(&self, x: f64, y: f64) -> bool {
+        let (cx, cy) = self.button_center;
+        (x - cx).hypot(y - cy) <= BUTTON_RADIUS + 4.0
+    }
+
+    /// Fraction along the track for a press at `(x, y)`, if it hits the track.
+    pub fn track_fraction_at(&self, x: f64, y: f64) -> Option<f64> {
+        let within_x = x >= self.track_x - TRACK_GRAB_SLOP
+            && x <= self.track_x + self.track_width + TRACK_GRAB_SLOP;
+        let within_y = y >= self.track_y - TRACK_GRAB_SLOP
+            && y <= self.track_y + TRACK_HEIGHT + TRACK_GRAB_SLOP;
+        (within_x && within_y).then(|| self.track_fraction_for_x(x))
+    }
+
+    /// Fraction along the track for a pointer at `x`, clamped to the track.
+    pub fn track_fraction_for_x(&self, x: f64) -> f64 {
+        if self.track_width <= 0.0 {
+            return 0.0;
+        }
+        ((x - self.track_x) / self.track_width).clamp(0.0, 1.0)
+    }
+}
+
+/// Replay bar geometry for a screen of the given size.
+pub fn replay_bar_layout(screen_width: u32, screen_height: u32) -> ReplayBarLayout {
+    let screen_width = screen_width as f64;
+    let screen_height = screen_height as f64;
+    let width = BAR_MAX_WIDTH.min(screen_width - BAR_MARGIN * 2.0).max(0.0);
+    let x = (screen_width - width) / 2.0;
+    let y = (screen_height - BAR_HEIGHT - BAR_BOTTOM_OFFSET).max(0.0);
+    ReplayBarLayout {
+        x,
+        y,
+        width,
+        height: BAR_HEIGHT,
+        button_center: (x + 28.0, y + BAR_HEIGHT / 2.0),
+        track_x: x + TRACK_LEFT,
+        track_y: y + TRACK_TOP,
+        track_width: (width - TRACK_LEFT - BAR_MARGIN).max(0.0),
+    }
+}
+
+/// Render the replay transport bar.
+pub fn render_replay_bar(ctx: &cairo::Context, input_state: &InputState, width: u32, height: u32) {
+    let Some(replay) = input_state.replay() else {
+        return;
+    };
+    let layout = replay_bar_layout(width, height);
+    if layout.width <= TRACK_LEFT + BAR_MARGIN {
+        return;
+    }
+
+    draw_rounded_rect(
+        ctx,
+        layout.x,
+        layout.y,
+        layout.width,
+        layout.height,
+        RADIUS_PANEL,
+    );
+    constants::set_color(ctx, crate::ui::theme::popup::bg_modal());
+    let _ = ctx.fill_preserve();
+    constants::set_color(ctx, crate::ui::theme::popup::border_modal());
+    ctx.set_line_width(1.0);
+    let _ = ctx.stroke();
+
+    // Play/pause button
+    let (cx, cy) = layout.button_center;
+    constants::set_color(ctx, ACCENT_BRIGHT);
+    ctx.arc(cx, cy, BUTTON_RADIUS, 0.0, std::f64::consts::TAU);
+    let _ = ctx.fill();
+    constants::set_color(ctx, TEXT_PRIMARY);
+    if replay.playing() {
+        ctx.rectangle(cx - 5.0, cy - 6.0, 3.5, 12.0);
+        ctx.rectangle(cx + 1.5, cy - 6.0, 3.5, 12.0);
+    } else {
+        ctx.move_to(cx - 4.0, cy - 7.0);
+        ctx.line_to(cx + 7.0, cy);
+        ctx.line_to(cx - 4.0, cy + 7.0);
+        ctx.close_path();
+    }
+    let _ = ctx.fill();
+
+    // Progress track and knob
+    let progress = replay.progress();
+    constants::set_color(ctx, PROGRESS_TRACK);
+    draw_rounded_rect(
+        ctx,
+        layout.track_x,
+        layout.track_y,
+        layout.track_width,
+        TRACK_HEIGHT,
+        TRACK_HEIGHT / 2.0,
+    );
+    let _ = ctx.fill();
+    let filled = layout.track_width * progress;
+    if filled > 0.0 {
+        constants::set_color(ctx, PROGRESS_FILL);
+        draw_rounded_rect(
+            ctx,
+            layout.track_x,
+            layout.track_y,
+            filled,
+            TRACK_HEIGHT,
+            TRACK_HEIGHT / 2.0,
+        );
+        let _ = ctx.fill();
+    }
+    constants::set_color(ctx, TEXT_PRIMARY);
+    ctx.arc(
+        layout.track_x + filled,
+        layout.track_y + TRACK_HEIGHT / 2.0,
+        TRACK_HEIGHT,
+        0.0,
+        std::f64::consts::TAU,
+    );
+    let _ = ctx.fill();
+
+    let style = UiTextStyle {
+        family: "Sans",
+        slant: cairo::FontSlant::Normal,
+        weight: cairo::FontWeight::Normal,
+        size: 12.0,
+    };
+    let baseline = layout.y + layout.height - 14.0;
+    let counter = format!(
+        "{} / {} \u{00b7} {}\u{00d7}",
+        replay.shapes_shown(),
+        replay.len(),
+        format_speed(replay.speed())
+    );
+    constants::set_color(ctx, TEXT_PRIMARY);
+    let counter_extents = draw_text_baseline(ctx, style, &counter, layout.track_x, baseline, None);
+    constants::set_color(ctx, TEXT_HINT);
+    let hint_x = layout.track_x + counter_extents.x_advance() + 16.0;
+    let hint_width = layout.track_x + layout.track_width - hint_x;
+    if hint_width > 0.0 {
+        let hint = ellipsize_to_fit(
+            ctx,
+            HINT,
+            style.family,
+            style.size,
+            style.weight,
+            hint_width,
+        );
+        draw_text_baseline(ctx, style, &hint, hint_x, baseline, None);
+    }
+}
+
+fn format_speed(speed: f64) -> String {
+    if speed.fract() == 0.0 {
+        format!("{speed:.0}")
+    } else {
+        format!("{speed}")
+    }
+}
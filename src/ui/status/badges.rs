@@ -1,5 +1,7 @@
 use super::super::primitives::{BADGE_PADDING, BADGE_STACK_GAP, BadgeAlign, draw_badge};
 use super::super::theme::overlay;
+use crate::input::TimerView;
+use std::time::Duration;
 
 /// Vertical inset of the floating page badge from the screen edge.
 const PAGE_BADGE_EDGE_PADDING: f64 = overlay::SPACING_SM;
@@ -7,10 +9,17 @@ const PAGE_BADGE_EDGE_PADDING: f64 = overlay::SPACING_SM;
 /// Warning tint for the FROZEN badge — literal red for the safety state,
 /// deliberately never abstracted behind the theme.
 pub(crate) const FROZEN_BADGE_TINT: [f64; 4] = [0.82, 0.22, 0.2, 0.9];
+/// Tint for the REC badge, the same literal red as FROZEN: a running
+/// recording is a state the presenter must never miss.
+pub(crate) const RECORDING_BADGE_TINT: [f64; 4] = FROZEN_BADGE_TINT;
 /// Teal tint for the zoom badge.
 pub(crate) const ZOOM_BADGE_TINT: [f64; 4] = [0.2, 0.52, 0.7, 0.9];
 /// Olive tint for the pan badge.
 pub(crate) const PAN_BADGE_TINT: [f64; 4] = [0.33, 0.44, 0.24, 0.92];
+/// Indigo tint for the compact timer badge.
+pub(crate) const TIMER_BADGE_TINT: [f64; 4] = [0.36, 0.3, 0.68, 0.92];
+/// Amber tint the timer badge flashes with when a countdown runs out.
+pub(crate) const TIMER_BADGE_FLASH_TINT: [f64; 4] = [0.96, 0.62, 0.04, 0.95];
 /// Teal accent tint for the text-editing badge.
 pub(crate) const EDITING_BADGE_TINT: [f64; 4] = [0.2, 0.55, 0.65, 0.9];
 
@@ -22,8 +31,12 @@ pub(crate) const EDITING_BADGE_TINT: [f64; 4] = [0.2, 0.55, 0.65, 0.9];
 pub(crate) const FROZEN_BADGE_LABEL: &str = "FROZEN";
 /// FROZEN badge label font size.
 pub(crate) const FROZEN_BADGE_FONT_SIZE: f64 = 16.0;
+/// REC badge label font size.
+pub(crate) const RECORDING_BADGE_FONT_SIZE: f64 = 16.0;
 /// Zoom badge label font size.
 pub(crate) const ZOOM_BADGE_FONT_SIZE: f64 = 15.0;
+/// Timer badge label font size.
+pub(crate) const TIMER_BADGE_FONT_SIZE: f64 = 16.0;
 /// Pan badge label font size.
 pub(crate) const PAN_BADGE_FONT_SIZE: f64 = 14.0;
 /// EDITING badge label.
@@ -43,6 +56,32 @@ pub(crate) fn zoom_badge_label(zoom_scale: f64, locked: bool) -> String {
     }
 }
 
+/// REC badge label with the elapsed recording time ("REC 1:05").
+pub(crate) fn recording_badge_label(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    format!("REC {}:{:02}", secs / 60, secs % 60)
+}
+
+/// Timer badge label with the clock ("BREAK 9:59", "STOPWATCH 0:42 PAUSED").
+pub(crate) fn timer_badge_label(view: &TimerView) -> String {
+    let kind = view.kind.label().to_uppercase();
+    if view.paused {
+        format!("{kind} {} PAUSED", view.clock)
+    } else {
+        format!("{kind} {}", view.clock)
+    }
+}
+
+/// Timer badge tint, amber during the lit half of a finished countdown's
+/// flash.
+pub(crate) fn timer_badge_tint(view: &TimerView) -> [f64; 4] {
+    if view.flash_lit {
+        TIMER_BADGE_FLASH_TINT
+    } else {
+        TIMER_BADGE_TINT
+    }
+}
+
 /// Pan badge label in the HUD's mixed-case form; the top-corner badge
 /// renders the same label uppercased.
 pub(crate) fn pan_badge_label(panned: bool) -> &'static str {
@@ -54,8 +93,11 @@ pub(crate) fn pan_badge_label(panned: bool) -> &'static str {
 }
 
 /// Render a small badge indicating frozen mode (visible even when status bar is hidden).
-pub fn render_frozen_badge(ctx: &cairo::Context, screen_width: u32, _screen_height: u32) {
-    draw_badge(
+///
+/// Returns the vertical space consumed (badge height plus stacking gap) so
+/// callers can position the next stacked badge below it.
+pub fn render_frozen_badge(ctx: &cairo::Context, screen_width: u32, _screen_height: u32) -> f64 {
+    let height = draw_badge(
         ctx,
         screen_width as f64 - BADGE_PADDING,
         BADGE_PADDING,
@@ -65,6 +107,58 @@ pub fn render_frozen_badge(ctx: &cairo::Context, screen_width: u32, _screen_heig
         None,
         FROZEN_BADGE_TINT,
     );
+    height + BADGE_STACK_GAP
+}
+
+/// Render a small badge indicating an overlay recording is running (visible
+/// even when status bar is hidden).
+///
+/// Returns the vertical space consumed (badge height plus stacking gap) so
+/// callers can position the next stacked badge below it.
+pub fn render_recording_badge(
+    ctx: &cairo::Context,
+    screen_width: u32,
+    _screen_height: u32,
+    elapsed: Duration,
+    offset_y: f64,
+) -> f64 {
+    let label = recording_badge_label(elapsed);
+    let height = draw_badge(
+        ctx,
+        screen_width as f64 - BADGE_PADDING,
+        BADGE_PADDING + offset_y,
+        BadgeAlign::Right,
+        &label,
+        RECORDING_BADGE_FONT_SIZE,
+        None,
+        RECORDING_BADGE_TINT,
+    );
+    height + BADGE_STACK_GAP
+}
+
+/// Render the compact timer badge (visible even when status bar is hidden).
+///
+/// Returns the vertical space consumed (badge height plus stacking gap) so
+/// callers can position the next stacked badge below it.
+pub fn render_timer_badge(
+    ctx: &cairo::Context,
+    screen_width: u32,
+    _screen_height: u32,
+    view: &TimerView,
+    offset_y: f64,
+) -> f64 {
+    let label = timer_badge_label(view);
+    let height = draw_badge(
+        ctx,
+        screen_width as f64 - BADGE_PADDING,
+        BADGE_PADDING + offset_y,
+        BadgeAlign::Right,
+        &label,
+        TIMER_BADGE_FONT_SIZE,
+        None,
+        timer_badge_tint(view),
+    );
+    height + BADGE_STACK_GAP
 }
 
 /// Render a small badge indicating zoom mode (visible even when status bar is hidden).
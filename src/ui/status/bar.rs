@@ -7,8 +7,11 @@ use super::super::theme::{self, overlay};
 use super::badges::{
     EDITING_BADGE_FONT_SIZE, EDITING_BADGE_HINT, EDITING_BADGE_LABEL, EDITING_BADGE_TINT,
     FROZEN_BADGE_FONT_SIZE, FROZEN_BADGE_LABEL, FROZEN_BADGE_TINT, PAN_BADGE_FONT_SIZE,
-    PAN_BADGE_TINT, ZOOM_BADGE_FONT_SIZE, ZOOM_BADGE_TINT, pan_badge_label, zoom_badge_label,
+    PAN_BADGE_TINT, RECORDING_BADGE_FONT_SIZE, RECORDING_BADGE_TINT, TIMER_BADGE_FONT_SIZE,
+    ZOOM_BADGE_FONT_SIZE, ZOOM_BADGE_TINT, pan_badge_label, recording_badge_label,
+    timer_badge_label, timer_badge_tint, zoom_badge_label,
 };
+use super::model::StatusModel;
 use crate::config::{Action, StatusPosition, action_display_label};
 use crate::input::{BoardBackground, DrawingState, InputState, TextInputMode, Tool};
 use crate::label_format::{format_binding_labels, join_binding_labels};
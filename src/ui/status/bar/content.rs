@@ -73,7 +73,8 @@ pub fn compute_status_hud_layout(
     let sep_extents = measure_text(text_style, SEGMENT_SEPARATOR, None)?;
     let sep_advance = sep_extents.x_advance();
 
-    let mut pieces = build_cluster_pieces(input_state);
+    let status = StatusModel::new(input_state);
+    let mut pieces = build_cluster_pieces(input_state, &status);
     let prefix_text = build_prefix_text(input_state);
     if pieces.is_empty() && prefix_text.is_none() {
         return None;
@@ -111,7 +112,7 @@ pub fn compute_status_hud_layout(
                 .iter_mut()
                 .find(|piece| piece.kind == Some(StatusHudSegmentKind::Board))
             {
-                let label = board_segment_label(input_state, limit);
+                let label = board_segment_label(&status, limit);
                 if piece.text.as_deref() != Some(label.as_str()) {
                     piece.extents = Some(measure_text(text_style, &label, None)?);
                     piece.text = Some(label);
@@ -261,11 +262,10 @@ pub fn compute_status_hud_layout(
 /// shows "{truncated-name} {i}/{N}", an unnamed board the compact
 /// "Board i/N". `max_name_chars: None` forces the compact form (the last
 /// degradation rung).
-fn board_segment_label(input_state: &InputState, max_name_chars: Option<usize>) -> String {
-    let index = input_state.boards.active_index() + 1;
-    let count = input_state.boards.board_count().max(1);
+fn board_segment_label(status: &StatusModel, max_name_chars: Option<usize>) -> String {
+    let (index, count) = (status.board, status.board_count);
     let name = max_name_chars
-        .map(|limit| crate::util::truncate_with_ellipsis(input_state.board_name(), limit))
+        .map(|limit| crate::util::truncate_with_ellipsis(&status.board_name, limit))
         .unwrap_or_default();
     if name.trim().is_empty() {
         format!("Board {index}/{count}")
@@ -275,12 +275,15 @@ fn board_segment_label(input_state: &InputState, max_name_chars: Option<usize>)
 }
 
 /// Build the single-line segment pieces in display order.
-pub(super) fn build_cluster_pieces(input_state: &InputState) -> Vec<StatusHudPiece> {
+pub(super) fn build_cluster_pieces(
+    input_state: &InputState,
+    status: &StatusModel,
+) -> Vec<StatusHudPiece> {
     let mut pieces = Vec::new();
 
     if input_state.show_status_board_badge && input_state.boards.show_badge() {
         pieces.push(StatusHudPiece::text(
-            board_segment_label(input_state, Some(BOARD_NAME_MAX_CHARS)),
+            board_segment_label(status, Some(BOARD_NAME_MAX_CHARS)),
             Some(StatusHudSegmentKind::Board),
             false,
         ));
@@ -288,11 +291,7 @@ pub(super) fn build_cluster_pieces(input_state: &InputState) -> Vec<StatusHudPie
 
     if input_state.show_status_page_badge {
         pieces.push(StatusHudPiece::text(
-            format!(
-                "Page {}/{}",
-                input_state.boards.active_page_index() + 1,
-                input_state.boards.page_count().max(1)
-            ),
+            format!("Page {}/{}", status.page, status.page_count),
             Some(StatusHudSegmentKind::Page),
             false,
         ));
@@ -302,17 +301,16 @@ pub(super) fn build_cluster_pieces(input_state: &InputState) -> Vec<StatusHudPie
         pieces.push(StatusHudPiece::dot());
     }
 
-    let tool = input_state.active_tool();
     if input_state.show_status_tool {
         pieces.push(StatusHudPiece::text(
-            tool_display_name(input_state, tool).to_string(),
+            tool_display_name(input_state, status.tool).to_string(),
             Some(StatusHudSegmentKind::Tool),
             false,
         ));
     }
     if input_state.show_status_size {
         pieces.push(StatusHudPiece::text(
-            format!("{}px", input_state.size_for_active_tool() as i32),
+            format!("{}px", status.size as i32),
             Some(StatusHudSegmentKind::Size),
             false,
         ));
@@ -343,6 +341,13 @@ pub(super) fn build_cluster_pieces(input_state: &InputState) -> Vec<StatusHudPie
                 true,
             ));
         }
+        if input_state.fade_ink_enabled() {
+            pieces.push(StatusHudPiece::text(
+                action_display_label(Action::ToggleFadeInk).to_string(),
+                None,
+                true,
+            ));
+        }
     }
 
     // Hidden-toolbar hint: when every toolbar surface is gone (F9 toggle or
@@ -108,7 +108,7 @@ struct StatusHudBadgeSpec {
     tint: [f64; 4],
 }
 
-/// Mode badges (FROZEN/ZOOM/PAN/EDITING) stacked directly above the HUD, or
+/// Mode badges (REC/timer/FROZEN/ZOOM/PAN/EDITING) stacked directly above the HUD, or
 /// below it for top positions, aligned to the pill's near screen edge.
 pub(super) fn layout_mode_badges(
     input_state: &InputState,
@@ -122,6 +122,25 @@ pub(super) fn layout_mode_badges(
     // `badges.rs`, so the stacked pills and the top-corner badges cannot
     // drift apart.
     let mut specs: Vec<StatusHudBadgeSpec> = Vec::new();
+    if let Some(elapsed) = input_state.recording_elapsed() {
+        specs.push(StatusHudBadgeSpec {
+            label: recording_badge_label(elapsed),
+            hint: None,
+            font_size: RECORDING_BADGE_FONT_SIZE,
+            tint: RECORDING_BADGE_TINT,
+        });
+    }
+    if let Some(view) = input_state
+        .timer_view(std::time::Instant::now())
+        .filter(|view| view.compact)
+    {
+        specs.push(StatusHudBadgeSpec {
+            label: timer_badge_label(&view),
+            hint: None,
+            font_size: TIMER_BADGE_FONT_SIZE,
+            tint: timer_badge_tint(&view),
+        });
+    }
     if input_state.frozen_active() {
         // Literal red safety state; never abstracted behind the theme.
         specs.push(StatusHudBadgeSpec {
@@ -2,7 +2,7 @@ use super::*;
 use crate::config::{
     BoardsConfig, KeybindingsConfig, PresenterModeConfig, StatusBarItem, StatusBarStyle,
 };
-use crate::draw::{Color, FontDescriptor, Shape};
+use crate::draw::{Color, FontDescriptor, LineStyle, Shape};
 use crate::input::{ClickHighlightSettings, EraserMode};
 
 /// Worst-case prefix: selection info plus a long output label on a
@@ -229,6 +229,29 @@ fn mode_badges_stack_above_bottom_hud_and_below_top_hud() {
     }
 }
 
+#[test]
+fn recording_badge_leads_the_mode_badge_stack() {
+    let mut state = make_state();
+    state.set_frozen_active(true);
+    state.set_recording_started_at(Some(std::time::Instant::now()));
+    let style = StatusBarStyle::default();
+
+    let layout = compute_status_hud_layout(&state, StatusPosition::BottomLeft, &style, 1920, 1080)
+        .expect("layout");
+    assert!(layout.badges[0].label.starts_with("REC 0:0"));
+    assert_eq!(layout.badges[1].label, "FROZEN");
+
+    state.set_recording_started_at(None);
+    let idle = compute_status_hud_layout(&state, StatusPosition::BottomLeft, &style, 1920, 1080)
+        .expect("layout");
+    assert!(
+        !idle
+            .badges
+            .iter()
+            .any(|badge| badge.label.starts_with("REC"))
+    );
+}
+
 /// Reconciliation (M8): with zoom actions enabled the HUD-stacked ZOOM
 /// badge is suppressed (the bottom-right zoom chip is the canonical zoom
 /// indicator), so the percentage never shows in two places at once. Other
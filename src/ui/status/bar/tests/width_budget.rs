@@ -3,7 +3,7 @@ use super::*;
 #[test]
 fn configurable_core_segments_keep_fixed_order_and_split_tool_from_size() {
     let state = make_state();
-    let pieces = build_cluster_pieces(&state);
+    let pieces = build_cluster_pieces(&state, &StatusModel::new(&state));
     let kinds: Vec<_> = pieces.iter().filter_map(|piece| piece.kind).collect();
     assert_eq!(
         kinds,
@@ -45,7 +45,7 @@ fn each_core_content_flag_removes_only_its_segment() {
     for (item, kind) in cases {
         let mut state = make_state();
         assert!(state.set_status_bar_item_visible(item, false));
-        let pieces = build_cluster_pieces(&state);
+        let pieces = build_cluster_pieces(&state, &StatusModel::new(&state));
         assert!(
             !pieces.iter().any(|piece| piece.kind == Some(kind)),
             "{item:?} should remove {kind:?}"
@@ -74,6 +74,8 @@ fn prefix_content_keeps_output_before_selection_and_honors_both_flags() {
         fill: false,
         color: state.current_color,
         thick: state.current_thickness,
+        rotation: 0.0,
+        line_style: LineStyle::Solid,
     });
     state.set_selection(vec![shape_id]);
 
@@ -94,14 +96,14 @@ fn context_indicator_flag_gates_transient_status_text() {
     assert!(state.toggle_click_highlight());
     let label = action_display_label(Action::ToggleClickHighlight);
     assert!(
-        build_cluster_pieces(&state)
+        build_cluster_pieces(&state, &StatusModel::new(&state))
             .iter()
             .any(|piece| piece.text.as_deref() == Some(label))
     );
 
     state.set_status_bar_item_visible(StatusBarItem::ContextIndicators, false);
     assert!(
-        !build_cluster_pieces(&state)
+        !build_cluster_pieces(&state, &StatusModel::new(&state))
             .iter()
             .any(|piece| piece.text.as_deref() == Some(label))
     );
@@ -1,13 +1,15 @@
 mod badges;
 mod bar;
+mod model;
 mod zoom_chip;
 
-pub use badges::render_pan_badge;
 pub use badges::{render_editing_badge, render_frozen_badge, render_page_badge, render_zoom_badge};
+pub use badges::{render_pan_badge, render_recording_badge, render_timer_badge};
 pub use bar::{
     StatusHudLayout, StatusHudSegmentKind, compute_status_hud_layout, render_status_bar,
     status_hud_geometry,
 };
+pub use model::{StatusChange, StatusModel};
 pub use zoom_chip::{
     ZoomChipButtonKind, ZoomChipLayout, ZoomChipPress, compute_zoom_chip_layout, render_zoom_chip,
     zoom_chip_geometry,
@@ -0,0 +1,95 @@
+use crate::draw::Color;
+use crate::input::{InputState, Tool};
+
+/// The overlay state the status HUD reports, as plain values.
+///
+/// The HUD builds its board, page, tool, and size chips from this, and
+/// the control socket publishes it to `subscribe` clients, so a status bar
+/// outside the overlay and the pill inside it can never disagree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusModel {
+    pub tool: Tool,
+    pub color: Color,
+    pub size: f64,
+    pub board_id: String,
+    pub board_name: String,
+    /// 1-based slot of the active board.
+    pub board: usize,
+    pub board_count: usize,
+    /// 1-based index of the active page.
+    pub page: usize,
+    pub page_count: usize,
+    pub frozen: bool,
+    pub zoomed: bool,
+    pub presenter: bool,
+    pub recording: bool,
+}
+
+/// One field group of [`StatusModel`] that differs between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusChange {
+    Tool,
+    Color,
+    Size,
+    Board,
+    Page,
+    Frozen,
+    Zoomed,
+    Presenter,
+    Recording,
+}
+
+impl StatusModel {
+    pub fn new(input_state: &InputState) -> Self {
+        let tool = input_state.active_tool();
+        Self {
+            tool,
+            color: input_state.color_for_tool(tool),
+            size: input_state.size_for_active_tool(),
+            board_id: input_state.boards.active_board_id().to_string(),
+            board_name: input_state.board_name().to_string(),
+            board: input_state.boards.active_index() + 1,
+            board_count: input_state.boards.board_count().max(1),
+            page: input_state.boards.active_page_index() + 1,
+            page_count: input_state.boards.page_count().max(1),
+            frozen: input_state.frozen_active(),
+            zoomed: input_state.zoom_active(),
+            presenter: input_state.presenter_mode,
+            recording: input_state.recording_active(),
+        }
+    }
+
+    /// What changed on the way from `previous` to this snapshot, in a fixed
+    /// order.
+    ///
+    /// A board switch lands on that board's page; it is reported as the board
+    /// change it is, not also as a page turn.
+    pub fn changes_since(&self, previous: &Self) -> Vec<StatusChange> {
+        let board_changed = previous.board_id != self.board_id
+            || previous.board_name != self.board_name
+            || previous.board != self.board
+            || previous.board_count != self.board_count;
+        let page_changed = !board_changed
+            && (previous.page != self.page || previous.page_count != self.page_count);
+        [
+            (previous.tool != self.tool, StatusChange::Tool),
+            (previous.color != self.color, StatusChange::Color),
+            (previous.size != self.size, StatusChange::Size),
+            (board_changed, StatusChange::Board),
+            (page_changed, StatusChange::Page),
+            (previous.frozen != self.frozen, StatusChange::Frozen),
+            (previous.zoomed != self.zoomed, StatusChange::Zoomed),
+            (
+                previous.presenter != self.presenter,
+                StatusChange::Presenter,
+            ),
+            (
+                previous.recording != self.recording,
+                StatusChange::Recording,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(changed, change)| changed.then_some(change))
+        .collect()
+    }
+}
@@ -0,0 +1,154 @@
+//! Large timer readout shown over the active board while a break timer,
+//! talk timer, or stopwatch runs.
+
+use std::time::Instant;
+
+use crate::input::state::InputState;
+use crate::ui_text::{UiTextStyle, draw_text_baseline, text_layout};
+
+use super::constants::{
+    self, PROGRESS_FILL, PROGRESS_TRACK, RADIUS_PANEL, TEXT_HINT, TEXT_PRIMARY, TOAST_WARNING,
+};
+use super::primitives::draw_rounded_rect;
+
+const CLOCK_FONT_SIZE: f64 = 120.0;
+const LABEL_FONT_SIZE: f64 = 18.0;
+const PANEL_PADDING: f64 = 32.0;
+const LABEL_GAP: f64 = 12.0;
+const PROGRESS_GAP: f64 = 20.0;
+const PROGRESS_HEIGHT: f64 = 8.0;
+const SCREEN_MARGIN: f64 = 24.0;
+
+/// Render the large timer overlay, centered on the screen.
+pub fn render_timer_overlay(
+    ctx: &cairo::Context,
+    input_state: &InputState,
+    width: u32,
+    height: u32,
+) {
+    let Some(view) = input_state.timer_view(Instant::now()) else {
+        return;
+    };
+    if view.compact {
+        return;
+    }
+
+    let clock_style = UiTextStyle {
+        family: "Sans",
+        slant: cairo::FontSlant::Normal,
+        weight: cairo::FontWeight::Bold,
+        size: CLOCK_FONT_SIZE,
+    };
+    let label_style = UiTextStyle {
+        family: "Sans",
+        slant: cairo::FontSlant::Normal,
+        weight: cairo::FontWeight::Normal,
+        size: LABEL_FONT_SIZE,
+    };
+
+    // Size the panel for the widest digits of this clock length so the panel
+    // does not twitch as proportional digits change every second.
+    let template: String = view
+        .clock
+        .chars()
+        .map(|c| if c.is_ascii_digit() { '0' } else { c })
+        .collect();
+    let clock_extents = text_layout(ctx, clock_style, &template, None).ink_extents();
+    let label = match (view.finished, view.paused) {
+        (true, _) => format!("{} \u{00b7} time is up", view.kind.label()),
+        (false, true) => format!("{} \u{00b7} paused", view.kind.label()),
+        (false, false) => view.kind.label().to_string(),
+    };
+    let label_extents = text_layout(ctx, label_style, &label, None).ink_extents();
+
+    let content_width = clock_extents.width().max(label_extents.width());
+    let progress_height = if view.progress.is_some() {
+        PROGRESS_GAP + PROGRESS_HEIGHT
+    } else {
+        0.0
+    };
+    let panel_width = content_width + PANEL_PADDING * 2.0;
+    let panel_height = label_extents.height()
+        + LABEL_GAP
+        + clock_extents.height()
+        + progress_height
+        + PANEL_PADDING * 2.0;
+    let (screen_width, screen_height) = (width as f64, height as f64);
+    if panel_width > screen_width - SCREEN_MARGIN * 2.0
+        || panel_height > screen_height - SCREEN_MARGIN * 2.0
+    {
+        return;
+    }
+    let x = (screen_width - panel_width) / 2.0;
+    let y = (screen_height - panel_height) / 2.0;
+
+    draw_rounded_rect(ctx, x, y, panel_width, panel_height, RADIUS_PANEL);
+    if view.flash_lit {
+        constants::set_color_alpha(ctx, TOAST_WARNING, 0.92);
+    } else {
+        constants::set_color(ctx, crate::ui::theme::popup::bg_modal());
+    }
+    let _ = ctx.fill_preserve();
+    constants::set_color(ctx, crate::ui::theme::popup::border_modal());
+    ctx.set_line_width(1.0);
+    let _ = ctx.stroke();
+
+    let mut cursor_y = y + PANEL_PADDING;
+    constants::set_color(
+        ctx,
+        if view.flash_lit {
+            TEXT_PRIMARY
+        } else {
+            TEXT_HINT
+        },
+    );
+    draw_text_baseline(
+        ctx,
+        label_style,
+        &label,
+        x + (panel_width - label_extents.width()) / 2.0 - label_extents.x_bearing(),
+        cursor_y - label_extents.y_bearing(),
+        None,
+    );
+    cursor_y += label_extents.height() + LABEL_GAP;
+
+    let shown = text_layout(ctx, clock_style, &view.clock, None).ink_extents();
+    constants::set_color(ctx, TEXT_PRIMARY);
+    draw_text_baseline(
+        ctx,
+        clock_style,
+        &view.clock,
+        x + (panel_width - shown.width()) / 2.0 - shown.x_bearing(),
+        cursor_y - clock_extents.y_bearing(),
+        None,
+    );
+    cursor_y += clock_extents.height();
+
+    if let Some(progress) = view.progress {
+        let track_x = x + PANEL_PADDING;
+        let track_y = cursor_y + PROGRESS_GAP;
+        constants::set_color(ctx, PROGRESS_TRACK);
+        draw_rounded_rect(
+            ctx,
+            track_x,
+            track_y,
+            content_width,
+            PROGRESS_HEIGHT,
+            PROGRESS_HEIGHT / 2.0,
+        );
+        let _ = ctx.fill();
+        let filled = content_width * progress.clamp(0.0, 1.0);
+        if filled > 0.0 {
+            constants::set_color(ctx, PROGRESS_FILL);
+            draw_rounded_rect(
+                ctx,
+                track_x,
+                track_y,
+                filled,
+                PROGRESS_HEIGHT,
+                PROGRESS_HEIGHT / 2.0,
+            );
+            let _ = ctx.fill();
+        }
+    }
+}
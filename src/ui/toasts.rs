@@ -107,11 +107,12 @@ fn latest_preset_feedback(
 }
 
 fn preset_feedback_label(slot: usize, kind: PresetFeedbackKind) -> String {
-    match kind {
-        PresetFeedbackKind::Apply => format!("Preset {} applied", slot),
-        PresetFeedbackKind::Save => format!("Preset {} saved", slot),
-        PresetFeedbackKind::Clear => format!("Preset {} cleared", slot),
-    }
+    let msgid = match kind {
+        PresetFeedbackKind::Apply => "Preset {slot} applied",
+        PresetFeedbackKind::Save => "Preset {slot} saved",
+        PresetFeedbackKind::Clear => "Preset {slot} cleared",
+    };
+    crate::i18n::tr_args(msgid, &[("slot", &slot.to_string())])
 }
 
 fn measured_width(text: &str) -> Option<f64> {
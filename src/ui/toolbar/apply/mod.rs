@@ -61,6 +61,7 @@ impl InputState {
             ToolbarEvent::ToggleFill(enable) => self.apply_toolbar_toggle_fill(enable),
             ToolbarEvent::SetPolygonSides(sides) => self.apply_toolbar_set_polygon_sides(sides),
             ToolbarEvent::NudgePolygonSides(delta) => self.apply_toolbar_nudge_polygon_sides(delta),
+            ToolbarEvent::SetLineStyle(style) => self.apply_toolbar_set_line_style(style),
             ToolbarEvent::ToggleArrowLabels(enable) => {
                 self.apply_toolbar_toggle_arrow_labels(enable)
             }
@@ -262,7 +263,7 @@ impl InputState {
 #[cfg(test)]
 mod coach_tests {
     use crate::config::{Action, Shortcut};
-    use crate::draw::{Color, Shape};
+    use crate::draw::{Color, LineStyle, Shape};
     use crate::input::InputState;
     use crate::input::state::test_support::{
         make_test_input_state, make_test_input_state_with_action_bindings,
@@ -284,6 +285,8 @@ mod coach_tests {
                 a: 1.0,
             },
             thick: 1.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         });
     }
 
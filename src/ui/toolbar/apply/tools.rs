@@ -1,4 +1,4 @@
-use crate::draw::{Color, FontDescriptor};
+use crate::draw::{Color, FontDescriptor, LineStyle};
 use crate::input::{DrawingState, EraserMode, InputState, Tool};
 
 use super::super::PrecisionEntryTarget;
@@ -99,6 +99,10 @@ impl InputState {
         self.nudge_polygon_sides(delta)
     }
 
+    pub(super) fn apply_toolbar_set_line_style(&mut self, style: LineStyle) -> bool {
+        self.set_line_style(style)
+    }
+
     pub(super) fn apply_toolbar_toggle_arrow_labels(&mut self, enable: bool) -> bool {
         self.set_arrow_label_enabled(enable)
     }
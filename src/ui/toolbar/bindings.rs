@@ -90,12 +90,12 @@ pub(crate) fn action_for_tool(tool: Tool) -> Option<Action> {
 }
 
 pub(crate) fn tool_label(tool: Tool) -> &'static str {
-    tool.short_label()
+    crate::i18n::tr(tool.short_label())
 }
 
 #[allow(dead_code)]
 pub(crate) fn tool_tooltip_label(tool: Tool) -> &'static str {
-    tool.display_label()
+    crate::i18n::tr(tool.display_label())
 }
 
 pub(crate) fn action_for_event(event: &ToolbarEvent) -> Option<Action> {
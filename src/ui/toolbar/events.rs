@@ -3,7 +3,7 @@ use std::path::PathBuf;
 use crate::config::{
     Action, StatusBarItem, ToolbarItemId, ToolbarItemOrderGroup, ToolbarLayoutMode,
 };
-use crate::draw::{Color, FontDescriptor};
+use crate::draw::{Color, FontDescriptor, LineStyle};
 use crate::input::{EraserMode, Tool};
 
 use super::ToolbarSnapshot;
@@ -93,6 +93,7 @@ pub enum ToolbarEvent {
     ToggleFill(bool),
     SetPolygonSides(u8),
     NudgePolygonSides(i8),
+    SetLineStyle(LineStyle),
     ToggleArrowLabels(bool),
     ResetArrowLabelCounter,
     ResetStepMarkerCounter,
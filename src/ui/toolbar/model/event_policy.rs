@@ -270,7 +270,9 @@ pub(crate) fn popovers_for_event(event: &ToolbarEvent) -> &'static [ToolbarPopov
             &[P::TopOverflow, P::ShapePicker]
         }
         // Shapes hosts its own inline options.
-        ToolbarEvent::ToggleFill(_) | ToolbarEvent::NudgePolygonSides(_) => &[P::ShapePicker],
+        ToolbarEvent::ToggleFill(_)
+        | ToolbarEvent::NudgePolygonSides(_)
+        | ToolbarEvent::SetLineStyle(_) => &[P::ShapePicker],
 
         ToolbarEvent::OpenPrecisionEntry(_)
         | ToolbarEvent::CommitPrecisionEntry { .. }
@@ -506,6 +508,7 @@ fn persistence_for_event(event: &ToolbarEvent) -> ToolbarPersistence {
         | ToolbarEvent::ToggleFill(_)
         | ToolbarEvent::SetPolygonSides(_)
         | ToolbarEvent::NudgePolygonSides(_)
+        | ToolbarEvent::SetLineStyle(_)
         | ToolbarEvent::ToggleArrowLabels(_)
         | ToolbarEvent::ResetArrowLabelCounter
         | ToolbarEvent::ResetStepMarkerCounter
@@ -156,12 +156,16 @@ pub(crate) const fn selection_kind_slug(kind: SelectionPropertyKind) -> &'static
     match kind {
         SelectionPropertyKind::Color => "color",
         SelectionPropertyKind::Thickness => "thickness",
+        SelectionPropertyKind::LineStyle => "line-style",
         SelectionPropertyKind::Fill => "fill",
         SelectionPropertyKind::FontSize => "font-size",
         SelectionPropertyKind::ArrowHead => "arrow-head",
         SelectionPropertyKind::ArrowLength => "arrow-length",
         SelectionPropertyKind::ArrowAngle => "arrow-angle",
         SelectionPropertyKind::TextBackground => "text-background",
+        SelectionPropertyKind::Opacity => "opacity",
+        SelectionPropertyKind::BlendMode => "blend-mode",
+        SelectionPropertyKind::Layer => "layer",
     }
 }
 
@@ -170,13 +174,17 @@ pub(crate) const fn selection_kind_slug(kind: SelectionPropertyKind) -> &'static
 pub(crate) const fn selection_control_for_kind(kind: SelectionPropertyKind) -> StylePillControl {
     match kind {
         SelectionPropertyKind::Color
+        | SelectionPropertyKind::LineStyle
         | SelectionPropertyKind::Fill
         | SelectionPropertyKind::ArrowHead
-        | SelectionPropertyKind::TextBackground => StylePillControl::SelectionCycle(kind),
+        | SelectionPropertyKind::TextBackground
+        | SelectionPropertyKind::BlendMode
+        | SelectionPropertyKind::Layer => StylePillControl::SelectionCycle(kind),
         SelectionPropertyKind::Thickness
         | SelectionPropertyKind::FontSize
         | SelectionPropertyKind::ArrowLength
-        | SelectionPropertyKind::ArrowAngle => StylePillControl::SelectionStepper(kind),
+        | SelectionPropertyKind::ArrowAngle
+        | SelectionPropertyKind::Opacity => StylePillControl::SelectionStepper(kind),
     }
 }
 
@@ -1,4 +1,5 @@
 use super::*;
+use crate::i18n::{tr, tr_args};
 
 impl StylePillControl {
     pub(crate) fn id(self) -> Cow<'static, str> {
@@ -162,24 +163,24 @@ impl StylePillControl {
 
     pub(crate) fn label(self, snapshot: &ToolbarSnapshot) -> Cow<'static, str> {
         match self {
-            Self::ColorChip => Cow::Borrowed("Color picker"),
+            Self::ColorChip => Cow::Borrowed(tr("Color picker")),
             Self::QuickSwatch(index) => Cow::Owned(
                 snapshot.quick_colors.rendered_entries()[index]
                     .label
                     .clone(),
             ),
             Self::ThicknessSlider => {
-                Cow::Borrowed(ToolContext::from_snapshot(snapshot).thickness_label)
+                Cow::Borrowed(tr(ToolContext::from_snapshot(snapshot).thickness_label))
             }
-            Self::OpacitySlider => Cow::Borrowed("Marker opacity"),
-            Self::FontSizeSlider => Cow::Borrowed("Text size"),
+            Self::OpacitySlider => Cow::Borrowed(tr("Marker opacity")),
+            Self::FontSizeSlider => Cow::Borrowed(tr("Text size")),
             Self::ThicknessValue => Cow::Owned(format!("{:.0}px", snapshot.thickness)),
             Self::FontSizeValue => Cow::Owned(format!("{:.0}pt", snapshot.font_size)),
             Self::FillToggle => Cow::Borrowed(action_short_label(Action::ToggleFill)),
-            Self::AutoNumberToggle => Cow::Borrowed("Auto-number"),
-            Self::CounterReset(_) => Cow::Borrowed("Reset"),
-            Self::FontFamilySegment => Cow::Borrowed("Font"),
-            Self::EraserModeSegment => Cow::Borrowed("Eraser mode"),
+            Self::AutoNumberToggle => Cow::Borrowed(tr("Auto-number")),
+            Self::CounterReset(_) => Cow::Borrowed(tr("Reset")),
+            Self::FontFamilySegment => Cow::Borrowed(tr("Font")),
+            Self::EraserModeSegment => Cow::Borrowed(tr("Eraser mode")),
             Self::SelectionCycle(kind) | Self::SelectionStepper(kind) => Cow::Owned(
                 selection_entry(snapshot, kind)
                     .map(|entry| entry.label.clone())
@@ -190,33 +191,31 @@ impl StylePillControl {
 
     pub(crate) fn tooltip(self, snapshot: &ToolbarSnapshot) -> Option<String> {
         match self {
-            Self::ColorChip => Some("Color picker".to_string()),
+            Self::ColorChip => Some(tr("Color picker").to_string()),
             Self::QuickSwatch(index) => {
                 let entry = &snapshot.quick_colors.rendered_entries()[index];
                 let binding = QuickColorPalette::action_for_index(index)
                     .and_then(|action| snapshot.binding_hints.binding_for_action(action));
                 Some(format_quick_color_tooltip(&entry.label, binding))
             }
-            Self::ThicknessValue => Some(
-                ToolContext::from_snapshot(snapshot)
-                    .thickness_label
-                    .to_string(),
-            ),
-            Self::FontSizeValue => Some("Text size".to_string()),
+            Self::ThicknessValue => {
+                Some(tr(ToolContext::from_snapshot(snapshot).thickness_label).to_string())
+            }
+            Self::FontSizeValue => Some(tr("Text size").to_string()),
             Self::FillToggle => Some(format_binding_label(
                 action_label(Action::ToggleFill),
                 snapshot
                     .binding_hints
                     .binding_for_action(Action::ToggleFill),
             )),
-            Self::AutoNumberToggle => Some("Auto-number arrows 1, 2, 3.".to_string()),
-            Self::CounterReset(StylePillCounter::Arrow) => Some(format!(
-                "Reset numbering to 1 (next: {})",
-                snapshot.arrow_label_next
+            Self::AutoNumberToggle => Some(tr("Auto-number arrows 1, 2, 3.").to_string()),
+            Self::CounterReset(StylePillCounter::Arrow) => Some(tr_args(
+                "Reset numbering to 1 (next: {next})",
+                &[("next", &snapshot.arrow_label_next.to_string())],
             )),
-            Self::CounterReset(StylePillCounter::Step) => Some(format!(
-                "Reset numbering to 1 (next: {})",
-                snapshot.step_marker_next
+            Self::CounterReset(StylePillCounter::Step) => Some(tr_args(
+                "Reset numbering to 1 (next: {next})",
+                &[("next", &snapshot.step_marker_next.to_string())],
             )),
             Self::SelectionCycle(kind) => selection_entry(snapshot, kind)
                 .map(|entry| format!("{}: {}", entry.label, entry.value)),
@@ -304,6 +303,9 @@ impl StylePillControl {
                 "top.style.sel.arrow-angle.minus",
                 "top.style.sel.arrow-angle.plus",
             ),
+            SelectionPropertyKind::Opacity => {
+                ("top.style.sel.opacity.minus", "top.style.sel.opacity.plus")
+            }
             _ => return None,
         };
         Some([
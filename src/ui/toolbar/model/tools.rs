@@ -26,6 +26,7 @@ pub(crate) enum SemanticToolIcon {
     Spotlight,
     Marker,
     Highlight,
+    Laser,
     StepMarker,
     Eraser,
 }
@@ -38,12 +39,13 @@ const SIMPLE_TOOL_BUTTONS: [Tool; 5] = [
     Tool::Eraser,
 ];
 
-const FULL_TOOL_BUTTONS: [Tool; 7] = [
+const FULL_TOOL_BUTTONS: [Tool; 8] = [
     Tool::Select,
     Tool::Pen,
     Tool::Marker,
     Tool::StepMarker,
     Tool::Eraser,
+    Tool::Laser,
     Tool::Line,
     Tool::Arrow,
 ];
@@ -174,6 +176,7 @@ pub(crate) fn toolbar_item_id_for_tool(tool: Tool) -> ToolbarItemId {
         Tool::Spotlight => ids::TOP_TOOL_SPOTLIGHT,
         Tool::Marker => ids::TOP_TOOL_MARKER,
         Tool::Highlight => ids::TOP_UTILITY_HIGHLIGHT,
+        Tool::Laser => ids::TOP_TOOL_LASER,
         Tool::StepMarker => ids::TOP_TOOL_STEP_MARKER,
         Tool::Eraser => ids::TOP_TOOL_ERASER,
     }
@@ -197,6 +200,7 @@ fn tool_for_toolbar_item_id(id: ToolbarItemId) -> Option<Tool> {
         (ids::TOP_TOOL_MARKER, Tool::Marker),
         (ids::TOP_TOOL_STEP_MARKER, Tool::StepMarker),
         (ids::TOP_TOOL_ERASER, Tool::Eraser),
+        (ids::TOP_TOOL_LASER, Tool::Laser),
     ]
     .into_iter()
     .find_map(|(candidate, tool)| (candidate == id).then_some(tool))
@@ -332,7 +336,7 @@ pub(crate) enum TopToolGroup {
 
 pub(crate) fn top_tool_group(tool: Tool) -> TopToolGroup {
     match tool {
-        Tool::Select | Tool::Pen | Tool::Marker | Tool::StepMarker | Tool::Eraser => {
+        Tool::Select | Tool::Pen | Tool::Marker | Tool::StepMarker | Tool::Eraser | Tool::Laser => {
             TopToolGroup::Pens
         }
         _ => TopToolGroup::Shapes,
@@ -398,6 +402,7 @@ pub(crate) fn semantic_icon_for_tool(tool: Tool) -> SemanticToolIcon {
         Tool::Spotlight => SemanticToolIcon::Spotlight,
         Tool::Marker => SemanticToolIcon::Marker,
         Tool::Highlight => SemanticToolIcon::Highlight,
+        Tool::Laser => SemanticToolIcon::Laser,
         Tool::StepMarker => SemanticToolIcon::StepMarker,
         Tool::Eraser => SemanticToolIcon::Eraser,
     }
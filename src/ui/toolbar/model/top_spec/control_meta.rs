@@ -1,4 +1,5 @@
 use super::*;
+use crate::i18n::{tr, tr_args};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum TopToolbarControlId {
@@ -132,9 +133,9 @@ pub(super) fn utility_action(utility: TopToolbarUtility) -> Action {
 
 pub(super) fn utility_short_label(utility: TopToolbarUtility) -> &'static str {
     match utility {
-        TopToolbarUtility::Screenshot => "Shot",
-        TopToolbarUtility::Ocr => "Copy text",
-        TopToolbarUtility::Highlight => "Highlight",
+        TopToolbarUtility::Screenshot => tr("Shot"),
+        TopToolbarUtility::Ocr => tr("Copy text"),
+        TopToolbarUtility::Highlight => tr("Highlight"),
         _ => action_short_label(utility_action(utility)),
     }
 }
@@ -166,12 +167,19 @@ fn preset_name(snapshot: &ToolbarSnapshot, index: usize) -> Option<&str> {
 /// leads either way so the slots read distinctly under a screen reader.
 pub(super) fn preset_accessible_label(snapshot: &ToolbarSnapshot, index: usize) -> String {
     let slot = index + 1;
+    let slot = slot.to_string();
     match preset_slot(snapshot, index) {
-        Some(preset) => match preset_name(snapshot, index) {
-            Some(name) => format!("Preset {slot}: {name}"),
-            None => format!("Preset {slot}: {}", tool_tooltip_label(preset.tool)),
-        },
-        None => format!("Preset {slot} (empty)"),
+        Some(preset) => tr_args(
+            "Preset {slot}: {name}",
+            &[
+                ("slot", &slot),
+                (
+                    "name",
+                    preset_name(snapshot, index).unwrap_or(tool_tooltip_label(preset.tool)),
+                ),
+            ],
+        ),
+        None => tr_args("Preset {slot} (empty)", &[("slot", &slot)]),
     }
 }
 
@@ -181,14 +189,20 @@ pub(super) fn preset_tooltip(snapshot: &ToolbarSnapshot, index: usize) -> String
     let slot = index + 1;
     match preset_slot(snapshot, index) {
         Some(preset) => {
-            let label = match preset_name(snapshot, index) {
-                Some(name) => format!("Preset {slot}: {name}"),
-                None => format!("Preset {slot}: {}", tool_tooltip_label(preset.tool)),
-            };
+            let label = tr_args(
+                "Preset {slot}: {name}",
+                &[
+                    ("slot", &slot.to_string()),
+                    (
+                        "name",
+                        preset_name(snapshot, index).unwrap_or(tool_tooltip_label(preset.tool)),
+                    ),
+                ],
+            );
             format_binding_label(&label, snapshot.binding_hints.apply_preset(slot))
         }
         None => format_binding_label(
-            &format!("Save preset {slot}"),
+            &tr_args("Save preset {slot}", &[("slot", &slot.to_string())]),
             snapshot.binding_hints.save_preset(slot),
         ),
     }
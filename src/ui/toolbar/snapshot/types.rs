@@ -1,6 +1,6 @@
 use crate::config::QuickColorPalette;
 use crate::config::{ResolvedToolbarItems, ToolbarItemId, ToolbarLayoutMode, TopDisplayMode};
-use crate::draw::{Color, EraserKind, FontDescriptor};
+use crate::draw::{Color, EraserKind, FontDescriptor, LineStyle};
 use crate::input::state::PresetFeedbackKind;
 use crate::input::tool::{ToolControlGroup, ToolProfile};
 use crate::input::{EraserMode, Tool};
@@ -261,6 +261,7 @@ pub struct ToolbarSnapshot {
     pub zoom_locked: bool,
     pub fill_enabled: bool,
     pub polygon_sides: u8,
+    pub line_style: LineStyle,
     pub arrow_label_enabled: bool,
     pub arrow_label_next: u32,
     pub step_marker_next: u32,
@@ -50,6 +50,20 @@ pub(crate) struct ArrowOutline {
     pub points: [(f64, f64); 7],
 }
 
+/// A styled arrow: a plain dashed shaft ending just behind a filled head.
+///
+/// The tapered outline of a solid arrow is one fill and cannot be dashed, so
+/// styled arrows stroke the shaft at full width instead and fill the head
+/// triangle separately.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DashedArrow {
+    pub tail: (f64, f64),
+    /// Where the stroked shaft stops, half a stroke short of the head base so
+    /// its round cap ends flush. `None` when the head swallows the whole arrow.
+    pub shaft_end: Option<(f64, f64)>,
+    pub head: ArrowheadTriangle,
+}
+
 /// The shaft axis plus the arrowhead sizing every arrow consumer needs.
 ///
 /// Both the head triangle and the full outline derive from this, so the
@@ -225,3 +239,42 @@ pub(crate) fn calculate_arrow_outline(
         ],
     })
 }
+
+/// Calculates the shaft and head of an arrow drawn with a non-solid line style.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn calculate_dashed_arrow(
+    tip_x: i32,
+    tip_y: i32,
+    tail_x: i32,
+    tail_y: i32,
+    thick: f64,
+    arrow_length: f64,
+    arrow_angle: f64,
+) -> Option<DashedArrow> {
+    let axis = arrow_axis(
+        tip_x,
+        tip_y,
+        tail_x,
+        tail_y,
+        thick,
+        arrow_length,
+        arrow_angle,
+    )?;
+    let base = axis.base();
+    let shaft_length = (axis.tail.0 - base.0).hypot(axis.tail.1 - base.1);
+    let inset = thick / 2.0;
+    let shaft_end = (shaft_length > inset).then_some((
+        base.0 + axis.toward_tail.0 * inset,
+        base.1 + axis.toward_tail.1 * inset,
+    ));
+
+    Some(DashedArrow {
+        tail: axis.tail,
+        shaft_end,
+        head: ArrowheadTriangle {
+            tip: axis.tip,
+            left: axis.offset(base, 1.0, axis.head_half_base),
+            right: axis.offset(base, -1.0, axis.head_half_base),
+        },
+    })
+}
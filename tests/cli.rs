@@ -255,6 +255,7 @@ fn saved_tool_state() -> wayscriber::session::ToolStateSnapshot {
         eraser_kind: wayscriber::draw::EraserKind::Circle,
         eraser_mode: wayscriber::input::EraserMode::Brush,
         blur_style: Default::default(),
+        line_style: Default::default(),
         recent_colors: Vec::new(),
         marker_opacity: Some(0.32),
         fill_enabled: Some(false),
@@ -286,6 +287,7 @@ fn saved_line_snapshot(with_tool_state: bool) -> wayscriber::session::SessionSna
             a: 1.0,
         },
         thick: 2.0,
+        line_style: wayscriber::draw::LineStyle::Solid,
     });
 
     wayscriber::session::SessionSnapshot {
@@ -857,6 +859,7 @@ fn session_info_reports_saved_snapshot() {
             a: 1.0,
         },
         thick: 2.0,
+        line_style: wayscriber::draw::LineStyle::Solid,
     });
 
     let snapshot = wayscriber::session::SessionSnapshot {
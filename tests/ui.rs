@@ -3,7 +3,7 @@ use wayscriber::config::{
     Action, HelpOverlayStyle, KeybindingsConfig, PresenterModeConfig, StatusBarStyle,
     StatusPosition,
 };
-use wayscriber::draw::{Color, Shape};
+use wayscriber::draw::{Color, LineStyle, Shape};
 use wayscriber::input::{
     BOARD_ID_BLACKBOARD, BOARD_ID_WHITEBOARD, ClickHighlightSettings, EraserMode, InputState,
 };
@@ -221,6 +221,8 @@ fn render_shape_ellipse_does_not_connect_to_existing_current_path() {
             fill: false,
             color: magenta,
             thick: 6.0,
+            rotation: 0.0,
+            line_style: LineStyle::Solid,
         },
     );
 
@@ -0,0 +1,288 @@
+#!/usr/bin/env python3
+"""Regenerate locales/wayscriber.pot, the English message catalog.
+
+Overlay text is written in English in the source and doubles as the gettext
+message id (see src/i18n.rs). This script collects every id from:
+
+- `tr(...)` / `tr_args(...)` calls: string literals in the message argument,
+  a `const` it names, or the `let` binding it names;
+- action registry entries (`meta!` label, short label, description) and the
+  display labels derived from them;
+- tool catalog labels;
+- help overlay `row(...)` literals;
+- toast messages and chip labels passed as plain literals.
+
+Text with values in it uses a `{placeholder}` id passed to `tr_args`. A toast
+built with `format!` is looked up whole and can never match an id, so the
+script reports any it finds.
+
+Translations live in locales/<lang>.po; after regenerating, add new ids there
+with `msgmerge` or by hand. `cargo test i18n` checks the result.
+
+Usage: ./tools/update-pot.py
+"""
+
+from __future__ import annotations
+
+import pathlib
+import re
+import sys
+
+ROOT = pathlib.Path(__file__).resolve().parent.parent
+SRC = ROOT / "src"
+POT = ROOT / "locales" / "wayscriber.pot"
+
+TR_CALL = re.compile(r"\btr(_args)?\(")
+TOAST_CALL = re.compile(
+    r"\bToast::(?:info|warning|error)\(|"
+    r"\.(?:action|command|secondary_action|secondary_command)\("
+)
+TOAST_NEW = re.compile(r"\bToast::new\(")
+HELP_ROW = re.compile(r"\brow\(")
+CONST_DEF = re.compile(r"\bconst\s+([A-Z][A-Z0-9_]*)\s*:\s*&(?:'static\s+)?str\s*=\s*")
+META_CALL = re.compile(r"\bmeta!\(")
+DESCRIPTOR_FIELD = re.compile(r"\b(?:short_label|display_label):\s*")
+THICKNESS_PROFILE = re.compile(r"\bprofile\(")
+
+
+def strip_tests(source: str) -> str:
+    """Drops an inline `#[cfg(test)] mod tests { ... }` tail."""
+    match = re.search(r"#\[cfg\(test\)\]\s*mod\s+\w+\s*\{", source)
+    return source[: match.start()] if match else source
+
+
+def skip_comment_or_char(source: str, i: int) -> int | None:
+    if source.startswith("//", i):
+        end = source.find("\n", i)
+        return len(source) if end < 0 else end
+    if source.startswith("/*", i):
+        end = source.find("*/", i + 2)
+        return len(source) if end < 0 else end + 2
+    return None
+
+
+def read_string(source: str, i: int) -> tuple[str, int] | None:
+    """Parses a Rust string literal starting at `i`; returns (text, end)."""
+    raw = re.match(r'r(#*)"', source[i:])
+    if raw:
+        hashes = raw.group(1)
+        start = i + raw.end()
+        end = source.index('"' + hashes, start)
+        return source[start:end], end + 1 + len(hashes)
+    if i >= len(source) or source[i] != '"':
+        return None
+    out = []
+    j = i + 1
+    while source[j] != '"':
+        ch = source[j]
+        if ch != "\\":
+            out.append(ch)
+            j += 1
+            continue
+        esc = source[j + 1]
+        if esc == "\n":
+            j += 2
+            while source[j] in " \t\r\n":
+                j += 1
+            continue
+        if esc == "u":
+            close = source.index("}", j)
+            out.append(chr(int(source[j + 3 : close], 16)))
+            j = close + 1
+            continue
+        out.append({"n": "\n", "t": "\t", "r": "\r", "0": "\0"}.get(esc, esc))
+        j += 2
+    return "".join(out), j + 1
+
+
+def arguments(source: str, open_paren: int) -> tuple[list[tuple[int, int]], int]:
+    """Top-level argument spans of the call whose `(` is at `open_paren`."""
+    depth = 0
+    spans = []
+    start = open_paren + 1
+    i = open_paren
+    while i < len(source):
+        skipped = skip_comment_or_char(source, i)
+        if skipped is not None:
+            i = skipped
+            continue
+        literal = read_string(source, i) if source[i] in 'r"' else None
+        if literal and (source[i] == '"' or re.match(r'r#*"', source[i:])):
+            i = literal[1]
+            continue
+        ch = source[i]
+        if ch in "([{":
+            depth += 1
+        elif ch in ")]}":
+            depth -= 1
+            if depth == 0:
+                if source[start:i].strip():
+                    spans.append((start, i))
+                return spans, i + 1
+        elif ch == "," and depth == 1:
+            spans.append((start, i))
+            start = i + 1
+        i += 1
+    raise ValueError("unbalanced call")
+
+
+def literals(source: str, start: int, end: int) -> list[str]:
+    found = []
+    i = start
+    while i < end:
+        skipped = skip_comment_or_char(source, i)
+        if skipped is not None:
+            i = skipped
+            continue
+        if source[i] == '"' or (
+            source[i] == "r"
+            and re.match(r'r#*"', source[i:])
+            and not source[i - 1].isalnum()
+            and source[i - 1] != "_"
+        ):
+            text, i = read_string(source, i)
+            found.append(text)
+            continue
+        if source[i] == "'" and re.match(r"'(\\.|[^\\'])'", source[i:]):
+            i += len(re.match(r"'(\\.|[^\\'])'", source[i:]).group(0))
+            continue
+        i += 1
+    return found
+
+
+def sole_literal(source: str, span: tuple[int, int]) -> str | None:
+    text = re.sub(r"\.(?:to_string|to_owned|into)\(\)$", "", source[span[0] : span[1]].strip())
+    parsed = read_string(text, 0) if text[:1] in 'r"' else None
+    if parsed and parsed[1] == len(text):
+        return parsed[0]
+    return None
+
+
+def collect(path: pathlib.Path, consts: dict[str, str], ids: dict[str, set[str]]):
+    source = strip_tests(path.read_text())
+    rel = str(path.relative_to(ROOT))
+
+    def add(text: str | None):
+        if text and any(ch.isalpha() for ch in text):
+            ids.setdefault(text, set()).add(rel)
+
+    for match in TR_CALL.finditer(source):
+        spans, _ = arguments(source, match.end() - 1)
+        if not spans:
+            continue
+        message = spans[0]
+        found = literals(source, *message)
+        if found:
+            for text in found:
+                add(text)
+            continue
+        name = source[message[0] : message[1]].strip()
+        if re.fullmatch(r"[A-Z][A-Z0-9_]*", name):
+            add(consts.get(name))
+        elif re.fullmatch(r"[a-z_][a-z0-9_]*", name):
+            binding = source.rfind(f"let {name} =", 0, match.start())
+            if binding >= 0:
+                end = source.index(";", binding)
+                for text in literals(source, binding, end):
+                    add(text)
+
+    for pattern, index in ((TOAST_CALL, 0), (TOAST_NEW, 1)):
+        for match in pattern.finditer(source):
+            spans, _ = arguments(source, match.end() - 1)
+            if len(spans) > index:
+                add(sole_literal(source, spans[index]))
+                if source[spans[index][0] : spans[index][1]].strip().startswith("format!"):
+                    line = source.count("\n", 0, match.start()) + 1
+                    print(
+                        f"{rel}:{line}: formatted toast text cannot be translated",
+                        file=sys.stderr,
+                    )
+
+    if rel.startswith("src/ui/help_overlay/"):
+        for match in HELP_ROW.finditer(source):
+            spans, _ = arguments(source, match.end() - 1)
+            for span in spans:
+                add(sole_literal(source, span))
+
+    if rel.startswith("src/config/action_meta/entries/"):
+        for match in META_CALL.finditer(source):
+            spans, _ = arguments(source, match.end() - 1)
+            label = sole_literal(source, spans[1])
+            add(label)
+            add(display_label(label))
+            for text in literals(source, *spans[2]):
+                add(text)
+            add(sole_literal(source, spans[3]))
+
+    if rel == "src/input/tool/catalog.rs":
+        for match in DESCRIPTOR_FIELD.finditer(source):
+            parsed = read_string(source, match.end())
+            if parsed:
+                add(parsed[0])
+        for match in THICKNESS_PROFILE.finditer(source):
+            spans, _ = arguments(source, match.end() - 1)
+            if len(spans) == 5:
+                add(sole_literal(source, spans[4]))
+
+
+def display_label(label: str) -> str:
+    """Mirrors `english_display_label` in src/config/action_meta/mod.rs."""
+    for suffix in (" Tool", " Mode"):
+        if label.endswith(suffix):
+            return label[: -len(suffix)]
+    if label.startswith("Toggle "):
+        return label[len("Toggle ") :]
+    return label
+
+
+def escape(text: str) -> str:
+    return (
+        text.replace("\\", "\\\\")
+        .replace('"', '\\"')
+        .replace("\n", "\\n")
+        .replace("\t", "\\t")
+    )
+
+
+def main() -> int:
+    files = sorted(
+        path
+        for path in SRC.rglob("*.rs")
+        if path.name != "tests.rs" and "tests" not in path.relative_to(SRC).parts
+    )
+    consts = {}
+    for path in files:
+        source = path.read_text()
+        for match in CONST_DEF.finditer(source):
+            parsed = read_string(source, match.end())
+            if parsed:
+                consts[match.group(1)] = parsed[0]
+
+    ids: dict[str, set[str]] = {}
+    for path in files:
+        collect(path, consts, ids)
+    ids.setdefault("Thickness", set()).add("src/ui/toolbar/snapshot/types.rs")
+    ids.setdefault("Eraser size", set()).add("src/ui/toolbar/snapshot/types.rs")
+
+    lines = [
+        "# English message catalog for wayscriber's overlay text.",
+        "# Generated by tools/update-pot.py; do not edit by hand.",
+        'msgid ""',
+        'msgstr ""',
+        '"Content-Type: text/plain; charset=UTF-8\\n"',
+        "",
+    ]
+    for text in sorted(ids):
+        for source in sorted(ids[text]):
+            lines.append(f"#: {source}")
+        lines.append(f'msgid "{escape(text)}"')
+        lines.append('msgstr ""')
+        lines.append("")
+    POT.parent.mkdir(exist_ok=True)
+    POT.write_text("\n".join(lines))
+    print(f"Wrote {len(ids)} messages to {POT.relative_to(ROOT)}")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())